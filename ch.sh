@@ -7,6 +7,7 @@ FEATS=(
 	comctl
 	'comctl gdi'
 	'comctl shell'
+	dbghelp
 	dshow
 	dwm
 	dxgi
@@ -16,9 +17,13 @@ FEATS=(
 	'gui shell'
 	kernel
 	mf
+	'kernel msg-table'
+	netapi
 	ole
 	oleaut
+	pdh
 	psapi
+	richedit
 	shell
 	taskschd
 	user
@@ -2,6 +2,60 @@
 
 use crate::co::*;
 
+const_bitflag! { CRED_ENUMERATE: u32;
+	/// [`CredEnumerate`](crate::CredEnumerate) `flags` (`u32`).
+	=>
+	NoValue 0
+	ALL_CREDENTIALS 0x1
+}
+
+const_bitflag! { CRED_FLAGS: u32;
+	/// [`CREDENTIAL`](crate::CREDENTIAL) `Flags` (`u32`).
+	=>
+	NoValue 0
+	PASSWORD_FOR_CERT 0x1
+	PROMPT_NOW 0x2
+	USERNAME_TARGET 0x4
+}
+
+const_ordinary! { CRED_PERSIST: u32;
+	/// [`CREDENTIAL`](crate::CREDENTIAL) `Persist` (`u32`).
+	=>
+	NONE 0
+	SESSION 1
+	LOCAL_MACHINE 2
+	ENTERPRISE 3
+}
+
+const_ordinary! { CRED_TYPE: u32;
+	/// [`CREDENTIAL`](crate::CREDENTIAL) `Type` (`u32`).
+	=>
+	GENERIC 1
+	DOMAIN_PASSWORD 2
+	DOMAIN_CERTIFICATE 3
+	DOMAIN_VISIBLE_PASSWORD 4
+	GENERIC_CERTIFICATE 5
+	DOMAIN_EXTENDED 6
+}
+
+const_bitflag! { CRED_WRITE: u32;
+	/// [`CredWrite`](crate::CredWrite) `flags` (`u32`).
+	=>
+	NoValue 0
+	PRESERVE_CREDENTIAL_BLOB 0x1
+}
+
+const_bitflag! { CRYPTPROTECT: u32;
+	/// [`CryptProtectData`](crate::CryptProtectData) and
+	/// [`CryptUnprotectData`](crate::CryptUnprotectData) `flags` (`u32`).
+	=>
+	NoValue 0
+	UI_FORBIDDEN 0x1
+	LOCAL_MACHINE 0x4
+	AUDIT 0x10
+	VERIFY_PROTECTION 0x40
+}
+
 const_ordinary! { EVENTLOG: u16;
 	/// [`HEVENTLOG::ReportEvent`](crate::prelude::advapi_Heventlog::ReportEvent)
 	/// `event_type` [`u16`].
@@ -14,6 +68,28 @@ const_ordinary! { EVENTLOG: u16;
 	WARNING_TYPE 0x0002
 }
 
+const_ordinary! { EVENT_DATA_DESCRIPTOR_TYPE: u8;
+	/// [`EVENT_DATA_DESCRIPTOR`](crate::EVENT_DATA_DESCRIPTOR) `Type`
+	/// (`u8`).
+	=>
+	NONE 0
+	EVENT_METADATA 1
+	PROVIDER_METADATA 2
+	TIMESTAMP_OVERRIDE 3
+}
+
+const_ordinary! { EVENT_INFO_CLASS: u32;
+	/// [`EVENT_INFO_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/ne-evntprov-event_info_class)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Event` prefix.
+	=>
+	ProviderBinaryTrackInfo 0
+	ProviderSetReserved1 1
+	ProviderSetTraits 2
+	ProviderUseDescriptorType 3
+}
+
 const_bitflag! { KEY: u32;
 	/// [Registry access rights](https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry-key-security-and-access-rights)
 	/// (`u32`).
@@ -33,6 +109,18 @@ const_bitflag! { KEY: u32;
 	ALL_ACCESS (STANDARD_RIGHTS::ALL.raw() | Self::QUERY_VALUE.0 | Self::SET_VALUE.0 | Self::CREATE_SUB_KEY.0 | Self::ENUMERATE_SUB_KEYS.0 | Self::NOTIFY.0 | Self::CREATE_LINK.0) & !ACCESS_RIGHTS::SYNCHRONIZE.raw()
 }
 
+const_bitflag! { LOGON: u32;
+	/// [`HACCESSTOKEN::CreateProcessWithToken`](crate::prelude::advapi_Haccesstoken::CreateProcessWithToken)
+	/// `logon_flags` (`u32`).
+	///
+	/// Originally has `LOGON` prefix.
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	WITH_PROFILE 0x0000_0001
+	NETCREDENTIALS_ONLY 0x0000_0002
+}
+
 const_ordinary! { REG: u32;
 	/// Registry
 	/// [value types](https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry-value-types)
@@ -267,6 +355,13 @@ const_ordinary! { RID: u32;
 	SECURITY_PROCESS_PROTECTION_LEVEL_ANTIMALWARE 0x0000_0600
 	SECURITY_PROCESS_PROTECTION_LEVEL_AUTHENTICODE 0x0000_0400
 	SECURITY_PROCESS_PROTECTION_LEVEL_NONE 0x0000_0000
+	SECURITY_MANDATORY_UNTRUSTED 0x0000_0000
+	SECURITY_MANDATORY_LOW 0x0000_1000
+	SECURITY_MANDATORY_MEDIUM 0x0000_2000
+	SECURITY_MANDATORY_MEDIUM_PLUS 0x0000_2100
+	SECURITY_MANDATORY_HIGH 0x0000_3000
+	SECURITY_MANDATORY_SYSTEM 0x0000_4000
+	SECURITY_MANDATORY_PROTECTED_PROCESS 0x0000_5000
 	SECURITY_TRUSTED_INSTALLER_1 9_5600_8885
 	SECURITY_TRUSTED_INSTALLER_2 34_1852_2649
 	SECURITY_TRUSTED_INSTALLER_3 18_3103_8044
@@ -572,6 +667,18 @@ const_ordinary! { TOKEN_TYPE: u32;
 	Impersonation 2
 }
 
+const_ordinary! { TRACE_LEVEL: u8;
+	/// [Event tracing levels](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/nf-evntrace-enabletraceex2)
+	/// (`u8`).
+	=>
+	NONE 0
+	CRITICAL 1
+	ERROR 2
+	WARNING 3
+	INFORMATION 4
+	VERBOSE 5
+}
+
 const_bitflag! { TRANSACTION: u32;
 	/// [`Transaction access masks`](https://learn.microsoft.com/en-us/windows/win32/ktm/transaction-access-masks)
 	/// (`u32`).
@@ -349,3 +349,180 @@ pub enum TokenInfo<'a, 'b, 'c, 'd, 'e, 'f> {
 	DeviceGroups(Box<TOKEN_GROUPS<'a>>),
 	RestrictedDeviceGroups(Box<TOKEN_GROUPS<'a>>),
 }
+
+/// Value of a field of a TraceLogging event, used in
+/// [`TraceLoggingEvent`](crate::TraceLoggingEvent).
+#[derive(Clone, Debug, PartialEq)]
+pub enum TraceValue {
+	/// An `i8` integer.
+	I8(i8),
+	/// An `u8` integer.
+	U8(u8),
+	/// An `i16` integer.
+	I16(i16),
+	/// An `u16` integer.
+	U16(u16),
+	/// An `i32` integer.
+	I32(i32),
+	/// An `u32` integer.
+	U32(u32),
+	/// An `i64` integer.
+	I64(i64),
+	/// An `u64` integer.
+	U64(u64),
+	/// An `f32` floating point.
+	F32(f32),
+	/// An `f64` floating point.
+	F64(f64),
+	/// A boolean, written as a 32-bit `BOOL`.
+	Bool(bool),
+	/// A string, written as a null-terminated UTF-16 string.
+	Str(String),
+	/// Binary data, which is truncated to 65,535 bytes when written.
+	Binary(Vec<u8>),
+	/// A [`GUID`](crate::GUID).
+	Guid(GUID),
+	/// A [`FILETIME`](crate::FILETIME).
+	FileTime(FILETIME),
+}
+
+impl TraceValue {
+	/// Returns the TraceLogging in-type of the value.
+	#[must_use]
+	pub(in crate::advapi) const fn in_type(&self) -> u8 {
+		match self {
+			Self::Str(_) => 1, // TlgInUNICODESTRING
+			Self::I8(_) => 3,
+			Self::U8(_) => 4,
+			Self::I16(_) => 5,
+			Self::U16(_) => 6,
+			Self::I32(_) => 7,
+			Self::U32(_) => 8,
+			Self::I64(_) => 9,
+			Self::U64(_) => 10,
+			Self::F32(_) => 11,
+			Self::F64(_) => 12,
+			Self::Bool(_) => 13, // TlgInBOOL32
+			Self::Binary(_) => 14,
+			Self::Guid(_) => 15,
+			Self::FileTime(_) => 17,
+		}
+	}
+
+	/// Appends the payload bytes of the value.
+	pub(in crate::advapi) fn write_to(&self, buf: &mut Vec<u8>) {
+		match self {
+			Self::I8(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::U8(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::I16(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::U16(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::I32(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::U32(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::I64(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::U64(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::F32(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::F64(n) => buf.extend_from_slice(&n.to_le_bytes()),
+			Self::Bool(b) => buf.extend_from_slice(&(*b as u32).to_le_bytes()),
+			Self::Str(s) => {
+				s.encode_utf16()
+					.chain(std::iter::once(0))
+					.for_each(|ch| buf.extend_from_slice(&ch.to_le_bytes()));
+			},
+			Self::Binary(data) => {
+				let data = &data[..data.len().min(u16::MAX as _)];
+				buf.extend_from_slice(&(data.len() as u16).to_le_bytes());
+				buf.extend_from_slice(data);
+			},
+			Self::Guid(guid) => {
+				buf.extend_from_slice(&unsafe { std::mem::transmute::<GUID, [u8; 16]>(*guid) })
+			},
+			Self::FileTime(ft) => {
+				buf.extend_from_slice(&ft.dwLowDateTime.to_le_bytes());
+				buf.extend_from_slice(&ft.dwHighDateTime.to_le_bytes());
+			},
+		}
+	}
+
+	/// Parses a value of the given TraceLogging in-type from the payload,
+	/// advancing the offset. ANSI strings are decoded as UTF-8.
+	pub(in crate::advapi) fn read_from(
+		in_type: u8,
+		payload: &[u8],
+		off: &mut usize,
+	) -> SysResult<Self> {
+		let o = *off;
+		let (val, len) = match in_type {
+			1 => {
+				// TlgInUNICODESTRING, null-terminated
+				let wchars = payload
+					.get(o..)
+					.unwrap_or_default()
+					.chunks_exact(2)
+					.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+					.take_while(|ch| *ch != 0)
+					.collect::<Vec<_>>();
+				if payload.len() < o + wchars.len() * 2 + 2 {
+					return Err(co::ERROR::INVALID_DATA); // no terminating null
+				}
+				(Self::Str(String::from_utf16_lossy(&wchars)), wchars.len() * 2 + 2)
+			},
+			2 => {
+				// TlgInANSISTRING, null-terminated
+				let bytes = payload.get(o..).unwrap_or_default();
+				let len = bytes
+					.iter()
+					.position(|b| *b == 0)
+					.ok_or(co::ERROR::INVALID_DATA)?;
+				(Self::Str(String::from_utf8_lossy(&bytes[..len]).into_owned()), len + 1)
+			},
+			3 => (Self::I8(i8::from_le_bytes(le_bytes(payload, o)?)), 1),
+			4 => (Self::U8(u8::from_le_bytes(le_bytes(payload, o)?)), 1),
+			5 => (Self::I16(i16::from_le_bytes(le_bytes(payload, o)?)), 2),
+			6 => (Self::U16(u16::from_le_bytes(le_bytes(payload, o)?)), 2),
+			7 => (Self::I32(i32::from_le_bytes(le_bytes(payload, o)?)), 4),
+			8 | 20 => (Self::U32(u32::from_le_bytes(le_bytes(payload, o)?)), 4), // HEXINT32
+			9 => (Self::I64(i64::from_le_bytes(le_bytes(payload, o)?)), 8),
+			10 | 21 => (Self::U64(u64::from_le_bytes(le_bytes(payload, o)?)), 8), // HEXINT64
+			11 => (Self::F32(f32::from_le_bytes(le_bytes(payload, o)?)), 4),
+			12 => (Self::F64(f64::from_le_bytes(le_bytes(payload, o)?)), 8),
+			13 => (Self::Bool(u32::from_le_bytes(le_bytes(payload, o)?) != 0), 4),
+			14 | 22 | 23 | 25 => {
+				// BINARY, COUNTEDSTRING, COUNTEDANSISTRING, COUNTEDBINARY
+				let cb = u16::from_le_bytes(le_bytes(payload, o)?) as usize;
+				let data = payload
+					.get(o + 2..o + 2 + cb)
+					.ok_or(co::ERROR::INVALID_DATA)?;
+				let val = match in_type {
+					22 => {
+						let wchars = data
+							.chunks_exact(2)
+							.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+							.collect::<Vec<_>>();
+						Self::Str(String::from_utf16_lossy(&wchars))
+					},
+					23 => Self::Str(String::from_utf8_lossy(data).into_owned()),
+					_ => Self::Binary(data.to_vec()),
+				};
+				(val, 2 + cb)
+			},
+			15 => (Self::Guid(unsafe { std::mem::transmute::<[u8; 16], GUID>(le_bytes(payload, o)?) }), 16),
+			17 => (
+				Self::FileTime(FILETIME {
+					dwLowDateTime: u32::from_le_bytes(le_bytes(payload, o)?),
+					dwHighDateTime: u32::from_le_bytes(le_bytes(payload, o + 4)?),
+				}),
+				8,
+			),
+			19 => {
+				// TlgInSID, variable length
+				let sub_auths = u8::from_le_bytes(le_bytes(payload, o + 1)?) as usize;
+				let len = 8 + 4 * sub_auths;
+				let data = payload.get(o..o + len).ok_or(co::ERROR::INVALID_DATA)?;
+				(Self::Binary(data.to_vec()), len)
+			},
+			_ => return Err(co::ERROR::NOT_SUPPORTED), // pointers, SYSTEMTIME, structs
+		};
+		*off = o + len;
+		Ok(val)
+	}
+}
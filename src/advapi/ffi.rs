@@ -6,20 +6,35 @@ extern_sys! { "advapi32";
 	CheckTokenCapability(HANDLE, PCVOID, *mut BOOL) -> BOOL
 	CheckTokenMembership(HANDLE, PCVOID, *mut BOOL) -> BOOL
 	CloseServiceHandle(HANDLE) -> BOOL
+	CloseTrace(u64) -> u32
+	ControlTraceW(u64, PCSTR, PVOID, u32) -> u32
 	ConvertSidToStringSidW(PCVOID, *mut PSTR) -> BOOL
 	ConvertStringSidToSidW(PCSTR, *mut *mut u8) -> BOOL
 	CopySid(u32, PVOID, PCVOID) -> BOOL
+	CreateProcessAsUserW(HANDLE, PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
+	CreateProcessWithTokenW(HANDLE, u32, PCSTR, PSTR, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
 	CreateServiceW(HANDLE, PCSTR, PCSTR, u32, u32, u32, u32, PCSTR, PCSTR, *mut u32, PCSTR, PCSTR, PCSTR) -> HANDLE
 	CreateWellKnownSid(u32, PCVOID, PVOID, *mut u32) -> BOOL
+	CredDeleteW(PCSTR, u32, u32) -> BOOL
+	CredEnumerateW(PCSTR, u32, *mut u32, *mut PVOID) -> BOOL
+	CredFree(PCVOID)
+	CredReadW(PCSTR, u32, u32, *mut PVOID) -> BOOL
+	CredWriteW(PCVOID, u32) -> BOOL
 	DecryptFileW(PCSTR, u32) -> BOOL
 	DeleteService(HANDLE) -> BOOL
 	DeregisterEventSource(HANDLE) -> BOOL
 	DuplicateToken(HANDLE, u32, *mut HANDLE) -> BOOL
+	EnableTraceEx2(u64, PCVOID, u32, u8, u64, u64, u32, PCVOID) -> u32
 	EncryptFileW(PCSTR) -> BOOL
 	EncryptionDisable(PCSTR, BOOL) -> BOOL
 	EqualDomainSid(PVOID, PVOID, *mut BOOL) -> BOOL
 	EqualPrefixSid(PVOID, PVOID) -> BOOL
 	EqualSid(PVOID, PVOID) -> BOOL
+	EventProviderEnabled(u64, u8, u64) -> u8
+	EventRegister(PCVOID, PCVOID, PCVOID, *mut u64) -> u32
+	EventSetInformation(u64, u32, PCVOID, u32) -> u32
+	EventUnregister(u64) -> u32
+	EventWriteTransfer(u64, PCVOID, PCVOID, PCVOID, u32, PCVOID) -> u32
 	FreeSid(PVOID)
 	GetLengthSid(PVOID) -> u32
 	GetSidLengthRequired(u8) -> u32
@@ -42,6 +57,8 @@ extern_sys! { "advapi32";
 	OpenSCManagerW(PCSTR, PCSTR, u32) -> HANDLE
 	OpenServiceW(HANDLE, PCSTR, u32) -> HANDLE
 	OpenThreadToken(HANDLE, u32, BOOL, *mut HANDLE) -> BOOL
+	OpenTraceW(PVOID) -> u64
+	ProcessTrace(*const u64, u32, PCVOID, PCVOID) -> u32
 	RegCloseKey(HANDLE) -> i32
 	RegConnectRegistryW(PCSTR, HANDLE, *mut HANDLE) -> i32
 	RegCopyTreeW(HANDLE, PCSTR, HANDLE) -> i32
@@ -80,6 +97,21 @@ extern_sys! { "advapi32";
 	RegUnLoadKeyW(HANDLE, PCSTR) -> i32
 	ReportEventW(HANDLE, u16, u16, u32, PCVOID, u16, u32, *const PCSTR, PCVOID) -> BOOL
 	SetServiceStatus(HANDLE, PCVOID) -> BOOL
+	SetTokenInformation(HANDLE, u32, PVOID, u32) -> BOOL
+	StartTraceW(*mut u64, PCSTR, PVOID) -> u32
+}
+
+extern_sys! { "crypt32";
+	CryptProtectData(PCVOID, PCSTR, PCVOID, PVOID, PVOID, u32, PVOID) -> BOOL
+	CryptUnprotectData(PCVOID, *mut PSTR, PCVOID, PVOID, PVOID, u32, PVOID) -> BOOL
+}
+
+extern_sys! { "kernel32";
+	CopyFileTransactedW(PCSTR, PCSTR, PVOID, PVOID, *mut BOOL, u32, HANDLE) -> BOOL
+	CreateDirectoryTransactedW(PCSTR, PCSTR, PVOID, HANDLE) -> BOOL
+	CreateFileTransactedW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE, HANDLE, *mut u16, PVOID) -> HANDLE
+	DeleteFileTransactedW(PCSTR, HANDLE) -> BOOL
+	MoveFileTransactedW(PCSTR, PCSTR, PVOID, PVOID, u32, HANDLE) -> BOOL
 }
 
 extern_sys! { "ktmw32";
@@ -1,6 +1,6 @@
 #![allow(non_snake_case)]
 
-use crate::advapi::{ffi, privs::*};
+use crate::advapi::{ffi, privs::*, structs::DATA_BLOB};
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
@@ -156,6 +156,53 @@ pub fn ConvertStringSidToSid(str_sid: &str) -> SysResult<LocalFreeSidGuard> {
 	}
 }
 
+/// [`CopyFileTransacted`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-copyfiletransactedw)
+/// function.
+///
+/// The progress routine is not supported.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let tx = w::Transaction::new("Copy config")?;
+///
+/// w::CopyFileTransacted(
+///     "C:\\Temp\\config.ini",
+///     "C:\\Temp\\config.bak",
+///     Some(co::COPY_FILE::FAIL_IF_EXISTS),
+///     tx.htransaction(),
+/// )?;
+///
+/// tx.commit()?;
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`CreateDirectoryTransacted`](crate::CreateDirectoryTransacted)
+/// * [`DeleteFileTransacted`](crate::DeleteFileTransacted)
+/// * [`MoveFileTransacted`](crate::MoveFileTransacted)
+pub fn CopyFileTransacted(
+	existing_file: &str,
+	new_file: &str,
+	flags: Option<co::COPY_FILE>,
+	htransaction: &HTRANSACTION,
+) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::CopyFileTransactedW(
+			WString::from_str(existing_file).as_ptr(),
+			WString::from_str(new_file).as_ptr(),
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			flags.unwrap_or_default().raw(),
+			htransaction.ptr(),
+		)
+	})
+}
+
 /// [`CopySid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-copysid)
 /// function.
 ///
@@ -186,6 +233,30 @@ pub fn CopySid(src: &SID) -> SysResult<SidGuard> {
 	}
 }
 
+/// [`CreateDirectoryTransacted`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createdirectorytransactedw)
+/// function.
+///
+/// # Related functions
+///
+/// * [`CopyFileTransacted`](crate::CopyFileTransacted)
+/// * [`DeleteFileTransacted`](crate::DeleteFileTransacted)
+/// * [`MoveFileTransacted`](crate::MoveFileTransacted)
+pub fn CreateDirectoryTransacted(
+	template_directory: Option<&str>,
+	new_directory: &str,
+	security_attributes: Option<&SECURITY_ATTRIBUTES>,
+	htransaction: &HTRANSACTION,
+) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::CreateDirectoryTransactedW(
+			WString::from_opt_str(template_directory).as_ptr(),
+			WString::from_str(new_directory).as_ptr(),
+			security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+			htransaction.ptr(),
+		)
+	})
+}
+
 /// [`CreateWellKnownSid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-createwellknownsid)
 /// function.
 ///
@@ -249,6 +320,236 @@ pub fn CreateWellKnownSid(
 	}
 }
 
+/// [`CredDelete`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-creddeletew)
+/// function.
+///
+/// # Related functions
+///
+/// * [`CredEnumerate`](crate::CredEnumerate)
+/// * [`CredRead`](crate::CredRead)
+/// * [`CredWrite`](crate::CredWrite)
+pub fn CredDelete(target_name: &str, cred_type: co::CRED_TYPE) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::CredDeleteW(WString::from_str(target_name).as_ptr(), cred_type.raw(), 0)
+	})
+}
+
+/// [`CredEnumerate`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credenumeratew)
+/// function.
+///
+/// If no credential matches the filter, an empty list is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let creds = w::CredEnumerate(Some("MyApp/*"), co::CRED_ENUMERATE::NoValue)?;
+/// for cred in creds.iter() {
+///     println!("{} {}",
+///         cred.TargetName().unwrap_or_default(),
+///         cred.UserName().unwrap_or_default());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`CredDelete`](crate::CredDelete)
+/// * [`CredRead`](crate::CredRead)
+/// * [`CredWrite`](crate::CredWrite)
+#[must_use]
+pub fn CredEnumerate(
+	filter: Option<&str>,
+	flags: co::CRED_ENUMERATE,
+) -> SysResult<CredFreeCredentialsGuard> {
+	let mut count = u32::default();
+	let mut ppcreds = std::ptr::null_mut();
+
+	match bool_to_sysresult(unsafe {
+		ffi::CredEnumerateW(
+			WString::from_opt_str(filter).as_ptr(),
+			flags.raw(),
+			&mut count,
+			&mut ppcreds,
+		)
+	}) {
+		Ok(_) => Ok(unsafe { CredFreeCredentialsGuard::new(ppcreds as _, count as _) }),
+		Err(co::ERROR::NOT_FOUND) => {
+			Ok(unsafe { CredFreeCredentialsGuard::new(std::ptr::null_mut(), 0) })
+		},
+		Err(e) => Err(e),
+	}
+}
+
+/// [`CredRead`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credreadw)
+/// function.
+///
+/// Fails with [`co::ERROR::NOT_FOUND`](crate::co::ERROR::NOT_FOUND) if the
+/// credential doesn't exist.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let cred = w::CredRead("MyApp/api-token", co::CRED_TYPE::GENERIC)?;
+/// let token = String::from_utf8_lossy(cred.CredentialBlob()).into_owned();
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`CredDelete`](crate::CredDelete)
+/// * [`CredEnumerate`](crate::CredEnumerate)
+/// * [`CredWrite`](crate::CredWrite)
+#[must_use]
+pub fn CredRead(target_name: &str, cred_type: co::CRED_TYPE) -> SysResult<CredFreeGuard> {
+	let mut pcred = std::ptr::null_mut();
+	unsafe {
+		bool_to_sysresult(ffi::CredReadW(
+			WString::from_str(target_name).as_ptr(),
+			cred_type.raw(),
+			0,
+			&mut pcred,
+		))
+		.map(|_| CredFreeGuard::new(pcred as _))
+	}
+}
+
+/// [`CredWrite`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credwritew)
+/// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut target = w::WString::from_str("MyApp/api-token");
+/// let mut user = w::WString::from_str("john");
+/// let secret = "my-secret-token";
+///
+/// let mut cred = w::CREDENTIAL::default();
+/// cred.Type = co::CRED_TYPE::GENERIC;
+/// cred.Persist = co::CRED_PERSIST::LOCAL_MACHINE;
+/// cred.set_TargetName(Some(&mut target));
+/// cred.set_UserName(Some(&mut user));
+/// cred.set_CredentialBlob(secret.as_bytes());
+///
+/// w::CredWrite(&cred, co::CRED_WRITE::NoValue)?;
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`CredDelete`](crate::CredDelete)
+/// * [`CredEnumerate`](crate::CredEnumerate)
+/// * [`CredRead`](crate::CredRead)
+pub fn CredWrite(credential: &CREDENTIAL, flags: co::CRED_WRITE) -> SysResult<()> {
+	bool_to_sysresult(unsafe { ffi::CredWriteW(credential as *const _ as _, flags.raw()) })
+}
+
+/// [`CryptProtectData`](https://learn.microsoft.com/en-us/windows/win32/api/dpapi/nf-dpapi-cryptprotectdata)
+/// function.
+///
+/// By default, the data can be decrypted only by the current user; pass
+/// [`co::CRYPTPROTECT::LOCAL_MACHINE`](crate::co::CRYPTPROTECT::LOCAL_MACHINE)
+/// to allow any user of the computer.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let entropy = [0x1f, 0x2e, 0x3d, 0x4c];
+///
+/// let encrypted = w::CryptProtectData(
+///     b"my-secret-token",
+///     Some("API token"),
+///     Some(&entropy),
+///     co::CRYPTPROTECT::UI_FORBIDDEN,
+/// )?;
+///
+/// let (decrypted, descr) = w::CryptUnprotectData(
+///     encrypted.as_slice(),
+///     Some(&entropy),
+///     co::CRYPTPROTECT::UI_FORBIDDEN,
+/// )?;
+/// assert_eq!(decrypted.as_slice(), b"my-secret-token");
+/// assert_eq!(descr.as_deref(), Some("API token"));
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`CryptUnprotectData`](crate::CryptUnprotectData)
+#[must_use]
+pub fn CryptProtectData(
+	data: &[u8],
+	description: Option<&str>,
+	entropy: Option<&[u8]>,
+	flags: co::CRYPTPROTECT,
+) -> SysResult<LocalFreeSecretGuard> {
+	let data_in = DATA_BLOB::from_slice(data);
+	let entropy = entropy.map(DATA_BLOB::from_slice);
+	let mut data_out = DATA_BLOB::default();
+
+	unsafe {
+		bool_to_sysresult(ffi::CryptProtectData(
+			&data_in as *const _ as _,
+			WString::from_opt_str(description).as_ptr(),
+			entropy.as_ref().map_or(std::ptr::null(), |e| e as *const _ as _),
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			flags.raw(),
+			&mut data_out as *mut _ as _,
+		))
+		.map(|_| LocalFreeSecretGuard::new(data_out.pbData as _, data_out.cbData as _))
+	}
+}
+
+/// [`CryptUnprotectData`](https://learn.microsoft.com/en-us/windows/win32/api/dpapi/nf-dpapi-cryptunprotectdata)
+/// function.
+///
+/// Returns the decrypted data and its description.
+///
+/// # Related functions
+///
+/// * [`CryptProtectData`](crate::CryptProtectData)
+#[must_use]
+pub fn CryptUnprotectData(
+	data: &[u8],
+	entropy: Option<&[u8]>,
+	flags: co::CRYPTPROTECT,
+) -> SysResult<(LocalFreeSecretGuard, Option<String>)> {
+	let data_in = DATA_BLOB::from_slice(data);
+	let entropy = entropy.map(DATA_BLOB::from_slice);
+	let mut data_out = DATA_BLOB::default();
+	let mut pdescr: *mut u16 = std::ptr::null_mut();
+
+	bool_to_sysresult(unsafe {
+		ffi::CryptUnprotectData(
+			&data_in as *const _ as _,
+			&mut pdescr,
+			entropy.as_ref().map_or(std::ptr::null(), |e| e as *const _ as _),
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			flags.raw(),
+			&mut data_out as *mut _ as _,
+		)
+	})?;
+
+	let decrypted = unsafe { LocalFreeSecretGuard::new(data_out.pbData as _, data_out.cbData as _) };
+	let descr = if pdescr.is_null() {
+		None
+	} else {
+		let descr = unsafe { WString::from_wchars_nullt(pdescr) }.to_string();
+		let _ = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(pdescr as _)) }; // free returned pointer
+		Some(descr)
+	};
+	Ok((decrypted, descr))
+}
+
 /// [`DecryptFile`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-decryptfilew)
 /// function.
 ///
@@ -260,6 +561,20 @@ pub fn DecryptFile(file_name: &str) -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::DecryptFileW(WString::from_str(file_name).as_ptr(), 0) })
 }
 
+/// [`DeleteFileTransacted`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-deletefiletransactedw)
+/// function.
+///
+/// # Related functions
+///
+/// * [`CopyFileTransacted`](crate::CopyFileTransacted)
+/// * [`CreateDirectoryTransacted`](crate::CreateDirectoryTransacted)
+/// * [`MoveFileTransacted`](crate::MoveFileTransacted)
+pub fn DeleteFileTransacted(file_name: &str, htransaction: &HTRANSACTION) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::DeleteFileTransactedW(WString::from_str(file_name).as_ptr(), htransaction.ptr())
+	})
+}
+
 /// [`EncryptFile`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-encryptfilew)
 /// function.
 ///
@@ -376,6 +691,43 @@ pub fn EqualSid(sid1: &SID, sid2: &SID) -> SysResult<bool> {
 	}
 }
 
+/// [`EventRegister`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/nf-evntprov-eventregister)
+/// function.
+///
+/// For TraceLogging events, prefer
+/// [`TraceLoggingProvider`](crate::TraceLoggingProvider), which also sets the
+/// provider traits.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let provider_id = w::GUID::new("3970f9cf-2c0c-4f11-b1cc-e3a1e9958833");
+/// let reghandle = w::EventRegister(&provider_id)?;
+///
+/// let mut descr = w::EVENT_DESCRIPTOR::default();
+/// descr.Id = 1;
+/// descr.Level = co::TRACE_LEVEL::INFORMATION;
+///
+/// reghandle.EventWriteTransfer(&descr, None, None, &[])?;
+///
+/// // EventUnregister() automatically called
+/// # w::SysResult::Ok(())
+/// ```
+pub fn EventRegister(provider_id: &GUID) -> SysResult<EventUnregisterGuard> {
+	let mut reghandle = REGHANDLE::default();
+	error_to_sysresult(unsafe {
+		ffi::EventRegister(
+			provider_id as *const _ as _,
+			std::ptr::null(),
+			std::ptr::null(),
+			reghandle.as_mut(),
+		)
+	} as _)
+	.map(|_| unsafe { EventUnregisterGuard::new(reghandle) })
+}
+
 /// [`GetLengthSid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getlengthsid)
 /// function.
 ///
@@ -793,6 +1145,34 @@ pub fn LookupPrivilegeValue(system_name: Option<&str>, name: co::SE_PRIV) -> Sys
 	.map(|_| luid)
 }
 
+/// [`MoveFileTransacted`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-movefiletransactedw)
+/// function.
+///
+/// The progress routine is not supported.
+///
+/// # Related functions
+///
+/// * [`CopyFileTransacted`](crate::CopyFileTransacted)
+/// * [`CreateDirectoryTransacted`](crate::CreateDirectoryTransacted)
+/// * [`DeleteFileTransacted`](crate::DeleteFileTransacted)
+pub fn MoveFileTransacted(
+	existing_file: &str,
+	new_file: Option<&str>,
+	flags: co::MOVEFILE,
+	htransaction: &HTRANSACTION,
+) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::MoveFileTransactedW(
+			WString::from_str(existing_file).as_ptr(),
+			WString::from_opt_str(new_file).as_ptr(),
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			flags.raw(),
+			htransaction.ptr(),
+		)
+	})
+}
+
 /// [`RegDisablePredefinedCache`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regdisablepredefinedcache)
 /// function.
 pub fn RegDisablePredefinedCache() -> SysResult<()> {
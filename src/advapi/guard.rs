@@ -7,6 +7,56 @@ use crate::decl::*;
 use crate::guard::*;
 use crate::prelude::*;
 
+/// RAII implementation for a privilege changed by
+/// [`HACCESSTOKEN::set_privilege`](crate::prelude::advapi_Haccesstoken::set_privilege)
+/// which automatically calls
+/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
+/// to restore the previous state when the object goes out of scope.
+pub struct AdjustTokenPrivilegesGuard<'a, H>
+where
+	H: advapi_Haccesstoken,
+{
+	htoken: &'a H,
+	previous_state: TokenPrivilegesGuard,
+}
+
+impl<'a, H> Drop for AdjustTokenPrivilegesGuard<'a, H>
+where
+	H: advapi_Haccesstoken,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.htoken.as_opt() {
+			if self.previous_state.PrivilegeCount > 0 {
+				let _ = h.AdjustTokenPrivileges(DisabPriv::Privs(&self.previous_state)); // ignore errors
+			}
+		}
+	}
+}
+
+impl<'a, H> AdjustTokenPrivilegesGuard<'a, H>
+where
+	H: advapi_Haccesstoken,
+{
+	/// Constructs the guard by taking ownership of the previous state.
+	///
+	/// # Safety
+	///
+	/// Be sure the previous state must be restored with
+	/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(htoken: &'a H, previous_state: TokenPrivilegesGuard) -> Self {
+		Self { htoken, previous_state }
+	}
+
+	/// Returns the privileges as they were before the change, which will be
+	/// restored.
+	#[must_use]
+	pub fn previous_state(&self) -> &TOKEN_PRIVILEGES {
+		&self.previous_state
+	}
+}
+
 handle_guard! { CloseServiceHandleGuard: HSC;
 	ffi::CloseServiceHandle;
 	/// RAII implementation for [`HSC`](crate::HSC) which automatically calls
@@ -22,6 +72,128 @@ handle_guard! { CloseServiceHandleSvcGuard: HSERVICE;
 	/// when the object goes out of scope.
 }
 
+/// RAII implementation for [`CREDENTIAL`](crate::CREDENTIAL), returned by
+/// [`CredRead`](crate::CredRead), which automatically overwrites the secret
+/// with zeros and calls
+/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree)
+/// when the object goes out of scope.
+pub struct CredFreeGuard {
+	pcred: *mut CREDENTIAL<'static>,
+}
+
+impl Drop for CredFreeGuard {
+	fn drop(&mut self) {
+		if let Some(cred) = unsafe { self.pcred.as_mut() } {
+			unsafe {
+				cred.zero_blob();
+				ffi::CredFree(self.pcred as _);
+			}
+		}
+	}
+}
+
+impl Deref for CredFreeGuard {
+	type Target = CREDENTIAL<'static>;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*self.pcred }
+	}
+}
+
+impl CredFreeGuard {
+	/// Constructs the guard by taking ownership of the pointer.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer must be freed with
+	/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree).
+	#[must_use]
+	pub const unsafe fn new(pcred: *mut CREDENTIAL<'static>) -> Self {
+		Self { pcred }
+	}
+
+	/// Ejects the underlying pointer, leaving a null pointer in its place.
+	///
+	/// Since the internal pointer will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> *mut CREDENTIAL<'static> {
+		std::mem::replace(&mut self.pcred, std::ptr::null_mut())
+	}
+}
+
+/// RAII implementation for the array of [`CREDENTIAL`](crate::CREDENTIAL)
+/// returned by [`CredEnumerate`](crate::CredEnumerate), which automatically
+/// overwrites the secrets with zeros and calls
+/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree)
+/// when the object goes out of scope.
+pub struct CredFreeCredentialsGuard {
+	ppcreds: *mut *mut CREDENTIAL<'static>,
+	count: usize,
+}
+
+impl Drop for CredFreeCredentialsGuard {
+	fn drop(&mut self) {
+		if !self.ppcreds.is_null() {
+			unsafe {
+				for i in 0..self.count {
+					if let Some(cred) = (*self.ppcreds.add(i)).as_mut() {
+						cred.zero_blob();
+					}
+				}
+				ffi::CredFree(self.ppcreds as _);
+			}
+		}
+	}
+}
+
+impl CredFreeCredentialsGuard {
+	/// Constructs the guard by taking ownership of the array.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer must be freed with
+	/// [`CredFree`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/nf-wincred-credfree),
+	/// and the count is correct.
+	#[must_use]
+	pub const unsafe fn new(ppcreds: *mut *mut CREDENTIAL<'static>, count: usize) -> Self {
+		Self { ppcreds, count }
+	}
+
+	/// Returns `true` if there are no credentials.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.count == 0
+	}
+
+	/// Returns an iterator over the credentials.
+	#[must_use]
+	pub fn iter(&self) -> impl Iterator<Item = &CREDENTIAL<'static>> + '_ {
+		(0..self.count).map(|i| unsafe { &**self.ppcreds.add(i) })
+	}
+
+	/// Ejects the underlying array pointer and count, leaving null and zero in
+	/// their places.
+	///
+	/// Since the internal pointer will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> (*mut *mut CREDENTIAL<'static>, usize) {
+		(
+			std::mem::replace(&mut self.ppcreds, std::ptr::null_mut()),
+			std::mem::replace(&mut self.count, 0),
+		)
+	}
+
+	/// Returns the number of credentials.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.count
+	}
+}
+
 handle_guard! { DeregisterEventSourceGuard: HEVENTLOG;
 	ffi::DeregisterEventSource;
 	/// RAII implementation for [`HEVENTLOG`](crate::HEVENTLOG) which
@@ -30,6 +202,56 @@ handle_guard! { DeregisterEventSourceGuard: HEVENTLOG;
 	/// when the object goes out of scope.
 }
 
+/// RAII implementation for [`REGHANDLE`](crate::REGHANDLE) which
+/// automatically calls
+/// [`EventUnregister`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/nf-evntprov-eventunregister)
+/// when the object goes out of scope.
+pub struct EventUnregisterGuard {
+	reghandle: REGHANDLE,
+}
+
+impl Drop for EventUnregisterGuard {
+	fn drop(&mut self) {
+		if u64::from(self.reghandle) != 0 {
+			unsafe {
+				ffi::EventUnregister(self.reghandle.into()); // ignore errors
+			}
+		}
+	}
+}
+
+impl Deref for EventUnregisterGuard {
+	type Target = REGHANDLE;
+
+	fn deref(&self) -> &Self::Target {
+		&self.reghandle
+	}
+}
+
+impl EventUnregisterGuard {
+	/// Constructs the guard by taking ownership of the handle.
+	///
+	/// # Safety
+	///
+	/// Be sure the handle must be freed with
+	/// [`EventUnregister`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/nf-evntprov-eventunregister)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(reghandle: REGHANDLE) -> Self {
+		Self { reghandle }
+	}
+
+	/// Ejects the underlying handle, leaving a zero handle in its place.
+	///
+	/// Since the internal handle will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> REGHANDLE {
+		std::mem::take(&mut self.reghandle)
+	}
+}
+
 /// RAII implementation for [`SID`](crate::SID), returned by
 /// [`AllocateAndInitializeSid`](crate::AllocateAndInitializeSid), which
 /// automatically calls
@@ -57,6 +279,12 @@ impl Deref for FreeSidGuard {
 	}
 }
 
+impl DerefMut for FreeSidGuard {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe { &mut *self.psid }
+	}
+}
+
 impl std::fmt::Display for FreeSidGuard {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.deref(), f) // delegate to the underlying SID
@@ -145,6 +373,66 @@ impl RegCloseKeyGuard {
 	}
 }
 
+/// RAII implementation for the memory block returned by
+/// [`CryptProtectData`](crate::CryptProtectData) and
+/// [`CryptUnprotectData`](crate::CryptUnprotectData), which automatically
+/// overwrites the memory with zeros and calls
+/// [`LocalFree`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-localfree)
+/// when the object goes out of scope.
+pub struct LocalFreeSecretGuard {
+	pmem: *mut std::ffi::c_void,
+	sz: usize,
+}
+
+impl Drop for LocalFreeSecretGuard {
+	fn drop(&mut self) {
+		if !self.pmem.is_null() {
+			unsafe {
+				for i in 0..self.sz {
+					std::ptr::write_volatile((self.pmem as *mut u8).add(i), 0);
+				}
+				let _ = LocalFreeGuard::new(HLOCAL::from_ptr(self.pmem));
+			}
+		}
+	}
+}
+
+impl LocalFreeSecretGuard {
+	/// Constructs the guard by taking ownership of the memory block.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer must be freed with
+	/// [`LocalFree`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-localfree),
+	/// and the size is correct.
+	#[must_use]
+	pub const unsafe fn new(pmem: *mut std::ffi::c_void, sz: usize) -> Self {
+		Self { pmem, sz }
+	}
+
+	/// Ejects the underlying memory pointer and size, leaving null and zero in
+	/// their places.
+	///
+	/// Since the internal memory pointer will be invalidated, the destructor
+	/// will not run. It's your responsibility to run it, otherwise you'll cause
+	/// a memory leak.
+	#[must_use]
+	pub fn leak(&mut self) -> (*mut std::ffi::c_void, usize) {
+		(
+			std::mem::replace(&mut self.pmem, std::ptr::null_mut()),
+			std::mem::replace(&mut self.sz, 0),
+		)
+	}
+
+	pub_fn_mem_block!();
+
+	/// Returns `true` if the memory block is empty.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.sz == 0
+	}
+}
+
 /// RAII implementation for [`SID`](crate::SID), returned by
 /// [`ConvertStringSidToSid`](crate::ConvertStringSidToSid), which automatically
 /// calls
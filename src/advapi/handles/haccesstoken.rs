@@ -24,6 +24,200 @@ impl advapi_Haccesstoken for HACCESSTOKEN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait advapi_Haccesstoken: Handle {
+	/// Calls
+	/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::advapi_Haccesstoken::GetTokenInformation)
+	/// with
+	/// [`co::TOKEN_INFORMATION_CLASS::ElevationType`](crate::co::TOKEN_INFORMATION_CLASS::ElevationType).
+	///
+	/// The token must have been opened with
+	/// [`co::TOKEN::QUERY`](crate::co::TOKEN::QUERY).
+	#[must_use]
+	fn elevation_type(&self) -> SysResult<co::TOKEN_ELEVATION_TYPE> {
+		match self.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::ElevationType)? {
+			TokenInfo::ElevationType(ty) => Ok(*ty),
+			_ => unreachable!(),
+		}
+	}
+
+	/// Calls
+	/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::advapi_Haccesstoken::GetTokenInformation)
+	/// with
+	/// [`co::TOKEN_INFORMATION_CLASS::IntegrityLevel`](crate::co::TOKEN_INFORMATION_CLASS::IntegrityLevel),
+	/// returning the last subauthority of the label, like
+	/// [`co::RID::SECURITY_MANDATORY_MEDIUM`](crate::co::RID::SECURITY_MANDATORY_MEDIUM).
+	///
+	/// The token must have been opened with
+	/// [`co::TOKEN::QUERY`](crate::co::TOKEN::QUERY).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::QUERY)?;
+	///
+	/// if htoken.integrity_level()? >= co::RID::SECURITY_MANDATORY_HIGH {
+	///     println!("Running with high integrity.");
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn integrity_level(&self) -> SysResult<co::RID> {
+		match self.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::IntegrityLevel)? {
+			TokenInfo::IntegrityLevel(label) => label
+				.Label
+				.Sid()
+				.and_then(|sid| sid.SubAuthority().last().copied())
+				.ok_or(co::ERROR::INVALID_SID),
+			_ => unreachable!(),
+		}
+	}
+
+	/// Calls
+	/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::advapi_Haccesstoken::GetTokenInformation)
+	/// with
+	/// [`co::TOKEN_INFORMATION_CLASS::Elevation`](crate::co::TOKEN_INFORMATION_CLASS::Elevation).
+	///
+	/// The token must have been opened with
+	/// [`co::TOKEN::QUERY`](crate::co::TOKEN::QUERY).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::QUERY)?;
+	///
+	/// if !htoken.is_elevated()? {
+	///     println!("Not running as administrator.");
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn is_elevated(&self) -> SysResult<bool> {
+		match self.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::Elevation)? {
+			TokenInfo::Elevation(elevation) => Ok(elevation.TokenIsElevated()),
+			_ => unreachable!(),
+		}
+	}
+
+	/// Calls
+	/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::advapi_Haccesstoken::GetTokenInformation)
+	/// with
+	/// [`co::TOKEN_INFORMATION_CLASS::LinkedToken`](crate::co::TOKEN_INFORMATION_CLASS::LinkedToken),
+	/// returning the other token of a split UAC token: the elevated token for a
+	/// limited one, and vice-versa.
+	///
+	/// The token must have been opened with
+	/// [`co::TOKEN::QUERY`](crate::co::TOKEN::QUERY).
+	#[must_use]
+	fn linked_token(&self) -> SysResult<CloseHandleGuard<HACCESSTOKEN>> {
+		match self.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::LinkedToken)? {
+			TokenInfo::LinkedToken(linked) => {
+				Ok(unsafe { CloseHandleGuard::new(linked.LinkedToken.raw_copy()) })
+			},
+			_ => unreachable!(),
+		}
+	}
+
+	/// Sets the integrity level of the token by calling
+	/// [`SetTokenInformation`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-settokeninformation)
+	/// with `TokenIntegrityLevel`.
+	///
+	/// The level can only be lowered, unless the process has the
+	/// [`co::SE_PRIV::TCB_NAME`](crate::co::SE_PRIV::TCB_NAME) privilege. The
+	/// token must have been opened with
+	/// [`co::TOKEN::ADJUST_DEFAULT`](crate::co::TOKEN::ADJUST_DEFAULT).
+	///
+	/// # Examples
+	///
+	/// Dropping the current process to low integrity:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::ADJUST_DEFAULT | co::TOKEN::QUERY)?;
+	///
+	/// htoken.set_integrity_level(co::RID::SECURITY_MANDATORY_LOW)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn set_integrity_level(&self, level: co::RID) -> SysResult<()> {
+		let mut sid = AllocateAndInitializeSid(&SID_IDENTIFIER_AUTHORITY::MANDATORY_LABEL, &[level])?;
+		let mut label = TOKEN_MANDATORY_LABEL::default();
+		label.Label.Attributes = 0x0000_0020; // SE_GROUP_INTEGRITY
+		label.Label.set_Sid(Some(&mut sid));
+
+		bool_to_sysresult(unsafe {
+			ffi::SetTokenInformation(
+				self.ptr(),
+				co::TOKEN_INFORMATION_CLASS::IntegrityLevel.raw(),
+				&mut label as *mut _ as _,
+				(std::mem::size_of::<TOKEN_MANDATORY_LABEL>() as u32) + GetLengthSid(&sid),
+			)
+		})
+	}
+
+	/// Enables or disables a privilege with
+	/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges),
+	/// returning a guard which restores the previous state.
+	///
+	/// Fails with
+	/// [`co::ERROR::NOT_ALL_ASSIGNED`](crate::co::ERROR::NOT_ALL_ASSIGNED) if
+	/// the token doesn't hold the privilege. The token must have been opened
+	/// with [`co::TOKEN::ADJUST_PRIVILEGES`](crate::co::TOKEN::ADJUST_PRIVILEGES)
+	/// and [`co::TOKEN::QUERY`](crate::co::TOKEN::QUERY).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::ADJUST_PRIVILEGES | co::TOKEN::QUERY)?;
+	///
+	/// let _priv_guard = htoken.set_privilege(co::SE_PRIV::BACKUP_NAME, true)?;
+	///
+	/// // privilege is enabled here...
+	///
+	/// // ...and restored when _priv_guard goes out of scope
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn set_privilege(
+		&self,
+		privilege: co::SE_PRIV,
+		enable: bool,
+	) -> SysResult<AdjustTokenPrivilegesGuard<'_, Self>> {
+		let luid = LookupPrivilegeValue(None, privilege)?;
+		let new_state = TOKEN_PRIVILEGES::new(&[LUID_AND_ATTRIBUTES::new(
+			luid,
+			if enable { co::SE_PRIV_ATTR::ENABLED } else { co::SE_PRIV_ATTR::NoValue },
+		)])?;
+		let mut previous_state = TOKEN_PRIVILEGES::new(&[
+			LUID_AND_ATTRIBUTES::new(luid, co::SE_PRIV_ATTR::NoValue), // room for 1 entry
+		])?;
+		let mut ret_len = u32::default();
+
+		bool_to_sysresult(unsafe {
+			ffi::AdjustTokenPrivileges(
+				self.ptr(),
+				0,
+				&*new_state as *const _ as _,
+				std::mem::size_of::<TOKEN_PRIVILEGES>() as _,
+				&mut *previous_state as *mut _ as _,
+				&mut ret_len,
+			)
+		})?;
+
+		match GetLastError() {
+			co::ERROR::NOT_ALL_ASSIGNED => Err(co::ERROR::NOT_ALL_ASSIGNED),
+			_ => Ok(unsafe { AdjustTokenPrivilegesGuard::new(self, previous_state) }),
+		}
+	}
+
 	/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
 	/// function.
 	///
@@ -89,6 +283,86 @@ pub trait advapi_Haccesstoken: Handle {
 		.map(|_| is_member != 0)
 	}
 
+	/// [`CreateProcessAsUser`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createprocessasuserw)
+	/// function.
+	///
+	/// The token must be a primary token, usually returned by
+	/// [`HACCESSTOKEN::linked_token`](crate::prelude::advapi_Haccesstoken::linked_token),
+	/// and the calling process usually needs the
+	/// [`co::SE_PRIV::INCREASE_QUOTA_NAME`](crate::co::SE_PRIV::INCREASE_QUOTA_NAME)
+	/// privilege.
+	#[must_use]
+	fn CreateProcessAsUser(
+		&self,
+		application_name: Option<&str>,
+		command_line: Option<&str>,
+		process_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+		thread_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+		inherit_handles: bool,
+		creation_flags: co::CREATE,
+		environment: Option<Vec<(&str, &str)>>,
+		current_dir: Option<&str>,
+		si: &mut STARTUPINFO,
+	) -> SysResult<CloseHandlePiGuard> {
+		let mut buf_cmd_line = WString::from_opt_str(command_line);
+		let buf_env = environment.as_deref().map(env_block);
+		let mut pi = PROCESS_INFORMATION::default();
+
+		unsafe {
+			bool_to_sysresult(ffi::CreateProcessAsUserW(
+				self.ptr(),
+				WString::from_opt_str(application_name).as_ptr(),
+				buf_cmd_line.as_mut_ptr(),
+				process_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				thread_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				inherit_handles as _,
+				env_creation_flags(creation_flags, &buf_env).raw(),
+				buf_env.as_ref().map_or(std::ptr::null_mut(), |env| env.as_ptr() as _),
+				WString::from_opt_str(current_dir).as_ptr(),
+				si as *mut _ as _,
+				&mut pi as *mut _ as _,
+			))
+			.map(|_| CloseHandlePiGuard::new(pi))
+		}
+	}
+
+	/// [`CreateProcessWithToken`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createprocesswithtokenw)
+	/// function.
+	///
+	/// The calling process must have the
+	/// [`co::SE_PRIV::IMPERSONATE_NAME`](crate::co::SE_PRIV::IMPERSONATE_NAME)
+	/// privilege.
+	#[must_use]
+	fn CreateProcessWithToken(
+		&self,
+		logon_flags: co::LOGON,
+		application_name: Option<&str>,
+		command_line: Option<&str>,
+		creation_flags: co::CREATE,
+		environment: Option<Vec<(&str, &str)>>,
+		current_dir: Option<&str>,
+		si: &mut STARTUPINFO,
+	) -> SysResult<CloseHandlePiGuard> {
+		let mut buf_cmd_line = WString::from_opt_str(command_line);
+		let buf_env = environment.as_deref().map(env_block);
+		let mut pi = PROCESS_INFORMATION::default();
+
+		unsafe {
+			bool_to_sysresult(ffi::CreateProcessWithTokenW(
+				self.ptr(),
+				logon_flags.raw(),
+				WString::from_opt_str(application_name).as_ptr(),
+				buf_cmd_line.as_mut_ptr(),
+				env_creation_flags(creation_flags, &buf_env).raw(),
+				buf_env.as_ref().map_or(std::ptr::null_mut(), |env| env.as_ptr() as _),
+				WString::from_opt_str(current_dir).as_ptr(),
+				si as *mut _ as _,
+				&mut pi as *mut _ as _,
+			))
+			.map(|_| CloseHandlePiGuard::new(pi))
+		}
+	}
+
 	/// [`DuplicateToken`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-duplicatetoken)
 	/// function.
 	#[must_use]
@@ -306,3 +580,19 @@ pub trait advapi_Haccesstoken: Handle {
 		}
 	}
 }
+
+/// Builds the environment block passed to process creation functions.
+fn env_block(environment: &[(&str, &str)]) -> WString {
+	WString::from_str_vec(
+		&environment
+			.iter()
+			.map(|(name, val)| format!("{}={}", name, val))
+			.collect::<Vec<_>>(),
+	)
+}
+
+/// Adds [`co::CREATE::UNICODE_ENVIRONMENT`](crate::co::CREATE::UNICODE_ENVIRONMENT)
+/// if an environment block is passed.
+fn env_creation_flags(flags: co::CREATE, env: &Option<WString>) -> co::CREATE {
+	if env.is_some() { flags | co::CREATE::UNICODE_ENVIRONMENT } else { flags }
+}
@@ -0,0 +1,84 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::advapi::ffi;
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+impl advapi_Hfile for HFILE {}
+
+/// This trait is enabled with the `advapi` feature, and provides methods for
+/// [`HFILE`](crate::HFILE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait advapi_Hfile: kernel_Hfile {
+	/// [`CreateFileTransacted`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createfiletransactedw)
+	/// function.
+	///
+	/// The returned error code is the same as in
+	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let tx = w::Transaction::new("Write settings")?;
+	///
+	/// let (hfile, _) = w::HFILE::CreateFileTransacted(
+	///     "C:\\Temp\\settings.txt",
+	///     co::GENERIC::WRITE,
+	///     None,
+	///     None,
+	///     co::DISPOSITION::CREATE_ALWAYS,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     None,
+	///     None,
+	///     None,
+	///     tx.htransaction(),
+	/// )?;
+	/// hfile.WriteFile("new settings".as_bytes())?;
+	/// drop(hfile); // close the file before committing
+	///
+	/// tx.commit()?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn CreateFileTransacted(
+		file_name: &str,
+		desired_access: co::GENERIC,
+		share_mode: Option<co::FILE_SHARE>,
+		security_attributes: Option<&mut SECURITY_ATTRIBUTES>,
+		creation_disposition: co::DISPOSITION,
+		attributes: co::FILE_ATTRIBUTE,
+		flags: Option<co::FILE_FLAG>,
+		security: Option<co::FILE_SECURITY>,
+		hfile_template: Option<&HFILE>,
+		htransaction: &HTRANSACTION,
+	) -> SysResult<(CloseHandleGuard<HFILE>, co::ERROR)> {
+		unsafe {
+			match HFILE::from_ptr(ffi::CreateFileTransactedW(
+				WString::from_str(file_name).as_ptr(),
+				desired_access.raw(),
+				share_mode.unwrap_or_default().raw(),
+				security_attributes.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				creation_disposition.raw(),
+				attributes.raw()
+					| flags.unwrap_or_default().raw()
+					| security.map_or(0, |s| SECURITY_SQOS_PRESENT | s.raw()),
+				hfile_template.map_or(std::ptr::null_mut(), |h| h.ptr()),
+				htransaction.ptr(),
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+			)) {
+				HFILE::NULL | HFILE::INVALID => Err(GetLastError()),
+				handle => Ok((CloseHandleGuard::new(handle), GetLastError())),
+			}
+		}
+	}
+}
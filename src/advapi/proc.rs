@@ -1,3 +1,4 @@
+use crate::advapi::{ffi, structs::*};
 use crate::decl::*;
 use crate::kernel::ffi_types::*;
 
@@ -13,3 +14,26 @@ where
 	let func = unsafe { &mut *(context as *mut F) };
 	func(unsafe { SvcCtl::from_raw(control, event_type, event_data) })
 }
+
+pub(in crate::advapi) struct TraceProcessContext<F> {
+	pub(in crate::advapi) func: F,
+	pub(in crate::advapi) htrace: u64,
+	pub(in crate::advapi) closed: bool,
+}
+
+pub(in crate::advapi) extern "system" fn trace_session_process<F>(record: *mut EVENT_RECORD)
+where
+	F: FnMut(&TraceEvent) -> bool,
+{
+	let record = unsafe { &*record };
+	let ctx = unsafe { &mut *(record.UserContext as *mut TraceProcessContext<F>) };
+	if !ctx.closed {
+		let event = unsafe { TraceEvent::from_record(record) };
+		if !(ctx.func)(&event) {
+			unsafe {
+				ffi::CloseTrace(ctx.htrace); // ProcessTrace will return CANCELLED
+			}
+			ctx.closed = true;
+		}
+	}
+}
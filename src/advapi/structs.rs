@@ -2,10 +2,11 @@
 
 use std::marker::PhantomData;
 
-use crate::advapi::privs::*;
+use crate::advapi::{ffi, privs::*};
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
+use crate::kernel::{ffi_types::*, privs::*};
 
 /// [`CLAIM_SECURITY_ATTRIBUTES_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attributes_information)
 /// struct.
@@ -27,6 +28,85 @@ impl<'a, 'b> CLAIM_SECURITY_ATTRIBUTES_INFORMATION<'a, 'b> {
 	}
 }
 
+/// [`CREDENTIAL`](https://learn.microsoft.com/en-us/windows/win32/api/wincred/ns-wincred-credentialw)
+/// struct.
+///
+/// Credential attributes are not supported.
+#[repr(C)]
+pub struct CREDENTIAL<'a> {
+	pub Flags: co::CRED_FLAGS,
+	pub Type: co::CRED_TYPE,
+	TargetName: *mut u16,
+	Comment: *mut u16,
+	pub LastWritten: FILETIME,
+	CredentialBlobSize: u32,
+	CredentialBlob: *mut u8,
+	pub Persist: co::CRED_PERSIST,
+	AttributeCount: u32,
+	Attributes: *mut std::ffi::c_void,
+	TargetAlias: *mut u16,
+	UserName: *mut u16,
+
+	_CredentialBlob: PhantomData<&'a [u8]>,
+}
+
+impl_default!(CREDENTIAL, 'a);
+
+impl<'a> CREDENTIAL<'a> {
+	pub_fn_string_ptr_get_set!('a, TargetName, set_TargetName);
+	pub_fn_string_ptr_get_set!('a, Comment, set_Comment);
+
+	/// Returns the `CredentialBlob` field.
+	#[must_use]
+	pub fn CredentialBlob(&self) -> &[u8] {
+		if self.CredentialBlob.is_null() {
+			&[]
+		} else {
+			unsafe {
+				std::slice::from_raw_parts(self.CredentialBlob, self.CredentialBlobSize as _)
+			}
+		}
+	}
+
+	/// Sets the `CredentialBlob` field.
+	pub fn set_CredentialBlob(&mut self, blob: &'a [u8]) {
+		self.CredentialBlob = vec_ptr(blob) as _;
+		self.CredentialBlobSize = blob.len() as _;
+	}
+
+	pub_fn_string_ptr_get_set!('a, TargetAlias, set_TargetAlias);
+	pub_fn_string_ptr_get_set!('a, UserName, set_UserName);
+
+	/// Overwrites the `CredentialBlob` buffer with zeros.
+	///
+	/// # Safety
+	///
+	/// The buffer must be writable.
+	pub(in crate::advapi) unsafe fn zero_blob(&mut self) {
+		for i in 0..self.CredentialBlobSize as usize {
+			std::ptr::write_volatile(self.CredentialBlob.add(i), 0);
+		}
+	}
+}
+
+/// [`DATA_BLOB`](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/aa381414(v=vs.85))
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct DATA_BLOB {
+	pub cbData: u32,
+	pub pbData: *mut u8,
+}
+
+impl_default!(DATA_BLOB);
+
+impl DATA_BLOB {
+	/// Points the blob to the given buffer.
+	#[must_use]
+	pub fn from_slice(data: &[u8]) -> Self {
+		Self { cbData: data.len() as _, pbData: vec_ptr(data) as _ }
+	}
+}
+
 /// [`DEV_BROADCAST_DEVICEINTERFACE`](https://learn.microsoft.com/en-us/windows/win32/api/dbt/ns-dbt-dev_broadcast_deviceinterface_w)
 /// struct.
 #[repr(C)]
@@ -93,6 +173,179 @@ pub struct DEV_BROADCAST_VOLUME {
 	pub dbcv_flags: co::DBTF,
 }
 
+/// [`ETW_BUFFER_CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/api/relogger/ns-relogger-etw_buffer_context)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub(in crate::advapi) struct ETW_BUFFER_CONTEXT {
+	pub(in crate::advapi) ProcessorNumber: u8,
+	pub(in crate::advapi) Alignment: u8,
+	pub(in crate::advapi) LoggerId: u16,
+}
+
+/// [`EVENT_DATA_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/ns-evntprov-event_data_descriptor)
+/// struct.
+#[repr(C)]
+pub struct EVENT_DATA_DESCRIPTOR<'a> {
+	Ptr: u64,
+	Size: u32,
+	pub Type: co::EVENT_DATA_DESCRIPTOR_TYPE,
+	Reserved1: u8,
+	Reserved2: u16,
+
+	_Ptr: PhantomData<&'a [u8]>,
+}
+
+impl_default!(EVENT_DATA_DESCRIPTOR, 'a);
+
+impl<'a> EVENT_DATA_DESCRIPTOR<'a> {
+	/// Returns the data pointed by the `Ptr` and `Size` fields.
+	#[must_use]
+	pub fn data(&self) -> &'a [u8] {
+		if self.Ptr == 0 {
+			&[]
+		} else {
+			unsafe { std::slice::from_raw_parts(self.Ptr as usize as *const u8, self.Size as _) }
+		}
+	}
+
+	/// Sets the `Ptr` and `Size` fields.
+	pub fn set_data(&mut self, data: &'a [u8]) {
+		self.Ptr = data.as_ptr() as usize as _;
+		self.Size = data.len() as _;
+	}
+}
+
+/// [`EVENT_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/ns-evntprov-event_descriptor)
+/// struct.
+#[repr(C)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EVENT_DESCRIPTOR {
+	pub Id: u16,
+	pub Version: u8,
+	pub Channel: u8,
+	pub Level: co::TRACE_LEVEL,
+	pub Opcode: u8,
+	pub Task: u16,
+	pub Keyword: u64,
+}
+
+/// [`EVENT_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/evntcons/ns-evntcons-event_header)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub(in crate::advapi) struct EVENT_HEADER {
+	pub(in crate::advapi) Size: u16,
+	pub(in crate::advapi) HeaderType: u16,
+	pub(in crate::advapi) Flags: u16,
+	pub(in crate::advapi) EventProperty: u16,
+	pub(in crate::advapi) ThreadId: u32,
+	pub(in crate::advapi) ProcessId: u32,
+	pub(in crate::advapi) TimeStamp: i64,
+	pub(in crate::advapi) ProviderId: GUID,
+	pub(in crate::advapi) EventDescriptor: EVENT_DESCRIPTOR,
+	pub(in crate::advapi) ProcessorTime: u64,
+	pub(in crate::advapi) ActivityId: GUID,
+}
+
+/// [`EVENT_HEADER_EXTENDED_DATA_ITEM`](https://learn.microsoft.com/en-us/windows/win32/api/evntcons/ns-evntcons-event_header_extended_data_item)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub(in crate::advapi) struct EVENT_HEADER_EXTENDED_DATA_ITEM {
+	pub(in crate::advapi) Reserved1: u16,
+	pub(in crate::advapi) ExtType: u16,
+	pub(in crate::advapi) Linkage: u16,
+	pub(in crate::advapi) DataSize: u16,
+	pub(in crate::advapi) DataPtr: u64,
+}
+
+/// [`EVENT_RECORD`](https://learn.microsoft.com/en-us/windows/win32/api/evntcons/ns-evntcons-event_record)
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct EVENT_RECORD {
+	pub(in crate::advapi) EventHeader: EVENT_HEADER,
+	pub(in crate::advapi) BufferContext: ETW_BUFFER_CONTEXT,
+	pub(in crate::advapi) ExtendedDataCount: u16,
+	pub(in crate::advapi) UserDataLength: u16,
+	pub(in crate::advapi) ExtendedData: *mut EVENT_HEADER_EXTENDED_DATA_ITEM,
+	pub(in crate::advapi) UserData: PVOID,
+	pub(in crate::advapi) UserContext: PVOID,
+}
+
+/// [`EVENT_TRACE`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/ns-evntrace-event_trace)
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct EVENT_TRACE {
+	pub(in crate::advapi) Header: EVENT_TRACE_HEADER,
+	pub(in crate::advapi) InstanceId: u32,
+	pub(in crate::advapi) ParentInstanceId: u32,
+	pub(in crate::advapi) ParentGuid: GUID,
+	pub(in crate::advapi) MofData: PVOID,
+	pub(in crate::advapi) MofLength: u32,
+	pub(in crate::advapi) ClientContext: u32,
+}
+
+/// [`EVENT_TRACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/ns-evntrace-event_trace_header)
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct EVENT_TRACE_HEADER {
+	pub(in crate::advapi) Size: u16,
+	pub(in crate::advapi) FieldTypeFlags: u16,
+	pub(in crate::advapi) Version: u32,
+	pub(in crate::advapi) ThreadId: u32,
+	pub(in crate::advapi) ProcessId: u32,
+	pub(in crate::advapi) TimeStamp: i64,
+	pub(in crate::advapi) Guid: GUID,
+	pub(in crate::advapi) ProcessorTime: u64,
+}
+
+/// [`EVENT_TRACE_LOGFILE`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/ns-evntrace-event_trace_logfilew)
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct EVENT_TRACE_LOGFILE {
+	pub(in crate::advapi) LogFileName: PSTR,
+	pub(in crate::advapi) LoggerName: PSTR,
+	pub(in crate::advapi) CurrentTime: i64,
+	pub(in crate::advapi) BuffersRead: u32,
+	pub(in crate::advapi) ProcessTraceMode: u32,
+	pub(in crate::advapi) CurrentEvent: EVENT_TRACE,
+	pub(in crate::advapi) LogfileHeader: TRACE_LOGFILE_HEADER,
+	pub(in crate::advapi) BufferCallback: PVOID,
+	pub(in crate::advapi) BufferSize: u32,
+	pub(in crate::advapi) Filled: u32,
+	pub(in crate::advapi) EventsLost: u32,
+	pub(in crate::advapi) EventRecordCallback: PVOID,
+	pub(in crate::advapi) IsKernelTrace: u32,
+	pub(in crate::advapi) Context: PVOID,
+}
+
+impl_default!(EVENT_TRACE_LOGFILE);
+
+/// [`EVENT_TRACE_PROPERTIES`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/ns-evntrace-event_trace_properties)
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct EVENT_TRACE_PROPERTIES {
+	pub(in crate::advapi) Wnode: WNODE_HEADER,
+	pub(in crate::advapi) BufferSize: u32,
+	pub(in crate::advapi) MinimumBuffers: u32,
+	pub(in crate::advapi) MaximumBuffers: u32,
+	pub(in crate::advapi) MaximumFileSize: u32,
+	pub(in crate::advapi) LogFileMode: u32,
+	pub(in crate::advapi) FlushTimer: u32,
+	pub(in crate::advapi) EnableFlags: u32,
+	pub(in crate::advapi) AgeLimit: i32,
+	pub(in crate::advapi) NumberOfBuffers: u32,
+	pub(in crate::advapi) FreeBuffers: u32,
+	pub(in crate::advapi) EventsLost: u32,
+	pub(in crate::advapi) BuffersWritten: u32,
+	pub(in crate::advapi) LogBuffersLost: u32,
+	pub(in crate::advapi) RealTimeBuffersLost: u32,
+	pub(in crate::advapi) LoggerThreadId: HANDLE,
+	pub(in crate::advapi) LogFileNameOffset: u32,
+	pub(in crate::advapi) LoggerNameOffset: u32,
+}
+
 /// [`LUID_AND_ATTRIBUTES`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-luid_and_attributes)
 /// struct.
 #[repr(C)]
@@ -110,6 +363,67 @@ impl LUID_AND_ATTRIBUTES {
 	}
 }
 
+newtype_num! { REGHANDLE: u64;
+	/// Registration handle of an event provider, returned by
+	/// [`EventRegister`](crate::EventRegister).
+	///
+	/// Unlike other handles, it's not a pointer.
+}
+
+impl REGHANDLE {
+	/// [`EventProviderEnabled`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/nf-evntprov-eventproviderenabled)
+	/// function.
+	///
+	/// Returns `true` if any session is listening to events of the given level
+	/// and keyword, so that building the event can be skipped otherwise.
+	#[must_use]
+	pub fn EventProviderEnabled(&self, level: co::TRACE_LEVEL, keyword: u64) -> bool {
+		unsafe { ffi::EventProviderEnabled(self.0, level.raw(), keyword) != 0 }
+	}
+
+	/// [`EventSetInformation`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/nf-evntprov-eventsetinformation)
+	/// function.
+	pub fn EventSetInformation(
+		&self,
+		information_class: co::EVENT_INFO_CLASS,
+		information: &[u8],
+	) -> SysResult<()> {
+		error_to_sysresult(unsafe {
+			ffi::EventSetInformation(
+				self.0,
+				information_class.raw(),
+				vec_ptr(information) as _,
+				information.len() as _,
+			)
+		} as _)
+	}
+
+	/// [`EventWriteTransfer`](https://learn.microsoft.com/en-us/windows/win32/api/evntprov/nf-evntprov-eventwritetransfer)
+	/// function.
+	///
+	/// For TraceLogging events, prefer
+	/// [`TraceLoggingProvider`](crate::TraceLoggingProvider), which builds
+	/// the descriptors.
+	pub fn EventWriteTransfer(
+		&self,
+		descriptor: &EVENT_DESCRIPTOR,
+		activity_id: Option<&GUID>,
+		related_activity_id: Option<&GUID>,
+		user_data: &[EVENT_DATA_DESCRIPTOR],
+	) -> SysResult<()> {
+		error_to_sysresult(unsafe {
+			ffi::EventWriteTransfer(
+				self.0,
+				descriptor as *const _ as _,
+				activity_id.map_or(std::ptr::null(), |g| g as *const _ as _),
+				related_activity_id.map_or(std::ptr::null(), |g| g as *const _ as _),
+				user_data.len() as _,
+				vec_ptr(user_data) as _,
+			)
+		} as _)
+	}
+}
+
 /// [`SERVICE_STATUS`](https://learn.microsoft.com/en-us/windows/win32/api/winsvc/ns-winsvc-service_status)
 /// struct.
 #[repr(C)]
@@ -556,6 +870,30 @@ pub struct TOKEN_USER<'a> {
 	pub User: SID_AND_ATTRIBUTES<'a>,
 }
 
+/// [`TRACE_LOGFILE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/ns-evntrace-trace_logfile_header)
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct TRACE_LOGFILE_HEADER {
+	pub(in crate::advapi) BufferSize: u32,
+	pub(in crate::advapi) Version: u32,
+	pub(in crate::advapi) ProviderVersion: u32,
+	pub(in crate::advapi) NumberOfProcessors: u32,
+	pub(in crate::advapi) EndTime: i64,
+	pub(in crate::advapi) TimerResolution: u32,
+	pub(in crate::advapi) MaximumFileSize: u32,
+	pub(in crate::advapi) LogFileMode: u32,
+	pub(in crate::advapi) BuffersWritten: u32,
+	pub(in crate::advapi) LogInstanceGuid: GUID,
+	pub(in crate::advapi) LoggerName: PSTR,
+	pub(in crate::advapi) LogFileName: PSTR,
+	pub(in crate::advapi) TimeZone: TIME_ZONE_INFORMATION,
+	pub(in crate::advapi) BootTime: i64,
+	pub(in crate::advapi) PerfFreq: i64,
+	pub(in crate::advapi) StartTime: i64,
+	pub(in crate::advapi) ReservedFlags: u32,
+	pub(in crate::advapi) BuffersLost: u32,
+}
+
 /// [`VALENT`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/ns-winreg-valentw)
 /// struct.
 #[repr(C)]
@@ -580,6 +918,19 @@ impl VALENT {
 	}
 }
 
+/// [`WNODE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/etw/wnode-header)
+/// struct.
+#[repr(C)]
+pub(in crate::advapi) struct WNODE_HEADER {
+	pub(in crate::advapi) BufferSize: u32,
+	pub(in crate::advapi) ProviderId: u32,
+	pub(in crate::advapi) HistoricalContext: u64,
+	pub(in crate::advapi) TimeStamp: i64,
+	pub(in crate::advapi) Guid: GUID,
+	pub(in crate::advapi) ClientContext: u32,
+	pub(in crate::advapi) Flags: u32,
+}
+
 /// [`WTSSESSION_NOTIFICATION`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-wtssession_notification)
 /// struct.
 #[repr(C)]
@@ -0,0 +1,7 @@
+mod trace_logging;
+mod trace_session;
+mod transaction;
+
+pub use trace_logging::{TraceLoggingEvent, TraceLoggingProvider};
+pub use trace_session::{TraceEvent, TraceSession};
+pub use transaction::Transaction;
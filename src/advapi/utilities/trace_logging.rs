@@ -0,0 +1,433 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+
+/// A manifest-free event in the
+/// [TraceLogging](https://learn.microsoft.com/en-us/windows/win32/tracelogging/trace-logging-portal)
+/// format, with a name and a list of typed fields, to be written by a
+/// [`TraceLoggingProvider`](crate::TraceLoggingProvider).
+///
+/// The metadata and payload encoders – and the decoder – are written in pure
+/// Rust and don't call any system function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let event = w::TraceLoggingEvent::new("FileOpened")
+///     .level(co::TRACE_LEVEL::INFORMATION)
+///     .field("Path", w::TraceValue::Str("C:\\Temp\\foo.txt".to_owned()))
+///     .field("Size", w::TraceValue::U64(1024));
+///
+/// let decoded = w::TraceLoggingEvent::from_bytes(
+///     &event.metadata()?, &event.payload())?;
+/// assert_eq!(decoded.fields(), event.fields());
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceLoggingEvent {
+	name: String,
+	descriptor: EVENT_DESCRIPTOR,
+	fields: Vec<(String, TraceValue)>,
+}
+
+impl TraceLoggingEvent {
+	/// Creates a new event with the given name, with
+	/// [`co::TRACE_LEVEL::VERBOSE`](crate::co::TRACE_LEVEL::VERBOSE) level and
+	/// no fields.
+	#[must_use]
+	pub fn new(name: &str) -> Self {
+		Self {
+			name: name.to_owned(),
+			descriptor: EVENT_DESCRIPTOR {
+				Channel: 11, // WINEVENT_CHANNEL_TRACELOGGING
+				Level: co::TRACE_LEVEL::VERBOSE,
+				..Default::default()
+			},
+			fields: Vec::new(),
+		}
+	}
+
+	/// Decodes an event from its TraceLogging metadata and payload, as
+	/// returned by [`metadata`](crate::TraceLoggingEvent::metadata) and
+	/// [`payload`](crate::TraceLoggingEvent::payload).
+	///
+	/// Fails with [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA)
+	/// if the data is malformed, or with
+	/// [`co::ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if a
+	/// field has a type which can't be represented by
+	/// [`TraceValue`](crate::TraceValue), like arrays and structs.
+	pub fn from_bytes(metadata: &[u8], payload: &[u8]) -> SysResult<Self> {
+		let size = u16::from_le_bytes(le_bytes(metadata, 0)?) as usize;
+		let meta = metadata.get(..size).ok_or(co::ERROR::INVALID_DATA)?;
+
+		let mut off = skip_tags(meta, 2)?;
+		let name = read_utf8z(meta, &mut off)?;
+
+		let mut fields = Vec::new();
+		let mut payload_off = 0;
+		while off < meta.len() {
+			let field_name = read_utf8z(meta, &mut off)?;
+			let in_type = *meta.get(off).ok_or(co::ERROR::INVALID_DATA)?;
+			off += 1;
+			if in_type & 0x80 != 0 {
+				// out-type follows
+				let out_type = *meta.get(off).ok_or(co::ERROR::INVALID_DATA)?;
+				off += 1;
+				if out_type & 0x80 != 0 {
+					off = skip_tags(meta, off)?;
+				}
+			}
+			if in_type & 0x60 != 0 {
+				return Err(co::ERROR::NOT_SUPPORTED); // arrays and custom types
+			}
+
+			let val = TraceValue::read_from(in_type & 0x1f, payload, &mut payload_off)?;
+			fields.push((field_name, val));
+		}
+
+		let mut event = Self::new(&name);
+		event.fields = fields;
+		Ok(event)
+	}
+
+	/// Returns the
+	/// [`EVENT_DESCRIPTOR`](crate::EVENT_DESCRIPTOR) to be written, with the
+	/// level, keyword and opcode of the event.
+	#[must_use]
+	pub const fn descriptor(&self) -> &EVENT_DESCRIPTOR {
+		&self.descriptor
+	}
+
+	/// Appends a field to the event.
+	#[must_use]
+	pub fn field(mut self, name: &str, value: TraceValue) -> Self {
+		self.fields.push((name.to_owned(), value));
+		self
+	}
+
+	/// Returns the fields of the event.
+	#[must_use]
+	pub fn fields(&self) -> &[(String, TraceValue)] {
+		&self.fields
+	}
+
+	/// Sets the keyword of the event, which sessions use to filter events.
+	#[must_use]
+	pub const fn keyword(mut self, keyword: u64) -> Self {
+		self.descriptor.Keyword = keyword;
+		self
+	}
+
+	/// Sets the level of the event, which sessions use to filter events.
+	#[must_use]
+	pub const fn level(mut self, level: co::TRACE_LEVEL) -> Self {
+		self.descriptor.Level = level;
+		self
+	}
+
+	/// Encodes the TraceLogging event metadata: the name of the event, and the
+	/// name and type of each field.
+	///
+	/// Null characters in the names are dropped.
+	///
+	/// Fails with
+	/// [`co::ERROR::ARITHMETIC_OVERFLOW`](crate::co::ERROR::ARITHMETIC_OVERFLOW)
+	/// if the metadata exceeds 65,535 bytes, the limit of its size prefix.
+	pub fn metadata(&self) -> SysResult<Vec<u8>> {
+		let mut buf = vec![0, 0, 0]; // size, tags
+		write_utf8z(&mut buf, &self.name);
+		for (name, val) in self.fields.iter() {
+			write_utf8z(&mut buf, name);
+			buf.push(val.in_type());
+		}
+
+		write_size_prefix(&mut buf)?;
+		Ok(buf)
+	}
+
+	/// Returns the name of the event.
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Sets the opcode of the event, like `1` for the start of an activity, and
+	/// `2` for its end.
+	#[must_use]
+	pub const fn opcode(mut self, opcode: u8) -> Self {
+		self.descriptor.Opcode = opcode;
+		self
+	}
+
+	/// Encodes the payload of the event: the values of the fields.
+	#[must_use]
+	pub fn payload(&self) -> Vec<u8> {
+		let mut buf = Vec::new();
+		self.fields
+			.iter()
+			.for_each(|(_, val)| val.write_to(&mut buf));
+		buf
+	}
+
+	/// Replaces the descriptor fields which came with a received event.
+	pub(in crate::advapi) fn set_descriptor(&mut self, descriptor: &EVENT_DESCRIPTOR) {
+		self.descriptor = *descriptor;
+	}
+}
+
+fn read_utf8z(buf: &[u8], off: &mut usize) -> SysResult<String> {
+	let bytes = buf.get(*off..).unwrap_or_default();
+	let len = bytes
+		.iter()
+		.position(|b| *b == 0)
+		.ok_or(co::ERROR::INVALID_DATA)?;
+	*off += len + 1;
+	Ok(String::from_utf8_lossy(&bytes[..len]).into_owned())
+}
+
+fn skip_tags(buf: &[u8], mut off: usize) -> SysResult<usize> {
+	loop {
+		// Each byte with the high bit set is followed by another one.
+		let b = *buf.get(off).ok_or(co::ERROR::INVALID_DATA)?;
+		off += 1;
+		if b & 0x80 == 0 {
+			return Ok(off);
+		}
+	}
+}
+
+fn write_utf8z(buf: &mut Vec<u8>, s: &str) {
+	buf.extend(s.bytes().filter(|b| *b != 0));
+	buf.push(0);
+}
+
+/// Writes the size of the whole buffer into its first 2 bytes.
+fn write_size_prefix(buf: &mut [u8]) -> SysResult<()> {
+	let size = u16::try_from(buf.len()).map_err(|_| co::ERROR::ARITHMETIC_OVERFLOW)?;
+	buf[..2].copy_from_slice(&size.to_le_bytes());
+	Ok(())
+}
+
+/// A manifest-free event provider, which writes
+/// [`TraceLoggingEvent`](crate::TraceLoggingEvent) objects with
+/// [`REGHANDLE::EventWriteTransfer`](crate::REGHANDLE::EventWriteTransfer).
+///
+/// The provider is unregistered when the object goes out of scope.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let provider = w::TraceLoggingProvider::new(
+///     "MyCompany.MyApp",
+///     &w::GUID::new("3970f9cf-2c0c-4f11-b1cc-e3a1e9958833"),
+/// )?;
+///
+/// provider.write(
+///     &w::TraceLoggingEvent::new("Started")
+///         .level(co::TRACE_LEVEL::INFORMATION)
+///         .field("Pid", w::TraceValue::U32(w::GetCurrentProcessId())),
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+pub struct TraceLoggingProvider {
+	reghandle: EventUnregisterGuard,
+	metadata: Vec<u8>,
+}
+
+impl TraceLoggingProvider {
+	/// Registers the provider with
+	/// [`EventRegister`](crate::EventRegister), and sets its name as a trait
+	/// with
+	/// [`REGHANDLE::EventSetInformation`](crate::REGHANDLE::EventSetInformation).
+	///
+	/// Fails with
+	/// [`co::ERROR::ARITHMETIC_OVERFLOW`](crate::co::ERROR::ARITHMETIC_OVERFLOW)
+	/// if the name is too long for the provider traits.
+	pub fn new(name: &str, provider_id: &GUID) -> SysResult<Self> {
+		let mut metadata = vec![0, 0]; // size
+		write_utf8z(&mut metadata, name);
+		write_size_prefix(&mut metadata)?;
+
+		let reghandle = EventRegister(provider_id)?;
+		let _ = reghandle.EventSetInformation(co::EVENT_INFO_CLASS::ProviderSetTraits, &metadata); // not supported before Windows 10
+
+		Ok(Self { reghandle, metadata })
+	}
+
+	/// Returns `true` if any session is listening to events of the given level
+	/// and keyword.
+	#[must_use]
+	pub fn is_enabled(&self, level: co::TRACE_LEVEL, keyword: u64) -> bool {
+		self.reghandle.EventProviderEnabled(level, keyword)
+	}
+
+	/// Returns the encoded provider traits, with the name of the provider.
+	#[must_use]
+	pub fn metadata(&self) -> &[u8] {
+		&self.metadata
+	}
+
+	/// Returns the underlying registration handle.
+	#[must_use]
+	pub fn reghandle(&self) -> &REGHANDLE {
+		&self.reghandle
+	}
+
+	/// Writes the event, if any session is listening to it.
+	pub fn write(&self, event: &TraceLoggingEvent) -> SysResult<()> {
+		self.write_transfer(event, None, None)
+	}
+
+	/// Writes the event, if any session is listening to it, with the given
+	/// activity IDs.
+	pub fn write_transfer(
+		&self,
+		event: &TraceLoggingEvent,
+		activity_id: Option<&GUID>,
+		related_activity_id: Option<&GUID>,
+	) -> SysResult<()> {
+		let descr = event.descriptor();
+		if !self.is_enabled(descr.Level, descr.Keyword) {
+			return Ok(());
+		}
+
+		let event_metadata = event.metadata()?;
+		let payload = event.payload();
+
+		let mut data = [
+			EVENT_DATA_DESCRIPTOR::default(),
+			EVENT_DATA_DESCRIPTOR::default(),
+			EVENT_DATA_DESCRIPTOR::default(),
+		];
+		data[0].Type = co::EVENT_DATA_DESCRIPTOR_TYPE::PROVIDER_METADATA;
+		data[0].set_data(&self.metadata);
+		data[1].Type = co::EVENT_DATA_DESCRIPTOR_TYPE::EVENT_METADATA;
+		data[1].set_data(&event_metadata);
+		data[2].set_data(&payload);
+
+		self.reghandle.EventWriteTransfer(
+			descr,
+			activity_id,
+			related_activity_id,
+			&data[..if payload.is_empty() { 2 } else { 3 }],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn round_trip(event: &TraceLoggingEvent) -> TraceLoggingEvent {
+		TraceLoggingEvent::from_bytes(&event.metadata().unwrap(), &event.payload()).unwrap()
+	}
+
+	#[test]
+	fn metadata_encoding() {
+		let event = TraceLoggingEvent::new("Ev")
+			.field("A", TraceValue::U32(5))
+			.field("S", TraceValue::Str("hi".to_owned()));
+		assert_eq!(event.metadata().unwrap(), [12, 0, 0, b'E', b'v', 0, b'A', 0, 8, b'S', 0, 1],);
+
+		let event = TraceLoggingEvent::new("E\0v").field("\0", TraceValue::U8(1));
+		assert_eq!(event.metadata().unwrap(), [8, 0, 0, b'E', b'v', 0, 0, 4]); // nulls dropped
+	}
+
+	#[test]
+	fn payload_encoding() {
+		let event = TraceLoggingEvent::new("Ev")
+			.field("a", TraceValue::I8(-1))
+			.field("b", TraceValue::U16(0x1234))
+			.field("c", TraceValue::Bool(true))
+			.field("d", TraceValue::Str("hé".to_owned()))
+			.field("e", TraceValue::Binary(vec![1, 2, 3]))
+			.field("f", TraceValue::FileTime(FILETIME { dwLowDateTime: 1, dwHighDateTime: 2 }));
+		assert_eq!(
+			event.payload(),
+			[
+				0xff, // i8
+				0x34, 0x12, // u16
+				1, 0, 0, 0, // BOOL
+				b'h', 0, 0xe9, 0, 0, 0, // UTF-16, null-terminated
+				3, 0, 1, 2, 3, // size-prefixed
+				1, 0, 0, 0, 2, 0, 0, 0, // FILETIME
+			],
+		);
+	}
+
+	#[test]
+	fn all_values_round_trip() {
+		let event = TraceLoggingEvent::new("Everything")
+			.field("i8", TraceValue::I8(i8::MIN))
+			.field("u8", TraceValue::U8(u8::MAX))
+			.field("i16", TraceValue::I16(-300))
+			.field("u16", TraceValue::U16(60_000))
+			.field("i32", TraceValue::I32(-70_000))
+			.field("u32", TraceValue::U32(u32::MAX))
+			.field("i64", TraceValue::I64(i64::MIN))
+			.field("u64", TraceValue::U64(u64::MAX))
+			.field("f32", TraceValue::F32(1.5))
+			.field("f64", TraceValue::F64(-0.25))
+			.field("bool", TraceValue::Bool(false))
+			.field("str", TraceValue::Str("Olá, 世界".to_owned()))
+			.field("empty", TraceValue::Str(String::new()))
+			.field("bin", TraceValue::Binary(vec![0, 1, 2, 0]))
+			.field("guid", TraceValue::Guid(GUID::new("3970f9cf-2c0c-4f11-b1cc-e3a1e9958833")))
+			.field("ft", TraceValue::FileTime(FILETIME { dwLowDateTime: 7, dwHighDateTime: 9 }));
+
+		let decoded = round_trip(&event);
+		assert_eq!(decoded.name(), "Everything");
+		assert_eq!(decoded.fields(), event.fields());
+		assert!(round_trip(&TraceLoggingEvent::new("")).fields().is_empty());
+	}
+
+	#[test]
+	fn binary_truncated() {
+		let event = TraceLoggingEvent::new("Ev").field("b", TraceValue::Binary(vec![7; 70_000]));
+		let decoded = round_trip(&event);
+		assert_eq!(decoded.fields()[0].1, TraceValue::Binary(vec![7; u16::MAX as _]));
+	}
+
+	#[test]
+	fn metadata_size_limit() {
+		let max_name = "n".repeat(u16::MAX as usize - 4); // size, tags and null
+		let event = TraceLoggingEvent::new(&max_name);
+		assert_eq!(event.metadata().unwrap().len(), u16::MAX as usize);
+		assert_eq!(event.metadata().unwrap()[..2], [0xff, 0xff]);
+
+		let event = TraceLoggingEvent::new(&(max_name.clone() + "n"));
+		assert_eq!(event.metadata(), Err(co::ERROR::ARITHMETIC_OVERFLOW));
+
+		let event = TraceLoggingEvent::new(&max_name[3..]).field("a", TraceValue::U8(0));
+		assert_eq!(event.metadata().unwrap().len(), u16::MAX as usize);
+		let event = event.field("", TraceValue::U8(0));
+		assert_eq!(event.metadata(), Err(co::ERROR::ARITHMETIC_OVERFLOW));
+
+		let mut buf = vec![0; u16::MAX as usize + 1];
+		assert_eq!(write_size_prefix(&mut buf), Err(co::ERROR::ARITHMETIC_OVERFLOW));
+		assert_eq!(write_size_prefix(&mut buf[..300]), Ok(()));
+		assert_eq!(buf[..2], [44, 1]);
+	}
+
+	#[test]
+	fn malformed_input() {
+		let event = TraceLoggingEvent::new("Ev").field("A", TraceValue::U32(5));
+		let meta = event.metadata().unwrap();
+		let payload = event.payload();
+
+		let err = |meta: &[u8], payload: &[u8]| TraceLoggingEvent::from_bytes(meta, payload).err();
+		assert_eq!(err(&meta[..meta.len() - 1], &payload), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(err(&meta[..1], &payload), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(err(&meta, &payload[..3]), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(err(&[4, 0, 0, b'E'], &[]), Some(co::ERROR::INVALID_DATA)); // no null
+
+		let mut array = meta.clone();
+		*array.last_mut().unwrap() |= 0x20; // array of u32
+		assert_eq!(err(&array, &payload), Some(co::ERROR::NOT_SUPPORTED));
+	}
+}
@@ -0,0 +1,317 @@
+use crate::advapi::{ffi, proc, structs::*};
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+
+const EVENT_TRACE_CONTROL_STOP: u32 = 1;
+const EVENT_TRACE_REAL_TIME_MODE: u32 = 0x0000_0100;
+const PROCESS_TRACE_MODE_EVENT_RECORD: u32 = 0x1000_0000;
+const PROCESS_TRACE_MODE_REAL_TIME: u32 = 0x0000_0100;
+const WNODE_FLAG_TRACED_GUID: u32 = 0x0002_0000;
+
+/// `EVENT_TRACE_PROPERTIES` followed by the buffer for the session name.
+#[repr(C)]
+struct TraceProps {
+	props: EVENT_TRACE_PROPERTIES,
+	name: [u16; 1024],
+}
+
+impl TraceProps {
+	fn new() -> Box<Self> {
+		let mut p = Box::new(unsafe { std::mem::zeroed::<Self>() });
+		p.props.Wnode.BufferSize = std::mem::size_of::<Self>() as _;
+		p.props.Wnode.Flags = WNODE_FLAG_TRACED_GUID;
+		p.props.Wnode.ClientContext = 1; // QueryPerformanceCounter resolution
+		p.props.LogFileMode = EVENT_TRACE_REAL_TIME_MODE;
+		p.props.LoggerNameOffset = std::mem::size_of::<EVENT_TRACE_PROPERTIES>() as _;
+		p
+	}
+}
+
+/// A real-time event tracing session, started with
+/// [`StartTrace`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/nf-evntrace-starttracew),
+/// which collects events from the enabled providers and delivers them to
+/// [`process`](crate::TraceSession::process).
+///
+/// The session is stopped with
+/// [`ControlTrace`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/nf-evntrace-controltracew)
+/// when the object goes out of scope. Controlling sessions usually requires
+/// administrator rights, or membership in the Performance Log Users group.
+///
+/// # Examples
+///
+/// Printing the TraceLogging events of a provider:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let session = w::TraceSession::new("MyAppTraceSession")?;
+/// session.enable_provider(
+///     &w::GUID::new("3970f9cf-2c0c-4f11-b1cc-e3a1e9958833"),
+///     co::TRACE_LEVEL::VERBOSE,
+///     u64::MAX,
+/// )?;
+///
+/// session.process(|event| {
+///     if let Ok(Some(tl)) = event.trace_logging() {
+///         println!("{} {:?}", tl.name(), tl.fields());
+///     }
+///     true // keep processing
+/// })?;
+/// # w::SysResult::Ok(())
+/// ```
+pub struct TraceSession {
+	name: WString,
+	htrace: u64,
+}
+
+impl Drop for TraceSession {
+	fn drop(&mut self) {
+		let _ = Self::stop(&self.name); // ignore errors
+	}
+}
+
+impl TraceSession {
+	/// Starts a new real-time session with the given name.
+	///
+	/// If a session with the same name already exists – for example, one left
+	/// behind by a process which crashed – it is stopped and replaced.
+	pub fn new(session_name: &str) -> SysResult<Self> {
+		let name = WString::from_str(session_name);
+		let mut htrace = 0u64;
+
+		for retry in [true, false] {
+			let mut props = TraceProps::new();
+			match error_to_sysresult(unsafe {
+				ffi::StartTraceW(&mut htrace, name.as_ptr(), &mut props.props as *mut _ as _)
+			} as _)
+			{
+				Ok(_) => return Ok(Self { name, htrace }),
+				Err(co::ERROR::ALREADY_EXISTS) if retry => Self::stop(&name)?,
+				Err(e) => return Err(e),
+			}
+		}
+		unreachable!()
+	}
+
+	/// Enables a provider in the session with
+	/// [`EnableTraceEx2`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/nf-evntrace-enabletraceex2),
+	/// collecting its events up to the given level which match any bit of the
+	/// given keyword.
+	pub fn enable_provider(
+		&self,
+		provider_id: &GUID,
+		level: co::TRACE_LEVEL,
+		match_any_keyword: u64,
+	) -> SysResult<()> {
+		error_to_sysresult(unsafe {
+			ffi::EnableTraceEx2(
+				self.htrace,
+				provider_id as *const _ as _,
+				1, // EVENT_CONTROL_CODE_ENABLE_PROVIDER
+				level.raw(),
+				match_any_keyword,
+				0,
+				0,
+				std::ptr::null(),
+			)
+		} as _)
+	}
+
+	/// Opens the session for consumption with
+	/// [`OpenTrace`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/nf-evntrace-opentracew),
+	/// then blocks the current thread with
+	/// [`ProcessTrace`](https://learn.microsoft.com/en-us/windows/win32/api/evntrace/nf-evntrace-processtrace),
+	/// calling the closure for each received event.
+	///
+	/// Processing stops when the closure returns `false`, or when the session
+	/// is stopped.
+	pub fn process<F>(&self, func: F) -> SysResult<()>
+	where
+		F: FnMut(&TraceEvent) -> bool,
+	{
+		let mut ctx = proc::TraceProcessContext { func, htrace: 0, closed: false };
+
+		let mut logfile = EVENT_TRACE_LOGFILE {
+			LoggerName: self.name.as_ptr() as _,
+			ProcessTraceMode: PROCESS_TRACE_MODE_REAL_TIME | PROCESS_TRACE_MODE_EVENT_RECORD,
+			EventRecordCallback: proc::trace_session_process::<F> as _,
+			Context: &mut ctx as *mut _ as _,
+			..Default::default()
+		};
+
+		let htrace = unsafe { ffi::OpenTraceW(&mut logfile as *mut _ as _) };
+		if htrace == usize::MAX as u64 {
+			// INVALID_PROCESSTRACE_HANDLE
+			return Err(GetLastError());
+		}
+		ctx.htrace = htrace;
+
+		let res = error_to_sysresult(unsafe {
+			ffi::ProcessTrace(&htrace, 1, std::ptr::null(), std::ptr::null())
+		} as _);
+		if !ctx.closed {
+			unsafe {
+				ffi::CloseTrace(htrace); // ignore errors
+			}
+		}
+
+		match res {
+			Err(co::ERROR::CANCELLED) => Ok(()), // closed by the callback
+			res => res,
+		}
+	}
+
+	fn stop(name: &WString) -> SysResult<()> {
+		let mut props = TraceProps::new();
+		error_to_sysresult(unsafe {
+			ffi::ControlTraceW(
+				0,
+				name.as_ptr(),
+				&mut props.props as *mut _ as _,
+				EVENT_TRACE_CONTROL_STOP,
+			)
+		} as _)
+	}
+}
+
+/// An event received by
+/// [`TraceSession::process`](crate::TraceSession::process).
+pub struct TraceEvent {
+	provider_id: GUID,
+	provider_name: Option<String>,
+	descriptor: EVENT_DESCRIPTOR,
+	process_id: u32,
+	thread_id: u32,
+	time_stamp: FILETIME,
+	activity_id: GUID,
+	event_metadata: Option<Vec<u8>>,
+	user_data: Vec<u8>,
+}
+
+impl TraceEvent {
+	/// Copies the data of the raw record.
+	///
+	/// # Safety
+	///
+	/// The record must have been received by the event record callback.
+	#[must_use]
+	pub(in crate::advapi) unsafe fn from_record(record: &EVENT_RECORD) -> Self {
+		let hdr = &record.EventHeader;
+		let ext_items = if record.ExtendedData.is_null() {
+			&[]
+		} else {
+			std::slice::from_raw_parts(record.ExtendedData, record.ExtendedDataCount as _)
+		};
+
+		let mut event_metadata = None;
+		let mut provider_name = None;
+		for item in ext_items.iter() {
+			let data =
+				std::slice::from_raw_parts(item.DataPtr as usize as *const u8, item.DataSize as _);
+			match item.ExtType {
+				0x000b => event_metadata = Some(data.to_vec()), // EVENT_HEADER_EXT_TYPE_EVENT_SCHEMA_TL
+				0x000c => {
+					// EVENT_HEADER_EXT_TYPE_PROV_TRAITS: size, then the null-terminated name
+					provider_name = data.get(2..).and_then(|name| {
+						name.iter()
+							.position(|b| *b == 0)
+							.map(|len| String::from_utf8_lossy(&name[..len]).into_owned())
+					});
+				},
+				_ => {},
+			}
+		}
+
+		Self {
+			provider_id: hdr.ProviderId,
+			provider_name,
+			descriptor: hdr.EventDescriptor,
+			process_id: hdr.ProcessId,
+			thread_id: hdr.ThreadId,
+			time_stamp: FILETIME {
+				dwLowDateTime: hdr.TimeStamp as u32,
+				dwHighDateTime: (hdr.TimeStamp >> 32) as u32,
+			},
+			activity_id: hdr.ActivityId,
+			event_metadata,
+			user_data: if record.UserData.is_null() {
+				Vec::new()
+			} else {
+				std::slice::from_raw_parts(record.UserData as *const u8, record.UserDataLength as _)
+					.to_vec()
+			},
+		}
+	}
+
+	/// Returns the activity ID of the event.
+	#[must_use]
+	pub const fn activity_id(&self) -> &GUID {
+		&self.activity_id
+	}
+
+	/// Returns the descriptor of the event.
+	#[must_use]
+	pub const fn descriptor(&self) -> &EVENT_DESCRIPTOR {
+		&self.descriptor
+	}
+
+	/// Returns the TraceLogging event metadata, if the event was written by a
+	/// TraceLogging provider.
+	#[must_use]
+	pub fn event_metadata(&self) -> Option<&[u8]> {
+		self.event_metadata.as_deref()
+	}
+
+	/// Returns the ID of the process which wrote the event.
+	#[must_use]
+	pub const fn process_id(&self) -> u32 {
+		self.process_id
+	}
+
+	/// Returns the ID of the provider which wrote the event.
+	#[must_use]
+	pub const fn provider_id(&self) -> &GUID {
+		&self.provider_id
+	}
+
+	/// Returns the name of the provider, if the event was written by a
+	/// TraceLogging provider.
+	#[must_use]
+	pub fn provider_name(&self) -> Option<&str> {
+		self.provider_name.as_deref()
+	}
+
+	/// Returns the ID of the thread which wrote the event.
+	#[must_use]
+	pub const fn thread_id(&self) -> u32 {
+		self.thread_id
+	}
+
+	/// Returns the time the event was written.
+	#[must_use]
+	pub const fn time_stamp(&self) -> FILETIME {
+		self.time_stamp
+	}
+
+	/// Decodes the event with
+	/// [`TraceLoggingEvent::from_bytes`](crate::TraceLoggingEvent::from_bytes),
+	/// returning `None` if the event was not written by a TraceLogging
+	/// provider.
+	pub fn trace_logging(&self) -> SysResult<Option<TraceLoggingEvent>> {
+		self.event_metadata
+			.as_ref()
+			.map(|meta| {
+				let mut event = TraceLoggingEvent::from_bytes(meta, &self.user_data)?;
+				event.set_descriptor(&self.descriptor);
+				Ok(event)
+			})
+			.transpose()
+	}
+
+	/// Returns the raw payload of the event.
+	#[must_use]
+	pub fn user_data(&self) -> &[u8] {
+		&self.user_data
+	}
+}
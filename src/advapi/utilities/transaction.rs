@@ -0,0 +1,105 @@
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Manages an [`HTRANSACTION`](crate::HTRANSACTION) handle, which is rolled
+/// back automatically when the object goes out of scope, unless
+/// [`commit`](crate::Transaction::commit) was called.
+///
+/// The handle can be passed to the transacted file functions, like
+/// [`CopyFileTransacted`](crate::CopyFileTransacted) and
+/// [`HFILE::CreateFileTransacted`](crate::prelude::advapi_Hfile::CreateFileTransacted),
+/// and to the transacted registry methods, like
+/// [`HKEY::RegCreateKeyTransacted`](crate::prelude::advapi_Hkey::RegCreateKeyTransacted),
+/// so that all the changes are applied atomically.
+///
+/// # Examples
+///
+/// Updating a file and a registry value atomically:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let tx = w::Transaction::new("Install update")?;
+///
+/// w::CopyFileTransacted(
+///     "C:\\Temp\\new\\app.dll",
+///     "C:\\Temp\\app\\app.dll",
+///     None,
+///     tx.htransaction(),
+/// )?;
+///
+/// let (hkey, _) = w::HKEY::CURRENT_USER.RegCreateKeyTransacted(
+///     "Software\\MyApp",
+///     None,
+///     co::REG_OPTION::default(),
+///     co::KEY::WRITE,
+///     None,
+///     tx.htransaction(),
+/// )?;
+/// hkey.RegSetValueEx(Some("Version"), w::RegistryValue::Dword(2))?;
+/// drop(hkey);
+///
+/// tx.commit()?; // if not called, everything is rolled back
+/// # w::SysResult::Ok(())
+/// ```
+pub struct Transaction {
+	htransaction: CloseHandleGuard<HTRANSACTION>,
+	finished: bool,
+}
+
+impl Drop for Transaction {
+	fn drop(&mut self) {
+		if !self.finished {
+			let _ = self.htransaction.RollbackTransaction(); // ignore errors
+		}
+	}
+}
+
+impl Transaction {
+	/// Creates a new transaction with
+	/// [`HTRANSACTION::CreateTransaction`](crate::prelude::advapi_Htransaction::CreateTransaction),
+	/// with no timeout.
+	#[must_use]
+	pub fn new(description: &str) -> SysResult<Self> {
+		Self::new_with_timeout(description, None)
+	}
+
+	/// Creates a new transaction with
+	/// [`HTRANSACTION::CreateTransaction`](crate::prelude::advapi_Htransaction::CreateTransaction),
+	/// which is rolled back by the system if not committed within the given
+	/// number of milliseconds.
+	#[must_use]
+	pub fn new_with_timeout(description: &str, timeout_ms: Option<u32>) -> SysResult<Self> {
+		Ok(Self {
+			htransaction: HTRANSACTION::CreateTransaction(None, None, timeout_ms, description)?,
+			finished: false,
+		})
+	}
+
+	/// Commits the transaction with
+	/// [`HTRANSACTION::CommitTransaction`](crate::prelude::advapi_Htransaction::CommitTransaction).
+	///
+	/// If the commit fails, the transaction is rolled back when the object is
+	/// dropped.
+	pub fn commit(mut self) -> SysResult<()> {
+		self.htransaction.CommitTransaction()?;
+		self.finished = true;
+		Ok(())
+	}
+
+	/// Returns the underlying handle.
+	#[must_use]
+	pub fn htransaction(&self) -> &HTRANSACTION {
+		&self.htransaction
+	}
+
+	/// Explicitly rolls back the transaction with
+	/// [`HTRANSACTION::RollbackTransaction`](crate::prelude::advapi_Htransaction::RollbackTransaction),
+	/// instead of waiting for the object to be dropped, so the error can be
+	/// checked.
+	pub fn rollback(mut self) -> SysResult<()> {
+		self.finished = true;
+		self.htransaction.RollbackTransaction()
+	}
+}
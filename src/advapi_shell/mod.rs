@@ -3,6 +3,7 @@
 mod ffi;
 mod funcs;
 mod structs;
+mod utilities;
 
 pub mod co;
 pub(crate) mod privs;
@@ -10,4 +11,5 @@ pub(crate) mod privs;
 pub mod decl {
 	pub use super::funcs::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
@@ -0,0 +1,81 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Starts a new instance of the current executable with administrator rights,
+/// passing the same command line arguments, by calling
+/// [`ShellExecuteEx`](crate::ShellExecuteEx) with the `runas` verb.
+///
+/// The UAC consent dialog is shown; if the user refuses it, fails with
+/// [`co::ERROR::CANCELLED`](crate::co::ERROR::CANCELLED). The current process
+/// is not terminated, this is up to the caller.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let htoken = w::HPROCESS::GetCurrentProcess()
+///     .OpenProcessToken(co::TOKEN::QUERY)?;
+///
+/// if !htoken.is_elevated()? {
+///     match w::relaunch_elevated(None) {
+///         Ok(_) => std::process::exit(0),
+///         Err(co::ERROR::CANCELLED) => println!("User refused elevation."),
+///         Err(e) => return Err(e),
+///     }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub fn relaunch_elevated(hwnd: Option<&HWND>) -> SysResult<()> {
+	let exe = HINSTANCE::NULL.GetModuleFileName()?;
+	let args = std::env::args_os()
+		.skip(1)
+		.map(|arg| quote_arg(&arg.to_string_lossy()))
+		.collect::<Vec<_>>()
+		.join(" ");
+	let cur_dir = std::env::current_dir()
+		.ok()
+		.map(|dir| dir.to_string_lossy().into_owned());
+
+	ShellExecuteEx(&SHELLEXECUTEINFO {
+		hwnd,
+		verb: Some("runas"),
+		file: &exe,
+		parameters: if args.is_empty() { None } else { Some(&args) },
+		directory: cur_dir.as_deref(),
+		show: co::SW::SHOWNORMAL,
+		..Default::default()
+	})
+}
+
+/// Quotes a command line argument so it's parsed back verbatim by
+/// [`CommandLineToArgvW`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw).
+fn quote_arg(arg: &str) -> String {
+	if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '"']) {
+		return arg.to_owned();
+	}
+
+	let mut quoted = String::with_capacity(arg.len() + 2);
+	quoted.push('"');
+	let mut backslashes = 0;
+	for ch in arg.chars() {
+		match ch {
+			'\\' => backslashes += 1,
+			'"' => {
+				quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+				backslashes = 0;
+			},
+			_ => {
+				quoted.push_str(&"\\".repeat(backslashes));
+				backslashes = 0;
+			},
+		}
+		if ch != '\\' {
+			quoted.push(ch);
+		}
+	}
+	quoted.push_str(&"\\".repeat(backslashes * 2));
+	quoted.push('"');
+	quoted
+}
@@ -3,6 +3,45 @@
 use crate::co::*;
 use crate::comctl::privs::*;
 
+const_wm! { ACM;
+	/// Animation control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-messages)
+	/// (`u32`).
+	=>
+	OPEN WM::USER.raw() + 103
+	PLAY WM::USER.raw() + 101
+	STOP WM::USER.raw() + 102
+	ISPLAYING WM::USER.raw() + 104
+}
+
+const_cmd! { ACN;
+	/// Animation control `WM_COMMAND`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-notifications)
+	/// (`u16`).
+	=>
+	START 1
+	STOP 2
+}
+
+const_ws! { ACS: u32;
+	/// Animation control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/animation-control-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	/// Centers the animation in the animation control's window.
+	CENTER 0x0001
+	/// Allows you to match an animation's background color to that of the
+	/// underlying window, creating a "transparent" background.
+	TRANSPARENT 0x0002
+	/// Starts playing the animation as soon as the AVI clip is opened.
+	AUTOPLAY 0x0004
+	/// Plays the AVI clip without creating a thread; the control uses a timer
+	/// to synchronize playback.
+	TIMER 0x0008
+}
+
 const_ordinary! { ADRF: u32;
 	/// [`NMTVASYNCDRAW`](crate::NMTVASYNCDRAW) `dwRetFlags` (`u32`).
 	///
@@ -132,6 +171,18 @@ const_values_pub! { CB;
 	GETCUEBANNER CB_FIRST + 4
 }
 
+const_bitflag! { CBEIF: u32;
+	/// [`COMBOBOXEXITEM`](crate::COMBOBOXEXITEM) `mask` (`u32`).
+	=>
+	TEXT 0x0000_0001
+	IMAGE 0x0000_0002
+	SELECTEDIMAGE 0x0000_0004
+	OVERLAY 0x0000_0008
+	INDENT 0x0000_0010
+	LPARAM 0x0000_0020
+	DI_SETITEM 0x1000_0000
+}
+
 const_wm! { CBEM;
 	/// ComboBoxEx control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-comboboxex-control-reference-messages)
@@ -152,6 +203,28 @@ const_wm! { CBEM;
 	GETITEM WM::USER.raw() + 13
 }
 
+const_nm! { CBEN;
+	/// ComboBoxEx control `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-comboboxex-control-reference-notifications)
+	/// (`i32`).
+	=>
+	INSERTITEM CBEN_FIRST - 1
+	DELETEITEM CBEN_FIRST - 2
+	BEGINEDIT CBEN_FIRST - 4
+	ENDEDIT CBEN_FIRST - 6
+	GETDISPINFO CBEN_FIRST - 7
+	DRAGBEGIN CBEN_FIRST - 9
+}
+
+const_ordinary! { CBENF: i32;
+	/// [`NMCBEENDEDIT`](crate::NMCBEENDEDIT) `iWhy` (`i32`).
+	=>
+	KILLFOCUS 1
+	RETURN 2
+	ESCAPE 3
+	DROPDOWN 4
+}
+
 const_wsex! { CBES_EX;
 	/// Extended combo box
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/comboboxex-control-extended-styles)
@@ -185,6 +258,26 @@ const_wm! { CCM;
 	DPISCALE CCM_FIRST + 0xc
 }
 
+const_ws! { CCS: u32;
+	/// Common control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/common-control-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOP 0x0000_0001
+	NOMOVEY 0x0000_0002
+	BOTTOM 0x0000_0003
+	NORESIZE 0x0000_0004
+	NOPARENTALIGN 0x0000_0008
+	ADJUSTABLE 0x0000_0020
+	NODIVIDER 0x0000_0040
+	VERT 0x0000_0080
+	LEFT Self::VERT.0 | Self::TOP.0
+	RIGHT Self::VERT.0 | Self::BOTTOM.0
+	NOMOVEX Self::VERT.0 | Self::NOMOVEY.0
+}
+
 const_bitflag! { CDDS: u32;
 	/// [`NMCUSTOMDRAW`](crate::NMCUSTOMDRAW) `dwDrawStage` (`u32`).
 	=>
@@ -341,7 +434,8 @@ const_ordinary! { GMR: u32;
 }
 
 const_bitflag! { HICF: u32;
-	/// [NMBCHOTITEM](crate::NMBCHOTITEM) `dwFlags` (`u32`).
+	/// [NMBCHOTITEM](crate::NMBCHOTITEM) and
+	/// [`NMTBHOTITEM`](crate::NMTBHOTITEM) `dwFlags` (`u32`).
 	=>
 	OTHER 0x0000_0000
 	ARROWKEYS 0x0000_0002
@@ -520,6 +614,37 @@ const_bitflag! { HHT: u32;
 	ONOVERFLOW 0x4000
 }
 
+const_bitflag! { HKCOMB: u16;
+	/// [`hkm::SetRules`](crate::msg::hkm::SetRules) `invalid_combos` (`u16`).
+	=>
+	/// Unmodified keys.
+	NONE 0x0001
+	/// Shift.
+	S 0x0002
+	/// Ctrl.
+	C 0x0004
+	/// Alt.
+	A 0x0008
+	/// Shift+Ctrl.
+	SC 0x0010
+	/// Shift+Alt.
+	SA 0x0020
+	/// Ctrl+Alt.
+	CA 0x0040
+	/// Shift+Ctrl+Alt.
+	SCA 0x0080
+}
+
+const_wm! { HKM;
+	/// Hot key control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-hot-key-control-reference-messages)
+	/// (`u32`).
+	=>
+	SETHOTKEY WM::USER.raw() + 1
+	GETHOTKEY WM::USER.raw() + 2
+	SETRULES WM::USER.raw() + 3
+}
+
 const_bitflag! { ICC: u32;
 	/// [`INITCOMMONCONTROLSEX`](crate::INITCOMMONCONTROLSEX) `icc` (`u32`).
 	=>
@@ -1604,6 +1729,44 @@ const_wm! { RB;
 	SETBANDWIDTH WM::USER.raw() + 44
 }
 
+const_bitflag! { RBBIM: u32;
+	/// [`REBARBANDINFO`](crate::REBARBANDINFO) `fMask` (`u32`).
+	=>
+	STYLE 0x0000_0001
+	COLORS 0x0000_0002
+	TEXT 0x0000_0004
+	IMAGE 0x0000_0008
+	CHILD 0x0000_0010
+	CHILDSIZE 0x0000_0020
+	SIZE 0x0000_0040
+	BACKGROUND 0x0000_0080
+	ID 0x0000_0100
+	IDEALSIZE 0x0000_0200
+	LPARAM 0x0000_0400
+	HEADERSIZE 0x0000_0800
+	CHEVRONLOCATION 0x0000_1000
+	CHEVRONSTATE 0x0000_2000
+}
+
+const_bitflag! { RBBS: u32;
+	/// [`REBARBANDINFO`](crate::REBARBANDINFO) `fStyle` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	BREAK 0x0000_0001
+	FIXEDSIZE 0x0000_0002
+	CHILDEDGE 0x0000_0004
+	HIDDEN 0x0000_0008
+	NOVERT 0x0000_0010
+	FIXEDBMP 0x0000_0020
+	VARIABLEHEIGHT 0x0000_0040
+	GRIPPERALWAYS 0x0000_0080
+	NOGRIPPER 0x0000_0100
+	USECHEVRON 0x0000_0200
+	HIDETITLE 0x0000_0400
+	TOPALIGN 0x0000_0800
+}
+
 const_nm! { RBN;
 	/// Rebar control `WM_NOTIFY`
 	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-notifications)
@@ -1624,6 +1787,14 @@ const_nm! { RBN;
 	AUTOBREAK RBN_FIRST - 22
 }
 
+const_bitflag! { RBNM: u32;
+	/// [`NMREBAR`](crate::NMREBAR) `dwMask` (`u32`).
+	=>
+	ID 0x0000_0001
+	STYLE 0x0000_0002
+	LPARAM 0x0000_0004
+}
+
 const_ws! { RBS: u32;
 	/// Rebar control
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/rebar-control-styles)
@@ -1730,6 +1901,15 @@ const_bitflag! { TBBF: u32;
 	LARGE 0x0001
 }
 
+const_ordinary! { TBDDRET: u32;
+	/// [`TBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dropdown)
+	/// return value (`u32`).
+	=>
+	DEFAULT 0
+	NODEFAULT 1
+	TREATPRESSED 2
+}
+
 const_bitflag! { TBIF: u32;
 	/// [`TBBUTTONINFO`](crate::TBBUTTONINFO) `dwFlags` (`u32`).
 	=>
@@ -1938,6 +2118,23 @@ const_bitflag! { TBSTATE: u8;
 	MARKED 0x80
 }
 
+const_ws! { TBSTYLE: u32;
+	/// Toolbar control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-control-and-button-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOOLTIPS 0x0100
+	WRAPABLE 0x0200
+	ALTDRAG 0x0400
+	FLAT 0x0800
+	LIST 0x1000
+	CUSTOMERASE 0x2000
+	REGISTERDROP 0x4000
+	TRANSPARENT 0x8000
+}
+
 const_wsex! { TBSTYLE_EX;
 	/// Extended toolbar control
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-extended-styles)
@@ -2203,8 +2400,35 @@ const_nm! { TRBN;
 	THUMBPOSCHANGING TRBN_FIRST - 1
 }
 
+const_ordinary! { TTDT: u32;
+	/// [`ttm::GetDelayTime`](crate::msg::ttm::GetDelayTime) and
+	/// [`ttm::SetDelayTime`](crate::msg::ttm::SetDelayTime) `which` (`u32`).
+	=>
+	AUTOMATIC 0
+	RESHOW 1
+	AUTOPOP 2
+	INITIAL 3
+}
+
+const_bitflag! { TTF: u32;
+	/// [`TTTOOLINFO`](crate::TTTOOLINFO) `uFlags` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	IDISHWND 0x0001
+	CENTERTIP 0x0002
+	RTLREADING 0x0004
+	SUBCLASS 0x0010
+	TRACK 0x0020
+	ABSOLUTE 0x0080
+	TRANSPARENT 0x0100
+	PARSELINKS 0x1000
+	DI_SETITEM 0x8000
+}
+
 const_ordinary! { TTI: i32;
-	/// [`EDITBALLOONTIP`](crate::EDITBALLOONTIP) ttiIcon (`i32`).
+	/// [`EDITBALLOONTIP`](crate::EDITBALLOONTIP) `ttiIcon` and
+	/// [`ttm::SetTitle`](crate::msg::ttm::SetTitle) `icon` (`i32`).
 	=>
 	ERROR 3
 	INFO 1
@@ -2215,6 +2439,75 @@ const_ordinary! { TTI: i32;
 	ERROR_LARGE 6
 }
 
+const_wm! { TTM;
+	/// Tooltip control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages)
+	/// (`u32`).
+	=>
+	ACTIVATE WM::USER.raw() + 1
+	SETDELAYTIME WM::USER.raw() + 3
+	RELAYEVENT WM::USER.raw() + 7
+	GETTOOLCOUNT WM::USER.raw() + 13
+	WINDOWFROMPOINT WM::USER.raw() + 16
+	TRACKACTIVATE WM::USER.raw() + 17
+	TRACKPOSITION WM::USER.raw() + 18
+	SETTIPBKCOLOR WM::USER.raw() + 19
+	SETTIPTEXTCOLOR WM::USER.raw() + 20
+	GETDELAYTIME WM::USER.raw() + 21
+	GETTIPBKCOLOR WM::USER.raw() + 22
+	GETTIPTEXTCOLOR WM::USER.raw() + 23
+	SETMAXTIPWIDTH WM::USER.raw() + 24
+	GETMAXTIPWIDTH WM::USER.raw() + 25
+	SETMARGIN WM::USER.raw() + 26
+	GETMARGIN WM::USER.raw() + 27
+	POP WM::USER.raw() + 28
+	UPDATE WM::USER.raw() + 29
+	GETBUBBLESIZE WM::USER.raw() + 30
+	ADJUSTRECT WM::USER.raw() + 31
+	SETTITLE WM::USER.raw() + 33
+	POPUP WM::USER.raw() + 34
+	GETTITLE WM::USER.raw() + 35
+	ADDTOOL WM::USER.raw() + 50
+	DELTOOL WM::USER.raw() + 51
+	NEWTOOLRECT WM::USER.raw() + 52
+	GETTOOLINFO WM::USER.raw() + 53
+	SETTOOLINFO WM::USER.raw() + 54
+	HITTEST WM::USER.raw() + 55
+	GETTEXT WM::USER.raw() + 56
+	UPDATETIPTEXT WM::USER.raw() + 57
+	ENUMTOOLS WM::USER.raw() + 58
+	GETCURRENTTOOL WM::USER.raw() + 59
+	SETWINDOWTHEME CCM::SETWINDOWTHEME.0
+}
+
+const_nm! { TTN;
+	/// Tooltip control `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-notifications)
+	/// (`i32`).
+	=>
+	GETDISPINFO TTN_FIRST - 10
+	SHOW TTN_FIRST - 1
+	POP TTN_FIRST - 2
+	LINKCLICK TTN_FIRST - 3
+	NEEDTEXT Self::GETDISPINFO.0
+}
+
+const_ws! { TTS: u32;
+	/// Tooltip control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ALWAYSTIP 0x01
+	NOPREFIX 0x02
+	NOANIMATE 0x10
+	NOFADE 0x20
+	BALLOON 0x40
+	CLOSE 0x80
+	USEVISUALSTYLE 0x100
+}
+
 const_ordinary! { TVC: u32;
 	/// [`NMTREEVIEW`](crate::NMTREEVIEW) `action` (`u32`).
 	=>
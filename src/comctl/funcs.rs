@@ -6,6 +6,40 @@ use crate::decl::*;
 use crate::kernel::{ffi_types::*, privs::*};
 use crate::ole::privs::*;
 
+/// [`FIRST_IPADDRESS`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-first_ipaddress)
+/// macro.
+///
+/// Returns the first field of a packed IPv4 address.
+///
+/// # Related functions
+///
+/// * [`FOURTH_IPADDRESS`](crate::FOURTH_IPADDRESS)
+/// * [`MAKEIPADDRESS`](crate::MAKEIPADDRESS)
+/// * [`MAKEIPRANGE`](crate::MAKEIPRANGE)
+/// * [`SECOND_IPADDRESS`](crate::SECOND_IPADDRESS)
+/// * [`THIRD_IPADDRESS`](crate::THIRD_IPADDRESS)
+#[must_use]
+pub const fn FIRST_IPADDRESS(v: u32) -> u8 {
+	(v >> 24 & 0xff) as _
+}
+
+/// [`FOURTH_IPADDRESS`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-fourth_ipaddress)
+/// macro.
+///
+/// Returns the fourth field of a packed IPv4 address.
+///
+/// # Related functions
+///
+/// * [`FIRST_IPADDRESS`](crate::FIRST_IPADDRESS)
+/// * [`MAKEIPADDRESS`](crate::MAKEIPADDRESS)
+/// * [`MAKEIPRANGE`](crate::MAKEIPRANGE)
+/// * [`SECOND_IPADDRESS`](crate::SECOND_IPADDRESS)
+/// * [`THIRD_IPADDRESS`](crate::THIRD_IPADDRESS)
+#[must_use]
+pub const fn FOURTH_IPADDRESS(v: u32) -> u8 {
+	(v & 0xff) as _
+}
+
 /// [`InitCommonControls`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-initcommoncontrols)
 /// function.
 pub fn InitCommonControls() {
@@ -24,6 +58,42 @@ pub fn InitMUILanguage(ui_lang: LANGID) {
 	unsafe { ffi::InitMUILanguage(ui_lang.into()) }
 }
 
+/// [`MAKEIPADDRESS`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-makeipaddress)
+/// macro.
+///
+/// Packs the four fields of an IPv4 address, as used by
+/// [`ipm::SetAddress`](crate::msg::ipm::SetAddress).
+///
+/// # Related functions
+///
+/// * [`FIRST_IPADDRESS`](crate::FIRST_IPADDRESS)
+/// * [`FOURTH_IPADDRESS`](crate::FOURTH_IPADDRESS)
+/// * [`MAKEIPRANGE`](crate::MAKEIPRANGE)
+/// * [`SECOND_IPADDRESS`](crate::SECOND_IPADDRESS)
+/// * [`THIRD_IPADDRESS`](crate::THIRD_IPADDRESS)
+#[must_use]
+pub const fn MAKEIPADDRESS(b1: u8, b2: u8, b3: u8, b4: u8) -> u32 {
+	(b1 as u32) << 24 | (b2 as u32) << 16 | (b3 as u32) << 8 | b4 as u32
+}
+
+/// [`MAKEIPRANGE`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-makeiprange)
+/// macro.
+///
+/// Packs the range of a field of an IPv4 address, as used by
+/// [`ipm::SetRange`](crate::msg::ipm::SetRange).
+///
+/// # Related functions
+///
+/// * [`FIRST_IPADDRESS`](crate::FIRST_IPADDRESS)
+/// * [`FOURTH_IPADDRESS`](crate::FOURTH_IPADDRESS)
+/// * [`MAKEIPADDRESS`](crate::MAKEIPADDRESS)
+/// * [`SECOND_IPADDRESS`](crate::SECOND_IPADDRESS)
+/// * [`THIRD_IPADDRESS`](crate::THIRD_IPADDRESS)
+#[must_use]
+pub const fn MAKEIPRANGE(low: u8, high: u8) -> u16 {
+	(high as u16) << 8 | low as u16
+}
+
 /// [`PropertySheet`](https://learn.microsoft.com/en-us/windows/win32/api/prsht/nf-prsht-propertysheetw)
 /// function.
 pub unsafe fn PropertySheet(header: &PROPSHEETHEADER) -> SysResult<isize> {
@@ -34,6 +104,40 @@ pub unsafe fn PropertySheet(header: &PROPSHEETHEADER) -> SysResult<isize> {
 	}
 }
 
+/// [`SECOND_IPADDRESS`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-second_ipaddress)
+/// macro.
+///
+/// Returns the second field of a packed IPv4 address.
+///
+/// # Related functions
+///
+/// * [`FIRST_IPADDRESS`](crate::FIRST_IPADDRESS)
+/// * [`FOURTH_IPADDRESS`](crate::FOURTH_IPADDRESS)
+/// * [`MAKEIPADDRESS`](crate::MAKEIPADDRESS)
+/// * [`MAKEIPRANGE`](crate::MAKEIPRANGE)
+/// * [`THIRD_IPADDRESS`](crate::THIRD_IPADDRESS)
+#[must_use]
+pub const fn SECOND_IPADDRESS(v: u32) -> u8 {
+	(v >> 16 & 0xff) as _
+}
+
+/// [`THIRD_IPADDRESS`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-third_ipaddress)
+/// macro.
+///
+/// Returns the third field of a packed IPv4 address.
+///
+/// # Related functions
+///
+/// * [`FIRST_IPADDRESS`](crate::FIRST_IPADDRESS)
+/// * [`FOURTH_IPADDRESS`](crate::FOURTH_IPADDRESS)
+/// * [`MAKEIPADDRESS`](crate::MAKEIPADDRESS)
+/// * [`MAKEIPRANGE`](crate::MAKEIPRANGE)
+/// * [`SECOND_IPADDRESS`](crate::SECOND_IPADDRESS)
+#[must_use]
+pub const fn THIRD_IPADDRESS(v: u32) -> u8 {
+	(v >> 8 & 0xff) as _
+}
+
 /// [`TaskDialogIndirect`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-taskdialogindirect)
 /// function.
 ///
@@ -131,3 +235,41 @@ pub fn TaskDialogIndirect(config: &TASKDIALOGCONFIG) -> HrResult<(co::DLGID, u16
 
 	Ok((unsafe { co::DLGID::from_raw(pn_button as _) }, pn_radio_button as _, pf_bool != 0))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ipaddress_round_trip() {
+		let ip = MAKEIPADDRESS(192, 168, 0, 1);
+		assert_eq!(ip, 0xc0a8_0001);
+		assert_eq!(FIRST_IPADDRESS(ip), 192);
+		assert_eq!(SECOND_IPADDRESS(ip), 168);
+		assert_eq!(THIRD_IPADDRESS(ip), 0);
+		assert_eq!(FOURTH_IPADDRESS(ip), 1);
+
+		for (b1, b2, b3, b4) in
+			[(0, 0, 0, 0), (255, 255, 255, 255), (1, 2, 3, 4), (10, 255, 0, 128)]
+		{
+			let ip = MAKEIPADDRESS(b1, b2, b3, b4);
+			assert_eq!(
+				(
+					FIRST_IPADDRESS(ip),
+					SECOND_IPADDRESS(ip),
+					THIRD_IPADDRESS(ip),
+					FOURTH_IPADDRESS(ip)
+				),
+				(b1, b2, b3, b4),
+			);
+			assert_eq!(ip, u32::from_be_bytes([b1, b2, b3, b4]));
+		}
+	}
+
+	#[test]
+	fn iprange() {
+		assert_eq!(MAKEIPRANGE(0, 255), 0xff00);
+		assert_eq!(MAKEIPRANGE(10, 20), 20 << 8 | 10);
+		assert_eq!(MAKEIPRANGE(255, 0), 0x00ff);
+	}
+}
@@ -311,6 +311,17 @@ pub trait comctl_Himagelist: Handle {
 		}
 	}
 
+	/// [`ImageList_SetIconSize`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-imagelist_seticonsize)
+	/// function.
+	///
+	/// **Note:** All images are removed from the image list.
+	fn SetIconSize(&self, sz: SIZE) -> HrResult<()> {
+		match unsafe { ffi::ImageList_SetIconSize(self.ptr(), sz.cx, sz.cy) } {
+			0 => Err(co::HRESULT::E_FAIL),
+			_ => Ok(()),
+		}
+	}
+
 	/// [`ImageList_SetImageCount`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-imagelist_setimagecount)
 	/// function.
 	///
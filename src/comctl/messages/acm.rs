@@ -0,0 +1,110 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// [`ACM_ISPLAYING`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-isplaying)
+/// message, which has no parameters.
+///
+/// Return type: `bool`.
+pub struct IsPlaying {}
+
+impl MsgSend for IsPlaying {
+	type RetType = bool;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::ISPLAYING.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`ACM_OPEN`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-open)
+/// message parameters.
+///
+/// To load the AVI clip from a file, pass its path as `resource`, and
+/// `HINSTANCE::NULL` as `hinstance`. If `resource` is `None`, the current clip
+/// is closed.
+///
+/// Return type: `SysResult<()>`.
+pub struct Open<'a> {
+	pub hinstance: &'a HINSTANCE,
+	pub resource: Option<IdStr>,
+}
+
+impl<'a> MsgSend for Open<'a> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::OPEN.into(),
+			wparam: self.hinstance.ptr() as _,
+			lparam: self
+				.resource
+				.as_ref()
+				.map_or(std::ptr::null(), |res| res.as_ptr()) as _,
+		}
+	}
+}
+
+/// [`ACM_PLAY`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-play)
+/// message parameters.
+///
+/// If `repeat` is `None`, the clip is played indefinitely. If `to_frame` is
+/// `None`, the clip is played until its last frame.
+///
+/// Return type: `SysResult<()>`.
+pub struct Play {
+	pub repeat: Option<u32>,
+	pub from_frame: u16,
+	pub to_frame: Option<u16>,
+}
+
+impl MsgSend for Play {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::PLAY.into(),
+			wparam: self.repeat.map_or(-1, |r| r as i32) as _,
+			lparam: MAKEDWORD(self.from_frame, self.to_frame.unwrap_or(0xffff)) as _,
+		}
+	}
+}
+
+/// [`ACM_STOP`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-stop)
+/// message, which has no parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct Stop {}
+
+impl MsgSend for Stop {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::STOP.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
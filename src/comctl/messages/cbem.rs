@@ -0,0 +1,260 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// [`CBEM_DELETEITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-deleteitem)
+/// message parameters.
+///
+/// Return type: `SysResult<u32>`.
+pub struct DeleteItem {
+	pub index: u32,
+}
+
+impl MsgSend for DeleteItem {
+	type RetType = SysResult<u32>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		minus1_as_badargs(v).map(|count| count as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::DELETEITEM.into(),
+			wparam: self.index as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`CBEM_GETCOMBOCONTROL`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-getcombocontrol)
+/// message, which has no parameters.
+///
+/// Return type: `Option<HWND>`.
+pub struct GetComboControl {}
+
+impl MsgSend for GetComboControl {
+	type RetType = Option<HWND>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_none(v).map(|p| HWND::from_ptr(p as _))
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::GETCOMBOCONTROL.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`CBEM_GETEDITCONTROL`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-geteditcontrol)
+/// message, which has no parameters.
+///
+/// Return type: `Option<HWND>`.
+pub struct GetEditControl {}
+
+impl MsgSend for GetEditControl {
+	type RetType = Option<HWND>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_none(v).map(|p| HWND::from_ptr(p as _))
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::GETEDITCONTROL.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`CBEM_GETEXTENDEDSTYLE`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-getextendedstyle)
+/// message, which has no parameters.
+///
+/// Return type: `co::CBES_EX`.
+pub struct GetExtendedStyle {}
+
+impl MsgSend for GetExtendedStyle {
+	type RetType = co::CBES_EX;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::CBES_EX::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::GETEXTENDEDSTYLE.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`CBEM_GETIMAGELIST`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-getimagelist)
+/// message, which has no parameters.
+///
+/// Return type: `Option<HIMAGELIST>`.
+pub struct GetImageList {}
+
+impl MsgSend for GetImageList {
+	type RetType = Option<HIMAGELIST>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_none(v).map(|v| HIMAGELIST::from_ptr(v as _))
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::GETIMAGELIST.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`CBEM_GETITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-getitem)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct GetItem<'a, 'b> {
+	pub item: &'b mut COMBOBOXEXITEM<'a>,
+}
+
+impl<'a, 'b> MsgSend for GetItem<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::GETITEM.into(),
+			wparam: 0,
+			lparam: self.item as *mut _ as _,
+		}
+	}
+}
+
+/// [`CBEM_HASEDITCHANGED`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-haseditchanged)
+/// message, which has no parameters.
+///
+/// Return type: `bool`.
+pub struct HasEditChanged {}
+
+impl MsgSend for HasEditChanged {
+	type RetType = bool;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::HASEDITCHANGED.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`CBEM_INSERTITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-insertitem)
+/// message parameters.
+///
+/// Return type: `SysResult<u32>`.
+pub struct InsertItem<'a, 'b> {
+	pub item: &'b COMBOBOXEXITEM<'a>,
+}
+
+impl<'a, 'b> MsgSend for InsertItem<'a, 'b> {
+	type RetType = SysResult<u32>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		minus1_as_badargs(v).map(|i| i as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::INSERTITEM.into(),
+			wparam: 0,
+			lparam: self.item as *const _ as _,
+		}
+	}
+}
+
+/// [`CBEM_SETEXTENDEDSTYLE`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-setextendedstyle)
+/// message parameters.
+///
+/// Return type: `co::CBES_EX`.
+pub struct SetExtendedStyle {
+	pub mask: co::CBES_EX,
+	pub style: co::CBES_EX,
+}
+
+impl MsgSend for SetExtendedStyle {
+	type RetType = co::CBES_EX;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::CBES_EX::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::SETEXTENDEDSTYLE.into(),
+			wparam: self.mask.raw() as _,
+			lparam: self.style.raw() as _,
+		}
+	}
+}
+
+/// [`CBEM_SETIMAGELIST`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-setimagelist)
+/// message parameters.
+///
+/// Return type: `Option<HIMAGELIST>`.
+pub struct SetImageList {
+	pub himagelist: HIMAGELIST,
+}
+
+impl MsgSend for SetImageList {
+	type RetType = Option<HIMAGELIST>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_none(v).map(|v| HIMAGELIST::from_ptr(v as _))
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::SETIMAGELIST.into(),
+			wparam: 0,
+			lparam: self.himagelist.ptr() as _,
+		}
+	}
+}
+
+/// [`CBEM_SETITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-setitem)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetItem<'a, 'b> {
+	pub item: &'b COMBOBOXEXITEM<'a>,
+}
+
+impl<'a, 'b> MsgSend for SetItem<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::CBEM::SETITEM.into(),
+			wparam: 0,
+			lparam: self.item as *const _ as _,
+		}
+	}
+}
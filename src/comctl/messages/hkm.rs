@@ -0,0 +1,76 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// [`HKM_GETHOTKEY`](https://learn.microsoft.com/en-us/windows/win32/controls/hkm-gethotkey)
+/// message, which has no parameters.
+///
+/// Return type: `(co::VK, co::HOTKEYF)`.
+pub struct GetHotKey {}
+
+impl MsgSend for GetHotKey {
+	type RetType = (co::VK, co::HOTKEYF);
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		(co::VK::from_raw(LOBYTE(v as _) as _), co::HOTKEYF::from_raw(HIBYTE(v as _) as _))
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::HKM::GETHOTKEY.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`HKM_SETHOTKEY`](https://learn.microsoft.com/en-us/windows/win32/controls/hkm-sethotkey)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetHotKey {
+	pub vkey_code: co::VK,
+	pub modifiers: co::HOTKEYF,
+}
+
+impl MsgSend for SetHotKey {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::HKM::SETHOTKEY.into(),
+			wparam: MAKEWORD(self.vkey_code.raw() as _, self.modifiers.raw() as _) as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`HKM_SETRULES`](https://learn.microsoft.com/en-us/windows/win32/controls/hkm-setrules)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetRules {
+	pub invalid_combos: co::HKCOMB,
+	pub default_modifiers: co::HOTKEYF,
+}
+
+impl MsgSend for SetRules {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::HKM::SETRULES.into(),
+			wparam: self.invalid_combos.raw() as _,
+			lparam: self.default_modifiers.raw() as _,
+		}
+	}
+}
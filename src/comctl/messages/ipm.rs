@@ -0,0 +1,136 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+pub_struct_msg_empty! { ClearAddress: co::IPM::CLEARADDRESS.into();
+	/// [`IPM_CLEARADDRESS`](https://learn.microsoft.com/en-us/windows/win32/controls/ipm-clearaddress)
+}
+
+/// [`IPM_GETADDRESS`](https://learn.microsoft.com/en-us/windows/win32/controls/ipm-getaddress)
+/// message parameters.
+///
+/// The address is packed, and its fields can be retrieved with
+/// [`FIRST_IPADDRESS`](crate::FIRST_IPADDRESS) and the related functions.
+///
+/// Return type: `u8`.
+pub struct GetAddress<'a> {
+	pub address: &'a mut u32,
+}
+
+impl<'a> MsgSend for GetAddress<'a> {
+	type RetType = u8;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::IPM::GETADDRESS.into(),
+			wparam: 0,
+			lparam: self.address as *mut _ as _,
+		}
+	}
+}
+
+/// [`IPM_ISBLANK`](https://learn.microsoft.com/en-us/windows/win32/controls/ipm-isblank)
+/// message, which has no parameters.
+///
+/// Return type: `bool`.
+pub struct IsBlank {}
+
+impl MsgSend for IsBlank {
+	type RetType = bool;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::IPM::ISBLANK.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`IPM_SETADDRESS`](https://learn.microsoft.com/en-us/windows/win32/controls/ipm-setaddress)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetAddress {
+	pub address: [u8; 4],
+}
+
+impl MsgSend for SetAddress {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		let [b1, b2, b3, b4] = self.address;
+		WndMsg {
+			msg_id: co::IPM::SETADDRESS.into(),
+			wparam: 0,
+			lparam: MAKEIPADDRESS(b1, b2, b3, b4) as _,
+		}
+	}
+}
+
+/// [`IPM_SETFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/ipm-setfocus)
+/// message parameters.
+///
+/// If `field` is `None`, the focus is set to the first blank field, or to the
+/// first field if all fields have values.
+///
+/// Return type: `()`.
+pub struct SetFocus {
+	pub field: Option<u8>,
+}
+
+impl MsgSend for SetFocus {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::IPM::SETFOCUS.into(),
+			wparam: self.field.map_or(-1, |f| f as isize) as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`IPM_SETRANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/ipm-setrange)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetRange {
+	pub field: u8,
+	pub min: u8,
+	pub max: u8,
+}
+
+impl MsgSend for SetRange {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::IPM::SETRANGE.into(),
+			wparam: self.field as _,
+			lparam: MAKEIPRANGE(self.min, self.max) as _,
+		}
+	}
+}
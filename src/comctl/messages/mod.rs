@@ -1,16 +1,23 @@
+pub mod acm;
 pub mod bcm;
 pub mod cb;
+pub mod cbem;
 pub mod dtm;
 pub mod em;
 pub mod hdm;
+pub mod hkm;
+pub mod ipm;
+pub mod lm;
 pub mod lvm;
 pub mod mcm;
 pub mod pbm;
+pub mod rb;
 pub mod sb;
 pub mod stm;
 pub mod tbm;
 pub mod tcm;
 pub mod trbm;
+pub mod ttm;
 pub mod tvm;
 pub mod udm;
 pub mod wm;
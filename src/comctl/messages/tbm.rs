@@ -230,7 +230,7 @@ impl MsgSend for DeleteButton {
 
 	fn as_generic_wm(&mut self) -> WndMsg {
 		WndMsg {
-			msg_id: co::TBM::CHECKBUTTON.into(),
+			msg_id: co::TBM::DELETEBUTTON.into(),
 			wparam: self.btn_index as _,
 			lparam: 0,
 		}
@@ -10,6 +10,7 @@ const_values_num_privs! {
 	I_IMAGECALLBACK isize = -1
 	I_IMAGENONE isize = -2
 	L_MAX_URL_LENGTH usize = 2048 + 32 + 4
+	LPSTR_TEXTCALLBACK isize = -1
 	MAX_LINKID_TEXT usize = 48
 }
 
@@ -17,6 +18,7 @@ const_values_num_privs! {
 	BCM_FIRST u32 = 0x1600
 	BCN_FIRST i32 = -1250
 	CB_FIRST u32 = 0x1700
+	CBEN_FIRST i32 = -800
 	CCM_FIRST u32 = 0x2000
 	DTM_FIRST u32 = 0x1000
 	DTN_FIRST i32 = -740
@@ -36,6 +38,7 @@ const_values_num_privs! {
 	TCM_FIRST u32 = 0x1300
 	TCN_FIRST i32 = -550
 	TRBN_FIRST i32 = -1501
+	TTN_FIRST i32 = -520
 	TVM_FIRST u32 = 0x1100
 	TVN_FIRST i32 = -400
 	UDN_FIRST i32 = -721
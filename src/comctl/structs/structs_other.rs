@@ -38,6 +38,29 @@ pub struct COLORSCHEME {
 
 impl_default!(COLORSCHEME, dwSize);
 
+/// [`COMBOBOXEXITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-comboboxexitemw)
+/// struct.
+#[repr(C)]
+pub struct COMBOBOXEXITEM<'a> {
+	pub mask: co::CBEIF,
+	pub iItem: isize,
+	pszText: *mut u16,
+	cchTextMax: i32,
+	pub iImage: i32,
+	pub iSelectedImage: i32,
+	pub iOverlay: i32,
+	pub iIndent: i32,
+	pub lParam: isize,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(COMBOBOXEXITEM, 'a);
+
+impl<'a> COMBOBOXEXITEM<'a> {
+	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchTextMax);
+}
+
 /// [`DATETIMEPICKERINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-datetimepickerinfo)
 /// struct.
 #[repr(C)]
@@ -138,6 +161,15 @@ pub struct INITCOMMONCONTROLSEX {
 
 impl_default!(INITCOMMONCONTROLSEX, dwSize);
 
+/// [`LHITTESTINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-lhittestinfo)
+/// struct.
+#[repr(C)]
+#[derive(Default)]
+pub struct LHITTESTINFO {
+	pub pt: POINT,
+	pub item: LITEM,
+}
+
 /// [`LITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-litem)
 /// struct.
 #[repr(C)]
@@ -596,6 +628,24 @@ pub struct NMBCHOTITEM {
 	pub dwFlags: co::HICF,
 }
 
+/// [`NMCBEENDEDIT`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmcbeenditw)
+/// struct.
+#[repr(C)]
+pub struct NMCBEENDEDIT {
+	pub hdr: NMHDR,
+	fChanged: BOOL,
+	pub iNewSelection: i32,
+	szText: [u16; MAX_PATH],
+	pub iWhy: co::CBENF,
+}
+
+impl_default!(NMCBEENDEDIT);
+
+impl NMCBEENDEDIT {
+	pub_fn_bool_get_set!(fChanged, set_fChanged);
+	pub_fn_string_arr_get_set!(szText, set_szText);
+}
+
 /// [`NMCHAR`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmchar)
 /// struct.
 #[repr(C)]
@@ -606,6 +656,16 @@ pub struct NMCHAR {
 	pub dwItemNext: u32,
 }
 
+/// [`NMCOMBOBOXEX`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmcomboboxexw)
+/// struct.
+#[repr(C)]
+pub struct NMCOMBOBOXEX<'a> {
+	pub hdr: NMHDR,
+	pub ceItem: COMBOBOXEXITEM<'a>,
+}
+
+impl_default!(NMCOMBOBOXEX, 'a);
+
 /// [`NMCUSTOMDRAW`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmcustomdraw)
 /// struct.
 #[repr(C)]
@@ -1067,6 +1127,63 @@ pub struct NMMOUSE {
 	pub dwHitInfo: isize,
 }
 
+/// [`NMRBAUTOSIZE`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmrbautosize)
+/// struct.
+#[repr(C)]
+pub struct NMRBAUTOSIZE {
+	pub hdr: NMHDR,
+	fChanged: BOOL,
+	pub rcTarget: RECT,
+	pub rcActual: RECT,
+}
+
+impl_default!(NMRBAUTOSIZE);
+
+impl NMRBAUTOSIZE {
+	pub_fn_bool_get_set!(fChanged, set_fChanged);
+}
+
+/// [`NMREBAR`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmrebar)
+/// struct.
+#[repr(C)]
+pub struct NMREBAR {
+	pub hdr: NMHDR,
+	pub dwMask: co::RBNM,
+	pub uBand: u32,
+	pub fStyle: co::RBBS,
+	pub wID: u32,
+	pub lParam: isize,
+}
+
+impl_default!(NMREBAR);
+
+/// [`NMREBARCHEVRON`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmrebarchevron)
+/// struct.
+#[repr(C)]
+pub struct NMREBARCHEVRON {
+	pub hdr: NMHDR,
+	pub uBand: u32,
+	pub wID: u32,
+	pub lParam: isize,
+	pub rc: RECT,
+	pub lParamNM: isize,
+}
+
+impl_default!(NMREBARCHEVRON);
+
+/// [`NMREBARCHILDSIZE`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmrebarchildsize)
+/// struct.
+#[repr(C)]
+pub struct NMREBARCHILDSIZE {
+	pub hdr: NMHDR,
+	pub uBand: u32,
+	pub wID: u32,
+	pub rcChild: RECT,
+	pub rcBand: RECT,
+}
+
+impl_default!(NMREBARCHILDSIZE);
+
 /// [`NMTRBTHUMBPOSCHANGING`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtrbthumbposchanging)
 /// struct.
 #[repr(C)]
@@ -1085,6 +1202,37 @@ pub struct NMSELCHANGE {
 	pub stSelEnd: SYSTEMTIME,
 }
 
+/// [`NMTBGETINFOTIP`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtbgetinfotipw)
+/// struct.
+#[repr(C)]
+pub struct NMTBGETINFOTIP<'a> {
+	pub hdr: NMHDR,
+	pszText: *mut u16,
+	cchTextMax: i32,
+	pub iItem: i32,
+	pub lParam: isize,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTBGETINFOTIP, 'a);
+
+impl<'a> NMTBGETINFOTIP<'a> {
+	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchTextMax);
+}
+
+/// [`NMTBHOTITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtbhotitem)
+/// struct.
+#[repr(C)]
+pub struct NMTBHOTITEM {
+	pub hdr: NMHDR,
+	pub idOld: i32,
+	pub idNew: i32,
+	pub dwFlags: co::HICF,
+}
+
+impl_default!(NMTBHOTITEM);
+
 /// [`NMTCKEYDOWN`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtckeydown)
 /// struct.
 #[repr(C)]
@@ -1096,6 +1244,26 @@ pub struct NMTCKEYDOWN {
 
 impl_default!(NMTCKEYDOWN);
 
+/// [`NMTOOLBAR`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtoolbarw)
+/// struct.
+#[repr(C)]
+pub struct NMTOOLBAR<'a> {
+	pub hdr: NMHDR,
+	pub iItem: i32,
+	pub tbButton: TBBUTTON<'a>,
+	cchText: i32,
+	pszText: *mut u16,
+	pub rcButton: RECT,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTOOLBAR, 'a);
+
+impl<'a> NMTOOLBAR<'a> {
+	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchText);
+}
+
 /// [`NMTREEVIEW`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtreevieww)
 /// struct.
 #[repr(C)]
@@ -1107,6 +1275,27 @@ pub struct NMTREEVIEW<'a, 'b> {
 	pub ptDrag: POINT,
 }
 
+/// [`NMTTDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmttdispinfow)
+/// struct.
+#[repr(C)]
+pub struct NMTTDISPINFO<'a> {
+	pub hdr: NMHDR,
+	lpszText: *mut u16,
+	szText: [u16; 80],
+	pub hinst: HINSTANCE,
+	pub uFlags: co::TTF,
+	pub lParam: isize,
+
+	_lpszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTTDISPINFO, 'a);
+
+impl<'a> NMTTDISPINFO<'a> {
+	pub_fn_string_ptr_get_set!('a, lpszText, set_lpszText);
+	pub_fn_string_arr_get_set!(szText, set_szText);
+}
+
 /// [`NMTVCUSTOMDRAW`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtvcustomdraw)
 /// stuct.
 #[repr(C)]
@@ -1334,6 +1523,42 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> PROPSHEETPAGE<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
 	}
 }
 
+/// [`REBARBANDINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-rebarbandinfow)
+/// struct.
+#[repr(C)]
+pub struct REBARBANDINFO<'a> {
+	cbSize: u32,
+	pub fMask: co::RBBIM,
+	pub fStyle: co::RBBS,
+	pub clrFore: COLORREF,
+	pub clrBack: COLORREF,
+	lpText: *mut u16,
+	cch: u32,
+	pub iImage: i32,
+	pub hwndChild: HWND,
+	pub cxMinChild: u32,
+	pub cyMinChild: u32,
+	pub cx: u32,
+	pub hbmBack: HBITMAP,
+	pub wID: u32,
+	pub cyChild: u32,
+	pub cyMaxChild: u32,
+	pub cyIntegral: u32,
+	pub cxIdeal: u32,
+	pub lParam: isize,
+	pub cxHeader: u32,
+	pub rcChevronLocation: RECT,
+	pub uChevronState: u32,
+
+	_lpText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(REBARBANDINFO, cbSize, 'a);
+
+impl<'a> REBARBANDINFO<'a> {
+	pub_fn_string_buf_get_set!('a, lpText, set_lpText, raw_lpText, cch);
+}
+
 /// [`TBADDBITMAP`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tbaddbitmap)
 /// struct.
 #[repr(C)]
@@ -1556,6 +1781,52 @@ impl<'a> TCITEM<'a> {
 	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchTextMax);
 }
 
+/// [`TTTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tttoolinfow)
+/// struct.
+#[repr(C)]
+pub struct TTTOOLINFO<'a> {
+	cbSize: u32,
+	pub uFlags: co::TTF,
+	pub hwnd: HWND,
+	pub uId: usize,
+	pub rect: RECT,
+	pub hinst: HINSTANCE,
+	lpszText: *mut u16,
+	pub lParam: isize,
+	lpReserved: *mut std::ffi::c_void,
+
+	_lpszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(TTTOOLINFO, cbSize, 'a);
+
+impl<'a> TTTOOLINFO<'a> {
+	/// Returns the `lpszText` field, if any. Returns `None` if the text is
+	/// retrieved through
+	/// [`TTN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-getdispinfo).
+	#[must_use]
+	pub fn lpszText(&self) -> Option<String> {
+		if self.lpszText as isize == LPSTR_TEXTCALLBACK {
+			None
+		} else {
+			unsafe { self.lpszText.as_mut() }
+				.map(|psz| unsafe { WString::from_wchars_nullt(psz) }.to_string())
+		}
+	}
+
+	/// Sets the `lpszText` field.
+	pub fn set_lpszText(&mut self, buf: Option<&'a mut WString>) {
+		self.lpszText = buf.map_or(std::ptr::null_mut(), |buf| unsafe { buf.as_mut_ptr() });
+	}
+
+	/// Sets the `lpszText` field to `LPSTR_TEXTCALLBACK`, so the text will be
+	/// retrieved through
+	/// [`TTN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-getdispinfo).
+	pub fn set_lpszText_callback(&mut self) {
+		self.lpszText = LPSTR_TEXTCALLBACK as _;
+	}
+}
+
 /// [`TVHITTESTINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tvhittestinfo)
 /// struct.
 #[repr(C)]
@@ -0,0 +1,70 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+const_bitflag! { MINIDUMP_TYPE: u32;
+	/// [`MINIDUMP_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ne-minidumpapiset-minidump_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `MiniDump` prefix.
+	=>
+	Normal 0x0000_0000
+	WithDataSegs 0x0000_0001
+	WithFullMemory 0x0000_0002
+	WithHandleData 0x0000_0004
+	FilterMemory 0x0000_0008
+	ScanMemory 0x0000_0010
+	WithUnloadedModules 0x0000_0020
+	WithIndirectlyReferencedMemory 0x0000_0040
+	FilterModulePaths 0x0000_0080
+	WithProcessThreadData 0x0000_0100
+	WithPrivateReadWriteMemory 0x0000_0200
+	WithoutOptionalData 0x0000_0400
+	WithFullMemoryInfo 0x0000_0800
+	WithThreadInfo 0x0000_1000
+	WithCodeSegs 0x0000_2000
+	WithoutAuxiliaryState 0x0000_4000
+	WithFullAuxiliaryState 0x0000_8000
+	WithPrivateWriteCopyMemory 0x0001_0000
+	IgnoreInaccessibleMemory 0x0002_0000
+	WithTokenInformation 0x0004_0000
+	WithModuleHeaders 0x0008_0000
+	FilterTriage 0x0010_0000
+	WithAvxXStateContext 0x0020_0000
+	WithIptTrace 0x0040_0000
+	ScanInaccessiblePartialPages 0x0080_0000
+	FilterWriteCombinedMemory 0x0100_0000
+	ValidTypeFlags 0x01ff_ffff
+}
+
+const_ordinary! { MINIDUMP_STREAM_TYPE: u32;
+	/// [`MINIDUMP_STREAM_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ne-minidumpapiset-minidump_stream_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Stream` suffix.
+	///
+	/// User-defined streams must have types greater than `LastReserved`.
+	=>
+	Unused 0
+	ThreadList 3
+	ModuleList 4
+	MemoryList 5
+	Exception 6
+	SystemInfo 7
+	ThreadExList 8
+	Memory64List 9
+	CommentStreamA 10
+	CommentStreamW 11
+	HandleData 12
+	FunctionTable 13
+	UnloadedModuleList 14
+	MiscInfo 15
+	MemoryInfoList 16
+	ThreadInfoList 17
+	HandleOperationList 18
+	Token 19
+	JavaScriptData 20
+	SystemMemoryInfo 21
+	ProcessVmCounters 22
+	IptTrace 23
+	ThreadNames 24
+	LastReserved 0xffff
+}
@@ -0,0 +1,5 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "dbghelp";
+	MiniDumpWriteDump(HANDLE, u32, HANDLE, u32, PCVOID, PCVOID, PCVOID) -> BOOL
+}
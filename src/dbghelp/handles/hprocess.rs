@@ -0,0 +1,105 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::dbghelp::{ffi, structs::*};
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+impl dbghelp_Hprocess for HPROCESS {}
+
+/// This trait is enabled with the `dbghelp` feature, and provides methods for
+/// [`HPROCESS`](crate::HPROCESS).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait dbghelp_Hprocess: kernel_Hprocess {
+	/// [`MiniDumpWriteDump`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/nf-minidumpapiset-minidumpwritedump)
+	/// function.
+	///
+	/// Each user stream is a pair of its type – which must be greater than
+	/// [`co::MINIDUMP_STREAM_TYPE::LastReserved`](crate::co::MINIDUMP_STREAM_TYPE::LastReserved)
+	/// – and its data.
+	///
+	/// # Examples
+	///
+	/// Writing a dump of another process:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let pid: u32 = 1234;
+	/// let hprocess = w::HPROCESS::OpenProcess(
+	///     co::PROCESS::QUERY_INFORMATION | co::PROCESS::VM_READ,
+	///     false,
+	///     pid,
+	/// )?;
+	///
+	/// let (hfile, _) = w::HFILE::CreateFile(
+	///     "C:\\Temp\\app.dmp",
+	///     co::GENERIC::WRITE,
+	///     None,
+	///     None,
+	///     co::DISPOSITION::CREATE_ALWAYS,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     None,
+	///     None,
+	///     None,
+	/// )?;
+	///
+	/// let notes = "collected by triage tool".as_bytes();
+	///
+	/// hprocess.MiniDumpWriteDump(
+	///     pid,
+	///     &hfile,
+	///     co::MINIDUMP_TYPE::WithThreadInfo | co::MINIDUMP_TYPE::WithUnloadedModules,
+	///     None,
+	///     &[(unsafe { co::MINIDUMP_STREAM_TYPE::from_raw(0x1_0000) }, notes)],
+	/// )?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn MiniDumpWriteDump(
+		&self,
+		process_id: u32,
+		hfile: &HFILE,
+		dump_type: co::MINIDUMP_TYPE,
+		exception_param: Option<&MINIDUMP_EXCEPTION_INFORMATION>,
+		user_streams: &[(co::MINIDUMP_STREAM_TYPE, &[u8])],
+	) -> SysResult<()> {
+		let streams = user_streams
+			.iter()
+			.map(|(stream_type, data)| MINIDUMP_USER_STREAM {
+				Type: stream_type.raw(),
+				BufferSize: data.len() as _,
+				Buffer: data.as_ptr() as _,
+				_Buffer: PhantomData,
+			})
+			.collect::<Vec<_>>();
+		let streams_info = MINIDUMP_USER_STREAM_INFORMATION {
+			UserStreamCount: streams.len() as _,
+			UserStreamArray: vec_ptr(&streams),
+			_UserStreamArray: PhantomData,
+		};
+
+		bool_to_sysresult(unsafe {
+			ffi::MiniDumpWriteDump(
+				self.ptr(),
+				process_id,
+				hfile.ptr(),
+				dump_type.raw(),
+				exception_param.map_or(std::ptr::null(), |p| p as *const _ as _),
+				if streams.is_empty() {
+					std::ptr::null()
+				} else {
+					&streams_info as *const _ as _
+				},
+				std::ptr::null(),
+			)
+		})
+	}
+}
@@ -0,0 +1,5 @@
+mod hprocess;
+
+pub mod traits {
+	pub use super::hprocess::dbghelp_Hprocess;
+}
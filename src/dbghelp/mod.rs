@@ -0,0 +1,17 @@
+#![cfg(feature = "dbghelp")]
+
+mod handles;
+mod structs;
+mod utilities;
+
+pub mod co;
+pub(in crate::dbghelp) mod ffi;
+
+pub mod decl {
+	pub use super::structs::*;
+	pub use super::utilities::*;
+}
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
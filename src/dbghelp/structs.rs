@@ -0,0 +1,244 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::{ffi_types::*, privs::*};
+
+/// [`MINIDUMP_DIRECTORY`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_directory)
+/// struct.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MINIDUMP_DIRECTORY {
+	pub StreamType: co::MINIDUMP_STREAM_TYPE,
+	pub Location: MINIDUMP_LOCATION_DESCRIPTOR,
+}
+
+impl MINIDUMP_DIRECTORY {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			StreamType: unsafe {
+				co::MINIDUMP_STREAM_TYPE::from_raw(u32::from_le_bytes(le_bytes(buf, 0)?))
+			},
+			Location: MINIDUMP_LOCATION_DESCRIPTOR::from_bytes(buf.get(4..).unwrap_or_default())?,
+		})
+	}
+}
+
+/// [`MINIDUMP_EXCEPTION`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_exception)
+/// struct.
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct MINIDUMP_EXCEPTION {
+	pub ExceptionCode: co::EXCEPTION,
+	pub ExceptionFlags: u32,
+	pub ExceptionRecord: u64,
+	pub ExceptionAddress: u64,
+	pub NumberParameters: u32,
+	pub ExceptionInformation: [u64; 15],
+}
+
+impl MINIDUMP_EXCEPTION {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		let mut info = [0u64; 15];
+		for (i, param) in info.iter_mut().enumerate() {
+			*param = u64::from_le_bytes(le_bytes(buf, 32 + i * 8)?);
+		}
+
+		Ok(Self {
+			ExceptionCode: unsafe {
+				co::EXCEPTION::from_raw(u32::from_le_bytes(le_bytes(buf, 0)?))
+			},
+			ExceptionFlags: u32::from_le_bytes(le_bytes(buf, 4)?),
+			ExceptionRecord: u64::from_le_bytes(le_bytes(buf, 8)?),
+			ExceptionAddress: u64::from_le_bytes(le_bytes(buf, 16)?),
+			NumberParameters: u32::from_le_bytes(le_bytes(buf, 24)?),
+			ExceptionInformation: info,
+		})
+	}
+}
+
+/// [`MINIDUMP_EXCEPTION_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_exception_information)
+/// struct.
+#[repr(C, packed(4))]
+pub struct MINIDUMP_EXCEPTION_INFORMATION {
+	pub ThreadId: u32,
+	pub ExceptionPointers: *mut EXCEPTION_POINTERS,
+	ClientPointers: BOOL,
+}
+
+impl_default!(MINIDUMP_EXCEPTION_INFORMATION);
+
+impl MINIDUMP_EXCEPTION_INFORMATION {
+	pub_fn_bool_get_set!(ClientPointers, set_ClientPointers);
+}
+
+/// [`MINIDUMP_EXCEPTION_STREAM`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_exception_stream)
+/// struct.
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct MINIDUMP_EXCEPTION_STREAM {
+	pub ThreadId: u32,
+	pub ExceptionRecord: MINIDUMP_EXCEPTION,
+	pub ThreadContext: MINIDUMP_LOCATION_DESCRIPTOR,
+}
+
+impl MINIDUMP_EXCEPTION_STREAM {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			ThreadId: u32::from_le_bytes(le_bytes(buf, 0)?),
+			ExceptionRecord: MINIDUMP_EXCEPTION::from_bytes(buf.get(8..).unwrap_or_default())?,
+			ThreadContext: MINIDUMP_LOCATION_DESCRIPTOR::from_bytes(
+				buf.get(160..).unwrap_or_default(),
+			)?,
+		})
+	}
+}
+
+/// [`MINIDUMP_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_header)
+/// struct.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MINIDUMP_HEADER {
+	pub Signature: u32,
+	pub Version: u32,
+	pub NumberOfStreams: u32,
+	pub StreamDirectoryRva: u32,
+	pub CheckSum: u32,
+	pub TimeDateStamp: u32,
+	pub Flags: u64,
+}
+
+impl MINIDUMP_HEADER {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			Signature: u32::from_le_bytes(le_bytes(buf, 0)?),
+			Version: u32::from_le_bytes(le_bytes(buf, 4)?),
+			NumberOfStreams: u32::from_le_bytes(le_bytes(buf, 8)?),
+			StreamDirectoryRva: u32::from_le_bytes(le_bytes(buf, 12)?),
+			CheckSum: u32::from_le_bytes(le_bytes(buf, 16)?),
+			TimeDateStamp: u32::from_le_bytes(le_bytes(buf, 20)?),
+			Flags: u64::from_le_bytes(le_bytes(buf, 24)?),
+		})
+	}
+}
+
+/// [`MINIDUMP_LOCATION_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_location_descriptor)
+/// struct.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MINIDUMP_LOCATION_DESCRIPTOR {
+	pub DataSize: u32,
+	pub Rva: u32,
+}
+
+impl MINIDUMP_LOCATION_DESCRIPTOR {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			DataSize: u32::from_le_bytes(le_bytes(buf, 0)?),
+			Rva: u32::from_le_bytes(le_bytes(buf, 4)?),
+		})
+	}
+}
+
+/// [`MINIDUMP_MEMORY_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_memory_descriptor)
+/// struct.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MINIDUMP_MEMORY_DESCRIPTOR {
+	pub StartOfMemoryRange: u64,
+	pub Memory: MINIDUMP_LOCATION_DESCRIPTOR,
+}
+
+impl MINIDUMP_MEMORY_DESCRIPTOR {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			StartOfMemoryRange: u64::from_le_bytes(le_bytes(buf, 0)?),
+			Memory: MINIDUMP_LOCATION_DESCRIPTOR::from_bytes(buf.get(8..).unwrap_or_default())?,
+		})
+	}
+}
+
+/// [`MINIDUMP_MODULE`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_module)
+/// struct.
+///
+/// The `VersionInfo` member, with the `VS_FIXEDFILEINFO` of the module, is not
+/// included. The module name can be read with
+/// [`MiniDumpReader::string_at`](crate::MiniDumpReader::string_at).
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MINIDUMP_MODULE {
+	pub BaseOfImage: u64,
+	pub SizeOfImage: u32,
+	pub CheckSum: u32,
+	pub TimeDateStamp: u32,
+	pub ModuleNameRva: u32,
+	pub CvRecord: MINIDUMP_LOCATION_DESCRIPTOR,
+	pub MiscRecord: MINIDUMP_LOCATION_DESCRIPTOR,
+}
+
+impl MINIDUMP_MODULE {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			BaseOfImage: u64::from_le_bytes(le_bytes(buf, 0)?),
+			SizeOfImage: u32::from_le_bytes(le_bytes(buf, 8)?),
+			CheckSum: u32::from_le_bytes(le_bytes(buf, 12)?),
+			TimeDateStamp: u32::from_le_bytes(le_bytes(buf, 16)?),
+			ModuleNameRva: u32::from_le_bytes(le_bytes(buf, 20)?),
+			CvRecord: MINIDUMP_LOCATION_DESCRIPTOR::from_bytes(buf.get(76..).unwrap_or_default())?,
+			MiscRecord: MINIDUMP_LOCATION_DESCRIPTOR::from_bytes(
+				buf.get(84..).unwrap_or_default(),
+			)?,
+		})
+	}
+}
+
+/// [`MINIDUMP_THREAD`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_thread)
+/// struct.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MINIDUMP_THREAD {
+	pub ThreadId: u32,
+	pub SuspendCount: u32,
+	pub PriorityClass: u32,
+	pub Priority: u32,
+	pub Teb: u64,
+	pub Stack: MINIDUMP_MEMORY_DESCRIPTOR,
+	pub ThreadContext: MINIDUMP_LOCATION_DESCRIPTOR,
+}
+
+impl MINIDUMP_THREAD {
+	/// Decodes the struct from the raw bytes of a minidump file.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			ThreadId: u32::from_le_bytes(le_bytes(buf, 0)?),
+			SuspendCount: u32::from_le_bytes(le_bytes(buf, 4)?),
+			PriorityClass: u32::from_le_bytes(le_bytes(buf, 8)?),
+			Priority: u32::from_le_bytes(le_bytes(buf, 12)?),
+			Teb: u64::from_le_bytes(le_bytes(buf, 16)?),
+			Stack: MINIDUMP_MEMORY_DESCRIPTOR::from_bytes(buf.get(24..).unwrap_or_default())?,
+			ThreadContext: MINIDUMP_LOCATION_DESCRIPTOR::from_bytes(
+				buf.get(40..).unwrap_or_default(),
+			)?,
+		})
+	}
+}
+
+/// [`MINIDUMP_USER_STREAM`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_user_stream)
+/// struct.
+#[repr(C, packed(4))]
+pub(in crate::dbghelp) struct MINIDUMP_USER_STREAM<'a> {
+	pub(in crate::dbghelp) Type: u32,
+	pub(in crate::dbghelp) BufferSize: u32,
+	pub(in crate::dbghelp) Buffer: PCVOID,
+	pub(in crate::dbghelp) _Buffer: PhantomData<&'a [u8]>,
+}
+
+/// [`MINIDUMP_USER_STREAM_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/minidumpapiset/ns-minidumpapiset-minidump_user_stream_information)
+/// struct.
+#[repr(C, packed(4))]
+pub(in crate::dbghelp) struct MINIDUMP_USER_STREAM_INFORMATION<'a, 'b> {
+	pub(in crate::dbghelp) UserStreamCount: u32,
+	pub(in crate::dbghelp) UserStreamArray: *const MINIDUMP_USER_STREAM<'a>,
+	pub(in crate::dbghelp) _UserStreamArray: PhantomData<&'b [MINIDUMP_USER_STREAM<'a>]>,
+}
@@ -0,0 +1,116 @@
+use std::sync::Mutex;
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+struct FilterConf {
+	dump_path: String,
+	dump_type: co::MINIDUMP_TYPE,
+	prev_filter: Option<LPTOP_LEVEL_EXCEPTION_FILTER>,
+}
+
+static FILTER_CONF: Mutex<Option<FilterConf>> = Mutex::new(None);
+
+/// Installs, with
+/// [`SetUnhandledExceptionFilter`](crate::SetUnhandledExceptionFilter), a
+/// process-wide exception filter which writes a minidump of the current process
+/// when it crashes. The previous filter is restored when the object goes out of
+/// scope.
+///
+/// After the dump is written, the previous filter is called, if any; otherwise
+/// the exception goes on, and the system terminates the process as usual.
+///
+/// Note that a process writing its own dump while crashing is not fully
+/// reliable – a stack overflow, for example, leaves little room to work. For
+/// critical cases, prefer writing the dump from a separate watchdog process.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _crash_dump = w::CrashDumpFilter::new(
+///     "C:\\Temp\\app.dmp",
+///     co::MINIDUMP_TYPE::WithThreadInfo | co::MINIDUMP_TYPE::WithUnloadedModules,
+/// );
+///
+/// // run the application...
+/// ```
+pub struct CrashDumpFilter {
+	prev_conf: Option<FilterConf>,
+}
+
+impl Drop for CrashDumpFilter {
+	fn drop(&mut self) {
+		let mut conf = FILTER_CONF.lock().unwrap_or_else(|err| err.into_inner());
+		let cur_conf = std::mem::replace(&mut *conf, self.prev_conf.take());
+		SetUnhandledExceptionFilter(if conf.is_some() {
+			Some(crash_dump_filter) // a previous CrashDumpFilter is still alive
+		} else {
+			cur_conf.and_then(|c| c.prev_filter)
+		});
+	}
+}
+
+impl CrashDumpFilter {
+	/// Installs the exception filter, which will write the dump to the given
+	/// path, replacing any existing file.
+	#[must_use]
+	pub fn new(dump_path: &str, dump_type: co::MINIDUMP_TYPE) -> Self {
+		let mut conf = FILTER_CONF.lock().unwrap_or_else(|err| err.into_inner());
+		let prev_filter = SetUnhandledExceptionFilter(Some(crash_dump_filter));
+		let prev_filter = match &*conf {
+			Some(c) => c.prev_filter, // don't chain to ourselves
+			None => prev_filter,
+		};
+
+		Self {
+			prev_conf: conf.replace(FilterConf {
+				dump_path: dump_path.to_owned(),
+				dump_type,
+				prev_filter,
+			}),
+		}
+	}
+}
+
+extern "system" fn crash_dump_filter(exception_info: *mut EXCEPTION_POINTERS) -> i32 {
+	let conf = match FILTER_CONF.try_lock() {
+		Ok(conf) => conf,
+		Err(_) => return 0, // EXCEPTION_CONTINUE_SEARCH
+	};
+	let conf = match conf.as_ref() {
+		Some(conf) => conf,
+		None => return 0,
+	};
+
+	if let Ok((hfile, _)) = HFILE::CreateFile(
+		&conf.dump_path,
+		co::GENERIC::WRITE,
+		None,
+		None,
+		co::DISPOSITION::CREATE_ALWAYS,
+		co::FILE_ATTRIBUTE::NORMAL,
+		None,
+		None,
+		None,
+	) {
+		let mut mei = MINIDUMP_EXCEPTION_INFORMATION::default();
+		mei.ThreadId = GetCurrentThreadId();
+		mei.ExceptionPointers = exception_info;
+
+		let _ = HPROCESS::GetCurrentProcess().MiniDumpWriteDump(
+			GetCurrentProcessId(),
+			&hfile,
+			conf.dump_type,
+			Some(&mei),
+			&[],
+		); // nothing else to do if it fails, we're crashing anyway
+	}
+
+	match conf.prev_filter {
+		Some(prev_filter) => prev_filter(exception_info),
+		None => 0,
+	}
+}
@@ -0,0 +1,410 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+
+const MINIDUMP_SIGNATURE: u32 = 0x504d_444d; // "MDMP"
+const MINIDUMP_VERSION: u32 = 0xa793;
+
+/// Parser of minidump files, like those written by
+/// [`HPROCESS::MiniDumpWriteDump`](crate::prelude::dbghelp_Hprocess::MiniDumpWriteDump).
+///
+/// The parser is written in pure Rust and doesn't call any system function.
+/// Since the system libraries are linked only when targeting Windows, it can
+/// be used to triage crash dumps on other platforms too, as long as no other
+/// function of the crate is called – note that formatting a
+/// [`co::ERROR`](crate::co::ERROR) calls `FormatMessage`, so use its
+/// [`raw`](crate::co::ERROR::raw) value instead. Malformed dumps yield
+/// [`co::ERROR::BAD_FORMAT`](crate::co::ERROR::BAD_FORMAT) or
+/// [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let dump = w::MiniDumpReader::from_bytes(std::fs::read("C:\\Temp\\app.dmp")?)?;
+///
+/// if let Some(exc) = dump.exception()? {
+///     println!("Thread {} crashed with {} at {:#x}",
+///         exc.ThreadId,
+///         exc.ExceptionRecord.ExceptionCode,
+///         exc.ExceptionRecord.ExceptionAddress);
+/// }
+///
+/// for module in dump.module_list()? {
+///     println!("{:#018x} {}",
+///         module.BaseOfImage, dump.string_at(module.ModuleNameRva)?);
+/// }
+/// # w::AnyResult::Ok(())
+/// ```
+pub struct MiniDumpReader {
+	data: Vec<u8>,
+	header: MINIDUMP_HEADER,
+	directory: Vec<MINIDUMP_DIRECTORY>,
+}
+
+impl MiniDumpReader {
+	/// Parses the header and the stream directory of the given minidump file
+	/// contents.
+	pub fn from_bytes(data: Vec<u8>) -> SysResult<Self> {
+		let header = MINIDUMP_HEADER::from_bytes(&data)?;
+		if header.Signature != MINIDUMP_SIGNATURE || header.Version & 0xffff != MINIDUMP_VERSION {
+			return Err(co::ERROR::BAD_FORMAT);
+		}
+
+		let directory = (0..header.NumberOfStreams as usize)
+			.map(|i| {
+				let offset = header.StreamDirectoryRva as usize + i * 12; // sizeof(MINIDUMP_DIRECTORY)
+				MINIDUMP_DIRECTORY::from_bytes(data.get(offset..).unwrap_or_default())
+			})
+			.collect::<SysResult<Vec<_>>>()?;
+
+		Ok(Self { data, header, directory })
+	}
+
+	/// Returns the raw contents of the minidump file.
+	#[must_use]
+	pub fn data(&self) -> &[u8] {
+		&self.data
+	}
+
+	/// Returns the stream directory.
+	#[must_use]
+	pub fn directory(&self) -> &[MINIDUMP_DIRECTORY] {
+		&self.directory
+	}
+
+	/// Parses the
+	/// [`co::MINIDUMP_STREAM_TYPE::Exception`](crate::co::MINIDUMP_STREAM_TYPE::Exception)
+	/// stream, returning `None` if the dump has none.
+	pub fn exception(&self) -> SysResult<Option<MINIDUMP_EXCEPTION_STREAM>> {
+		self.stream(co::MINIDUMP_STREAM_TYPE::Exception)?
+			.map(MINIDUMP_EXCEPTION_STREAM::from_bytes)
+			.transpose()
+	}
+
+	/// Returns the header.
+	#[must_use]
+	pub const fn header(&self) -> &MINIDUMP_HEADER {
+		&self.header
+	}
+
+	/// Returns the bytes referenced by the given location, like the
+	/// [`ThreadContext`](crate::MINIDUMP_THREAD::ThreadContext) of a thread.
+	pub fn location(&self, location: &MINIDUMP_LOCATION_DESCRIPTOR) -> SysResult<&[u8]> {
+		let start = location.Rva as usize;
+		self.data
+			.get(start..start.saturating_add(location.DataSize as _))
+			.ok_or(co::ERROR::INVALID_DATA)
+	}
+
+	/// Parses the
+	/// [`co::MINIDUMP_STREAM_TYPE::ModuleList`](crate::co::MINIDUMP_STREAM_TYPE::ModuleList)
+	/// stream, returning an empty `Vec` if the dump has none.
+	pub fn module_list(&self) -> SysResult<Vec<MINIDUMP_MODULE>> {
+		self.list(co::MINIDUMP_STREAM_TYPE::ModuleList, 108, MINIDUMP_MODULE::from_bytes)
+	}
+
+	/// Returns the bytes of the first stream of the given type, or `None` if
+	/// the dump has no such stream.
+	pub fn stream(&self, stream_type: co::MINIDUMP_STREAM_TYPE) -> SysResult<Option<&[u8]>> {
+		self.directory
+			.iter()
+			.find(|dir| dir.StreamType == stream_type)
+			.map(|dir| self.location(&dir.Location))
+			.transpose()
+	}
+
+	/// Reads the `MINIDUMP_STRING` at the given offset, like the
+	/// [`ModuleNameRva`](crate::MINIDUMP_MODULE::ModuleNameRva) of a module.
+	pub fn string_at(&self, rva: u32) -> SysResult<String> {
+		let rva = rva as usize;
+		let len = u32::from_le_bytes(le_bytes(&self.data, rva)?) as usize; // in bytes
+		let bytes = self
+			.data
+			.get(rva + 4..(rva + 4).saturating_add(len))
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		let wchars = bytes
+			.chunks_exact(2)
+			.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+			.take_while(|ch| *ch != 0x0000)
+			.collect::<Vec<_>>();
+		Ok(String::from_utf16_lossy(&wchars)) // WString would allocate with GlobalAlloc
+	}
+
+	/// Parses the
+	/// [`co::MINIDUMP_STREAM_TYPE::ThreadList`](crate::co::MINIDUMP_STREAM_TYPE::ThreadList)
+	/// stream, returning an empty `Vec` if the dump has none.
+	pub fn thread_list(&self) -> SysResult<Vec<MINIDUMP_THREAD>> {
+		self.list(co::MINIDUMP_STREAM_TYPE::ThreadList, 48, MINIDUMP_THREAD::from_bytes)
+	}
+
+	fn list<T>(
+		&self,
+		stream_type: co::MINIDUMP_STREAM_TYPE,
+		entry_size: usize,
+		decode: fn(&[u8]) -> SysResult<T>,
+	) -> SysResult<Vec<T>> {
+		let stream = match self.stream(stream_type)? {
+			Some(stream) => stream,
+			None => return Ok(Vec::new()),
+		};
+
+		let count = u32::from_le_bytes(le_bytes(stream, 0)?) as usize;
+		let entries_size = count
+			.checked_mul(entry_size)
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		// Some writers align the array to 8 bytes, after the 4-byte count.
+		let start: usize = if stream.len() == entries_size.saturating_add(8) { 8 } else { 4 };
+		let entries = stream
+			.get(start..start.saturating_add(entries_size))
+			.ok_or(co::ERROR::INVALID_DATA)?;
+
+		entries.chunks_exact(entry_size).map(decode).collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const USER_STREAM: u32 = 0x1_0000; // beyond LastReserved
+
+	/// Lays out a minidump with the given streams, in order, right after the
+	/// directory.
+	fn dump(streams: &[(u32, &[u8])]) -> Vec<u8> {
+		let mut buf = Vec::new();
+		buf.extend_from_slice(&MINIDUMP_SIGNATURE.to_le_bytes());
+		buf.extend_from_slice(&(0x000a_0000 | MINIDUMP_VERSION).to_le_bytes()); // high word is implementation-specific
+		buf.extend_from_slice(&(streams.len() as u32).to_le_bytes());
+		buf.extend_from_slice(&32u32.to_le_bytes()); // StreamDirectoryRva
+		buf.extend_from_slice(&0u32.to_le_bytes()); // CheckSum
+		buf.extend_from_slice(&0x6543_2100u32.to_le_bytes()); // TimeDateStamp
+		buf.extend_from_slice(&0x2u64.to_le_bytes()); // Flags
+
+		let mut rva = 32 + streams.len() * 12;
+		for (stream_type, data) in streams {
+			buf.extend_from_slice(&stream_type.to_le_bytes());
+			buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+			buf.extend_from_slice(&(rva as u32).to_le_bytes());
+			rva += data.len();
+		}
+		for (_, data) in streams {
+			buf.extend_from_slice(data);
+		}
+		buf
+	}
+
+	/// RVA of the first stream of a dump with `num_streams` streams.
+	fn first_rva(num_streams: usize) -> u32 {
+		(32 + num_streams * 12) as _
+	}
+
+	fn minidump_string(s: &str) -> Vec<u8> {
+		let wchars = s.encode_utf16().collect::<Vec<_>>();
+		let mut buf = ((wchars.len() * 2) as u32).to_le_bytes().to_vec();
+		for ch in wchars.iter().chain(&[0]) {
+			buf.extend_from_slice(&ch.to_le_bytes()); // null terminator not counted
+		}
+		buf
+	}
+
+	fn thread_bytes(id: u32, teb: u64) -> Vec<u8> {
+		let mut buf = Vec::new();
+		for field in [id, 1, 0x20, 2] {
+			buf.extend_from_slice(&field.to_le_bytes()); // id, suspend, class, priority
+		}
+		buf.extend_from_slice(&teb.to_le_bytes());
+		buf.extend_from_slice(&0x7ff0_0000u64.to_le_bytes()); // StartOfMemoryRange
+		for field in [0x1000u32, 0x400, 0x4d0, 0x800] {
+			buf.extend_from_slice(&field.to_le_bytes()); // stack, context
+		}
+		buf
+	}
+
+	fn module_bytes(base: u64, name_rva: u32) -> Vec<u8> {
+		let mut buf = Vec::new();
+		buf.extend_from_slice(&base.to_le_bytes());
+		for field in [0x5000u32, 0xabcd, 0x1234_5678, name_rva] {
+			buf.extend_from_slice(&field.to_le_bytes());
+		}
+		buf.resize(76, 0); // VersionInfo
+		for field in [0x18u32, 0x900, 0, 0] {
+			buf.extend_from_slice(&field.to_le_bytes()); // CvRecord, MiscRecord
+		}
+		buf.resize(108, 0); // Reserved0, Reserved1
+		buf
+	}
+
+	fn list(entries: &[Vec<u8>], aligned: bool) -> Vec<u8> {
+		let mut buf = (entries.len() as u32).to_le_bytes().to_vec();
+		if aligned {
+			buf.extend_from_slice(&[0; 4]);
+		}
+		for entry in entries {
+			buf.extend_from_slice(entry);
+		}
+		buf
+	}
+
+	#[test]
+	fn header() {
+		let reader = MiniDumpReader::from_bytes(dump(&[])).unwrap();
+		let hdr = reader.header();
+		assert_eq!(hdr.Version, 0x000a_a793);
+		assert_eq!((hdr.NumberOfStreams, hdr.StreamDirectoryRva), (0, 32));
+		assert_eq!((hdr.TimeDateStamp, hdr.Flags), (0x6543_2100, 2));
+		assert!(reader.directory().is_empty());
+		assert_eq!(reader.data().len(), 32);
+	}
+
+	#[test]
+	fn header_checks() {
+		let mut buf = dump(&[]);
+		buf[0] = b'X'; // signature
+		assert_eq!(MiniDumpReader::from_bytes(buf).err(), Some(co::ERROR::BAD_FORMAT));
+
+		let mut buf = dump(&[]);
+		buf[4] = 0x92; // low word of the version
+		assert_eq!(MiniDumpReader::from_bytes(buf).err(), Some(co::ERROR::BAD_FORMAT));
+
+		let buf = dump(&[]);
+		assert_eq!(
+			MiniDumpReader::from_bytes(buf[..31].to_vec()).err(),
+			Some(co::ERROR::INVALID_DATA),
+		);
+		assert_eq!(MiniDumpReader::from_bytes(Vec::new()).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn directory() {
+		let reader = MiniDumpReader::from_bytes(dump(&[
+			(USER_STREAM, b"abc"),
+			(co::MINIDUMP_STREAM_TYPE::SystemInfo.raw(), b"defgh"),
+		]))
+		.unwrap();
+
+		let dir = reader.directory();
+		assert_eq!(dir.len(), 2);
+		assert_eq!(dir[0].StreamType.raw(), USER_STREAM);
+		assert_eq!((dir[0].Location.DataSize, dir[0].Location.Rva), (3, first_rva(2)));
+		assert_eq!(dir[1].StreamType, co::MINIDUMP_STREAM_TYPE::SystemInfo);
+		assert_eq!((dir[1].Location.DataSize, dir[1].Location.Rva), (5, first_rva(2) + 3));
+
+		assert_eq!(reader.stream(co::MINIDUMP_STREAM_TYPE::SystemInfo), Ok(Some(&b"defgh"[..])),);
+		assert_eq!(reader.stream(co::MINIDUMP_STREAM_TYPE::MemoryList), Ok(None));
+		assert!(reader.exception().unwrap().is_none());
+
+		// More streams declared than the directory holds.
+		let mut buf = dump(&[(USER_STREAM, b"")]);
+		buf[8] = 2;
+		assert_eq!(MiniDumpReader::from_bytes(buf).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn thread_list() {
+		for aligned in [false, true] {
+			let threads = list(&[thread_bytes(100, 0xa000), thread_bytes(200, 0xb000)], aligned);
+			let reader = MiniDumpReader::from_bytes(dump(&[(
+				co::MINIDUMP_STREAM_TYPE::ThreadList.raw(),
+				&threads,
+			)]))
+			.unwrap();
+
+			let threads = reader.thread_list().unwrap();
+			assert_eq!(threads.len(), 2);
+			assert_eq!((threads[0].ThreadId, threads[0].Teb), (100, 0xa000));
+			assert_eq!((threads[1].ThreadId, threads[1].Teb), (200, 0xb000));
+
+			let th = &threads[1];
+			assert_eq!((th.SuspendCount, th.PriorityClass, th.Priority), (1, 0x20, 2));
+			assert_eq!(th.Stack.StartOfMemoryRange, 0x7ff0_0000);
+			assert_eq!((th.Stack.Memory.DataSize, th.Stack.Memory.Rva), (0x1000, 0x400));
+			assert_eq!((th.ThreadContext.DataSize, th.ThreadContext.Rva), (0x4d0, 0x800));
+		}
+
+		let reader = MiniDumpReader::from_bytes(dump(&[])).unwrap();
+		assert!(reader.thread_list().unwrap().is_empty()); // no stream
+	}
+
+	#[test]
+	fn module_list() {
+		let name = minidump_string("C:\\Program Files\\app.exe");
+		for aligned in [false, true] {
+			let modules = list(&[module_bytes(0x1_4000_0000, first_rva(2))], aligned);
+			let reader = MiniDumpReader::from_bytes(dump(&[
+				(USER_STREAM, &name),
+				(co::MINIDUMP_STREAM_TYPE::ModuleList.raw(), &modules),
+			]))
+			.unwrap();
+
+			let modules = reader.module_list().unwrap();
+			assert_eq!(modules.len(), 1);
+			let module = &modules[0];
+			assert_eq!((module.BaseOfImage, module.SizeOfImage), (0x1_4000_0000, 0x5000));
+			assert_eq!((module.CheckSum, module.TimeDateStamp), (0xabcd, 0x1234_5678));
+			assert_eq!((module.CvRecord.DataSize, module.CvRecord.Rva), (0x18, 0x900));
+			assert_eq!(
+				reader.string_at(module.ModuleNameRva).unwrap(),
+				"C:\\Program Files\\app.exe"
+			);
+		}
+	}
+
+	#[test]
+	fn string_at() {
+		let name = minidump_string("ação 😀");
+		let reader = MiniDumpReader::from_bytes(dump(&[(USER_STREAM, &name)])).unwrap();
+		let rva = first_rva(1);
+		assert_eq!(reader.string_at(rva).unwrap(), "ação 😀");
+
+		let empty = minidump_string("");
+		let reader = MiniDumpReader::from_bytes(dump(&[(USER_STREAM, &empty)])).unwrap();
+		assert_eq!(reader.string_at(rva).unwrap(), "");
+
+		let mut long = minidump_string("abc");
+		long[0] = 200; // length past the end of the data
+		let reader = MiniDumpReader::from_bytes(dump(&[(USER_STREAM, &long)])).unwrap();
+		assert_eq!(reader.string_at(rva).err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(reader.string_at(rva + 100).err(), Some(co::ERROR::INVALID_DATA));
+		assert_eq!(reader.string_at(u32::MAX).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn out_of_range() {
+		let mut buf = dump(&[(co::MINIDUMP_STREAM_TYPE::ThreadList.raw(), &[0; 8])]);
+		buf[36..40].copy_from_slice(&9u32.to_le_bytes()); // DataSize past the end
+		let reader = MiniDumpReader::from_bytes(buf).unwrap();
+		assert_eq!(
+			reader.stream(co::MINIDUMP_STREAM_TYPE::ThreadList).err(),
+			Some(co::ERROR::INVALID_DATA),
+		);
+		assert_eq!(reader.thread_list().err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut buf = dump(&[(co::MINIDUMP_STREAM_TYPE::ThreadList.raw(), &[0; 8])]);
+		buf[40..44].copy_from_slice(&u32::MAX.to_le_bytes()); // Rva past the end
+		let reader = MiniDumpReader::from_bytes(buf).unwrap();
+		assert_eq!(reader.thread_list().err(), Some(co::ERROR::INVALID_DATA));
+
+		let location = MINIDUMP_LOCATION_DESCRIPTOR { DataSize: u32::MAX, Rva: u32::MAX };
+		assert_eq!(reader.location(&location).err(), Some(co::ERROR::INVALID_DATA));
+
+		// Count of entries larger than the stream.
+		let mut threads = list(&[thread_bytes(1, 0)], false);
+		threads[0] = 2;
+		let reader = MiniDumpReader::from_bytes(dump(&[(
+			co::MINIDUMP_STREAM_TYPE::ThreadList.raw(),
+			&threads,
+		)]))
+		.unwrap();
+		assert_eq!(reader.thread_list().err(), Some(co::ERROR::INVALID_DATA));
+
+		let modules = u32::MAX.to_le_bytes();
+		let reader = MiniDumpReader::from_bytes(dump(&[(
+			co::MINIDUMP_STREAM_TYPE::ModuleList.raw(),
+			&modules,
+		)]))
+		.unwrap();
+		assert_eq!(reader.module_list().err(), Some(co::ERROR::INVALID_DATA));
+	}
+}
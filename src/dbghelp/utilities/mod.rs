@@ -0,0 +1,5 @@
+mod crash_dump_filter;
+mod mini_dump_reader;
+
+pub use crash_dump_filter::CrashDumpFilter;
+pub use mini_dump_reader::MiniDumpReader;
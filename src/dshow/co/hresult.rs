@@ -1,159 +1,314 @@
 use crate::co::*;
 
-const_values_pub! { HRESULT;
+const_values_pub_msgs! { HRESULT, HRESULT_MSGS_DSHOW;
+	/// An invalid media type was specified.
 	VFW_E_INVALIDMEDIATYPE 0x8004_0200
+	/// An invalid media subtype was specified.
 	VFW_E_INVALIDSUBTYPE 0x8004_0201
+	/// This object can only be created as an aggregated object.
 	VFW_E_NEED_OWNER 0x8004_0202
+	/// The enumerator has become invalid.
 	VFW_E_ENUM_OUT_OF_SYNC 0x8004_0203
+	/// At least one of the pins involved in the operation is already connected.
 	VFW_E_ALREADY_CONNECTED 0x8004_0204
+	/// This operation cannot be performed because the filter is active.
 	VFW_E_FILTER_ACTIVE 0x8004_0205
+	/// One of the specified pins supports no media types.
 	VFW_E_NO_TYPES 0x8004_0206
+	/// There is no common media type between these pins.
 	VFW_E_NO_ACCEPTABLE_TYPES 0x8004_0207
+	/// Two pins of the same direction cannot be connected together.
 	VFW_E_INVALID_DIRECTION 0x8004_0208
+	/// The operation cannot be performed because the pins are not connected.
 	VFW_E_NOT_CONNECTED 0x8004_0209
+	/// No sample buffer allocator is available.
 	VFW_E_NO_ALLOCATOR 0x8004_020a
+	/// A run-time error occurred.
 	VFW_E_RUNTIME_ERROR 0x8004_020b
+	/// No buffer space has been set.
 	VFW_E_BUFFER_NOTSET 0x8004_020c
+	/// The buffer is not big enough.
 	VFW_E_BUFFER_OVERFLOW 0x8004_020d
+	/// An invalid alignment was specified.
 	VFW_E_BADALIGN 0x8004_020e
+	/// Cannot change allocated memory while the filter is active.
 	VFW_E_ALREADY_COMMITTED 0x8004_020f
+	/// One or more buffers are still active.
 	VFW_E_BUFFERS_OUTSTANDING 0x8004_0210
+	/// Cannot allocate a sample when the allocator is not active.
 	VFW_E_NOT_COMMITTED 0x8004_0211
+	/// Cannot allocate memory because no size has been set.
 	VFW_E_SIZENOTSET 0x8004_0212
+	/// Cannot lock for synchronization because no clock has been defined.
 	VFW_E_NO_CLOCK 0x8004_0213
+	/// Quality messages could not be sent because no quality sink has been defined.
 	VFW_E_NO_SINK 0x8004_0214
+	/// A required interface has not been implemented.
 	VFW_E_NO_INTERFACE 0x8004_0215
+	/// An object or name was not found.
 	VFW_E_NOT_FOUND 0x8004_0216
+	/// No combination of intermediate filters could be found to make the connection.
 	VFW_E_CANNOT_CONNECT 0x8004_0217
+	/// No combination of filters could be found to render the stream.
 	VFW_E_CANNOT_RENDER 0x8004_0218
+	/// Could not change formats dynamically.
 	VFW_E_CHANGING_FORMAT 0x8004_0219
+	/// No color key has been set.
 	VFW_E_NO_COLOR_KEY_SET 0x8004_021a
+	/// Current pin connection is not using the IOverlay transport.
 	VFW_E_NOT_OVERLAY_CONNECTION 0x8004_021b
+	/// Current pin connection is not using the IMemInputPin transport.
 	VFW_E_NOT_SAMPLE_CONNECTION 0x8004_021c
+	/// Setting a color key would conflict with the palette already set.
 	VFW_E_PALETTE_SET 0x8004_021d
+	/// Setting a palette would conflict with the color key already set.
 	VFW_E_COLOR_KEY_SET 0x8004_021e
+	/// No matching color key is available.
 	VFW_E_NO_COLOR_KEY_FOUND 0x8004_021f
+	/// No palette is available.
 	VFW_E_NO_PALETTE_AVAILABLE 0x8004_0220
+	/// Display does not use a palette.
 	VFW_E_NO_DISPLAY_PALETTE 0x8004_0221
+	/// Too many colors for the current display settings.
 	VFW_E_TOO_MANY_COLORS 0x8004_0222
+	/// The state changed while waiting to process the sample.
 	VFW_E_STATE_CHANGED 0x8004_0223
+	/// The operation could not be performed because the filter is not stopped.
 	VFW_E_NOT_STOPPED 0x8004_0224
+	/// The operation could not be performed because the filter is not paused.
 	VFW_E_NOT_PAUSED 0x8004_0225
+	/// The operation could not be performed because the filter is not running.
 	VFW_E_NOT_RUNNING 0x8004_0226
+	/// The operation could not be performed because the filter is in the wrong state.
 	VFW_E_WRONG_STATE 0x8004_0227
+	/// The sample start time is after the sample end time.
 	VFW_E_START_TIME_AFTER_END 0x8004_0228
+	/// The supplied rectangle is invalid.
 	VFW_E_INVALID_RECT 0x8004_0229
+	/// This pin cannot use the supplied media type.
 	VFW_E_TYPE_NOT_ACCEPTED 0x8004_022a
+	/// This sample cannot be rendered.
 	VFW_E_SAMPLE_REJECTED 0x8004_022b
+	/// This sample cannot be rendered because the end of the stream has been reached.
 	VFW_E_SAMPLE_REJECTED_EOS 0x8004_022c
+	/// An attempt to add a filter with a duplicate name failed.
 	VFW_E_DUPLICATE_NAME 0x8004_022d
+	/// An attempt to add a filter with a duplicate name succeeded with a modified name.
 	VFW_S_DUPLICATE_NAME 0x0004_022d
+	/// A time-out has expired.
 	VFW_E_TIMEOUT 0x8004_022e
+	/// The file format is invalid.
 	VFW_E_INVALID_FILE_FORMAT 0x8004_022f
+	/// The list has already been exhausted.
 	VFW_E_ENUM_OUT_OF_RANGE 0x8004_0230
+	/// The filter graph is circular.
 	VFW_E_CIRCULAR_GRAPH 0x8004_0231
+	/// Updates are not allowed in this state.
 	VFW_E_NOT_ALLOWED_TO_SAVE 0x8004_0232
+	/// An attempt was made to queue a command for a time in the past.
 	VFW_E_TIME_ALREADY_PASSED 0x8004_0233
+	/// The queued command has already been canceled.
 	VFW_E_ALREADY_CANCELLED 0x8004_0234
+	/// Cannot render the file because it is corrupt.
 	VFW_E_CORRUPT_GRAPH_FILE 0x8004_0235
+	/// An overlay advise link already exists.
 	VFW_E_ADVISE_ALREADY_SET 0x8004_0236
+	/// The state transition has not completed.
 	VFW_S_STATE_INTERMEDIATE 0x0004_0237
+	/// No full-screen modes are available.
 	VFW_E_NO_MODEX_AVAILABLE 0x8004_0238
+	/// This Advise cannot be canceled because it was not successfully set.
 	VFW_E_NO_ADVISE_SET 0x8004_0239
+	/// A full-screen mode is not available.
 	VFW_E_NO_FULLSCREEN 0x8004_023a
+	/// Cannot call IVideoWindow methods while in full-screen mode.
 	VFW_E_IN_FULLSCREEN_MODE 0x8004_023b
+	/// The media type of this file is not recognized.
 	VFW_E_UNKNOWN_FILE_TYPE 0x8004_0240
+	/// The source filter for this file could not be loaded.
 	VFW_E_CANNOT_LOAD_SOURCE_FILTER 0x8004_0241
+	/// Some of the streams in this movie are in an unsupported format.
 	VFW_S_PARTIAL_RENDER 0x0004_0242
+	/// A file appeared to be incomplete.
 	VFW_E_FILE_TOO_SHORT 0x8004_0243
+	/// The version number of the file is invalid.
 	VFW_E_INVALID_FILE_VERSION 0x8004_0244
+	/// The file contained some property settings that were not used.
 	VFW_S_SOME_DATA_IGNORED 0x0004_0245
+	/// Some connections have failed and have been deferred.
 	VFW_S_CONNECTIONS_DEFERRED 0x0004_0246
+	/// This file is corrupt: it contains an invalid class identifier.
 	VFW_E_INVALID_CLSID 0x8004_0247
+	/// This file is corrupt: it contains an invalid media type.
 	VFW_E_INVALID_MEDIA_TYPE 0x8004_0248
+	/// A registry entry is corrupt.
 	VFW_E_BAD_KEY 0x8004_03f2
+	/// The end of the list has been reached.
 	VFW_S_NO_MORE_ITEMS 0x0004_0103
+	/// No time stamp has been set for this sample.
 	VFW_E_SAMPLE_TIME_NOT_SET 0x8004_0249
+	/// The resource specified is no longer needed.
 	VFW_S_RESOURCE_NOT_NEEDED 0x0004_0250
+	/// No media time stamp has been set for this sample.
 	VFW_E_MEDIA_TIME_NOT_SET 0x8004_0251
+	/// No media time format has been selected.
 	VFW_E_NO_TIME_FORMAT_SET 0x8004_0252
+	/// Cannot change balance because audio device is mono only.
 	VFW_E_MONO_AUDIO_HW 0x8004_0253
+	/// A connection could not be made with the media type in the persistent graph, but has been made with a negotiated media type.
 	VFW_S_MEDIA_TYPE_IGNORED 0x0004_0254
+	/// Cannot play back the video stream: no suitable decompressor could be found.
 	VFW_E_NO_DECOMPRESSOR 0x8004_0255
+	/// Cannot play back the audio stream: no audio hardware is available, or the hardware is not responding.
 	VFW_E_NO_AUDIO_HARDWARE 0x8004_0256
+	/// Cannot play back the video stream: no suitable decompressor could be found.
 	VFW_S_VIDEO_NOT_RENDERED 0x0004_0257
+	/// Cannot play back the audio stream: no audio hardware is available.
 	VFW_S_AUDIO_NOT_RENDERED 0x0004_0258
+	/// Cannot play back the video stream: format 'RPZA' is not supported.
 	VFW_E_RPZA 0x8004_0259
+	/// Cannot play back the video stream: format 'RPZA' is not supported.
 	VFW_S_RPZA 0x0004_025a
+	/// DirectShow cannot play MPEG movies on this processor.
 	VFW_E_PROCESSOR_NOT_SUITABLE 0x8004_025b
+	/// Cannot play back the audio stream: the audio format is not supported.
 	VFW_E_UNSUPPORTED_AUDIO 0x8004_025c
+	/// Cannot play back the video stream: the video format is not supported.
 	VFW_E_UNSUPPORTED_VIDEO 0x8004_025d
+	/// DirectShow cannot play this video stream because it falls outside the constrained standard.
 	VFW_E_MPEG_NOT_CONSTRAINED 0x8004_025e
+	/// Cannot perform the requested function on an object that is not in the filter graph.
 	VFW_E_NOT_IN_GRAPH 0x8004_025f
+	/// The value returned had to be estimated. Its accuracy can not be guaranteed.
 	VFW_S_ESTIMATED 0x0004_0260
+	/// Cannot get or set time related information on an object that is using a time format of TIME_FORMAT_NONE.
 	VFW_E_NO_TIME_FORMAT 0x8004_0261
+	/// The connection cannot be made because the stream is read only and the filter alters the data.
 	VFW_E_READ_ONLY 0x8004_0262
+	/// This success code is reserved for internal purposes within ActiveMovie.
 	VFW_S_RESERVED 0x0004_0263
+	/// The buffer is not full enough.
 	VFW_E_BUFFER_UNDERFLOW 0x8004_0264
+	/// Cannot play back the file: the format is not supported.
 	VFW_E_UNSUPPORTED_STREAM 0x8004_0265
+	/// Pins cannot connect due to not supporting the same transport.
 	VFW_E_NO_TRANSPORT 0x8004_0266
+	/// The stream has been turned off.
 	VFW_S_STREAM_OFF 0x0004_0267
+	/// The graph can't be cued because of lack of or corrupt data.
 	VFW_S_CANT_CUE 0x0004_0268
+	/// The Video CD can't be read correctly by the device or is the data is corrupt.
 	VFW_E_BAD_VIDEOCD 0x8004_0269
+	/// The stop time for the sample was not set.
 	VFW_S_NO_STOP_TIME 0x0004_0270
+	/// There is not enough Video Memory at this display resolution and number of colors. Reducing resolution might help.
 	VFW_E_OUT_OF_VIDEO_MEMORY 0x8004_0271
+	/// The VideoPort connection negotiation process has failed.
 	VFW_E_VP_NEGOTIATION_FAILED 0x8004_0272
+	/// Either DirectDraw has not been installed or the Video Card capabilities are not suitable. Make sure the display is not in 16 color mode.
 	VFW_E_DDRAW_CAPS_NOT_SUITABLE 0x8004_0273
+	/// No VideoPort hardware is available, or the hardware is not responding.
 	VFW_E_NO_VP_HARDWARE 0x8004_0274
+	/// No Capture hardware is available, or the hardware is not responding.
 	VFW_E_NO_CAPTURE_HARDWARE 0x8004_0275
+	/// This User Operation is inhibited by DVD Content at this time.
 	VFW_E_DVD_OPERATION_INHIBITED 0x8004_0276
+	/// This Operation is not permitted in the current domain.
 	VFW_E_DVD_INVALIDDOMAIN 0x8004_0277
+	/// The specified button is invalid or is not present at the current time, or there is no button present at the specified location.
 	VFW_E_DVD_NO_BUTTON 0x8004_0278
+	/// DVD-Video playback graph has not been built yet.
 	VFW_E_DVD_GRAPHNOTREADY 0x8004_0279
+	/// DVD-Video playback graph building failed.
 	VFW_E_DVD_RENDERFAIL 0x8004_027a
+	/// DVD-Video playback graph could not be built due to insufficient decoders.
 	VFW_E_DVD_DECNOTENOUGH 0x8004_027b
+	/// Version number of DirectDraw not suitable. Make sure to install dx5 or higher version.
 	VFW_E_DDRAW_VERSION_NOT_SUITABLE 0x8004_027c
+	/// Copy protection cannot be enabled. Please make sure any other copy protected content is not being shown now.
 	VFW_E_COPYPROT_FAILED 0x8004_027d
+	/// There was no preview pin available, so the capture pin output is being split to provide both capture and preview.
 	VFW_S_NOPREVIEWPIN 0x0004_027e
+	/// This object cannot be used anymore as its time has expired.
 	VFW_E_TIME_EXPIRED 0x8004_027f
+	/// The current title was not a sequential set of chapters (PGC), and the returned timing information might not be continuous.
 	VFW_S_DVD_NON_ONE_SEQUENTIAL 0x0004_0280
+	/// The operation cannot be performed at the current playback speed.
 	VFW_E_DVD_WRONG_SPEED 0x8004_0281
+	/// The specified menu doesn't exist.
 	VFW_E_DVD_MENU_DOES_NOT_EXIST 0x8004_0282
+	/// The specified command was either cancelled or no longer exists.
 	VFW_E_DVD_CMD_CANCELLED 0x8004_0283
+	/// The data did not contain a recognized version.
 	VFW_E_DVD_STATE_WRONG_VERSION 0x8004_0284
+	/// The state data was corrupt.
 	VFW_E_DVD_STATE_CORRUPT 0x8004_0285
+	/// The state data is from a different disc.
 	VFW_E_DVD_STATE_WRONG_DISC 0x8004_0286
+	/// The region was not compatible with the current drive.
 	VFW_E_DVD_INCOMPATIBLE_REGION 0x8004_0287
+	/// The requested DVD stream attribute does not exist.
 	VFW_E_DVD_NO_ATTRIBUTES 0x8004_0288
+	/// Currently there is no GoUp (Annex J user function) program chain (PGC).
 	VFW_E_DVD_NO_GOUP_PGC 0x8004_0289
+	/// The current parental level was too low.
 	VFW_E_DVD_LOW_PARENTAL_LEVEL 0x8004_028a
+	/// The current audio is not karaoke content.
 	VFW_E_DVD_NOT_IN_KARAOKE_MODE 0x8004_028b
+	/// The audio stream did not contain sufficient information to determine the contents of each channel.
 	VFW_S_DVD_CHANNEL_CONTENTS_NOT_AVAILABLE 0x0004_028c
+	/// The seek into the movie was not frame accurate.
 	VFW_S_DVD_NOT_ACCURATE 0x0004_028d
+	/// Frame step is not supported on this configuration.
 	VFW_E_FRAME_STEP_UNSUPPORTED 0x8004_028e
+	/// The specified stream is disabled and cannot be selected.
 	VFW_E_DVD_STREAM_DISABLED 0x8004_028f
+	/// The operation depends on the current title number, however the navigator has not yet entered the VTSM or the title domains, so the 'current' title index is unknown.
 	VFW_E_DVD_TITLE_UNKNOWN 0x8004_0290
+	/// The specified path does not point to a valid DVD disc.
 	VFW_E_DVD_INVALID_DISC 0x8004_0291
+	/// There is currently no resume information.
 	VFW_E_DVD_NO_RESUME_INFORMATION 0x8004_0292
+	/// This thread has already blocked this output pin. There is no need to call IPinFlowControl::Block() again.
 	VFW_E_PIN_ALREADY_BLOCKED_ON_THIS_THREAD 0x8004_0293
+	/// IPinFlowControl::Block() has been called on another thread. The current thread cannot make any assumptions about this pin's block state.
 	VFW_E_PIN_ALREADY_BLOCKED 0x8004_0294
+	/// An operation failed due to a certification failure.
 	VFW_E_CERTIFICATION_FAILURE 0x8004_0295
+	/// The VMR has not yet created a mixing component. That is, IVMRFilterConfig::SetNumberofStreams has not yet been called.
 	VFW_E_VMR_NOT_IN_MIXER_MODE 0x8004_0296
+	/// The application has not yet provided the VMR filter with a valid allocator-presenter object.
 	VFW_E_VMR_NO_AP_SUPPLIED 0x8004_0297
+	/// The VMR could not find any de-interlacing hardware on the current display device.
 	VFW_E_VMR_NO_DEINTERLACE_HW 0x8004_0298
+	/// The VMR could not find any ProcAmp hardware on the current display device.
 	VFW_E_VMR_NO_PROCAMP_HW 0x8004_0299
+	/// VMR9 does not work with VPE-based hardware decoders.
 	VFW_E_DVD_VMR9_INCOMPATIBLEDEC 0x8004_029a
+	/// The current display device does not support Content Output Protection Protocol (COPP) H/W.
 	VFW_E_NO_COPP_HW 0x8004_029b
+	/// The command could not be executed because it would block.
 	VFW_E_DVD_NONBLOCKING 0x8004_029c
+	/// DVD Navigator cannot be used with more than one renderer in the filter graph.
 	VFW_E_DVD_TOO_MANY_RENDERERS_IN_FILTER_GRAPH 0x8004_029d
+	/// DVD Navigator requires the Enhanced Video Renderer in the filter graph.
 	VFW_E_DVD_NON_EVR_RENDERER_IN_FILTER_GRAPH 0x8004_029e
+	/// The display resolution is not compatible with DVD playback.
 	VFW_E_DVD_RESOLUTION_ERROR 0x8004_029f
+	/// The specified property set is not supported.
 	E_PROP_SET_UNSUPPORTED 0x8007_0492
+	/// The specified property ID is not supported for the specified property set.
 	E_PROP_ID_UNSUPPORTED 0x8007_0490
+	/// Parameter has a linear range.
 	VFW_E_CODECAPI_LINEAR_RANGE 0x8004_0310
+	/// Parameter is enumerated. It has no linear range.
 	VFW_E_CODECAPI_ENUMERATED 0x8004_0311
+	/// Parameter does not have a default value.
 	VFW_E_CODECAPI_NO_DEFAULT 0x8004_0313
+	/// Parameter does not have a current value.
 	VFW_E_CODECAPI_NO_CURRENT_VALUE 0x8004_0314
+	/// The specified chapter does not exist.
 	VFW_E_DVD_CHAPTER_DOES_NOT_EXIST 0x8004_0315
+	/// The graph was built, but with some rendering errors.
 	VFW_S_DVD_RENDER_STATUS 0x0004_0320
 }
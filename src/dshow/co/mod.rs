@@ -4,3 +4,6 @@ mod hresult;
 
 pub use consts::*;
 pub use guids::*;
+
+#[cfg(feature = "msg-table")]
+pub(crate) use hresult::HRESULT_MSGS_DSHOW;
@@ -1,49 +1,94 @@
 use crate::co::*;
 
-const_values_pub! { HRESULT;
+const_values_pub_msgs! { HRESULT, HRESULT_MSGS_DXGI;
+	/// The Present operation was invisible to the user.
 	DXGI_STATUS_OCCLUDED 0x087a_0001
+	/// The Present operation was partially invisible to the user.
 	DXGI_STATUS_CLIPPED 0x087a_0002
+	/// The driver is requesting that the DXGI runtime not use shared resources to communicate with the Desktop Window Manager.
 	DXGI_STATUS_NO_REDIRECTION 0x087a_0004
+	/// The Present operation was not visible because the Windows session has switched to another desktop (for example, ctrl-alt-del).
 	DXGI_STATUS_NO_DESKTOP_ACCESS 0x087a_0005
+	/// The Present operation was not visible because the target monitor was being used for some other purpose.
 	DXGI_STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE 0x087a_0006
+	/// The Present operation was not visible because the display mode changed. DXGI will have re-attempted the presentation.
 	DXGI_STATUS_MODE_CHANGED 0x087a_0007
+	/// The Present operation was not visible because another Direct3D device was attempting to take fullscreen mode at the time.
 	DXGI_STATUS_MODE_CHANGE_IN_PROGRESS 0x087a_0008
+	/// The application made a call that is invalid. Either the parameters of the call or the state of some object was incorrect. Enable the D3D debug layer in order to see details via debug messages.
 	DXGI_ERROR_INVALID_CALL 0x887a_0001
+	/// The object was not found. If calling IDXGIFactory::EnumAdaptes, there is no adapter with the specified ordinal.
 	DXGI_ERROR_NOT_FOUND 0x887a_0002
+	/// The caller did not supply a sufficiently large buffer.
 	DXGI_ERROR_MORE_DATA 0x887a_0003
+	/// The specified device interface or feature level is not supported on this system.
 	DXGI_ERROR_UNSUPPORTED 0x887a_0004
+	/// The GPU device instance has been suspended. Use GetDeviceRemovedReason to determine the appropriate action.
 	DXGI_ERROR_DEVICE_REMOVED 0x887a_0005
+	/// The GPU will not respond to more commands, most likely because of an invalid command passed by the calling application.
 	DXGI_ERROR_DEVICE_HUNG 0x887a_0006
+	/// The GPU will not respond to more commands, most likely because some other application submitted invalid commands. The calling application should re-create the device and continue.
 	DXGI_ERROR_DEVICE_RESET 0x887a_0007
+	/// The GPU was busy at the moment when the call was made, and the call was neither executed nor scheduled.
 	DXGI_ERROR_WAS_STILL_DRAWING 0x887a_000a
+	/// An event (such as power cycle) interrupted the gathering of presentation statistics. Any previous statistics should be considered invalid.
 	DXGI_ERROR_FRAME_STATISTICS_DISJOINT 0x887a_000b
+	/// Fullscreen mode could not be achieved because the specified output was already in use.
 	DXGI_ERROR_GRAPHICS_VIDPN_SOURCE_IN_USE 0x887a_000c
+	/// An internal issue prevented the driver from carrying out the specified operation. The driver's state is probably suspect, and the application should not continue.
 	DXGI_ERROR_DRIVER_INTERNAL_ERROR 0x887a_0020
+	/// A global counter resource was in use, and the specified counter cannot be used by this Direct3D device at this time.
 	DXGI_ERROR_NONEXCLUSIVE 0x887a_0021
+	/// A resource is not available at the time of the call, but may become available later.
 	DXGI_ERROR_NOT_CURRENTLY_AVAILABLE 0x887a_0022
+	/// The application's remote device has been removed due to session disconnect or network disconnect. The application should call IDXGIFactory1::IsCurrent to find out when the remote device becomes available again.
 	DXGI_ERROR_REMOTE_CLIENT_DISCONNECTED 0x887a_0023
+	/// The device has been removed during a remote session because the remote computer ran out of memory.
 	DXGI_ERROR_REMOTE_OUTOFMEMORY 0x887a_0024
+	/// The keyed mutex was abandoned.
 	DXGI_ERROR_ACCESS_LOST 0x887a_0026
+	/// The timeout value has elapsed and the resource is not yet available.
 	DXGI_ERROR_WAIT_TIMEOUT 0x887a_0027
+	/// The output duplication has been turned off because the Windows session ended or was disconnected. This happens when a remote user disconnects, or when "switch user" is used locally.
 	DXGI_ERROR_SESSION_DISCONNECTED 0x887a_0028
+	/// The DXGI output (monitor) to which the swapchain content was restricted, has been disconnected or changed.
 	DXGI_ERROR_RESTRICT_TO_OUTPUT_STALE 0x887a_0029
+	/// DXGI is unable to provide content protection on the swapchain. This is typically caused by an older driver, or by the application using a swapchain that is incompatible with content protection.
 	DXGI_ERROR_CANNOT_PROTECT_CONTENT 0x887a_002a
+	/// The application is trying to use a resource to which it does not have the required access privileges. This is most commonly caused by writing to a shared resource with read-only access.
 	DXGI_ERROR_ACCESS_DENIED 0x887a_002b
+	/// The application is trying to create a shared handle using a name that is already associated with some other resource.
 	DXGI_ERROR_NAME_ALREADY_EXISTS 0x887a_002c
+	/// The application requested an operation that depends on an SDK component that is missing or mismatched.
 	DXGI_ERROR_SDK_COMPONENT_MISSING 0x887a_002d
+	/// The DXGI objects that the application has created are no longer current and need to be recreated for this operation to be performed.
 	DXGI_ERROR_NOT_CURRENT 0x887a_002e
+	/// Insufficient HW protected memory exits for proper function.
 	DXGI_ERROR_HW_PROTECTION_OUTOFMEMORY 0x887a_0030
+	/// Creating this device would violate the process's dynamic code policy.
 	DXGI_ERROR_DYNAMIC_CODE_POLICY_VIOLATION 0x887a_0031
+	/// The operation failed because the compositor is not in control of the output.
 	DXGI_ERROR_NON_COMPOSITED_UI 0x887a_0032
+	/// The swapchain has become unoccluded.
 	DXGI_STATUS_UNOCCLUDED 0x087a_0009
+	/// The adapter did not have access to the required resources to complete the Desktop Duplication Present() call, the Present() call needs to be made again.
 	DXGI_STATUS_DDA_WAS_STILL_DRAWING 0x087a_000a
+	/// An on-going mode change prevented completion of the call. The call may succeed if attempted later.
 	DXGI_ERROR_MODE_CHANGE_IN_PROGRESS 0x887a_0025
+	/// The present succeeded but the caller should present again on the next V-sync, even if there are no changes to the content.
 	DXGI_STATUS_PRESENT_REQUIRED 0x087a_002f
+	/// The cache is corrupt and either could not be opened or could not be reset.
 	DXGI_ERROR_CACHE_CORRUPT 0x887a_0033
+	/// This entry would cause the cache to exceed its quota. On a load operation, this may indicate exceeding the maximum in-memory size.
 	DXGI_ERROR_CACHE_FULL 0x887a_0034
+	/// A cache entry was found, but the key provided does not match the key stored in the entry.
 	DXGI_ERROR_CACHE_HASH_COLLISION 0x887a_0035
+	/// The desired element already exists.
 	DXGI_ERROR_ALREADY_EXISTS 0x887a_0036
+	/// The GPU was busy when the operation was requested.
 	DXGI_DDI_ERR_WASSTILLDRAWING 0x887b_0001
+	/// The driver has rejected the creation of this resource.
 	DXGI_DDI_ERR_UNSUPPORTED 0x887b_0002
+	/// The GPU counter was in use by another process or d3d device when application requested access to it.
 	DXGI_DDI_ERR_NONEXCLUSIVE 0x887b_0003
 }
@@ -19,17 +19,21 @@ fn cache_dpi() {
 /// Returns the value adjusted according to the current horizontal system DPI,
 /// retrieved with
 /// [`HDC::GetDeviceCaps`](crate::prelude::gdi_Hdc::GetDeviceCaps).
+///
+/// Windows and controls created with system DPI values are rescaled when moved
+/// to a monitor with a different DPI; to query the current DPI of a window, use
+/// [`GuiWindow::dpi`](crate::prelude::GuiWindow::dpi).
 pub fn dpi_x(x_val: i32) -> i32 {
 	cache_dpi();
-	MulDiv(x_val.into(), unsafe { DPI }.0, 96)
+	MulDiv(x_val, unsafe { DPI }.0, 96)
 }
 
 /// Returns the value adjusted according to the current vertical system DPI,
 /// retrieved with
 /// [`HDC::GetDeviceCaps`](crate::prelude::gdi_Hdc::GetDeviceCaps).
-pub fn dpi_y(x_val: i32) -> i32 {
+pub fn dpi_y(y_val: i32) -> i32 {
 	cache_dpi();
-	MulDiv(x_val.into(), unsafe { DPI }.0, 96)
+	MulDiv(y_val, unsafe { DPI }.1, 96)
 }
 
 /// Returns the values adjusted according to the current horizontal and vertical
@@ -38,3 +42,11 @@ pub fn dpi_y(x_val: i32) -> i32 {
 pub fn dpi(x_val: i32, y_val: i32) -> (i32, i32) {
 	(dpi_x(x_val), dpi_y(y_val))
 }
+
+/// Returns the horizontal system DPI, to which the values returned by
+/// [`dpi_x`](crate::gui::dpi_x) are relative.
+#[must_use]
+pub(in crate::gui) fn system_dpi() -> u32 {
+	cache_dpi();
+	unsafe { DPI }.0 as _
+}
@@ -0,0 +1,106 @@
+use crate::decl::*;
+
+/// Scales a value from one DPI to another, rounding half away from zero, like
+/// [`MulDiv`](crate::MulDiv) does.
+///
+/// If `from_dpi` is zero, the value is returned unchanged.
+#[must_use]
+pub(in crate::gui) const fn scale(val: i32, from_dpi: u32, to_dpi: u32) -> i32 {
+	if from_dpi == 0 || from_dpi == to_dpi {
+		return val;
+	}
+	let num = val as i64 * to_dpi as i64;
+	let den = from_dpi as i64;
+	let half = den / 2;
+	(if num >= 0 { (num + half) / den } else { (num - half) / den }) as i32
+}
+
+/// Scales both dimensions of a `SIZE`.
+#[must_use]
+pub(in crate::gui) const fn scale_size(sz: SIZE, from_dpi: u32, to_dpi: u32) -> SIZE {
+	SIZE::new(scale(sz.cx, from_dpi, to_dpi), scale(sz.cy, from_dpi, to_dpi))
+}
+
+/// Scales the four edges of a `RECT`, so adjacent rectangles remain adjacent
+/// after scaling.
+#[must_use]
+pub(in crate::gui) const fn scale_rect(rc: RECT, from_dpi: u32, to_dpi: u32) -> RECT {
+	RECT {
+		left: scale(rc.left, from_dpi, to_dpi),
+		top: scale(rc.top, from_dpi, to_dpi),
+		right: scale(rc.right, from_dpi, to_dpi),
+		bottom: scale(rc.bottom, from_dpi, to_dpi),
+	}
+}
+
+/// Scales the `lfHeight` of a [`LOGFONT`](crate::LOGFONT), keeping its sign,
+/// which distinguishes cell height from character height. A non-zero height
+/// never becomes zero, which would mean the default font height.
+#[must_use]
+pub(in crate::gui) const fn scale_font_height(height: i32, from_dpi: u32, to_dpi: u32) -> i32 {
+	let scaled = scale(height, from_dpi, to_dpi);
+	if height != 0 && scaled == 0 {
+		height.signum()
+	} else {
+		scaled
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn identity() {
+		for val in [i32::MIN, -7, 0, 1, 13, i32::MAX] {
+			assert_eq!(scale(val, 96, 96), val);
+			assert_eq!(scale(val, 0, 144), val); // zero source DPI is ignored
+		}
+		let rc = RECT { left: -3, top: 5, right: 7, bottom: 11 };
+		assert!(scale_rect(rc, 120, 120) == rc);
+		assert!(scale_size(SIZE::new(3, -4), 144, 144) == SIZE::new(3, -4));
+	}
+
+	#[test]
+	fn rounding() {
+		assert_eq!(scale(100, 96, 144), 150);
+		assert_eq!(scale(1, 96, 144), 2); // 1.5 rounds up
+		assert_eq!(scale(3, 96, 120), 4); // 3.75
+		assert_eq!(scale(5, 96, 120), 6); // 6.25
+		assert_eq!(scale(1, 96, 48), 1); // 0.5 rounds up
+		assert_eq!(scale(1, 96, 47), 0);
+		assert_eq!(scale(150, 144, 96), 100);
+		assert_eq!(scale(i32::MAX / 2, 96, 192), i32::MAX - 1); // no overflow in between
+	}
+
+	#[test]
+	fn negative_values() {
+		assert_eq!(scale(-100, 96, 144), -150);
+		assert_eq!(scale(-1, 96, 144), -2); // -1.5 rounds away from zero
+		assert_eq!(scale(-3, 96, 120), -4);
+		assert_eq!(scale(-1, 96, 47), 0);
+		assert_eq!(scale(-1, 96, 48), -1);
+	}
+
+	#[test]
+	fn rects_stay_adjacent() {
+		let a = RECT { left: 0, top: 0, right: 33, bottom: 10 };
+		let b = RECT { left: 33, top: 0, right: 67, bottom: 10 };
+		let (a, b) = (scale_rect(a, 96, 120), scale_rect(b, 96, 120));
+		assert_eq!(a.right, b.left);
+		assert_eq!((a.left, a.right, b.right), (0, 41, 84));
+
+		let sz = scale_size(SIZE::new(-10, 10), 96, 192);
+		assert_eq!((sz.cx, sz.cy), (-20, 20));
+	}
+
+	#[test]
+	fn font_height() {
+		assert_eq!(scale_font_height(-12, 96, 144), -18);
+		assert_eq!(scale_font_height(16, 96, 120), 20);
+		assert_eq!(scale_font_height(0, 96, 144), 0); // default height stays default
+		assert_eq!(scale_font_height(-1, 96, 40), -1); // never becomes zero
+		assert_eq!(scale_font_height(1, 96, 40), 1);
+		assert_eq!(scale_font_height(-12, 96, 96), -12);
+	}
+}
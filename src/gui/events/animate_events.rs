@@ -0,0 +1,31 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*};
+
+/// Exposes animation control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct AnimateEvents(BaseCtrlEvents);
+
+impl AnimateEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<BaseWnd>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEvents::new(parent, ctrl_id))
+	}
+
+	pub_fn_cmd_noparm_noret! { acn_start, co::ACN::START;
+		/// [`ACN_START`](https://learn.microsoft.com/en-us/windows/win32/controls/acn-start)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { acn_stop, co::ACN::STOP;
+		/// [`ACN_STOP`](https://learn.microsoft.com/en-us/windows/win32/controls/acn-stop)
+		/// command notification.
+	}
+}
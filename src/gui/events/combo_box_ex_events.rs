@@ -0,0 +1,88 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*};
+
+/// Exposes ComboBoxEx control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-comboboxex-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct ComboBoxExEvents(BaseCtrlEvents);
+
+impl ComboBoxExEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<BaseWnd>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEvents::new(parent, ctrl_id))
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_close_up, co::CBN::CLOSEUP;
+		/// [`CBN_CLOSEUP`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-closeup)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_dbl_clk, co::CBN::DBLCLK;
+		/// [`CBN_DBLCLK`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-dblclk)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_drop_down, co::CBN::DROPDOWN;
+		/// [`CBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-dropdown)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_err_space, co::CBN::ERRSPACE;
+		/// [`CBN_ERRSPACE`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-errspace)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_kill_focus, co::CBN::KILLFOCUS;
+		/// [`CBN_KILLFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-killfocus)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_sel_change, co::CBN::SELCHANGE;
+		/// [`CBN_SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-selchange)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_sel_end_cancel, co::CBN::SELENDCANCEL;
+		/// [`CBN_SELENDCANCEL`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-selendcancel)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_sel_end_ok, co::CBN::SELENDOK;
+		/// [`CBN_SELENDOK`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-selendok)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { cbn_set_focus, co::CBN::SETFOCUS;
+		/// [`CBN_SETFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/cbn-setfocus)
+		/// command notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { cben_begin_edit, co::CBEN::BEGINEDIT;
+		/// [`CBEN_BEGINEDIT`](https://learn.microsoft.com/en-us/windows/win32/controls/cben-beginedit)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { cben_delete_item, co::CBEN::DELETEITEM, NMCOMBOBOXEX;
+		/// [`CBEN_DELETEITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/cben-deleteitem)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { cben_end_edit, co::CBEN::ENDEDIT, NMCBEENDEDIT;
+		/// [`CBEN_ENDEDIT`](https://learn.microsoft.com/en-us/windows/win32/controls/cben-endedit)
+		/// notification.
+		///
+		/// Return `true` to prevent the selection from changing.
+	}
+
+	pub_fn_nfy_withparm_noret! { cben_insert_item, co::CBEN::INSERTITEM, NMCOMBOBOXEX;
+		/// [`CBEN_INSERTITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/cben-insertitem)
+		/// notification.
+	}
+}
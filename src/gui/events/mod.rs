@@ -1,18 +1,25 @@
 //! Exposes native control messages that can be handled.
 
+mod animate_events;
 mod base_ctrl_events;
 mod button_events;
 mod combo_box_events;
+mod combo_box_ex_events;
 mod date_time_picker_events;
 mod edit_events;
 mod header_events;
+mod ip_address_events;
 mod label_events;
 mod list_box_events;
 mod list_view_events;
 mod month_calendar_events;
 mod radio_group_events;
+mod rebar_events;
+#[cfg(feature = "richedit")] mod rich_edit_events;
 mod status_bar_events;
+mod sys_link_events;
 mod tab_events;
+mod toolbar_events;
 mod trackbar_events;
 mod tree_view_events;
 mod up_down_events;
@@ -20,18 +27,25 @@ mod window_events;
 
 pub(in crate::gui) use base_ctrl_events::BaseCtrlEvents;
 
+pub use animate_events::AnimateEvents;
 pub use button_events::ButtonEvents;
 pub use combo_box_events::ComboBoxEvents;
+pub use combo_box_ex_events::ComboBoxExEvents;
 pub use date_time_picker_events::DateTimePickerEvents;
 pub use edit_events::EditEvents;
 pub use header_events::HeaderEvents;
+pub use ip_address_events::IpAddressEvents;
 pub use label_events::LabelEvents;
 pub use list_box_events::ListBoxEvents;
 pub use list_view_events::ListViewEvents;
 pub use month_calendar_events::MonthCalendarEvents;
 pub use radio_group_events::RadioGroupEvents;
+pub use rebar_events::RebarEvents;
+#[cfg(feature = "richedit")] pub use rich_edit_events::RichEditEvents;
 pub use status_bar_events::StatusBarEvents;
+pub use sys_link_events::SysLinkEvents;
 pub use tab_events::TabEvents;
+pub use toolbar_events::ToolbarEvents;
 pub use trackbar_events::TrackbarEvents;
 pub use tree_view_events::TreeViewEvents;
 pub use up_down_events::UpDownEvents;
@@ -0,0 +1,80 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*};
+
+/// Exposes rebar control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct RebarEvents(BaseCtrlEvents);
+
+impl RebarEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<BaseWnd>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEvents::new(parent, ctrl_id))
+	}
+
+	pub_fn_nfy_noparm_noret! { nm_released_capture, co::NM::RELEASEDCAPTURE;
+		/// [`NM_RELEASEDCAPTURE`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-releasedcapture-rebar-)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_auto_size, co::RBN::AUTOSIZE, NMRBAUTOSIZE;
+		/// [`RBN_AUTOSIZE`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-autosize)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { rbn_begin_drag, co::RBN::BEGINDRAG, NMREBAR;
+		/// [`RBN_BEGINDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-begindrag)
+		/// notification.
+		///
+		/// Return `true` to abort the drag operation.
+	}
+
+	pub_fn_nfy_withmutparm_noret! { rbn_child_size, co::RBN::CHILDSIZE, NMREBARCHILDSIZE;
+		/// [`RBN_CHILDSIZE`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-childsize)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_chevron_pushed, co::RBN::CHEVRONPUSHED, NMREBARCHEVRON;
+		/// [`RBN_CHEVRONPUSHED`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-chevronpushed)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_deleted_band, co::RBN::DELETEDBAND, NMREBAR;
+		/// [`RBN_DELETEDBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-deletedband)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_deleting_band, co::RBN::DELETINGBAND, NMREBAR;
+		/// [`RBN_DELETINGBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-deletingband)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_end_drag, co::RBN::ENDDRAG, NMREBAR;
+		/// [`RBN_ENDDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-enddrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { rbn_height_change, co::RBN::HEIGHTCHANGE;
+		/// [`RBN_HEIGHTCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-heightchange)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { rbn_layout_changed, co::RBN::LAYOUTCHANGED;
+		/// [`RBN_LAYOUTCHANGED`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-layoutchanged)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_boolret! { rbn_min_max, co::RBN::MINMAX;
+		/// [`RBN_MINMAX`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-minmax)
+		/// notification.
+		///
+		/// Return `true` to prevent the band from being minimized or maximized.
+	}
+}
@@ -0,0 +1,92 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*};
+
+/// Exposes rich edit control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rich-edit-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// **Note:** The control only sends the notifications enabled in its
+/// [event mask](crate::gui::RichEditOpts::event_mask).
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct RichEditEvents(BaseCtrlEvents);
+
+impl RichEditEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<BaseWnd>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEvents::new(parent, ctrl_id))
+	}
+
+	pub_fn_cmd_noparm_noret! { en_change, co::EN::CHANGE;
+		/// [`EN_CHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-change)
+		/// command notification.
+		///
+		/// Requires [`ENM::CHANGE`](crate::co::ENM::CHANGE) in the event mask.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_kill_focus, co::EN::KILLFOCUS;
+		/// [`EN_KILLFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/en-killfocus)
+		/// command notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { en_link, co::REN::LINK, ENLINK;
+		/// [`EN_LINK`](https://learn.microsoft.com/en-us/windows/win32/controls/en-link)
+		/// notification.
+		///
+		/// Sent for mouse and cursor messages over a link. Return `true` to
+		/// prevent the control from processing the message.
+		///
+		/// Requires [`ENM::LINK`](crate::co::ENM::LINK) in the event mask.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// let txt: gui::RichEdit;
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+		///
+		/// let txt2 = txt.clone(); // to pass into the closure
+		///
+		/// txt.on().en_link(move |p| {
+		///     if p.msg == co::WM::LBUTTONUP {
+		///         println!("Link clicked: {}", txt2.text_range(p.chrg));
+		///         return Ok(true);
+		///     }
+		///     Ok(false)
+		/// });
+		/// ```
+	}
+
+	pub_fn_cmd_noparm_noret! { en_max_text, co::EN::MAXTEXT;
+		/// [`EN_MAXTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/en-maxtext)
+		/// command notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { en_sel_change, co::REN::SELCHANGE, SELCHANGE;
+		/// [`EN_SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-selchange)
+		/// notification.
+		///
+		/// Requires [`ENM::SELCHANGE`](crate::co::ENM::SELCHANGE) in the event
+		/// mask.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_set_focus, co::EN::SETFOCUS;
+		/// [`EN_SETFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/en-setfocus)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_update, co::EN::UPDATE;
+		/// [`EN_UPDATE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-update)
+		/// command notification.
+		///
+		/// Requires [`ENM::UPDATE`](crate::co::ENM::UPDATE) in the event mask.
+	}
+}
@@ -0,0 +1,50 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*};
+
+/// Exposes SysLink control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-syslink-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct SysLinkEvents(BaseCtrlEvents);
+
+impl SysLinkEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<BaseWnd>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEvents::new(parent, ctrl_id))
+	}
+
+	pub_fn_nfy_withparm_noret! { nm_click, co::NM::CLICK, NMLINK;
+		/// [`NM_CLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-click-syslink)
+		/// notification.
+		///
+		/// The [`LITEM`](crate::LITEM) holds the ID and the URL of the clicked
+		/// link.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// let link: gui::SysLink;
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let link = gui::SysLink::new(&wnd, gui::SysLinkOpts::default());
+		///
+		/// link.on().nm_click(|p| {
+		///     println!("Link {} clicked: {}", p.item.szID(), p.item.szUrl());
+		///     Ok(())
+		/// });
+		/// ```
+	}
+
+	pub_fn_nfy_withparm_noret! { nm_return, co::NM::RETURN, NMLINK;
+		/// [`NM_RETURN`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-return-syslink-)
+		/// notification.
+	}
+}
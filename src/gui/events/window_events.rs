@@ -491,6 +491,15 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_withparm_noret! { wm_dpi_changed, co::WM::DPICHANGED, wm::DpiChanged;
+		/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+		/// message.
+		///
+		/// Before this event, the window is already moved to the suggested
+		/// rectangle, and its child controls are rescaled. Handle it to reload
+		/// images at the new DPI, since rescaled image lists may look blurry.
+	}
+
 	#[cfg(feature = "shell")]
 	pub_fn_wm_withparm_noret! { wm_drop_files, co::WM::DROPFILES, wm::DropFiles;
 		/// [`WM_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
@@ -56,26 +56,38 @@ pub(in crate::gui) mod ui_font {
 	use crate::co;
 	use crate::decl::*;
 	use crate::guard::*;
+	use crate::gui::privs::*;
 	use crate::msg::*;
 	use crate::prelude::*;
 
 	/// Global UI font object.
 	static mut UI_HFONT: Option<DeleteObjectGuard<HFONT>> = None;
 
+	/// UI font objects for DPIs other than the system DPI, created when windows
+	/// are moved to other monitors.
+	static mut UI_HFONTS_DPI: Vec<(u32, DeleteObjectGuard<HFONT>)> = Vec::new();
+
+	/// Retrieves the system UI font, which has the system DPI.
+	fn system_logfont() -> SysResult<LOGFONT> {
+		let mut ncm = NONCLIENTMETRICS::default();
+		unsafe {
+			SystemParametersInfo(
+				co::SPI::GETNONCLIENTMETRICS,
+				std::mem::size_of::<NONCLIENTMETRICS>() as _,
+				&mut ncm,
+				co::SPIF::NoValue,
+			)?;
+		}
+		Ok(ncm.lfMenuFont)
+	}
+
 	// Returns the global UI font, creating it of not yet.
 	pub(in crate::gui) fn get() -> SysResult<HFONT> {
 		Ok(unsafe {
 			match &*&raw const UI_HFONT {
 				None => {
 					// not created yet
-					let mut ncm = NONCLIENTMETRICS::default();
-					SystemParametersInfo(
-						co::SPI::GETNONCLIENTMETRICS,
-						std::mem::size_of::<NONCLIENTMETRICS>() as _,
-						&mut ncm,
-						co::SPIF::NoValue,
-					)?;
-					let font = HFONT::CreateFontIndirect(&ncm.lfMenuFont)?;
+					let font = HFONT::CreateFontIndirect(&system_logfont()?)?;
 					let ret_font = font.raw_copy();
 					UI_HFONT = Some(font);
 					ret_font
@@ -85,6 +97,27 @@ pub(in crate::gui) mod ui_font {
 		})
 	}
 
+	/// Returns the global UI font scaled to the given DPI, creating it if not
+	/// yet.
+	pub(in crate::gui) fn get_for_dpi(dpi: u32) -> SysResult<HFONT> {
+		if dpi == system_dpi() {
+			return get();
+		}
+
+		let fonts_ptr = &raw mut UI_HFONTS_DPI;
+		let fonts = unsafe { &mut *fonts_ptr };
+		if let Some((_, font)) = fonts.iter().find(|(font_dpi, _)| *font_dpi == dpi) {
+			return Ok(unsafe { font.raw_copy() });
+		}
+
+		let mut lf = system_logfont()?;
+		lf.lfHeight = scale_font_height(lf.lfHeight, system_dpi(), dpi);
+		let font = HFONT::CreateFontIndirect(&lf)?;
+		let ret_font = unsafe { font.raw_copy() };
+		fonts.push((dpi, font));
+		Ok(ret_font)
+	}
+
 	/// Sets the global UI font on the given window.
 	pub(in crate::gui) fn set(hwnd: &HWND) -> SysResult<()> {
 		unsafe {
@@ -93,10 +126,11 @@ pub(in crate::gui) mod ui_font {
 		Ok(())
 	}
 
-	/// Frees the global UI font object.
+	/// Frees the global UI font objects.
 	pub(in crate::gui) fn delete() {
 		unsafe {
 			UI_HFONT = None; // https://users.rust-lang.org/t/why-drop-trait-not-called-when-use-global-static
+			UI_HFONTS_DPI = Vec::new();
 		}
 	}
 }
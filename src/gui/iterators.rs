@@ -438,6 +438,51 @@ impl<'a> StatusBarPartIter<'a> {
 	}
 }
 
+pub(in crate::gui) struct ToolbarButtonIter<'a> {
+	owner: &'a Toolbar,
+	front_idx: u32,
+	past_back_idx: u32,
+}
+
+impl<'a> Iterator for ToolbarButtonIter<'a> {
+	type Item = ToolbarButton<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.grab(true)
+	}
+}
+impl<'a> DoubleEndedIterator for ToolbarButtonIter<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.grab(false)
+	}
+}
+
+impl<'a> ToolbarButtonIter<'a> {
+	#[must_use]
+	pub(in crate::gui) fn new(owner: &'a Toolbar) -> Self {
+		Self {
+			owner,
+			front_idx: 0,
+			past_back_idx: owner.buttons().count(),
+		}
+	}
+
+	fn grab(&mut self, is_front: bool) -> Option<ToolbarButton<'a>> {
+		if self.front_idx == self.past_back_idx {
+			return None;
+		}
+		let our_idx = if is_front { self.front_idx } else { self.past_back_idx - 1 };
+
+		let button = self.owner.buttons().get(our_idx);
+		if is_front {
+			self.front_idx += 1;
+		} else {
+			self.past_back_idx -= 1;
+		}
+		Some(button)
+	}
+}
+
 pub(in crate::gui) struct TreeViewItemIter<'a, T: 'static> {
 	owner: &'a TreeView<T>,
 	current: Option<TreeViewItem<'a, T>>,
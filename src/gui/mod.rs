@@ -2,6 +2,7 @@
 #![cfg(feature = "gui")]
 
 mod dpi;
+mod dpi_scale;
 mod globals;
 mod iterators;
 mod msg_error;
@@ -12,6 +13,8 @@ pub mod events;
 pub(crate) mod traits;
 
 pub(in crate::gui) mod privs {
+	pub(in crate::gui) use super::dpi::system_dpi;
+	pub(in crate::gui) use super::dpi_scale::*;
 	pub(in crate::gui) use super::globals::*;
 	pub(in crate::gui) use super::iterators::*;
 	pub(in crate::gui) use super::native_controls::privs::*;
@@ -0,0 +1,234 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*, *};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct AnimateObj {
+	base: BaseCtrl,
+	events: AnimateEvents,
+	_pin: PhantomPinned,
+}
+
+native_ctrl! { Animate: AnimateObj => AnimateEvents;
+	/// Native
+	/// [animation](https://learn.microsoft.com/en-us/windows/win32/controls/animation-control-overview)
+	/// control, which plays silent AVI clips.
+}
+
+impl Animate {
+	/// Instantiates a new `Animate` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create an `Animate` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let anim = gui::Animate::new(&wnd, gui::AnimateOpts::default());
+	///
+	/// let anim2 = anim.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     anim2.open_file("C:\\Temp\\copying.avi")?;
+	///     anim2.play(None, 0, None)?;
+	///     Ok(0)
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: AnimateOpts) -> Self {
+		let ctrl_id = auto_id::set_if_zero(opts.ctrl_id);
+		let new_self = Self(Arc::pin(AnimateObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: AnimateEvents::new(parent, ctrl_id),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.0.base.create_window(
+					opts.window_ex_style,
+					"SysAnimate32",
+					None,
+					opts.window_style | opts.control_style.into(),
+					opts.position.into(),
+					opts.size.into(),
+					&parent2,
+				)?;
+				parent2
+					.as_ref()
+					.add_to_layout(self2.hwnd(), opts.resize_behavior)?;
+				Ok(0) // ignored
+			});
+
+		new_self
+	}
+
+	/// Instantiates a new `Animate` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create an `Animate` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &(impl GuiParent + 'static),
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self {
+		let new_self = Self(Arc::pin(AnimateObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: AnimateEvents::new(parent, ctrl_id),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent.as_ref().before_on().wm_init_dialog(move |_| {
+			self2.0.base.assign_dlg(&parent2)?;
+			parent2
+				.as_ref()
+				.add_to_layout(self2.hwnd(), resize_behavior)?;
+			Ok(true) // ignored
+		});
+
+		new_self
+	}
+
+	/// Closes the current AVI clip, if any, by sending an
+	/// [`acm::Open`](crate::msg::acm::Open) message with no resource.
+	pub fn close(&self) -> SysResult<()> {
+		unsafe {
+			self.hwnd().SendMessage(acm::Open {
+				hinstance: &HINSTANCE::NULL,
+				resource: None,
+			})
+		}
+	}
+
+	/// Tells whether a clip is playing by sending an
+	/// [`acm::IsPlaying`](crate::msg::acm::IsPlaying) message.
+	#[must_use]
+	pub fn is_playing(&self) -> bool {
+		unsafe { self.hwnd().SendMessage(acm::IsPlaying {}) }
+	}
+
+	/// Opens an AVI clip from a file by sending an
+	/// [`acm::Open`](crate::msg::acm::Open) message.
+	pub fn open_file(&self, path: &str) -> SysResult<()> {
+		unsafe {
+			self.hwnd().SendMessage(acm::Open {
+				hinstance: &HINSTANCE::NULL,
+				resource: Some(IdStr::from_str(path)),
+			})
+		}
+	}
+
+	/// Opens an AVI clip from an `"AVI"` resource by sending an
+	/// [`acm::Open`](crate::msg::acm::Open) message.
+	pub fn open_resource(&self, hinst: &HINSTANCE, resource: IdStr) -> SysResult<()> {
+		unsafe {
+			self.hwnd().SendMessage(acm::Open {
+				hinstance: hinst,
+				resource: Some(resource),
+			})
+		}
+	}
+
+	/// Plays the clip by sending an [`acm::Play`](crate::msg::acm::Play)
+	/// message.
+	///
+	/// If `repeat` is `None`, the clip is played indefinitely. If `to_frame` is
+	/// `None`, the clip is played until its last frame.
+	pub fn play(
+		&self,
+		repeat: Option<u32>,
+		from_frame: u16,
+		to_frame: Option<u16>,
+	) -> SysResult<()> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(acm::Play { repeat, from_frame, to_frame })
+		}
+	}
+
+	/// Stops the clip by sending an [`acm::Stop`](crate::msg::acm::Stop)
+	/// message.
+	pub fn stop(&self) -> SysResult<()> {
+		unsafe { self.hwnd().SendMessage(acm::Stop {}) }
+	}
+}
+
+/// Options to create an [`Animate`](crate::gui::Animate) programmatically with
+/// [`Animate::new`](crate::gui::Animate::new).
+pub struct AnimateOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi(100, 50)`.
+	pub size: (i32, i32),
+	/// Animation styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `ACS::CENTER | ACS::TRANSPARENT`.
+	pub control_style: co::ACS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for AnimateOpts {
+	fn default() -> Self {
+		Self {
+			position: dpi(0, 0),
+			size: dpi(100, 50),
+			control_style: co::ACS::CENTER | co::ACS::TRANSPARENT,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
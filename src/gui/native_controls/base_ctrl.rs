@@ -85,6 +85,41 @@ impl BaseCtrl {
 		Ok(())
 	}
 
+	/// Creates a popup window owned by the parent, like a tooltip. Since popups
+	/// cannot have a control ID, `IdMenu::None` is passed.
+	pub(in crate::gui) fn create_popup(
+		&self,
+		ex_style: co::WS_EX,
+		class_name: &str,
+		style: co::WS,
+		parent: &impl GuiParent,
+	) -> SysResult<()> {
+		let hparent = parent.as_ref().hwnd();
+
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot create control twice.");
+		} else if *hparent == HWND::NULL {
+			panic!("Cannot create control before parent window creation.");
+		}
+
+		self.set_hwnd(unsafe {
+			HWND::CreateWindowEx(
+				ex_style,
+				AtomStr::from_str(class_name),
+				None,
+				style,
+				POINT::default(),
+				SIZE::default(),
+				Some(hparent),
+				IdMenu::None,
+				&hparent.hinstance(),
+				None,
+			)?
+		});
+		self.install_subclass_if_needed()?;
+		Ok(())
+	}
+
 	pub(in crate::gui) fn assign_dlg(&self, parent: &impl AsRef<BaseWnd>) -> SysResult<()> {
 		let hparent = parent.as_ref().hwnd();
 
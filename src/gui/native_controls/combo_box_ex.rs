@@ -0,0 +1,271 @@
+use std::any::Any;
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::gui::{collections::*, events::*, privs::*, *};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct ComboBoxExObj {
+	base: BaseCtrl,
+	events: ComboBoxExEvents,
+	himagelist: UnsafeCell<Option<ImageListDestroyGuard>>,
+	_pin: PhantomPinned,
+}
+
+native_ctrl! { ComboBoxEx: ComboBoxExObj => ComboBoxExEvents;
+	/// Native
+	/// [ComboBoxEx](https://learn.microsoft.com/en-us/windows/win32/controls/comboboxex-controls)
+	/// control, a combo box whose items can display images from an image list.
+}
+
+impl ComboBoxEx {
+	/// Instantiates a new `ComboBoxEx` object, to be created on the parent
+	/// window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `ComboBoxEx` in an event closure.
+	///
+	/// Panics if vertical resizing behavior is
+	/// [`Vert::Resize`](crate::gui::Vert::Resize).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let cmb = gui::ComboBoxEx::new(
+	///     &wnd,
+	///     gui::ComboBoxExOpts {
+	///         position: (10, 10),
+	///         width: 140,
+	///         items: vec![
+	///             ("Drive".to_owned(), Some(0)),
+	///             ("Folder".to_owned(), Some(1)),
+	///         ],
+	///         selected_item: Some(0),
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// let cmb2 = cmb.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     let himgl = w::HIMAGELIST::Create(w::SIZE::new(16, 16), co::ILC::COLOR32, 2, 1)?;
+	///     cmb2.set_image_list(himgl);
+	///     Ok(0)
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: ComboBoxExOpts) -> Self {
+		if opts.resize_behavior.1 == Vert::Resize {
+			panic!("ComboBoxEx cannot be resized with Vert::Resize.");
+		}
+
+		let ctrl_id = auto_id::set_if_zero(opts.ctrl_id);
+		let new_self = Self(Arc::pin(ComboBoxExObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: ComboBoxExEvents::new(parent, ctrl_id),
+			himagelist: UnsafeCell::new(None),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.0.base.create_window(
+					opts.window_ex_style,
+					"ComboBoxEx32",
+					None,
+					opts.window_style | opts.control_style.into(),
+					opts.position.into(),
+					SIZE::new(opts.width, 0),
+					&parent2,
+				)?;
+				ui_font::set(self2.hwnd())?;
+				if opts.control_ex_style != co::CBES_EX::NoValue {
+					unsafe {
+						self2.hwnd().SendMessage(cbem::SetExtendedStyle {
+							mask: opts.control_ex_style,
+							style: opts.control_ex_style,
+						});
+					}
+				}
+				for (text, image_index) in opts.items.iter() {
+					self2.items().add(text, *image_index)?;
+				}
+				self2.items().select(opts.selected_item);
+				parent2
+					.as_ref()
+					.add_to_layout(self2.hwnd(), opts.resize_behavior)?;
+				Ok(0) // ignored
+			});
+
+		new_self
+	}
+
+	/// Instantiates a new `ComboBoxEx` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `ComboBoxEx` in an event closure.
+	///
+	/// Panics if vertical resizing behavior is
+	/// [`Vert::Resize`](crate::gui::Vert::Resize).
+	#[must_use]
+	pub fn new_dlg(
+		parent: &(impl GuiParent + 'static),
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self {
+		if resize_behavior.1 == Vert::Resize {
+			panic!("ComboBoxEx cannot be resized with Vert::Resize.");
+		}
+
+		let new_self = Self(Arc::pin(ComboBoxExObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: ComboBoxExEvents::new(parent, ctrl_id),
+			himagelist: UnsafeCell::new(None),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent.as_ref().before_on().wm_init_dialog(move |_| {
+			self2.0.base.assign_dlg(&parent2)?;
+			parent2
+				.as_ref()
+				.add_to_layout(self2.hwnd(), resize_behavior)?;
+			Ok(true) // ignored
+		});
+
+		new_self
+	}
+
+	/// Returns a reference to the image list set with
+	/// [`set_image_list`](crate::gui::ComboBoxEx::set_image_list), if any.
+	#[must_use]
+	pub fn image_list(&self) -> Option<&HIMAGELIST> {
+		unsafe { &*self.0.himagelist.get() }
+			.as_ref()
+			.map(|hil| &**hil)
+	}
+
+	/// Item methods.
+	#[must_use]
+	pub const fn items(&self) -> ComboBoxExItems<'_> {
+		ComboBoxExItems::new(self)
+	}
+
+	/// Sets the image list of the items by sending a
+	/// [`cbem::SetImageList`](crate::msg::cbem::SetImageList) message.
+	///
+	/// The ComboBoxEx control doesn't destroy its image list, so the image list
+	/// will be kept by this object until it's dropped. Returns the previous
+	/// one, if any.
+	pub fn set_image_list(
+		&self,
+		himagelist: ImageListDestroyGuard,
+	) -> Option<ImageListDestroyGuard> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(cbem::SetImageList { himagelist: himagelist.raw_copy() });
+		}
+		unsafe { &mut *self.0.himagelist.get() }.replace(himagelist)
+	}
+}
+
+/// Options to create a [`ComboBoxEx`](crate::gui::ComboBoxEx) programmatically
+/// with [`ComboBoxEx::new`](crate::gui::ComboBoxEx::new).
+pub struct ComboBoxExOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi(0, 0)`.
+	pub position: (i32, i32),
+	/// Control width to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi_x(120)`.
+	pub width: i32,
+	/// Combo box styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `CBS::DROPDOWNLIST`.
+	///
+	/// Suggestions:
+	/// * replace with `CBS::DROPDOWN` to allow the user to type a text.
+	pub control_style: co::CBS,
+	/// Extended ComboBoxEx styles to be
+	/// [set](https://learn.microsoft.com/en-us/windows/win32/controls/cbem-setextendedstyle).
+	///
+	/// Defaults to `CBES_EX::NoValue`.
+	pub control_ex_style: co::CBES_EX,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::GROUP | WS::TABSTOP | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// **Note:** A `ComboBoxEx` cannot be resized vertically, so it will panic
+	/// if you use `Vert::Resize`.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+
+	/// Items to be added, with the texts and the indexes of their images
+	/// within the image list.
+	///
+	/// Defaults to none.
+	pub items: Vec<(String, Option<u32>)>,
+	/// Index of the item initially selected. The item must exist.
+	///
+	/// Defaults to `None`.
+	pub selected_item: Option<u32>,
+}
+
+impl Default for ComboBoxExOpts {
+	fn default() -> Self {
+		Self {
+			position: dpi(0, 0),
+			width: dpi_x(120),
+			control_style: co::CBS::DROPDOWNLIST,
+			control_ex_style: co::CBES_EX::NoValue,
+			window_style: co::WS::CHILD | co::WS::GROUP | co::WS::TABSTOP | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+			items: Vec::default(),
+			selected_item: None,
+		}
+	}
+}
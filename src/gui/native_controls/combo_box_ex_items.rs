@@ -0,0 +1,163 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::*;
+use crate::kernel::privs::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Exposes item methods of a [`ComboBoxEx`](crate::gui::ComboBoxEx) control.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct ComboBoxExItems<'a> {
+	owner: &'a ComboBoxEx,
+}
+
+impl<'a> ComboBoxExItems<'a> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a ComboBoxEx) -> Self {
+		Self { owner }
+	}
+
+	/// Adds a new item by sending a
+	/// [`cbem::InsertItem`](crate::msg::cbem::InsertItem) message, returning
+	/// its index.
+	///
+	/// The `image_index` refers to the image list set with
+	/// [`ComboBoxEx::set_image_list`](crate::gui::ComboBoxEx::set_image_list),
+	/// and it's used both for the normal and the selected states.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_combo: gui::ComboBoxEx; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_combo = gui::ComboBoxEx::new(&wnd, gui::ComboBoxExOpts::default());
+	///
+	/// my_combo.items().add("Desktop", Some(0))?;
+	/// my_combo.items().add("No image", None)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn add(&self, text: &str, image_index: Option<u32>) -> SysResult<u32> {
+		let mut wtext = WString::from_str(text);
+
+		let mut cbei = COMBOBOXEXITEM::default();
+		cbei.mask = co::CBEIF::TEXT;
+		cbei.iItem = -1; // insert at the end
+		cbei.set_pszText(Some(&mut wtext));
+		if let Some(image_index) = image_index {
+			cbei.mask |= co::CBEIF::IMAGE | co::CBEIF::SELECTEDIMAGE;
+			cbei.iImage = image_index as _;
+			cbei.iSelectedImage = image_index as _;
+		}
+
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(cbem::InsertItem { item: &cbei })
+		}
+	}
+
+	/// Retrieves the number of items by sending a
+	/// [`cb::GetCount`](crate::msg::cb::GetCount) message.
+	#[must_use]
+	pub fn count(&self) -> SysResult<u32> {
+		unsafe { self.owner.hwnd().SendMessage(cb::GetCount {}) }
+	}
+
+	/// Deletes the item at the given index by sending a
+	/// [`cbem::DeleteItem`](crate::msg::cbem::DeleteItem) message.
+	pub fn delete(&self, index: u32) -> SysResult<()> {
+		unsafe {
+			self.owner.hwnd().SendMessage(cbem::DeleteItem { index })?;
+		}
+		Ok(())
+	}
+
+	/// Deletes all items by sending a
+	/// [`cb::ResetContent`](crate::msg::cb::ResetContent) message.
+	pub fn delete_all(&self) {
+		unsafe {
+			self.owner.hwnd().SendMessage(cb::ResetContent {});
+		}
+	}
+
+	/// Retrieves the index of the image of the item at the given position, if
+	/// any, by sending a [`cbem::GetItem`](crate::msg::cbem::GetItem) message.
+	#[must_use]
+	pub fn image_index(&self, index: u32) -> SysResult<Option<u32>> {
+		let mut cbei = COMBOBOXEXITEM::default();
+		cbei.mask = co::CBEIF::IMAGE;
+		cbei.iItem = index as _;
+
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(cbem::GetItem { item: &mut cbei })?;
+		}
+		Ok(if cbei.iImage < 0 { None } else { Some(cbei.iImage as _) })
+	}
+
+	/// Sets the currently selected index, or clears it, by sending a
+	/// [`cb::SetCurSel`](crate::msg::cb::SetCurSel) message.
+	pub fn select(&self, index: Option<u32>) {
+		unsafe {
+			self.owner.hwnd().SendMessage(cb::SetCurSel { index });
+		}
+	}
+
+	/// Retrieves the index of the currently selected item, if any, by sending a
+	/// [`cb::GetCurSel`](crate::msg::cb::GetCurSel) message.
+	#[must_use]
+	pub fn selected_index(&self) -> Option<u32> {
+		unsafe { self.owner.hwnd().SendMessage(cb::GetCurSel {}) }
+	}
+
+	/// Sets the index of the image of the item at the given position by
+	/// sending a [`cbem::SetItem`](crate::msg::cbem::SetItem) message.
+	pub fn set_image_index(&self, index: u32, image_index: Option<u32>) -> SysResult<()> {
+		let image_index = image_index.map_or(-1, |idx| idx as i32);
+
+		let mut cbei = COMBOBOXEXITEM::default();
+		cbei.mask = co::CBEIF::IMAGE | co::CBEIF::SELECTEDIMAGE;
+		cbei.iItem = index as _;
+		cbei.iImage = image_index;
+		cbei.iSelectedImage = image_index;
+
+		unsafe { self.owner.hwnd().SendMessage(cbem::SetItem { item: &cbei }) }
+	}
+
+	/// Sets the text of the item at the given position by sending a
+	/// [`cbem::SetItem`](crate::msg::cbem::SetItem) message.
+	pub fn set_text(&self, index: u32, text: &str) -> SysResult<()> {
+		let mut wtext = WString::from_str(text);
+
+		let mut cbei = COMBOBOXEXITEM::default();
+		cbei.mask = co::CBEIF::TEXT;
+		cbei.iItem = index as _;
+		cbei.set_pszText(Some(&mut wtext));
+
+		unsafe { self.owner.hwnd().SendMessage(cbem::SetItem { item: &cbei }) }
+	}
+
+	/// Retrieves the text at the given position by sending a
+	/// [`cbem::GetItem`](crate::msg::cbem::GetItem) message.
+	#[must_use]
+	pub fn text(&self, index: u32) -> SysResult<String> {
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1); // arbitrary
+
+		let mut cbei = COMBOBOXEXITEM::default();
+		cbei.mask = co::CBEIF::TEXT;
+		cbei.iItem = index as _;
+		cbei.set_pszText(Some(&mut buf));
+
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(cbem::GetItem { item: &mut cbei })?;
+		}
+		Ok(buf.to_string())
+	}
+}
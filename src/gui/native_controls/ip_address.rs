@@ -0,0 +1,250 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*, *};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct IpAddressObj {
+	base: BaseCtrl,
+	events: IpAddressEvents,
+	_pin: PhantomPinned,
+}
+
+native_ctrl! { IpAddress: IpAddressObj => IpAddressEvents;
+	/// Native
+	/// [IP address](https://learn.microsoft.com/en-us/windows/win32/controls/ip-address-controls)
+	/// control, which accepts an IPv4 address in four fields.
+}
+
+impl IpAddress {
+	/// Instantiates a new `IpAddress` object, to be created on the parent
+	/// window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create an `IpAddress` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let ip = gui::IpAddress::new(
+	///     &wnd,
+	///     gui::IpAddressOpts {
+	///         position: gui::dpi(10, 10),
+	///         address: Some([192, 168, 0, 1]),
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// let ip2 = ip.clone();
+	/// ip.on().ipn_field_changed(move |_| {
+	///     if let Some(addr) = ip2.address() {
+	///         println!("Address: {}", std::net::Ipv4Addr::from(addr));
+	///     }
+	///     Ok(())
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: IpAddressOpts) -> Self {
+		let ctrl_id = auto_id::set_if_zero(opts.ctrl_id);
+		let new_self = Self(Arc::pin(IpAddressObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: IpAddressEvents::new(parent, ctrl_id),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.0.base.create_window(
+					opts.window_ex_style,
+					"SysIPAddress32",
+					None,
+					opts.window_style,
+					opts.position.into(),
+					SIZE::new(opts.width, opts.height),
+					&parent2,
+				)?;
+				ui_font::set(self2.hwnd())?;
+				if let Some(address) = opts.address {
+					self2.set_address(address);
+				}
+				parent2
+					.as_ref()
+					.add_to_layout(self2.hwnd(), opts.resize_behavior)?;
+				Ok(0) // ignored
+			});
+
+		new_self
+	}
+
+	/// Instantiates a new `IpAddress` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create an `IpAddress` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &(impl GuiParent + 'static),
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self {
+		let new_self = Self(Arc::pin(IpAddressObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: IpAddressEvents::new(parent, ctrl_id),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent.as_ref().before_on().wm_init_dialog(move |_| {
+			self2.0.base.assign_dlg(&parent2)?;
+			parent2
+				.as_ref()
+				.add_to_layout(self2.hwnd(), resize_behavior)?;
+			Ok(true) // ignored
+		});
+
+		new_self
+	}
+
+	/// Retrieves the address by sending an
+	/// [`ipm::GetAddress`](crate::msg::ipm::GetAddress) message. Returns
+	/// `None` if all fields are blank; blank fields among filled ones are
+	/// returned as zero.
+	#[must_use]
+	pub fn address(&self) -> Option<[u8; 4]> {
+		let mut packed = 0u32;
+		let num_filled = unsafe {
+			self.hwnd()
+				.SendMessage(ipm::GetAddress { address: &mut packed })
+		};
+		if num_filled == 0 {
+			None
+		} else {
+			Some([
+				FIRST_IPADDRESS(packed),
+				SECOND_IPADDRESS(packed),
+				THIRD_IPADDRESS(packed),
+				FOURTH_IPADDRESS(packed),
+			])
+		}
+	}
+
+	/// Clears all the fields by sending an
+	/// [`ipm::ClearAddress`](crate::msg::ipm::ClearAddress) message.
+	pub fn clear(&self) {
+		unsafe {
+			self.hwnd().SendMessage(ipm::ClearAddress {});
+		}
+	}
+
+	/// Tells whether all the fields are blank by sending an
+	/// [`ipm::IsBlank`](crate::msg::ipm::IsBlank) message.
+	#[must_use]
+	pub fn is_blank(&self) -> bool {
+		unsafe { self.hwnd().SendMessage(ipm::IsBlank {}) }
+	}
+
+	/// Sets the address by sending an
+	/// [`ipm::SetAddress`](crate::msg::ipm::SetAddress) message.
+	pub fn set_address(&self, address: [u8; 4]) {
+		unsafe {
+			self.hwnd().SendMessage(ipm::SetAddress { address });
+		}
+	}
+
+	/// Sets the valid range of the given zero-based field by sending an
+	/// [`ipm::SetRange`](crate::msg::ipm::SetRange) message.
+	pub fn set_field_range(&self, field: u8, min: u8, max: u8) -> SysResult<()> {
+		unsafe { self.hwnd().SendMessage(ipm::SetRange { field, min, max }) }
+	}
+
+	/// Sets the keyboard focus to the given zero-based field by sending an
+	/// [`ipm::SetFocus`](crate::msg::ipm::SetFocus) message. If `field` is
+	/// `None`, the focus goes to the first blank field.
+	pub fn set_focus_field(&self, field: Option<u8>) {
+		unsafe {
+			self.hwnd().SendMessage(ipm::SetFocus { field });
+		}
+	}
+}
+
+/// Options to create an [`IpAddress`](crate::gui::IpAddress) programmatically
+/// with [`IpAddress::new`](crate::gui::IpAddress::new).
+pub struct IpAddressOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi(0, 0)`.
+	pub position: (i32, i32),
+	/// Control width to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi_x(130)`.
+	pub width: i32,
+	/// Control height to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi_y(23)`.
+	pub height: i32,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::GROUP | WS::TABSTOP | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT | WS_EX::CLIENTEDGE`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+
+	/// Initial address.
+	///
+	/// Defaults to `None`, which leaves all fields blank.
+	pub address: Option<[u8; 4]>,
+}
+
+impl Default for IpAddressOpts {
+	fn default() -> Self {
+		Self {
+			position: dpi(0, 0),
+			width: dpi_x(130),
+			height: dpi_y(23),
+			window_style: co::WS::CHILD | co::WS::GROUP | co::WS::TABSTOP | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::LEFT | co::WS_EX::CLIENTEDGE,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+			address: None,
+		}
+	}
+}
@@ -1,13 +1,18 @@
+mod animate;
 mod base_ctrl;
 mod button;
 mod check_box;
 mod combo_box;
+mod combo_box_ex;
+mod combo_box_ex_items;
 mod combo_box_items;
 mod date_time_picker;
 mod edit;
 mod header;
 mod header_item;
 mod header_items;
+mod hot_key;
+mod ip_address;
 mod label;
 mod list_box;
 mod list_box_items;
@@ -20,30 +25,45 @@ mod month_calendar;
 mod progress_bar;
 mod radio_button;
 mod radio_group;
+mod rebar;
+mod rebar_band;
+mod rebar_bands;
+#[cfg(feature = "richedit")] mod rich_edit;
 mod status_bar;
 mod status_bar_part;
 mod status_bar_parts;
+mod sys_link;
 mod tab;
 mod tab_item;
 mod tab_items;
+mod toolbar;
+mod toolbar_button;
+mod toolbar_buttons;
+mod tooltip;
 mod trackbar;
 mod tree_view;
 mod tree_view_item;
 mod tree_view_items;
 mod up_down;
+mod virtual_list_state;
+mod virtual_list_view;
 
 pub(in crate::gui) mod privs {
 	pub(in crate::gui) use super::base_ctrl::BaseCtrl;
 }
 
 pub mod decl {
+	pub use super::animate::{Animate, AnimateOpts};
 	pub use super::button::{Button, ButtonOpts};
 	pub use super::check_box::{CheckBox, CheckBoxOpts};
 	pub use super::combo_box::{ComboBox, ComboBoxOpts};
+	pub use super::combo_box_ex::{ComboBoxEx, ComboBoxExOpts};
 	pub use super::date_time_picker::{DateTimePicker, DateTimePickerOpts};
 	pub use super::edit::{Edit, EditOpts};
 	pub use super::header::{Header, HeaderOpts};
 	pub use super::header_item::{HeaderArrow, HeaderItem, HeaderJustify};
+	pub use super::hot_key::{HotKey, HotKeyOpts};
+	pub use super::ip_address::{IpAddress, IpAddressOpts};
 	pub use super::label::{Label, LabelOpts};
 	pub use super::list_box::{ListBox, ListBoxOpts};
 	pub use super::list_view::{ListView, ListViewOpts};
@@ -53,26 +73,37 @@ pub mod decl {
 	pub use super::progress_bar::{ProgressBar, ProgressBarOpts};
 	pub use super::radio_button::{RadioButton, RadioButtonOpts};
 	pub use super::radio_group::RadioGroup;
+	pub use super::rebar::{Rebar, RebarOpts};
+	pub use super::rebar_band::RebarBand;
+	#[cfg(feature = "richedit")] pub use super::rich_edit::{RichEdit, RichEditOpts};
 	pub use super::status_bar::{SbPart, StatusBar};
 	pub use super::status_bar_part::StatusBarPart;
+	pub use super::sys_link::{SysLink, SysLinkOpts};
 	pub use super::tab::{Tab, TabOpts};
 	pub use super::tab_item::TabItem;
+	pub use super::toolbar::{Toolbar, ToolbarOpts};
+	pub use super::toolbar_button::ToolbarButton;
+	pub use super::tooltip::{Tooltip, TooltipOpts};
 	pub use super::trackbar::{Trackbar, TrackbarOpts};
 	pub use super::tree_view::{TreeView, TreeViewOpts};
 	pub use super::tree_view_item::TreeViewItem;
 	pub use super::up_down::{UpDown, UpDownOpts};
+	pub use super::virtual_list_view::VirtualListView;
 }
 
 pub mod collections {
 	//! Objects which exposes methods to work upon individual items of certain
 	//! native controls.
 
+	pub use super::combo_box_ex_items::ComboBoxExItems;
 	pub use super::combo_box_items::ComboBoxItems;
 	pub use super::header_items::HeaderItems;
 	pub use super::list_box_items::ListBoxItems;
 	pub use super::list_view_cols::ListViewCols;
 	pub use super::list_view_items::ListViewItems;
+	pub use super::rebar_bands::RebarBands;
 	pub use super::status_bar_parts::StatusBarParts;
 	pub use super::tab_items::TabItems;
+	pub use super::toolbar_buttons::ToolbarButtons;
 	pub use super::tree_view_items::TreeViewItems;
 }
@@ -0,0 +1,162 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{collections::*, events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct RebarObj {
+	base: BaseCtrl,
+	events: RebarEvents,
+	_pin: PhantomPinned,
+}
+
+native_ctrl! { Rebar: RebarObj => RebarEvents;
+	/// Native
+	/// [rebar](https://learn.microsoft.com/en-us/windows/win32/controls/rebar-controls)
+	/// control, which hosts other controls in bands.
+	///
+	/// The rebar is placed at the top of the parent window, and it's
+	/// automatically resized when the parent is resized.
+}
+
+impl Rebar {
+	/// Instantiates a new `Rebar` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Rebar` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let rebar = gui::Rebar::new(&wnd, gui::RebarOpts::default());
+	/// let cmb = gui::ComboBox::new(&wnd, gui::ComboBoxOpts::default());
+	///
+	/// let (rebar2, cmb2) = (rebar.clone(), cmb.clone());
+	/// wnd.on().wm_create(move |_| {
+	///     rebar2.bands().add(
+	///         &cmb2,
+	///         "Fruit",
+	///         w::SIZE::new(gui::dpi_x(120), gui::dpi_y(24)),
+	///         co::RBBS::GRIPPERALWAYS,
+	///     )?;
+	///     Ok(0)
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: RebarOpts) -> Self {
+		let ctrl_id = auto_id::set_if_zero(opts.ctrl_id);
+		let new_self = Self(Arc::pin(RebarObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: RebarEvents::new(parent, ctrl_id),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.0.base.create_window(
+					opts.window_ex_style,
+					"ReBarWindow32",
+					None,
+					opts.window_style | opts.control_style.into(),
+					POINT::default(),
+					SIZE::default(),
+					&parent2,
+				)?;
+				Ok(0) // ignored
+			});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_on().wm_size(move |mut p| {
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				unsafe {
+					self2.hwnd().SendMessage(p.as_generic_wm()); // send WM_SIZE to rebar, so it fits the parent width
+				}
+			}
+			Ok(())
+		});
+
+		new_self
+	}
+
+	/// Band methods.
+	#[must_use]
+	pub const fn bands(&self) -> RebarBands<'_> {
+		RebarBands::new(self)
+	}
+
+	/// Retrieves the height of the rebar, in pixels, by sending a
+	/// [`rb::GetBarHeight`](crate::msg::rb::GetBarHeight) message.
+	///
+	/// This is useful to position the other controls of the parent window
+	/// below the rebar.
+	#[must_use]
+	pub fn height(&self) -> u32 {
+		unsafe { self.hwnd().SendMessage(rb::GetBarHeight {}) }
+	}
+
+	/// Retrieves the number of band rows by sending a
+	/// [`rb::GetRowCount`](crate::msg::rb::GetRowCount) message.
+	#[must_use]
+	pub fn row_count(&self) -> u32 {
+		unsafe { self.hwnd().SendMessage(rb::GetRowCount {}) }
+	}
+}
+
+/// Options to create a [`Rebar`](crate::gui::Rebar) programmatically with
+/// [`Rebar::new`](crate::gui::Rebar::new).
+pub struct RebarOpts {
+	/// Rebar styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `RBS::VARHEIGHT | RBS::BANDBORDERS`.
+	pub control_style: co::RBS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to
+	/// `WS::CHILD | WS::VISIBLE | WS::CLIPCHILDREN | WS::CLIPSIBLINGS | CCS::NODIVIDER`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::TOOLWINDOW`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+}
+
+impl Default for RebarOpts {
+	fn default() -> Self {
+		Self {
+			control_style: co::RBS::VARHEIGHT | co::RBS::BANDBORDERS,
+			window_style: co::WS::CHILD
+				| co::WS::VISIBLE
+				| co::WS::CLIPCHILDREN
+				| co::WS::CLIPSIBLINGS
+				| co::CCS::NODIVIDER.into(),
+			window_ex_style: co::WS_EX::TOOLWINDOW,
+			ctrl_id: 0,
+		}
+	}
+}
@@ -0,0 +1,164 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::*;
+use crate::kernel::privs::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// A single band of a [`Rebar`](crate::gui::Rebar) control.
+///
+/// **Note:** Each object keeps the zero-based index of a band. If new bands are
+/// added/removed from the rebar control, the object may then point to a
+/// different band.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+#[derive(Clone, Copy)]
+pub struct RebarBand<'a> {
+	owner: &'a Rebar,
+	index: u32,
+}
+
+impl<'a> RebarBand<'a> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a Rebar, index: u32) -> Self {
+		Self { owner, index }
+	}
+
+	fn get_info(&self, rbbi: &mut REBARBANDINFO) {
+		unsafe {
+			let _ = self
+				.owner
+				.hwnd()
+				.SendMessage(rb::GetBandInfo { index: self.index, info: rbbi });
+		}
+	}
+
+	fn set_info(&self, rbbi: &REBARBANDINFO) {
+		unsafe {
+			let _ = self
+				.owner
+				.hwnd()
+				.SendMessage(rb::SetBandInfo { index: self.index, info: rbbi });
+		}
+	}
+
+	/// Deletes the band by sending a
+	/// [`rb::DeleteBand`](crate::msg::rb::DeleteBand) message.
+	///
+	/// The child control is not destroyed.
+	pub fn delete(&self) -> SysResult<()> {
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(rb::DeleteBand { index: self.index })
+		}
+	}
+
+	/// Returns the zero-based index of the band.
+	#[must_use]
+	pub const fn index(&self) -> u32 {
+		self.index
+	}
+
+	/// Resizes the band to its largest size by sending a
+	/// [`rb::MaximizeBand`](crate::msg::rb::MaximizeBand) message.
+	///
+	/// Returns the same band, so further operations can be chained.
+	pub fn maximize(&self) -> Self {
+		unsafe {
+			self.owner.hwnd().SendMessage(rb::MaximizeBand {
+				index: self.index,
+				use_ideal_width: false,
+			});
+		}
+		*self
+	}
+
+	/// Resizes the band to its smallest size by sending a
+	/// [`rb::MinimizeBand`](crate::msg::rb::MinimizeBand) message.
+	///
+	/// Returns the same band, so further operations can be chained.
+	pub fn minimize(&self) -> Self {
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(rb::MinimizeBand { index: self.index });
+		}
+		*self
+	}
+
+	/// Retrieves the bounding rectangle of the band, relative to the rebar, by
+	/// sending a [`rb::GetRect`](crate::msg::rb::GetRect) message.
+	#[must_use]
+	pub fn rect(&self) -> SysResult<RECT> {
+		let mut rc = RECT::default();
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(rb::GetRect { index: self.index, rect: &mut rc })?;
+		}
+		Ok(rc)
+	}
+
+	/// Sets the style flags of the band by sending a
+	/// [`rb::SetBandInfo`](crate::msg::rb::SetBandInfo) message.
+	///
+	/// Returns the same band, so further operations can be chained.
+	pub fn set_style(&self, style: co::RBBS) -> Self {
+		let mut rbbi = REBARBANDINFO::default();
+		rbbi.fMask = co::RBBIM::STYLE;
+		rbbi.fStyle = style;
+		self.set_info(&rbbi);
+		*self
+	}
+
+	/// Sets the text of the band by sending a
+	/// [`rb::SetBandInfo`](crate::msg::rb::SetBandInfo) message.
+	///
+	/// Returns the same band, so further operations can be chained.
+	pub fn set_text(&self, text: &str) -> Self {
+		let mut rbbi = REBARBANDINFO::default();
+		rbbi.fMask = co::RBBIM::TEXT;
+
+		let mut wtext = WString::from_str(text);
+		rbbi.set_lpText(Some(&mut wtext));
+
+		self.set_info(&rbbi);
+		*self
+	}
+
+	/// Shows or hides the band by sending a
+	/// [`rb::ShowBand`](crate::msg::rb::ShowBand) message.
+	pub fn show(&self, show: bool) -> SysResult<()> {
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(rb::ShowBand { index: self.index, show })
+		}
+	}
+
+	/// Retrieves the style flags of the band by sending a
+	/// [`rb::GetBandInfo`](crate::msg::rb::GetBandInfo) message.
+	#[must_use]
+	pub fn style(&self) -> co::RBBS {
+		let mut rbbi = REBARBANDINFO::default();
+		rbbi.fMask = co::RBBIM::STYLE;
+		self.get_info(&mut rbbi);
+		rbbi.fStyle
+	}
+
+	/// Retrieves the text of the band by sending a
+	/// [`rb::GetBandInfo`](crate::msg::rb::GetBandInfo) message.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let mut rbbi = REBARBANDINFO::default();
+		rbbi.fMask = co::RBBIM::TEXT;
+
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1); // arbitrary
+		rbbi.set_lpText(Some(&mut buf));
+
+		self.get_info(&mut rbbi);
+		buf.to_string()
+	}
+}
@@ -0,0 +1,105 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Exposes the band methods of a [`Rebar`](crate::gui::Rebar) control.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct RebarBands<'a> {
+	owner: &'a Rebar,
+}
+
+impl<'a> RebarBands<'a> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a Rebar) -> Self {
+		Self { owner }
+	}
+
+	/// Adds a new band hosting the given child control by sending a
+	/// [`rb::InsertBand`](crate::msg::rb::InsertBand) message, returning the
+	/// new band.
+	///
+	/// The child control must be already created, so this method is usually
+	/// called within the parent's
+	/// [`wm_create`](crate::gui::events::WindowEvents::wm_create) event. The
+	/// control is reparented to the rebar, and its size is managed by the
+	/// band, so it should not have a resizing behavior of its own.
+	///
+	/// The band ID is the control ID of the child, so it can later be retrieved
+	/// with [`find`](crate::gui::collections::RebarBands::find).
+	///
+	/// The `style` can be used to start a new row with `RBBS::BREAK`, and to
+	/// control the gripper with `RBBS::GRIPPERALWAYS` or `RBBS::NOGRIPPER`.
+	pub fn add(
+		&self,
+		child: &impl GuiControl,
+		text: &str,
+		min_size: SIZE,
+		style: co::RBBS,
+	) -> SysResult<RebarBand<'a>> {
+		let mut wtext = WString::from_str(text);
+
+		let mut rbbi = REBARBANDINFO::default();
+		rbbi.fMask = co::RBBIM::STYLE
+			| co::RBBIM::TEXT
+			| co::RBBIM::CHILD
+			| co::RBBIM::CHILDSIZE
+			| co::RBBIM::SIZE
+			| co::RBBIM::ID;
+		rbbi.fStyle = style | co::RBBS::CHILDEDGE;
+		rbbi.set_lpText(Some(&mut wtext));
+		rbbi.hwndChild = unsafe { child.hwnd().raw_copy() };
+		rbbi.cxMinChild = min_size.cx as _;
+		rbbi.cyMinChild = min_size.cy as _;
+		rbbi.cx = min_size.cx as _;
+		rbbi.wID = child.ctrl_id() as _;
+
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(rb::InsertBand { index: None, info: &rbbi })?;
+		}
+		Ok(self.get(self.count() - 1))
+	}
+
+	/// Retrieves the number of bands by sending a
+	/// [`rb::GetBandCount`](crate::msg::rb::GetBandCount) message.
+	#[must_use]
+	pub fn count(&self) -> u32 {
+		unsafe { self.owner.hwnd().SendMessage(rb::GetBandCount {}) }
+	}
+
+	/// Deletes all bands by sending
+	/// [`rb::DeleteBand`](crate::msg::rb::DeleteBand) messages.
+	pub fn delete_all(&self) -> SysResult<()> {
+		for index in (0..self.count()).rev() {
+			self.get(index).delete()?;
+		}
+		Ok(())
+	}
+
+	/// Retrieves the band which hosts the child control with the given ID by
+	/// sending a [`rb::IdToIndex`](crate::msg::rb::IdToIndex) message.
+	#[must_use]
+	pub fn find(&self, child_ctrl_id: u16) -> Option<RebarBand<'a>> {
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(rb::IdToIndex { id: child_ctrl_id as _ })
+		}
+		.map(|index| self.get(index))
+	}
+
+	/// Retrieves the band at the given zero-based position.
+	///
+	/// **Note:** This method is cheap – even if `index` is beyond the range of
+	/// existing bands, an object will still be returned. However, operations
+	/// upon this object will produce no effect.
+	#[must_use]
+	pub const fn get(&self, index: u32) -> RebarBand<'a> {
+		RebarBand::new(self.owner, index)
+	}
+}
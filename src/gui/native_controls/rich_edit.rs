@@ -0,0 +1,577 @@
+use std::any::Any;
+use std::io::{Read, Write};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*, *};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct RichEditObj {
+	base: BaseCtrl,
+	events: RichEditEvents,
+	_pin: PhantomPinned,
+}
+
+native_ctrl! { RichEdit: RichEditObj => RichEditEvents;
+	/// Native
+	/// [rich edit](https://learn.microsoft.com/en-us/windows/win32/controls/about-rich-edit-controls)
+	/// control, version 4.1, from Msftedit.dll.
+	///
+	/// Text can be loaded and saved as RTF or plain text, and it can be
+	/// formatted with [`CHARFORMAT2`](crate::CHARFORMAT2) and
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2). A simple RTF document can be built
+	/// with [`RtfWriter`](crate::RtfWriter).
+	///
+	/// **Note:** To use this control, enable the `richedit`
+	/// [Cargo feature](https://doc.rust-lang.org/cargo/reference/features.html#the-features-section).
+}
+
+impl RichEdit {
+	/// Instantiates a new `RichEdit` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `RichEdit` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let txt = gui::RichEdit::new(
+	///     &wnd,
+	///     gui::RichEditOpts {
+	///         position: gui::dpi(10, 10),
+	///         width: gui::dpi_x(300),
+	///         height: gui::dpi_y(200),
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// let txt2 = txt.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     let rtf = w::RtfWriter::new("Segoe UI", 10)
+	///         .run("Hello", &w::RtfStyle { bold: true, ..Default::default() })
+	///         .text(", world.")
+	///         .to_string();
+	///     txt2.set_rtf(&rtf)?;
+	///     Ok(0)
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: RichEditOpts) -> Self {
+		let ctrl_id = auto_id::set_if_zero(opts.ctrl_id);
+		let new_self = Self(Arc::pin(RichEditObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: RichEditEvents::new(parent, ctrl_id),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				load_msftedit()?;
+				self2.0.base.create_window(
+					opts.window_ex_style,
+					"RICHEDIT50W",
+					Some(&opts.text),
+					opts.window_style | opts.control_style.into(),
+					opts.position.into(),
+					SIZE::new(opts.width, opts.height),
+					&parent2,
+				)?;
+				ui_font::set(self2.hwnd())?;
+				self2.set_options(opts.event_mask, opts.auto_url_detect)?;
+				parent2
+					.as_ref()
+					.add_to_layout(self2.hwnd(), opts.resize_behavior)?;
+				Ok(0) // ignored
+			});
+
+		new_self
+	}
+
+	/// Instantiates a new `RichEdit` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// The resource must use the `RICHEDIT50W` window class. The event mask is
+	/// set to `ENM::CHANGE | ENM::SELCHANGE | ENM::LINK`, and URLs are
+	/// automatically detected.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `RichEdit` in an event closure.
+	///
+	/// Panics if Msftedit.dll cannot be loaded.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &(impl GuiParent + 'static),
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self {
+		load_msftedit().unwrap_or_else(|err| panic!("{}", err)); // class must exist before the dialog
+
+		let new_self = Self(Arc::pin(RichEditObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: RichEditEvents::new(parent, ctrl_id),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent.as_ref().before_on().wm_init_dialog(move |_| {
+			self2.0.base.assign_dlg(&parent2)?;
+			let opts = RichEditOpts::default();
+			self2.set_options(opts.event_mask, opts.auto_url_detect)?;
+			parent2
+				.as_ref()
+				.add_to_layout(self2.hwnd(), resize_behavior)?;
+			Ok(true) // ignored
+		});
+
+		new_self
+	}
+
+	fn set_options(&self, event_mask: co::ENM, auto_url_detect: co::AURL) -> SysResult<()> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::SetEventMask { mask: event_mask });
+			self.hwnd()
+				.SendMessage(em::AutoUrlDetect { flags: auto_url_detect })
+		}
+	}
+
+	/// Appends RTF text at the end of the current contents, keeping it, by
+	/// sending an [`em::StreamIn`](crate::msg::em::StreamIn) message.
+	pub fn append_rtf(&self, rtf: &str) -> AnyResult<()> {
+		let len = self.hwnd().GetWindowTextLength()?;
+		self.set_selection(CHARRANGE::new(len, len)); // clamped to the actual text end
+		self.stream_in(co::SF::RTF | co::SF::SELECTION, &mut rtf.as_bytes())?;
+		Ok(())
+	}
+
+	/// Retrieves the character formatting of the selection, or the default
+	/// formatting, by sending an
+	/// [`em::GetCharFormat`](crate::msg::em::GetCharFormat) message.
+	///
+	/// `kind` must be either `SCF::SELECTION` or `SCF::DEFAULT`. For the
+	/// selection, the `dwMask` of the returned struct tells which attributes
+	/// are consistent throughout it.
+	#[must_use]
+	pub fn char_format(&self, kind: co::SCF) -> CHARFORMAT2 {
+		let mut cf = CHARFORMAT2::default();
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::GetCharFormat { kind, format: &mut cf });
+		}
+		cf
+	}
+
+	/// Searches the text by sending an
+	/// [`em::FindTextEx`](crate::msg::em::FindTextEx) message, returning the
+	/// range of the first match.
+	///
+	/// The search starts at the `from` character index. If `flags` has
+	/// `FR::DOWN`, it goes until the end of the text, otherwise it goes
+	/// backwards until the beginning.
+	#[must_use]
+	pub fn find(&self, text: &str, from: i32, flags: co::FR) -> Option<CHARRANGE> {
+		let mut wtext = WString::from_str(text);
+		let mut fte = FINDTEXTEX::default();
+		fte.chrg = CHARRANGE::new(from, if flags.has(co::FR::DOWN) { -1 } else { 0 });
+		fte.set_lpstrText(Some(&mut wtext));
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::FindTextEx { flags, find: &mut fte })
+		}
+		.map(|_| fte.chrgText)
+	}
+
+	/// Retrieves the paragraph formatting of the selection by sending an
+	/// [`em::GetParaFormat`](crate::msg::em::GetParaFormat) message.
+	#[must_use]
+	pub fn para_format(&self) -> PARAFORMAT2 {
+		let mut pf = PARAFORMAT2::default();
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::GetParaFormat { format: &mut pf });
+		}
+		pf
+	}
+
+	/// Replaces all occurrences of `text` with `replacement`, returning how
+	/// many were replaced.
+	///
+	/// The search is made with [`find`](crate::gui::RichEdit::find), and the
+	/// replacement with
+	/// [`replace_sel`](crate::gui::RichEdit::replace_sel). `FR::DOWN` is
+	/// always added to `flags`.
+	pub fn replace_all(&self, text: &str, replacement: &str, flags: co::FR) -> u32 {
+		if text.is_empty() {
+			return 0;
+		}
+
+		let repl_len = replacement.encode_utf16().count() as i32;
+		let mut from = 0;
+		let mut count = 0;
+
+		while let Some(found) = self.find(text, from, flags | co::FR::DOWN) {
+			self.set_selection(found);
+			self.replace_sel(replacement, true);
+			from = found.cpMin + repl_len;
+			count += 1;
+		}
+		count
+	}
+
+	/// Replaces the selected text by sending an
+	/// [`em::ReplaceSel`](crate::msg::em::ReplaceSel) message. If there's no
+	/// selection, the text is inserted at the caret.
+	pub fn replace_sel(&self, replacement: &str, can_be_undone: bool) {
+		unsafe {
+			self.hwnd().SendMessage(em::ReplaceSel {
+				can_be_undone,
+				replacement_text: WString::from_str(replacement),
+			});
+		}
+	}
+
+	/// Retrieves the RTF contents by sending an
+	/// [`em::StreamOut`](crate::msg::em::StreamOut) message.
+	pub fn rtf(&self) -> AnyResult<String> {
+		let mut buf = Vec::<u8>::new();
+		self.stream_out(co::SF::RTF, &mut buf)?;
+		Ok(String::from_utf8_lossy(&buf).into_owned()) // RTF is written as ASCII
+	}
+
+	/// Retrieves the selected range by sending an
+	/// [`em::ExGetSel`](crate::msg::em::ExGetSel) message.
+	#[must_use]
+	pub fn selection(&self) -> CHARRANGE {
+		let mut range = CHARRANGE::default();
+		unsafe {
+			self.hwnd().SendMessage(em::ExGetSel { range: &mut range });
+		}
+		range
+	}
+
+	/// Sets the background color by sending an
+	/// [`em::SetBkgndColor`](crate::msg::em::SetBkgndColor) message. If `None`,
+	/// the system window color is used.
+	pub fn set_bkgnd_color(&self, color: Option<COLORREF>) {
+		unsafe {
+			self.hwnd().SendMessage(em::SetBkgndColor { color });
+		}
+	}
+
+	/// Sets the character formatting of the selection, of the word at the
+	/// caret, of the whole text or the default formatting, by sending an
+	/// [`em::SetCharFormat`](crate::msg::em::SetCharFormat) message.
+	///
+	/// Only the attributes specified in the `dwMask` field are changed.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let txt: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut cf = w::CHARFORMAT2::default();
+	/// cf.dwMask = co::CFM::BOLD | co::CFM::COLOR;
+	/// cf.dwEffects = co::CFE::BOLD;
+	/// cf.crTextColor = w::COLORREF::new(0xc0, 0x00, 0x00);
+	///
+	/// txt.set_char_format(co::SCF::SELECTION, &cf)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn set_char_format(&self, kind: co::SCF, format: &CHARFORMAT2) -> SysResult<()> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::SetCharFormat { kind, format })
+		}
+	}
+
+	/// Sets the paragraph formatting of the selection by sending an
+	/// [`em::SetParaFormat`](crate::msg::em::SetParaFormat) message.
+	///
+	/// Only the attributes specified in the `dwMask` field are changed.
+	pub fn set_para_format(&self, format: &PARAFORMAT2) -> SysResult<()> {
+		unsafe { self.hwnd().SendMessage(em::SetParaFormat { format }) }
+	}
+
+	/// Replaces the whole contents with the given RTF text by sending an
+	/// [`em::StreamIn`](crate::msg::em::StreamIn) message.
+	pub fn set_rtf(&self, rtf: &str) -> AnyResult<()> {
+		self.stream_in(co::SF::RTF, &mut rtf.as_bytes())?;
+		Ok(())
+	}
+
+	/// Sets the selected range by sending an
+	/// [`em::ExSetSel`](crate::msg::em::ExSetSel) message.
+	///
+	/// A range from `0` to `-1` selects all the text.
+	pub fn set_selection(&self, range: CHARRANGE) {
+		unsafe {
+			self.hwnd().SendMessage(em::ExSetSel { range });
+		}
+	}
+
+	/// Sets the plain text by calling
+	/// [`HWND::SetWindowText`](crate::prelude::user_Hwnd::SetWindowText).
+	pub fn set_text(&self, text: &str) -> SysResult<()> {
+		self.hwnd().SetWindowText(text)?;
+		Ok(())
+	}
+
+	/// Replaces the contents, or the selection if `format` has
+	/// `SF::SELECTION`, with data read from `reader`, by sending an
+	/// [`em::StreamIn`](crate::msg::em::StreamIn) message.
+	///
+	/// `format` must have either `SF::RTF` or `SF::TEXT`; add `SF::UNICODE`
+	/// if the plain text is encoded as UTF-16. Returns the number of
+	/// characters read.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let txt: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut file = std::fs::File::open("C:\\Temp\\document.rtf")?;
+	/// txt.stream_in(co::SF::RTF, &mut file)?;
+	/// # w::AnyResult::Ok(())
+	/// ```
+	pub fn stream_in(&self, format: co::SF, reader: &mut impl Read) -> AnyResult<u32> {
+		let mut ctx: StreamCtx<&mut dyn Read> = StreamCtx { io: reader, err: None };
+		let mut es = EDITSTREAM {
+			dwCookie: &mut ctx as *mut _ as _,
+			pfnCallback: Some(stream_in_proc),
+			..Default::default()
+		};
+
+		let num_chars = unsafe {
+			self.hwnd()
+				.SendMessage(em::StreamIn { format, stream: &mut es })
+		};
+		ctx.into_result(es.dwError, co::ERROR::READ_FAULT)
+			.map(|_| num_chars)
+	}
+
+	/// Writes the contents, or the selection if `format` has
+	/// `SF::SELECTION`, to `writer`, by sending an
+	/// [`em::StreamOut`](crate::msg::em::StreamOut) message.
+	///
+	/// `format` must have either `SF::RTF` or `SF::TEXT`; add `SF::UNICODE`
+	/// to write the plain text as UTF-16. Returns the number of characters
+	/// written.
+	pub fn stream_out(&self, format: co::SF, writer: &mut impl Write) -> AnyResult<u32> {
+		let mut ctx: StreamCtx<&mut dyn Write> = StreamCtx { io: writer, err: None };
+		let mut es = EDITSTREAM {
+			dwCookie: &mut ctx as *mut _ as _,
+			pfnCallback: Some(stream_out_proc),
+			..Default::default()
+		};
+
+		let num_chars = unsafe {
+			self.hwnd()
+				.SendMessage(em::StreamOut { format, stream: &mut es })
+		};
+		ctx.into_result(es.dwError, co::ERROR::WRITE_FAULT)
+			.map(|_| num_chars)
+	}
+
+	/// Retrieves the plain text by calling
+	/// [`HWND::GetWindowText`](crate::prelude::user_Hwnd::GetWindowText).
+	#[must_use]
+	pub fn text(&self) -> SysResult<String> {
+		self.hwnd().GetWindowText()
+	}
+
+	/// Retrieves the plain text within the given range by sending an
+	/// [`em::GetTextRange`](crate::msg::em::GetTextRange) message.
+	#[must_use]
+	pub fn text_range(&self, range: CHARRANGE) -> String {
+		let len = if range.cpMax < 0 {
+			self.hwnd().GetWindowTextLength().unwrap_or(0) - range.cpMin
+		} else {
+			range.cpMax - range.cpMin
+		};
+		let mut buf = WString::new_alloc_buf(len.max(0) as usize + 1); // room for terminating null
+
+		let mut tr = TEXTRANGE::default();
+		tr.chrg = range;
+		tr.set_lpstrText(Some(&mut buf));
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::GetTextRange { range: &mut tr });
+		}
+		buf.to_string()
+	}
+}
+
+/// Options to create a [`RichEdit`](crate::gui::RichEdit) programmatically
+/// with [`RichEdit::new`](crate::gui::RichEdit::new).
+pub struct RichEditOpts {
+	/// Text of the control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to empty string.
+	pub text: String,
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi(0, 0)`.
+	pub position: (i32, i32),
+	/// Control width to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi_x(200)`.
+	pub width: i32,
+	/// Control height to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `gui::dpi_y(120)`.
+	pub height: i32,
+	/// Edit styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `ES::MULTILINE | ES::WANTRETURN | ES::AUTOVSCROLL | ES::NOHIDESEL`.
+	pub control_style: co::ES,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::GROUP | WS::TABSTOP | WS::VISIBLE | WS::VSCROLL`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT | WS_EX::CLIENTEDGE`.
+	pub window_ex_style: co::WS_EX,
+	/// Notifications to be sent to the parent window, to be
+	/// [set](https://learn.microsoft.com/en-us/windows/win32/controls/em-seteventmask).
+	///
+	/// Defaults to `ENM::CHANGE | ENM::SELCHANGE | ENM::LINK`.
+	pub event_mask: co::ENM,
+	/// Kinds of URL to be automatically detected and shown as links, to be
+	/// [set](https://learn.microsoft.com/en-us/windows/win32/controls/em-autourldetect).
+	///
+	/// Defaults to `AURL::ENABLEURL`.
+	pub auto_url_detect: co::AURL,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for RichEditOpts {
+	fn default() -> Self {
+		Self {
+			text: "".to_owned(),
+			position: dpi(0, 0),
+			width: dpi_x(200),
+			height: dpi_y(120),
+			control_style: co::ES::MULTILINE
+				| co::ES::WANTRETURN
+				| co::ES::AUTOVSCROLL
+				| co::ES::NOHIDESEL,
+			window_style: co::WS::CHILD
+				| co::WS::GROUP
+				| co::WS::TABSTOP
+				| co::WS::VISIBLE
+				| co::WS::VSCROLL,
+			window_ex_style: co::WS_EX::LEFT | co::WS_EX::CLIENTEDGE,
+			event_mask: co::ENM::CHANGE | co::ENM::SELCHANGE | co::ENM::LINK,
+			auto_url_detect: co::AURL::ENABLEURL,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
+
+/// Loads Msftedit.dll, which registers the `RICHEDIT50W` window class. The
+/// library is loaded only once, and kept until the process ends.
+fn load_msftedit() -> SysResult<()> {
+	static MSFTEDIT: OnceLock<SysResult<()>> = OnceLock::new();
+	*MSFTEDIT.get_or_init(|| {
+		HINSTANCE::LoadLibrary("Msftedit.dll").map(|mut hlib| {
+			let _ = hlib.leak(); // never freed
+		})
+	})
+}
+
+/// State passed as the `dwCookie` of the `EDITSTREAM` callbacks.
+struct StreamCtx<T> {
+	io: T,
+	err: Option<std::io::Error>,
+}
+
+impl<T> StreamCtx<T> {
+	/// Returns the I/O error caught by the callback, if any, otherwise the
+	/// error reported by the control.
+	fn into_result(self, dw_error: u32, ctrl_err: co::ERROR) -> AnyResult<()> {
+		match self.err {
+			Some(err) => Err(err.into()),
+			None if dw_error != 0 => Err(ctrl_err.into()),
+			None => Ok(()),
+		}
+	}
+}
+
+extern "system" fn stream_in_proc(cookie: usize, buf: *mut u8, cb: i32, pcb: *mut i32) -> u32 {
+	let ctx = unsafe { &mut *(cookie as *mut StreamCtx<&mut dyn Read>) };
+	let buf = unsafe { std::slice::from_raw_parts_mut(buf, cb as _) };
+	match ctx.io.read(buf) {
+		Ok(num_bytes) => {
+			unsafe { *pcb = num_bytes as _ }; // zero ends the stream
+			0
+		},
+		Err(err) => {
+			ctx.err = Some(err);
+			1 // any non-zero aborts the stream
+		},
+	}
+}
+
+extern "system" fn stream_out_proc(cookie: usize, buf: *mut u8, cb: i32, pcb: *mut i32) -> u32 {
+	let ctx = unsafe { &mut *(cookie as *mut StreamCtx<&mut dyn Write>) };
+	let buf = unsafe { std::slice::from_raw_parts(buf, cb as _) };
+	match ctx.io.write_all(buf) {
+		Ok(_) => {
+			unsafe { *pcb = cb };
+			0
+		},
+		Err(err) => {
+			ctx.err = Some(err);
+			1 // any non-zero aborts the stream
+		},
+	}
+}
@@ -0,0 +1,194 @@
+use std::any::Any;
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::gui::{collections::*, events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct ToolbarObj {
+	base: BaseCtrl,
+	events: ToolbarEvents,
+	himagelist: UnsafeCell<Option<ImageListDestroyGuard>>,
+	_pin: PhantomPinned,
+}
+
+native_ctrl! { Toolbar: ToolbarObj => ToolbarEvents;
+	/// Native
+	/// [toolbar](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-controls-overview)
+	/// control, which has buttons and separators.
+	///
+	/// The toolbar is placed at the top of the parent window, and it's
+	/// automatically resized when the parent is resized.
+	///
+	/// When a button is clicked, the parent window receives a
+	/// [`WM_COMMAND`](crate::gui::events::WindowEvents::wm_command_accel_menu)
+	/// message with the command ID of the button.
+}
+
+impl Toolbar {
+	/// Instantiates a new `Toolbar` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Toolbar` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// const ID_NEW: u16 = 1001;
+	/// const ID_BOLD: u16 = 1002;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+	///
+	/// let toolbar2 = toolbar.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     toolbar2.buttons().add(ID_NEW, "New", None, co::BTNS::AUTOSIZE)?;
+	///     toolbar2.buttons().add_separator()?;
+	///     toolbar2.buttons().add(ID_BOLD, "Bold", None, co::BTNS::CHECK | co::BTNS::AUTOSIZE)?;
+	///     Ok(0)
+	/// });
+	///
+	/// wnd.on().wm_command_accel_menu(ID_NEW, move || {
+	///     println!("New clicked");
+	///     Ok(())
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: ToolbarOpts) -> Self {
+		let ctrl_id = auto_id::set_if_zero(opts.ctrl_id);
+		let new_self = Self(Arc::pin(ToolbarObj {
+			base: BaseCtrl::new(ctrl_id),
+			events: ToolbarEvents::new(parent, ctrl_id),
+			himagelist: UnsafeCell::new(None),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.0.base.create_window(
+					opts.window_ex_style,
+					"ToolbarWindow32",
+					None,
+					opts.window_style | opts.control_style.into(),
+					POINT::default(),
+					SIZE::default(),
+					&parent2,
+				)?;
+				unsafe {
+					self2.hwnd().SendMessage(tbm::ButtonStructSize {
+						size: std::mem::size_of::<TBBUTTON>() as _,
+					});
+					self2
+						.hwnd()
+						.SendMessage(tbm::SetExtendedStyle { style: opts.control_ex_style });
+					self2.hwnd().SendMessage(tbm::AutoSize {});
+				}
+				Ok(0) // ignored
+			});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_on().wm_size(move |p| {
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				unsafe {
+					self2.hwnd().SendMessage(tbm::AutoSize {}); // fit the parent width
+				}
+			}
+			Ok(())
+		});
+
+		new_self
+	}
+
+	/// Button methods.
+	#[must_use]
+	pub const fn buttons(&self) -> ToolbarButtons<'_> {
+		ToolbarButtons::new(self)
+	}
+
+	/// Returns a reference to the image list set with
+	/// [`set_image_list`](crate::gui::Toolbar::set_image_list), if any.
+	#[must_use]
+	pub fn image_list(&self) -> Option<&HIMAGELIST> {
+		unsafe { &*self.0.himagelist.get() }
+			.as_ref()
+			.map(|hil| &**hil)
+	}
+
+	/// Sets the image list of the buttons by sending a
+	/// [`tbm::SetImageList`](crate::msg::tbm::SetImageList) message.
+	///
+	/// Unlike other controls, the toolbar doesn't destroy its image list, so
+	/// the image list will be kept by this object until it's dropped. Returns
+	/// the previous one, if any.
+	pub fn set_image_list(
+		&self,
+		himagelist: ImageListDestroyGuard,
+	) -> Option<ImageListDestroyGuard> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(tbm::SetImageList { himagelist: himagelist.raw_copy() });
+			self.hwnd().SendMessage(tbm::AutoSize {});
+		}
+		unsafe { &mut *self.0.himagelist.get() }.replace(himagelist)
+	}
+}
+
+/// Options to create a [`Toolbar`](crate::gui::Toolbar) programmatically with
+/// [`Toolbar::new`](crate::gui::Toolbar::new).
+pub struct ToolbarOpts {
+	/// Toolbar styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `TBSTYLE::FLAT | TBSTYLE::LIST | TBSTYLE::TOOLTIPS`.
+	pub control_style: co::TBSTYLE,
+	/// Extended toolbar styles to be
+	/// [set](https://learn.microsoft.com/en-us/windows/win32/controls/tb-setextendedstyle).
+	///
+	/// Defaults to `TBSTYLE_EX::DRAWDDARROWS`.
+	pub control_ex_style: co::TBSTYLE_EX,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+}
+
+impl Default for ToolbarOpts {
+	fn default() -> Self {
+		Self {
+			control_style: co::TBSTYLE::FLAT | co::TBSTYLE::LIST | co::TBSTYLE::TOOLTIPS,
+			control_ex_style: co::TBSTYLE_EX::DRAWDDARROWS,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+		}
+	}
+}
@@ -0,0 +1,203 @@
+use crate::co;
+use crate::comctl::privs::*;
+use crate::decl::*;
+use crate::gui::*;
+use crate::kernel::privs::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// A single button of a [`Toolbar`](crate::gui::Toolbar) control, which can
+/// also be a separator.
+///
+/// **Note:** Each object keeps the zero-based index of a button. If new buttons
+/// are added/removed from the toolbar control, the object may then point to a
+/// different button.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+#[derive(Clone, Copy)]
+pub struct ToolbarButton<'a> {
+	owner: &'a Toolbar,
+	index: u32,
+}
+
+impl<'a> ToolbarButton<'a> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a Toolbar, index: u32) -> Self {
+		Self { owner, index }
+	}
+
+	fn get_info(&self, tbbi: &mut TBBUTTONINFO) {
+		tbbi.dwMask |= co::TBIF::BYINDEX;
+		unsafe {
+			let _ = self.owner.hwnd().SendMessage(tbm::GetButtonInfo {
+				btn_cmd_id: self.index as _,
+				info: tbbi,
+			});
+		}
+	}
+
+	fn set_info(&self, tbbi: &mut TBBUTTONINFO) {
+		tbbi.dwMask |= co::TBIF::BYINDEX;
+		unsafe {
+			let _ = self.owner.hwnd().SendMessage(tbm::SetButtonInfo {
+				btn_cmd_id: self.index as _,
+				info: tbbi,
+			});
+		}
+	}
+
+	/// Checks or unchecks the button by sending a
+	/// [`tbm::CheckButton`](crate::msg::tbm::CheckButton) message.
+	///
+	/// Returns the same button, so further operations can be chained.
+	pub fn check(&self, check: bool) -> Self {
+		unsafe {
+			let _ = self.owner.hwnd().SendMessage(tbm::CheckButton {
+				btn_cmd_id: self.cmd_id(),
+				check,
+			});
+		}
+		*self
+	}
+
+	/// Retrieves the command ID of the button, which is sent to the parent
+	/// window within
+	/// [`WM_COMMAND`](crate::gui::events::WindowEvents::wm_command_accel_menu),
+	/// by sending a [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo)
+	/// message.
+	#[must_use]
+	pub fn cmd_id(&self) -> u16 {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::COMMAND;
+		self.get_info(&mut tbbi);
+		tbbi.idCommand as _
+	}
+
+	/// Deletes the button by sending a
+	/// [`tbm::DeleteButton`](crate::msg::tbm::DeleteButton) message.
+	pub fn delete(&self) -> SysResult<()> {
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(tbm::DeleteButton { btn_index: self.index })
+		}
+	}
+
+	/// Enables or disables the button by sending a
+	/// [`tbm::EnableButton`](crate::msg::tbm::EnableButton) message.
+	///
+	/// Returns the same button, so further operations can be chained.
+	pub fn enable(&self, enable: bool) -> Self {
+		unsafe {
+			let _ = self.owner.hwnd().SendMessage(tbm::EnableButton {
+				btn_cmd_id: self.cmd_id(),
+				enable,
+			});
+		}
+		*self
+	}
+
+	/// Retrieves the index of the image of the button, within the image list
+	/// of the toolbar, by sending a
+	/// [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo) message.
+	#[must_use]
+	pub fn image_index(&self) -> Option<u32> {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::IMAGE;
+		self.get_info(&mut tbbi);
+		if tbbi.iImage < 0 { None } else { Some(tbbi.iImage as _) }
+	}
+
+	/// Returns the zero-based index of the button.
+	#[must_use]
+	pub const fn index(&self) -> u32 {
+		self.index
+	}
+
+	/// Tells whether the button is checked by retrieving its
+	/// [`state`](crate::gui::ToolbarButton::state).
+	#[must_use]
+	pub fn is_checked(&self) -> bool {
+		self.state().has(co::TBSTATE::CHECKED)
+	}
+
+	/// Tells whether the button is enabled by retrieving its
+	/// [`state`](crate::gui::ToolbarButton::state).
+	#[must_use]
+	pub fn is_enabled(&self) -> bool {
+		self.state().has(co::TBSTATE::ENABLED)
+	}
+
+	/// Tells whether this is a separator by retrieving its
+	/// [`style`](crate::gui::ToolbarButton::style).
+	#[must_use]
+	pub fn is_separator(&self) -> bool {
+		self.style().has(co::BTNS::SEP)
+	}
+
+	/// Sets the index of the image of the button, within the image list of the
+	/// toolbar, by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	///
+	/// Returns the same button, so further operations can be chained.
+	pub fn set_image_index(&self, image_index: Option<u32>) -> Self {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::IMAGE;
+		tbbi.iImage = image_index.map_or(I_IMAGENONE as _, |idx| idx as _);
+		self.set_info(&mut tbbi);
+		*self
+	}
+
+	/// Sets the text of the button by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	///
+	/// Returns the same button, so further operations can be chained.
+	pub fn set_text(&self, text: &str) -> Self {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::TEXT;
+
+		let mut wtext = WString::from_str(text);
+		tbbi.set_pszText(Some(&mut wtext));
+
+		self.set_info(&mut tbbi);
+		unsafe {
+			self.owner.hwnd().SendMessage(tbm::AutoSize {});
+		}
+		*self
+	}
+
+	/// Retrieves the state flags of the button by sending a
+	/// [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo) message.
+	#[must_use]
+	pub fn state(&self) -> co::TBSTATE {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::STATE;
+		self.get_info(&mut tbbi);
+		tbbi.fsState
+	}
+
+	/// Retrieves the style flags of the button by sending a
+	/// [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo) message.
+	#[must_use]
+	pub fn style(&self) -> co::BTNS {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::STYLE;
+		self.get_info(&mut tbbi);
+		tbbi.fsStyle
+	}
+
+	/// Retrieves the text of the button by sending a
+	/// [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo) message.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::TEXT;
+
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1); // arbitrary
+		tbbi.set_pszText(Some(&mut buf));
+
+		self.get_info(&mut tbbi);
+		buf.to_string()
+	}
+}
@@ -0,0 +1,117 @@
+use crate::co;
+use crate::comctl::privs::*;
+use crate::decl::*;
+use crate::gui::{iterators::*, *};
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Exposes the button methods of a [`Toolbar`](crate::gui::Toolbar) control.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct ToolbarButtons<'a> {
+	owner: &'a Toolbar,
+}
+
+impl<'a> ToolbarButtons<'a> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a Toolbar) -> Self {
+		Self { owner }
+	}
+
+	/// Adds a new button by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message, returning the
+	/// new button.
+	///
+	/// The `image_index` refers to the image list set with
+	/// [`Toolbar::set_image_list`](crate::gui::Toolbar::set_image_list). The
+	/// `style` can be used to create check, group and dropdown buttons.
+	pub fn add(
+		&self,
+		cmd_id: u16,
+		text: &str,
+		image_index: Option<u32>,
+		style: co::BTNS,
+	) -> SysResult<ToolbarButton<'a>> {
+		let mut text = IdxStr::Str(WString::from_str(text));
+
+		let mut tbb = TBBUTTON::default();
+		tbb.iBitmap = image_index.map_or(I_IMAGENONE as _, |idx| idx as _);
+		tbb.idCommand = cmd_id as _;
+		tbb.fsState = co::TBSTATE::ENABLED;
+		tbb.fsStyle = style;
+		tbb.set_iString(&mut text);
+
+		self.add_raw(tbb)?;
+		Ok(self.get(self.count() - 1))
+	}
+
+	/// Adds a new separator by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message.
+	pub fn add_separator(&self) -> SysResult<()> {
+		let mut tbb = TBBUTTON::default();
+		tbb.fsStyle = co::BTNS::SEP;
+		self.add_raw(tbb)
+	}
+
+	fn add_raw(&self, tbb: TBBUTTON) -> SysResult<()> {
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(tbm::AddButtons { buttons: &mut [tbb] })?;
+			self.owner.hwnd().SendMessage(tbm::AutoSize {});
+		}
+		Ok(())
+	}
+
+	/// Retrieves the total number of buttons, including separators, by sending
+	/// a [`tbm::ButtonCount`](crate::msg::tbm::ButtonCount) message.
+	#[must_use]
+	pub fn count(&self) -> u32 {
+		unsafe { self.owner.hwnd().SendMessage(tbm::ButtonCount {}) }
+	}
+
+	/// Deletes all buttons by sending
+	/// [`tbm::DeleteButton`](crate::msg::tbm::DeleteButton) messages.
+	pub fn delete_all(&self) -> SysResult<()> {
+		for index in (0..self.count()).rev() {
+			self.get(index).delete()?;
+		}
+		Ok(())
+	}
+
+	/// Retrieves the button with the given command ID by sending a
+	/// [`tbm::CommandToIndex`](crate::msg::tbm::CommandToIndex) message.
+	#[must_use]
+	pub fn find(&self, cmd_id: u16) -> Option<ToolbarButton<'a>> {
+		unsafe {
+			self.owner
+				.hwnd()
+				.SendMessage(tbm::CommandToIndex { btn_cmd_id: cmd_id })
+		}
+		.map(|index| self.get(index))
+	}
+
+	/// Retrieves the button at the given zero-based position.
+	///
+	/// **Note:** This method is cheap – even if `index` is beyond the range of
+	/// existing buttons, an object will still be returned. However, operations
+	/// upon this object will produce no effect.
+	#[must_use]
+	pub const fn get(&self, index: u32) -> ToolbarButton<'a> {
+		ToolbarButton::new(self.owner, index)
+	}
+
+	/// Returns an iterator over all buttons, including separators.
+	#[must_use]
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = ToolbarButton<'a>> + 'a {
+		ToolbarButtonIter::new(self.owner)
+	}
+
+	/// Returns the last button, if any.
+	#[must_use]
+	pub fn last(&self) -> Option<ToolbarButton<'a>> {
+		let count = self.count();
+		if count > 0 { Some(self.get(count - 1)) } else { None }
+	}
+}
@@ -0,0 +1,464 @@
+use std::any::Any;
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Where the tool is.
+enum ToolKind {
+	Ctrl(Box<dyn GuiWindow>),
+	Rect(RECT),
+	Track,
+}
+
+/// How the tool text is retrieved.
+enum ToolText {
+	Static(WString),
+	Callback(Box<dyn Fn() -> AnyResult<String>>),
+}
+
+struct ToolEntry {
+	id: u32,
+	kind: ToolKind,
+	text: ToolText,
+}
+
+struct TooltipObj {
+	base: BaseCtrl,
+	hparent: UnsafeCell<HWND>,
+	tools: UnsafeCell<Vec<ToolEntry>>,
+	next_id: UnsafeCell<u32>,
+	text_buf: UnsafeCell<WString>, // kept alive while the callback text is displayed
+	_pin: PhantomPinned,
+}
+
+native_ctrl! { Tooltip: TooltipObj;
+	/// Native
+	/// [tooltip](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-controls)
+	/// control, which can be attached to any other control, or to a rectangle
+	/// of the parent window.
+	///
+	/// The tooltip is a popup window, which is created after all the other
+	/// controls of the parent window. Tools added before that are kept and
+	/// registered at creation.
+}
+
+impl Tooltip {
+	/// Instantiates a new `Tooltip` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Tooltip` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let btn: gui::Button;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// let tip = gui::Tooltip::new(
+	///     &wnd,
+	///     gui::TooltipOpts {
+	///         max_tip_width: Some(300),
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// tip.add_tool(&btn, "Saves the current file.")?;
+	///
+	/// let wnd2 = wnd.clone();
+	/// tip.add_rect_tool_fn(w::RECT { left: 0, top: 0, right: 100, bottom: 20 }, move || {
+	///     let rc = wnd2.hwnd().GetClientRect()?;
+	///     Ok(format!("Client area is {}x{}.", rc.right, rc.bottom))
+	/// })?;
+	/// # w::SysResult::<()>::Ok(())
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: TooltipOpts) -> Self {
+		let new_self = Self(Arc::pin(TooltipObj {
+			base: BaseCtrl::new(auto_id::next()),
+			hparent: UnsafeCell::new(HWND::NULL),
+			tools: UnsafeCell::new(Vec::new()),
+			next_id: UnsafeCell::new(1),
+			text_buf: UnsafeCell::new(WString::new()),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		let parent2 = parent.clone();
+		parent
+			.as_ref()
+			.after_on() // created after all other controls, so their HWNDs are available
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.0.base.create_popup(
+					opts.window_ex_style,
+					"tooltips_class32",
+					co::WS::POPUP | opts.control_style.into(),
+					&parent2,
+				)?;
+				*unsafe { &mut *self2.0.hparent.get() } = unsafe { parent2.hwnd().raw_copy() };
+
+				if opts.max_tip_width.is_some() {
+					self2.set_max_tip_width(opts.max_tip_width);
+				}
+				[
+					(co::TTDT::INITIAL, opts.initial_delay),
+					(co::TTDT::AUTOPOP, opts.autopop_delay),
+					(co::TTDT::RESHOW, opts.reshow_delay),
+				]
+				.into_iter()
+				.filter(|(_, time_ms)| time_ms.is_some())
+				.for_each(|(which, time_ms)| self2.set_delay_time(which, time_ms));
+
+				for entry in unsafe { &mut *self2.0.tools.get() }.iter_mut() {
+					self2.register_tool(entry)?;
+				}
+				Ok(0) // ignored
+			});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_on().wm(co::WM::NOTIFY, move |p| {
+			let wm_nfy = unsafe { wm::Notify::from_generic_wm(p) };
+			if *self2.hwnd() != HWND::NULL
+				&& wm_nfy.nmhdr.hwndFrom == *self2.hwnd()
+				&& wm_nfy.nmhdr.code == co::TTN::GETDISPINFO.into()
+			{
+				self2.fill_disp_info(unsafe { wm_nfy.cast_nmhdr_mut::<NMTTDISPINFO>() })?;
+			}
+			Ok(0) // ignored
+		});
+
+		new_self
+	}
+
+	fn fill_disp_info(&self, di: &mut NMTTDISPINFO) -> AnyResult<()> {
+		let tools = unsafe { &*self.0.tools.get() };
+		if let Some(entry) = tools.iter().find(|entry| entry.id as isize == di.lParam) {
+			if let ToolText::Callback(func) = &entry.text {
+				let text_buf = unsafe { &mut *self.0.text_buf.get() };
+				*text_buf = WString::from_str(&func()?);
+				di.set_lpszText(Some(text_buf));
+			}
+		}
+		Ok(())
+	}
+
+	fn tool_info<'a>(&self, entry: &'a mut ToolEntry) -> TTTOOLINFO<'a> {
+		let mut ti = TTTOOLINFO::default();
+		ti.hwnd = unsafe { (*self.0.hparent.get()).raw_copy() };
+		ti.lParam = entry.id as _; // sent back in TTN_GETDISPINFO
+
+		match &entry.kind {
+			ToolKind::Ctrl(ctrl) => {
+				ti.uFlags = co::TTF::IDISHWND | co::TTF::SUBCLASS;
+				ti.uId = ctrl.hwnd().ptr() as _;
+			},
+			ToolKind::Rect(rc) => {
+				ti.uFlags = co::TTF::SUBCLASS;
+				ti.uId = entry.id as _;
+				ti.rect = *rc;
+			},
+			ToolKind::Track => {
+				ti.uFlags = co::TTF::TRACK | co::TTF::ABSOLUTE;
+				ti.uId = entry.id as _;
+			},
+		}
+
+		match &mut entry.text {
+			ToolText::Static(wtext) => ti.set_lpszText(Some(wtext)),
+			ToolText::Callback(_) => ti.set_lpszText_callback(),
+		}
+		ti
+	}
+
+	fn register_tool(&self, entry: &mut ToolEntry) -> SysResult<()> {
+		let ti = self.tool_info(entry);
+		unsafe { self.hwnd().SendMessage(ttm::AddTool { info: &ti }) }
+	}
+
+	fn add_entry(&self, kind: ToolKind, text: ToolText) -> SysResult<u32> {
+		let next_id = unsafe { &mut *self.0.next_id.get() };
+		let id = *next_id;
+		*next_id += 1;
+
+		let tools = unsafe { &mut *self.0.tools.get() };
+		tools.push(ToolEntry { id, kind, text });
+		if *self.hwnd() != HWND::NULL {
+			self.register_tool(tools.last_mut().unwrap())?;
+		}
+		Ok(id)
+	}
+
+	fn with_entry<F>(&self, tool_id: u32, func: F) -> Option<()>
+	where
+		F: FnOnce(&mut ToolEntry),
+	{
+		unsafe { &mut *self.0.tools.get() }
+			.iter_mut()
+			.find(|entry| entry.id == tool_id)
+			.map(func)
+	}
+
+	/// Attaches a tool to the given control, showing the given text. A control
+	/// can have only one tool.
+	///
+	/// If the tooltip is not created yet, the tool will be registered at
+	/// creation. Returns the tool ID.
+	pub fn add_tool(
+		&self,
+		ctrl: &(impl GuiControl + Clone + 'static),
+		text: &str,
+	) -> SysResult<u32> {
+		self.add_entry(
+			ToolKind::Ctrl(Box::new(ctrl.clone())),
+			ToolText::Static(WString::from_str(text)),
+		)
+	}
+
+	/// Attaches a tool to the given control, whose text is retrieved by the
+	/// closure each time the tooltip is about to be shown, through
+	/// [`TTN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-getdispinfo).
+	///
+	/// If the tooltip is not created yet, the tool will be registered at
+	/// creation. Returns the tool ID.
+	pub fn add_tool_fn<F>(
+		&self,
+		ctrl: &(impl GuiControl + Clone + 'static),
+		func: F,
+	) -> SysResult<u32>
+	where
+		F: Fn() -> AnyResult<String> + 'static,
+	{
+		self.add_entry(ToolKind::Ctrl(Box::new(ctrl.clone())), ToolText::Callback(Box::new(func)))
+	}
+
+	/// Attaches a tool to a rectangle of the parent client area, showing the
+	/// given text.
+	///
+	/// If the tooltip is not created yet, the tool will be registered at
+	/// creation. Returns the tool ID.
+	pub fn add_rect_tool(&self, rc: RECT, text: &str) -> SysResult<u32> {
+		self.add_entry(ToolKind::Rect(rc), ToolText::Static(WString::from_str(text)))
+	}
+
+	/// Attaches a tool to a rectangle of the parent client area, whose text is
+	/// retrieved by the closure each time the tooltip is about to be shown,
+	/// through
+	/// [`TTN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-getdispinfo).
+	///
+	/// If the tooltip is not created yet, the tool will be registered at
+	/// creation. Returns the tool ID.
+	pub fn add_rect_tool_fn<F>(&self, rc: RECT, func: F) -> SysResult<u32>
+	where
+		F: Fn() -> AnyResult<String> + 'static,
+	{
+		self.add_entry(ToolKind::Rect(rc), ToolText::Callback(Box::new(func)))
+	}
+
+	/// Adds a tracking tool, which is shown and hidden with
+	/// [`track_activate`](crate::gui::Tooltip::track_activate), and positioned
+	/// with [`track_position`](crate::gui::Tooltip::track_position).
+	///
+	/// If the tooltip is not created yet, the tool will be registered at
+	/// creation. Returns the tool ID.
+	pub fn add_track_tool(&self, text: &str) -> SysResult<u32> {
+		self.add_entry(ToolKind::Track, ToolText::Static(WString::from_str(text)))
+	}
+
+	/// Removes the tool with the given ID.
+	///
+	/// # Panics
+	///
+	/// Panics if the tool ID doesn't exist.
+	pub fn del_tool(&self, tool_id: u32) {
+		let tools = unsafe { &mut *self.0.tools.get() };
+		let idx = tools
+			.iter()
+			.position(|entry| entry.id == tool_id)
+			.unwrap_or_else(|| panic!("Tool ID {} doesn't exist.", tool_id));
+
+		let mut entry = tools.remove(idx);
+		if *self.hwnd() != HWND::NULL {
+			let ti = self.tool_info(&mut entry);
+			unsafe {
+				self.hwnd().SendMessage(ttm::DelTool { info: &ti });
+			}
+		}
+	}
+
+	/// Replaces the text of the tool with the given ID. If the tool text was
+	/// retrieved by a closure, the closure is discarded.
+	///
+	/// # Panics
+	///
+	/// Panics if the tool ID doesn't exist.
+	pub fn set_tool_text(&self, tool_id: u32, text: &str) {
+		self.with_entry(tool_id, |entry| {
+			entry.text = ToolText::Static(WString::from_str(text));
+			if *self.hwnd() != HWND::NULL {
+				let ti = self.tool_info(entry);
+				unsafe {
+					self.hwnd().SendMessage(ttm::UpdateTipText { info: &ti });
+				}
+			}
+		})
+		.unwrap_or_else(|| panic!("Tool ID {} doesn't exist.", tool_id));
+	}
+
+	/// Moves the rectangle of a tool added with
+	/// [`add_rect_tool`](crate::gui::Tooltip::add_rect_tool) or
+	/// [`add_rect_tool_fn`](crate::gui::Tooltip::add_rect_tool_fn).
+	///
+	/// # Panics
+	///
+	/// Panics if the tool ID doesn't exist, or if it's not a rectangle tool.
+	pub fn set_tool_rect(&self, tool_id: u32, rc: RECT) {
+		self.with_entry(tool_id, |entry| {
+			match &mut entry.kind {
+				ToolKind::Rect(cur_rc) => *cur_rc = rc,
+				_ => panic!("Tool ID {} is not a rectangle tool.", tool_id),
+			}
+			if *self.hwnd() != HWND::NULL {
+				let ti = self.tool_info(entry);
+				unsafe {
+					self.hwnd().SendMessage(ttm::NewToolRect { info: &ti });
+				}
+			}
+		})
+		.unwrap_or_else(|| panic!("Tool ID {} doesn't exist.", tool_id));
+	}
+
+	/// Shows or hides a tool added with
+	/// [`add_track_tool`](crate::gui::Tooltip::add_track_tool), by sending a
+	/// [`ttm::TrackActivate`](crate::msg::ttm::TrackActivate) message.
+	///
+	/// # Panics
+	///
+	/// Panics if the tool ID doesn't exist.
+	pub fn track_activate(&self, tool_id: u32, activate: bool) {
+		self.with_entry(tool_id, |entry| {
+			let ti = self.tool_info(entry);
+			unsafe {
+				self.hwnd()
+					.SendMessage(ttm::TrackActivate { activate, info: &ti });
+			}
+		})
+		.unwrap_or_else(|| panic!("Tool ID {} doesn't exist.", tool_id));
+	}
+
+	/// Sets the position of the active tracking tool, in screen coordinates,
+	/// by sending a [`ttm::TrackPosition`](crate::msg::ttm::TrackPosition)
+	/// message.
+	pub fn track_position(&self, coords: POINT) {
+		unsafe {
+			self.hwnd().SendMessage(ttm::TrackPosition { coords });
+		}
+	}
+
+	/// Activates or deactivates the tooltip by sending a
+	/// [`ttm::Activate`](crate::msg::ttm::Activate) message.
+	pub fn activate(&self, activate: bool) {
+		unsafe {
+			self.hwnd().SendMessage(ttm::Activate { activate });
+		}
+	}
+
+	/// Sets one of the delay times by sending a
+	/// [`ttm::SetDelayTime`](crate::msg::ttm::SetDelayTime) message. If
+	/// `time_ms` is `None`, the default delay is restored.
+	pub fn set_delay_time(&self, which: co::TTDT, time_ms: Option<u16>) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(ttm::SetDelayTime { which, time_ms });
+		}
+	}
+
+	/// Sets the maximum width of the tooltip by sending a
+	/// [`ttm::SetMaxTipWidth`](crate::msg::ttm::SetMaxTipWidth) message. A
+	/// width allows multiline text, which is broken at word boundaries or at
+	/// line breaks; `None` removes the limit.
+	///
+	/// Returns the previous width.
+	pub fn set_max_tip_width(&self, width: Option<i32>) -> Option<i32> {
+		unsafe { self.hwnd().SendMessage(ttm::SetMaxTipWidth { width }) }
+	}
+
+	/// Sets the title and the icon of the tooltip by sending a
+	/// [`ttm::SetTitle`](crate::msg::ttm::SetTitle) message.
+	pub fn set_title(&self, icon: co::TTI, title: &str) -> SysResult<()> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(ttm::SetTitle { icon, title: WString::from_str(title) })
+		}
+	}
+
+	/// Hides the tooltip by sending a [`ttm::Pop`](crate::msg::ttm::Pop)
+	/// message.
+	pub fn pop(&self) {
+		unsafe {
+			self.hwnd().SendMessage(ttm::Pop {});
+		}
+	}
+}
+
+/// Options to create a [`Tooltip`](crate::gui::Tooltip) programmatically with
+/// [`Tooltip::new`](crate::gui::Tooltip::new).
+pub struct TooltipOpts {
+	/// Tooltip styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Use `TTS::BALLOON` for a balloon-shaped tooltip.
+	///
+	/// Defaults to `TTS::ALWAYSTIP | TTS::NOPREFIX`.
+	pub control_style: co::TTS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::TOPMOST`.
+	pub window_ex_style: co::WS_EX,
+
+	/// Maximum width of the tooltip, in pixels, which allows multiline text.
+	///
+	/// Defaults to `None`.
+	pub max_tip_width: Option<i32>,
+	/// Time, in milliseconds, the pointer must remain stationary before the
+	/// tooltip appears.
+	///
+	/// Defaults to `None`, which uses the system default.
+	pub initial_delay: Option<u16>,
+	/// Time, in milliseconds, the tooltip remains visible.
+	///
+	/// Defaults to `None`, which uses the system default.
+	pub autopop_delay: Option<u16>,
+	/// Time, in milliseconds, for subsequent tooltips to appear as the pointer
+	/// moves from one tool to another.
+	///
+	/// Defaults to `None`, which uses the system default.
+	pub reshow_delay: Option<u16>,
+}
+
+impl Default for TooltipOpts {
+	fn default() -> Self {
+		Self {
+			control_style: co::TTS::ALWAYSTIP | co::TTS::NOPREFIX,
+			window_ex_style: co::WS_EX::TOPMOST,
+			max_tip_width: None,
+			initial_delay: None,
+			autopop_delay: None,
+			reshow_delay: None,
+		}
+	}
+}
@@ -0,0 +1,255 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Sorting and selection bookkeeping of a
+/// [`VirtualListView`](crate::gui::VirtualListView), which maps the positions
+/// displayed by the control to the rows of the data source.
+///
+/// This object doesn't touch any window, so it can be reasoned about
+/// independently of the control.
+pub(in crate::gui) struct VirtualListState {
+	order: Vec<u32>,           // view index -> source row
+	sort: Option<(u32, bool)>, // column index, ascending
+}
+
+impl VirtualListState {
+	#[must_use]
+	pub(in crate::gui) const fn new() -> Self {
+		Self { order: Vec::new(), sort: None }
+	}
+
+	/// Returns the number of rows currently mapped.
+	#[must_use]
+	pub(in crate::gui) fn count(&self) -> u32 {
+		self.order.len() as _
+	}
+
+	/// Returns the source row displayed at the given position, if any.
+	#[must_use]
+	pub(in crate::gui) fn row(&self, view_index: u32) -> Option<u32> {
+		self.order.get(view_index as usize).copied()
+	}
+
+	/// Returns the current sort column and direction, if any.
+	#[must_use]
+	pub(in crate::gui) const fn sort(&self) -> Option<(u32, bool)> {
+		self.sort
+	}
+
+	/// Sets the sort column and direction, without sorting.
+	pub(in crate::gui) fn set_sort(&mut self, sort: Option<(u32, bool)>) {
+		self.sort = sort;
+	}
+
+	/// Computes the sort state after the user clicks the given column: the
+	/// same column toggles the direction, another column sorts ascending.
+	pub(in crate::gui) fn toggle_sort(&mut self, column: u32) -> (u32, bool) {
+		let new_sort = match self.sort {
+			Some((cur_col, ascending)) if cur_col == column => (column, !ascending),
+			_ => (column, true),
+		};
+		self.sort = Some(new_sort);
+		new_sort
+	}
+
+	/// Rebuilds the mapping for the given number of source rows, then sorts it
+	/// with the current sort state.
+	///
+	/// The comparator receives two source rows and the sort column.
+	pub(in crate::gui) fn reset<F>(&mut self, row_count: u32, compare: F)
+	where
+		F: FnMut(u32, u32, u32) -> Ordering,
+	{
+		self.order.clear();
+		self.order.extend(0..row_count);
+		self.apply_sort(compare);
+	}
+
+	/// Sorts the mapping with the current sort state. If there is no sort
+	/// state, the source order is restored.
+	///
+	/// The sort is stable, so rows which compare equal keep their relative
+	/// order.
+	pub(in crate::gui) fn apply_sort<F>(&mut self, mut compare: F)
+	where
+		F: FnMut(u32, u32, u32) -> Ordering,
+	{
+		match self.sort {
+			None => self.order.sort_unstable(),
+			Some((column, ascending)) => self.order.sort_by(|a, b| {
+				let ord = compare(*a, *b, column);
+				if ascending {
+					ord
+				} else {
+					ord.reverse()
+				}
+			}),
+		}
+	}
+
+	/// Returns the positions, in ascending order, of the rows whose keys are
+	/// in the given set.
+	#[must_use]
+	pub(in crate::gui) fn view_indexes_of<K, F>(&self, keys: &HashSet<K>, mut key_of: F) -> Vec<u32>
+	where
+		K: Eq + Hash,
+		F: FnMut(u32) -> K,
+	{
+		if keys.is_empty() {
+			return Vec::new();
+		}
+		self.order
+			.iter()
+			.enumerate()
+			.filter(|(_, row)| keys.contains(&key_of(**row)))
+			.map(|(view_index, _)| view_index as _)
+			.collect()
+	}
+
+	/// Searches for the first position, starting at `start`, whose text starts
+	/// with `prefix`, ignoring case. If `wrap` is `true`, the search continues
+	/// from the first position.
+	#[must_use]
+	pub(in crate::gui) fn find_prefix<F>(
+		&self,
+		start: u32,
+		prefix: &str,
+		wrap: bool,
+		mut text_of: F,
+	) -> Option<u32>
+	where
+		F: FnMut(u32) -> String,
+	{
+		let count = self.order.len();
+		let start = (start as usize).min(count);
+		let prefix = prefix.to_lowercase();
+		let positions = (start..count).chain(if wrap { 0..start } else { 0..0 });
+
+		for view_index in positions {
+			if text_of(self.order[view_index])
+				.to_lowercase()
+				.starts_with(&prefix)
+			{
+				return Some(view_index as _);
+			}
+		}
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Column 0 compares the names, column 1 their lengths.
+	fn compare(source: &[&str], a: u32, b: u32, column: u32) -> Ordering {
+		let (a, b) = (source[a as usize], source[b as usize]);
+		match column {
+			0 => a.cmp(b),
+			_ => a.len().cmp(&b.len()),
+		}
+	}
+
+	fn loaded(source: &[&str], sort: Option<(u32, bool)>) -> VirtualListState {
+		let mut state = VirtualListState::new();
+		state.set_sort(sort);
+		state.reset(source.len() as _, |a, b, col| compare(source, a, b, col));
+		state
+	}
+
+	fn rows(state: &VirtualListState) -> Vec<u32> {
+		(0..state.count()).map(|i| state.row(i).unwrap()).collect()
+	}
+
+	fn view_indexes(state: &VirtualListState, source: &[&str], keys: &[&str]) -> Vec<u32> {
+		let keys = keys.iter().map(|k| k.to_string()).collect::<HashSet<_>>();
+		state.view_indexes_of(&keys, |row| source[row as usize].to_owned())
+	}
+
+	const SOURCE: [&str; 4] = ["delta", "alpha", "charlie", "bravo"];
+
+	#[test]
+	fn unsorted() {
+		let state = loaded(&SOURCE, None);
+		assert_eq!(state.count(), 4);
+		assert_eq!(rows(&state), [0, 1, 2, 3]);
+		assert_eq!(state.row(4), None);
+	}
+
+	#[test]
+	fn toggle_sort() {
+		let mut state = loaded(&SOURCE, None);
+		let resort = |state: &mut VirtualListState| {
+			state.apply_sort(|a, b, col| compare(&SOURCE, a, b, col));
+			rows(state)
+		};
+
+		assert_eq!(state.toggle_sort(0), (0, true));
+		assert_eq!(resort(&mut state), [1, 3, 2, 0]);
+		assert_eq!(state.toggle_sort(0), (0, false));
+		assert_eq!(resort(&mut state), [0, 2, 3, 1]);
+		assert_eq!(state.toggle_sort(1), (1, true)); // another column starts ascending
+		assert_eq!(resort(&mut state), [0, 3, 1, 2]); // equal lengths keep the previous order
+		assert_eq!(state.toggle_sort(1), (1, false));
+		assert_eq!(resort(&mut state), [2, 0, 3, 1]);
+
+		state.set_sort(None);
+		assert_eq!(resort(&mut state), [0, 1, 2, 3]); // source order restored
+	}
+
+	#[test]
+	fn resort_keeps_selection() {
+		let mut state = loaded(&SOURCE, None);
+		let selected = [SOURCE[0], SOURCE[2]]; // positions 0 and 2
+		assert_eq!(view_indexes(&state, &SOURCE, &selected), [0, 2]);
+
+		state.set_sort(Some((0, true)));
+		state.apply_sort(|a, b, col| compare(&SOURCE, a, b, col));
+		assert_eq!(view_indexes(&state, &SOURCE, &selected), [2, 3]);
+
+		state.set_sort(Some((0, false)));
+		state.apply_sort(|a, b, col| compare(&SOURCE, a, b, col));
+		assert_eq!(view_indexes(&state, &SOURCE, &selected), [0, 1]);
+	}
+
+	#[test]
+	fn source_shrink() {
+		let mut state = loaded(&SOURCE, Some((0, true)));
+		let selected = ["charlie", "bravo"];
+		assert_eq!(view_indexes(&state, &SOURCE, &selected), [1, 2]);
+
+		let shrunk = ["delta", "alpha", "bravo"]; // "charlie" removed, rows shifted
+		state.reset(shrunk.len() as _, |a, b, col| compare(&shrunk, a, b, col));
+		assert_eq!(state.count(), 3);
+		assert_eq!(rows(&state), [1, 2, 0]);
+		assert_eq!(state.row(3), None);
+		assert_eq!(view_indexes(&state, &shrunk, &selected), [1]);
+		assert_eq!(state.sort(), Some((0, true))); // sort state survives
+
+		state.reset(0, |_, _, _| unreachable!());
+		assert_eq!(state.count(), 0);
+		assert_eq!(state.row(0), None);
+		assert!(view_indexes(&state, &[], &selected).is_empty());
+		assert_eq!(state.find_prefix(0, "a", true, |_| unreachable!()), None);
+	}
+
+	#[test]
+	fn empty_keys() {
+		let state = loaded(&SOURCE, None);
+		let none = state.view_indexes_of(&HashSet::<String>::new(), |_| unreachable!());
+		assert!(none.is_empty());
+	}
+
+	#[test]
+	fn find_prefix() {
+		let state = loaded(&SOURCE, Some((0, true))); // alpha, bravo, charlie, delta
+		let text_of = |row: u32| SOURCE[row as usize].to_owned();
+		assert_eq!(state.find_prefix(0, "CH", false, text_of), Some(2));
+		assert_eq!(state.find_prefix(3, "b", false, text_of), None);
+		assert_eq!(state.find_prefix(3, "b", true, text_of), Some(1)); // wrapped
+		assert_eq!(state.find_prefix(2, "", false, text_of), Some(2));
+		assert_eq!(state.find_prefix(99, "a", true, text_of), Some(0));
+		assert_eq!(state.find_prefix(0, "x", true, text_of), None);
+	}
+}
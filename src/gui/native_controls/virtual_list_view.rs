@@ -0,0 +1,406 @@
+use std::any::Any;
+use std::cell::{Ref, RefCell};
+use std::collections::HashSet;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::comctl::privs::*;
+use crate::decl::*;
+use crate::gui::{native_controls::virtual_list_state::VirtualListState, *};
+use crate::prelude::*;
+
+struct VirtualListViewObj<S: ListViewDataSource> {
+	list: ListView,
+	source: RefCell<S>,
+	state: RefCell<VirtualListState>,
+	_pin: PhantomPinned,
+}
+
+/// A virtual [`ListView`](crate::gui::ListView) – that is, created with
+/// [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA) style –, whose rows are
+/// provided on demand by a [`ListViewDataSource`](crate::prelude::ListViewDataSource).
+///
+/// Since the items are not stored by the control, it can display a very large
+/// number of rows. The control:
+///
+/// * answers `LVN_GETDISPINFO` with the texts, icons and states of the source;
+/// * sorts the rows when a column is clicked, showing the arrow in the header;
+/// * answers `LVN_ODFINDITEM`, so the user can type to search the first column;
+/// * keeps the selection by row key when the model changes.
+///
+/// After changing the model, call
+/// [`update`](crate::gui::VirtualListView::update) or
+/// [`refresh`](crate::gui::VirtualListView::refresh).
+pub struct VirtualListView<S: ListViewDataSource>(Pin<Arc<VirtualListViewObj<S>>>);
+
+unsafe impl<S: ListViewDataSource> Send for VirtualListView<S> {}
+
+impl<S: ListViewDataSource> Clone for VirtualListView<S> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<S: ListViewDataSource> GuiWindow for VirtualListView<S> {
+	fn hwnd(&self) -> &HWND {
+		self.0.list.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl<S: ListViewDataSource> GuiControl for VirtualListView<S> {
+	fn ctrl_id(&self) -> u16 {
+		self.0.list.ctrl_id()
+	}
+}
+
+impl<S: ListViewDataSource> VirtualListView<S> {
+	/// Instantiates a new `VirtualListView` object, to be created on the parent
+	/// window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// The [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA) style is always added,
+	/// and [`LVS::NOSORTHEADER`](crate::co::LVS::NOSORTHEADER) is always
+	/// removed, so the columns can be clicked to sort.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `VirtualListView` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// struct Numbers(Vec<u32>);
+	///
+	/// impl ListViewDataSource for Numbers {
+	///     type Key = u32;
+	///     fn row_count(&self) -> u32 { self.0.len() as _ }
+	///     fn key(&self, row: u32) -> u32 { self.0[row as usize] }
+	///     fn text(&self, row: u32, _: u32) -> String { self.0[row as usize].to_string() }
+	///     fn compare(&self, a: u32, b: u32, _: u32) -> std::cmp::Ordering {
+	///         self.0[a as usize].cmp(&self.0[b as usize])
+	///     }
+	/// }
+	///
+	/// const ID_EVEN_ONLY: u16 = 1001;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let list = gui::VirtualListView::new(
+	///     &wnd,
+	///     gui::ListViewOpts {
+	///         columns: vec![("Number".to_owned(), 120)],
+	///         ..Default::default()
+	///     },
+	///     Numbers((0..100_000).collect()),
+	/// );
+	///
+	/// let list2 = list.clone();
+	/// wnd.on().wm_command_accel_menu(ID_EVEN_ONLY, move || {
+	///     list2.update(|numbers| numbers.0.retain(|n| n % 2 == 0))?;
+	///     Ok(())
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), opts: ListViewOpts, source: S) -> Self {
+		let mut opts = opts;
+		opts.control_style = (opts.control_style | co::LVS::OWNERDATA) & !co::LVS::NOSORTHEADER;
+
+		let new_self = Self(Arc::pin(VirtualListViewObj {
+			list: ListView::new(parent, opts),
+			source: RefCell::new(source),
+			state: RefCell::new(VirtualListState::new()),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.refresh()?; // list view was created by its own handler
+				Ok(0) // ignored
+			});
+
+		new_self.default_message_handlers(parent);
+		new_self
+	}
+
+	/// Instantiates a new `VirtualListView` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// The resource must have the
+	/// [`LVS::OWNERDATA`](crate::co::LVS::OWNERDATA) style, which cannot be
+	/// set after the control is created.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `VirtualListView` in an event closure.
+	///
+	/// Panics if the context menu, when specified, does not exist.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &(impl GuiParent + 'static),
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+		context_menu_id: Option<u16>,
+		source: S,
+	) -> Self {
+		let new_self = Self(Arc::pin(VirtualListViewObj {
+			list: ListView::new_dlg(parent, ctrl_id, resize_behavior, context_menu_id),
+			source: RefCell::new(source),
+			state: RefCell::new(VirtualListState::new()),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_on().wm_init_dialog(move |_| {
+			self2.refresh()?; // list view was assigned by its own handler
+			Ok(true) // ignored
+		});
+
+		new_self.default_message_handlers(parent);
+		new_self
+	}
+
+	fn default_message_handlers(&self, parent: &impl GuiParent) {
+		let self2 = self.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm_notify(self.ctrl_id(), co::LVN::GETDISPINFO, move |p| {
+				let di = unsafe { p.cast_nmhdr_mut::<NMLVDISPINFO>() };
+				let row = match self2.0.state.borrow().row(di.item.iItem as _) {
+					Some(row) => row,
+					None => return Ok(0), // item beyond the mapped rows
+				};
+				let source = match self2.0.source.try_borrow() {
+					Ok(source) => source,
+					Err(_) => return Ok(0), // source being updated, will be redrawn
+				};
+
+				if di.item.mask.has(co::LVIF::TEXT) {
+					let (psz, cch) = di.item.raw_pszText();
+					if !psz.is_null() && cch > 0 {
+						let text = WString::from_str(source.text(row, di.item.iSubItem as _));
+						text.copy_to_slice(unsafe {
+							std::slice::from_raw_parts_mut(psz, cch as _)
+						});
+					}
+				}
+				if di.item.mask.has(co::LVIF::IMAGE) && di.item.iSubItem == 0 {
+					di.item.iImage = source.icon(row).map_or(I_IMAGENONE as _, |idx| idx as _);
+				}
+				if di.item.mask.has(co::LVIF::STATE) {
+					di.item.state = source.state(row) & di.item.stateMask;
+				}
+				Ok(0) // ignored
+			});
+
+		let self2 = self.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm_notify(self.ctrl_id(), co::LVN::COLUMNCLICK, move |p| {
+				let nmlv = unsafe { p.cast_nmhdr::<NMLISTVIEW>() };
+				let new_sort = self2.0.state.borrow_mut().toggle_sort(nmlv.iSubItem as _);
+				self2.set_sort(Some(new_sort))?;
+				Ok(0) // ignored
+			});
+
+		let self2 = self.clone();
+		self.0.list.on().lvn_od_find_item(move |p| {
+			if !p.lvfi.flags.has(co::LVFI::STRING) && !p.lvfi.flags.has(co::LVFI::PARTIAL) {
+				return Ok(None); // only text searches are supported
+			}
+			let prefix = match p.lvfi.psz() {
+				Some(prefix) => prefix,
+				None => return Ok(None),
+			};
+			let source = self2.0.source.borrow();
+			Ok(self2.0.state.borrow().find_prefix(
+				p.iStart.max(0) as _,
+				&prefix,
+				p.lvfi.flags.has(co::LVFI::WRAP),
+				|row| source.text(row, 0),
+			))
+		});
+	}
+
+	/// Returns the underlying [`ListView`](crate::gui::ListView), which can be
+	/// used to manage columns, image lists and events.
+	///
+	/// Since the items are provided by the data source, the item methods which
+	/// add or change items have no effect.
+	#[must_use]
+	pub fn list(&self) -> &ListView {
+		&self.0.list
+	}
+
+	/// Re-reads the number of rows from the data source, re-applies the
+	/// current sort, restores the selection and the focus by row key, and
+	/// redraws the control by sending an
+	/// [`lvm::SetItemCount`](crate::msg::lvm::SetItemCount) message.
+	///
+	/// The keys of the selected rows are read before reloading, so if the data
+	/// source is changed through interior mutability, the selection may not be
+	/// restored; prefer [`update`](crate::gui::VirtualListView::update).
+	pub fn refresh(&self) -> SysResult<()> {
+		let selected_keys = self.selected_keys().into_iter().collect::<HashSet<_>>();
+		let focused_key = self.focused_key();
+		self.reload(&selected_keys, focused_key)
+	}
+
+	fn reload(
+		&self,
+		selected_keys: &HashSet<S::Key>,
+		focused_key: Option<S::Key>,
+	) -> SysResult<()> {
+		let new_count = {
+			let source = self.0.source.borrow();
+			let mut state = self.0.state.borrow_mut();
+			state.reset(source.row_count(), |a, b, col| source.compare(a, b, col));
+			state.count()
+		};
+
+		self.0
+			.list
+			.items()
+			.set_count(new_count, Some(co::LVSICF::NOSCROLL))?;
+		self.restore_selection(selected_keys, focused_key)
+	}
+
+	fn restore_selection(
+		&self,
+		selected_keys: &HashSet<S::Key>,
+		focused_key: Option<S::Key>,
+	) -> SysResult<()> {
+		let items = self.0.list.items();
+		items.select_all(false)?;
+
+		let (selected_idxs, focused_idx) = {
+			let source = self.0.source.borrow();
+			let state = self.0.state.borrow();
+			let selected_idxs = state.view_indexes_of(selected_keys, |row| source.key(row));
+			let focused_idx = focused_key.and_then(|focused_key| {
+				state
+					.view_indexes_of(&HashSet::from([focused_key]), |row| source.key(row))
+					.first()
+					.copied()
+			});
+			(selected_idxs, focused_idx)
+		};
+
+		for idx in selected_idxs.iter() {
+			items.get(*idx).select(true)?;
+		}
+		if let Some(focused_idx) = focused_idx {
+			items.get(focused_idx).focus()?;
+		}
+		Ok(())
+	}
+
+	/// Returns the source row displayed at the given zero-based position, if
+	/// any.
+	#[must_use]
+	pub fn row(&self, view_index: u32) -> Option<u32> {
+		self.0.state.borrow().row(view_index)
+	}
+
+	/// Returns the key of the focused row, if any.
+	#[must_use]
+	pub fn focused_key(&self) -> Option<S::Key> {
+		self.0
+			.list
+			.items()
+			.focused()
+			.and_then(|item| self.row(item.index()))
+			.map(|row| self.0.source.borrow().key(row))
+	}
+
+	/// Selects the rows with the given keys, deselecting all others.
+	pub fn select_keys(&self, keys: &[S::Key]) -> SysResult<()> {
+		let keys = keys.iter().cloned().collect::<HashSet<_>>();
+		self.restore_selection(&keys, self.focused_key())
+	}
+
+	/// Returns the keys of the selected rows, in the displayed order.
+	#[must_use]
+	pub fn selected_keys(&self) -> Vec<S::Key> {
+		let source = self.0.source.borrow();
+		self.0
+			.list
+			.items()
+			.iter_selected()
+			.filter_map(|item| self.row(item.index()))
+			.map(|row| source.key(row))
+			.collect()
+	}
+
+	/// Sets the sort column and direction – `true` for ascending –, updating
+	/// the arrows of the header, then sorts the rows. If `None`, the rows are
+	/// displayed in the source order.
+	///
+	/// The selection is kept by row key.
+	pub fn set_sort(&self, sort: Option<(u32, bool)>) -> SysResult<()> {
+		self.0.state.borrow_mut().set_sort(sort);
+
+		if let Some(header) = self.0.list.header() {
+			for idx in 0..header.items().count()? {
+				header.items().get(idx).set_arrow(match sort {
+					Some((col, true)) if col == idx => HeaderArrow::Asc,
+					Some((col, false)) if col == idx => HeaderArrow::Desc,
+					_ => HeaderArrow::None,
+				});
+			}
+		}
+
+		self.refresh()
+	}
+
+	/// Returns the current sort column and direction – `true` for ascending –,
+	/// if any.
+	#[must_use]
+	pub fn sort(&self) -> Option<(u32, bool)> {
+		self.0.state.borrow().sort()
+	}
+
+	/// Returns a reference to the data source.
+	///
+	/// # Panics
+	///
+	/// Panics if the data source is being updated.
+	#[must_use]
+	pub fn source(&self) -> Ref<'_, S> {
+		self.0.source.borrow()
+	}
+
+	/// Changes the data source through the given closure, then reloads the
+	/// rows like [`refresh`](crate::gui::VirtualListView::refresh), keeping
+	/// the selection by row key.
+	///
+	/// # Panics
+	///
+	/// Panics if the data source is already borrowed.
+	pub fn update<F>(&self, func: F) -> SysResult<()>
+	where
+		F: FnOnce(&mut S),
+	{
+		let selected_keys = self.selected_keys().into_iter().collect::<HashSet<_>>();
+		let focused_key = self.focused_key();
+
+		func(&mut self.0.source.borrow_mut());
+		self.reload(&selected_keys, focused_key)
+	}
+}
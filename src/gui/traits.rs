@@ -1,5 +1,8 @@
 use std::any::Any;
+use std::cmp::Ordering;
+use std::hash::Hash;
 
+use crate::co;
 use crate::decl::*;
 use crate::gui::{events::*, privs::*};
 use crate::msg::*;
@@ -50,6 +53,16 @@ pub trait GuiWindow: Send {
 	/// ```
 	#[must_use]
 	fn as_any(&self) -> &dyn Any;
+
+	/// Returns the current DPI of the window, which depends on the monitor it's
+	/// displayed in, by calling
+	/// [`HWND::GetDpiForWindow`](crate::prelude::user_Hwnd::GetDpiForWindow).
+	///
+	/// Returns zero if the window was not created yet.
+	#[must_use]
+	fn dpi(&self) -> u32 {
+		self.hwnd().GetDpiForWindow()
+	}
 }
 
 /// Any window which can host child controls.
@@ -242,3 +255,98 @@ pub trait GuiControl: GuiWindow {
 		Ok(())
 	}
 }
+
+/// Data source of a [`VirtualListView`](crate::gui::VirtualListView), which
+/// provides the contents of the rows on demand.
+///
+/// Rows are identified by their zero-based index within the source, which is
+/// independent of the position displayed by the control, since the control can
+/// be sorted. Each row also has a key, which identifies it across model
+/// changes, and it's used to keep the selection.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// struct People(Vec<(u32, String, u32)>); // ID, name, age
+///
+/// impl ListViewDataSource for People {
+///     type Key = u32;
+///
+///     fn row_count(&self) -> u32 {
+///         self.0.len() as _
+///     }
+///
+///     fn key(&self, row: u32) -> Self::Key {
+///         self.0[row as usize].0
+///     }
+///
+///     fn text(&self, row: u32, column: u32) -> String {
+///         let (_, name, age) = &self.0[row as usize];
+///         match column {
+///             0 => name.clone(),
+///             _ => age.to_string(),
+///         }
+///     }
+///
+///     fn compare(&self, row_a: u32, row_b: u32, column: u32) -> std::cmp::Ordering {
+///         let (a, b) = (&self.0[row_a as usize], &self.0[row_b as usize]);
+///         match column {
+///             0 => a.1.cmp(&b.1),
+///             _ => a.2.cmp(&b.2),
+///         }
+///     }
+/// }
+/// ```
+pub trait ListViewDataSource: 'static {
+	/// The type which uniquely identifies a row across model changes.
+	type Key: Clone + Eq + Hash;
+
+	/// Returns the number of rows.
+	#[must_use]
+	fn row_count(&self) -> u32;
+
+	/// Returns the key of the given row.
+	#[must_use]
+	fn key(&self, row: u32) -> Self::Key;
+
+	/// Returns the text of the given row, at the given column.
+	#[must_use]
+	fn text(&self, row: u32, column: u32) -> String;
+
+	/// Returns the index of the icon of the given row, within the image list
+	/// of the control, if any.
+	///
+	/// By default, returns `None`.
+	#[must_use]
+	fn icon(&self, row: u32) -> Option<u32> {
+		let _ = row;
+		None
+	}
+
+	/// Returns the overlay and state image flags of the given row, like
+	/// [`LVIS::STATEIMAGEMASK`](crate::co::LVIS::STATEIMAGEMASK) bits for
+	/// checkboxes. Selection and focus are managed by the control itself.
+	///
+	/// By default, returns `LVIS::NoValue`.
+	#[must_use]
+	fn state(&self, row: u32) -> co::LVIS {
+		let _ = row;
+		co::LVIS::NoValue
+	}
+
+	/// Compares two rows by the given column, when the control is sorted.
+	///
+	/// By default, compares the texts of the column.
+	#[must_use]
+	fn compare(&self, row_a: u32, row_b: u32, column: u32) -> Ordering {
+		self.text(row_a, column).cmp(&self.text(row_b, column))
+	}
+}
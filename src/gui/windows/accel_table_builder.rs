@@ -0,0 +1,110 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{privs::*, *};
+use crate::prelude::*;
+
+enum Binding {
+	Func(Box<dyn Fn() -> AnyResult<()>>),
+	Cmd(u16),
+}
+
+/// Builder of an accelerator table from shortcut strings like
+/// `"Ctrl+Shift+S"`, as parsed by [`ACCEL::parse`](crate::ACCEL::parse).
+///
+/// Each shortcut is either routed to its own closure, with an automatically
+/// assigned command ID, or bound to an existing command ID, like the one of a
+/// menu item.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// const ID_FILE_OPEN: u16 = 2001; // handled somewhere else
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// gui::AccelTableBuilder::new()
+///     .command("Ctrl+O", ID_FILE_OPEN)
+///     .bind("Ctrl+Shift+S", move || {
+///         println!("Save as");
+///         Ok(())
+///     })
+///     .build(&wnd)?; // the window now owns the table
+///
+/// wnd.run_main(None)?;
+/// # w::AnyResult::Ok(())
+/// ```
+#[derive(Default)]
+pub struct AccelTableBuilder {
+	bindings: Vec<(String, Binding)>,
+}
+
+impl AccelTableBuilder {
+	/// Creates a new, empty `AccelTableBuilder`.
+	#[must_use]
+	pub fn new() -> Self {
+		Self { bindings: Vec::new() }
+	}
+
+	/// Adds a shortcut which calls the closure.
+	#[must_use]
+	pub fn bind<F>(mut self, shortcut: &str, func: F) -> Self
+	where
+		F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.bindings
+			.push((shortcut.to_owned(), Binding::Func(Box::new(func))));
+		self
+	}
+
+	/// Adds a shortcut which sends a
+	/// [`WM_COMMAND`](crate::msg::wm::Command) with the given command ID.
+	#[must_use]
+	pub fn command(mut self, shortcut: &str, cmd_id: u16) -> Self {
+		self.bindings
+			.push((shortcut.to_owned(), Binding::Cmd(cmd_id)));
+		self
+	}
+
+	/// Creates the accelerator table, routes the closures to the window, and
+	/// sets the table with
+	/// [`WindowMain::set_accel_table`](crate::gui::WindowMain::set_accel_table),
+	/// replacing any table given in the options.
+	///
+	/// Returns [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if a shortcut string is not valid.
+	///
+	/// # Panics
+	///
+	/// Panics if the window was already created.
+	pub fn build(self, wnd: &WindowMain) -> SysResult<()> {
+		if *wnd.hwnd() != HWND::NULL {
+			panic!("Cannot build an accelerator table after window creation.");
+		}
+
+		let mut accels = Vec::<ACCEL>::with_capacity(self.bindings.len());
+		let mut funcs = Vec::<(u16, Box<dyn Fn() -> AnyResult<()>>)>::new();
+		for (shortcut, binding) in self.bindings.into_iter() {
+			let cmd_id = match binding {
+				Binding::Func(func) => {
+					let cmd_id = auto_id::next();
+					funcs.push((cmd_id, func));
+					cmd_id
+				},
+				Binding::Cmd(cmd_id) => cmd_id,
+			};
+			accels.push(ACCEL::parse(&shortcut, cmd_id)?);
+		}
+
+		let haccel = HACCEL::CreateAcceleratorTable(&accels)?;
+		for (cmd_id, func) in funcs.into_iter() {
+			wnd.as_ref()
+				.before_on()
+				.wm_command(cmd_id, co::CMD::Accelerator, func);
+		}
+		wnd.set_accel_table(haccel);
+		Ok(())
+	}
+}
@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use crate::co;
 use crate::decl::*;
 use crate::gui::{events::*, privs::*, *};
@@ -13,6 +15,7 @@ struct ThreadPack {
 pub(in crate::gui) struct BaseWnd {
 	hwnd: HWND,
 	is_dlg: IsDlg,
+	dpi: Cell<u32>, // DPI of the current contents, updated when moved to another monitor
 	layout: Layout,
 	before_events: WindowEvents,
 	user_events: WindowEvents,
@@ -27,6 +30,7 @@ impl BaseWnd {
 		let new_self = Self {
 			hwnd: HWND::NULL,
 			is_dlg,
+			dpi: Cell::new(0),
 			layout: Layout::new(),
 			before_events: WindowEvents::new(is_dlg),
 			user_events: WindowEvents::new(is_dlg),
@@ -44,7 +48,19 @@ impl BaseWnd {
 	pub(in crate::gui) const fn hwnd(&self) -> &HWND {
 		&self.hwnd
 	}
+	/// Returns the DPI the contents of the window are currently scaled to.
+	#[must_use]
+	pub(in crate::gui) fn dpi(&self) -> u32 {
+		self.dpi.get()
+	}
+
 	pub(in crate::gui) fn set_hwnd(&mut self, hwnd: HWND) {
+		if hwnd != HWND::NULL {
+			self.dpi.set(match self.is_dlg {
+				IsDlg::Yes => hwnd.GetDpiForWindow(), // dialog manager scales the template
+				IsDlg::No => system_dpi(),            // contents are created with dpi_x() and dpi_y()
+			});
+		}
 		self.hwnd = hwnd
 	}
 
@@ -64,6 +80,38 @@ impl BaseWnd {
 		&self.after_events
 	}
 
+	/// Rescales the children and the layout when the window is moved to a
+	/// monitor with a different DPI. Top-level windows receive `WM_DPICHANGED`,
+	/// and the child windows receive `WM_DPICHANGED_AFTERPARENT`.
+	///
+	/// Runs before any other handler, so they see the rescaled contents.
+	pub(in crate::gui) fn process_dpi_change(&self, p: WndMsg) -> AnyResult<()> {
+		if p.msg_id != co::WM::DPICHANGED && p.msg_id != co::WM::DPICHANGED_AFTERPARENT {
+			return Ok(());
+		}
+
+		let from_dpi = self.dpi.get();
+		let to_dpi = self.hwnd.GetDpiForWindow();
+		if from_dpi != 0 && to_dpi != 0 && from_dpi != to_dpi {
+			self.dpi.set(to_dpi);
+			let is_raw = matches!(self.is_dlg, IsDlg::No); // dialog manager scales dialog controls
+			rescale_children(&self.hwnd, from_dpi, to_dpi, is_raw)?;
+			self.layout.rescale(from_dpi, to_dpi);
+		}
+
+		if p.msg_id == co::WM::DPICHANGED && matches!(self.is_dlg, IsDlg::No) {
+			let msg = unsafe { wm::DpiChanged::from_generic_wm(p) };
+			let rc = *msg.suggested_rect;
+			self.hwnd.SetWindowPos(
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+			)?; // triggers WM_SIZE, so the layout is rearranged
+		}
+		Ok(())
+	}
+
 	pub(in crate::gui) fn process_before_messages(&self, p: WndMsg) -> AnyResult<bool> {
 		self.before_events.process_all_messages(p)
 	}
@@ -1,9 +1,11 @@
+use std::cell::UnsafeCell;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::gui::privs::*;
 use crate::prelude::*;
 
@@ -11,6 +13,7 @@ struct DlgMainObj {
 	dlg_base: DlgBase,
 	icon_id: Option<u16>,
 	accel_tbl_id: Option<u16>,
+	accel_table: UnsafeCell<Option<DestroyAcceleratorTableGuard>>, // set after construction, replaces the resource one
 	_pin: PhantomPinned,
 }
 
@@ -29,6 +32,7 @@ impl DlgMain {
 			dlg_base: DlgBase::new(dlg_id),
 			icon_id,
 			accel_tbl_id,
+			accel_table: UnsafeCell::new(None),
 			_pin: PhantomPinned,
 		}));
 		new_self.default_message_handlers();
@@ -53,6 +57,10 @@ impl DlgMain {
 		&self.0.dlg_base
 	}
 
+	pub(in crate::gui) fn set_accel_table(&self, accel_table: DestroyAcceleratorTableGuard) {
+		*unsafe { &mut *self.0.accel_table.get() } = Some(accel_table);
+	}
+
 	pub(in crate::gui) fn run_main(
 		&self,
 		hinst: &HINSTANCE,
@@ -63,11 +71,14 @@ impl DlgMain {
 			self.0.dlg_base.set_icon(hinst, id)?;
 		}
 
-		let haccel = self
-			.0
-			.accel_tbl_id
-			.map(|id| hinst.LoadAccelerators(IdStr::Id(id))) // resources are automatically freed
-			.transpose()?;
+		let haccel = match unsafe { &mut *self.0.accel_table.get() }.take() {
+			Some(accel_table) => Some(accel_table),
+			None => self
+				.0
+				.accel_tbl_id
+				.map(|id| hinst.LoadAccelerators(IdStr::Id(id))) // resources are automatically freed
+				.transpose()?,
+		};
 
 		self.0
 			.dlg_base
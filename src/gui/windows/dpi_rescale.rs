@@ -0,0 +1,156 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::privs::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Rescales the direct children of a window which moved to a monitor with a
+/// different DPI.
+///
+/// If `reposition` is `true`, the positions, sizes and UI fonts of the children
+/// are scaled; dialogs don't need this, because the dialog manager already does
+/// it. The image lists of the known common controls are always scaled.
+pub(in crate::gui) fn rescale_children(
+	hparent: &HWND,
+	from_dpi: u32,
+	to_dpi: u32,
+	reposition: bool,
+) -> AnyResult<()> {
+	let mut hchildren = Vec::<HWND>::new();
+	hparent.EnumChildWindows(|hchild| {
+		if hchild.GetParent().is_ok_and(|h| h == *hparent) {
+			hchildren.push(hchild); // nested children are rescaled by their own parents
+		}
+		true
+	});
+
+	let (hfont_from, hfont_to) = if reposition {
+		(Some(ui_font::get_for_dpi(from_dpi)?), Some(ui_font::get_for_dpi(to_dpi)?))
+	} else {
+		(None, None)
+	};
+	let mut done_hils = Vec::<(HIMAGELIST, bool)>::new(); // image lists may be shared among controls
+
+	for hchild in hchildren.iter() {
+		let class_name = hchild.GetClassName()?;
+
+		if reposition {
+			let mut rc = hparent.ScreenToClientRc(hchild.GetWindowRect()?)?;
+			if class_name == "ComboBox" || class_name == "ComboBoxEx32" {
+				let mut rc_dropped = RECT::default();
+				unsafe {
+					hchild.SendMessage(cb::GetDroppedControlRect { rect: &mut rc_dropped })?;
+				}
+				rc.bottom = rc.top + rc_dropped.bottom - rc_dropped.top; // size includes the drop-down list
+			}
+
+			let rc = scale_rect(rc, from_dpi, to_dpi);
+			hchild.SetWindowPos(
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+			)?;
+
+			if let (Some(hfont_from), Some(hfont_to)) = (&hfont_from, &hfont_to) {
+				if unsafe { hchild.SendMessage(wm::GetFont {}) }.as_ref() == Some(hfont_from) {
+					unsafe {
+						hchild
+							.SendMessage(wm::SetFont { hfont: hfont_to.raw_copy(), redraw: true });
+					}
+				}
+			}
+		}
+
+		rescale_control_image_lists(hchild, &class_name, from_dpi, to_dpi, &mut done_hils)?;
+	}
+
+	Ok(())
+}
+
+/// Rescales the image lists of the common controls which have them, and sets
+/// them again, so the controls recompute their metrics.
+fn rescale_control_image_lists(
+	hctrl: &HWND,
+	class_name: &str,
+	from_dpi: u32,
+	to_dpi: u32,
+	done_hils: &mut Vec<(HIMAGELIST, bool)>,
+) -> AnyResult<()> {
+	unsafe {
+		match class_name {
+			"SysListView32" => {
+				for kind in [co::LVSIL::NORMAL, co::LVSIL::SMALL, co::LVSIL::STATE] {
+					if let Some(hil) = hctrl.SendMessage(lvm::GetImageList { kind }) {
+						if rescale_image_list(&hil, from_dpi, to_dpi, done_hils)? {
+							hctrl.SendMessage(lvm::SetImageList { kind, himagelist: Some(hil) });
+						}
+					}
+				}
+			},
+			"SysTreeView32" => {
+				for kind in [co::TVSIL::NORMAL, co::TVSIL::STATE] {
+					if let Some(hil) = hctrl.SendMessage(tvm::GetImageList { kind }) {
+						if rescale_image_list(&hil, from_dpi, to_dpi, done_hils)? {
+							hctrl.SendMessage(tvm::SetImageList { kind, himagelist: Some(hil) });
+						}
+					}
+				}
+			},
+			"ToolbarWindow32" => {
+				if let Some(hil) = hctrl.SendMessage(tbm::GetImageList {}) {
+					if rescale_image_list(&hil, from_dpi, to_dpi, done_hils)? {
+						hctrl.SendMessage(tbm::SetImageList { himagelist: hil });
+					}
+				}
+			},
+			"ComboBoxEx32" => {
+				if let Some(hil) = hctrl.SendMessage(cbem::GetImageList {}) {
+					if rescale_image_list(&hil, from_dpi, to_dpi, done_hils)? {
+						hctrl.SendMessage(cbem::SetImageList { himagelist: hil });
+					}
+				}
+			},
+			"SysTabControl32" => {
+				if let Some(hil) = hctrl.SendMessage(tcm::GetImageList {}) {
+					if rescale_image_list(&hil, from_dpi, to_dpi, done_hils)? {
+						hctrl.SendMessage(tcm::SetImageList { himagelist: Some(hil) });
+					}
+				}
+			},
+			_ => {},
+		}
+	}
+	Ok(())
+}
+
+/// Scales the images by extracting them as icons and adding them back after
+/// resizing the image list. Returns `true` if the image list was resized, now
+/// or by a previous control sharing it, so the control must set it again.
+fn rescale_image_list(
+	hil: &HIMAGELIST,
+	from_dpi: u32,
+	to_dpi: u32,
+	done_hils: &mut Vec<(HIMAGELIST, bool)>,
+) -> AnyResult<bool> {
+	if let Some((_, resized)) = done_hils.iter().find(|(done, _)| done == hil) {
+		return Ok(*resized);
+	}
+
+	let sz = hil.GetIconSize()?;
+	let new_sz = scale_size(sz, from_dpi, to_dpi);
+	let resized = new_sz != sz;
+	done_hils.push((unsafe { hil.raw_copy() }, resized));
+	if !resized {
+		return Ok(false);
+	}
+
+	let icons = (0..hil.GetImageCount())
+		.map(|index| hil.GetIcon(index, co::ILD::TRANSPARENT))
+		.collect::<HrResult<Vec<_>>>()?;
+	hil.SetIconSize(new_sz)?; // removes all images
+	for hicon in icons.iter() {
+		hil.AddIcon(hicon)?; // icon is stretched to the new size
+	}
+	Ok(true)
+}
@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use crate::co;
 use crate::decl::*;
+use crate::gui::privs::*;
 use crate::msg::*;
 use crate::prelude::*;
 
@@ -102,6 +103,21 @@ impl Layout {
 		Ok(())
 	}
 
+	/// Scales the stored original coordinates after the parent window moved to
+	/// a monitor with a different DPI, so the next rearrangement uses them.
+	pub(in crate::gui) fn rescale(&self, from_dpi: u32, to_dpi: u32) {
+		if let Some(sz) = unsafe { &mut *self.0.sz_parent_orig.get() } {
+			*sz = scale_size(*sz, from_dpi, to_dpi);
+		}
+
+		let ctrls = unsafe { &mut *self.0.ctrls.get() };
+		for ctrl in ctrls.iter_mut() {
+			if let Some(rc) = &mut ctrl.rc_orig {
+				*rc = scale_rect(*rc, from_dpi, to_dpi);
+			}
+		}
+	}
+
 	/// Rearranges all child controls to fit the new width/height of parent
 	/// window.
 	pub(in crate::gui) fn rearrange(&self, p: wm::Size) -> SysResult<()> {
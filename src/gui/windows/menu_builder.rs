@@ -0,0 +1,414 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::privs::*;
+use crate::prelude::*;
+
+/// State of a menu item, returned by an
+/// [`update_ui`](crate::gui::MenuBuilder::update_ui) closure.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MenuItemState {
+	/// Whether the item can be clicked.
+	///
+	/// Defaults to `true`.
+	pub enabled: bool,
+	/// Whether the item displays a check mark – or a bullet, for radio items.
+	///
+	/// Defaults to `false`.
+	pub checked: bool,
+}
+
+impl Default for MenuItemState {
+	fn default() -> Self {
+		Self { enabled: true, checked: false }
+	}
+}
+
+enum EntryKind {
+	Item(Box<dyn Fn() -> AnyResult<()>>),
+	Check(bool, Box<dyn Fn(bool) -> AnyResult<()>>),
+	Radio(bool, Box<dyn Fn() -> AnyResult<()>>),
+	Separator,
+	Submenu(MenuBuilder),
+}
+
+struct Entry {
+	text: String,
+	kind: EntryKind,
+	enabled: bool,
+	is_default: bool,
+	hbmp: Option<HBITMAP>,
+	update_ui: Option<Box<dyn Fn() -> MenuItemState>>,
+}
+
+/// An item whose state is refreshed right before its menu is shown.
+struct UiHook {
+	pos: u32,
+	radio_group: Option<(u16, u16, u16)>, // first, last, own ID
+	func: Box<dyn Fn() -> MenuItemState>,
+}
+
+/// Declarative builder of a menu bar or a popup menu, which routes each item
+/// to its own closure.
+///
+/// The command IDs are assigned automatically, and the closures are handled by
+/// the parent window, both for menu clicks and for accelerators which share the
+/// same IDs.
+///
+/// Consecutive radio items form a group, which ends at any other item or
+/// separator.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let hmenu = gui::MenuBuilder::new()
+///     .submenu("&File", gui::MenuBuilder::new()
+///         .item("&Open...\tCtrl+O", move || {
+///             println!("Open");
+///             Ok(())
+///         })
+///         .default_item()
+///         .separator()
+///         .item("E&xit", move || {
+///             println!("Exit");
+///             Ok(())
+///         }),
+///     )
+///     .submenu("&View", gui::MenuBuilder::new()
+///         .check("&Status bar", true, move |checked| {
+///             println!("Status bar: {}", checked);
+///             Ok(())
+///         })
+///         .separator()
+///         .radio("&Icons", true, move || Ok(()))
+///         .radio("&Details", false, move || Ok(())),
+///     )
+///     .build_bar(&wnd)?;
+///
+/// let wnd2 = wnd.clone();
+/// wnd.on().wm_create(move |_| {
+///     wnd2.hwnd().SetMenu(&hmenu)?; // the window now owns the menu
+///     Ok(0)
+/// });
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Default)]
+pub struct MenuBuilder {
+	entries: Vec<Entry>,
+}
+
+impl MenuBuilder {
+	/// Creates a new, empty `MenuBuilder`.
+	#[must_use]
+	pub fn new() -> Self {
+		Self { entries: Vec::new() }
+	}
+
+	fn push(mut self, text: &str, kind: EntryKind) -> Self {
+		self.entries.push(Entry {
+			text: text.to_owned(),
+			kind,
+			enabled: true,
+			is_default: false,
+			hbmp: None,
+			update_ui: None,
+		});
+		self
+	}
+
+	fn last_entry(&mut self) -> &mut Entry {
+		self.entries.last_mut().expect("No menu item was added.")
+	}
+
+	/// Adds an item which calls the closure when clicked.
+	///
+	/// Text after a tab character is displayed right-aligned, which is usually
+	/// the accelerator of the item.
+	#[must_use]
+	pub fn item<F>(self, text: &str, func: F) -> Self
+	where
+		F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.push(text, EntryKind::Item(Box::new(func)))
+	}
+
+	/// Adds an item with a check mark, which is toggled when clicked. The
+	/// closure receives the new state.
+	#[must_use]
+	pub fn check<F>(self, text: &str, checked: bool, func: F) -> Self
+	where
+		F: Fn(bool) -> AnyResult<()> + 'static,
+	{
+		self.push(text, EntryKind::Check(checked, Box::new(func)))
+	}
+
+	/// Adds a radio item, which is selected within its group when clicked,
+	/// then calls the closure.
+	#[must_use]
+	pub fn radio<F>(self, text: &str, checked: bool, func: F) -> Self
+	where
+		F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.push(text, EntryKind::Radio(checked, Box::new(func)))
+	}
+
+	/// Adds a separator.
+	#[must_use]
+	pub fn separator(self) -> Self {
+		self.push("", EntryKind::Separator)
+	}
+
+	/// Adds a submenu.
+	#[must_use]
+	pub fn submenu(self, text: &str, submenu: MenuBuilder) -> Self {
+		self.push(text, EntryKind::Submenu(submenu))
+	}
+
+	/// Disables the last added item.
+	///
+	/// # Panics
+	///
+	/// Panics if no item was added.
+	#[must_use]
+	pub fn disabled(mut self) -> Self {
+		self.last_entry().enabled = false;
+		self
+	}
+
+	/// Makes the last added item the default one, displayed in bold.
+	///
+	/// # Panics
+	///
+	/// Panics if no item was added.
+	#[must_use]
+	pub fn default_item(mut self) -> Self {
+		self.entries
+			.iter_mut()
+			.for_each(|entry| entry.is_default = false);
+		self.last_entry().is_default = true;
+		self
+	}
+
+	/// Sets a bitmap to be displayed beside the last added item.
+	///
+	/// The bitmap is not copied: it must outlive the menu.
+	///
+	/// # Panics
+	///
+	/// Panics if no item was added.
+	#[must_use]
+	pub fn bitmap(mut self, hbmp: &HBITMAP) -> Self {
+		self.last_entry().hbmp = Some(unsafe { hbmp.raw_copy() });
+		self
+	}
+
+	/// Sets a closure which returns the state of the last added item, called
+	/// right before the menu containing it is shown. The state is applied
+	/// before any user handler of
+	/// [`wm_init_menu_popup`](crate::gui::events::WindowEvents::wm_init_menu_popup)
+	/// runs.
+	///
+	/// # Panics
+	///
+	/// Panics if no item was added.
+	#[must_use]
+	pub fn update_ui<F>(mut self, func: F) -> Self
+	where
+		F: Fn() -> MenuItemState + 'static,
+	{
+		self.last_entry().update_ui = Some(Box::new(func));
+		self
+	}
+
+	/// Creates the menu as a menu bar, and routes the items to the `parent`
+	/// window.
+	///
+	/// Since the parent window object already exists, its options were
+	/// consumed, so the menu can't be set as
+	/// [`WindowMainOpts::menu`](crate::gui::WindowMainOpts::menu). Instead, set
+	/// it with [`HWND::SetMenu`](crate::prelude::user_Hwnd::SetMenu) in the
+	/// [`wm_create`](crate::gui::events::WindowEvents::wm_create) event, as
+	/// shown in the [`MenuBuilder`](crate::gui::MenuBuilder) example; the
+	/// window then destroys the menu along with itself. Note that the menu bar
+	/// takes its height from the client area.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created.
+	pub fn build_bar(self, parent: &impl GuiParent) -> SysResult<HMENU> {
+		Self::check_parent(parent);
+		let hmenu = HMENU::CreateMenu()?;
+		self.build_into(&hmenu, parent.as_ref(), co::WM::INITMENU)?;
+		Ok(hmenu)
+	}
+
+	/// Creates the menu as a popup menu, to be displayed with
+	/// [`HMENU::track_popup_menu_at_point`](crate::prelude::user_Hmenu::track_popup_menu_at_point),
+	/// and routes the items to the `parent` window.
+	///
+	/// **Note:** Must be paired with an
+	/// [`HMENU::DestroyMenu`](crate::prelude::user_Hmenu::DestroyMenu) call.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created.
+	pub fn build_popup(self, parent: &impl GuiParent) -> SysResult<HMENU> {
+		Self::check_parent(parent);
+		let hmenu = HMENU::CreatePopupMenu()?;
+		self.build_into(&hmenu, parent.as_ref(), co::WM::INITMENUPOPUP)?;
+		Ok(hmenu)
+	}
+
+	fn check_parent(parent: &impl GuiParent) {
+		if *parent.hwnd() != HWND::NULL {
+			panic!("Cannot build a menu after parent window creation.");
+		}
+	}
+
+	fn build_into(self, hmenu: &HMENU, base: &BaseWnd, init_msg: co::WM) -> SysResult<()> {
+		let ids = self
+			.entries
+			.iter()
+			.map(|entry| match entry.kind {
+				EntryKind::Separator | EntryKind::Submenu(_) => 0,
+				_ => auto_id::next(),
+			})
+			.collect::<Vec<_>>();
+		let groups = radio_groups(&self.entries, &ids);
+		let mut hooks = Vec::<UiHook>::new();
+
+		for (pos, entry) in self.entries.into_iter().enumerate() {
+			let id = ids[pos];
+			match entry.kind {
+				EntryKind::Separator => {
+					hmenu.AppendMenu(co::MF::SEPARATOR, IdMenu::None, BmpPtrStr::None)?;
+				},
+				EntryKind::Submenu(submenu) => {
+					let hsub = HMENU::CreatePopupMenu()?;
+					submenu.build_into(&hsub, base, co::WM::INITMENUPOPUP)?;
+					hmenu.AppendMenu(
+						co::MF::POPUP,
+						IdMenu::Menu(&hsub),
+						BmpPtrStr::from_str(&entry.text),
+					)?; // submenu is now owned by the menu
+				},
+				EntryKind::Item(func) => {
+					hmenu.AppendMenu(
+						co::MF::STRING,
+						IdMenu::Id(id),
+						BmpPtrStr::from_str(&entry.text),
+					)?;
+					base.before_on().wm_command_accel_menu(id, func);
+				},
+				EntryKind::Check(checked, func) => {
+					hmenu.AppendMenu(
+						co::MF::STRING,
+						IdMenu::Id(id),
+						BmpPtrStr::from_str(&entry.text),
+					)?;
+					hmenu.CheckMenuItem(IdPos::Id(id), checked)?;
+					let hmenu = unsafe { hmenu.raw_copy() };
+					base.before_on().wm_command_accel_menu(id, move || {
+						let checked = !hmenu.GetMenuState(IdPos::Id(id))?.has(co::MF::CHECKED);
+						hmenu.CheckMenuItem(IdPos::Id(id), checked)?;
+						func(checked)
+					});
+				},
+				EntryKind::Radio(checked, func) => {
+					hmenu.AppendMenu(
+						co::MF::STRING,
+						IdMenu::Id(id),
+						BmpPtrStr::from_str(&entry.text),
+					)?;
+					let (first, last) = groups[pos].unwrap();
+					if checked {
+						hmenu.CheckMenuRadioItem(
+							IdPos::Id(first),
+							IdPos::Id(last),
+							IdPos::Id(id),
+						)?;
+					}
+					let hmenu = unsafe { hmenu.raw_copy() };
+					base.before_on().wm_command_accel_menu(id, move || {
+						hmenu.CheckMenuRadioItem(
+							IdPos::Id(first),
+							IdPos::Id(last),
+							IdPos::Id(id),
+						)?;
+						func()
+					});
+				},
+			}
+
+			let pos = pos as u32;
+			if !entry.enabled {
+				hmenu.EnableMenuItem(IdPos::Pos(pos), false)?;
+			}
+			if entry.is_default {
+				hmenu.SetMenuDefaultItem(IdPos::Pos(pos))?;
+			}
+			if let Some(hbmp) = &entry.hbmp {
+				hmenu.SetMenuItemBitmaps(IdPos::Pos(pos), Some(hbmp), None)?;
+			}
+			if let Some(func) = entry.update_ui {
+				hooks.push(UiHook {
+					pos,
+					radio_group: groups[pos as usize].map(|(first, last)| (first, last, id)),
+					func,
+				});
+			}
+		}
+
+		if !hooks.is_empty() {
+			let hmenu = unsafe { hmenu.raw_copy() };
+			base.before_on().wm(init_msg, move |p| {
+				if p.wparam == hmenu.ptr() as usize {
+					apply_ui_hooks(&hmenu, &hooks)?; // WM_INITMENU and WM_INITMENUPOPUP carry the HMENU
+				}
+				Ok(0) // ignored
+			});
+		}
+		Ok(())
+	}
+}
+
+/// Returns the first and last IDs of the radio group of each radio item.
+#[must_use]
+fn radio_groups(entries: &[Entry], ids: &[u16]) -> Vec<Option<(u16, u16)>> {
+	let mut groups = vec![None; entries.len()];
+	let mut idx = 0;
+	while idx < entries.len() {
+		if !matches!(entries[idx].kind, EntryKind::Radio(..)) {
+			idx += 1;
+			continue;
+		}
+		let first = idx;
+		while idx < entries.len() && matches!(entries[idx].kind, EntryKind::Radio(..)) {
+			idx += 1;
+		}
+		for group in groups[first..idx].iter_mut() {
+			*group = Some((ids[first], ids[idx - 1]));
+		}
+	}
+	groups
+}
+
+fn apply_ui_hooks(hmenu: &HMENU, hooks: &[UiHook]) -> SysResult<()> {
+	for hook in hooks.iter() {
+		let state = (hook.func)();
+		hmenu.EnableMenuItem(IdPos::Pos(hook.pos), state.enabled)?;
+		match hook.radio_group {
+			Some((first, last, id)) if state.checked => {
+				hmenu.CheckMenuRadioItem(IdPos::Id(first), IdPos::Id(last), IdPos::Id(id))?;
+			},
+			_ => {
+				hmenu.CheckMenuItem(IdPos::Pos(hook.pos), state.checked)?;
+			},
+		}
+	}
+	Ok(())
+}
@@ -1,10 +1,15 @@
+mod accel_table_builder;
 mod base_wnd;
 mod dlg_base;
 mod dlg_control;
 mod dlg_main;
 mod dlg_modal;
 mod dlg_modeless;
+mod dpi_rescale;
 mod layout;
+mod menu_builder;
+mod panel;
+mod panel_arrange;
 mod raw_base;
 mod raw_control;
 mod raw_main;
@@ -24,7 +29,9 @@ pub(in crate::gui) mod privs {
 	pub(in crate::gui) use super::dlg_main::DlgMain;
 	pub(in crate::gui) use super::dlg_modal::DlgModal;
 	pub(in crate::gui) use super::dlg_modeless::DlgModeless;
+	pub(in crate::gui) use super::dpi_rescale::rescale_children;
 	pub(in crate::gui) use super::layout::Layout;
+	pub(in crate::gui) use super::panel_arrange::{Node, PanelKind, PanelNode};
 	pub(in crate::gui) use super::raw_base::RawBase;
 	pub(in crate::gui) use super::raw_control::RawControl;
 	pub(in crate::gui) use super::raw_main::RawMain;
@@ -33,7 +40,11 @@ pub(in crate::gui) mod privs {
 }
 
 pub mod decl {
+	pub use super::accel_table_builder::AccelTableBuilder;
 	pub use super::layout::{Horz, Vert};
+	pub use super::menu_builder::{MenuBuilder, MenuItemState};
+	pub use super::panel::{Panel, PanelLayout};
+	pub use super::panel_arrange::{Align, Dock, LayoutItem, Margins, Track};
 	pub use super::raw_opts::*;
 	pub use super::window_control::WindowControl;
 	pub use super::window_main::WindowMain;
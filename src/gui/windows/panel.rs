@@ -0,0 +1,263 @@
+use std::cell::{Cell, UnsafeCell};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{privs::*, *};
+use crate::prelude::*;
+
+/// A control placed by a panel, with the size it had when first arranged.
+struct PanelLeaf {
+	ctrl: Box<dyn GuiWindow>,
+	sz_orig: Cell<Option<SIZE>>,
+}
+
+/// A tree of items – controls and nested panels – to be arranged by a
+/// [`PanelLayout`](crate::gui::PanelLayout).
+///
+/// A panel is a stack, a dock or a grid. Each item carries its
+/// [`LayoutItem`](crate::gui::LayoutItem) rules: margins, size limits, weight
+/// and alignment.
+///
+/// Hidden controls take no space.
+///
+/// # Examples
+///
+/// A dialog-like layout, with a list filling the window and two buttons at the
+/// bottom right:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let list: gui::ListView = gui::ListView::new(&wnd, gui::ListViewOpts::default());
+/// let btn_ok = gui::Button::new(&wnd, gui::ButtonOpts::default());
+/// let btn_cancel = gui::Button::new(&wnd, gui::ButtonOpts::default());
+///
+/// let buttons = gui::Panel::hstack(gui::dpi_x(8))
+///     .control(&btn_ok, gui::LayoutItem::default())
+///     .control(&btn_cancel, gui::LayoutItem::default());
+///
+/// let root = gui::Panel::dock()
+///     .padding(gui::Margins::all(gui::dpi_x(10)))
+///     .panel(buttons, gui::LayoutItem {
+///         dock: gui::Dock::Bottom,
+///         horz_align: gui::Align::End,
+///         margin: gui::Margins::new(0, gui::dpi_y(8), 0, 0),
+///         ..Default::default()
+///     })
+///     .control(&list, gui::LayoutItem::default());
+///
+/// let _layout = gui::PanelLayout::new(&wnd, root);
+/// ```
+pub struct Panel(PanelNode<PanelLeaf>);
+
+impl Panel {
+	/// Creates a panel which places its items side by side, from left to
+	/// right, separated by `spacing` pixels. Items with a
+	/// [`weight`](crate::gui::LayoutItem::weight) share the extra width.
+	#[must_use]
+	pub const fn hstack(spacing: i32) -> Self {
+		Self(PanelNode::new(PanelKind::HStack(spacing)))
+	}
+
+	/// Creates a panel which places its items one below the other, separated
+	/// by `spacing` pixels. Items with a
+	/// [`weight`](crate::gui::LayoutItem::weight) share the extra height.
+	#[must_use]
+	pub const fn vstack(spacing: i32) -> Self {
+		Self(PanelNode::new(PanelKind::VStack(spacing)))
+	}
+
+	/// Creates a panel which places each item, in the order they were added,
+	/// at one of the sides of the remaining space, according to its
+	/// [`dock`](crate::gui::LayoutItem::dock).
+	#[must_use]
+	pub const fn dock() -> Self {
+		Self(PanelNode::new(PanelKind::Dock))
+	}
+
+	/// Creates a panel which places its items in the cells of a grid,
+	/// according to their [`cell`](crate::gui::LayoutItem::cell) and
+	/// [`span`](crate::gui::LayoutItem::span).
+	///
+	/// Columns and rows are separated by `spacing` pixels.
+	#[must_use]
+	pub fn grid(columns: &[Track], rows: &[Track], spacing: SIZE) -> Self {
+		Self(PanelNode::new(PanelKind::Grid {
+			columns: columns.to_vec(),
+			rows: rows.to_vec(),
+			spacing,
+		}))
+	}
+
+	/// Sets the space between the panel borders and its items.
+	#[must_use]
+	pub const fn padding(mut self, padding: Margins) -> Self {
+		self.0.padding = padding;
+		self
+	}
+
+	/// Adds a control to the panel.
+	///
+	/// The control must not have a resizing behavior of its own – that is,
+	/// it must be created with `(Horz::None, Vert::None)`.
+	#[must_use]
+	pub fn control(mut self, ctrl: &(impl GuiControl + Clone + 'static), item: LayoutItem) -> Self {
+		self.0.children.push((
+			item,
+			Node::Leaf(PanelLeaf {
+				ctrl: Box::new(ctrl.clone()),
+				sz_orig: Cell::new(None),
+			}),
+		));
+		self
+	}
+
+	/// Adds a nested panel.
+	#[must_use]
+	pub fn panel(mut self, panel: Panel, item: LayoutItem) -> Self {
+		self.0.children.push((item, Node::Panel(panel.0)));
+		self
+	}
+}
+
+struct PanelLayoutObj {
+	parent: Box<dyn AsRef<BaseWnd>>,
+	root: UnsafeCell<Panel>,
+	dpi: Cell<u32>, // DPI of the root values, 0 before the first arrangement
+	_pin: PhantomPinned,
+}
+
+/// Arranges the controls of a parent window according to a
+/// [`Panel`](crate::gui::Panel) tree, every time the parent is resized.
+///
+/// This is an alternative to the [`Horz`](crate::gui::Horz) and
+/// [`Vert`](crate::gui::Vert) resizing behaviors: instead of moving the
+/// controls relative to the original parent size, the whole layout is computed
+/// again from the current client area.
+#[derive(Clone)]
+pub struct PanelLayout(Pin<Arc<PanelLayoutObj>>);
+
+unsafe impl Send for PanelLayout {}
+
+impl PanelLayout {
+	/// Instantiates a new `PanelLayout` object, which will arrange the controls
+	/// of the root panel when the parent is created and whenever it receives
+	/// [`WM_SIZE`](crate::msg::wm::Size).
+	///
+	/// The controls must be instantiated before the layout, so they are
+	/// already created when the layout is first arranged.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `PanelLayout` in an event closure.
+	#[must_use]
+	pub fn new(parent: &(impl GuiParent + 'static), root: Panel) -> Self {
+		if *parent.hwnd() != HWND::NULL {
+			panic!("Cannot create a layout after parent window creation.");
+		}
+
+		let new_self = Self(Arc::pin(PanelLayoutObj {
+			parent: Box::new(parent.clone()),
+			root: UnsafeCell::new(root),
+			dpi: Cell::new(0),
+			_pin: PhantomPinned,
+		}));
+
+		let self2 = new_self.clone();
+		parent
+			.as_ref()
+			.before_on()
+			.wm(parent.as_ref().is_dlg().create_msg(), move |_| {
+				self2.rearrange()?; // controls were created by their own handlers
+				Ok(0) // ignored
+			});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_on().wm_size(move |p| {
+			if p.request != co::SIZE_R::MINIMIZED {
+				self2.arrange(SIZE::new(p.client_area.cx, p.client_area.cy))?;
+			}
+			Ok(())
+		});
+
+		new_self
+	}
+
+	/// Arranges all the controls to fit the current client area of the parent
+	/// window.
+	///
+	/// Call this method after showing or hiding a control, or after changing
+	/// a size which affects the layout.
+	pub fn rearrange(&self) -> SysResult<()> {
+		let rc = self.parent().hwnd().GetClientRect()?;
+		self.arrange(SIZE::new(rc.right, rc.bottom))
+	}
+
+	#[must_use]
+	fn parent(&self) -> &BaseWnd {
+		(*self.0.parent).as_ref()
+	}
+
+	fn arrange(&self, sz_client: SIZE) -> SysResult<()> {
+		let cur_dpi = self.parent().dpi();
+		let prev_dpi = self.0.dpi.replace(cur_dpi);
+		if prev_dpi != 0 && prev_dpi != cur_dpi {
+			// Parent moved to another monitor, and its children were rescaled.
+			let root = unsafe { &mut *self.0.root.get() };
+			root.0.rescale(prev_dpi, cur_dpi, &mut |leaf| {
+				if let Some(sz) = leaf.sz_orig.get() {
+					leaf.sz_orig.set(Some(scale_size(sz, prev_dpi, cur_dpi)));
+				}
+			});
+		}
+
+		let root = unsafe { &(*self.0.root.get()).0 };
+		root.try_for_each_leaf(&mut |leaf| {
+			if leaf.sz_orig.get().is_none() {
+				let rc = leaf.ctrl.hwnd().GetWindowRect()?;
+				leaf.sz_orig
+					.set(Some(SIZE::new(rc.right - rc.left, rc.bottom - rc.top)));
+			}
+			SysResult::Ok(())
+		})?;
+
+		let mut placements = Vec::<(&HWND, RECT)>::new();
+		root.arrange(
+			RECT {
+				left: 0,
+				top: 0,
+				right: sz_client.cx,
+				bottom: sz_client.cy,
+			},
+			&mut |leaf| {
+				if leaf.ctrl.hwnd().style().has(co::WS::VISIBLE) {
+					leaf.sz_orig.get()
+				} else {
+					None // hidden controls are collapsed
+				}
+			},
+			&mut |leaf, rc| placements.push((leaf.ctrl.hwnd(), rc)),
+		);
+		if placements.is_empty() {
+			return Ok(());
+		}
+
+		let mut hdwp = HDWP::BeginDeferWindowPos(placements.len() as _)?;
+		for (hctrl, rc) in placements.iter() {
+			hdwp.DeferWindowPos(
+				hctrl,
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+			)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,967 @@
+use crate::decl::*;
+use crate::gui::privs::*;
+
+/// Alignment of an item within the space given to it by its
+/// [`Panel`](crate::gui::Panel).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+	/// Item is placed at the left or at the top, keeping its size.
+	Start,
+	/// Item is centered, keeping its size.
+	Center,
+	/// Item is placed at the right or at the bottom, keeping its size.
+	End,
+	/// Item fills the whole space, limited by its maximum size.
+	Stretch,
+}
+
+/// Side of a dock [`Panel`](crate::gui::Panel) where an item is placed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dock {
+	/// Item takes the left of the remaining space, with its own width.
+	Left,
+	/// Item takes the top of the remaining space, with its own height.
+	Top,
+	/// Item takes the right of the remaining space, with its own width.
+	Right,
+	/// Item takes the bottom of the remaining space, with its own height.
+	Bottom,
+	/// Item takes all the remaining space. Should be the last item.
+	Fill,
+}
+
+/// Size of a column or a row of a grid [`Panel`](crate::gui::Panel).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Track {
+	/// Size of the largest item which spans only this track.
+	Auto,
+	/// Fixed size, in pixels.
+	Fixed(i32),
+	/// Share of the space left by the other tracks, proportional to the
+	/// weight.
+	Weight(u32),
+}
+
+/// Space around an item, or inside a [`Panel`](crate::gui::Panel), in pixels.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Margins {
+	pub left: i32,
+	pub top: i32,
+	pub right: i32,
+	pub bottom: i32,
+}
+
+impl Margins {
+	/// Creates a new `Margins`.
+	#[must_use]
+	pub const fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+		Self { left, top, right, bottom }
+	}
+
+	/// Creates a new `Margins` with the same value on all sides.
+	#[must_use]
+	pub const fn all(value: i32) -> Self {
+		Self::new(value, value, value, value)
+	}
+}
+
+/// Placement rules of an item added to a [`Panel`](crate::gui::Panel).
+///
+/// Sizes are in pixels; you may want to use [`dpi`](crate::gui::dpi) to
+/// compute them. Some fields are used only by specific panel kinds.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LayoutItem {
+	/// Space around the item.
+	///
+	/// Defaults to zero.
+	pub margin: Margins,
+	/// Preferred size of the item. A zero dimension means the size is measured:
+	/// for a control, its size when first arranged; for a panel, the size
+	/// needed by its items.
+	///
+	/// Defaults to zero.
+	pub size: SIZE,
+	/// Minimum size of the item.
+	///
+	/// Defaults to zero.
+	pub min_size: SIZE,
+	/// Maximum size of the item. A zero dimension means no limit.
+	///
+	/// Defaults to zero.
+	pub max_size: SIZE,
+	/// In a stack panel, the share of the extra space the item receives along
+	/// the stacking direction. Items with zero weight keep their size.
+	///
+	/// Defaults to zero.
+	pub weight: u32,
+	/// Horizontal alignment within the space given to the item.
+	///
+	/// Defaults to `Align::Stretch`.
+	pub horz_align: Align,
+	/// Vertical alignment within the space given to the item.
+	///
+	/// Defaults to `Align::Stretch`.
+	pub vert_align: Align,
+	/// In a dock panel, the side where the item is placed.
+	///
+	/// Defaults to `Dock::Fill`.
+	pub dock: Dock,
+	/// In a grid panel, the zero-based column and row of the item.
+	///
+	/// Defaults to `(0, 0)`.
+	pub cell: (u32, u32),
+	/// In a grid panel, the number of columns and rows spanned by the item.
+	///
+	/// Defaults to `(1, 1)`.
+	pub span: (u32, u32),
+}
+
+impl Default for LayoutItem {
+	fn default() -> Self {
+		Self {
+			margin: Margins::default(),
+			size: SIZE::default(),
+			min_size: SIZE::default(),
+			max_size: SIZE::default(),
+			weight: 0,
+			horz_align: Align::Stretch,
+			vert_align: Align::Stretch,
+			dock: Dock::Fill,
+			cell: (0, 0),
+			span: (1, 1),
+		}
+	}
+}
+
+/// How a panel arranges its items.
+pub(in crate::gui) enum PanelKind {
+	HStack(i32), // spacing
+	VStack(i32),
+	Dock,
+	Grid { columns: Vec<Track>, rows: Vec<Track>, spacing: SIZE },
+}
+
+/// An item of a panel: either a leaf, which is actually placed, or a nested
+/// panel.
+pub(in crate::gui) enum Node<L> {
+	Leaf(L),
+	Panel(PanelNode<L>),
+}
+
+/// Measurement and arrangement of a tree of panels, over plain `SIZE` and
+/// `RECT` values.
+///
+/// The leaves are opaque; the caller provides a function which returns the
+/// natural size of a leaf, or `None` if the leaf is collapsed – in which case
+/// it takes no space at all.
+pub(in crate::gui) struct PanelNode<L> {
+	pub(in crate::gui) kind: PanelKind,
+	pub(in crate::gui) padding: Margins,
+	pub(in crate::gui) children: Vec<(LayoutItem, Node<L>)>,
+}
+
+impl<L> PanelNode<L> {
+	#[must_use]
+	pub(in crate::gui) const fn new(kind: PanelKind) -> Self {
+		Self {
+			kind,
+			padding: Margins::new(0, 0, 0, 0),
+			children: Vec::new(),
+		}
+	}
+
+	/// Calls the function for each leaf of the tree, stopping at the first
+	/// error.
+	pub(in crate::gui) fn try_for_each_leaf<E, F>(&self, func: &mut F) -> Result<(), E>
+	where
+		F: FnMut(&L) -> Result<(), E>,
+	{
+		for (_, node) in self.children.iter() {
+			match node {
+				Node::Leaf(leaf) => func(leaf)?,
+				Node::Panel(panel) => panel.try_for_each_leaf(func)?,
+			}
+		}
+		Ok(())
+	}
+
+	/// Scales all the pixel values of the tree – padding, spacing, fixed
+	/// tracks and item rules – from one DPI to another. The function is called
+	/// for each leaf, so the caller can scale it too.
+	pub(in crate::gui) fn rescale<F>(&mut self, from_dpi: u32, to_dpi: u32, rescale_leaf: &mut F)
+	where
+		F: FnMut(&mut L),
+	{
+		let scale_track = |track: &mut Track| {
+			if let Track::Fixed(len) = track {
+				*len = scale(*len, from_dpi, to_dpi);
+			}
+		};
+		match &mut self.kind {
+			PanelKind::HStack(spacing) | PanelKind::VStack(spacing) => {
+				*spacing = scale(*spacing, from_dpi, to_dpi);
+			},
+			PanelKind::Dock => {},
+			PanelKind::Grid { columns, rows, spacing } => {
+				columns.iter_mut().for_each(scale_track);
+				rows.iter_mut().for_each(scale_track);
+				*spacing = scale_size(*spacing, from_dpi, to_dpi);
+			},
+		}
+		self.padding = scale_margins(self.padding, from_dpi, to_dpi);
+
+		for (item, node) in self.children.iter_mut() {
+			item.margin = scale_margins(item.margin, from_dpi, to_dpi);
+			item.size = scale_size(item.size, from_dpi, to_dpi);
+			item.min_size = scale_size(item.min_size, from_dpi, to_dpi);
+			item.max_size = scale_size(item.max_size, from_dpi, to_dpi);
+			match node {
+				Node::Leaf(leaf) => rescale_leaf(leaf),
+				Node::Panel(panel) => panel.rescale(from_dpi, to_dpi, rescale_leaf),
+			}
+		}
+	}
+
+	/// Returns the size needed to display all the items, including the
+	/// padding.
+	#[must_use]
+	pub(in crate::gui) fn measure<M>(&self, measure_leaf: &mut M) -> SIZE
+	where
+		M: FnMut(&L) -> Option<SIZE>,
+	{
+		let kids = self.visible_children(measure_leaf);
+		let content = match &self.kind {
+			PanelKind::HStack(spacing) => stack_measure(&kids, *spacing, false),
+			PanelKind::VStack(spacing) => stack_measure(&kids, *spacing, true),
+			PanelKind::Dock => dock_measure(&kids),
+			PanelKind::Grid { columns, rows, spacing } => {
+				grid_measure(&kids, columns, rows, *spacing)
+			},
+		};
+		SIZE::new(
+			content.cx + self.padding.left + self.padding.right,
+			content.cy + self.padding.top + self.padding.bottom,
+		)
+	}
+
+	/// Arranges all the items within the given rectangle, calling `place_leaf`
+	/// with the final rectangle of each visible leaf.
+	pub(in crate::gui) fn arrange<'a, M, P>(
+		&'a self,
+		rc: RECT,
+		measure_leaf: &mut M,
+		place_leaf: &mut P,
+	) where
+		M: FnMut(&L) -> Option<SIZE>,
+		P: FnMut(&'a L, RECT),
+	{
+		let inner = deflate(rc, self.padding);
+		let kids = self.visible_children(measure_leaf);
+		let slots = match &self.kind {
+			PanelKind::HStack(spacing) => stack_slots(&kids, inner, *spacing, false),
+			PanelKind::VStack(spacing) => stack_slots(&kids, inner, *spacing, true),
+			PanelKind::Dock => dock_slots(&kids, inner),
+			PanelKind::Grid { columns, rows, spacing } => {
+				grid_slots(&kids, inner, columns, rows, *spacing)
+			},
+		};
+
+		for ((idx, item, desired), slot) in kids.iter().zip(slots.iter()) {
+			let rc_item = place_in_slot(item, *desired, *slot);
+			match &self.children[*idx].1 {
+				Node::Leaf(leaf) => place_leaf(leaf, rc_item),
+				Node::Panel(panel) => panel.arrange(rc_item, measure_leaf, place_leaf),
+			}
+		}
+	}
+
+	/// Returns the index, the item and the desired size – without margins – of
+	/// each child which is not collapsed.
+	fn visible_children<M>(&self, measure_leaf: &mut M) -> Vec<(usize, LayoutItem, SIZE)>
+	where
+		M: FnMut(&L) -> Option<SIZE>,
+	{
+		self.children
+			.iter()
+			.enumerate()
+			.filter_map(|(idx, (item, node))| {
+				let natural = match node {
+					Node::Leaf(leaf) => measure_leaf(leaf)?,
+					Node::Panel(panel) => panel.measure(measure_leaf),
+				};
+				Some((idx, *item, desired_size(item, natural)))
+			})
+			.collect()
+	}
+}
+
+/// Applies the preferred, minimum and maximum sizes of the item.
+#[must_use]
+fn desired_size(item: &LayoutItem, natural: SIZE) -> SIZE {
+	SIZE::new(
+		clamp_len(
+			if item.size.cx > 0 { item.size.cx } else { natural.cx },
+			item.min_size.cx,
+			item.max_size.cx,
+		),
+		clamp_len(
+			if item.size.cy > 0 { item.size.cy } else { natural.cy },
+			item.min_size.cy,
+			item.max_size.cy,
+		),
+	)
+}
+
+/// Clamps the length to the minimum and maximum; a zero maximum means no
+/// limit. The minimum wins over the maximum.
+#[must_use]
+fn clamp_len(len: i32, min: i32, max: i32) -> i32 {
+	let len = if max > 0 { len.min(max) } else { len };
+	len.max(min).max(0)
+}
+
+/// Desired size plus the margins.
+#[must_use]
+fn outer_size(item: &LayoutItem, desired: SIZE) -> SIZE {
+	SIZE::new(
+		desired.cx + item.margin.left + item.margin.right,
+		desired.cy + item.margin.top + item.margin.bottom,
+	)
+}
+
+/// Scales the four margins from one DPI to another.
+#[must_use]
+const fn scale_margins(m: Margins, from_dpi: u32, to_dpi: u32) -> Margins {
+	Margins::new(
+		scale(m.left, from_dpi, to_dpi),
+		scale(m.top, from_dpi, to_dpi),
+		scale(m.right, from_dpi, to_dpi),
+		scale(m.bottom, from_dpi, to_dpi),
+	)
+}
+
+#[must_use]
+fn deflate(rc: RECT, m: Margins) -> RECT {
+	let left = rc.left + m.left;
+	let top = rc.top + m.top;
+	RECT {
+		left,
+		top,
+		right: (rc.right - m.right).max(left),
+		bottom: (rc.bottom - m.bottom).max(top),
+	}
+}
+
+#[must_use]
+const fn transpose_size(sz: SIZE) -> SIZE {
+	SIZE::new(sz.cy, sz.cx)
+}
+
+#[must_use]
+const fn transpose_rect(rc: RECT) -> RECT {
+	RECT {
+		left: rc.top,
+		top: rc.left,
+		right: rc.bottom,
+		bottom: rc.right,
+	}
+}
+
+/// Places the item within its slot, according to its margins, alignment and
+/// size limits.
+#[must_use]
+fn place_in_slot(item: &LayoutItem, desired: SIZE, slot: RECT) -> RECT {
+	let avail = deflate(slot, item.margin);
+	let (left, right) = place_in_axis(
+		avail.left,
+		avail.right - avail.left,
+		desired.cx,
+		item.min_size.cx,
+		item.max_size.cx,
+		item.horz_align,
+	);
+	let (top, bottom) = place_in_axis(
+		avail.top,
+		avail.bottom - avail.top,
+		desired.cy,
+		item.min_size.cy,
+		item.max_size.cy,
+		item.vert_align,
+	);
+	RECT { left, top, right, bottom }
+}
+
+#[must_use]
+fn place_in_axis(
+	start: i32,
+	avail: i32,
+	desired: i32,
+	min: i32,
+	max: i32,
+	align: Align,
+) -> (i32, i32) {
+	let len = clamp_len(
+		match align {
+			Align::Stretch => avail,
+			_ => desired.min(avail),
+		},
+		min,
+		max,
+	);
+	let pos = match align {
+		Align::Start => start,
+		Align::End => start + avail - len,
+		Align::Center | Align::Stretch => start + (avail - len) / 2, // stretched only differs if limited by max
+	};
+	(pos, pos + len)
+}
+
+/// Splits `extra` among the weights, giving the rounding remainder to the last
+/// weighted entries.
+#[must_use]
+fn distribute(extra: i32, weights: &[u32]) -> Vec<i32> {
+	let total = weights.iter().map(|w| *w as i128).sum::<i128>();
+	if extra <= 0 || total == 0 {
+		return vec![0; weights.len()];
+	}
+	let mut acc = 0_i128; // i64 could overflow with huge weights
+	weights
+		.iter()
+		.map(|w| {
+			let before = extra as i128 * acc / total;
+			acc += *w as i128;
+			(extra as i128 * acc / total - before) as i32
+		})
+		.collect()
+}
+
+#[must_use]
+fn total_spacing(count: usize, spacing: i32) -> i32 {
+	count.saturating_sub(1) as i32 * spacing
+}
+
+#[must_use]
+fn stack_measure(kids: &[(usize, LayoutItem, SIZE)], spacing: i32, vertical: bool) -> SIZE {
+	let mut sz = SIZE::new(total_spacing(kids.len(), spacing), 0);
+	for (_, item, desired) in kids.iter() {
+		let outer = outer_size(item, *desired);
+		let outer = if vertical { transpose_size(outer) } else { outer };
+		sz.cx += outer.cx;
+		sz.cy = sz.cy.max(outer.cy);
+	}
+	if vertical {
+		transpose_size(sz)
+	} else {
+		sz
+	}
+}
+
+/// Slots of a stack, computed horizontally; a vertical stack is transposed.
+#[must_use]
+fn stack_slots(
+	kids: &[(usize, LayoutItem, SIZE)],
+	inner: RECT,
+	spacing: i32,
+	vertical: bool,
+) -> Vec<RECT> {
+	let inner = if vertical { transpose_rect(inner) } else { inner };
+	let lens = kids
+		.iter()
+		.map(|(_, item, desired)| {
+			let outer = outer_size(item, *desired);
+			if vertical {
+				outer.cy
+			} else {
+				outer.cx
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let used = lens.iter().sum::<i32>() + total_spacing(kids.len(), spacing);
+	let weights = kids
+		.iter()
+		.map(|(_, item, _)| item.weight)
+		.collect::<Vec<_>>();
+	let shares = distribute(inner.right - inner.left - used, &weights);
+
+	let mut x = inner.left;
+	lens.iter()
+		.zip(shares.iter())
+		.map(|(len, share)| {
+			let rc = RECT {
+				left: x,
+				top: inner.top,
+				right: x + len + share,
+				bottom: inner.bottom,
+			};
+			x = rc.right + spacing;
+			if vertical {
+				transpose_rect(rc)
+			} else {
+				rc
+			}
+		})
+		.collect()
+}
+
+#[must_use]
+fn dock_measure(kids: &[(usize, LayoutItem, SIZE)]) -> SIZE {
+	let (mut max_w, mut max_h) = (0, 0);
+	let (mut acc_w, mut acc_h) = (0, 0);
+	for (_, item, desired) in kids.iter() {
+		let outer = outer_size(item, *desired);
+		match item.dock {
+			Dock::Left | Dock::Right => {
+				max_h = max_h.max(acc_h + outer.cy);
+				acc_w += outer.cx;
+			},
+			Dock::Top | Dock::Bottom => {
+				max_w = max_w.max(acc_w + outer.cx);
+				acc_h += outer.cy;
+			},
+			Dock::Fill => {
+				max_w = max_w.max(acc_w + outer.cx);
+				max_h = max_h.max(acc_h + outer.cy);
+			},
+		}
+	}
+	SIZE::new(max_w.max(acc_w), max_h.max(acc_h))
+}
+
+#[must_use]
+fn dock_slots(kids: &[(usize, LayoutItem, SIZE)], inner: RECT) -> Vec<RECT> {
+	let mut rem = inner;
+	kids.iter()
+		.map(|(_, item, desired)| {
+			let outer = outer_size(item, *desired);
+			let w = outer.cx.min(rem.right - rem.left);
+			let h = outer.cy.min(rem.bottom - rem.top);
+			match item.dock {
+				Dock::Left => {
+					let rc = RECT { right: rem.left + w, ..rem };
+					rem.left += w;
+					rc
+				},
+				Dock::Top => {
+					let rc = RECT { bottom: rem.top + h, ..rem };
+					rem.top += h;
+					rc
+				},
+				Dock::Right => {
+					let rc = RECT { left: rem.right - w, ..rem };
+					rem.right -= w;
+					rc
+				},
+				Dock::Bottom => {
+					let rc = RECT { top: rem.bottom - h, ..rem };
+					rem.bottom -= h;
+					rc
+				},
+				Dock::Fill => rem,
+			}
+		})
+		.collect()
+}
+
+/// Returns the cell of the item, with the span limited to the tracks.
+#[must_use]
+fn grid_cell(item: &LayoutItem, num_cols: usize, num_rows: usize) -> (usize, usize, usize, usize) {
+	let col = (item.cell.0 as usize).min(num_cols - 1);
+	let row = (item.cell.1 as usize).min(num_rows - 1);
+	let col_span = (item.span.0 as usize).clamp(1, num_cols - col);
+	let row_span = (item.span.1 as usize).clamp(1, num_rows - row);
+	(col, row, col_span, row_span)
+}
+
+/// An empty track list is treated as a single track taking all the space.
+#[must_use]
+fn grid_tracks(tracks: &[Track]) -> &[Track] {
+	if tracks.is_empty() {
+		&[Track::Weight(1)]
+	} else {
+		tracks
+	}
+}
+
+/// Natural size of each track: the fixed size, or the largest item spanning
+/// only that track.
+#[must_use]
+fn grid_natural<F>(
+	kids: &[(usize, LayoutItem, SIZE)],
+	tracks: &[Track],
+	mut item_track: F,
+) -> Vec<i32>
+where
+	F: FnMut(&LayoutItem, SIZE) -> Option<(usize, i32)>, // track index, outer length
+{
+	let mut lens = tracks
+		.iter()
+		.map(|t| match t {
+			Track::Fixed(len) => *len,
+			Track::Auto | Track::Weight(_) => 0,
+		})
+		.collect::<Vec<_>>();
+	for (_, item, desired) in kids.iter() {
+		if let Some((idx, len)) = item_track(item, *desired) {
+			if !matches!(tracks[idx], Track::Fixed(_)) {
+				lens[idx] = lens[idx].max(len);
+			}
+		}
+	}
+	lens
+}
+
+/// Natural sizes of the columns and of the rows.
+#[must_use]
+fn grid_natural_lens(
+	kids: &[(usize, LayoutItem, SIZE)],
+	columns: &[Track],
+	rows: &[Track],
+) -> (Vec<i32>, Vec<i32>) {
+	let col_lens = grid_natural(kids, columns, |item, desired| {
+		let (col, _, col_span, _) = grid_cell(item, columns.len(), rows.len());
+		(col_span == 1).then(|| (col, outer_size(item, desired).cx))
+	});
+	let row_lens = grid_natural(kids, rows, |item, desired| {
+		let (_, row, _, row_span) = grid_cell(item, columns.len(), rows.len());
+		(row_span == 1).then(|| (row, outer_size(item, desired).cy))
+	});
+	(col_lens, row_lens)
+}
+
+/// Final size of each track, with the weighted tracks sharing what is left.
+#[must_use]
+fn grid_final(tracks: &[Track], natural: &[i32], avail: i32, spacing: i32) -> Vec<i32> {
+	let fixed = tracks
+		.iter()
+		.zip(natural.iter())
+		.map(|(t, len)| match t {
+			Track::Weight(_) => 0,
+			_ => *len,
+		})
+		.collect::<Vec<_>>();
+	let weights = tracks
+		.iter()
+		.map(|t| match t {
+			Track::Weight(w) => *w,
+			_ => 0,
+		})
+		.collect::<Vec<_>>();
+	let used = fixed.iter().sum::<i32>() + total_spacing(tracks.len(), spacing);
+	let shares = distribute(avail - used, &weights);
+	fixed
+		.iter()
+		.zip(shares.iter())
+		.map(|(f, s)| f + s)
+		.collect()
+}
+
+#[must_use]
+fn grid_measure(
+	kids: &[(usize, LayoutItem, SIZE)],
+	columns: &[Track],
+	rows: &[Track],
+	spacing: SIZE,
+) -> SIZE {
+	let (columns, rows) = (grid_tracks(columns), grid_tracks(rows));
+	let (col_lens, row_lens) = grid_natural_lens(kids, columns, rows);
+	SIZE::new(
+		col_lens.iter().sum::<i32>() + total_spacing(columns.len(), spacing.cx),
+		row_lens.iter().sum::<i32>() + total_spacing(rows.len(), spacing.cy),
+	)
+}
+
+#[must_use]
+fn grid_slots(
+	kids: &[(usize, LayoutItem, SIZE)],
+	inner: RECT,
+	columns: &[Track],
+	rows: &[Track],
+	spacing: SIZE,
+) -> Vec<RECT> {
+	let (columns, rows) = (grid_tracks(columns), grid_tracks(rows));
+	let (col_natural, row_natural) = grid_natural_lens(kids, columns, rows);
+	let col_lens = grid_final(columns, &col_natural, inner.right - inner.left, spacing.cx);
+	let row_lens = grid_final(rows, &row_natural, inner.bottom - inner.top, spacing.cy);
+
+	let offsets = |lens: &[i32], start: i32, spacing: i32| {
+		let mut pos = start;
+		lens.iter()
+			.map(|len| {
+				let cur = pos;
+				pos += len + spacing;
+				cur
+			})
+			.collect::<Vec<_>>()
+	};
+	let col_offs = offsets(&col_lens, inner.left, spacing.cx);
+	let row_offs = offsets(&row_lens, inner.top, spacing.cy);
+
+	kids.iter()
+		.map(|(_, item, _)| {
+			let (col, row, col_span, row_span) = grid_cell(item, columns.len(), rows.len());
+			let (last_col, last_row) = (col + col_span - 1, row + row_span - 1);
+			RECT {
+				left: col_offs[col],
+				top: row_offs[row],
+				right: col_offs[last_col] + col_lens[last_col],
+				bottom: row_offs[last_row] + row_lens[last_row],
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// A leaf is its ID and its natural size; `None` means collapsed.
+	type TestLeaf = (u32, Option<SIZE>);
+
+	fn leaf(id: u32, cx: i32, cy: i32) -> Node<TestLeaf> {
+		Node::Leaf((id, Some(SIZE::new(cx, cy))))
+	}
+
+	fn collapsed(id: u32) -> Node<TestLeaf> {
+		Node::Leaf((id, None))
+	}
+
+	fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+		RECT { left, top, right, bottom }
+	}
+
+	fn ltrb(rc: RECT) -> (i32, i32, i32, i32) {
+		(rc.left, rc.top, rc.right, rc.bottom)
+	}
+
+	fn measure(panel: &PanelNode<TestLeaf>) -> (i32, i32) {
+		let sz = panel.measure(&mut |leaf: &TestLeaf| leaf.1);
+		(sz.cx, sz.cy)
+	}
+
+	fn arrange(panel: &PanelNode<TestLeaf>, rc: RECT) -> Vec<(u32, (i32, i32, i32, i32))> {
+		let mut placed = Vec::new();
+		panel.arrange(rc, &mut |leaf: &TestLeaf| leaf.1, &mut |leaf, rc| {
+			placed.push((leaf.0, ltrb(rc)))
+		});
+		placed
+	}
+
+	#[test]
+	fn distribute_remainder() {
+		assert_eq!(distribute(10, &[1, 1, 1]), [3, 3, 4]);
+		assert_eq!(distribute(70, &[1, 0, 2]), [23, 0, 47]);
+		assert_eq!(distribute(7, &[0, 3, 0, 3]), [0, 3, 0, 4]);
+		assert_eq!(distribute(10, &[0, 0]), [0, 0]);
+		assert_eq!(distribute(-5, &[1, 1]), [0, 0]);
+		assert_eq!(
+			distribute(1_000_000_007, &[u32::MAX; 3])
+				.iter()
+				.sum::<i32>(),
+			1_000_000_007
+		);
+	}
+
+	#[test]
+	fn stack_weights() {
+		let mut p = PanelNode::new(PanelKind::HStack(10));
+		p.padding = Margins::all(5);
+		p.children.push((LayoutItem::default(), leaf(1, 50, 20)));
+		p.children
+			.push((LayoutItem { weight: 1, ..Default::default() }, leaf(2, 50, 20)));
+		p.children.push((
+			LayoutItem {
+				weight: 3,
+				vert_align: Align::Center,
+				..Default::default()
+			},
+			leaf(3, 50, 20),
+		));
+
+		assert_eq!(measure(&p), (5 + 50 + 10 + 50 + 10 + 50 + 5, 30));
+		assert_eq!(
+			arrange(&p, rect(0, 0, 300, 100)), // extra of 120 is split 30 + 90
+			[(1, (5, 5, 55, 95)), (2, (65, 5, 145, 95)), (3, (155, 40, 295, 60))],
+		);
+	}
+
+	#[test]
+	fn stack_rounding_remainder() {
+		let mut p = PanelNode::new(PanelKind::VStack(0));
+		for id in 1..=3 {
+			p.children
+				.push((LayoutItem { weight: 1, ..Default::default() }, leaf(id, 10, 10)));
+		}
+		assert_eq!(
+			arrange(&p, rect(0, 0, 20, 100)), // extra of 70 is split 23 + 23 + 24
+			[(1, (0, 0, 20, 33)), (2, (0, 33, 20, 66)), (3, (0, 66, 20, 100))],
+		);
+	}
+
+	#[test]
+	fn stack_overflow() {
+		let mut p = PanelNode::new(PanelKind::HStack(0));
+		p.children
+			.push((LayoutItem { weight: 1, ..Default::default() }, leaf(1, 60, 10)));
+		p.children.push((LayoutItem::default(), leaf(2, 60, 10)));
+		assert_eq!(
+			arrange(&p, rect(0, 0, 100, 10)), // no extra space, nothing shrinks
+			[(1, (0, 0, 60, 10)), (2, (60, 0, 120, 10))],
+		);
+	}
+
+	#[test]
+	fn min_max_clamping() {
+		assert_eq!(clamp_len(50, 0, 0), 50); // zero max means no limit
+		assert_eq!(clamp_len(50, 0, 20), 20);
+		assert_eq!(clamp_len(10, 30, 0), 30);
+		assert_eq!(clamp_len(50, 30, 20), 30); // min wins over max
+		assert_eq!(clamp_len(-5, 0, 0), 0);
+
+		let item = LayoutItem {
+			size: SIZE::new(0, 80),
+			min_size: SIZE::new(60, 0),
+			max_size: SIZE::new(40, 50),
+			..Default::default()
+		};
+		let desired = desired_size(&item, SIZE::new(10, 10));
+		assert_eq!((desired.cx, desired.cy), (60, 50));
+
+		assert_eq!(place_in_axis(0, 100, 10, 0, 40, Align::Stretch), (30, 70));
+		assert_eq!(place_in_axis(0, 100, 10, 60, 40, Align::Stretch), (20, 80));
+		assert_eq!(place_in_axis(0, 100, 10, 60, 40, Align::End), (40, 100));
+		assert_eq!(place_in_axis(0, 100, 10, 60, 40, Align::Start), (0, 60));
+		assert_eq!(place_in_axis(0, 30, 10, 60, 40, Align::Start), (0, 60)); // min exceeds the slot
+
+		let mut p = PanelNode::new(PanelKind::HStack(0));
+		p.children.push((item, leaf(1, 10, 10)));
+		assert_eq!(measure(&p), (60, 50));
+		assert_eq!(arrange(&p, rect(0, 0, 200, 100)), [(1, (0, 25, 60, 75))]);
+	}
+
+	#[test]
+	fn dock_order() {
+		let dock = |dock| LayoutItem { dock, ..Default::default() };
+		let mut p = PanelNode::new(PanelKind::Dock);
+		p.children.push((dock(Dock::Left), leaf(1, 30, 10)));
+		p.children.push((dock(Dock::Top), leaf(2, 10, 20)));
+		p.children.push((dock(Dock::Right), leaf(3, 30, 10)));
+		p.children.push((dock(Dock::Bottom), leaf(4, 10, 20)));
+		p.children.push((dock(Dock::Fill), leaf(5, 10, 10)));
+
+		assert_eq!(measure(&p), (70, 50));
+		assert_eq!(
+			arrange(&p, rect(0, 0, 100, 100)),
+			[
+				(1, (0, 0, 30, 100)),
+				(2, (30, 0, 100, 20)),
+				(3, (70, 20, 100, 100)),
+				(4, (30, 80, 70, 100)),
+				(5, (30, 20, 70, 80)),
+			],
+		);
+	}
+
+	#[test]
+	fn dock_measure_fill() {
+		let dock = |dock| LayoutItem { dock, ..Default::default() };
+		let mut p = PanelNode::new(PanelKind::Dock);
+		p.children.push((dock(Dock::Left), leaf(1, 30, 10)));
+		p.children.push((dock(Dock::Top), leaf(2, 10, 20)));
+		p.children.push((dock(Dock::Fill), leaf(3, 40, 40)));
+		assert_eq!(measure(&p), (70, 60));
+	}
+
+	#[test]
+	fn dock_overflow() {
+		let dock = |dock| LayoutItem { dock, ..Default::default() };
+		let mut p = PanelNode::new(PanelKind::Dock);
+		p.children.push((dock(Dock::Left), leaf(1, 80, 10)));
+		p.children.push((dock(Dock::Right), leaf(2, 50, 10)));
+		p.children.push((dock(Dock::Top), leaf(3, 10, 150)));
+		p.children.push((dock(Dock::Fill), leaf(4, 10, 10)));
+		assert_eq!(
+			arrange(&p, rect(0, 0, 100, 100)),
+			[
+				(1, (0, 0, 80, 100)),
+				(2, (80, 0, 100, 100)), // only 20 left
+				(3, (80, 0, 80, 100)),  // no width left
+				(4, (80, 100, 80, 100)),
+			],
+		);
+	}
+
+	#[test]
+	fn grid_tracks_and_spans() {
+		let at = |col, row| LayoutItem { cell: (col, row), ..Default::default() };
+		let mut p = PanelNode::new(PanelKind::Grid {
+			columns: vec![Track::Fixed(50), Track::Auto, Track::Weight(1)],
+			rows: vec![Track::Auto, Track::Weight(1)],
+			spacing: SIZE::new(10, 5),
+		});
+		p.children.push((at(0, 0), leaf(1, 20, 10)));
+		p.children.push((at(1, 0), leaf(2, 30, 15)));
+		p.children
+			.push((LayoutItem { span: (3, 1), ..at(0, 1) }, leaf(3, 500, 5)));
+		p.children
+			.push((LayoutItem { span: (5, 5), ..at(2, 1) }, leaf(4, 5, 5)));
+
+		// The spanning item doesn't size the tracks; the last item is
+		// clamped to a single cell, so it does.
+		assert_eq!(measure(&p), (50 + 10 + 30 + 10 + 5, 15 + 5 + 5));
+		assert_eq!(
+			arrange(&p, rect(0, 0, 200, 100)),
+			[
+				(1, (0, 0, 50, 15)),
+				(2, (60, 0, 90, 15)),
+				(3, (0, 20, 200, 100)),
+				(4, (100, 20, 200, 100)),
+			],
+		);
+	}
+
+	#[test]
+	fn grid_cell_clamping() {
+		let item = |cell, span| LayoutItem { cell, span, ..Default::default() };
+		assert_eq!(grid_cell(&item((5, 5), (3, 3)), 2, 2), (1, 1, 1, 1));
+		assert_eq!(grid_cell(&item((0, 0), (9, 0)), 2, 3), (0, 0, 2, 1));
+		assert_eq!(grid_cell(&item((1, 2), (2, 2)), 3, 3), (1, 2, 2, 1));
+	}
+
+	#[test]
+	fn grid_empty_tracks() {
+		let mut p = PanelNode::new(PanelKind::Grid {
+			columns: Vec::new(),
+			rows: Vec::new(),
+			spacing: SIZE::new(10, 10),
+		});
+		p.children.push((
+			LayoutItem {
+				cell: (3, 4),
+				span: (2, 2),
+				..Default::default()
+			},
+			leaf(1, 20, 30),
+		));
+		assert_eq!(measure(&p), (20, 30));
+		assert_eq!(arrange(&p, rect(0, 0, 100, 50)), [(1, (0, 0, 100, 50))]);
+	}
+
+	#[test]
+	fn collapsed_leaves() {
+		let mut p = PanelNode::new(PanelKind::HStack(10));
+		p.children.push((LayoutItem::default(), leaf(1, 10, 10)));
+		p.children
+			.push((LayoutItem { weight: 1, ..Default::default() }, collapsed(2)));
+		p.children.push((LayoutItem::default(), leaf(3, 10, 10)));
+
+		let mut nested = PanelNode::new(PanelKind::VStack(10));
+		nested.padding = Margins::all(2);
+		nested.children.push((LayoutItem::default(), collapsed(4)));
+		p.children
+			.push((LayoutItem::default(), Node::Panel(nested)));
+
+		assert_eq!(measure(&p), (10 + 10 + 10 + 10 + 4, 10)); // no spacing for the collapsed leaf
+		assert_eq!(arrange(&p, rect(0, 0, 100, 10)), [(1, (0, 0, 10, 10)), (3, (20, 0, 30, 10))],);
+	}
+}
@@ -160,6 +160,9 @@ impl RawBase {
 		}
 		let ref_self = unsafe { &mut *ptr_self };
 
+		// Rescale contents if DPI changed, before any closure.
+		ref_self.base.process_dpi_change(p)?;
+
 		// Execute before-user closures, keep track if at least one was executed.
 		let at_least_one_before = ref_self.base.process_before_messages(p)?;
 
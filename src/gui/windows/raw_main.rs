@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::gui::{privs::*, *};
 use crate::prelude::*;
 
@@ -12,6 +13,7 @@ struct RawMainObj {
 	raw_base: RawBase,
 	opts: WindowMainOpts,
 	hchild_prev_focus: UnsafeCell<HWND>,
+	accel_table: UnsafeCell<Option<DestroyAcceleratorTableGuard>>, // set after construction, replaces the one in opts
 	_pin: PhantomPinned,
 }
 
@@ -26,6 +28,7 @@ impl RawMain {
 			raw_base: RawBase::new(),
 			opts,
 			hchild_prev_focus: UnsafeCell::new(HWND::NULL),
+			accel_table: UnsafeCell::new(None),
 			_pin: PhantomPinned,
 		}));
 		new_self.default_message_handlers();
@@ -67,6 +70,10 @@ impl RawMain {
 		&self.0.raw_base
 	}
 
+	pub(in crate::gui) fn set_accel_table(&self, accel_table: DestroyAcceleratorTableGuard) {
+		*unsafe { &mut *self.0.accel_table.get() } = Some(accel_table);
+	}
+
 	pub(in crate::gui) fn run_main(
 		&self,
 		hinst: &HINSTANCE,
@@ -116,6 +123,10 @@ impl RawMain {
 			.hwnd()
 			.ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 		self.0.raw_base.base().hwnd().UpdateWindow()?;
-		BaseWnd::run_main_loop(opts.accel_table.as_deref(), opts.process_dlg_msgs) // blocks until window is closed
+		let accel_table = unsafe { &*self.0.accel_table.get() };
+		BaseWnd::run_main_loop(
+			accel_table.as_deref().or(opts.accel_table.as_deref()),
+			opts.process_dlg_msgs,
+		) // blocks until window is closed
 	}
 }
@@ -2,6 +2,7 @@ use std::any::Any;
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::gui::{privs::*, *};
 use crate::kernel::ffi_types::*;
 use crate::msg::*;
@@ -68,7 +69,12 @@ impl WindowMain {
 	///
 	/// Panics if the window is already created.
 	pub fn run_main(&self, cmd_show: Option<co::SW>) -> AnyResult<i32> {
-		if IsWindowsVistaOrGreater()? {
+		if IsWindows10OrGreater()? {
+			match SetProcessDpiAwarenessContext(co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2) {
+				Ok(_) | Err(co::ERROR::ACCESS_DENIED) => {}, // already set by the manifest
+				Err(_) => SetProcessDPIAware()?,             // before Windows 10 version 1703
+			}
+		} else if IsWindowsVistaOrGreater()? {
 			SetProcessDPIAware()?;
 		}
 
@@ -102,6 +108,27 @@ impl WindowMain {
 		res
 	}
 
+	/// Sets the accelerator table of the window, which replaces the one given
+	/// in [`WindowMainOpts`](crate::gui::WindowMainOpts) or loaded from the
+	/// resource. The window takes ownership of the table.
+	///
+	/// Use it when the table can only be created after the window object, like
+	/// the one built by [`AccelTableBuilder`](crate::gui::AccelTableBuilder).
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn set_accel_table(&self, accel_table: DestroyAcceleratorTableGuard) {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot set the accelerator table after window creation.");
+		}
+
+		match &self.0 {
+			RawDlg::Raw(r) => r.set_accel_table(accel_table),
+			RawDlg::Dlg(d) => d.set_accel_table(accel_table),
+		}
+	}
+
 	/// Closes the window by posting a [`WM_CLOSE`](crate::msg::wm::Close)
 	/// message. This is the safest way to close any popup window, because
 	/// you'll able to process the
@@ -1,4 +1,7 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
 use crate::co;
+use crate::decl::*;
 
 /// A [`Result` alias](crate#errors-and-result-aliases) which returns a `Box<dyn
 /// Error + Send + Sync>` on failure.
@@ -22,3 +25,9 @@ pub type AnyResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 /// let err_result: w::AnyResult<()> = sys_result.map_err(|err| err.into());
 /// ```
 pub type SysResult<T> = Result<T, co::ERROR>;
+
+/// Type alias to
+/// [`LPTOP_LEVEL_EXCEPTION_FILTER`](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setunhandledexceptionfilter)
+/// callback function.
+pub type LPTOP_LEVEL_EXCEPTION_FILTER =
+	extern "system" fn(ExceptionInfo: *mut EXCEPTION_POINTERS) -> i32;
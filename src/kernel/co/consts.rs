@@ -65,6 +65,66 @@ const_bitflag! { CONSOLE: u32;
 	ENABLE_LVB_GRID_WORLDWIDE 0x0010
 }
 
+#[cfg(target_arch = "x86_64")]
+const_bitflag! { CONTEXT: u32;
+	/// [`CONTEXT`](crate::CONTEXT) `ContextFlags` (`u32`).
+	///
+	/// Originally has `CONTEXT` prefix.
+	=>
+	CONTROL 0x0010_0001
+	INTEGER 0x0010_0002
+	SEGMENTS 0x0010_0004
+	FLOATING_POINT 0x0010_0008
+	DEBUG_REGISTERS 0x0010_0010
+	FULL 0x0010_000b
+	ALL 0x0010_001f
+}
+
+#[cfg(target_arch = "x86")]
+const_bitflag! { CONTEXT: u32;
+	/// [`CONTEXT`](crate::CONTEXT) `ContextFlags` (`u32`).
+	///
+	/// Originally has `CONTEXT` prefix.
+	=>
+	CONTROL 0x0001_0001
+	INTEGER 0x0001_0002
+	SEGMENTS 0x0001_0004
+	FLOATING_POINT 0x0001_0008
+	DEBUG_REGISTERS 0x0001_0010
+	EXTENDED_REGISTERS 0x0001_0020
+	FULL 0x0001_0007
+	ALL 0x0001_003f
+}
+
+#[cfg(target_arch = "aarch64")]
+const_bitflag! { CONTEXT: u32;
+	/// [`CONTEXT`](crate::CONTEXT) `ContextFlags` (`u32`).
+	///
+	/// Originally has `CONTEXT` prefix.
+	=>
+	CONTROL 0x0040_0001
+	INTEGER 0x0040_0002
+	FLOATING_POINT 0x0040_0004
+	DEBUG 0x0040_0008
+	FULL 0x0040_0007
+	ALL 0x0040_000f
+}
+
+const_bitflag! { COPY_FILE: u32;
+	/// [`CopyFileTransacted`](crate::CopyFileTransacted) `flags` (`u32`).
+	=>
+	FAIL_IF_EXISTS 0x0000_0001
+	RESTARTABLE 0x0000_0002
+	OPEN_SOURCE_FOR_WRITE 0x0000_0004
+	ALLOW_DECRYPTED_DESTINATION 0x0000_0008
+	COPY_SYMLINK 0x0000_0800
+	NO_BUFFERING 0x0000_1000
+	REQUEST_SECURITY_PRIVILEGES 0x0000_2000
+	RESUME_FROM_PAUSE 0x0000_4000
+	NO_OFFLOAD 0x0004_0000
+	REQUEST_COMPRESSED_TRAFFIC 0x1000_0000
+}
+
 const_ordinary! { CP: u16;
 	/// [`WideCharToMultiByte`](crate::WideCharToMultiByte) and
 	/// [`MultiByteToWideChar`](crate::MultiByteToWideChar) `code_page`
@@ -166,6 +226,30 @@ const_bitflag! { CREATE_EVENT: u32;
 	MANUAL_RESET 0x0000_0001
 }
 
+const_bitflag! { DATE: u32;
+	/// [`GetDateFormatEx`](crate::GetDateFormatEx) `flags` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	SHORTDATE 0x0000_0001
+	LONGDATE 0x0000_0002
+	USE_ALT_CALENDAR 0x0000_0004
+	YEARMONTH 0x0000_0008
+	LTRREADING 0x0000_0010
+	RTLREADING 0x0000_0020
+	AUTOLAYOUT 0x0000_0040
+	MONTHDAY 0x0000_0080
+}
+
+const_ordinary! { DBG: u32;
+	/// [`ContinueDebugEvent`](crate::ContinueDebugEvent) `continue_status`
+	/// (`u32`).
+	=>
+	CONTINUE 0x0001_0002
+	EXCEPTION_NOT_HANDLED 0x8001_0001
+	REPLY_LATER 0x4001_0001
+}
+
 const_ordinary! { DBT: u16;
 	/// [`wm::DeviceChange`](crate::msg::wm::DeviceChange) event (`u16`).
 	=>
@@ -282,6 +366,35 @@ const_bitflag! { EVENT_RIGHTS: u32;
 	MODIFY_STATE 0x0002
 }
 
+const_ordinary! { EXCEPTION: u32;
+	/// [`EXCEPTION_RECORD`](crate::EXCEPTION_RECORD) `ExceptionCode` (`u32`).
+	///
+	/// Originally has `EXCEPTION` prefix.
+	=>
+	GUARD_PAGE 0x8000_0001
+	DATATYPE_MISALIGNMENT 0x8000_0002
+	BREAKPOINT 0x8000_0003
+	SINGLE_STEP 0x8000_0004
+	ACCESS_VIOLATION 0xc000_0005
+	IN_PAGE_ERROR 0xc000_0006
+	INVALID_HANDLE 0xc000_0008
+	ILLEGAL_INSTRUCTION 0xc000_001d
+	NONCONTINUABLE_EXCEPTION 0xc000_0025
+	INVALID_DISPOSITION 0xc000_0026
+	ARRAY_BOUNDS_EXCEEDED 0xc000_008c
+	FLT_DENORMAL_OPERAND 0xc000_008d
+	FLT_DIVIDE_BY_ZERO 0xc000_008e
+	FLT_INEXACT_RESULT 0xc000_008f
+	FLT_INVALID_OPERATION 0xc000_0090
+	FLT_OVERFLOW 0xc000_0091
+	FLT_STACK_CHECK 0xc000_0092
+	FLT_UNDERFLOW 0xc000_0093
+	INT_DIVIDE_BY_ZERO 0xc000_0094
+	INT_OVERFLOW 0xc000_0095
+	PRIV_INSTRUCTION 0xc000_0096
+	STACK_OVERFLOW 0xc000_00fd
+}
+
 const_bitflag! { FILE_ATTRIBUTE: u32;
 	/// File
 	/// [attributes](https://learn.microsoft.com/en-us/windows/win32/fileio/file-attribute-constants)
@@ -338,6 +451,46 @@ const_bitflag! { FILE_FLAG: u32;
 	WRITE_THROUGH 0x8000_0000
 }
 
+const_ordinary! { FILE_ID_TYPE: u32;
+	/// [`FILE_ID_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ne-winbase-file_id_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Type` suffix.
+	=>
+	FileId 0
+	ObjectId 1
+	ExtendedFileId 2
+}
+
+const_ordinary! { FILE_INFO_BY_HANDLE_CLASS: u32;
+	/// [`FILE_INFO_BY_HANDLE_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ne-minwinbase-file_info_by_handle_class)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `File` prefix.
+	=>
+	BasicInfo 0
+	StandardInfo 1
+	NameInfo 2
+	RenameInfo 3
+	DispositionInfo 4
+	AllocationInfo 5
+	EndOfFileInfo 6
+	StreamInfo 7
+	CompressionInfo 8
+	AttributeTagInfo 9
+	IdBothDirectoryInfo 10
+	IdBothDirectoryRestartInfo 11
+	IoPriorityHintInfo 12
+	RemoteProtocolInfo 13
+	FullDirectoryInfo 14
+	FullDirectoryRestartInfo 15
+	StorageInfo 16
+	AlignmentInfo 17
+	IdInfo 18
+	IdExtdDirectoryInfo 19
+	IdExtdDirectoryRestartInfo 20
+}
+
 const_bitflag! { FILE_MAP: u32;
 	/// [`HFILEMAP::MapViewOfFile`](crate::prelude::kernel_Hfilemap::MapViewOfFile)
 	/// `desired_access` (`u32`).
@@ -437,6 +590,47 @@ const_bitflag! { FILE_VOL: u32;
 	SUPPORTS_BLOCK_REFCOUNTING 0x0800_0000
 }
 
+const_ordinary! { FINDEX_INFO_LEVELS: u32;
+	/// [`FINDEX_INFO_LEVELS`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ne-minwinbase-findex_info_levels)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `FindExInfo` prefix.
+	=>
+	/// The function queries all the information, including the short file
+	/// name.
+	Standard 0
+	/// The function does not query the short file name, improving overall
+	/// enumeration speed.
+	Basic 1
+}
+
+const_ordinary! { FINDEX_SEARCH_OPS: u32;
+	/// [`FINDEX_SEARCH_OPS`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ne-minwinbase-findex_search_ops)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `FindExSearch` prefix.
+	=>
+	/// The search for a file that matches a specified file name.
+	NameMatch 0
+	/// This is an advisory flag. If the file system supports directory
+	/// filtering, the function searches for a file that matches the specified
+	/// name and is also a directory.
+	LimitToDirectories 1
+}
+
+const_bitflag! { FIND_FIRST_EX: u32;
+	/// [`HFINDFILE::FindFirstFileEx`](crate::prelude::kernel_Hfindfile::FindFirstFileEx)
+	/// `additional_flags` (`u32`).
+	=>
+	/// Searches are case-sensitive.
+	CASE_SENSITIVE 1
+	/// Uses a larger buffer for directory queries, which can increase
+	/// performance of the find operation.
+	LARGE_FETCH 2
+	/// Limits the results to files that are physically on disk.
+	ON_DISK_ENTRIES_ONLY 4
+}
+
 const_ordinary! { FIRMWARE_TYPE: u32;
 	/// [`FIRMWARE_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-firmware_type)
 	/// enumeration (`u32`).
@@ -587,6 +781,60 @@ const_ordinary! { HF32: u32;
 	DEFAULT 1
 }
 
+const_ordinary! { IO_REPARSE_TAG: u32;
+	/// [Reparse point tags](https://learn.microsoft.com/en-us/windows/win32/fileio/reparse-point-tags)
+	/// (`u32`).
+	=>
+	AF_UNIX 0x8000_0023
+	APPEXECLINK 0x8000_001b
+	CLOUD 0x9000_001a
+	CSV 0x8000_0009
+	DEDUP 0x8000_0013
+	DFS 0x8000_000a
+	DFSR 0x8000_0012
+	HSM 0xc000_0004
+	HSM2 0x8000_0006
+	LX_SYMLINK 0xa000_001d
+	MOUNT_POINT 0xa000_0003
+	NFS 0x8000_0014
+	SIS 0x8000_0007
+	SYMLINK 0xa000_000c
+	WCI 0x8000_0018
+	WIM 0x8000_0008
+}
+
+const_ordinary! { IOCTL: u32;
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl)
+	/// `control_code` (`u32`).
+	///
+	/// Originally has `IOCTL` prefix. The file system control codes keep their
+	/// `FSCTL` prefix.
+	=>
+	DISK_GET_DRIVE_GEOMETRY_EX 0x0007_00a0
+	DISK_GET_LENGTH_INFO 0x0007_405c
+	FSCTL_DELETE_REPARSE_POINT 0x0009_00ac
+	FSCTL_GET_REPARSE_POINT 0x0009_00a8
+	FSCTL_SET_REPARSE_POINT 0x0009_00a4
+	STORAGE_GET_DEVICE_NUMBER 0x002d_1080
+	STORAGE_QUERY_PROPERTY 0x002d_1400
+	VOLUME_GET_VOLUME_DISK_EXTENTS 0x0056_0000
+}
+
+impl IOCTL {
+	/// [`CTL_CODE`](https://learn.microsoft.com/en-us/windows-hardware/drivers/kernel/defining-i-o-control-codes)
+	/// macro.
+	#[allow(non_snake_case)]
+	#[must_use]
+	pub const fn CTL_CODE(device_type: u16, function: u16, method: u8, access: u8) -> Self {
+		Self(
+			((device_type as u32) << 16)
+				| (((access & 0b11) as u32) << 14)
+				| (((function & 0xfff) as u32) << 2)
+				| (method & 0b11) as u32,
+		)
+	}
+}
+
 const_ordinary! { LANG: u16;
 	/// Language
 	/// [identifier](https://learn.microsoft.com/en-us/windows/win32/intl/language-identifier-constants-and-strings)
@@ -734,6 +982,173 @@ const_ordinary! { LANG: u16;
 	ZULU 0x35
 }
 
+const_bitflag! { LCMAP: u32;
+	/// [`LCMapStringEx`](crate::LCMapStringEx) `flags` (`u32`).
+	=>
+	LOWERCASE 0x0000_0100
+	UPPERCASE 0x0000_0200
+	TITLECASE 0x0000_0300
+	BYTEREV 0x0000_0800
+	HIRAGANA 0x0010_0000
+	KATAKANA 0x0020_0000
+	HALFWIDTH 0x0040_0000
+	FULLWIDTH 0x0080_0000
+	LINGUISTIC_CASING 0x0100_0000
+	SIMPLIFIED_CHINESE 0x0200_0000
+	TRADITIONAL_CHINESE 0x0400_0000
+}
+
+const_ordinary! { LCTYPE: u32;
+	/// [`GetLocaleInfoEx`](crate::GetLocaleInfoEx) `lc_type` (`u32`).
+	///
+	/// Originally has `LOCALE_` prefix.
+	=>
+	ILANGUAGE 0x0001
+	SLANGUAGE 0x0002
+	SLOCALIZEDDISPLAYNAME 0x0002
+	SENGLISHDISPLAYNAME 0x0072
+	SNATIVEDISPLAYNAME 0x0073
+	SLOCALIZEDLANGUAGENAME 0x006f
+	SENGLISHLANGUAGENAME 0x1001
+	SNATIVELANGUAGENAME 0x0004
+	SABBREVLANGNAME 0x0003
+	ICOUNTRY 0x0005
+	SLOCALIZEDCOUNTRYNAME 0x0006
+	SENGLISHCOUNTRYNAME 0x1002
+	SNATIVECOUNTRYNAME 0x0008
+	SABBREVCTRYNAME 0x0007
+	IDEFAULTLANGUAGE 0x0009
+	IDEFAULTCOUNTRY 0x000a
+	IDEFAULTCODEPAGE 0x000b
+	IDEFAULTANSICODEPAGE 0x1004
+	IDEFAULTMACCODEPAGE 0x1011
+	IDEFAULTEBCDICCODEPAGE 0x1012
+	SLIST 0x000c
+	IMEASURE 0x000d
+	IPAPERSIZE 0x100a
+	SDECIMAL 0x000e
+	STHOUSAND 0x000f
+	SGROUPING 0x0010
+	IDIGITS 0x0011
+	ILZERO 0x0012
+	INEGNUMBER 0x1010
+	SNATIVEDIGITS 0x0013
+	IDIGITSUBSTITUTION 0x1014
+	SCURRENCY 0x0014
+	SINTLSYMBOL 0x0015
+	SENGCURRNAME 0x1007
+	SNATIVECURRNAME 0x1008
+	SMONDECIMALSEP 0x0016
+	SMONTHOUSANDSEP 0x0017
+	SMONGROUPING 0x0018
+	ICURRDIGITS 0x0019
+	IINTLCURRDIGITS 0x001a
+	ICURRENCY 0x001b
+	INEGCURR 0x001c
+	SPOSITIVESIGN 0x0050
+	SNEGATIVESIGN 0x0051
+	IPOSSIGNPOSN 0x0052
+	INEGSIGNPOSN 0x0053
+	IPOSSYMPRECEDES 0x0054
+	IPOSSEPBYSPACE 0x0055
+	INEGSYMPRECEDES 0x0056
+	INEGSEPBYSPACE 0x0057
+	SPERCENT 0x0076
+	INEGATIVEPERCENT 0x0074
+	IPOSITIVEPERCENT 0x0075
+	SPERMILLE 0x0077
+	SNAN 0x0069
+	SPOSINFINITY 0x006a
+	SNEGINFINITY 0x006b
+	SDATE 0x001d
+	STIME 0x001e
+	SSHORTDATE 0x001f
+	SLONGDATE 0x0020
+	SYEARMONTH 0x1006
+	SMONTHDAY 0x0078
+	SRELATIVELONGDATE 0x007c
+	STIMEFORMAT 0x1003
+	SSHORTTIME 0x0079
+	SDURATION 0x005d
+	IDATE 0x0021
+	ILDATE 0x0022
+	ITIME 0x0023
+	ITIMEMARKPOSN 0x1005
+	ICENTURY 0x0024
+	ITLZERO 0x0025
+	IDAYLZERO 0x0026
+	IMONLZERO 0x0027
+	S1159 0x0028
+	S2359 0x0029
+	SSHORTESTAM 0x007e
+	SSHORTESTPM 0x007f
+	ICALENDARTYPE 0x1009
+	IOPTIONALCALENDAR 0x100b
+	IFIRSTDAYOFWEEK 0x100c
+	IFIRSTWEEKOFYEAR 0x100d
+	SDAYNAME1 0x002a
+	SDAYNAME2 0x002b
+	SDAYNAME3 0x002c
+	SDAYNAME4 0x002d
+	SDAYNAME5 0x002e
+	SDAYNAME6 0x002f
+	SDAYNAME7 0x0030
+	SABBREVDAYNAME1 0x0031
+	SABBREVDAYNAME2 0x0032
+	SABBREVDAYNAME3 0x0033
+	SABBREVDAYNAME4 0x0034
+	SABBREVDAYNAME5 0x0035
+	SABBREVDAYNAME6 0x0036
+	SABBREVDAYNAME7 0x0037
+	SSHORTESTDAYNAME1 0x0060
+	SSHORTESTDAYNAME2 0x0061
+	SSHORTESTDAYNAME3 0x0062
+	SSHORTESTDAYNAME4 0x0063
+	SSHORTESTDAYNAME5 0x0064
+	SSHORTESTDAYNAME6 0x0065
+	SSHORTESTDAYNAME7 0x0066
+	SMONTHNAME1 0x0038
+	SMONTHNAME2 0x0039
+	SMONTHNAME3 0x003a
+	SMONTHNAME4 0x003b
+	SMONTHNAME5 0x003c
+	SMONTHNAME6 0x003d
+	SMONTHNAME7 0x003e
+	SMONTHNAME8 0x003f
+	SMONTHNAME9 0x0040
+	SMONTHNAME10 0x0041
+	SMONTHNAME11 0x0042
+	SMONTHNAME12 0x0043
+	SMONTHNAME13 0x100e
+	SABBREVMONTHNAME1 0x0044
+	SABBREVMONTHNAME2 0x0045
+	SABBREVMONTHNAME3 0x0046
+	SABBREVMONTHNAME4 0x0047
+	SABBREVMONTHNAME5 0x0048
+	SABBREVMONTHNAME6 0x0049
+	SABBREVMONTHNAME7 0x004a
+	SABBREVMONTHNAME8 0x004b
+	SABBREVMONTHNAME9 0x004c
+	SABBREVMONTHNAME10 0x004d
+	SABBREVMONTHNAME11 0x004e
+	SABBREVMONTHNAME12 0x004f
+	SABBREVMONTHNAME13 0x100f
+	SISO639LANGNAME 0x0059
+	SISO639LANGNAME2 0x0067
+	SISO3166CTRYNAME 0x005a
+	SISO3166CTRYNAME2 0x0068
+	IGEOID 0x005b
+	SNAME 0x005c
+	SSCRIPTS 0x006c
+	SPARENT 0x006d
+	SCONSOLEFALLBACKNAME 0x006e
+	IREADINGLAYOUT 0x0070
+	INEUTRAL 0x0071
+	SOPENTYPELANGUAGETAG 0x007a
+	SSORTLOCALE 0x007b
+	SSORTNAME 0x1013
+}
+
 const_bitflag! { LMEM: u32;
 	/// [`HLOCAL::LocalAlloc`](crate::prelude::kernel_Hlocal::LocalAlloc) and
 	/// [`HLOCAL::LocalReAlloc`](crate::prelude::kernel_Hlocal::LocalReAlloc)
@@ -757,6 +1172,22 @@ const_bitflag! { LMEM: u32;
 	LPTR Self::FIXED.0 | Self::ZEROINIT.0
 }
 
+const_bitflag! { LOCALE: u32;
+	/// [`EnumSystemLocalesEx`](crate::EnumSystemLocalesEx) `flags` (`u32`),
+	/// also used in the formatting functions.
+	=>
+	/// All the locales (zero).
+	ALL 0x0000_0000
+	WINDOWS 0x0000_0001
+	SUPPLEMENTAL 0x0000_0002
+	ALTERNATE_SORTS 0x0000_0004
+	REPLACEMENT 0x0000_0008
+	NEUTRALDATA 0x0000_0010
+	SPECIFICDATA 0x0000_0020
+	/// Formats with the system default values, ignoring the user overrides.
+	NOUSEROVERRIDE 0x8000_0000
+}
+
 const_bitflag! { MBC: u32;
 	/// [`MultiByteToWideChar`](crate::MultiByteToWideChar) `flags` (`u32`).
 	///
@@ -770,6 +1201,25 @@ const_bitflag! { MBC: u32;
 	USEGLYPHCHARS 0x0000_0004
 }
 
+const_ordinary! { MEDIA_TYPE: u32;
+	/// [`MEDIA_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ne-winioctl-media_type)
+	/// enumeration (`u32`).
+	=>
+	Unknown 0
+	F5_1Pt2_512 1
+	F3_1Pt44_512 2
+	F3_2Pt88_512 3
+	F3_20Pt8_512 4
+	F3_720_512 5
+	F5_360_512 6
+	F5_320_512 7
+	F5_320_1024 8
+	F5_180_512 9
+	F5_160_512 10
+	RemovableMedia 11
+	FixedMedia 12
+}
+
 const_ordinary! { MONITOR_DISPLAY_STATE: u32;
 	/// [`MONITOR_DISPLAY_STATE`](https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/ne-wdm-_monitor_display_state)
 	/// enumeration (`u32`).
@@ -812,6 +1262,26 @@ const_ordinary! { MOVEFILE: u32;
 	WRITE_THROUGH 0x0000_0008
 }
 
+const_bitflag! { NORM: u32;
+	/// [`CompareStringEx`](crate::CompareStringEx) `flags` (`u32`).
+	///
+	/// Originally has `NORM_` prefix; the `LINGUISTIC_` and `SORT_` flags keep
+	/// their prefixes.
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	IGNORECASE 0x0000_0001
+	IGNORENONSPACE 0x0000_0002
+	IGNORESYMBOLS 0x0000_0004
+	IGNOREKANATYPE 0x0001_0000
+	IGNOREWIDTH 0x0002_0000
+	LINGUISTIC_CASING 0x0800_0000
+	LINGUISTIC_IGNORECASE 0x0000_0010
+	LINGUISTIC_IGNOREDIACRITIC 0x0000_0020
+	SORT_DIGITSASNUMBERS 0x0000_0008
+	SORT_STRINGSORT 0x0000_1000
+}
+
 const_ordinary! { PAGE: u32;
 	/// [`HFILE::CreateFileMapping`](crate::prelude::kernel_Hfile::CreateFileMapping)
 	/// `protect` (`u32`).
@@ -1243,6 +1713,62 @@ const_ordinary! { STD_HANDLE: u32;
 	ERROR -12i32 as u32
 }
 
+const_ordinary! { STORAGE_BUS_TYPE: u32;
+	/// [`STORAGE_BUS_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ne-winioctl-storage_bus_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `BusType` prefix.
+	=>
+	Unknown 0x00
+	Scsi 0x01
+	Atapi 0x02
+	Ata 0x03
+	/// Originally `BusType1394`.
+	Ieee1394 0x04
+	Ssa 0x05
+	Fibre 0x06
+	Usb 0x07
+	RAID 0x08
+	iScsi 0x09
+	Sas 0x0a
+	Sata 0x0b
+	Sd 0x0c
+	Mmc 0x0d
+	Virtual 0x0e
+	FileBackedVirtual 0x0f
+	Spaces 0x10
+	Nvme 0x11
+	SCM 0x12
+	Ufs 0x13
+}
+
+const_ordinary! { STORAGE_PROPERTY_ID: u32;
+	/// [`STORAGE_PROPERTY_ID`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ne-winioctl-storage_property_id)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Storage` prefix and `Property` suffix.
+	=>
+	Device 0
+	Adapter 1
+	DeviceId 2
+	DeviceUniqueId 3
+	DeviceWriteCache 4
+	Miniport 5
+	AccessAlignment 6
+	DeviceSeekPenalty 7
+	DeviceTrim 8
+}
+
+const_ordinary! { STORAGE_QUERY_TYPE: u32;
+	/// [`STORAGE_QUERY_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ne-winioctl-storage_query_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Property` prefix and `Query` suffix.
+	=>
+	Standard 0
+	Exists 1
+}
+
 const_ordinary! { SUBLANG: u16;
 	/// Sublanguage
 	/// [identifier](https://learn.microsoft.com/en-us/windows/win32/intl/language-identifier-constants-and-strings)
@@ -1538,6 +2064,16 @@ const_ordinary! { SW: i32;
 	FORCEMINIMIZE 11
 }
 
+const_bitflag! { SYMBOLIC_LINK_FLAG: u32;
+	/// [`CreateSymbolicLink`](crate::CreateSymbolicLink) `flags` (`u32`).
+	=>
+	/// The link target is a directory.
+	DIRECTORY 0x1
+	/// Allows the creation of symbolic links when the process is not elevated,
+	/// if the developer mode is enabled.
+	ALLOW_UNPRIVILEGED_CREATE 0x2
+}
+
 const_ordinary! { SYSTEM_POWER_CONDITION: u32;
 	/// [`SYSTEM_POWER_CONDITION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-system_power_condition)
 	/// enumeration (`u32`).
@@ -1582,6 +2118,27 @@ const_bitflag! { THREAD_CREATE: u32;
 	STACK_SIZE_PARAM_IS_A_RESERVATION 0x0001_0000
 }
 
+const_bitflag! { TIME: u32;
+	/// [`GetTimeFormatEx`](crate::GetTimeFormatEx) `flags` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	NOMINUTESORSECONDS 0x0000_0001
+	NOSECONDS 0x0000_0002
+	NOTIMEMARKER 0x0000_0004
+	FORCE24HOURFORMAT 0x0000_0008
+}
+
+const_ordinary! { TIME_ZONE_ID: u32;
+	/// [`GetTimeZoneInformation`](crate::GetTimeZoneInformation) and
+	/// [`GetDynamicTimeZoneInformation`](crate::GetDynamicTimeZoneInformation)
+	/// return value (`u32`).
+	=>
+	UNKNOWN 0
+	STANDARD 1
+	DAYLIGHT 2
+}
+
 const_bitflag! { TOKEN: u32;
 	/// [Token access rights](https://learn.microsoft.com/en-us/windows/win32/secauthz/access-rights-for-access-token-objects).
 	=>
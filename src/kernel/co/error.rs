@@ -58,2611 +58,5378 @@ impl std::fmt::Debug for ERROR {
 
 impl SystemError for ERROR {}
 
-const_values_pub! { ERROR;
+impl From<ERROR> for std::io::ErrorKind {
+	fn from(err: ERROR) -> Self {
+		use std::io::ErrorKind as K;
+		match err {
+			ERROR::ACCESS_DENIED | ERROR::NETWORK_ACCESS_DENIED | ERROR::PRIVILEGE_NOT_HELD => {
+				K::PermissionDenied
+			},
+			ERROR::ALREADY_EXISTS | ERROR::FILE_EXISTS => K::AlreadyExists,
+			ERROR::BROKEN_PIPE | ERROR::NO_DATA => K::BrokenPipe,
+			ERROR::BUSY | ERROR::PIPE_BUSY | ERROR::PATH_BUSY => K::ResourceBusy,
+			ERROR::CALL_NOT_IMPLEMENTED | ERROR::NOT_SUPPORTED => K::Unsupported,
+			ERROR::CONNECTION_ABORTED => K::ConnectionAborted,
+			ERROR::CONNECTION_REFUSED => K::ConnectionRefused,
+			ERROR::DIR_NOT_EMPTY => K::DirectoryNotEmpty,
+			ERROR::DIRECTORY => K::NotADirectory,
+			ERROR::DIRECTORY_NOT_SUPPORTED => K::IsADirectory,
+			ERROR::DISK_FULL | ERROR::HANDLE_DISK_FULL => K::StorageFull,
+			ERROR::FILE_NOT_FOUND
+			| ERROR::PATH_NOT_FOUND
+			| ERROR::INVALID_DRIVE
+			| ERROR::BAD_NETPATH
+			| ERROR::BAD_NET_NAME
+			| ERROR::MOD_NOT_FOUND
+			| ERROR::PROC_NOT_FOUND
+			| ERROR::NOT_FOUND => K::NotFound,
+			ERROR::FILE_TOO_LARGE => K::FileTooLarge,
+			ERROR::FILENAME_EXCED_RANGE | ERROR::INVALID_NAME | ERROR::BAD_PATHNAME => {
+				K::InvalidInput // InvalidFilename requires Rust 1.87
+			},
+			ERROR::HANDLE_EOF => K::UnexpectedEof,
+			ERROR::HOST_UNREACHABLE => K::HostUnreachable,
+			ERROR::INVALID_DATA => K::InvalidData,
+			ERROR::INVALID_PARAMETER | ERROR::BAD_ARGUMENTS | ERROR::INVALID_FLAGS => {
+				K::InvalidInput
+			},
+			ERROR::IO_PENDING => K::WouldBlock,
+			ERROR::NETNAME_DELETED => K::ConnectionReset,
+			ERROR::NETWORK_UNREACHABLE => K::NetworkUnreachable,
+			ERROR::NOT_ENOUGH_MEMORY | ERROR::OUTOFMEMORY => K::OutOfMemory,
+			ERROR::OPERATION_ABORTED | ERROR::CANCELLED => K::Interrupted,
+			ERROR::PIPE_NOT_CONNECTED => K::NotConnected,
+			ERROR::POSSIBLE_DEADLOCK => K::Deadlock,
+			ERROR::SEEK_ON_DEVICE => K::NotSeekable,
+			ERROR::SEM_TIMEOUT
+			| ERROR::TIMEOUT
+			| ERROR::SERVICE_REQUEST_TIMEOUT
+			| ERROR::DRIVER_CANCEL_TIMEOUT
+			| ERROR::COUNTER_TIMEOUT => K::TimedOut,
+			ERROR::TOO_MANY_LINKS => K::TooManyLinks,
+			ERROR::WRITE_PROTECT => K::ReadOnlyFilesystem,
+			_ => K::Other,
+		}
+	}
+}
+
+impl ERROR {
+	/// Returns the English description of the error code, taken from the
+	/// embedded message table, if available.
+	///
+	/// Unlike [`FormatMessage`](crate::prelude::SystemError::FormatMessage),
+	/// this method doesn't call the system, so the text doesn't depend on the
+	/// system language.
+	///
+	/// Every constant declared in this module has a description; `None` is
+	/// returned only for codes unknown to this crate. The descriptions follow
+	/// the English system messages, but small wording differences are
+	/// possible. The [`Display`](std::fmt::Display) and
+	/// [`Debug`](std::fmt::Debug) implementations always use `FormatMessage`,
+	/// regardless of this feature.
+	///
+	/// This method is available with the `msg-table` feature.
+	#[cfg(feature = "msg-table")]
+	#[must_use]
+	pub fn description(self) -> Option<&'static str> {
+		self.msg_entry()
+			.map(|(_, desc)| desc.trim())
+			.filter(|desc| !desc.is_empty())
+	}
+
+	/// Returns the symbolic name of the error code, without the `ERROR_`
+	/// prefix, taken from the embedded message table, if available.
+	///
+	/// This method is available with the `msg-table` feature.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let name = co::ERROR::FILE_NOT_FOUND.name(); // Some("FILE_NOT_FOUND")
+	/// ```
+	#[cfg(feature = "msg-table")]
+	#[must_use]
+	pub fn name(self) -> Option<&'static str> {
+		self.msg_entry().map(|(name, _)| name)
+	}
+
+	#[cfg(feature = "msg-table")]
+	#[must_use]
+	pub(crate) fn msg_entry(self) -> Option<(&'static str, &'static str)> {
+		ERROR_MSGS
+			.iter()
+			.find(|(val, _, _)| *val == self.0)
+			.map(|(_, name, desc)| (*name, *desc))
+	}
+}
+
+const_values_pub_msgs! { ERROR, ERROR_MSGS;
 	/// The operation completed successfully.
 	SUCCESS 0
+	/// Incorrect function.
 	INVALID_FUNCTION 1
+	/// The system cannot find the file specified.
 	FILE_NOT_FOUND 2
+	/// The system cannot find the path specified.
 	PATH_NOT_FOUND 3
+	/// The system cannot open the file.
 	TOO_MANY_OPEN_FILES 4
+	/// Access is denied.
 	ACCESS_DENIED 5
+	/// The handle is invalid.
 	INVALID_HANDLE 6
+	/// The storage control blocks were destroyed.
 	ARENA_TRASHED 7
+	/// Not enough memory resources are available to process this command.
 	NOT_ENOUGH_MEMORY 8
+	/// The storage control block address is invalid.
 	INVALID_BLOCK 9
+	/// The environment is incorrect.
 	BAD_ENVIRONMENT 10
+	/// An attempt was made to load a program with an incorrect format.
 	BAD_FORMAT 11
+	/// The access code is invalid.
 	INVALID_ACCESS 12
+	/// The data is invalid.
 	INVALID_DATA 13
+	/// Not enough memory resources are available to complete this operation.
 	OUTOFMEMORY 14
+	/// The system cannot find the drive specified.
 	INVALID_DRIVE 15
+	/// The directory cannot be removed.
 	CURRENT_DIRECTORY 16
+	/// The system cannot move the file to a different disk drive.
 	NOT_SAME_DEVICE 17
+	/// There are no more files.
 	NO_MORE_FILES 18
+	/// The media is write protected.
 	WRITE_PROTECT 19
+	/// The system cannot find the device specified.
 	BAD_UNIT 20
+	/// The device is not ready.
 	NOT_READY 21
+	/// The device does not recognize the command.
 	BAD_COMMAND 22
+	/// Data error (cyclic redundancy check).
 	CRC 23
+	/// The program issued a command but the command length is incorrect.
 	BAD_LENGTH 24
+	/// The drive cannot locate a specific area or track on the disk.
 	SEEK 25
+	/// The specified disk or diskette cannot be accessed.
 	NOT_DOS_DISK 26
+	/// The drive cannot find the sector requested.
 	SECTOR_NOT_FOUND 27
+	/// The printer is out of paper.
 	OUT_OF_PAPER 28
+	/// The system cannot write to the specified device.
 	WRITE_FAULT 29
+	/// The system cannot read from the specified device.
 	READ_FAULT 30
+	/// A device attached to the system is not functioning.
 	GEN_FAILURE 31
+	/// The process cannot access the file because it is being used by another process.
 	SHARING_VIOLATION 32
+	/// The process cannot access the file because another process has locked a portion of the file.
 	LOCK_VIOLATION 33
+	/// The wrong diskette is in the drive.
 	WRONG_DISK 34
+	/// Too many files opened for sharing.
 	SHARING_BUFFER_EXCEEDED 36
+	/// Reached the end of the file.
 	HANDLE_EOF 38
+	/// The disk is full.
 	HANDLE_DISK_FULL 39
+	/// The request is not supported.
 	NOT_SUPPORTED 50
+	/// Windows cannot find the network path. Verify that the network path is correct and the destination computer is not busy or turned off. If Windows still cannot find the network path, contact your network administrator.
 	REM_NOT_LIST 51
+	/// You were not connected because a duplicate name exists on the network. If joining a domain, go to System in Control Panel to change the computer name and try again. If joining a workgroup, choose another workgroup name.
 	DUP_NAME 52
+	/// The network path was not found.
 	BAD_NETPATH 53
+	/// The network is busy.
 	NETWORK_BUSY 54
+	/// The specified network resource or device is no longer available.
 	DEV_NOT_EXIST 55
+	/// The network BIOS command limit has been reached.
 	TOO_MANY_CMDS 56
+	/// A network adapter hardware error occurred.
 	ADAP_HDW_ERR 57
+	/// The specified server cannot perform the requested operation.
 	BAD_NET_RESP 58
+	/// An unexpected network error occurred.
 	UNEXP_NET_ERR 59
+	/// The remote adapter is not compatible.
 	BAD_REM_ADAP 60
+	/// The printer queue is full.
 	PRINTQ_FULL 61
+	/// Space to store the file waiting to be printed is not available on the server.
 	NO_SPOOL_SPACE 62
+	/// Your file waiting to be printed was deleted.
 	PRINT_CANCELLED 63
+	/// The specified network name is no longer available.
 	NETNAME_DELETED 64
+	/// Network access is denied.
 	NETWORK_ACCESS_DENIED 65
+	/// The network resource type is not correct.
 	BAD_DEV_TYPE 66
+	/// The network name cannot be found.
 	BAD_NET_NAME 67
+	/// The name limit for the local computer network adapter card was exceeded.
 	TOO_MANY_NAMES 68
+	/// The network BIOS session limit was exceeded.
 	TOO_MANY_SESS 69
+	/// The remote server has been paused or is in the process of being started.
 	SHARING_PAUSED 70
+	/// No more connections can be made to this remote computer at this time because there are already as many connections as the computer can accept.
 	REQ_NOT_ACCEP 71
+	/// The specified printer or disk device has been paused.
 	REDIR_PAUSED 72
+	/// The file exists.
 	FILE_EXISTS 80
+	/// The directory or file cannot be created.
 	CANNOT_MAKE 82
+	/// Fail on INT 24.
 	FAIL_I24 83
+	/// Storage to process this request is not available.
 	OUT_OF_STRUCTURES 84
+	/// The local device name is already in use.
 	ALREADY_ASSIGNED 85
+	/// The specified network password is not correct.
 	INVALID_PASSWORD 86
+	/// The parameter is incorrect.
 	INVALID_PARAMETER 87
+	/// A write fault occurred on the network.
 	NET_WRITE_FAULT 88
+	/// The system cannot start another process at this time.
 	NO_PROC_SLOTS 89
+	/// Cannot create another system semaphore.
 	TOO_MANY_SEMAPHORES 100
+	/// The exclusive semaphore is owned by another process.
 	EXCL_SEM_ALREADY_OWNED 101
+	/// The semaphore is set and cannot be closed.
 	SEM_IS_SET 102
+	/// The semaphore cannot be set again.
 	TOO_MANY_SEM_REQUESTS 103
+	/// Cannot request exclusive semaphores at interrupt time.
 	INVALID_AT_INTERRUPT_TIME 104
+	/// The previous ownership of this semaphore has ended.
 	SEM_OWNER_DIED 105
+	/// Insert the diskette for drive %1.
 	SEM_USER_LIMIT 106
+	/// The program stopped because an alternate diskette was not inserted.
 	DISK_CHANGE 107
+	/// The disk is in use or locked by another process.
 	DRIVE_LOCKED 108
+	/// The pipe has been ended.
 	BROKEN_PIPE 109
+	/// The system cannot open the device or file specified.
 	OPEN_FAILED 110
+	/// The file name is too long.
 	BUFFER_OVERFLOW 111
+	/// There is not enough space on the disk.
 	DISK_FULL 112
+	/// No more internal file identifiers available.
 	NO_MORE_SEARCH_HANDLES 113
+	/// The target internal file identifier is incorrect.
 	INVALID_TARGET_HANDLE 114
+	/// The IOCTL call made by the application program is not correct.
 	INVALID_CATEGORY 117
+	/// The verify-on-write switch parameter value is not correct.
 	INVALID_VERIFY_SWITCH 118
+	/// The system does not support the command requested.
 	BAD_DRIVER_LEVEL 119
+	/// This function is not supported on this system.
 	CALL_NOT_IMPLEMENTED 120
+	/// The semaphore timeout period has expired.
 	SEM_TIMEOUT 121
+	/// The data area passed to a system call is too small.
 	INSUFFICIENT_BUFFER 122
+	/// The filename, directory name, or volume label syntax is incorrect.
 	INVALID_NAME 123
+	/// The system call level is not correct.
 	INVALID_LEVEL 124
+	/// The disk has no volume label.
 	NO_VOLUME_LABEL 125
+	/// The specified module could not be found.
 	MOD_NOT_FOUND 126
+	/// The specified procedure could not be found.
 	PROC_NOT_FOUND 127
+	/// There are no child processes to wait for.
 	WAIT_NO_CHILDREN 128
+	/// The %1 application cannot be run in Win32 mode.
 	CHILD_NOT_COMPLETE 129
+	/// Attempt to use a file handle to an open disk partition for an operation other than raw disk I/O.
 	DIRECT_ACCESS_HANDLE 130
+	/// An attempt was made to move the file pointer before the beginning of the file.
 	NEGATIVE_SEEK 131
+	/// The file pointer cannot be set on the specified device or file.
 	SEEK_ON_DEVICE 132
+	/// A JOIN or SUBST command cannot be used for a drive that contains previously joined drives.
 	IS_JOIN_TARGET 133
+	/// An attempt was made to use a JOIN or SUBST command on a drive that has already been joined.
 	IS_JOINED 134
+	/// An attempt was made to use a JOIN or SUBST command on a drive that has already been substituted.
 	IS_SUBSTED 135
+	/// The system tried to delete the JOIN of a drive that is not joined.
 	NOT_JOINED 136
+	/// The system tried to delete the substitution of a drive that is not substituted.
 	NOT_SUBSTED 137
+	/// The system tried to join a drive to a directory on a joined drive.
 	JOIN_TO_JOIN 138
+	/// The system tried to substitute a drive to a directory on a substituted drive.
 	SUBST_TO_SUBST 139
+	/// The system tried to join a drive to a directory on a substituted drive.
 	JOIN_TO_SUBST 140
+	/// The system tried to SUBST a drive to a directory on a joined drive.
 	SUBST_TO_JOIN 141
+	/// The system cannot perform a JOIN or SUBST at this time.
 	BUSY_DRIVE 142
+	/// The system cannot join or substitute a drive to or for a directory on the same drive.
 	SAME_DRIVE 143
+	/// The directory is not a subdirectory of the root directory.
 	DIR_NOT_ROOT 144
+	/// The directory is not empty.
 	DIR_NOT_EMPTY 145
+	/// The path specified is being used in a substitute.
 	IS_SUBST_PATH 146
+	/// Not enough resources are available to process this command.
 	IS_JOIN_PATH 147
+	/// The path specified cannot be used at this time.
 	PATH_BUSY 148
+	/// An attempt was made to join or substitute a drive for which a directory on the drive is the target of a previous substitute.
 	IS_SUBST_TARGET 149
+	/// System trace information was not specified in your CONFIG.SYS file, or tracing is disallowed.
 	SYSTEM_TRACE 150
+	/// The number of specified semaphore events for DosMuxSemWait is not correct.
 	INVALID_EVENT_COUNT 151
+	/// DosMuxSemWait did not execute; too many semaphores are already set.
 	TOO_MANY_MUXWAITERS 152
+	/// The DosMuxSemWait list is not correct.
 	INVALID_LIST_FORMAT 153
+	/// The volume label you entered exceeds the label character limit of the target file system.
 	LABEL_TOO_LONG 154
+	/// Cannot create another thread.
 	TOO_MANY_TCBS 155
+	/// The recipient process has refused the signal.
 	SIGNAL_REFUSED 156
+	/// The segment is already discarded and cannot be locked.
 	DISCARDED 157
+	/// The segment is already unlocked.
 	NOT_LOCKED 158
+	/// The address for the thread ID is not correct.
 	BAD_THREADID_ADDR 159
+	/// One or more arguments are not correct.
 	BAD_ARGUMENTS 160
+	/// The specified path is invalid.
 	BAD_PATHNAME 161
+	/// A signal is already pending.
 	SIGNAL_PENDING 162
+	/// No more threads can be created in the system.
 	MAX_THRDS_REACHED 164
+	/// Unable to lock a region of a file.
 	LOCK_FAILED 167
+	/// The requested resource is in use.
 	BUSY 170
+	/// The device does not support the command feature.
 	DEVICE_SUPPORT_IN_PROGRESS 171
+	/// A lock request was not outstanding for the supplied cancel region.
 	CANCEL_VIOLATION 173
+	/// The file system does not support atomic changes to the lock type.
 	ATOMIC_LOCKS_NOT_SUPPORTED 174
+	/// The system detected a segment number that was not correct.
 	INVALID_SEGMENT_NUMBER 180
+	/// The operating system cannot run %1.
 	INVALID_ORDINAL 182
+	/// Cannot create a file when that file already exists.
 	ALREADY_EXISTS 183
+	/// The flag passed is not correct.
 	INVALID_FLAG_NUMBER 186
+	/// The specified system semaphore name was not found.
 	SEM_NOT_FOUND 187
+	/// The operating system cannot run %1.
 	INVALID_STARTING_CODESEG 188
+	/// The operating system cannot run %1.
 	INVALID_STACKSEG 189
+	/// The operating system cannot run %1.
 	INVALID_MODULETYPE 190
+	/// Cannot run %1 in Win32 mode.
 	INVALID_EXE_SIGNATURE 191
+	/// The operating system cannot run %1.
 	EXE_MARKED_INVALID 192
+	/// %1 is not a valid Win32 application.
 	BAD_EXE_FORMAT 193
+	/// The operating system cannot run %1.
 	ITERATED_DATA_EXCEEDS_64k 194
+	/// The operating system cannot run %1.
 	INVALID_MINALLOCSIZE 195
+	/// The operating system cannot run this application program.
 	DYNLINK_FROM_INVALID_RING 196
+	/// The operating system is not presently configured to run this application.
 	IOPL_NOT_ENABLED 197
+	/// The operating system cannot run %1.
 	INVALID_SEGDPL 198
+	/// The operating system cannot run this application program.
 	AUTODATASEG_EXCEEDS_64k 199
+	/// The code segment cannot be greater than or equal to 64K.
 	RING2SEG_MUST_BE_MOVABLE 200
+	/// The operating system cannot run %1.
 	RELOC_CHAIN_XEEDS_SEGLIM 201
+	/// The operating system cannot run %1.
 	INFLOOP_IN_RELOC_CHAIN 202
+	/// The system could not find the environment option that was entered.
 	ENVVAR_NOT_FOUND 203
+	/// No process in the command subtree has a signal handler.
 	NO_SIGNAL_SENT 205
+	/// The filename or extension is too long.
 	FILENAME_EXCED_RANGE 206
+	/// The ring 2 stack is in use.
 	RING2_STACK_IN_USE 207
+	/// The global filename characters, * or ?, are entered incorrectly or too many global filename characters are specified.
 	META_EXPANSION_TOO_LONG 208
+	/// The signal being posted is not correct.
 	INVALID_SIGNAL_NUMBER 209
+	/// The signal handler cannot be set.
 	THREAD_1_INACTIVE 210
+	/// The segment is locked and cannot be reallocated.
 	LOCKED 212
+	/// Too many dynamic-link modules are attached to this program or dynamic-link module.
 	TOO_MANY_MODULES 214
+	/// Cannot nest calls to LoadModule.
 	NESTING_NOT_ALLOWED 215
+	/// This version of %1 is not compatible with the version of Windows you're running. Check your computer's system information and then contact the software publisher.
 	EXE_MACHINE_TYPE_MISMATCH 216
+	/// The image file %1 is signed, unable to modify.
 	EXE_CANNOT_MODIFY_SIGNED_BINARY 217
+	/// The image file %1 is strong signed, unable to modify.
 	EXE_CANNOT_MODIFY_STRONG_SIGNED_BINARY 218
+	/// This file is checked out or locked for editing by another user.
 	FILE_CHECKED_OUT 220
+	/// The file must be checked out before saving changes.
 	CHECKOUT_REQUIRED 221
+	/// The file type being saved or retrieved has been blocked.
 	BAD_FILE_TYPE 222
+	/// The file size exceeds the limit allowed and cannot be saved.
 	FILE_TOO_LARGE 223
+	/// Access Denied. Before opening files in this location, you must first add the web site to your trusted sites list, browse to the web site, and select the option to login automatically.
 	FORMS_AUTH_REQUIRED 224
+	/// Operation did not complete successfully because the file contains a virus or potentially unwanted software.
 	VIRUS_INFECTED 225
+	/// This file contains a virus or potentially unwanted software and cannot be opened. Due to the nature of this virus or potentially unwanted software, the file has been removed from this location.
 	VIRUS_DELETED 226
+	/// The pipe is local.
 	PIPE_LOCAL 229
+	/// The pipe state is invalid.
 	BAD_PIPE 230
+	/// All pipe instances are busy.
 	PIPE_BUSY 231
+	/// The pipe is being closed.
 	NO_DATA 232
+	/// No process is on the other end of the pipe.
 	PIPE_NOT_CONNECTED 233
+	/// More data is available.
 	MORE_DATA 234
+	/// The data area passed to a system call is too small.
 	NO_WORK_DONE 235
+	/// The session was canceled.
 	VC_DISCONNECTED 240
+	/// The specified extended attribute name was invalid.
 	INVALID_EA_NAME 254
+	/// The extended attributes are inconsistent.
 	EA_LIST_INCONSISTENT 255
+	/// No more data is available.
 	NO_MORE_ITEMS 259
+	/// The copy functions cannot be used.
 	CANNOT_COPY 266
+	/// The directory name is invalid.
 	DIRECTORY 267
+	/// The extended attributes did not fit in the buffer.
 	EAS_DIDNT_FIT 275
+	/// The extended attribute file on the mounted file system is corrupt.
 	EA_FILE_CORRUPT 276
+	/// The extended attribute table file is full.
 	EA_TABLE_FULL 277
+	/// The specified extended attribute handle is invalid.
 	INVALID_EA_HANDLE 278
+	/// The mounted file system does not support extended attributes.
 	EAS_NOT_SUPPORTED 282
+	/// Attempt to release mutex not owned by caller.
 	NOT_OWNER 288
+	/// Too many posts were made to a semaphore.
 	TOO_MANY_POSTS 298
+	/// Only part of a ReadProcessMemory or WriteProcessMemory request was completed.
 	PARTIAL_COPY 299
+	/// The oplock request is denied.
 	OPLOCK_NOT_GRANTED 300
+	/// An invalid oplock acknowledgment was received by the system.
 	INVALID_OPLOCK_PROTOCOL 301
+	/// The volume is too fragmented to complete this operation.
 	DISK_TOO_FRAGMENTED 302
+	/// The file cannot be opened because it is in the process of being deleted.
 	DELETE_PENDING 303
+	/// Short name settings may not be changed on this volume due to the global registry setting.
 	INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING 304
+	/// Short names are not enabled on this volume.
 	SHORT_NAMES_NOT_ENABLED_ON_VOLUME 305
+	/// The security stream for the given volume is in an inconsistent state. Please run CHKDSK on the volume.
 	SECURITY_STREAM_IS_INCONSISTENT 306
+	/// A requested file lock operation cannot be processed due to an invalid byte range.
 	INVALID_LOCK_RANGE 307
+	/// The subsystem needed to support the image type is not present.
 	IMAGE_SUBSYSTEM_NOT_PRESENT 308
+	/// The specified file already has a notification GUID associated with it.
 	NOTIFICATION_GUID_ALREADY_DEFINED 309
+	/// An invalid exception handler routine has been detected.
 	INVALID_EXCEPTION_HANDLER 310
+	/// Duplicate privileges were specified for the token.
 	DUPLICATE_PRIVILEGES 311
+	/// No ranges for the specified operation were able to be processed.
 	NO_RANGES_PROCESSED 312
+	/// Operation is not allowed on a file system internal file.
 	NOT_ALLOWED_ON_SYSTEM_FILE 313
+	/// The physical resources of this disk have been exhausted.
 	DISK_RESOURCES_EXHAUSTED 314
+	/// The token representing the data is invalid.
 	INVALID_TOKEN 315
+	/// The device does not support the command feature.
 	DEVICE_FEATURE_NOT_SUPPORTED 316
+	/// The system cannot find message text for message number 0x%1 in the message file for %2.
 	MR_MID_NOT_FOUND 317
+	/// The specified scope was not found.
 	SCOPE_NOT_FOUND 318
+	/// The Central Access Policy specified is not defined on the target machine.
 	UNDEFINED_SCOPE 319
+	/// The Central Access Policy obtained from Active Directory is invalid.
 	INVALID_CAP 320
+	/// The device is unreachable.
 	DEVICE_UNREACHABLE 321
+	/// The target device has insufficient resources to complete the operation.
 	DEVICE_NO_RESOURCES 322
+	/// A data integrity checksum error occurred. Data in the file stream is corrupt.
 	DATA_CHECKSUM_ERROR 323
+	/// An attempt was made to modify both a KERNEL and normal Extended Attribute (EA) in the same operation.
 	INTERMIXED_KERNEL_EA_OPERATION 324
+	/// Device does not support file-level TRIM.
 	FILE_LEVEL_TRIM_NOT_SUPPORTED 326
+	/// The command specified a data offset that does not align to the device's granularity/alignment.
 	OFFSET_ALIGNMENT_VIOLATION 327
+	/// The command specified an invalid field in its parameter list.
 	INVALID_FIELD_IN_PARAMETER_LIST 328
+	/// An operation is currently in progress with the device.
 	OPERATION_IN_PROGRESS 329
+	/// An attempt was made to send down the command via an invalid path to the target device.
 	BAD_DEVICE_PATH 330
+	/// The command specified a number of descriptors that exceeded the maximum supported by the device.
 	TOO_MANY_DESCRIPTORS 331
+	/// Scrub is disabled on the specified file.
 	SCRUB_DATA_DISABLED 332
+	/// The storage device does not provide redundancy.
 	NOT_REDUNDANT_STORAGE 333
+	/// An operation is not supported on a resident file.
 	RESIDENT_FILE_NOT_SUPPORTED 334
+	/// An operation is not supported on a compressed file.
 	COMPRESSED_FILE_NOT_SUPPORTED 335
+	/// An operation is not supported on a directory.
 	DIRECTORY_NOT_SUPPORTED 336
+	/// The specified copy of the requested data could not be read.
 	NOT_READ_FROM_COPY 337
+	/// The specified data could not be written to any of the copies.
 	FT_WRITE_FAILURE 338
+	/// One or more copies of data on this device may be out of sync. No writes may be performed until a data integrity scan is completed.
 	FT_DI_SCAN_REQUIRED 339
+	/// The supplied kernel information version is invalid.
 	INVALID_KERNEL_INFO_VERSION 340
+	/// The supplied PEP information version is invalid.
 	INVALID_PEP_INFO_VERSION 341
+	/// This object is not externally backed by any provider.
 	OBJECT_NOT_EXTERNALLY_BACKED 342
+	/// The external backing provider is not recognized.
 	EXTERNAL_BACKING_PROVIDER_UNKNOWN 343
+	/// Compressing this object would not save space.
 	COMPRESSION_NOT_BENEFICIAL 344
+	/// The request failed due to a storage topology ID mismatch.
 	STORAGE_TOPOLOGY_ID_MISMATCH 345
+	/// The operation was blocked by parental controls.
 	BLOCKED_BY_PARENTAL_CONTROLS 346
+	/// A file system block being referenced has already reached the maximum reference count and can't be referenced any further.
 	BLOCK_TOO_MANY_REFERENCES 347
+	/// The requested operation failed because the file stream is marked to disallow writes.
 	MARKED_TO_DISALLOW_WRITES 348
+	/// The requested operation failed with an architecture-specific failure code.
 	ENCLAVE_FAILURE 349
+	/// No action was taken as a system reboot is required.
 	FAIL_NOACTION_REBOOT 350
+	/// The shutdown operation failed.
 	FAIL_SHUTDOWN 351
+	/// The restart operation failed.
 	FAIL_RESTART 352
+	/// The maximum number of sessions has been reached.
 	MAX_SESSIONS_REACHED 353
+	/// Windows Information Protection policy does not allow access to this network resource.
 	NETWORK_ACCESS_DENIED_EDP 354
+	/// The device hint name buffer is too small to receive the remaining name.
 	DEVICE_HINT_NAME_BUFFER_TOO_SMALL 355
+	/// The requested operation was blocked by Windows Information Protection policy. For more information, contact your system administrator.
 	EDP_POLICY_DENIES_OPERATION 356
+	/// The requested operation cannot be performed because hardware or software configuration of the device does not comply with Windows Information Protection under Lock policy. Please, verify that user PIN has been created.
 	EDP_DPL_POLICY_CANT_BE_SATISFIED 357
+	/// The cloud sync root metadata is corrupted.
 	CLOUD_FILE_SYNC_ROOT_METADATA_CORRUPT 358
+	/// The device is in maintenance mode.
 	DEVICE_IN_MAINTENANCE 359
+	/// This operation is not supported on a DAX volume.
 	NOT_SUPPORTED_ON_DAX 360
+	/// The volume has active DAX mappings.
 	DAX_MAPPING_EXISTS 361
+	/// The cloud file provider is not running.
 	CLOUD_FILE_PROVIDER_NOT_RUNNING 362
+	/// The cloud file metadata is corrupt and unreadable.
 	CLOUD_FILE_METADATA_CORRUPT 363
+	/// The cloud file metadata is too large.
 	CLOUD_FILE_METADATA_TOO_LARGE 364
+	/// The cloud file property is too large.
 	CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE 365
+	/// The cloud file property is possibly corrupt. The on-disk checksum does not match the computed checksum.
 	CLOUD_FILE_PROPERTY_BLOB_CHECKSUM_MISMATCH 366
+	/// The process creation has been blocked.
 	CHILD_PROCESS_BLOCKED 367
+	/// The storage device has lost data or persistence.
 	STORAGE_LOST_DATA_PERSISTENCE 368
+	/// The provider that supports file system virtualization is temporarily unavailable.
 	FILE_SYSTEM_VIRTUALIZATION_UNAVAILABLE 369
+	/// The metadata for file system virtualization is corrupt and unreadable.
 	FILE_SYSTEM_VIRTUALIZATION_METADATA_CORRUPT 370
+	/// The provider that supports file system virtualization is too busy to complete this operation.
 	FILE_SYSTEM_VIRTUALIZATION_BUSY 371
+	/// The provider that supports file system virtualization is unknown.
 	FILE_SYSTEM_VIRTUALIZATION_PROVIDER_UNKNOWN 372
+	/// GDI handles were potentially leaked by the application.
 	GDI_HANDLE_LEAK 373
+	/// The maximum number of cloud file properties has been reached.
 	CLOUD_FILE_TOO_MANY_PROPERTY_BLOBS 374
+	/// The version of the cloud file property store is not supported.
 	CLOUD_FILE_PROPERTY_VERSION_NOT_SUPPORTED 375
+	/// The file is not a cloud file.
 	NOT_A_CLOUD_FILE 376
+	/// The file is not in sync with the cloud.
 	CLOUD_FILE_NOT_IN_SYNC 377
+	/// The cloud sync root is already connected with another cloud sync provider.
 	CLOUD_FILE_ALREADY_CONNECTED 378
+	/// The operation is not supported by the cloud sync provider.
 	CLOUD_FILE_NOT_SUPPORTED 379
+	/// The cloud operation is invalid.
 	CLOUD_FILE_INVALID_REQUEST 380
+	/// The cloud operation is not supported on a read-only volume.
 	CLOUD_FILE_READ_ONLY_VOLUME 381
+	/// The operation is reserved for a connected cloud sync provider.
 	CLOUD_FILE_CONNECTED_PROVIDER_ONLY 382
+	/// The cloud sync provider failed to validate the downloaded data.
 	CLOUD_FILE_VALIDATION_FAILED 383
+	/// You can't connect to the file share because it's not secure. This share requires the obsolete SMB1 protocol, which is unsafe and could expose your system to attack.
 	SMB1_NOT_AVAILABLE 384
+	/// The virtualization operation is not allowed on the file in its current state.
 	FILE_SYSTEM_VIRTUALIZATION_INVALID_OPERATION 385
+	/// The cloud sync provider failed user authentication.
 	CLOUD_FILE_AUTHENTICATION_FAILED 386
+	/// The cloud sync provider failed to perform the operation due to low system resources.
 	CLOUD_FILE_INSUFFICIENT_RESOURCES 387
+	/// The cloud sync provider failed to perform the operation due to network being unavailable.
 	CLOUD_FILE_NETWORK_UNAVAILABLE 388
+	/// The cloud operation was unsuccessful.
 	CLOUD_FILE_UNSUCCESSFUL 389
+	/// The operation is only supported on files under a cloud sync root.
 	CLOUD_FILE_NOT_UNDER_SYNC_ROOT 390
+	/// The operation cannot be performed on cloud files in use.
 	CLOUD_FILE_IN_USE 391
+	/// The operation cannot be performed on pinned cloud files.
 	CLOUD_FILE_PINNED 392
+	/// The cloud operation was aborted.
 	CLOUD_FILE_REQUEST_ABORTED 393
+	/// The cloud file's property store is corrupt.
 	CLOUD_FILE_PROPERTY_CORRUPT 394
+	/// Access to the cloud file is denied.
 	CLOUD_FILE_ACCESS_DENIED 395
+	/// The cloud operation cannot be performed on a file with incompatible hardlinks.
 	CLOUD_FILE_INCOMPATIBLE_HARDLINKS 396
+	/// The operation failed due to a conflicting cloud file property lock.
 	CLOUD_FILE_PROPERTY_LOCK_CONFLICT 397
+	/// The cloud operation was canceled by user.
 	CLOUD_FILE_REQUEST_CANCELED 398
+	/// An externally encrypted syskey has been configured, but the system no longer supports this feature.
 	EXTERNAL_SYSKEY_NOT_SUPPORTED 399
+	/// The thread is already in background processing mode.
 	THREAD_MODE_ALREADY_BACKGROUND 400
+	/// The thread is not in background processing mode.
 	THREAD_MODE_NOT_BACKGROUND 401
+	/// The process is already in background processing mode.
 	PROCESS_MODE_ALREADY_BACKGROUND 402
+	/// The process is not in background processing mode.
 	PROCESS_MODE_NOT_BACKGROUND 403
+	/// The cloud file provider exited unexpectedly.
 	CLOUD_FILE_PROVIDER_TERMINATED 404
+	/// The file is not a cloud sync root.
 	NOT_A_CLOUD_SYNC_ROOT 405
+	/// The read or write operation to an encrypted file could not be completed because the file can only be accessed when the device is unlocked.
 	FILE_PROTECTED_UNDER_DPL 406
+	/// The volume is not cluster aligned on the disk.
 	VOLUME_NOT_CLUSTER_ALIGNED 407
+	/// No physically aligned free space was found on the volume.
 	NO_PHYSICALLY_ALIGNED_FREE_SPACE_FOUND 408
+	/// The APPX file can not be accessed because it is not encrypted as expected.
 	APPX_FILE_NOT_ENCRYPTED 409
+	/// A read or write of raw encrypted data cannot be performed because the file is not encrypted.
 	RWRAW_ENCRYPTED_FILE_NOT_ENCRYPTED 410
+	/// An invalid file offset in the encrypted data info block was passed for read or write operation of file's raw encrypted data.
 	RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILEOFFSET 411
+	/// An invalid offset and length combination in the encrypted data info block was passed for read or write operation of file's raw encrypted data.
 	RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILERANGE 412
+	/// An invalid parameter in the encrypted data info block was passed for read or write operation of file's raw encrypted data.
 	RWRAW_ENCRYPTED_INVALID_EDATAINFO_PARAMETER 413
+	/// The Windows Subsystem for Linux has not been enabled.
 	LINUX_SUBSYSTEM_NOT_PRESENT 414
+	/// The specified data could not be read from any of the copies.
 	FT_READ_FAILURE 415
+	/// The specified storage reserve ID is invalid.
 	STORAGE_RESERVE_ID_INVALID 416
+	/// The specified storage reserve does not exist.
 	STORAGE_RESERVE_DOES_NOT_EXIST 417
+	/// The specified storage reserve already exists.
 	STORAGE_RESERVE_ALREADY_EXISTS 418
+	/// The specified storage reserve is not empty.
 	STORAGE_RESERVE_NOT_EMPTY 419
+	/// This operation requires a DAX volume.
 	NOT_A_DAX_VOLUME 420
+	/// This stream is not DAX mappable.
 	NOT_DAX_MAPPABLE 421
+	/// Time-sensitive thread creation failed.
 	TIME_SENSITIVE_THREAD 422
+	/// Windows Information Protection under Lock is not supported for this user.
 	DPL_NOT_SUPPORTED_FOR_USER 423
+	/// This directory contains entries whose names differ only in case.
 	CASE_DIFFERING_NAMES_IN_DIR 424
+	/// The file cannot be safely opened because it is not supported by this version of Windows.
 	FILE_NOT_SUPPORTED 425
+	/// The cloud operation was not completed before the time-out period expired.
 	CLOUD_FILE_REQUEST_TIMEOUT 426
+	/// A task queue is required for this operation but none is available.
 	NO_TASK_QUEUE 427
+	/// Failed loading a valid version of srcsrv.dll.
 	SRC_SRV_DLL_LOAD_FAILED 428
+	/// This operation is not supported with BTT enabled.
 	NOT_SUPPORTED_WITH_BTT 429
+	/// This operation cannot be performed because encryption is currently disabled.
 	ENCRYPTION_DISABLED 430
+	/// This encryption operation cannot be performed on filesystem metadata.
 	ENCRYPTING_METADATA_DISALLOWED 431
+	/// Encryption cannot be cleared on this file/directory because it still has an encrypted attribute.
 	CANT_CLEAR_ENCRYPTION_FLAG 432
+	/// A device which does not exist was specified.
 	NO_SUCH_DEVICE 433
+	/// The operation cannot be performed because the device is not unlocked for development.
 	CAPAUTHZ_NOT_DEVUNLOCKED 450
+	/// The capability authorization type has changed.
 	CAPAUTHZ_CHANGE_TYPE 451
+	/// The application has not been provisioned for the requested capability.
 	CAPAUTHZ_NOT_PROVISIONED 452
+	/// The application is not authorized to use the requested capability.
 	CAPAUTHZ_NOT_AUTHORIZED 453
+	/// No capability authorization policy was found.
 	CAPAUTHZ_NO_POLICY 454
+	/// The capability authorization database is corrupted.
 	CAPAUTHZ_DB_CORRUPTED 455
+	/// The custom capability's SCCD has an invalid catalog.
 	CAPAUTHZ_SCCD_INVALID_CATALOG 456
+	/// None of the authorized entity elements in the SCCD matched the app being installed; either the PFNs don't match, or the element's signature hash doesn't validate.
 	CAPAUTHZ_SCCD_NO_AUTH_ENTITY 457
+	/// The custom capability's SCCD failed to parse.
 	CAPAUTHZ_SCCD_PARSE_ERROR 458
+	/// The custom capability's SCCD requires developer mode.
 	CAPAUTHZ_SCCD_DEV_MODE_REQUIRED 459
+	/// There not all declared custom capabilities are found in the SCCD.
 	CAPAUTHZ_SCCD_NO_CAPABILITY_MATCH 460
+	/// The operation timed out waiting for this device to complete a PnP query-remove request due to a potential hang in its device stack. The system may need to be rebooted to complete the request.
 	PNP_QUERY_REMOVE_DEVICE_TIMEOUT 480
+	/// The operation timed out waiting for this device to complete a PnP query-remove request due to a potential hang in the device stack of a related device. The system may need to be rebooted to complete the operation.
 	PNP_QUERY_REMOVE_RELATED_DEVICE_TIMEOUT 481
+	/// The operation timed out waiting for this device to complete a PnP query-remove request due to a potential hang in the device stack of an unrelated device. The system may need to be rebooted to complete the operation.
 	PNP_QUERY_REMOVE_UNRELATED_DEVICE_TIMEOUT 482
+	/// The request failed due to a fatal device hardware error.
 	DEVICE_HARDWARE_ERROR 483
+	/// Attempt to access invalid address.
 	INVALID_ADDRESS 487
+	/// Application verifier has found an error in the current process.
 	VRF_CFG_ENABLED 1183
+	/// The partition is being terminated.
 	PARTITION_TERMINATING 1184
+	/// The user profile could not be loaded.
 	USER_PROFILE_LOAD 500
+	/// Arithmetic result exceeded 32 bits.
 	ARITHMETIC_OVERFLOW 534
+	/// There is a process on other end of the pipe.
 	PIPE_CONNECTED 535
+	/// Waiting for a process to open the other end of the pipe.
 	PIPE_LISTENING 536
+	/// Application verifier has found an error in the current process.
 	VERIFIER_STOP 537
+	/// An error occurred in the ABIOS subsystem.
 	ABIOS_ERROR 538
+	/// A warning occurred in the WX86 subsystem.
 	WX86_WARNING 539
+	/// An error occurred in the WX86 subsystem.
 	WX86_ERROR 540
+	/// An attempt was made to cancel or set a timer that has an associated APC and the subject thread is not the thread that originally set the timer with an associated APC routine.
 	TIMER_NOT_CANCELED 541
+	/// Unwind exception code.
 	UNWIND 542
+	/// An invalid or unaligned stack was encountered during an unwind operation.
 	BAD_STACK 543
+	/// An invalid unwind target was encountered during an unwind operation.
 	INVALID_UNWIND_TARGET 544
+	/// Invalid Object Attributes specified to NtCreatePort or invalid Port Attributes specified to NtConnectPort.
 	INVALID_PORT_ATTRIBUTES 545
+	/// Length of message passed to NtRequestPort or NtRequestWaitReplyPort was longer than the maximum message allowed by the port.
 	PORT_MESSAGE_TOO_LONG 546
+	/// An attempt was made to lower a quota limit below the current usage.
 	INVALID_QUOTA_LOWER 547
+	/// An attempt was made to attach to a device that was already attached to another device.
 	DEVICE_ALREADY_ATTACHED 548
+	/// An attempt was made to execute an instruction at an unaligned address and the host system does not support unaligned instruction references.
 	INSTRUCTION_MISALIGNMENT 549
+	/// Profiling not started.
 	PROFILING_NOT_STARTED 550
+	/// Profiling not stopped.
 	PROFILING_NOT_STOPPED 551
+	/// The passed ACL did not contain the minimum required information.
 	COULD_NOT_INTERPRET 552
+	/// The number of active profiling objects is at the maximum and no more may be started.
 	PROFILING_AT_LIMIT 553
+	/// Used to indicate that an operation cannot continue without blocking for I/O.
 	CANT_WAIT 554
+	/// Indicates that a thread attempted to terminate itself by default (called NtTerminateThread with NULL) and it was the last thread in the current process.
 	CANT_TERMINATE_SELF 555
+	/// If an MM error is returned which is not defined in the standard FsRtl filter, it is converted to one of the following errors which is guaranteed to be in the filter. In this case information is lost, however, the filter correctly handles the exception.
 	UNEXPECTED_MM_CREATE_ERR 556
+	/// If an MM error is returned which is not defined in the standard FsRtl filter, it is converted to one of the following errors which is guaranteed to be in the filter. In this case information is lost, however, the filter correctly handles the exception.
 	UNEXPECTED_MM_MAP_ERROR 557
+	/// If an MM error is returned which is not defined in the standard FsRtl filter, it is converted to one of the following errors which is guaranteed to be in the filter. In this case information is lost, however, the filter correctly handles the exception.
 	UNEXPECTED_MM_EXTEND_ERR 558
+	/// A malformed function table was encountered during an unwind operation.
 	BAD_FUNCTION_TABLE 559
+	/// Indicates that an attempt was made to assign protection to a file system file or directory and one of the SIDs in the security descriptor could not be translated into a GUID that could be stored by the file system. This causes the protection attempt to fail, which may cause a file creation attempt to fail.
 	NO_GUID_TRANSLATION 560
+	/// Indicates that an attempt was made to grow an LDT by setting its size, or that the size was not an even number of selectors.
 	INVALID_LDT_SIZE 561
+	/// Indicates that the starting value for the LDT information was not an integral multiple of the selector size.
 	INVALID_LDT_OFFSET 563
+	/// Indicates that the user supplied an invalid descriptor when trying to set up Ldt descriptors.
 	INVALID_LDT_DESCRIPTOR 564
+	/// Indicates a process has too many threads to perform the requested action. For example, assignment of a primary token may only be performed when a process has zero or one threads.
 	TOO_MANY_THREADS 565
+	/// An attempt was made to operate on a thread within a specific process, but the thread specified is not in the process specified.
 	THREAD_NOT_IN_PROCESS 566
+	/// Page file quota was exceeded.
 	PAGEFILE_QUOTA_EXCEEDED 567
+	/// The Netlogon service cannot start because another Netlogon service running in the domain conflicts with the specified role.
 	LOGON_SERVER_CONFLICT 568
+	/// The SAM database on a Windows Server is significantly out of synchronization with the copy on the Domain Controller. A complete synchronization is required.
 	SYNCHRONIZATION_REQUIRED 569
+	/// The NtCreateFile API failed. This error should never be returned to an application, it is a place holder for the Windows Lan Manager Redirector to use in its internal error mapping routines.
 	NET_OPEN_FAILED 570
+	/// The I/O permissions for the process could not be changed.
 	IO_PRIVILEGE_FAILED 571
+	/// The application terminated as a result of a CTRL+C.
 	CONTROL_C_EXIT 572
+	/// The required system file %hs is bad or missing.
 	MISSING_SYSTEMFILE 573
+	/// The exception %s (0x%08lx) occurred in the application at location 0x%p.
 	UNHANDLED_EXCEPTION 574
+	/// The application was unable to start correctly (0x%lx). Click OK to close the application.
 	APP_INIT_FAILURE 575
+	/// The creation of the paging file %hs failed (%lx). The requested size was %ld.
 	PAGEFILE_CREATE_FAILED 576
+	/// Windows cannot verify the digital signature for this file. A recent hardware or software change might have installed a file that is signed incorrectly or damaged, or that might be malicious software from an unknown source.
 	INVALID_IMAGE_HASH 577
+	/// No paging file was specified in the system configuration.
 	NO_PAGEFILE 578
+	/// A real-mode application issued a floating-point instruction and floating-point hardware is not present.
 	ILLEGAL_FLOAT_CONTEXT 579
+	/// An event pair synchronization operation was performed using the thread specific client/server event pair object, but no event pair object was associated with the thread.
 	NO_EVENT_PAIR 580
+	/// A Windows Server has an incorrect configuration.
 	DOMAIN_CTRLR_CONFIG_ERROR 581
+	/// An illegal character was encountered. For a multi-byte character set this includes a lead byte without a succeeding trail byte. For the Unicode character set this includes the characters 0xFFFF and 0xFFFE.
 	ILLEGAL_CHARACTER 582
+	/// The Unicode character is not defined in the Unicode character set installed on the system.
 	UNDEFINED_CHARACTER 583
+	/// The paging file cannot be created on a floppy diskette.
 	FLOPPY_VOLUME 584
+	/// The system BIOS failed to connect a system interrupt to the device or bus for which the device is connected.
 	BIOS_FAILED_TO_CONNECT_INTERRUPT 585
+	/// This operation is only allowed for the Primary Domain Controller of the domain.
 	BACKUP_CONTROLLER 586
+	/// An attempt was made to acquire a mutant such that its maximum count would have been exceeded.
 	MUTANT_LIMIT_EXCEEDED 587
+	/// A volume has been accessed for which a file system driver is required that has not yet been loaded.
 	FS_DRIVER_REQUIRED 588
+	/// The registry cannot load the hive (file): %hs or its log or alternate. It is corrupt, absent, or not writable.
 	CANNOT_LOAD_REGISTRY_FILE 589
+	/// An unexpected failure occurred while processing a DebugActiveProcess API request. You may choose OK to terminate the process, or Cancel to ignore the error.
 	DEBUG_ATTACH_FAILED 590
+	/// The %hs system process terminated unexpectedly with a status of 0x%08x (0x%08x 0x%08x). The system has been shut down.
 	SYSTEM_PROCESS_TERMINATED 591
+	/// The TDI client could not handle the data received during an indication.
 	DATA_NOT_ACCEPTED 592
+	/// NTVDM encountered a hard error.
 	VDM_HARD_ERROR 593
+	/// The driver %hs failed to complete a cancelled I/O request in the allotted time.
 	DRIVER_CANCEL_TIMEOUT 594
+	/// An attempt was made to reply to an LPC message, but the thread specified by the client ID in the message was not waiting on that message.
 	REPLY_MESSAGE_MISMATCH 595
+	/// Windows was unable to save all the data for the file %hs. The data has been lost. This error may be caused by a failure of your computer hardware or network connection. Please try to save this file elsewhere.
 	LOST_WRITEBEHIND_DATA 596
+	/// The parameter(s) passed to the server in the client/server shared memory window were invalid. Too much data may have been put in the shared memory window.
 	CLIENT_SERVER_PARAMETERS_INVALID 597
+	/// The stream is not a tiny stream.
 	NOT_TINY_STREAM 598
+	/// The request must be handled by the stack overflow code.
 	STACK_OVERFLOW_READ 599
+	/// Internal OFS status codes indicating how an allocation operation is handled. Either it is retried after the containing onode is moved or the extent stream is converted to a large stream.
 	CONVERT_TO_LARGE 600
+	/// The attempt to find the object found an object matching by ID on the volume but it is out of the scope of the handle used for the operation.
 	FOUND_OUT_OF_SCOPE 601
+	/// The bucket array must be grown. Retry transaction after doing so.
 	ALLOCATE_BUCKET 602
+	/// The user/kernel marshalling buffer has overflowed.
 	MARSHALL_OVERFLOW 603
+	/// The supplied variant structure contains invalid data.
 	INVALID_VARIANT 604
+	/// The specified buffer contains ill-formed data.
 	BAD_COMPRESSION_BUFFER 605
+	/// An attempt to generate a security audit failed.
 	AUDIT_FAILED 606
+	/// The timer resolution was not previously set by the current process.
 	TIMER_RESOLUTION_NOT_SET 607
+	/// There is insufficient account information to log you on.
 	INSUFFICIENT_LOGON_INFO 608
+	/// The dynamic link library %hs is not written correctly. The stack pointer has been left in an inconsistent state. The entrypoint should be declared as WINAPI or STDCALL. Select YES to fail the DLL load. Select NO to continue execution. Selecting NO may cause the application to operate incorrectly.
 	BAD_DLL_ENTRYPOINT 609
+	/// The %hs service is not written correctly. The stack pointer has been left in an inconsistent state. The callback entrypoint should be declared as WINAPI or STDCALL. Selecting OK will cause the service to continue operation. However, the service process may operate incorrectly.
 	BAD_SERVICE_ENTRYPOINT 610
+	/// There is an IP address conflict with another system on the network.
 	IP_ADDRESS_CONFLICT1 611
+	/// There is an IP address conflict with another system on the network.
 	IP_ADDRESS_CONFLICT2 612
+	/// The system has reached the maximum size allowed for the system part of the registry. Additional storage requests will be ignored.
 	REGISTRY_QUOTA_LIMIT 613
+	/// A callback return system service cannot be executed when no callback is active.
 	NO_CALLBACK_ACTIVE 614
+	/// The password provided is too short to meet the policy of your user account. Please provide a longer password.
 	PWD_TOO_SHORT 615
+	/// The policy of your user account does not allow you to change passwords too frequently. This is done to prevent users from changing back to a familiar, but potentially discovered, password. If you feel your password has been compromised then please contact your administrator immediately to have a new one assigned.
 	PWD_TOO_RECENT 616
+	/// You have attempted to change your password to one that you have used in the past. The policy of your user account does not allow this. Please select a password that you have not previously used.
 	PWD_HISTORY_CONFLICT 617
+	/// The specified compression format is unsupported.
 	UNSUPPORTED_COMPRESSION 618
+	/// The specified hardware profile configuration is invalid.
 	INVALID_HW_PROFILE 619
+	/// The specified Plug and Play registry device path is invalid.
 	INVALID_PLUGPLAY_DEVICE_PATH 620
+	/// The specified quota list is internally inconsistent with its descriptor.
 	QUOTA_LIST_INCONSISTENT 621
+	/// The evaluation period for this installation of Windows has expired. This system will shutdown in 1 hour. To restore access to this installation of Windows, please upgrade this installation using a licensed distribution of this product.
 	EVALUATION_EXPIRATION 622
+	/// The system DLL %hs was relocated in memory. The application will not run properly. The relocation occurred because the DLL %hs occupied an address range reserved for Windows system DLLs. The vendor supplying the DLL should be contacted for a new DLL.
 	ILLEGAL_DLL_RELOCATION 623
+	/// The application failed to initialize because the window station is shutting down.
 	DLL_INIT_FAILED_LOGOFF 624
+	/// The validation process needs to continue on to the next step.
 	VALIDATE_CONTINUE 625
+	/// There are no more matches for the current index enumeration.
 	NO_MORE_MATCHES 626
+	/// The range could not be added to the range list because of a conflict.
 	RANGE_LIST_CONFLICT 627
+	/// The server process is running under a SID different than that required by client.
 	SERVER_SID_MISMATCH 628
+	/// A group marked use for deny only cannot be enabled.
 	CANT_ENABLE_DENY_ONLY 629
+	/// Multiple floating point faults.
 	FLOAT_MULTIPLE_FAULTS 630
+	/// Multiple floating point traps.
 	FLOAT_MULTIPLE_TRAPS 631
+	/// The requested interface is not supported.
 	NOINTERFACE 632
+	/// The driver %hs does not support standby mode. Updating this driver may allow the system to go to standby mode.
 	DRIVER_FAILED_SLEEP 633
+	/// The system file %1 has become corrupt and has been replaced.
 	CORRUPT_SYSTEM_FILE 634
+	/// Your system is low on virtual memory. Windows is increasing the size of your virtual memory paging file. During this process, memory requests for some applications may be denied.
 	COMMITMENT_MINIMUM 635
+	/// A device was removed so enumeration must be restarted.
 	PNP_RESTART_ENUMERATION 636
+	/// The system image %s is not properly signed. The file has been replaced with the signed file. The system has been shut down.
 	SYSTEM_IMAGE_BAD_SIGNATURE 637
+	/// Device will not start without a reboot.
 	PNP_REBOOT_REQUIRED 638
+	/// There is not enough power to complete the requested operation.
 	INSUFFICIENT_POWER 639
+	/// Multiple fault violation.
 	MULTIPLE_FAULT_VIOLATION 640
+	/// The system is in the process of shutting down.
 	SYSTEM_SHUTDOWN 641
+	/// An attempt to remove a processes DebugPort was made, but a port was not already associated with the process.
 	PORT_NOT_SET 642
+	/// This version of Windows is not compatible with the behavior version of directory forest, domain or domain controller.
 	DS_VERSION_CHECK_FAILURE 643
+	/// The specified range could not be found in the range list.
 	RANGE_NOT_FOUND 644
+	/// The driver was not loaded because the system is booting into safe mode.
 	NOT_SAFE_MODE_DRIVER 646
+	/// The driver was not loaded because it failed its initialization call.
 	FAILED_DRIVER_ENTRY 647
+	/// The "%hs" encountered an error while applying power or reading the device configuration. This may be caused by a failure of your hardware or by a poor connection.
 	DEVICE_ENUMERATION_ERROR 648
+	/// The create operation failed because the name contained at least one mount point which resolves to a volume to which the specified device object is not attached.
 	MOUNT_POINT_NOT_RESOLVED 649
+	/// The device object parameter is either not a valid device object or is not attached to the volume specified by the file name.
 	INVALID_DEVICE_OBJECT_PARAMETER 650
+	/// A Machine Check Error has occurred. Please check the system eventlog for additional information.
 	MCA_OCCURED 651
+	/// There was error (%2) processing the driver database.
 	DRIVER_DATABASE_ERROR 652
+	/// System hive size has exceeded its limit.
 	SYSTEM_HIVE_TOO_LARGE 653
+	/// The driver could not be loaded because a previous version of the driver is still in memory.
 	DRIVER_FAILED_PRIOR_UNLOAD 654
+	/// Please wait while the Volume Shadow Copy Service prepares volume %hs for hibernation.
 	VOLSNAP_PREPARE_HIBERNATE 655
+	/// The system has failed to hibernate (The error code is %hs). Hibernation will be disabled until the system is restarted.
 	HIBERNATION_FAILURE 656
+	/// The password provided is too long to meet the policy of your user account. Please provide a shorter password.
 	PWD_TOO_LONG 657
+	/// The requested operation could not be completed due to a file system limitation.
 	FILE_SYSTEM_LIMITATION 665
+	/// An assertion failure has occurred.
 	ASSERTION_FAILURE 668
+	/// An error occurred in the ACPI subsystem.
 	ACPI_ERROR 669
+	/// WOW Assertion Error.
 	WOW_ASSERTION 670
+	/// A device is missing in the system BIOS MPS table. This device will not be used. Please contact your system vendor for system BIOS update.
 	PNP_BAD_MPS_TABLE 671
+	/// A translator failed to translate resources.
 	PNP_TRANSLATION_FAILED 672
+	/// A IRQ translator failed to translate resources.
 	PNP_IRQ_TRANSLATION_FAILED 673
+	/// Driver %2 returned invalid ID for a child device (%3).
 	PNP_INVALID_ID 674
+	/// The system debugger was awakened by an interrupt.
 	WAKE_SYSTEM_DEBUGGER 675
+	/// Handles to objects have been automatically closed as a result of the requested operation.
 	HANDLES_CLOSED 676
+	/// The specified access control list (ACL) contained more information than was expected.
 	EXTRANEOUS_INFORMATION 677
+	/// This warning level status indicates that the transaction state already exists for the registry sub-tree, but that a transaction commit was previously aborted. The commit has NOT been completed, but has not been rolled back either (so it may still be committed if desired).
 	RXACT_COMMIT_NECESSARY 678
+	/// The media may have changed.
 	MEDIA_CHECK 679
+	/// During the translation of a global identifier (GUID) to a Windows security ID (SID), no administratively-defined GUID prefix was found. A substitute prefix was used, which will not compromise system security. However, this may provide a more restrictive access than intended.
 	GUID_SUBSTITUTION_MADE 680
+	/// The create operation stopped after reaching a symbolic link.
 	STOPPED_ON_SYMLINK 681
+	/// A long jump has been executed.
 	LONGJUMP 682
+	/// The Plug and Play query operation was not successful.
 	PLUGPLAY_QUERY_VETOED 683
+	/// A frame consolidation has been executed.
 	UNWIND_CONSOLIDATE 684
+	/// Registry hive (file): %hs was corrupted and it has been recovered. Some data might have been lost.
 	REGISTRY_HIVE_RECOVERED 685
+	/// The application is attempting to run executable code from the module %hs. This may be insecure. An alternative, %hs, is available. Should the application use the secure module %hs?
 	DLL_MIGHT_BE_INSECURE 686
+	/// The application is loading executable code from the module %hs. This is secure, but may be incompatible with previous releases of the operating system. An alternative, %hs, is available. Should the application use the secure module %hs?
 	DLL_MIGHT_BE_INCOMPATIBLE 687
+	/// Debugger did not handle the exception.
 	DBG_EXCEPTION_NOT_HANDLED 688
+	/// Debugger will reply later.
 	DBG_REPLY_LATER 689
+	/// Debugger cannot provide handle.
 	DBG_UNABLE_TO_PROVIDE_HANDLE 690
+	/// Debugger terminated thread.
 	DBG_TERMINATE_THREAD 691
+	/// Debugger terminated process.
 	DBG_TERMINATE_PROCESS 692
+	/// Debugger got control C.
 	DBG_CONTROL_C 693
+	/// Debugger printed exception on control C.
 	DBG_PRINTEXCEPTION_C 694
+	/// Debugger received RIP exception.
 	DBG_RIPEXCEPTION 695
+	/// Debugger received control break.
 	DBG_CONTROL_BREAK 696
+	/// Debugger command communication exception.
 	DBG_COMMAND_EXCEPTION 697
+	/// An attempt was made to create an object and the object name already existed.
 	OBJECT_NAME_EXISTS 698
+	/// A thread termination occurred while the thread was suspended. The thread was resumed, and termination proceeded.
 	THREAD_WAS_SUSPENDED 699
+	/// An image file could not be mapped at the address specified in the image file. Local fixups must be performed on this image.
 	IMAGE_NOT_AT_BASE 700
+	/// This informational level status indicates that a specified registry sub-tree transaction state did not yet exist and had to be created.
 	RXACT_STATE_CREATED 701
+	/// A virtual DOS machine (VDM) is loading, unloading, or moving an MS-DOS or Win16 program segment image. An exception is raised so a debugger can load, unload or track symbols and breakpoints within these 16-bit segments.
 	SEGMENT_NOTIFICATION 702
+	/// The process cannot switch to the startup current directory %hs. Select OK to set current directory to %hs, or select CANCEL to exit.
 	BAD_CURRENT_DIRECTORY 703
+	/// To satisfy a read request, the NT fault-tolerant file system successfully read the requested data from a redundant copy. This was done because the file system encountered a failure on a member of the fault-tolerant volume, but was unable to reassign the failing area of the device.
 	FT_READ_RECOVERY_FROM_BACKUP 704
+	/// To satisfy a write request, the NT fault-tolerant file system successfully wrote a redundant copy of the information. This was done because the file system encountered a failure on a member of the fault-tolerant volume, but was not able to reassign the failing area of the device.
 	FT_WRITE_RECOVERY 705
+	/// The image file %hs is valid, but is for a machine type other than the current machine. Select OK to continue, or CANCEL to fail the DLL load.
 	IMAGE_MACHINE_TYPE_MISMATCH 706
+	/// The network transport returned partial data to its client. The remaining data will be sent later.
 	RECEIVE_PARTIAL 707
+	/// The network transport returned data to its client that was marked as expedited by the remote system.
 	RECEIVE_EXPEDITED 708
+	/// The network transport returned partial data to its client and this data was marked as expedited by the remote system. The remaining data will be sent later.
 	RECEIVE_PARTIAL_EXPEDITED 709
+	/// The TDI indication has completed successfully.
 	EVENT_DONE 710
+	/// The TDI indication has entered the pending state.
 	EVENT_PENDING 711
+	/// Checking file system on %wZ.
 	CHECKING_FILE_SYSTEM 712
+	/// %hs
 	FATAL_APP_EXIT 713
+	/// The specified registry key is referenced by a predefined handle.
 	PREDEFINED_HANDLE 714
+	/// The page protection of a locked page was changed to 'No Access' and the page was unlocked from memory and from the process.
 	WAS_UNLOCKED 715
+	/// %hs
 	SERVICE_NOTIFICATION 716
+	/// One of the pages to lock was already locked.
 	WAS_LOCKED 717
+	/// Application popup: %1 : %2
 	LOG_HARD_ERROR 718
+	/// The ERROR_ALREADY_WIN32 error.
 	ALREADY_WIN32 719
+	/// The image file %hs is valid, but is for a machine type other than the current machine.
 	IMAGE_MACHINE_TYPE_MISMATCH_EXE 720
+	/// A yield execution was performed and no thread was available to run.
 	NO_YIELD_PERFORMED 721
+	/// The resumable flag to a timer API was ignored.
 	TIMER_RESUME_IGNORED 722
+	/// The arbiter has deferred arbitration of these resources to its parent.
 	ARBITRATION_UNHANDLED 723
+	/// The inserted CardBus device cannot be started because of a configuration error on "%hs".
 	CARDBUS_NOT_SUPPORTED 724
+	/// The CPUs in this multiprocessor system are not all the same revision level. To use all processors the operating system restricts itself to the features of the least capable processor in the system. Should problems occur with this system, contact the CPU manufacturer to see if this mix of processors is supported.
 	MP_PROCESSOR_MISMATCH 725
+	/// The system was put into hibernation.
 	HIBERNATED 726
+	/// The system was resumed from hibernation.
 	RESUME_HIBERNATION 727
+	/// Windows has detected that the system firmware (BIOS) was updated (previous firmware date = %2, current firmware date %3).
 	FIRMWARE_UPDATED 728
+	/// A device driver is leaking locked I/O pages causing system degradation. The system has automatically enabled tracking code in order to try and catch the culprit.
 	DRIVERS_LEAKING_LOCKED_PAGES 729
+	/// The system has awoken.
 	WAKE_SYSTEM 730
+	/// The ERROR_WAIT_1 error.
 	WAIT_1 731
+	/// The ERROR_WAIT_2 error.
 	WAIT_2 732
+	/// The ERROR_WAIT_3 error.
 	WAIT_3 733
+	/// The ERROR_WAIT_63 error.
 	WAIT_63 734
+	/// The ERROR_ABANDONED_WAIT_0 error.
 	ABANDONED_WAIT_0 735
+	/// The ERROR_ABANDONED_WAIT_63 error.
 	ABANDONED_WAIT_63 736
+	/// The ERROR_USER_APC error.
 	USER_APC 737
+	/// The ERROR_KERNEL_APC error.
 	KERNEL_APC 738
+	/// The ERROR_ALERTED error.
 	ALERTED 739
+	/// The requested operation requires elevation.
 	ELEVATION_REQUIRED 740
+	/// A reparse should be performed by the Object Manager since the name of the file resulted in a symbolic link.
 	REPARSE 741
+	/// An open/create operation completed while an oplock break is underway.
 	OPLOCK_BREAK_IN_PROGRESS 742
+	/// A new volume has been mounted by a file system.
 	VOLUME_MOUNTED 743
+	/// This success level status indicates that the transaction state already exists for the registry sub-tree, but that a transaction commit was previously aborted. The commit has now been completed.
 	RXACT_COMMITTED 744
+	/// This indicates that a notify change request has been completed due to closing the handle which made the notify change request.
 	NOTIFY_CLEANUP 745
+	/// An attempt was made to connect to the remote server %hs on the primary transport, but the connection failed. The computer WAS able to connect on a secondary transport.
 	PRIMARY_TRANSPORT_CONNECT_FAILED 746
+	/// Page fault was a transition fault.
 	PAGE_FAULT_TRANSITION 747
+	/// Page fault was a demand zero fault.
 	PAGE_FAULT_DEMAND_ZERO 748
+	/// Page fault was a demand zero fault.
 	PAGE_FAULT_COPY_ON_WRITE 749
+	/// Page fault was a demand zero fault.
 	PAGE_FAULT_GUARD_PAGE 750
+	/// Page fault was satisfied by reading from a secondary storage device.
 	PAGE_FAULT_PAGING_FILE 751
+	/// Cached page was locked during operation.
 	CACHE_PAGE_LOCKED 752
+	/// Crash dump exists in paging file.
 	CRASH_DUMP 753
+	/// Specified buffer contains all zeros.
 	BUFFER_ALL_ZEROS 754
+	/// A reparse should be performed by the Object Manager since the name of the file resulted in a symbolic link.
 	REPARSE_OBJECT 755
+	/// The device has succeeded a query-stop and its resource requirements have changed.
 	RESOURCE_REQUIREMENTS_CHANGED 756
+	/// The translator has translated these resources into the global space and no further translations should be performed.
 	TRANSLATION_COMPLETE 757
+	/// A process being terminated has no threads to terminate.
 	NOTHING_TO_TERMINATE 758
+	/// The specified process is not part of a job.
 	PROCESS_NOT_IN_JOB 759
+	/// The specified process is part of a job.
 	PROCESS_IN_JOB 760
+	/// The system is now ready for hibernation.
 	VOLSNAP_HIBERNATE_READY 761
+	/// A file system or file system filter driver has successfully completed an FsFilter operation.
 	FSFILTER_OP_COMPLETED_SUCCESSFULLY 762
+	/// The specified interrupt vector was already connected.
 	INTERRUPT_VECTOR_ALREADY_CONNECTED 763
+	/// The specified interrupt vector is still connected.
 	INTERRUPT_STILL_CONNECTED 764
+	/// An operation is blocked waiting for an oplock.
 	WAIT_FOR_OPLOCK 765
+	/// Debugger handled exception.
 	DBG_EXCEPTION_HANDLED 766
+	/// Debugger continued.
 	DBG_CONTINUE 767
+	/// An exception occurred in a user mode callback and the kernel callback frame should be removed.
 	CALLBACK_POP_STACK 768
+	/// Compression is disabled for this volume.
 	COMPRESSION_DISABLED 769
+	/// The data provider cannot fetch backwards through a result set.
 	CANTFETCHBACKWARDS 770
+	/// The data provider cannot scroll backwards through a result set.
 	CANTSCROLLBACKWARDS 771
+	/// The data provider requires that previously fetched data is released before asking for more data.
 	ROWSNOTRELEASED 772
+	/// The data provider was not able to interpret the flags set for a column binding in an accessor.
 	BAD_ACCESSOR_FLAGS 773
+	/// One or more errors occurred while processing the request.
 	ERRORS_ENCOUNTERED 774
+	/// The implementation is not capable of performing the request.
 	NOT_CAPABLE 775
+	/// The client of a component requested an operation which is not valid given the state of the component instance.
 	REQUEST_OUT_OF_SEQUENCE 776
+	/// A version number could not be parsed.
 	VERSION_PARSE_ERROR 777
+	/// The component's start position is invalid.
 	BADSTARTPOSITION 778
+	/// A hardware memory error has occurred.
 	MEMORY_HARDWARE 779
+	/// The attempted operation required self healing to be enabled.
 	DISK_REPAIR_DISABLED 780
+	/// The Desktop heap encountered an error while allocating session memory. There is more information in the system event log.
 	INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE 781
+	/// The system power state is transitioning from %2 to %3.
 	SYSTEM_POWERSTATE_TRANSITION 782
+	/// The system power state is transitioning from %2 to %3 but could enter %4.
 	SYSTEM_POWERSTATE_COMPLEX_TRANSITION 783
+	/// A thread is getting dispatched with MCA EXCEPTION because of MCA.
 	MCA_EXCEPTION 784
+	/// Access to %1 is monitored by policy rule %2.
 	ACCESS_AUDIT_BY_POLICY 785
+	/// Access to %1 has been restricted by your Administrator by policy rule %2.
 	ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY 786
+	/// A valid hibernation file has been invalidated and should be abandoned.
 	ABANDON_HIBERFILE 787
+	/// Windows was unable to save all the data for the file %hs; the data has been lost. This error may be caused by network connectivity issues. Please try to save this file elsewhere.
 	LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED 788
+	/// Windows was unable to save all the data for the file %hs; the data has been lost. This error was returned by the server on which the file exists. Please try to save this file elsewhere.
 	LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR 789
+	/// Windows was unable to save all the data for the file %hs; the data has been lost. This error may be caused if the device has been removed or the media is write-protected.
 	LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR 790
+	/// The resources required for this device conflict with the MCFG table.
 	BAD_MCFG_TABLE 791
+	/// The volume repair could not be performed while it is online. Please schedule to take the volume offline so that it can be repaired.
 	DISK_REPAIR_REDIRECTED 792
+	/// The volume repair was not successful.
 	DISK_REPAIR_UNSUCCESSFUL 793
+	/// One of the volume corruption logs is full. Further corruptions that may be detected won't be logged.
 	CORRUPT_LOG_OVERFULL 794
+	/// One of the volume corruption logs is internally corrupted and needs to be recreated. The volume may contain undetected corruptions and must be scanned.
 	CORRUPT_LOG_CORRUPTED 795
+	/// One of the volume corruption logs is unavailable for being operated on.
 	CORRUPT_LOG_UNAVAILABLE 796
+	/// One of the volume corruption logs was deleted while still having corruption records in them. The volume contains detected corruptions and must be scanned.
 	CORRUPT_LOG_DELETED_FULL 797
+	/// One of the volume corruption logs was cleared by chkdsk and no longer contains real corruptions.
 	CORRUPT_LOG_CLEARED 798
+	/// Orphaned files exist on the volume but could not be recovered because no more new names could be created in the recovery directory. Files must be moved from the recovery directory.
 	ORPHAN_NAME_EXHAUSTED 799
+	/// The oplock that was associated with this handle is now associated with a different handle.
 	OPLOCK_SWITCHED_TO_NEW_HANDLE 800
+	/// An oplock of the requested level cannot be granted. An oplock of a lower level may be available.
 	CANNOT_GRANT_REQUESTED_OPLOCK 801
+	/// The operation did not complete successfully because it would cause an oplock to be broken. The caller has requested that existing oplocks not be broken.
 	CANNOT_BREAK_OPLOCK 802
+	/// The handle with which this oplock was associated has been closed. The oplock is now broken.
 	OPLOCK_HANDLE_CLOSED 803
+	/// The specified access control entry (ACE) does not contain a condition.
 	NO_ACE_CONDITION 804
+	/// The specified access control entry (ACE) contains an invalid condition.
 	INVALID_ACE_CONDITION 805
+	/// Access to the specified file handle has been revoked.
 	FILE_HANDLE_REVOKED 806
+	/// An image file was mapped at a different address from the one specified in the image file but fixups will still be automatically performed on the image.
 	IMAGE_AT_DIFFERENT_BASE 807
+	/// The read or write operation to an encrypted file could not be completed because the file has not been opened for data access.
 	ENCRYPTED_IO_NOT_POSSIBLE 808
+	/// File metadata optimization is already in progress.
 	FILE_METADATA_OPTIMIZATION_IN_PROGRESS 809
+	/// The requested operation failed due to quota operation is still in progress.
 	QUOTA_ACTIVITY 810
+	/// Access to the specified handle has been revoked.
 	HANDLE_REVOKED 811
+	/// The callback function must be invoked inline.
 	CALLBACK_INVOKE_INLINE 812
+	/// The specified CPU Set IDs are invalid.
 	CPU_SET_INVALID 813
+	/// The specified enclave has not yet been terminated.
 	ENCLAVE_NOT_TERMINATED 814
+	/// An attempt was made to access protected memory in violation of its secure access policy.
 	ENCLAVE_VIOLATION 815
+	/// Access to the extended attribute was denied.
 	EA_ACCESS_DENIED 994
+	/// The I/O operation has been aborted because of either a thread exit or an application request.
 	OPERATION_ABORTED 995
+	/// Overlapped I/O event is not in a signaled state.
 	IO_INCOMPLETE 996
+	/// Overlapped I/O operation is in progress.
 	IO_PENDING 997
+	/// Invalid access to memory location.
 	NOACCESS 998
+	/// Error performing inpage operation.
 	SWAPERROR 999
+	/// Recursion too deep; the stack overflowed.
 	STACK_OVERFLOW 1001
+	/// The window cannot act on the sent message.
 	INVALID_MESSAGE 1002
+	/// Cannot complete this function.
 	CAN_NOT_COMPLETE 1003
+	/// Invalid flags.
 	INVALID_FLAGS 1004
+	/// The volume does not contain a recognized file system. Please make sure that all required file system drivers are loaded and that the volume is not corrupted.
 	UNRECOGNIZED_VOLUME 1005
+	/// The volume for a file has been externally altered so that the opened file is no longer valid.
 	FILE_INVALID 1006
+	/// The requested operation cannot be performed in full-screen mode.
 	FULLSCREEN_MODE 1007
+	/// An attempt was made to reference a token that does not exist.
 	NO_TOKEN 1008
+	/// The configuration registry database is corrupt.
 	BADDB 1009
+	/// The configuration registry key is invalid.
 	BADKEY 1010
+	/// The configuration registry key could not be opened.
 	CANTOPEN 1011
+	/// The configuration registry key could not be read.
 	CANTREAD 1012
+	/// The configuration registry key could not be written.
 	CANTWRITE 1013
+	/// One of the files in the registry database had to be recovered by use of a log or alternate copy. The recovery was successful.
 	REGISTRY_RECOVERED 1014
+	/// The registry is corrupted. The structure of one of the files containing registry data is corrupted, or the system's memory image of the file is corrupted, or the file could not be recovered because the alternate copy or log was absent or corrupted.
 	REGISTRY_CORRUPT 1015
+	/// An I/O operation initiated by the registry failed unrecoverably. The registry could not read in, or write out, or flush, one of the files that contain the system's image of the registry.
 	REGISTRY_IO_FAILED 1016
+	/// The system has attempted to load or restore a file into the registry, but the specified file is not in a registry file format.
 	NOT_REGISTRY_FILE 1017
+	/// Illegal operation attempted on a registry key that has been marked for deletion.
 	KEY_DELETED 1018
+	/// System could not allocate the required space in a registry log.
 	NO_LOG_SPACE 1019
+	/// Cannot create a symbolic link in a registry key that already has subkeys or values.
 	KEY_HAS_CHILDREN 1020
+	/// Cannot create a stable subkey under a volatile parent key.
 	CHILD_MUST_BE_VOLATILE 1021
+	/// A notify change request is being completed and the information is not being returned in the caller's buffer. The caller now needs to enumerate the files to find the changes.
 	NOTIFY_ENUM_DIR 1022
+	/// A stop control has been sent to a service that other running services are dependent on.
 	DEPENDENT_SERVICES_RUNNING 1051
+	/// The requested control is not valid for this service.
 	INVALID_SERVICE_CONTROL 1052
+	/// The service did not respond to the start or control request in a timely fashion.
 	SERVICE_REQUEST_TIMEOUT 1053
+	/// A thread could not be created for the service.
 	SERVICE_NO_THREAD 1054
+	/// The service database is locked.
 	SERVICE_DATABASE_LOCKED 1055
+	/// An instance of the service is already running.
 	SERVICE_ALREADY_RUNNING 1056
+	/// The account name is invalid or does not exist, or the password is invalid for the account name specified.
 	INVALID_SERVICE_ACCOUNT 1057
+	/// The service cannot be started, either because it is disabled or because it has no enabled devices associated with it.
 	SERVICE_DISABLED 1058
+	/// Circular service dependency was specified.
 	CIRCULAR_DEPENDENCY 1059
+	/// The specified service does not exist as an installed service.
 	SERVICE_DOES_NOT_EXIST 1060
+	/// The service cannot accept control messages at this time.
 	SERVICE_CANNOT_ACCEPT_CTRL 1061
+	/// The service has not been started.
 	SERVICE_NOT_ACTIVE 1062
+	/// The service process could not connect to the service controller.
 	FAILED_SERVICE_CONTROLLER_CONNECT 1063
+	/// An exception occurred in the service when handling the control request.
 	EXCEPTION_IN_SERVICE 1064
+	/// The database specified does not exist.
 	DATABASE_DOES_NOT_EXIST 1065
+	/// The service has returned a service-specific error code.
 	SERVICE_SPECIFIC_ERROR 1066
+	/// The process terminated unexpectedly.
 	PROCESS_ABORTED 1067
+	/// The dependency service or group failed to start.
 	SERVICE_DEPENDENCY_FAIL 1068
+	/// The service did not start due to a logon failure.
 	SERVICE_LOGON_FAILED 1069
+	/// After starting, the service hung in a start-pending state.
 	SERVICE_START_HANG 1070
+	/// The specified service database lock is invalid.
 	INVALID_SERVICE_LOCK 1071
+	/// The specified service has been marked for deletion.
 	SERVICE_MARKED_FOR_DELETE 1072
+	/// The specified service already exists.
 	SERVICE_EXISTS 1073
+	/// The system is currently running with the last-known-good configuration.
 	ALREADY_RUNNING_LKG 1074
+	/// The dependency service does not exist or has been marked for deletion.
 	SERVICE_DEPENDENCY_DELETED 1075
+	/// The current boot has already been accepted for use as the last-known-good control set.
 	BOOT_ALREADY_ACCEPTED 1076
+	/// No attempts to start the service have been made since the last boot.
 	SERVICE_NEVER_STARTED 1077
+	/// The name is already in use as either a service name or a service display name.
 	DUPLICATE_SERVICE_NAME 1078
+	/// The account specified for this service is different from the account specified for other services running in the same process.
 	DIFFERENT_SERVICE_ACCOUNT 1079
+	/// Failure actions can only be set for Win32 services, not for drivers.
 	CANNOT_DETECT_DRIVER_FAILURE 1080
+	/// This service runs in the same process as the service control manager. Therefore, the service control manager cannot take action if this service's process terminates unexpectedly.
 	CANNOT_DETECT_PROCESS_ABORT 1081
+	/// No recovery program has been configured for this service.
 	NO_RECOVERY_PROGRAM 1082
+	/// The executable program that this service is configured to run in does not implement the service.
 	SERVICE_NOT_IN_EXE 1083
+	/// This service cannot be started in Safe Mode.
 	NOT_SAFEBOOT_SERVICE 1084
+	/// The physical end of the tape has been reached.
 	END_OF_MEDIA 1100
+	/// A tape access reached a filemark.
 	FILEMARK_DETECTED 1101
+	/// The beginning of the tape or a partition was encountered.
 	BEGINNING_OF_MEDIA 1102
+	/// A tape access reached the end of a set of files.
 	SETMARK_DETECTED 1103
+	/// No more data is on the tape.
 	NO_DATA_DETECTED 1104
+	/// Tape could not be partitioned.
 	PARTITION_FAILURE 1105
+	/// When accessing a new tape of a multivolume partition, the current block size is incorrect.
 	INVALID_BLOCK_LENGTH 1106
+	/// Tape partition information could not be found when loading a tape.
 	DEVICE_NOT_PARTITIONED 1107
+	/// Unable to lock the media eject mechanism.
 	UNABLE_TO_LOCK_MEDIA 1108
+	/// Unable to unload the media.
 	UNABLE_TO_UNLOAD_MEDIA 1109
+	/// The media in the drive may have changed.
 	MEDIA_CHANGED 1110
+	/// The I/O bus was reset.
 	BUS_RESET 1111
+	/// No media in drive.
 	NO_MEDIA_IN_DRIVE 1112
+	/// No mapping for the Unicode character exists in the target multi-byte code page.
 	NO_UNICODE_TRANSLATION 1113
+	/// A dynamic link library (DLL) initialization routine failed.
 	DLL_INIT_FAILED 1114
+	/// A system shutdown is in progress.
 	SHUTDOWN_IN_PROGRESS 1115
+	/// Unable to abort the system shutdown because no shutdown was in progress.
 	NO_SHUTDOWN_IN_PROGRESS 1116
+	/// The request could not be performed because of an I/O device error.
 	IO_DEVICE 1117
+	/// No serial device was successfully initialized. The serial driver will unload.
 	SERIAL_NO_DEVICE 1118
+	/// Unable to open a device that was sharing an interrupt request (IRQ) with other devices. At least one other device that uses that IRQ was already opened.
 	IRQ_BUSY 1119
+	/// A serial I/O operation was completed by another write to the serial port. (The IOCTL_SERIAL_XOFF_COUNTER reached zero.)
 	MORE_WRITES 1120
+	/// A serial I/O operation completed because the timeout period expired. (The IOCTL_SERIAL_XOFF_COUNTER did not reach zero.)
 	COUNTER_TIMEOUT 1121
+	/// No ID address mark was found on the floppy disk.
 	FLOPPY_ID_MARK_NOT_FOUND 1122
+	/// Mismatch between the floppy disk sector ID field and the floppy disk controller track address.
 	FLOPPY_WRONG_CYLINDER 1123
+	/// The floppy disk controller reported an error that is not recognized by the floppy disk driver.
 	FLOPPY_UNKNOWN_ERROR 1124
+	/// The floppy disk controller returned inconsistent results in its registers.
 	FLOPPY_BAD_REGISTERS 1125
+	/// While accessing the hard disk, a recalibrate operation failed, even after retries.
 	DISK_RECALIBRATE_FAILED 1126
+	/// While accessing the hard disk, a disk operation failed even after retries.
 	DISK_OPERATION_FAILED 1127
+	/// While accessing the hard disk, a disk controller reset was needed, but even that failed.
 	DISK_RESET_FAILED 1128
+	/// Physical end of tape encountered.
 	EOM_OVERFLOW 1129
+	/// Not enough server memory resources are available to process this command.
 	NOT_ENOUGH_SERVER_MEMORY 1130
+	/// A potential deadlock condition has been detected.
 	POSSIBLE_DEADLOCK 1131
+	/// The base address or the file offset specified does not have the proper alignment.
 	MAPPED_ALIGNMENT 1132
+	/// An attempt to change the system power state was vetoed by another application or driver.
 	SET_POWER_STATE_VETOED 1140
+	/// The system BIOS failed an attempt to change the system power state.
 	SET_POWER_STATE_FAILED 1141
+	/// An attempt was made to create more links on a file than the file system supports.
 	TOO_MANY_LINKS 1142
+	/// The specified program requires a newer version of Windows.
 	OLD_WIN_VERSION 1150
+	/// The specified program is not a Windows or MS-DOS program.
 	APP_WRONG_OS 1151
+	/// Cannot start more than one instance of the specified program.
 	SINGLE_INSTANCE_APP 1152
+	/// The specified program was written for an earlier version of Windows.
 	RMODE_APP 1153
+	/// One of the library files needed to run this application is damaged.
 	INVALID_DLL 1154
+	/// No application is associated with the specified file for this operation.
 	NO_ASSOCIATION 1155
+	/// An error occurred in sending the command to the application.
 	DDE_FAIL 1156
+	/// One of the library files needed to run this application cannot be found.
 	DLL_NOT_FOUND 1157
+	/// The current process has used all of its system allowance of handles for Window Manager objects.
 	NO_MORE_USER_HANDLES 1158
+	/// The message can be used only with synchronous operations.
 	MESSAGE_SYNC_ONLY 1159
+	/// The indicated source element has no media.
 	SOURCE_ELEMENT_EMPTY 1160
+	/// The indicated destination element already contains media.
 	DESTINATION_ELEMENT_FULL 1161
+	/// The indicated element does not exist.
 	ILLEGAL_ELEMENT_ADDRESS 1162
+	/// The indicated element is part of a magazine that is not present.
 	MAGAZINE_NOT_PRESENT 1163
+	/// The indicated device requires reinitialization due to hardware errors.
 	DEVICE_REINITIALIZATION_NEEDED 1164
+	/// The device has indicated that cleaning is required before further operations are attempted.
 	DEVICE_REQUIRES_CLEANING 1165
+	/// The device has indicated that its door is open.
 	DEVICE_DOOR_OPEN 1166
+	/// The device is not connected.
 	DEVICE_NOT_CONNECTED 1167
+	/// Element not found.
 	NOT_FOUND 1168
+	/// There was no match for the specified key in the index.
 	NO_MATCH 1169
+	/// The property set specified does not exist on the object.
 	SET_NOT_FOUND 1170
+	/// The point passed to GetMouseMovePoints is not in the buffer.
 	POINT_NOT_FOUND 1171
+	/// The tracking (workstation) service is not running.
 	NO_TRACKING_SERVICE 1172
+	/// The Volume ID could not be found.
 	NO_VOLUME_ID 1173
+	/// Unable to remove the file to be replaced.
 	UNABLE_TO_REMOVE_REPLACED 1175
+	/// Unable to move the replacement file to the file to be replaced. The file to be replaced has retained its original name.
 	UNABLE_TO_MOVE_REPLACEMENT 1176
+	/// Unable to move the replacement file to the file to be replaced. The file to be replaced has been renamed using the backup name.
 	UNABLE_TO_MOVE_REPLACEMENT_2 1177
+	/// The volume change journal is being deleted.
 	JOURNAL_DELETE_IN_PROGRESS 1178
+	/// The volume change journal is not active.
 	JOURNAL_NOT_ACTIVE 1179
+	/// A file was found, but it may not be the correct file.
 	POTENTIAL_FILE_FOUND 1180
+	/// The journal entry has been deleted from the journal.
 	JOURNAL_ENTRY_DELETED 1181
+	/// A system shutdown has already been scheduled.
 	SHUTDOWN_IS_SCHEDULED 1190
+	/// The system shutdown cannot be initiated because there are other users logged on to the computer.
 	SHUTDOWN_USERS_LOGGED_ON 1191
+	/// The specified device name is invalid.
 	BAD_DEVICE 1200
+	/// The device is not currently connected but it is a remembered connection.
 	CONNECTION_UNAVAIL 1201
+	/// The local device name has a remembered connection to another network resource.
 	DEVICE_ALREADY_REMEMBERED 1202
+	/// The network path was either typed incorrectly, does not exist, or the network provider is not currently available. Please try retyping the path or contact your network administrator.
 	NO_NET_OR_BAD_PATH 1203
+	/// The specified network provider name is invalid.
 	BAD_PROVIDER 1204
+	/// Unable to open the network connection profile.
 	CANNOT_OPEN_PROFILE 1205
+	/// The network connection profile is corrupted.
 	BAD_PROFILE 1206
+	/// Cannot enumerate a noncontainer.
 	NOT_CONTAINER 1207
+	/// An extended error has occurred.
 	EXTENDED_ERROR 1208
+	/// The format of the specified group name is invalid.
 	INVALID_GROUPNAME 1209
+	/// The format of the specified computer name is invalid.
 	INVALID_COMPUTERNAME 1210
+	/// The format of the specified event name is invalid.
 	INVALID_EVENTNAME 1211
+	/// The format of the specified domain name is invalid.
 	INVALID_DOMAINNAME 1212
+	/// The format of the specified service name is invalid.
 	INVALID_SERVICENAME 1213
+	/// The format of the specified network name is invalid.
 	INVALID_NETNAME 1214
+	/// The format of the specified share name is invalid.
 	INVALID_SHARENAME 1215
+	/// The format of the specified password is invalid.
 	INVALID_PASSWORDNAME 1216
+	/// The format of the specified message name is invalid.
 	INVALID_MESSAGENAME 1217
+	/// The format of the specified message destination is invalid.
 	INVALID_MESSAGEDEST 1218
+	/// Multiple connections to a server or shared resource by the same user, using more than one user name, are not allowed. Disconnect all previous connections to the server or shared resource and try again.
 	SESSION_CREDENTIAL_CONFLICT 1219
+	/// An attempt was made to establish a session to a network server, but there are already too many sessions established to that server.
 	REMOTE_SESSION_LIMIT_EXCEEDED 1220
+	/// The workgroup or domain name is already in use by another computer on the network.
 	DUP_DOMAINNAME 1221
+	/// The network is not present or not started.
 	NO_NETWORK 1222
+	/// The operation was canceled by the user.
 	CANCELLED 1223
+	/// The requested operation cannot be performed on a file with a user-mapped section open.
 	USER_MAPPED_FILE 1224
+	/// The remote computer refused the network connection.
 	CONNECTION_REFUSED 1225
+	/// The network connection was gracefully closed.
 	GRACEFUL_DISCONNECT 1226
+	/// The network transport endpoint already has an address associated with it.
 	ADDRESS_ALREADY_ASSOCIATED 1227
+	/// An address has not yet been associated with the network endpoint.
 	ADDRESS_NOT_ASSOCIATED 1228
+	/// An operation was attempted on a nonexistent network connection.
 	CONNECTION_INVALID 1229
+	/// An invalid operation was attempted on an active network connection.
 	CONNECTION_ACTIVE 1230
+	/// The network location cannot be reached.
 	NETWORK_UNREACHABLE 1231
+	/// The remote system is not reachable by the transport.
 	HOST_UNREACHABLE 1232
+	/// The network location cannot be reached. For information about network troubleshooting, see Windows Help.
 	PROTOCOL_UNREACHABLE 1233
+	/// No service is operating at the destination network endpoint on the remote system.
 	PORT_UNREACHABLE 1234
+	/// The request was aborted.
 	REQUEST_ABORTED 1235
+	/// The network connection was aborted by the local system.
 	CONNECTION_ABORTED 1236
+	/// The operation could not be completed. A retry should be performed.
 	RETRY 1237
+	/// A connection to the server could not be made because the limit on the number of concurrent connections for this account has been reached.
 	CONNECTION_COUNT_LIMIT 1238
+	/// Attempting to log in during an unauthorized time of day for this account.
 	LOGIN_TIME_RESTRICTION 1239
+	/// The account is not authorized to log in from this station.
 	LOGIN_WKSTA_RESTRICTION 1240
+	/// The network address could not be used for the operation requested.
 	INCORRECT_ADDRESS 1241
+	/// The service is already registered.
 	ALREADY_REGISTERED 1242
+	/// The specified service does not exist.
 	SERVICE_NOT_FOUND 1243
+	/// The operation being requested was not performed because the user has not been authenticated.
 	NOT_AUTHENTICATED 1244
+	/// The operation being requested was not performed because the user has not logged on to the network. The specified service does not exist.
 	NOT_LOGGED_ON 1245
+	/// Continue with work in progress.
 	CONTINUE 1246
+	/// An attempt was made to perform an initialization operation when initialization has already been completed.
 	ALREADY_INITIALIZED 1247
+	/// No more local devices.
 	NO_MORE_DEVICES 1248
+	/// The specified site does not exist.
 	NO_SUCH_SITE 1249
+	/// A domain controller with the specified name already exists.
 	DOMAIN_CONTROLLER_EXISTS 1250
+	/// This operation is supported only when you are connected to the server.
 	ONLY_IF_CONNECTED 1251
+	/// The group policy framework should call the extension even if there are no changes.
 	OVERRIDE_NOCHANGES 1252
+	/// The specified user does not have a valid profile.
 	BAD_USER_PROFILE 1253
+	/// This operation is not supported on a computer running Windows Server 2003 for Small Business Server.
 	NOT_SUPPORTED_ON_SBS 1254
+	/// The server machine is shutting down.
 	SERVER_SHUTDOWN_IN_PROGRESS 1255
+	/// The remote system is not available. For information about network troubleshooting, see Windows Help.
 	HOST_DOWN 1256
+	/// The security identifier provided is not from an account domain.
 	NON_ACCOUNT_SID 1257
+	/// The security identifier provided does not have a domain component.
 	NON_DOMAIN_SID 1258
+	/// AppHelp dialog canceled thus preventing the application from starting.
 	APPHELP_BLOCK 1259
+	/// This program is blocked by group policy. For more information, contact your system administrator.
 	ACCESS_DISABLED_BY_POLICY 1260
+	/// A program attempt to use an invalid register value. Normally caused by an uninitialized register. This error is Itanium specific.
 	REG_NAT_CONSUMPTION 1261
+	/// The share is currently offline or does not exist.
 	CSCSHARE_OFFLINE 1262
+	/// The Kerberos protocol encountered an error while validating the KDC certificate during smartcard logon. There is more information in the system event log.
 	PKINIT_FAILURE 1263
+	/// The Kerberos protocol encountered an error while attempting to utilize the smartcard subsystem.
 	SMARTCARD_SUBSYSTEM_FAILURE 1264
+	/// The system cannot contact a domain controller to service the authentication request. Please try again later.
 	DOWNGRADE_DETECTED 1265
+	/// The machine is locked and cannot be shut down without the force option.
 	MACHINE_LOCKED 1271
+	/// You can't access this shared folder because your organization's security policies block unauthenticated guest access. These policies help protect your PC from unsafe or malicious devices on the network.
 	SMB_GUEST_LOGON_BLOCKED 1272
+	/// An application-defined callback gave invalid data when called.
 	CALLBACK_SUPPLIED_INVALID_DATA 1273
+	/// The group policy framework should call the extension in the synchronous foreground policy refresh.
 	SYNC_FOREGROUND_REFRESH_REQUIRED 1274
+	/// This driver has been blocked from loading.
 	DRIVER_BLOCKED 1275
+	/// A dynamic link library (DLL) referenced a module that was neither a DLL nor the process's executable image.
 	INVALID_IMPORT_OF_NON_DLL 1276
+	/// Windows cannot open this program since it has been disabled.
 	ACCESS_DISABLED_WEBBLADE 1277
+	/// Windows cannot open this program because the license enforcement system has been tampered with or become corrupted.
 	ACCESS_DISABLED_WEBBLADE_TAMPER 1278
+	/// A transaction recover failed.
 	RECOVERY_FAILURE 1279
+	/// The current thread has already been converted to a fiber.
 	ALREADY_FIBER 1280
+	/// The current thread has already been converted from a fiber.
 	ALREADY_THREAD 1281
+	/// The system detected an overrun of a stack-based buffer in this application. This overrun could potentially allow a malicious user to gain control of this application.
 	STACK_BUFFER_OVERRUN 1282
+	/// Data present in one of the parameters is more than the function can operate on.
 	PARAMETER_QUOTA_EXCEEDED 1283
+	/// An attempt to do an operation on a debug object failed because the object is in the process of being deleted.
 	DEBUGGER_INACTIVE 1284
+	/// An attempt to delay-load a .dll or get a function address in a delay-loaded .dll failed.
 	DELAY_LOAD_FAILED 1285
+	/// %1 is a 16-bit application. You do not have permissions to execute 16-bit applications. Check your permissions with your system administrator.
 	VDM_DISALLOWED 1286
+	/// Insufficient information exists to identify the cause of failure.
 	UNIDENTIFIED_ERROR 1287
+	/// The parameter passed to a C runtime function is incorrect.
 	INVALID_CRUNTIME_PARAMETER 1288
+	/// The operation occurred beyond the valid data length of the file.
 	BEYOND_VDL 1289
+	/// The service start failed since one or more services in the same process have an incompatible service SID type setting. A service with restricted service SID type can only coexist in the same process with other services with a restricted SID type. If the service SID type for this service was just configured, the hosting process must be restarted in order to start this service.
 	INCOMPATIBLE_SERVICE_SID_TYPE 1290
+	/// The process hosting the driver for this device has been terminated.
 	DRIVER_PROCESS_TERMINATED 1291
+	/// An operation attempted to exceed an implementation-defined limit.
 	IMPLEMENTATION_LIMIT 1292
+	/// Either the target process, or the target thread's containing process, is a protected process.
 	PROCESS_IS_PROTECTED 1293
+	/// The service notification client is lagging too far behind the current state of services in the machine.
 	SERVICE_NOTIFY_CLIENT_LAGGING 1294
+	/// The amount of disk space required exceeds the user quota.
 	DISK_QUOTA_EXCEEDED 1295
+	/// The requested file operation failed because the storage quota was exceeded. To free up disk space, move files to a different location or delete unnecessary files. For more information, contact your system administrator.
 	CONTENT_BLOCKED 1296
+	/// A privilege that the service requires to function properly does not exist in the service account configuration. You may use the Services Microsoft Management Console (MMC) snap-in (services.msc) and the Local Security Settings MMC snap-in (secpol.msc) to view the service configuration and the account configuration.
 	INCOMPATIBLE_SERVICE_PRIVILEGE 1297
+	/// A thread involved in this operation appears to be unresponsive.
 	APP_HANG 1298
+	/// Indicates a particular Security ID may not be assigned as the label of an object.
 	INVALID_LABEL 1299
+	/// Not all privileges or groups referenced are assigned to the caller.
 	NOT_ALL_ASSIGNED 1300
+	/// Some mapping between account names and security IDs was not done.
 	SOME_NOT_MAPPED 1301
+	/// No system quota limits are specifically set for this account.
 	NO_QUOTAS_FOR_ACCOUNT 1302
+	/// No encryption key is available. A well-known encryption key was returned.
 	LOCAL_USER_SESSION_KEY 1303
+	/// The password is too complex to be converted to a LAN Manager password. The LAN Manager password returned is a NULL string.
 	NULL_LM_PASSWORD 1304
+	/// The revision level is unknown.
 	UNKNOWN_REVISION 1305
+	/// Indicates two revision levels are incompatible.
 	REVISION_MISMATCH 1306
+	/// This security ID may not be assigned as the owner of this object.
 	INVALID_OWNER 1307
+	/// This security ID may not be assigned as the primary group of an object.
 	INVALID_PRIMARY_GROUP 1308
+	/// An attempt has been made to operate on an impersonation token by a thread that is not currently impersonating a client.
 	NO_IMPERSONATION_TOKEN 1309
+	/// The group may not be disabled.
 	CANT_DISABLE_MANDATORY 1310
+	/// We can't sign you in with this credential because your domain isn't available. Make sure your device is connected to your organization's network and try again. If you previously signed in on this device with another credential, you can sign in with that credential.
 	NO_LOGON_SERVERS 1311
+	/// A specified logon session does not exist. It may already have been terminated.
 	NO_SUCH_LOGON_SESSION 1312
+	/// A specified privilege does not exist.
 	NO_SUCH_PRIVILEGE 1313
+	/// A required privilege is not held by the client.
 	PRIVILEGE_NOT_HELD 1314
+	/// The name provided is not a properly formed account name.
 	INVALID_ACCOUNT_NAME 1315
+	/// The specified account already exists.
 	USER_EXISTS 1316
+	/// The specified account does not exist.
 	NO_SUCH_USER 1317
+	/// The specified group already exists.
 	GROUP_EXISTS 1318
+	/// The specified group does not exist.
 	NO_SUCH_GROUP 1319
+	/// Either the specified user account is already a member of the specified group, or the specified group cannot be deleted because it contains a member.
 	MEMBER_IN_GROUP 1320
+	/// The specified user account is not a member of the specified group account.
 	MEMBER_NOT_IN_GROUP 1321
+	/// This operation is disallowed as it could result in an administration account being disabled, deleted or unable to logon.
 	LAST_ADMIN 1322
+	/// Unable to update the password. The value provided as the current password is incorrect.
 	WRONG_PASSWORD 1323
+	/// Unable to update the password. The value provided for the new password contains values that are not allowed in passwords.
 	ILL_FORMED_PASSWORD 1324
+	/// Unable to update the password. The value provided for the new password does not meet the length, complexity, or history requirements of the domain.
 	PASSWORD_RESTRICTION 1325
+	/// The user name or password is incorrect.
 	LOGON_FAILURE 1326
+	/// Account restrictions are preventing this user from signing in. For example: blank passwords aren't allowed, sign-in times are limited, or a policy restriction has been enforced.
 	ACCOUNT_RESTRICTION 1327
+	/// Your account has time restrictions that keep you from signing in right now.
 	INVALID_LOGON_HOURS 1328
+	/// This user isn't allowed to sign in to this computer.
 	INVALID_WORKSTATION 1329
+	/// The password for this account has expired.
 	PASSWORD_EXPIRED 1330
+	/// This user can't sign in because this account is currently disabled.
 	ACCOUNT_DISABLED 1331
+	/// No mapping between account names and security IDs was done.
 	NONE_MAPPED 1332
+	/// Too many local user identifiers (LUIDs) were requested at one time.
 	TOO_MANY_LUIDS_REQUESTED 1333
+	/// No more local user identifiers (LUIDs) are available.
 	LUIDS_EXHAUSTED 1334
+	/// The subauthority part of a security ID is invalid for this particular use.
 	INVALID_SUB_AUTHORITY 1335
+	/// The access control list (ACL) structure is invalid.
 	INVALID_ACL 1336
+	/// The security ID structure is invalid.
 	INVALID_SID 1337
+	/// The security descriptor structure is invalid.
 	INVALID_SECURITY_DESCR 1338
+	/// The inherited access control list (ACL) or access control entry (ACE) could not be built.
 	BAD_INHERITANCE_ACL 1340
+	/// The server is currently disabled.
 	SERVER_DISABLED 1341
+	/// The server is currently enabled.
 	SERVER_NOT_DISABLED 1342
+	/// The value provided was an invalid value for an identifier authority.
 	INVALID_ID_AUTHORITY 1343
+	/// No more memory is available for security information updates.
 	ALLOTTED_SPACE_EXCEEDED 1344
+	/// The specified attributes are invalid, or incompatible with the attributes for the group as a whole.
 	INVALID_GROUP_ATTRIBUTES 1345
+	/// Either a required impersonation level was not provided, or the provided impersonation level is invalid.
 	BAD_IMPERSONATION_LEVEL 1346
+	/// Cannot open an anonymous level security token.
 	CANT_OPEN_ANONYMOUS 1347
+	/// The validation information class requested was invalid.
 	BAD_VALIDATION_CLASS 1348
+	/// The type of the token is inappropriate for its attempted use.
 	BAD_TOKEN_TYPE 1349
+	/// Unable to perform a security operation on an object that has no associated security.
 	NO_SECURITY_ON_OBJECT 1350
+	/// Configuration information could not be read from the domain controller, either because the machine is unavailable, or access has been denied.
 	CANT_ACCESS_DOMAIN_INFO 1351
+	/// The security account manager (SAM) or local security authority (LSA) server was in the wrong state to perform the security operation.
 	INVALID_SERVER_STATE 1352
+	/// The domain was in the wrong state to perform the security operation.
 	INVALID_DOMAIN_STATE 1353
+	/// This operation is only allowed for the Primary Domain Controller of the domain.
 	INVALID_DOMAIN_ROLE 1354
+	/// The specified domain either does not exist or could not be contacted.
 	NO_SUCH_DOMAIN 1355
+	/// The specified domain already exists.
 	DOMAIN_EXISTS 1356
+	/// An attempt was made to exceed the limit on the number of domains per server.
 	DOMAIN_LIMIT_EXCEEDED 1357
+	/// Unable to complete the requested operation because of either a catastrophic media failure or a data structure corruption on the disk.
 	INTERNAL_DB_CORRUPTION 1358
+	/// An internal error occurred.
 	INTERNAL_ERROR 1359
+	/// Generic access types were contained in an access mask which should already be mapped to nongeneric types.
 	GENERIC_NOT_MAPPED 1360
+	/// A security descriptor is not in the right format (absolute or self-relative).
 	BAD_DESCRIPTOR_FORMAT 1361
+	/// The requested action is restricted for use by logon processes only. The calling process has not registered as a logon process.
 	NOT_LOGON_PROCESS 1362
+	/// Cannot start a new logon session with an ID that is already in use.
 	LOGON_SESSION_EXISTS 1363
+	/// A specified authentication package is unknown.
 	NO_SUCH_PACKAGE 1364
+	/// The logon session is not in a state that is consistent with the requested operation.
 	BAD_LOGON_SESSION_STATE 1365
+	/// The logon session ID is already in use.
 	LOGON_SESSION_COLLISION 1366
+	/// A logon request contained an invalid logon type value.
 	INVALID_LOGON_TYPE 1367
+	/// Unable to impersonate using a named pipe until data has been read from that pipe.
 	CANNOT_IMPERSONATE 1368
+	/// The transaction state of a registry subtree is incompatible with the requested operation.
 	RXACT_INVALID_STATE 1369
+	/// An internal security database corruption has been encountered.
 	RXACT_COMMIT_FAILURE 1370
+	/// Cannot perform this operation on built-in accounts.
 	SPECIAL_ACCOUNT 1371
+	/// Cannot perform this operation on this built-in special group.
 	SPECIAL_GROUP 1372
+	/// Cannot perform this operation on this built-in special user.
 	SPECIAL_USER 1373
+	/// The user cannot be removed from a group because the group is currently the user's primary group.
 	MEMBERS_PRIMARY_GROUP 1374
+	/// The token is already in use as a primary token.
 	TOKEN_ALREADY_IN_USE 1375
+	/// The specified local group does not exist.
 	NO_SUCH_ALIAS 1376
+	/// The specified account name is not a member of the group.
 	MEMBER_NOT_IN_ALIAS 1377
+	/// The specified account name is already a member of the group.
 	MEMBER_IN_ALIAS 1378
+	/// The specified local group already exists.
 	ALIAS_EXISTS 1379
+	/// Logon failure: the user has not been granted the requested logon type at this computer.
 	LOGON_NOT_GRANTED 1380
+	/// The maximum number of secrets that may be stored in a single system has been exceeded.
 	TOO_MANY_SECRETS 1381
+	/// The length of a secret exceeds the maximum length allowed.
 	SECRET_TOO_LONG 1382
+	/// The local security authority database contains an internal inconsistency.
 	INTERNAL_DB_ERROR 1383
+	/// During a logon attempt, the user's security context accumulated too many security IDs.
 	TOO_MANY_CONTEXT_IDS 1384
+	/// Logon failure: the user has not been granted the requested logon type at this computer.
 	LOGON_TYPE_NOT_GRANTED 1385
+	/// A cross-encrypted password is necessary to change a user password.
 	NT_CROSS_ENCRYPTION_REQUIRED 1386
+	/// A member could not be added to or removed from the local group because the member does not exist.
 	NO_SUCH_MEMBER 1387
+	/// A new member could not be added to a local group because the member has the wrong account type.
 	INVALID_MEMBER 1388
+	/// Too many security IDs have been specified.
 	TOO_MANY_SIDS 1389
+	/// A cross-encrypted password is necessary to change this user password.
 	LM_CROSS_ENCRYPTION_REQUIRED 1390
+	/// Indicates an ACL contains no inheritable components.
 	NO_INHERITANCE 1391
+	/// The file or directory is corrupted and unreadable.
 	FILE_CORRUPT 1392
+	/// The disk structure is corrupted and unreadable.
 	DISK_CORRUPT 1393
+	/// There is no user session key for the specified logon session.
 	NO_USER_SESSION_KEY 1394
+	/// The service being accessed is licensed for a particular number of connections. No more connections can be made to the service at this time because there are already as many connections as the service can accept.
 	LICENSE_QUOTA_EXCEEDED 1395
+	/// The target account name is incorrect.
 	WRONG_TARGET_NAME 1396
+	/// Mutual Authentication failed. The server's password is out of date at the domain controller.
 	MUTUAL_AUTH_FAILED 1397
+	/// There is a time and/or date difference between the client and server.
 	TIME_SKEW 1398
+	/// This operation cannot be performed on the current domain.
 	CURRENT_DOMAIN_NOT_ALLOWED 1399
+	/// Invalid window handle.
 	INVALID_WINDOW_HANDLE 1400
+	/// Invalid menu handle.
 	INVALID_MENU_HANDLE 1401
+	/// Invalid cursor handle.
 	INVALID_CURSOR_HANDLE 1402
+	/// Invalid accelerator table handle.
 	INVALID_ACCEL_HANDLE 1403
+	/// Invalid hook handle.
 	INVALID_HOOK_HANDLE 1404
+	/// Invalid handle to a multiple-window position structure.
 	INVALID_DWP_HANDLE 1405
+	/// Cannot create a top-level child window.
 	TLW_WITH_WSCHILD 1406
+	/// Cannot find window class.
 	CANNOT_FIND_WND_CLASS 1407
+	/// Invalid window; it belongs to other thread.
 	WINDOW_OF_OTHER_THREAD 1408
+	/// Hot key is already registered.
 	HOTKEY_ALREADY_REGISTERED 1409
+	/// Class already exists.
 	CLASS_ALREADY_EXISTS 1410
+	/// Class does not exist.
 	CLASS_DOES_NOT_EXIST 1411
+	/// Class still has open windows.
 	CLASS_HAS_WINDOWS 1412
+	/// Invalid index.
 	INVALID_INDEX 1413
+	/// Invalid icon handle.
 	INVALID_ICON_HANDLE 1414
+	/// Using private DIALOG window words.
 	PRIVATE_DIALOG_INDEX 1415
+	/// The list box identifier was not found.
 	LISTBOX_ID_NOT_FOUND 1416
+	/// No wildcards were found.
 	NO_WILDCARD_CHARACTERS 1417
+	/// Thread does not have a clipboard open.
 	CLIPBOARD_NOT_OPEN 1418
+	/// Hot key is not registered.
 	HOTKEY_NOT_REGISTERED 1419
+	/// The window is not a valid dialog window.
 	WINDOW_NOT_DIALOG 1420
+	/// Control ID not found.
 	CONTROL_ID_NOT_FOUND 1421
+	/// Invalid message for a combo box because it does not have an edit control.
 	INVALID_COMBOBOX_MESSAGE 1422
+	/// The window is not a combo box.
 	WINDOW_NOT_COMBOBOX 1423
+	/// Height must be less than 256.
 	INVALID_EDIT_HEIGHT 1424
+	/// Invalid device context (DC) handle.
 	DC_NOT_FOUND 1425
+	/// Invalid hook procedure type.
 	INVALID_HOOK_FILTER 1426
+	/// Invalid hook procedure.
 	INVALID_FILTER_PROC 1427
+	/// Cannot set nonlocal hook without a module handle.
 	HOOK_NEEDS_HMOD 1428
+	/// This hook procedure can only be set globally.
 	GLOBAL_ONLY_HOOK 1429
+	/// The journal hook procedure is already installed.
 	JOURNAL_HOOK_SET 1430
+	/// The hook procedure is not installed.
 	HOOK_NOT_INSTALLED 1431
+	/// Invalid message for single-selection list box.
 	INVALID_LB_MESSAGE 1432
+	/// LB_SETCOUNT sent to non-lazy list box.
 	SETCOUNT_ON_BAD_LB 1433
+	/// This list box does not support tab stops.
 	LB_WITHOUT_TABSTOPS 1434
+	/// Cannot destroy object created by another thread.
 	DESTROY_OBJECT_OF_OTHER_THREAD 1435
+	/// Child windows cannot have menus.
 	CHILD_WINDOW_MENU 1436
+	/// The window does not have a system menu.
 	NO_SYSTEM_MENU 1437
+	/// Invalid message box style.
 	INVALID_MSGBOX_STYLE 1438
+	/// Invalid system-wide (SPI_*) parameter.
 	INVALID_SPI_VALUE 1439
+	/// Screen already locked.
 	SCREEN_ALREADY_LOCKED 1440
+	/// All handles to windows in a multiple-window position structure must have the same parent.
 	HWNDS_HAVE_DIFF_PARENT 1441
+	/// The window is not a child window.
 	NOT_CHILD_WINDOW 1442
+	/// Invalid GW_* command.
 	INVALID_GW_COMMAND 1443
+	/// Invalid thread identifier.
 	INVALID_THREAD_ID 1444
+	/// Cannot process a message from a window that is not a multiple document interface (MDI) window.
 	NON_MDICHILD_WINDOW 1445
+	/// Popup menu already active.
 	POPUP_ALREADY_ACTIVE 1446
+	/// The window does not have scroll bars.
 	NO_SCROLLBARS 1447
+	/// Scroll bar range cannot be greater than MAXLONG.
 	INVALID_SCROLLBAR_RANGE 1448
+	/// Cannot show or remove the window in the way specified.
 	INVALID_SHOWWIN_COMMAND 1449
+	/// Insufficient system resources exist to complete the requested service.
 	NO_SYSTEM_RESOURCES 1450
+	/// Insufficient system resources exist to complete the requested service.
 	NONPAGED_SYSTEM_RESOURCES 1451
+	/// Insufficient system resources exist to complete the requested service.
 	PAGED_SYSTEM_RESOURCES 1452
+	/// Insufficient quota to complete the requested service.
 	WORKING_SET_QUOTA 1453
+	/// Insufficient quota to complete the requested service.
 	PAGEFILE_QUOTA 1454
+	/// The paging file is too small for this operation to complete.
 	COMMITMENT_LIMIT 1455
+	/// A menu item was not found.
 	MENU_ITEM_NOT_FOUND 1456
+	/// Invalid keyboard layout handle.
 	INVALID_KEYBOARD_HANDLE 1457
+	/// Hook type not allowed.
 	HOOK_TYPE_NOT_ALLOWED 1458
+	/// This operation requires an interactive window station.
 	REQUIRES_INTERACTIVE_WINDOWSTATION 1459
+	/// This operation returned because the timeout period expired.
 	TIMEOUT 1460
+	/// Invalid monitor handle.
 	INVALID_MONITOR_HANDLE 1461
+	/// Incorrect size argument.
 	INCORRECT_SIZE 1462
+	/// The symbolic link cannot be followed because its type is disabled.
 	SYMLINK_CLASS_DISABLED 1463
+	/// This application does not support the current operation on symbolic links.
 	SYMLINK_NOT_SUPPORTED 1464
+	/// Windows was unable to parse the requested XML data.
 	XML_PARSE_ERROR 1465
+	/// An error was encountered while processing an XML digital signature.
 	XMLDSIG_ERROR 1466
+	/// This application must be restarted.
 	RESTART_APPLICATION 1467
+	/// The caller made the connection request in the wrong routing compartment.
 	WRONG_COMPARTMENT 1468
+	/// There was an AuthIP failure when attempting to connect to the remote host.
 	AUTHIP_FAILURE 1469
+	/// Insufficient NVRAM resources exist to complete the requested service. A reboot might be required.
 	NO_NVRAM_RESOURCES 1470
+	/// Unable to finish the requested operation because the specified process is not a GUI process.
 	NOT_GUI_PROCESS 1471
+	/// The event log file is corrupted.
 	EVENTLOG_FILE_CORRUPT 1500
+	/// No event log file could be opened, so the event logging service did not start.
 	EVENTLOG_CANT_START 1501
+	/// The event log file is full.
 	LOG_FILE_FULL 1502
+	/// The event log file has changed between read operations.
 	EVENTLOG_FILE_CHANGED 1503
+	/// The specified Job already has a container assigned to it.
 	CONTAINER_ASSIGNED 1504
+	/// The specified Job does not have a container assigned to it.
 	JOB_NO_CONTAINER 1505
+	/// The specified task name is invalid.
 	INVALID_TASK_NAME 1550
+	/// The specified task index is invalid.
 	INVALID_TASK_INDEX 1551
+	/// The specified thread is already joining a task.
 	THREAD_ALREADY_IN_TASK 1552
+	/// The Windows Installer Service could not be accessed. This can occur if the Windows Installer is not correctly installed. Contact your support personnel for assistance.
 	INSTALL_SERVICE_FAILURE 1601
+	/// User cancelled installation.
 	INSTALL_USEREXIT 1602
+	/// Fatal error during installation.
 	INSTALL_FAILURE 1603
+	/// Installation suspended, incomplete.
 	INSTALL_SUSPEND 1604
+	/// This action is only valid for products that are currently installed.
 	UNKNOWN_PRODUCT 1605
+	/// Feature ID not registered.
 	UNKNOWN_FEATURE 1606
+	/// Component ID not registered.
 	UNKNOWN_COMPONENT 1607
+	/// Unknown property.
 	UNKNOWN_PROPERTY 1608
+	/// Handle is in an invalid state.
 	INVALID_HANDLE_STATE 1609
+	/// The configuration data for this product is corrupt. Contact your support personnel.
 	BAD_CONFIGURATION 1610
+	/// Component qualifier not present.
 	INDEX_ABSENT 1611
+	/// The installation source for this product is not available. Verify that the source exists and that you can access it.
 	INSTALL_SOURCE_ABSENT 1612
+	/// This installation package cannot be installed by the Windows Installer service. You must install a Windows service pack that contains a newer version of the Windows Installer service.
 	INSTALL_PACKAGE_VERSION 1613
+	/// Product is uninstalled.
 	PRODUCT_UNINSTALLED 1614
+	/// SQL query syntax invalid or unsupported.
 	BAD_QUERY_SYNTAX 1615
+	/// Record field does not exist.
 	INVALID_FIELD 1616
+	/// The device has been removed.
 	DEVICE_REMOVED 1617
+	/// Another installation is already in progress. Complete that installation before proceeding with this install.
 	INSTALL_ALREADY_RUNNING 1618
+	/// This installation package could not be opened. Verify that the package exists and that you can access it, or contact the application vendor to verify that this is a valid Windows Installer package.
 	INSTALL_PACKAGE_OPEN_FAILED 1619
+	/// This installation package could not be opened. Contact the application vendor to verify that this is a valid Windows Installer package.
 	INSTALL_PACKAGE_INVALID 1620
+	/// There was an error starting the Windows Installer service user interface. Contact your support personnel.
 	INSTALL_UI_FAILURE 1621
+	/// Error opening installation log file. Verify that the specified log file location exists and that you can write to it.
 	INSTALL_LOG_FAILURE 1622
+	/// The language of this installation package is not supported by your system.
 	INSTALL_LANGUAGE_UNSUPPORTED 1623
+	/// Error applying transforms. Verify that the specified transform paths are valid.
 	INSTALL_TRANSFORM_FAILURE 1624
+	/// This installation is forbidden by system policy. Contact your system administrator.
 	INSTALL_PACKAGE_REJECTED 1625
+	/// Function could not be executed.
 	FUNCTION_NOT_CALLED 1626
+	/// Function failed during execution.
 	FUNCTION_FAILED 1627
+	/// Invalid or unknown table specified.
 	INVALID_TABLE 1628
+	/// Data supplied is of wrong type.
 	DATATYPE_MISMATCH 1629
+	/// Data of this type is not supported.
 	UNSUPPORTED_TYPE 1630
+	/// The Windows Installer service failed to start. Contact your support personnel.
 	CREATE_FAILED 1631
+	/// The Temp folder is on a drive that is full or is inaccessible. Free up space on the drive or verify that you have write permission on the Temp folder.
 	INSTALL_TEMP_UNWRITABLE 1632
+	/// This installation package is not supported by this processor type. Contact your product vendor.
 	INSTALL_PLATFORM_UNSUPPORTED 1633
+	/// Component not used on this computer.
 	INSTALL_NOTUSED 1634
+	/// This update package could not be opened. Verify that the update package exists and that you can access it, or contact the application vendor to verify that this is a valid Windows Installer update package.
 	PATCH_PACKAGE_OPEN_FAILED 1635
+	/// This update package could not be opened. Contact the application vendor to verify that this is a valid Windows Installer update package.
 	PATCH_PACKAGE_INVALID 1636
+	/// This update package cannot be processed by the Windows Installer service. You must install a Windows service pack that contains a newer version of the Windows Installer service.
 	PATCH_PACKAGE_UNSUPPORTED 1637
+	/// Another version of this product is already installed. Installation of this version cannot continue. To configure or remove the existing version of this product, use Add/Remove Programs on the Control Panel.
 	PRODUCT_VERSION 1638
+	/// Invalid command line argument. Consult the Windows Installer SDK for detailed command line help.
 	INVALID_COMMAND_LINE 1639
+	/// Only administrators have permission to add, remove, or configure server software during a Terminal services remote session. If you want to install or configure software on the server, contact your network administrator.
 	INSTALL_REMOTE_DISALLOWED 1640
+	/// The requested operation completed successfully. The system will be restarted so the changes can take effect.
 	SUCCESS_REBOOT_INITIATED 1641
+	/// The upgrade cannot be installed by the Windows Installer service because the program to be upgraded may be missing, or the upgrade may update a different version of the program. Verify that the program to be upgraded exists on your computer and that you have the correct upgrade.
 	PATCH_TARGET_NOT_FOUND 1642
+	/// The update package is not permitted by software restriction policy.
 	PATCH_PACKAGE_REJECTED 1643
+	/// One or more customizations are not permitted by software restriction policy.
 	INSTALL_TRANSFORM_REJECTED 1644
+	/// The Windows Installer does not permit installation from a Remote Desktop Connection.
 	INSTALL_REMOTE_PROHIBITED 1645
+	/// Uninstallation of the update package is not supported.
 	PATCH_REMOVAL_UNSUPPORTED 1646
+	/// The update is not applied to this product.
 	UNKNOWN_PATCH 1647
+	/// No valid sequence could be found for the set of updates.
 	PATCH_NO_SEQUENCE 1648
+	/// Update removal was disallowed by policy.
 	PATCH_REMOVAL_DISALLOWED 1649
+	/// The XML update data is invalid.
 	INVALID_PATCH_XML 1650
+	/// Windows Installer does not permit updating of managed advertised products. At least one feature of the product must be installed before applying the update.
 	PATCH_MANAGED_ADVERTISED_PRODUCT 1651
+	/// The Windows Installer service is not accessible in Safe Mode. Please try again when your computer is not in Safe Mode or you can use System Restore to return your machine to a previous good state.
 	INSTALL_SERVICE_SAFEBOOT 1652
+	/// A fail fast exception occurred. Exception handlers will not be invoked and the process will be terminated immediately.
 	FAIL_FAST_EXCEPTION 1653
+	/// The app that you are trying to run is not supported on this version of Windows.
 	INSTALL_REJECTED 1654
+	/// The operation was blocked as the process prohibits dynamic code generation.
 	DYNAMIC_CODE_BLOCKED 1655
+	/// The objects are not identical.
 	NOT_SAME_OBJECT 1656
+	/// The specified image file was blocked from loading because it does not enable a feature required by the process: Control Flow Guard.
 	STRICT_CFG_VIOLATION 1657
+	/// The thread context could not be updated because this has been restricted for the process.
 	SET_CONTEXT_DENIED 1660
+	/// An invalid cross-partition private file/section access was attempted.
 	CROSS_PARTITION_VIOLATION 1661
+	/// The supplied user buffer is not valid for the requested operation.
 	INVALID_USER_BUFFER 1784
+	/// The disk media is not recognized. It may not be formatted.
 	UNRECOGNIZED_MEDIA 1785
+	/// The workstation does not have a trust secret.
 	NO_TRUST_LSA_SECRET 1786
+	/// The security database on the server does not have a computer account for this workstation trust relationship.
 	NO_TRUST_SAM_ACCOUNT 1787
+	/// The trust relationship between the primary domain and the trusted domain failed.
 	TRUSTED_DOMAIN_FAILURE 1788
+	/// The trust relationship between this workstation and the primary domain failed.
 	TRUSTED_RELATIONSHIP_FAILURE 1789
+	/// The network logon failed.
 	TRUST_FAILURE 1790
+	/// An attempt was made to logon, but the network logon service was not started.
 	NETLOGON_NOT_STARTED 1792
+	/// The user's account has expired.
 	ACCOUNT_EXPIRED 1793
+	/// The redirector is in use and cannot be unloaded.
 	REDIRECTOR_HAS_OPEN_HANDLES 1794
+	/// The specified printer driver is already installed.
 	PRINTER_DRIVER_ALREADY_INSTALLED 1795
+	/// The specified port is unknown.
 	UNKNOWN_PORT 1796
+	/// The printer driver is unknown.
 	UNKNOWN_PRINTER_DRIVER 1797
+	/// The print processor is unknown.
 	UNKNOWN_PRINTPROCESSOR 1798
+	/// The specified separator file is invalid.
 	INVALID_SEPARATOR_FILE 1799
+	/// The specified priority is invalid.
 	INVALID_PRIORITY 1800
+	/// The printer name is invalid.
 	INVALID_PRINTER_NAME 1801
+	/// The printer already exists.
 	PRINTER_ALREADY_EXISTS 1802
+	/// The printer command is invalid.
 	INVALID_PRINTER_COMMAND 1803
+	/// The specified datatype is invalid.
 	INVALID_DATATYPE 1804
+	/// The environment specified is invalid.
 	INVALID_ENVIRONMENT 1805
+	/// The account used is an interdomain trust account. Use your global user account or local user account to access this server.
 	NOLOGON_INTERDOMAIN_TRUST_ACCOUNT 1807
+	/// The account used is a computer account. Use your global user account or local user account to access this server.
 	NOLOGON_WORKSTATION_TRUST_ACCOUNT 1808
+	/// The account used is a server trust account. Use your global user account or local user account to access this server.
 	NOLOGON_SERVER_TRUST_ACCOUNT 1809
+	/// The name or security ID (SID) of the domain specified is inconsistent with the trust information for that domain.
 	DOMAIN_TRUST_INCONSISTENT 1810
+	/// The server is in use and cannot be unloaded.
 	SERVER_HAS_OPEN_HANDLES 1811
+	/// The specified image file did not contain a resource section.
 	RESOURCE_DATA_NOT_FOUND 1812
+	/// The specified resource type cannot be found in the image file.
 	RESOURCE_TYPE_NOT_FOUND 1813
+	/// The specified resource name cannot be found in the image file.
 	RESOURCE_NAME_NOT_FOUND 1814
+	/// The specified resource language ID cannot be found in the image file.
 	RESOURCE_LANG_NOT_FOUND 1815
+	/// Not enough quota is available to process this command.
 	NOT_ENOUGH_QUOTA 1816
+	/// The specified time is invalid.
 	INVALID_TIME 1901
+	/// The specified form name is invalid.
 	INVALID_FORM_NAME 1902
+	/// The specified form size is invalid.
 	INVALID_FORM_SIZE 1903
+	/// The specified printer handle is already being waited on.
 	ALREADY_WAITING 1904
+	/// The specified printer has been deleted.
 	PRINTER_DELETED 1905
+	/// The state of the printer is invalid.
 	INVALID_PRINTER_STATE 1906
+	/// The user's password must be changed before signing in.
 	PASSWORD_MUST_CHANGE 1907
+	/// Could not find the domain controller for this domain.
 	DOMAIN_CONTROLLER_NOT_FOUND 1908
+	/// The referenced account is currently locked out and may not be logged on to.
 	ACCOUNT_LOCKED_OUT 1909
+	/// The site name is unknown.
 	NO_SITENAME 1919
+	/// The file cannot be accessed by the system.
 	CANT_ACCESS_FILE 1920
+	/// The name of the file cannot be resolved by the system.
 	CANT_RESOLVE_FILENAME 1921
+	/// A kernel mode driver was blocked from loading.
 	KM_DRIVER_BLOCKED 1930
+	/// The context has expired and can no longer be used.
 	CONTEXT_EXPIRED 1931
+	/// The current user has reached the quota limit for trusts they can create.
 	PER_USER_TRUST_QUOTA_EXCEEDED 1932
+	/// The current user has reached the total quota limit for trusts that can be created.
 	ALL_USER_TRUST_QUOTA_EXCEEDED 1933
+	/// The current user has reached the quota limit for deleting trusts.
 	USER_DELETE_TRUST_QUOTA_EXCEEDED 1934
+	/// The computer you are signing into is protected by an authentication firewall. The specified account is not allowed to authenticate to the computer.
 	AUTHENTICATION_FIREWALL_FAILED 1935
+	/// Remote connections to the Print Spooler are blocked by a policy set on your machine.
 	REMOTE_PRINT_CONNECTIONS_BLOCKED 1936
+	/// Authentication failed because NTLM authentication has been disabled.
 	NTLM_BLOCKED 1937
+	/// Logon failure: EAS policy requires that the user change their password before this operation can be performed.
 	PASSWORD_CHANGE_REQUIRED 1938
+	/// An administrator has restricted sign in. To sign in, make sure your device is connected to the Internet, and have your administrator sign in first.
 	LOST_MODE_LOGON_RESTRICTION 1939
+	/// The pixel format is invalid.
 	INVALID_PIXEL_FORMAT 2000
+	/// The specified driver is invalid.
 	BAD_DRIVER 2001
+	/// The window style or class attribute is invalid for this operation.
 	INVALID_WINDOW_STYLE 2002
+	/// The requested metafile operation is not supported.
 	METAFILE_NOT_SUPPORTED 2003
+	/// The requested transformation operation is not supported.
 	TRANSFORM_NOT_SUPPORTED 2004
+	/// The requested clipping operation is not supported.
 	CLIPPING_NOT_SUPPORTED 2005
+	/// The specified color management module is invalid.
 	INVALID_CMM 2010
+	/// The specified color profile is invalid.
 	INVALID_PROFILE 2011
+	/// The specified tag was not found.
 	TAG_NOT_FOUND 2012
+	/// A required tag is not present.
 	TAG_NOT_PRESENT 2013
+	/// The specified tag is already present.
 	DUPLICATE_TAG 2014
+	/// The specified color profile is not associated with the specified device.
 	PROFILE_NOT_ASSOCIATED_WITH_DEVICE 2015
+	/// The specified color profile was not found.
 	PROFILE_NOT_FOUND 2016
+	/// The specified color space is invalid.
 	INVALID_COLORSPACE 2017
+	/// Image Color Management is not enabled.
 	ICM_NOT_ENABLED 2018
+	/// There was an error while deleting the color transform.
 	DELETING_ICM_XFORM 2019
+	/// The specified color transform is invalid.
 	INVALID_TRANSFORM 2020
+	/// The specified transform does not match the bitmap's color space.
 	COLORSPACE_MISMATCH 2021
+	/// The specified named color index is not present in the profile.
 	INVALID_COLORINDEX 2022
+	/// The specified profile is intended for a device of a different type than the specified device.
 	PROFILE_DOES_NOT_MATCH_DEVICE 2023
+	/// The network connection was made successfully, but the user had to be prompted for a password other than the one originally specified.
 	CONNECTED_OTHER_PASSWORD 2108
+	/// The network connection was made successfully using default credentials.
 	CONNECTED_OTHER_PASSWORD_DEFAULT 2109
+	/// The specified username is invalid.
 	BAD_USERNAME 2202
+	/// This network connection does not exist.
 	NOT_CONNECTED 2250
+	/// This network connection has files open or requests pending.
 	OPEN_FILES 2401
+	/// Active connections still exist.
 	ACTIVE_CONNECTIONS 2402
+	/// The device is in use by an active process and cannot be disconnected.
 	DEVICE_IN_USE 2404
+	/// The specified print monitor is unknown.
 	UNKNOWN_PRINT_MONITOR 3000
+	/// The specified printer driver is currently in use.
 	PRINTER_DRIVER_IN_USE 3001
+	/// The spool file was not found.
 	SPOOL_FILE_NOT_FOUND 3002
+	/// A StartDocPrinter call was not issued.
 	SPL_NO_STARTDOC 3003
+	/// An AddJob call was not issued.
 	SPL_NO_ADDJOB 3004
+	/// The specified print processor has already been installed.
 	PRINT_PROCESSOR_ALREADY_INSTALLED 3005
+	/// The specified print monitor has already been installed.
 	PRINT_MONITOR_ALREADY_INSTALLED 3006
+	/// The specified print monitor does not have the required functions.
 	INVALID_PRINT_MONITOR 3007
+	/// The specified print monitor is currently in use.
 	PRINT_MONITOR_IN_USE 3008
+	/// The requested operation is not allowed when there are jobs queued to the printer.
 	PRINTER_HAS_JOBS_QUEUED 3009
+	/// The requested operation is successful. Changes will not be effective until the system is rebooted.
 	SUCCESS_REBOOT_REQUIRED 3010
+	/// The requested operation is successful. Changes will not be effective until the service is restarted.
 	SUCCESS_RESTART_REQUIRED 3011
+	/// No printers were found.
 	PRINTER_NOT_FOUND 3012
+	/// The printer driver is known to be unreliable.
 	PRINTER_DRIVER_WARNED 3013
+	/// The printer driver is known to harm the system.
 	PRINTER_DRIVER_BLOCKED 3014
+	/// The specified printer driver package is currently in use.
 	PRINTER_DRIVER_PACKAGE_IN_USE 3015
+	/// Unable to find a core driver package that is required by the printer driver package.
 	CORE_DRIVER_PACKAGE_NOT_FOUND 3016
+	/// The requested operation failed. A system reboot is required to roll back changes made.
 	FAIL_REBOOT_REQUIRED 3017
+	/// The requested operation failed. A system reboot has been initiated to roll back changes made.
 	FAIL_REBOOT_INITIATED 3018
+	/// The specified printer driver was not found on the system and needs to be downloaded.
 	PRINTER_DRIVER_DOWNLOAD_NEEDED 3019
+	/// The requested print job has failed to print. A print system update requires the job to be resubmitted.
 	PRINT_JOB_RESTART_REQUIRED 3020
+	/// The printer driver does not contain a valid manifest, or contains too many manifests.
 	INVALID_PRINTER_DRIVER_MANIFEST 3021
+	/// The specified printer cannot be shared.
 	PRINTER_NOT_SHAREABLE 3022
+	/// The operation was paused.
 	REQUEST_PAUSED 3050
+	/// The condition supplied for the app execution request was not satisfied, so the request was not performed.
 	APPEXEC_CONDITION_NOT_SATISFIED 3060
+	/// The supplied handle has been invalidated and may not be used for the requested operation.
 	APPEXEC_HANDLE_INVALIDATED 3061
+	/// The supplied host generation has been invalidated and may not be used for the requested operation.
 	APPEXEC_INVALID_HOST_GENERATION 3062
+	/// An attempt to register a process failed because the target host was not in a valid state to receive process registrations.
 	APPEXEC_UNEXPECTED_PROCESS_REGISTRATION 3063
+	/// The host is not in a valid state to support the execution request.
 	APPEXEC_INVALID_HOST_STATE 3064
+	/// The operation was not completed because a required resource donor was not found for the host.
 	APPEXEC_NO_DONOR 3065
+	/// The operation was not completed because an unexpected host ID was encountered.
 	APPEXEC_HOST_ID_MISMATCH 3066
+	/// The operation was not completed because the specified user was not known to the service.
 	APPEXEC_UNKNOWN_USER 3067
+	/// Reissue the given operation as a cached IO operation.
 	IO_REISSUE_AS_CACHED 3950
+	/// WINS encountered an error while processing the command.
 	WINS_INTERNAL 4000
+	/// The local WINS cannot be deleted.
 	CAN_NOT_DEL_LOCAL_WINS 4001
+	/// The importation from the file failed.
 	STATIC_INIT 4002
+	/// The backup failed. Was a full backup done before?
 	INC_BACKUP 4003
+	/// The backup failed. Check the directory to which you are backing the database.
 	FULL_BACKUP 4004
+	/// The name does not exist in the WINS database.
 	REC_NON_EXISTENT 4005
+	/// Replication with a nonconfigured partner is not allowed.
 	RPL_NOT_ALLOWED 4006
+	/// The DHCP client has obtained an IP address that is already in use on the network. The local interface will be disabled until the DHCP client can obtain a new address.
 	DHCP_ADDRESS_CONFLICT 4100
+	/// The GUID passed was not recognized as valid by a WMI data provider.
 	WMI_GUID_NOT_FOUND 4200
+	/// The instance name passed was not recognized as valid by a WMI data provider.
 	WMI_INSTANCE_NOT_FOUND 4201
+	/// The data item ID passed was not recognized as valid by a WMI data provider.
 	WMI_ITEMID_NOT_FOUND 4202
+	/// The WMI request could not be completed and should be retried.
 	WMI_TRY_AGAIN 4203
+	/// The WMI data provider could not be located.
 	WMI_DP_NOT_FOUND 4204
+	/// The WMI data provider references an instance set that has not been registered.
 	WMI_UNRESOLVED_INSTANCE_REF 4205
+	/// The WMI data block or event notification has already been enabled.
 	WMI_ALREADY_ENABLED 4206
+	/// The WMI data block is no longer available.
 	WMI_GUID_DISCONNECTED 4207
+	/// The WMI data service is not available.
 	WMI_SERVER_UNAVAILABLE 4208
+	/// The WMI data provider failed to carry out the request.
 	WMI_DP_FAILED 4209
+	/// The WMI MOF information is not valid.
 	WMI_INVALID_MOF 4210
+	/// The WMI registration information is not valid.
 	WMI_INVALID_REGINFO 4211
+	/// The WMI data block or event notification has already been disabled.
 	WMI_ALREADY_DISABLED 4212
+	/// The WMI data item or data block is read only.
 	WMI_READ_ONLY 4213
+	/// The WMI data item or data block could not be changed.
 	WMI_SET_FAILURE 4214
+	/// This operation is only valid in the context of an app container.
 	NOT_APPCONTAINER 4250
+	/// This application can only run in the context of an app container.
 	APPCONTAINER_REQUIRED 4251
+	/// This functionality is not supported in the context of an app container.
 	NOT_SUPPORTED_IN_APPCONTAINER 4252
+	/// The length of the SID supplied is not a valid length for app container SIDs.
 	INVALID_PACKAGE_SID_LENGTH 4253
+	/// The media identifier does not represent a valid medium.
 	INVALID_MEDIA 4300
+	/// The library identifier does not represent a valid library.
 	INVALID_LIBRARY 4301
+	/// The media pool identifier does not represent a valid media pool.
 	INVALID_MEDIA_POOL 4302
+	/// The drive and medium are not compatible or exist in different libraries.
 	DRIVE_MEDIA_MISMATCH 4303
+	/// The medium currently exists in an offline library and must be online to perform this operation.
 	MEDIA_OFFLINE 4304
+	/// The operation cannot be performed on an offline library.
 	LIBRARY_OFFLINE 4305
+	/// The library, drive, or media pool is empty.
 	EMPTY 4306
+	/// The library, drive, or media pool must be empty to perform this operation.
 	NOT_EMPTY 4307
+	/// No media is currently available in this media pool or library.
 	MEDIA_UNAVAILABLE 4308
+	/// A resource required for this operation is disabled.
 	RESOURCE_DISABLED 4309
+	/// The media identifier does not represent a valid cleaner.
 	INVALID_CLEANER 4310
+	/// The drive cannot be cleaned or does not support cleaning.
 	UNABLE_TO_CLEAN 4311
+	/// The object identifier does not represent a valid object.
 	OBJECT_NOT_FOUND 4312
+	/// Unable to read from or write to the database.
 	DATABASE_FAILURE 4313
+	/// The database is full.
 	DATABASE_FULL 4314
+	/// The medium is not compatible with the device or media pool.
 	MEDIA_INCOMPATIBLE 4315
+	/// The resource required for this operation does not exist.
 	RESOURCE_NOT_PRESENT 4316
+	/// The operation identifier is not valid.
 	INVALID_OPERATION 4317
+	/// The media is not mounted or ready for use.
 	MEDIA_NOT_AVAILABLE 4318
+	/// The device is not ready for use.
 	DEVICE_NOT_AVAILABLE 4319
+	/// The operator or administrator has refused the request.
 	REQUEST_REFUSED 4320
+	/// The drive identifier does not represent a valid drive.
 	INVALID_DRIVE_OBJECT 4321
+	/// Library is full. No slot is available for use.
 	LIBRARY_FULL 4322
+	/// The transport cannot access the medium.
 	MEDIUM_NOT_ACCESSIBLE 4323
+	/// Unable to load the medium into the drive.
 	UNABLE_TO_LOAD_MEDIUM 4324
+	/// Unable to retrieve the drive status.
 	UNABLE_TO_INVENTORY_DRIVE 4325
+	/// Unable to retrieve the slot status.
 	UNABLE_TO_INVENTORY_SLOT 4326
+	/// Unable to retrieve status about the transport.
 	UNABLE_TO_INVENTORY_TRANSPORT 4327
+	/// Cannot use the transport because it is already in use.
 	TRANSPORT_FULL 4328
+	/// Unable to open or close the inject/eject port.
 	CONTROLLING_IEPORT 4329
+	/// Unable to eject the medium because it is in a drive.
 	UNABLE_TO_EJECT_MOUNTED_MEDIA 4330
+	/// A cleaner slot is already reserved.
 	CLEANER_SLOT_SET 4331
+	/// A cleaner slot is not reserved.
 	CLEANER_SLOT_NOT_SET 4332
+	/// The cleaner cartridge has performed the maximum number of drive cleanings.
 	CLEANER_CARTRIDGE_SPENT 4333
+	/// Unexpected on-medium identifier.
 	UNEXPECTED_OMID 4334
+	/// The last remaining item in this group or resource cannot be deleted.
 	CANT_DELETE_LAST_ITEM 4335
+	/// The message provided exceeds the maximum size allowed for this parameter.
 	MESSAGE_EXCEEDS_MAX_SIZE 4336
+	/// The volume contains system or paging files.
 	VOLUME_CONTAINS_SYS_FILES 4337
+	/// The media type cannot be removed from this library since at least one drive in the library reports it can support this media type.
 	INDIGENOUS_TYPE 4338
+	/// This offline media cannot be mounted on this system since no enabled drives are present which can be used.
 	NO_SUPPORTING_DRIVES 4339
+	/// A cleaner cartridge is present in the tape library.
 	CLEANER_CARTRIDGE_INSTALLED 4340
+	/// Cannot use the inject/eject port because it is not empty.
 	IEPORT_FULL 4341
+	/// This file is currently not available for use on this computer.
 	FILE_OFFLINE 4350
+	/// The remote storage service is not operational at this time.
 	REMOTE_STORAGE_NOT_ACTIVE 4351
+	/// The remote storage service encountered a media error.
 	REMOTE_STORAGE_MEDIA_ERROR 4352
+	/// The file or directory is not a reparse point.
 	NOT_A_REPARSE_POINT 4390
+	/// The file or directory is not a reparse point.
 	REPARSE_ATTRIBUTE_CONFLICT 4391
+	/// The data present in the reparse point buffer is invalid.
 	INVALID_REPARSE_DATA 4392
+	/// The tag present in the reparse point buffer is invalid.
 	REPARSE_TAG_INVALID 4393
+	/// There is a mismatch between the tag specified in the request and the tag present in the reparse point.
 	REPARSE_TAG_MISMATCH 4394
+	/// The object manager encountered a reparse point while retrieving an object.
 	REPARSE_POINT_ENCOUNTERED 4395
+	/// Fast Cache data not found.
 	APP_DATA_NOT_FOUND 4400
+	/// Fast Cache data expired.
 	APP_DATA_EXPIRED 4401
+	/// Fast Cache data corrupt.
 	APP_DATA_CORRUPT 4402
+	/// Fast Cache data has exceeded its max size and cannot be updated.
 	APP_DATA_LIMIT_EXCEEDED 4403
+	/// Fast Cache has been ReArmed and requires a reboot until it can be updated.
 	APP_DATA_REBOOT_REQUIRED 4404
+	/// Secure Boot detected that rollback of protected data has been attempted.
 	SECUREBOOT_ROLLBACK_DETECTED 4420
+	/// The value is protected by Secure Boot policy and cannot be modified or deleted.
 	SECUREBOOT_POLICY_VIOLATION 4421
+	/// The Secure Boot policy is invalid.
 	SECUREBOOT_INVALID_POLICY 4422
+	/// A new Secure Boot policy did not contain the current publisher on its update list.
 	SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND 4423
+	/// The Secure Boot policy is either not signed or is signed by a non-trusted signer.
 	SECUREBOOT_POLICY_NOT_SIGNED 4424
+	/// Secure Boot is not enabled on this machine.
 	SECUREBOOT_NOT_ENABLED 4425
+	/// Secure Boot requires that certain files and drivers are not replaced by other files or drivers.
 	SECUREBOOT_FILE_REPLACED 4426
+	/// The Secure Boot Supplemental Policy file was not authorized on this machine.
 	SECUREBOOT_POLICY_NOT_AUTHORIZED 4427
+	/// The Supplemental Policy is not recognized on this device.
 	SECUREBOOT_POLICY_UNKNOWN 4428
+	/// The Antirollback version was not found in the Secure Boot Policy.
 	SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION 4429
+	/// The Platform ID specified in the Secure Boot policy does not match the Platform ID on this device.
 	SECUREBOOT_PLATFORM_ID_MISMATCH 4430
+	/// The Secure Boot policy file has an older Antirollback Version than this device.
 	SECUREBOOT_POLICY_ROLLBACK_DETECTED 4431
+	/// The Secure Boot policy file does not match the upgraded legacy policy.
 	SECUREBOOT_POLICY_UPGRADE_MISMATCH 4432
+	/// The Secure Boot policy file is required but could not be found.
 	SECUREBOOT_REQUIRED_POLICY_FILE_MISSING 4433
+	/// Supplemental Secure Boot policy file can not be loaded as a base Secure Boot policy.
 	SECUREBOOT_NOT_BASE_POLICY 4434
+	/// Base Secure Boot policy file can not be loaded as a Supplemental Secure Boot policy.
 	SECUREBOOT_NOT_SUPPLEMENTAL_POLICY 4435
+	/// The copy offload read operation is not supported by a filter.
 	OFFLOAD_READ_FLT_NOT_SUPPORTED 4440
+	/// The copy offload write operation is not supported by a filter.
 	OFFLOAD_WRITE_FLT_NOT_SUPPORTED 4441
+	/// The copy offload read operation is not supported for the file.
 	OFFLOAD_READ_FILE_NOT_SUPPORTED 4442
+	/// The copy offload write operation is not supported for the file.
 	OFFLOAD_WRITE_FILE_NOT_SUPPORTED 4443
+	/// This file is currently associated with a different stream id.
 	ALREADY_HAS_STREAM_ID 4444
+	/// The volume must undergo garbage collection.
 	SMR_GARBAGE_COLLECTION_REQUIRED 4445
+	/// The WOF driver encountered a corruption in WIM image's Header.
 	WOF_WIM_HEADER_CORRUPT 4446
+	/// The WOF driver encountered a corruption in WIM image's Resource Table.
 	WOF_WIM_RESOURCE_TABLE_CORRUPT 4447
+	/// The WOF driver encountered a corruption in the compressed file's Resource Table.
 	WOF_FILE_RESOURCE_TABLE_CORRUPT 4448
+	/// Single Instance Storage is not available on this volume.
 	VOLUME_NOT_SIS_ENABLED 4500
+	/// System Integrity detected that policy rollback has been attempted.
 	SYSTEM_INTEGRITY_ROLLBACK_DETECTED 4550
+	/// Your organization used Device Guard to block this app. Contact your support person for more info.
 	SYSTEM_INTEGRITY_POLICY_VIOLATION 4551
+	/// The System Integrity policy is invalid.
 	SYSTEM_INTEGRITY_INVALID_POLICY 4552
+	/// The System Integrity policy is either not signed or is signed by a non-trusted signer.
 	SYSTEM_INTEGRITY_POLICY_NOT_SIGNED 4553
+	/// The number of System Integrity policies is out of limit.
 	SYSTEM_INTEGRITY_TOO_MANY_POLICIES 4554
+	/// The Code Integrity supplemental policy is not authorized by a Code Integrity base policy.
 	SYSTEM_INTEGRITY_SUPPLEMENTAL_POLICY_NOT_AUTHORIZED 4555
+	/// Virtual Secure Mode (VSM) is not initialized. The hypervisor or VSM may not be present or enabled.
 	VSM_NOT_INITIALIZED 4560
+	/// The hypervisor is not protecting DMA because an IOMMU is not present or not enabled in the BIOS.
 	VSM_DMA_PROTECTION_NOT_IN_USE 4561
+	/// The Platform Manifest file was not authorized on this machine.
 	PLATFORM_MANIFEST_NOT_AUTHORIZED 4570
+	/// The Platform Manifest file was not valid.
 	PLATFORM_MANIFEST_INVALID 4571
+	/// The file is not authorized on this platform because an entry was not found in the Platform Manifest.
 	PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED 4572
+	/// The catalog is not authorized on this platform because an entry was not found in the Platform Manifest.
 	PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED 4573
+	/// The file is not authorized on this platform because a Binary ID was not found in the embedded signature.
 	PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND 4574
+	/// No active Platform Manifest exists on this system.
 	PLATFORM_MANIFEST_NOT_ACTIVE 4575
+	/// The Platform Manifest file was not properly signed.
 	PLATFORM_MANIFEST_NOT_SIGNED 4576
+	/// The operation cannot be completed because other resources are dependent on this resource.
 	DEPENDENT_RESOURCE_EXISTS 5001
+	/// The cluster resource dependency cannot be found.
 	DEPENDENCY_NOT_FOUND 5002
+	/// The cluster resource cannot be made dependent on the specified resource because it is already dependent.
 	DEPENDENCY_ALREADY_EXISTS 5003
+	/// The cluster resource is not online.
 	RESOURCE_NOT_ONLINE 5004
+	/// A cluster node is not available for this operation.
 	HOST_NODE_NOT_AVAILABLE 5005
+	/// The cluster resource is not available.
 	RESOURCE_NOT_AVAILABLE 5006
+	/// The cluster resource could not be found.
 	RESOURCE_NOT_FOUND 5007
+	/// The cluster is being shut down.
 	SHUTDOWN_CLUSTER 5008
+	/// A cluster node cannot be evicted from the cluster unless the node is down or it is the last node.
 	CANT_EVICT_ACTIVE_NODE 5009
+	/// The object already exists.
 	OBJECT_ALREADY_EXISTS 5010
+	/// The object is already in the list.
 	OBJECT_IN_LIST 5011
+	/// The cluster group is not available for any new requests.
 	GROUP_NOT_AVAILABLE 5012
+	/// The cluster group could not be found.
 	GROUP_NOT_FOUND 5013
+	/// The operation could not be completed because the cluster group is not online.
 	GROUP_NOT_ONLINE 5014
+	/// The operation failed because either the specified cluster node is not the owner of the resource, or the node is not a possible owner of the resource.
 	HOST_NODE_NOT_RESOURCE_OWNER 5015
+	/// The operation failed because either the specified cluster node is not the owner of the group, or the node is not a possible owner of the group.
 	HOST_NODE_NOT_GROUP_OWNER 5016
+	/// The cluster resource could not be created in the specified resource monitor.
 	RESMON_CREATE_FAILED 5017
+	/// The cluster resource could not be brought online by the resource monitor.
 	RESMON_ONLINE_FAILED 5018
+	/// The operation could not be completed because the cluster resource is online.
 	RESOURCE_ONLINE 5019
+	/// The cluster resource could not be deleted or brought offline because it is the quorum resource.
 	QUORUM_RESOURCE 5020
+	/// The cluster could not make the specified resource a quorum resource because it is not capable of being a quorum resource.
 	NOT_QUORUM_CAPABLE 5021
+	/// The cluster software is shutting down.
 	CLUSTER_SHUTTING_DOWN 5022
+	/// The group or resource is not in the correct state to perform the requested operation.
 	INVALID_STATE 5023
+	/// The properties were stored but not all changes will take effect until the next time the resource is brought online.
 	RESOURCE_PROPERTIES_STORED 5024
+	/// The cluster could not make the specified resource a quorum resource because it does not belong to a shared storage class.
 	NOT_QUORUM_CLASS 5025
+	/// The cluster resource could not be deleted since it is a core resource.
 	CORE_RESOURCE 5026
+	/// The quorum resource failed to come online.
 	QUORUM_RESOURCE_ONLINE_FAILED 5027
+	/// The quorum log could not be created or mounted successfully.
 	QUORUMLOG_OPEN_FAILED 5028
+	/// The cluster log is corrupt.
 	CLUSTERLOG_CORRUPT 5029
+	/// The record could not be written to the cluster log since it exceeds the maximum size.
 	CLUSTERLOG_RECORD_EXCEEDS_MAXSIZE 5030
+	/// The cluster log exceeds its maximum size.
 	CLUSTERLOG_EXCEEDS_MAXSIZE 5031
+	/// No checkpoint record was found in the cluster log.
 	CLUSTERLOG_CHKPOINT_NOT_FOUND 5032
+	/// The minimum required disk space needed for logging is not available.
 	CLUSTERLOG_NOT_ENOUGH_SPACE 5033
+	/// The cluster node failed to take control of the quorum resource because the resource is owned by another active node.
 	QUORUM_OWNER_ALIVE 5034
+	/// A cluster network is not available for this operation.
 	NETWORK_NOT_AVAILABLE 5035
+	/// A cluster node is not available for this operation.
 	NODE_NOT_AVAILABLE 5036
+	/// All cluster nodes must be running to perform this operation.
 	ALL_NODES_NOT_AVAILABLE 5037
+	/// A cluster resource failed.
 	RESOURCE_FAILED 5038
+	/// The cluster node is not valid.
 	CLUSTER_INVALID_NODE 5039
+	/// The cluster node already exists.
 	CLUSTER_NODE_EXISTS 5040
+	/// A node is in the process of joining the cluster.
 	CLUSTER_JOIN_IN_PROGRESS 5041
+	/// The cluster node was not found.
 	CLUSTER_NODE_NOT_FOUND 5042
+	/// The cluster local node information was not found.
 	CLUSTER_LOCAL_NODE_NOT_FOUND 5043
+	/// The cluster network already exists.
 	CLUSTER_NETWORK_EXISTS 5044
+	/// The cluster network was not found.
 	CLUSTER_NETWORK_NOT_FOUND 5045
+	/// The cluster network interface already exists.
 	CLUSTER_NETINTERFACE_EXISTS 5046
+	/// The cluster network interface was not found.
 	CLUSTER_NETINTERFACE_NOT_FOUND 5047
+	/// The cluster request is not valid for this object.
 	CLUSTER_INVALID_REQUEST 5048
+	/// The cluster network provider is not valid.
 	CLUSTER_INVALID_NETWORK_PROVIDER 5049
+	/// The cluster node is down.
 	CLUSTER_NODE_DOWN 5050
+	/// The cluster node is not reachable.
 	CLUSTER_NODE_UNREACHABLE 5051
+	/// The cluster node is not a member of the cluster.
 	CLUSTER_NODE_NOT_MEMBER 5052
+	/// A cluster join operation is not in progress.
 	CLUSTER_JOIN_NOT_IN_PROGRESS 5053
+	/// The cluster network is not valid.
 	CLUSTER_INVALID_NETWORK 5054
+	/// The cluster node is up.
 	CLUSTER_NODE_UP 5056
+	/// The cluster IP address is already in use.
 	CLUSTER_IPADDR_IN_USE 5057
+	/// The cluster node is not paused.
 	CLUSTER_NODE_NOT_PAUSED 5058
+	/// No cluster security context is available.
 	CLUSTER_NO_SECURITY_CONTEXT 5059
+	/// The cluster network is not configured for internal cluster communication.
 	CLUSTER_NETWORK_NOT_INTERNAL 5060
+	/// The cluster node is already up.
 	CLUSTER_NODE_ALREADY_UP 5061
+	/// The cluster node is already down.
 	CLUSTER_NODE_ALREADY_DOWN 5062
+	/// The cluster network is already online.
 	CLUSTER_NETWORK_ALREADY_ONLINE 5063
+	/// The cluster network is already offline.
 	CLUSTER_NETWORK_ALREADY_OFFLINE 5064
+	/// The cluster node is already a member of the cluster.
 	CLUSTER_NODE_ALREADY_MEMBER 5065
+	/// The cluster network is the only one configured for internal cluster communication between two or more active cluster nodes. The internal communication capability cannot be removed from the network.
 	CLUSTER_LAST_INTERNAL_NETWORK 5066
+	/// One or more cluster resources depend on the network to provide service to clients. The client access capability cannot be removed from the network.
 	CLUSTER_NETWORK_HAS_DEPENDENTS 5067
+	/// This operation cannot currently be performed on the cluster group containing the quorum resource.
 	INVALID_OPERATION_ON_QUORUM 5068
+	/// The cluster quorum resource is not allowed to have any dependencies.
 	DEPENDENCY_NOT_ALLOWED 5069
+	/// The cluster node is paused.
 	CLUSTER_NODE_PAUSED 5070
+	/// The cluster resource cannot be brought online. The owner node cannot run this resource.
 	NODE_CANT_HOST_RESOURCE 5071
+	/// The cluster node is not ready to perform the requested operation.
 	CLUSTER_NODE_NOT_READY 5072
+	/// The cluster node is shutting down.
 	CLUSTER_NODE_SHUTTING_DOWN 5073
+	/// The cluster join operation was aborted.
 	CLUSTER_JOIN_ABORTED 5074
+	/// The node failed to join the cluster because the joining node and other nodes in the cluster have incompatible operating system versions.
 	CLUSTER_INCOMPATIBLE_VERSIONS 5075
+	/// This resource cannot be created because the cluster has reached the limit on the number of resources it can monitor.
 	CLUSTER_MAXNUM_OF_RESOURCES_EXCEEDED 5076
+	/// The system configuration changed during the cluster join or form operation. The join or form operation was aborted.
 	CLUSTER_SYSTEM_CONFIG_CHANGED 5077
+	/// The specified resource type was not found.
 	CLUSTER_RESOURCE_TYPE_NOT_FOUND 5078
+	/// The specified node does not support a resource of this type. This may be due to version inconsistencies or due to the absence of the resource DLL on this node.
 	CLUSTER_RESTYPE_NOT_SUPPORTED 5079
+	/// The specified resource name is not supported by this resource DLL. This may be due to a bad (or changed) name supplied to the resource DLL.
 	CLUSTER_RESNAME_NOT_FOUND 5080
+	/// No authentication package could be registered with the RPC server.
 	CLUSTER_NO_RPC_PACKAGES_REGISTERED 5081
+	/// You cannot bring the group online because the owner of the group is not in the preferred list for the group. To change the owner node for the group, move the group.
 	CLUSTER_OWNER_NOT_IN_PREFLIST 5082
+	/// The join operation failed because the cluster database sequence number has changed or is incompatible with the locker node. This may happen during a join operation if the cluster database was changing during the join.
 	CLUSTER_DATABASE_SEQMISMATCH 5083
+	/// The resource monitor will not allow the fail operation to be performed while the resource is in its current state. This may happen if the resource is in a pending state.
 	RESMON_INVALID_STATE 5084
+	/// A non locker code got a request to reserve the lock for making global updates.
 	CLUSTER_GUM_NOT_LOCKER 5085
+	/// The quorum disk could not be located by the cluster service.
 	QUORUM_DISK_NOT_FOUND 5086
+	/// The backed up cluster database is possibly corrupt.
 	DATABASE_BACKUP_CORRUPT 5087
+	/// A DFS root already exists in this cluster node.
 	CLUSTER_NODE_ALREADY_HAS_DFS_ROOT 5088
+	/// An attempt to modify a resource property failed because it conflicts with another existing property.
 	RESOURCE_PROPERTY_UNCHANGEABLE 5089
+	/// This operation is not supported on a cluster without an Administrator Access Point.
 	NO_ADMIN_ACCESS_POINT 5090
+	/// An operation was attempted that is incompatible with the current membership state of the node.
 	CLUSTER_MEMBERSHIP_INVALID_STATE 5890
+	/// The quorum resource does not contain the quorum log.
 	CLUSTER_QUORUMLOG_NOT_FOUND 5891
+	/// The membership engine requested shutdown of the cluster service on this node.
 	CLUSTER_MEMBERSHIP_HALT 5892
+	/// The join operation failed because the cluster instance ID of the joining node does not match the cluster instance ID of the sponsor node.
 	CLUSTER_INSTANCE_ID_MISMATCH 5893
+	/// A matching cluster network for the specified IP address could not be found.
 	CLUSTER_NETWORK_NOT_FOUND_FOR_IP 5894
+	/// The actual data type of the property did not match the expected data type of the property.
 	CLUSTER_PROPERTY_DATA_TYPE_MISMATCH 5895
+	/// The cluster node was evicted from the cluster successfully, but the node was not cleaned up. To determine what cleanup steps failed and how to recover, see the Failover Clustering application event log using Event Viewer.
 	CLUSTER_EVICT_WITHOUT_CLEANUP 5896
+	/// Two or more parameter values specified for a resource's properties are in conflict.
 	CLUSTER_PARAMETER_MISMATCH 5897
+	/// This computer cannot be made a member of a cluster.
 	NODE_CANNOT_BE_CLUSTERED 5898
+	/// This computer cannot be made a member of a cluster because it does not have the correct version of Windows installed.
 	CLUSTER_WRONG_OS_VERSION 5899
+	/// A cluster cannot be created with the specified cluster name because that cluster name is already in use. Specify a different name for the cluster.
 	CLUSTER_CANT_CREATE_DUP_CLUSTER_NAME 5900
+	/// The cluster configuration action has already been committed.
 	CLUSCFG_ALREADY_COMMITTED 5901
+	/// The cluster configuration action could not be rolled back.
 	CLUSCFG_ROLLBACK_FAILED 5902
+	/// The drive letter assigned to a system disk on one node conflicted with the drive letter assigned to a disk on another node.
 	CLUSCFG_SYSTEM_DISK_DRIVE_LETTER_CONFLICT 5903
+	/// One or more nodes in the cluster are running a version of Windows that does not support this operation.
 	CLUSTER_OLD_VERSION 5904
+	/// The name of the corresponding computer account doesn't match the Network Name for this resource.
 	CLUSTER_MISMATCHED_COMPUTER_ACCT_NAME 5905
+	/// No network adapters are available.
 	CLUSTER_NO_NET_ADAPTERS 5906
+	/// The cluster node has been poisoned.
 	CLUSTER_POISONED 5907
+	/// The group is unable to accept the request since it is moving to another node.
 	CLUSTER_GROUP_MOVING 5908
+	/// The resource type cannot accept the request since is too busy performing another operation.
 	CLUSTER_RESOURCE_TYPE_BUSY 5909
+	/// The call to the cluster resource DLL timed out.
 	RESOURCE_CALL_TIMED_OUT 5910
+	/// The address is not valid for an IPv6 Address resource. A global IPv6 address is required, and it must match a cluster network. Compatibility addresses are not permitted.
 	INVALID_CLUSTER_IPV6_ADDRESS 5911
+	/// An internal cluster error occurred. A call to an invalid function was attempted.
 	CLUSTER_INTERNAL_INVALID_FUNCTION 5912
+	/// A parameter value is out of acceptable range.
 	CLUSTER_PARAMETER_OUT_OF_BOUNDS 5913
+	/// A network error occurred while sending data to another node in the cluster. The number of bytes transmitted was less than required.
 	CLUSTER_PARTIAL_SEND 5914
+	/// An invalid cluster registry operation was attempted.
 	CLUSTER_REGISTRY_INVALID_FUNCTION 5915
+	/// An input string of characters is not properly terminated.
 	CLUSTER_INVALID_STRING_TERMINATION 5916
+	/// An input string of characters is not in a valid format for the data it represents.
 	CLUSTER_INVALID_STRING_FORMAT 5917
+	/// An internal cluster error occurred. A cluster database transaction was attempted while a transaction was already in progress.
 	CLUSTER_DATABASE_TRANSACTION_IN_PROGRESS 5918
+	/// An internal cluster error occurred. There was an attempt to commit a cluster database transaction while no transaction was in progress.
 	CLUSTER_DATABASE_TRANSACTION_NOT_IN_PROGRESS 5919
+	/// An internal cluster error occurred. Data was not properly initialized.
 	CLUSTER_NULL_DATA 5920
+	/// An error occurred while reading from a stream of data. An unexpected number of bytes was returned.
 	CLUSTER_PARTIAL_READ 5921
+	/// An error occurred while writing to a stream of data. The required number of bytes could not be written.
 	CLUSTER_PARTIAL_WRITE 5922
+	/// An error occurred while deserializing a stream of cluster data.
 	CLUSTER_CANT_DESERIALIZE_DATA 5923
+	/// One or more property values for this resource are in conflict with one or more property values associated with its dependent resource(s).
 	DEPENDENT_RESOURCE_PROPERTY_CONFLICT 5924
+	/// A quorum of cluster nodes was not present to form a cluster.
 	CLUSTER_NO_QUORUM 5925
+	/// The cluster network is not valid for an IPv6 Address resource, or it does not match the configured address.
 	CLUSTER_INVALID_IPV6_NETWORK 5926
+	/// The cluster network is not valid for an IPv6 Tunnel resource. Check the configuration of the IP Address resource on which the IPv6 Tunnel resource depends.
 	CLUSTER_INVALID_IPV6_TUNNEL_NETWORK 5927
+	/// Quorum resource cannot reside in the Available Storage group.
 	QUORUM_NOT_ALLOWED_IN_THIS_GROUP 5928
+	/// The dependencies for this resource are nested too deeply.
 	DEPENDENCY_TREE_TOO_COMPLEX 5929
+	/// The call into the resource DLL raised an unhandled exception.
 	EXCEPTION_IN_RESOURCE_CALL 5930
+	/// The RHS process failed to initialize.
 	CLUSTER_RHS_FAILED_INITIALIZATION 5931
+	/// The Failover Clustering feature is not installed on this node.
 	CLUSTER_NOT_INSTALLED 5932
+	/// The resources must be online on the same node for this operation.
 	CLUSTER_RESOURCES_MUST_BE_ONLINE_ON_THE_SAME_NODE 5933
+	/// A new node can not be added since this cluster is already at its maximum number of nodes.
 	CLUSTER_MAX_NODES_IN_CLUSTER 5934
+	/// This cluster can not be created since the specified number of nodes exceeds the maximum allowed limit.
 	CLUSTER_TOO_MANY_NODES 5935
+	/// An attempt to use the specified cluster name failed because an enabled computer object with the given name already exists in the domain.
 	CLUSTER_OBJECT_ALREADY_USED 5936
+	/// This cluster cannot be destroyed. It has non-core application groups which must be deleted before the cluster can be destroyed.
 	NONCORE_GROUPS_FOUND 5937
+	/// File share associated with file share witness resource cannot be hosted by this cluster or any of its nodes.
 	FILE_SHARE_RESOURCE_CONFLICT 5938
+	/// Eviction of this node is invalid at this time. Due to quorum requirements node eviction will result in cluster shutdown.
 	CLUSTER_EVICT_INVALID_REQUEST 5939
+	/// Only one instance of this resource type is allowed in the cluster.
 	CLUSTER_SINGLETON_RESOURCE 5940
+	/// Only one instance of this resource type is allowed per resource group.
 	CLUSTER_GROUP_SINGLETON_RESOURCE 5941
+	/// The resource failed to come online due to the failure of one or more provider resources.
 	CLUSTER_RESOURCE_PROVIDER_FAILED 5942
+	/// The resource has indicated that it cannot come online on any node.
 	CLUSTER_RESOURCE_CONFIGURATION_ERROR 5943
+	/// The current operation cannot be performed on this group at this time.
 	CLUSTER_GROUP_BUSY 5944
+	/// The directory or file is not located on a cluster shared volume.
 	CLUSTER_NOT_SHARED_VOLUME 5945
+	/// The Security Descriptor does not meet the requirements for a cluster.
 	CLUSTER_INVALID_SECURITY_DESCRIPTOR 5946
+	/// There is one or more shared volumes resources configured in the cluster. Those resources must be moved to available storage in order for operation to succeed.
 	CLUSTER_SHARED_VOLUMES_IN_USE 5947
+	/// This group or resource cannot be directly manipulated. Use shared volume APIs to perform desired operation.
 	CLUSTER_USE_SHARED_VOLUMES_API 5948
+	/// Back up is in progress. Please wait for backup completion before trying this operation again.
 	CLUSTER_BACKUP_IN_PROGRESS 5949
+	/// The path does not belong to a cluster shared volume.
 	NON_CSV_PATH 5950
+	/// The cluster shared volume is not locally mounted on this node.
 	CSV_VOLUME_NOT_LOCAL 5951
+	/// The cluster watchdog is terminating.
 	CLUSTER_WATCHDOG_TERMINATING 5952
+	/// A resource vetoed a move between two nodes because they are incompatible.
 	CLUSTER_RESOURCE_VETOED_MOVE_INCOMPATIBLE_NODES 5953
+	/// The request is invalid either because node weight cannot be changed while the cluster is in disk-only quorum mode, or because changing the node weight would violate the minimum cluster quorum requirements.
 	CLUSTER_INVALID_NODE_WEIGHT 5954
+	/// The resource vetoed the call.
 	CLUSTER_RESOURCE_VETOED_CALL 5955
+	/// Resource could not start or run because it could not reserve sufficient system resources.
 	RESMON_SYSTEM_RESOURCES_LACKING 5956
+	/// A resource vetoed a move between two nodes because the destination currently does not have enough resources to complete the operation.
 	CLUSTER_RESOURCE_VETOED_MOVE_NOT_ENOUGH_RESOURCES_ON_DESTINATION 5957
+	/// A resource vetoed a move between two nodes because the source currently does not have enough resources to complete the operation.
 	CLUSTER_RESOURCE_VETOED_MOVE_NOT_ENOUGH_RESOURCES_ON_SOURCE 5958
+	/// The requested operation can not be completed because the group is queued for an operation.
 	CLUSTER_GROUP_QUEUED 5959
+	/// The requested operation can not be completed because a resource has locked status.
 	CLUSTER_RESOURCE_LOCKED_STATUS 5960
+	/// The resource cannot move to another node because a cluster shared volume vetoed the operation.
 	CLUSTER_SHARED_VOLUME_FAILOVER_NOT_ALLOWED 5961
+	/// A node drain is already in progress.
 	CLUSTER_NODE_DRAIN_IN_PROGRESS 5962
+	/// Clustered storage is not connected to the node.
 	CLUSTER_DISK_NOT_CONNECTED 5963
+	/// The disk is not configured in a way to be used with CSV. CSV disks must have at least one partition that is formatted with NTFS or REFS.
 	DISK_NOT_CSV_CAPABLE 5964
+	/// The resource must be part of the Available Storage group to complete this action.
 	RESOURCE_NOT_IN_AVAILABLE_STORAGE 5965
+	/// CSVFS failed operation as volume is in redirected mode.
 	CLUSTER_SHARED_VOLUME_REDIRECTED 5966
+	/// CSVFS failed operation as volume is not in redirected mode.
 	CLUSTER_SHARED_VOLUME_NOT_REDIRECTED 5967
+	/// Cluster properties cannot be returned at this time.
 	CLUSTER_CANNOT_RETURN_PROPERTIES 5968
+	/// The clustered disk resource contains software snapshot diff area that are not supported for Cluster Shared Volumes.
 	CLUSTER_RESOURCE_CONTAINS_UNSUPPORTED_DIFF_AREA_FOR_SHARED_VOLUMES 5969
+	/// The operation cannot be completed because the resource is in maintenance mode.
 	CLUSTER_RESOURCE_IS_IN_MAINTENANCE_MODE 5970
+	/// The operation cannot be completed because of cluster affinity conflicts.
 	CLUSTER_AFFINITY_CONFLICT 5971
+	/// The operation cannot be completed because the resource is a replica virtual machine.
 	CLUSTER_RESOURCE_IS_REPLICA_VIRTUAL_MACHINE 5972
+	/// The Cluster Functional Level could not be increased because not all nodes in the cluster support the updated version.
 	CLUSTER_UPGRADE_INCOMPATIBLE_VERSIONS 5973
+	/// Updating the cluster functional level failed because the cluster is running in fix quorum mode. Start additional nodes which are members of the cluster until the cluster reaches quorum and the cluster will automatically switch out of fix quorum mode, or stop and restart the cluster without the FixQuorum switch. Once the cluster is out of fix quorum mode retry the Update-ClusterFunctionalLevel PowerShell cmdlet to update the cluster functional level.
 	CLUSTER_UPGRADE_FIX_QUORUM_NOT_SUPPORTED 5974
+	/// The cluster functional level has been successfully updated but not all features are available yet. Restart the cluster by using the Stop-Cluster PowerShell cmdlet followed by the Start-Cluster PowerShell cmdlet and all cluster features will be available.
 	CLUSTER_UPGRADE_RESTART_REQUIRED 5975
+	/// The cluster is currently performing a version upgrade.
 	CLUSTER_UPGRADE_IN_PROGRESS 5976
+	/// The cluster did not successfully complete the version upgrade.
 	CLUSTER_UPGRADE_INCOMPLETE 5977
+	/// The cluster node is in grace period.
 	CLUSTER_NODE_IN_GRACE_PERIOD 5978
+	/// The operation has failed because CSV volume was not able to recover in time specified on this file object.
 	CLUSTER_CSV_IO_PAUSE_TIMEOUT 5979
+	/// The operation failed because the requested node is not currently part of active cluster membership.
 	NODE_NOT_ACTIVE_CLUSTER_MEMBER 5980
+	/// The operation failed because the requested cluster resource is currently unmonitored.
 	CLUSTER_RESOURCE_NOT_MONITORED 5981
+	/// The operation failed because a resource does not support running in an unmonitored state.
 	CLUSTER_RESOURCE_DOES_NOT_SUPPORT_UNMONITORED 5982
+	/// The operation cannot be completed because a resource participates in replication.
 	CLUSTER_RESOURCE_IS_REPLICATED 5983
+	/// The operation failed because the requested cluster node has been isolated.
 	CLUSTER_NODE_ISOLATED 5984
+	/// The operation failed because the requested cluster node has been quarantined.
 	CLUSTER_NODE_QUARANTINED 5985
+	/// The operation failed because the specified database update condition was not met.
 	CLUSTER_DATABASE_UPDATE_CONDITION_FAILED 5986
+	/// A clustered space is in a degraded condition and the requested action cannot be completed at this time.
 	CLUSTER_SPACE_DEGRADED 5987
+	/// The operation failed because token delegation for this control is not supported.
 	CLUSTER_TOKEN_DELEGATION_NOT_SUPPORTED 5988
+	/// The operation has failed because CSV has invalidated this file object.
 	CLUSTER_CSV_INVALID_HANDLE 5989
+	/// This operation is supported only on the CSV coordinator node.
 	CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR 5990
+	/// The cluster group set is not available for any further requests.
 	GROUPSET_NOT_AVAILABLE 5991
+	/// The cluster group set could not be found.
 	GROUPSET_NOT_FOUND 5992
+	/// The action cannot be completed at this time because the cluster group set would fall below quorum and not be able to act as a provider.
 	GROUPSET_CANT_PROVIDE 5993
+	/// The specified parent fault domain is not found.
 	CLUSTER_FAULT_DOMAIN_PARENT_NOT_FOUND 5994
+	/// The fault domain cannot be a child of the parent specified.
 	CLUSTER_FAULT_DOMAIN_INVALID_HIERARCHY 5995
+	/// Storage Spaces Direct has rejected the proposed fault domain changes because it impacts the fault tolerance of the storage.
 	CLUSTER_FAULT_DOMAIN_FAILED_S2D_VALIDATION 5996
+	/// Storage Spaces Direct has rejected the proposed fault domain changes because it reduces the storage connected to the system.
 	CLUSTER_FAULT_DOMAIN_S2D_CONNECTIVITY_LOSS 5997
+	/// Cluster infrastructure file server creation failed because a valid non-empty file server name was not provided.
 	CLUSTER_INVALID_INFRASTRUCTURE_FILESERVER_NAME 5998
+	/// The action cannot be completed because the cluster set management cluster is unreachable.
 	CLUSTERSET_MANAGEMENT_CLUSTER_UNREACHABLE 5999
+	/// The specified file could not be encrypted.
 	ENCRYPTION_FAILED 6000
+	/// The specified file could not be decrypted.
 	DECRYPTION_FAILED 6001
+	/// The specified file is encrypted and the user does not have the ability to decrypt it.
 	FILE_ENCRYPTED 6002
+	/// There is no valid encryption recovery policy configured for this system.
 	NO_RECOVERY_POLICY 6003
+	/// The required encryption driver is not loaded for this system.
 	NO_EFS 6004
+	/// The file was encrypted with a different encryption driver than is currently loaded.
 	WRONG_EFS 6005
+	/// There are no EFS keys defined for the user.
 	NO_USER_KEYS 6006
+	/// The specified file is not encrypted.
 	FILE_NOT_ENCRYPTED 6007
+	/// The specified file is not in the defined EFS export format.
 	NOT_EXPORT_FORMAT 6008
+	/// The specified file is read only.
 	FILE_READ_ONLY 6009
+	/// The directory has been disabled for encryption.
 	DIR_EFS_DISALLOWED 6010
+	/// The server is not trusted for remote encryption operation.
 	EFS_SERVER_NOT_TRUSTED 6011
+	/// Recovery policy configured for this system contains invalid recovery certificate.
 	BAD_RECOVERY_POLICY 6012
+	/// The encryption algorithm used on the source file needs a bigger key buffer than the one on the destination file.
 	EFS_ALG_BLOB_TOO_BIG 6013
+	/// The disk partition does not support file encryption.
 	VOLUME_NOT_SUPPORT_EFS 6014
+	/// This machine is disabled for file encryption.
 	EFS_DISABLED 6015
+	/// A newer system is required to decrypt this encrypted file.
 	EFS_VERSION_NOT_SUPPORT 6016
+	/// The remote server sent an invalid response for a file being opened with Client Side Encryption.
 	CS_ENCRYPTION_INVALID_SERVER_RESPONSE 6017
+	/// Client Side Encryption is not supported by the remote server even though it claims to support it.
 	CS_ENCRYPTION_UNSUPPORTED_SERVER 6018
+	/// File is encrypted and should be opened in Client Side Encryption mode.
 	CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE 6019
+	/// A new encrypted file is being created and a $EFS needs to be provided.
 	CS_ENCRYPTION_NEW_ENCRYPTED_FILE 6020
+	/// The SMB client requested a CSE FSCTL on a non-CSE file.
 	CS_ENCRYPTION_FILE_NOT_CSE 6021
+	/// The requested operation was blocked by policy. For more information, contact your system administrator.
 	ENCRYPTION_POLICY_DENIES_OPERATION 6022
+	/// The specified file could not be encrypted with Windows Information Protection.
 	WIP_ENCRYPTION_FAILED 6023
+	/// The list of servers for this workgroup is not currently available.
 	NO_BROWSER_SERVERS_FOUND 6118
+	/// Log service encountered an invalid log sector.
 	LOG_SECTOR_INVALID 6600
+	/// Log service encountered a log sector with invalid block parity.
 	LOG_SECTOR_PARITY_INVALID 6601
+	/// Log service encountered a remapped log sector.
 	LOG_SECTOR_REMAPPED 6602
+	/// Log service encountered a partial or incomplete log block.
 	LOG_BLOCK_INCOMPLETE 6603
+	/// Log service encountered an attempt access data outside the active log range.
 	LOG_INVALID_RANGE 6604
+	/// Log service user marshalling buffers are exhausted.
 	LOG_BLOCKS_EXHAUSTED 6605
+	/// Log service encountered an attempt read from a marshalling area with an invalid read context.
 	LOG_READ_CONTEXT_INVALID 6606
+	/// Log service encountered an invalid log restart area.
 	LOG_RESTART_INVALID 6607
+	/// Log service encountered an invalid log block version.
 	LOG_BLOCK_VERSION 6608
+	/// Log service encountered an invalid log block.
 	LOG_BLOCK_INVALID 6609
+	/// Log service encountered an attempt to read the log with an invalid read mode.
 	LOG_READ_MODE_INVALID 6610
+	/// Log service encountered a log stream with no restart area.
 	LOG_NO_RESTART 6611
+	/// Log service encountered a corrupted metadata file.
 	LOG_METADATA_CORRUPT 6612
+	/// Log service encountered a metadata file that could not be created by the log file system.
 	LOG_METADATA_INVALID 6613
+	/// Log service encountered a metadata file with inconsistent data.
 	LOG_METADATA_INCONSISTENT 6614
+	/// Log service encountered an attempt to erroneous allocate or dispose reservation space.
 	LOG_RESERVATION_INVALID 6615
+	/// Log service cannot delete log file or file system container.
 	LOG_CANT_DELETE 6616
+	/// Log service has reached the maximum allowable containers allocated to a log file.
 	LOG_CONTAINER_LIMIT_EXCEEDED 6617
+	/// Log service has attempted to read or write backward past the start of the log.
 	LOG_START_OF_LOG 6618
+	/// Log policy could not be installed because a policy of the same type is already present.
 	LOG_POLICY_ALREADY_INSTALLED 6619
+	/// Log policy in question was not installed at the time of the request.
 	LOG_POLICY_NOT_INSTALLED 6620
+	/// The installed set of policies on the log is invalid.
 	LOG_POLICY_INVALID 6621
+	/// A policy on the log in question prevented the operation from completing.
 	LOG_POLICY_CONFLICT 6622
+	/// Log space cannot be reclaimed because the log is pinned by the archive tail.
 	LOG_PINNED_ARCHIVE_TAIL 6623
+	/// Log record is not a record in the log file.
 	LOG_RECORD_NONEXISTENT 6624
+	/// Number of reserved log records or the adjustment of the number of reserved log records is invalid.
 	LOG_RECORDS_RESERVED_INVALID 6625
+	/// Reserved log space or the adjustment of the log space is invalid.
 	LOG_SPACE_RESERVED_INVALID 6626
+	/// An new or existing archive tail or base of the active log is invalid.
 	LOG_TAIL_INVALID 6627
+	/// Log space is exhausted.
 	LOG_FULL 6628
+	/// The log could not be set to the requested size.
 	COULD_NOT_RESIZE_LOG 6629
+	/// Log is multiplexed, no direct writes to the physical log is allowed.
 	LOG_MULTIPLEXED 6630
+	/// The operation failed because the log is a dedicated log.
 	LOG_DEDICATED 6631
+	/// The operation requires an archive context.
 	LOG_ARCHIVE_NOT_IN_PROGRESS 6632
+	/// Log archival is in progress.
 	LOG_ARCHIVE_IN_PROGRESS 6633
+	/// The operation requires a non-ephemeral log, but the log is ephemeral.
 	LOG_EPHEMERAL 6634
+	/// The log must have at least two containers before it can be read from or written to.
 	LOG_NOT_ENOUGH_CONTAINERS 6635
+	/// A log client has already registered on the stream.
 	LOG_CLIENT_ALREADY_REGISTERED 6636
+	/// A log client has not been registered on the stream.
 	LOG_CLIENT_NOT_REGISTERED 6637
+	/// A request has already been made to handle the log full condition.
 	LOG_FULL_HANDLER_IN_PROGRESS 6638
+	/// Log service encountered an error when attempting to read from a log container.
 	LOG_CONTAINER_READ_FAILED 6639
+	/// Log service encountered an error when attempting to write to a log container.
 	LOG_CONTAINER_WRITE_FAILED 6640
+	/// Log service encountered an error when attempting open a log container.
 	LOG_CONTAINER_OPEN_FAILED 6641
+	/// Log service encountered an invalid container state when attempting a requested action.
 	LOG_CONTAINER_STATE_INVALID 6642
+	/// Log service is not in the correct state to perform a requested action.
 	LOG_STATE_INVALID 6643
+	/// Log space cannot be reclaimed because the log is pinned.
 	LOG_PINNED 6644
+	/// Log metadata flush failed.
 	LOG_METADATA_FLUSH_FAILED 6645
+	/// Security on the log and its containers is inconsistent.
 	LOG_INCONSISTENT_SECURITY 6646
+	/// Records were appended to the log or reservation changes were made, but the log could not be flushed.
 	LOG_APPENDED_FLUSH_FAILED 6647
+	/// The log is pinned due to reservation consuming most of the log space. Free some reserved records to make space available.
 	LOG_PINNED_RESERVATION 6648
+	/// The transaction handle associated with this operation is not valid.
 	INVALID_TRANSACTION 6700
+	/// The requested operation was made in the context of a transaction that is no longer active.
 	TRANSACTION_NOT_ACTIVE 6701
+	/// The requested operation is not valid on the Transaction object in its current state.
 	TRANSACTION_REQUEST_NOT_VALID 6702
+	/// The caller has called a response API, but the response is not expected because the TM did not issue the corresponding request to the caller.
 	TRANSACTION_NOT_REQUESTED 6703
+	/// It is too late to perform the requested operation, since the Transaction has already been aborted.
 	TRANSACTION_ALREADY_ABORTED 6704
+	/// It is too late to perform the requested operation, since the Transaction has already been committed.
 	TRANSACTION_ALREADY_COMMITTED 6705
+	/// The Transaction Manager was unable to be successfully initialized. Transacted operations are not supported.
 	TM_INITIALIZATION_FAILED 6706
+	/// The specified ResourceManager made no changes or updates to the resource under this transaction.
 	RESOURCEMANAGER_READ_ONLY 6707
+	/// The resource manager has attempted to prepare a transaction that it has not successfully joined.
 	TRANSACTION_NOT_JOINED 6708
+	/// The Transaction object already has a superior enlistment, and the caller attempted an operation that would have created a new superior. Only a single superior enlistment is allow.
 	TRANSACTION_SUPERIOR_EXISTS 6709
+	/// The RM tried to register a protocol that already exists.
 	CRM_PROTOCOL_ALREADY_EXISTS 6710
+	/// The attempt to propagate the Transaction failed.
 	TRANSACTION_PROPAGATION_FAILED 6711
+	/// The requested propagation protocol was not registered as a CRM.
 	CRM_PROTOCOL_NOT_FOUND 6712
+	/// The buffer passed in to PushTransaction or PullTransaction is not in a valid format.
 	TRANSACTION_INVALID_MARSHALL_BUFFER 6713
+	/// The current transaction context associated with the thread is not a valid handle to a transaction object.
 	CURRENT_TRANSACTION_NOT_VALID 6714
+	/// The specified Transaction object could not be opened because it was not found.
 	TRANSACTION_NOT_FOUND 6715
+	/// The specified ResourceManager object could not be opened because it was not found.
 	RESOURCEMANAGER_NOT_FOUND 6716
+	/// The specified Enlistment object could not be opened because it was not found.
 	ENLISTMENT_NOT_FOUND 6717
+	/// The specified TransactionManager object could not be opened because it was not found.
 	TRANSACTIONMANAGER_NOT_FOUND 6718
+	/// The object specified could not be created or opened because its associated TransactionManager is not online. The TransactionManager must be brought fully Online by calling RecoverTransactionManager to recover to the end of its LogFile before objects in its Transaction or ResourceManager namespaces can be opened. In addition, errors in writing records to its LogFile can cause a TransactionManager to go offline.
 	TRANSACTIONMANAGER_NOT_ONLINE 6719
+	/// The specified TM could not be opened because another TM with the same name or RecoveryID is already open.
 	TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION 6720
+	/// The call to create a superior Enlistment on this Transaction object could not be completed, because the Transaction object specified for the enlistment is a subordinate branch of the Transaction. Only the root of the Transaction can be enlisted on as a superior.
 	TRANSACTION_NOT_ROOT 6721
+	/// Because the associated transaction manager or resource manager has been closed, the handle is no longer valid.
 	TRANSACTION_OBJECT_EXPIRED 6722
+	/// The specified operation could not be performed on this Superior enlistment, because the enlistment was not created with the corresponding completion response in the NotificationMask.
 	TRANSACTION_RESPONSE_NOT_ENLISTED 6723
+	/// The specified operation could not be performed, because the record that would be logged was too long. This can occur because of two conditions: either there are too many Enlistments on this Transaction, or the combined RecoveryInformation being logged on behalf of those Enlistments is too long.
 	TRANSACTION_RECORD_TOO_LONG 6724
+	/// Implicit transaction are not supported.
 	IMPLICIT_TRANSACTION_NOT_SUPPORTED 6725
+	/// The kernel transaction manager had to abort or forget the transaction because it blocked forward progress.
 	TRANSACTION_INTEGRITY_VIOLATED 6726
+	/// The TransactionManager identity that was supplied did not match the one recorded in the TransactionManager's log file.
 	TRANSACTIONMANAGER_IDENTITY_MISMATCH 6727
+	/// This snapshot operation cannot continue because a transactional resource manager cannot be frozen in its current state. Please try again.
 	RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT 6728
+	/// The transaction cannot be enlisted on with the specified EnlistmentMask, because the transaction has already completed the PrePrepare phase. In order to ensure correctness, the ResourceManager must switch to a write-through mode and cease caching data within this transaction. Enlisting for only subsequent transaction phases may still succeed.
 	TRANSACTION_MUST_WRITETHROUGH 6729
+	/// The transaction does not have a superior enlistment.
 	TRANSACTION_NO_SUPERIOR 6730
+	/// The attempt to commit the Transaction completed, but it is possible that some portion of the transaction tree did not commit successfully due to heuristics. Therefore it is possible that some data modified in the transaction may not have committed, resulting in transactional inconsistency. If possible, check the consistency of the associated data.
 	HEURISTIC_DAMAGE_POSSIBLE 6731
+	/// The function attempted to use a name that is reserved for use by another transaction.
 	TRANSACTIONAL_CONFLICT 6800
+	/// Transaction support within the specified resource manager is not started or was shut down due to an error.
 	RM_NOT_ACTIVE 6801
+	/// The metadata of the RM has been corrupted. The RM will not function.
 	RM_METADATA_CORRUPT 6802
+	/// The specified directory does not contain a resource manager.
 	DIRECTORY_NOT_RM 6803
+	/// The remote server or share does not support transacted file operations.
 	TRANSACTIONS_UNSUPPORTED_REMOTE 6805
+	/// The requested log size is invalid.
 	LOG_RESIZE_INVALID_SIZE 6806
+	/// The object (file, stream, link) corresponding to the handle has been deleted by a Transaction Savepoint Rollback.
 	OBJECT_NO_LONGER_EXISTS 6807
+	/// The specified file miniversion was not found for this transacted file open.
 	STREAM_MINIVERSION_NOT_FOUND 6808
+	/// The specified file miniversion was found but has been invalidated. Most likely cause is a transaction savepoint rollback.
 	STREAM_MINIVERSION_NOT_VALID 6809
+	/// A miniversion may only be opened in the context of the transaction that created it.
 	MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION 6810
+	/// It is not possible to open a miniversion with modify access.
 	CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT 6811
+	/// It is not possible to create any more miniversions for this stream.
 	CANT_CREATE_MORE_STREAM_MINIVERSIONS 6812
+	/// The remote server sent mismatching version number or Fid for a file opened with transactions.
 	REMOTE_FILE_VERSION_MISMATCH 6814
+	/// The handle has been invalidated by a transaction. The most likely cause is the presence of memory mapping on a file or an open handle when the transaction ended or rolled back to savepoint.
 	HANDLE_NO_LONGER_VALID 6815
+	/// There is no transaction metadata on the file.
 	NO_TXF_METADATA 6816
+	/// The log data is corrupt.
 	LOG_CORRUPTION_DETECTED 6817
+	/// The file can't be recovered because there is a handle still open on it.
 	CANT_RECOVER_WITH_HANDLE_OPEN 6818
+	/// The transaction outcome is unavailable because the resource manager responsible for it has disconnected.
 	RM_DISCONNECTED 6819
+	/// The request was rejected because the enlistment in question is not a superior enlistment.
 	ENLISTMENT_NOT_SUPERIOR 6820
+	/// The transactional resource manager is already consistent. Recovery is not needed.
 	RECOVERY_NOT_NEEDED 6821
+	/// The transactional resource manager has already been started.
 	RM_ALREADY_STARTED 6822
+	/// The file cannot be opened transactionally, because its identity depends on the outcome of an unresolved transaction.
 	FILE_IDENTITY_NOT_PERSISTENT 6823
+	/// The operation cannot be performed because another transaction is depending on the fact that this property will not change.
 	CANT_BREAK_TRANSACTIONAL_DEPENDENCY 6824
+	/// The operation would involve a single file with two transactional resource managers and is therefore not allowed.
 	CANT_CROSS_RM_BOUNDARY 6825
+	/// The $Txf directory must be empty for this operation to succeed.
 	TXF_DIR_NOT_EMPTY 6826
+	/// The operation would leave a transactional resource manager in an inconsistent state and is therefore not allowed.
 	INDOUBT_TRANSACTIONS_EXIST 6827
+	/// The operation could not be completed because the transaction manager does not have a log.
 	TM_VOLATILE 6828
+	/// A rollback could not be scheduled because a previously scheduled rollback has already executed or been queued for execution.
 	ROLLBACK_TIMER_EXPIRED 6829
+	/// The transactional metadata attribute on the file or directory is corrupt and unreadable.
 	TXF_ATTRIBUTE_CORRUPT 6830
+	/// The encryption operation could not be completed because a transaction is active.
 	EFS_NOT_ALLOWED_IN_TRANSACTION 6831
+	/// This object is not allowed to be opened in a transaction.
 	TRANSACTIONAL_OPEN_NOT_ALLOWED 6832
+	/// An attempt to create space in the transactional resource manager's log failed. The failure status has been recorded in the event log.
 	LOG_GROWTH_FAILED 6833
+	/// Memory mapping (creating a mapped section) a remote file under a transaction is not supported.
 	TRANSACTED_MAPPING_UNSUPPORTED_REMOTE 6834
+	/// Transaction metadata is already present on this file and cannot be superseded.
 	TXF_METADATA_ALREADY_PRESENT 6835
+	/// A transaction scope could not be entered because the scope handler has not been initialized.
 	TRANSACTION_SCOPE_CALLBACKS_NOT_SET 6836
+	/// Promotion was required in order to allow the resource manager to enlist, but the transaction was set to disallow it.
 	TRANSACTION_REQUIRED_PROMOTION 6837
+	/// This file is open for modification in an unresolved transaction and may be opened for execute only by a transacted reader.
 	CANNOT_EXECUTE_FILE_IN_TRANSACTION 6838
+	/// The request to thaw frozen transactions was ignored because transactions had not previously been frozen.
 	TRANSACTIONS_NOT_FROZEN 6839
+	/// Transactions cannot be frozen because a freeze is already in progress.
 	TRANSACTION_FREEZE_IN_PROGRESS 6840
+	/// The target volume is not a snapshot volume. This operation is only valid on a volume mounted as a snapshot.
 	NOT_SNAPSHOT_VOLUME 6841
+	/// The savepoint operation failed because files are open on the transaction. This is not permitted.
 	NO_SAVEPOINT_WITH_OPEN_FILES 6842
+	/// Windows has discovered corruption in a file, and that file has since been repaired. Data loss may have occurred.
 	DATA_LOST_REPAIR 6843
+	/// The sparse operation could not be completed because a transaction is active on the file.
 	SPARSE_NOT_ALLOWED_IN_TRANSACTION 6844
+	/// The call to create a TransactionManager object failed because the Tm Identity stored in the logfile does not match the Tm Identity that was passed in as an argument.
 	TM_IDENTITY_MISMATCH 6845
+	/// I/O was attempted on a section object that has been floated as a result of a transaction ending. There is no valid data.
 	FLOATED_SECTION 6846
+	/// The transactional resource manager cannot currently accept transacted work due to a transient condition such as low resources.
 	CANNOT_ACCEPT_TRANSACTED_WORK 6847
+	/// The transactional resource manager had too many transactions outstanding that could not be aborted. The transactional resource manager has been shut down.
 	CANNOT_ABORT_TRANSACTIONS 6848
+	/// The operation could not be completed due to bad clusters on disk.
 	BAD_CLUSTERS 6849
+	/// The compression operation could not be completed because a transaction is active on the file.
 	COMPRESSION_NOT_ALLOWED_IN_TRANSACTION 6850
+	/// The operation could not be completed because the volume is dirty. Please run chkdsk and try again.
 	VOLUME_DIRTY 6851
+	/// The link tracking operation could not be completed because a transaction is active.
 	NO_LINK_TRACKING_IN_TRANSACTION 6852
+	/// This operation cannot be performed in a transaction.
 	OPERATION_NOT_SUPPORTED_IN_TRANSACTION 6853
+	/// The handle is no longer properly associated with its transaction. It may have been opened in a transactional resource manager that was subsequently forced to restart. Please close the handle and open a new one.
 	EXPIRED_HANDLE 6854
+	/// The specified operation could not be performed because the resource manager is not enlisted in the transaction.
 	TRANSACTION_NOT_ENLISTED 6855
+	/// The specified session name is invalid.
 	CTX_WINSTATION_NAME_INVALID 7001
+	/// The specified protocol driver is invalid.
 	CTX_INVALID_PD 7002
+	/// The specified protocol driver was not found in the system path.
 	CTX_PD_NOT_FOUND 7003
+	/// The specified terminal connection driver was not found in the system path.
 	CTX_WD_NOT_FOUND 7004
+	/// A registry key for event logging could not be created for this session.
 	CTX_CANNOT_MAKE_EVENTLOG_ENTRY 7005
+	/// A service with the same name already exists on the system.
 	CTX_SERVICE_NAME_COLLISION 7006
+	/// A close operation is pending on the session.
 	CTX_CLOSE_PENDING 7007
+	/// There are no free output buffers available.
 	CTX_NO_OUTBUF 7008
+	/// The MODEM.INF file was not found.
 	CTX_MODEM_INF_NOT_FOUND 7009
+	/// The modem name was not found in the MODEM.INF file.
 	CTX_INVALID_MODEMNAME 7010
+	/// The modem did not accept the command sent to it. Verify that the configured modem name matches the attached modem.
 	CTX_MODEM_RESPONSE_ERROR 7011
+	/// The modem did not respond to the command sent to it. Verify that the modem is properly cabled and powered on.
 	CTX_MODEM_RESPONSE_TIMEOUT 7012
+	/// Carrier detect has failed or carrier has been dropped due to disconnect.
 	CTX_MODEM_RESPONSE_NO_CARRIER 7013
+	/// Dial tone not detected within the required time. Verify that the phone cable is properly attached and functional.
 	CTX_MODEM_RESPONSE_NO_DIALTONE 7014
+	/// Busy signal detected at remote site on callback.
 	CTX_MODEM_RESPONSE_BUSY 7015
+	/// Voice detected at remote site on callback.
 	CTX_MODEM_RESPONSE_VOICE 7016
+	/// Transport driver error.
 	CTX_TD_ERROR 7017
+	/// The specified session cannot be found.
 	CTX_WINSTATION_NOT_FOUND 7022
+	/// The specified session name is already in use.
 	CTX_WINSTATION_ALREADY_EXISTS 7023
+	/// The task you are trying to do can't be completed because Remote Desktop Services is currently busy. Please try again in a few minutes. Other users should still be able to log on.
 	CTX_WINSTATION_BUSY 7024
+	/// An attempt has been made to connect to a session whose video mode is not supported by the current client.
 	CTX_BAD_VIDEO_MODE 7025
+	/// The application attempted to enable DOS graphics mode. DOS graphics mode is not supported.
 	CTX_GRAPHICS_INVALID 7035
+	/// Your interactive logon privilege has been disabled. Please contact your administrator.
 	CTX_LOGON_DISABLED 7037
+	/// The requested operation can be performed only on the system console. This is most often the result of a driver or system DLL requiring direct console access.
 	CTX_NOT_CONSOLE 7038
+	/// The client failed to respond to the server connect message.
 	CTX_CLIENT_QUERY_TIMEOUT 7040
+	/// Disconnecting the console session is not supported.
 	CTX_CONSOLE_DISCONNECT 7041
+	/// Reconnecting a disconnected session to the console is not supported.
 	CTX_CONSOLE_CONNECT 7042
+	/// The request to control another session remotely was denied.
 	CTX_SHADOW_DENIED 7044
+	/// The requested session access is denied.
 	CTX_WINSTATION_ACCESS_DENIED 7045
+	/// The specified terminal connection driver is invalid.
 	CTX_INVALID_WD 7049
+	/// The requested session cannot be controlled remotely. This may be because the session is disconnected or does not currently have a user logged on.
 	CTX_SHADOW_INVALID 7050
+	/// The requested session is not configured to allow remote control.
 	CTX_SHADOW_DISABLED 7051
+	/// Your request to connect to this Terminal Server has been rejected. Your Terminal Server client license number is currently being used by another user. Please call your system administrator to obtain a unique license number.
 	CTX_CLIENT_LICENSE_IN_USE 7052
+	/// Your request to connect to this Terminal Server has been rejected. Your Terminal Server client license number has not been entered for this copy of the Terminal Server client. Please contact your system administrator.
 	CTX_CLIENT_LICENSE_NOT_SET 7053
+	/// The number of connections to this computer is limited and all connections are in use right now. Try connecting later or contact your system administrator.
 	CTX_LICENSE_NOT_AVAILABLE 7054
+	/// The client you are using is not licensed to use this system. Your logon request is denied.
 	CTX_LICENSE_CLIENT_INVALID 7055
+	/// The system license has expired. Your logon request is denied.
 	CTX_LICENSE_EXPIRED 7056
+	/// Remote control could not be terminated because the specified session is not currently being remotely controlled.
 	CTX_SHADOW_NOT_RUNNING 7057
+	/// The remote control of the console was terminated because the display mode was changed. Changing the display mode in a remote control session is not supported.
 	CTX_SHADOW_ENDED_BY_MODE_CHANGE 7058
+	/// Activation has already been reset the maximum number of times for this installation. Your activation timer will not be cleared.
 	ACTIVATION_COUNT_EXCEEDED 7059
+	/// Remote logins are currently disabled.
 	CTX_WINSTATIONS_DISABLED 7060
+	/// You do not have the proper encryption level to access this Session.
 	CTX_ENCRYPTION_LEVEL_REQUIRED 7061
+	/// The user %s\%s is currently logged on to this computer. Only the current user or an administrator can log on to this computer.
 	CTX_SESSION_IN_USE 7062
+	/// The user %s\%s is already logged on to the console of this computer. You do not have permission to log in at this time. To resolve this issue, contact %s\%s and have them log off.
 	CTX_NO_FORCE_LOGOFF 7063
+	/// Unable to log you on because of an account restriction.
 	CTX_ACCOUNT_RESTRICTION 7064
+	/// The RDP protocol component %2 detected an error in the protocol stream and has disconnected the client.
 	RDP_PROTOCOL_ERROR 7065
+	/// The Client Drive Mapping Service Has Connected on Terminal Connection.
 	CTX_CDM_CONNECT 7066
+	/// The Client Drive Mapping Service Has Disconnected on Terminal Connection.
 	CTX_CDM_DISCONNECT 7067
+	/// The Terminal Server security layer detected an error in the protocol stream and has disconnected the client.
 	CTX_SECURITY_LAYER_ERROR 7068
+	/// The target session is incompatible with the current session.
 	TS_INCOMPATIBLE_SESSIONS 7069
+	/// Windows can't connect to your session because a problem occurred in the Windows video subsystem. Try connecting again later, or contact the server administrator for assistance.
 	TS_VIDEO_SUBSYSTEM_ERROR 7070
+	/// An error occurred while installing the directory service. For more information, see the event log.
 	DS_NOT_INSTALLED 8200
+	/// The directory service evaluated group memberships locally.
 	DS_MEMBERSHIP_EVALUATED_LOCALLY 8201
+	/// The specified directory service attribute or value does not exist.
 	DS_NO_ATTRIBUTE_OR_VALUE 8202
+	/// The attribute syntax specified to the directory service is invalid.
 	DS_INVALID_ATTRIBUTE_SYNTAX 8203
+	/// The attribute type specified to the directory service is not defined.
 	DS_ATTRIBUTE_TYPE_UNDEFINED 8204
+	/// The specified directory service attribute or value already exists.
 	DS_ATTRIBUTE_OR_VALUE_EXISTS 8205
+	/// The directory service is busy.
 	DS_BUSY 8206
+	/// The directory service is unavailable.
 	DS_UNAVAILABLE 8207
+	/// The directory service was unable to allocate a relative identifier.
 	DS_NO_RIDS_ALLOCATED 8208
+	/// The directory service has exhausted the pool of relative identifiers.
 	DS_NO_MORE_RIDS 8209
+	/// The requested operation could not be performed because the directory service is not the master for that type of operation.
 	DS_INCORRECT_ROLE_OWNER 8210
+	/// The directory service was unable to initialize the subsystem that allocates relative identifiers.
 	DS_RIDMGR_INIT_ERROR 8211
+	/// The requested operation did not satisfy one or more constraints associated with the class of the object.
 	DS_OBJ_CLASS_VIOLATION 8212
+	/// The directory service can perform the requested operation only on a leaf object.
 	DS_CANT_ON_NON_LEAF 8213
+	/// The directory service cannot perform the requested operation on the RDN attribute of an object.
 	DS_CANT_ON_RDN 8214
+	/// The directory service detected an attempt to modify the object class of an object.
 	DS_CANT_MOD_OBJ_CLASS 8215
+	/// The requested cross-domain move operation could not be performed.
 	DS_CROSS_DOM_MOVE_ERROR 8216
+	/// Unable to contact the global catalog server.
 	DS_GC_NOT_AVAILABLE 8217
+	/// The policy object is shared and can only be modified at the root.
 	SHARED_POLICY 8218
+	/// The policy object does not exist.
 	POLICY_OBJECT_NOT_FOUND 8219
+	/// The requested policy information is only in the directory service.
 	POLICY_ONLY_IN_DS 8220
+	/// A domain controller promotion is currently active.
 	PROMOTION_ACTIVE 8221
+	/// A domain controller promotion is not currently active.
 	NO_PROMOTION_ACTIVE 8222
+	/// An operations error occurred.
 	DS_OPERATIONS_ERROR 8224
+	/// A protocol error occurred.
 	DS_PROTOCOL_ERROR 8225
+	/// The time limit for this request was exceeded.
 	DS_TIMELIMIT_EXCEEDED 8226
+	/// The size limit for this request was exceeded.
 	DS_SIZELIMIT_EXCEEDED 8227
+	/// The administrative limit for this request was exceeded.
 	DS_ADMIN_LIMIT_EXCEEDED 8228
+	/// The compare response was false.
 	DS_COMPARE_FALSE 8229
+	/// The compare response was true.
 	DS_COMPARE_TRUE 8230
+	/// The requested authentication method is not supported by the server.
 	DS_AUTH_METHOD_NOT_SUPPORTED 8231
+	/// A more secure authentication method is required for this server.
 	DS_STRONG_AUTH_REQUIRED 8232
+	/// Inappropriate authentication.
 	DS_INAPPROPRIATE_AUTH 8233
+	/// The authentication mechanism is unknown.
 	DS_AUTH_UNKNOWN 8234
+	/// A referral was returned from the server.
 	DS_REFERRAL 8235
+	/// The server does not support the requested critical extension.
 	DS_UNAVAILABLE_CRIT_EXTENSION 8236
+	/// This request requires a secure connection.
 	DS_CONFIDENTIALITY_REQUIRED 8237
+	/// Inappropriate matching.
 	DS_INAPPROPRIATE_MATCHING 8238
+	/// A constraint violation occurred.
 	DS_CONSTRAINT_VIOLATION 8239
+	/// There is no such object on the server.
 	DS_NO_SUCH_OBJECT 8240
+	/// There is an alias problem.
 	DS_ALIAS_PROBLEM 8241
+	/// An invalid dn syntax has been specified.
 	DS_INVALID_DN_SYNTAX 8242
+	/// The object is a leaf object.
 	DS_IS_LEAF 8243
+	/// There is an alias dereferencing problem.
 	DS_ALIAS_DEREF_PROBLEM 8244
+	/// The server is unwilling to process the request.
 	DS_UNWILLING_TO_PERFORM 8245
+	/// A loop has been detected.
 	DS_LOOP_DETECT 8246
+	/// There is a naming violation.
 	DS_NAMING_VIOLATION 8247
+	/// The result set is too large.
 	DS_OBJECT_RESULTS_TOO_LARGE 8248
+	/// The operation affects multiple DSAs.
 	DS_AFFECTS_MULTIPLE_DSAS 8249
+	/// The server is not operational.
 	DS_SERVER_DOWN 8250
+	/// A local error has occurred.
 	DS_LOCAL_ERROR 8251
+	/// An encoding error has occurred.
 	DS_ENCODING_ERROR 8252
+	/// A decoding error has occurred.
 	DS_DECODING_ERROR 8253
+	/// The search filter cannot be recognized.
 	DS_FILTER_UNKNOWN 8254
+	/// One or more parameters are illegal.
 	DS_PARAM_ERROR 8255
+	/// The specified method is not supported.
 	DS_NOT_SUPPORTED 8256
+	/// No results were returned.
 	DS_NO_RESULTS_RETURNED 8257
+	/// The specified control is not supported by the server.
 	DS_CONTROL_NOT_FOUND 8258
+	/// A referral loop was detected by the client.
 	DS_CLIENT_LOOP 8259
+	/// The preset referral limit was exceeded.
 	DS_REFERRAL_LIMIT_EXCEEDED 8260
+	/// The search requires a SORT control.
 	DS_SORT_CONTROL_MISSING 8261
+	/// The search results exceed the offset range specified.
 	DS_OFFSET_RANGE_ERROR 8262
+	/// The directory service detected the subsystem that allocates relative identifiers is disabled. This can occur as a protective mechanism when the system determines a significant portion of relative identifiers (RIDs) have been exhausted.
 	DS_RIDMGR_DISABLED 8263
+	/// The root object must be the head of a naming context. The root object cannot have an instantiated parent.
 	DS_ROOT_MUST_BE_NC 8301
+	/// The add replica operation cannot be performed. The naming context must be writeable in order to create the replica.
 	DS_ADD_REPLICA_INHIBITED 8302
+	/// A reference to an attribute that is not defined in the schema occurred.
 	DS_ATT_NOT_DEF_IN_SCHEMA 8303
+	/// The maximum size of an object has been exceeded.
 	DS_MAX_OBJ_SIZE_EXCEEDED 8304
+	/// An attempt was made to add an object to the directory with a name that is already in use.
 	DS_OBJ_STRING_NAME_EXISTS 8305
+	/// An attempt was made to add an object of a class that does not have an RDN defined in the schema.
 	DS_NO_RDN_DEFINED_IN_SCHEMA 8306
+	/// An attempt was made to add an object using an RDN that is not the RDN defined in the schema.
 	DS_RDN_DOESNT_MATCH_SCHEMA 8307
+	/// None of the requested attributes were found on the objects.
 	DS_NO_REQUESTED_ATTS_FOUND 8308
+	/// The user buffer is too small.
 	DS_USER_BUFFER_TO_SMALL 8309
+	/// The attribute specified in the operation is not present on the object.
 	DS_ATT_IS_NOT_ON_OBJ 8310
+	/// Illegal modify operation. Some aspect of the modification is not permitted.
 	DS_ILLEGAL_MOD_OPERATION 8311
+	/// The specified object is too large.
 	DS_OBJ_TOO_LARGE 8312
+	/// The specified instance type is not valid.
 	DS_BAD_INSTANCE_TYPE 8313
+	/// The operation must be performed at a master DSA.
 	DS_MASTERDSA_REQUIRED 8314
+	/// The object class attribute must be specified.
 	DS_OBJECT_CLASS_REQUIRED 8315
+	/// A required attribute is missing.
 	DS_MISSING_REQUIRED_ATT 8316
+	/// An attempt was made to modify an object to include an attribute that is not legal for its class.
 	DS_ATT_NOT_DEF_FOR_CLASS 8317
+	/// The specified attribute is already present on the object.
 	DS_ATT_ALREADY_EXISTS 8318
+	/// The specified attribute is not present, or has no values.
 	DS_CANT_ADD_ATT_VALUES 8320
+	/// Multiple values were specified for an attribute that can have only one value.
 	DS_SINGLE_VALUE_CONSTRAINT 8321
+	/// A value for the attribute was not in the acceptable range of values.
 	DS_RANGE_CONSTRAINT 8322
+	/// The specified value already exists.
 	DS_ATT_VAL_ALREADY_EXISTS 8323
+	/// The attribute cannot be removed because it is not present on the object.
 	DS_CANT_REM_MISSING_ATT 8324
+	/// The attribute value cannot be removed because it is not present on the object.
 	DS_CANT_REM_MISSING_ATT_VAL 8325
+	/// The specified root object cannot be a subref.
 	DS_ROOT_CANT_BE_SUBREF 8326
+	/// Chaining is not permitted.
 	DS_NO_CHAINING 8327
+	/// Chained evaluation is not permitted.
 	DS_NO_CHAINED_EVAL 8328
+	/// The operation could not be performed because the object's parent is either uninstantiated or deleted.
 	DS_NO_PARENT_OBJECT 8329
+	/// Having a parent that is an alias is not permitted. Aliases are leaf objects.
 	DS_PARENT_IS_AN_ALIAS 8330
+	/// The object and parent must be of the same type, either both masters or both replicas.
 	DS_CANT_MIX_MASTER_AND_REPS 8331
+	/// The operation cannot be performed because child objects exist. This operation can only be performed on a leaf object.
 	DS_CHILDREN_EXIST 8332
+	/// Directory object not found.
 	DS_OBJ_NOT_FOUND 8333
+	/// The aliased object is missing.
 	DS_ALIASED_OBJ_MISSING 8334
+	/// The object name has bad syntax.
 	DS_BAD_NAME_SYNTAX 8335
+	/// It is not permitted for an alias to refer to another alias.
 	DS_ALIAS_POINTS_TO_ALIAS 8336
+	/// The alias cannot be dereferenced.
 	DS_CANT_DEREF_ALIAS 8337
+	/// The operation is out of scope.
 	DS_OUT_OF_SCOPE 8338
+	/// The operation cannot continue because the object is in the process of being removed.
 	DS_OBJECT_BEING_REMOVED 8339
+	/// The DSA object cannot be deleted.
 	DS_CANT_DELETE_DSA_OBJ 8340
+	/// A directory service error has occurred.
 	DS_GENERIC_ERROR 8341
+	/// The operation can only be performed on an internal master DSA object.
 	DS_DSA_MUST_BE_INT_MASTER 8342
+	/// The object must be of class DSA.
 	DS_CLASS_NOT_DSA 8343
+	/// Insufficient access rights to perform the operation.
 	DS_INSUFF_ACCESS_RIGHTS 8344
+	/// The object cannot be added because the parent is not on the list of possible superiors.
 	DS_ILLEGAL_SUPERIOR 8345
+	/// Access to the attribute is not permitted because the attribute is owned by the Security Accounts Manager (SAM).
 	DS_ATTRIBUTE_OWNED_BY_SAM 8346
+	/// The name has too many parts.
 	DS_NAME_TOO_MANY_PARTS 8347
+	/// The name is too long.
 	DS_NAME_TOO_LONG 8348
+	/// The name value is too long.
 	DS_NAME_VALUE_TOO_LONG 8349
+	/// The directory service encountered an error parsing a name.
 	DS_NAME_UNPARSEABLE 8350
+	/// The directory service cannot get the attribute type for a name.
 	DS_NAME_TYPE_UNKNOWN 8351
+	/// The name does not identify an object; the name identifies a phantom.
 	DS_NOT_AN_OBJECT 8352
+	/// The security descriptor is too short.
 	DS_SEC_DESC_TOO_SHORT 8353
+	/// The security descriptor is invalid.
 	DS_SEC_DESC_INVALID 8354
+	/// Failed to create name for deleted object.
 	DS_NO_DELETED_NAME 8355
+	/// The parent of a new subref must exist.
 	DS_SUBREF_MUST_HAVE_PARENT 8356
+	/// The object must be a naming context.
 	DS_NCNAME_MUST_BE_NC 8357
+	/// It is not permitted to add an attribute which is owned by the system.
 	DS_CANT_ADD_SYSTEM_ONLY 8358
+	/// The class of the object must be structural; you cannot instantiate an abstract class.
 	DS_CLASS_MUST_BE_CONCRETE 8359
+	/// The schema object could not be found.
 	DS_INVALID_DMD 8360
+	/// A local object with this GUID (dead or alive) already exists.
 	DS_OBJ_GUID_EXISTS 8361
+	/// The operation cannot be performed on a back link.
 	DS_NOT_ON_BACKLINK 8362
+	/// The cross reference for the specified naming context could not be found.
 	DS_NO_CROSSREF_FOR_NC 8363
+	/// The requested operation failed because the Directory Service is shutting down.
 	DS_SHUTTING_DOWN 8364
+	/// The directory service request is invalid.
 	DS_UNKNOWN_OPERATION 8365
+	/// The role owner attribute could not be read.
 	DS_INVALID_ROLE_OWNER 8366
+	/// The requested FSMO operation failed. The current FSMO holder could not be contacted.
 	DS_COULDNT_CONTACT_FSMO 8367
+	/// Modification of a DN across a naming context is not permitted.
 	DS_CROSS_NC_DN_RENAME 8368
+	/// The attribute cannot be modified because it is owned by the system.
 	DS_CANT_MOD_SYSTEM_ONLY 8369
+	/// Only the replicator can perform this function.
 	DS_REPLICATOR_ONLY 8370
+	/// The specified class is not defined.
 	DS_OBJ_CLASS_NOT_DEFINED 8371
+	/// The specified class is not a subclass.
 	DS_OBJ_CLASS_NOT_SUBCLASS 8372
+	/// The name reference is invalid.
 	DS_NAME_REFERENCE_INVALID 8373
+	/// A cross reference already exists.
 	DS_CROSS_REF_EXISTS 8374
+	/// It is not permitted to delete a master cross reference.
 	DS_CANT_DEL_MASTER_CROSSREF 8375
+	/// Subtree notifications are only supported on NC heads.
 	DS_SUBTREE_NOTIFY_NOT_NC_HEAD 8376
+	/// Notification filter is too complex.
 	DS_NOTIFY_FILTER_TOO_COMPLEX 8377
+	/// Schema update failed: duplicate RDN.
 	DS_DUP_RDN 8378
+	/// Schema update failed: duplicate OID.
 	DS_DUP_OID 8379
+	/// Schema update failed: duplicate MAPI identifier.
 	DS_DUP_MAPI_ID 8380
+	/// Schema update failed: duplicate schema-id GUID.
 	DS_DUP_SCHEMA_ID_GUID 8381
+	/// Schema update failed: duplicate LDAP display name.
 	DS_DUP_LDAP_DISPLAY_NAME 8382
+	/// Schema update failed: range-lower less than range upper.
 	DS_SEMANTIC_ATT_TEST 8383
+	/// Schema update failed: syntax mismatch.
 	DS_SYNTAX_MISMATCH 8384
+	/// Schema deletion failed: attribute is used in must-contain.
 	DS_EXISTS_IN_MUST_HAVE 8385
+	/// Schema deletion failed: attribute is used in may-contain.
 	DS_EXISTS_IN_MAY_HAVE 8386
+	/// Schema update failed: attribute in may-contain does not exist.
 	DS_NONEXISTENT_MAY_HAVE 8387
+	/// Schema update failed: attribute in must-contain does not exist.
 	DS_NONEXISTENT_MUST_HAVE 8388
+	/// Schema update failed: class in aux-class list does not exist or is not an auxiliary class.
 	DS_AUX_CLS_TEST_FAIL 8389
+	/// Schema update failed: class in poss-superiors does not exist.
 	DS_NONEXISTENT_POSS_SUP 8390
+	/// Schema update failed: class in subclassof list does not exist or does not satisfy hierarchy rules.
 	DS_SUB_CLS_TEST_FAIL 8391
+	/// Schema update failed: Rdn-Att-Id has wrong syntax.
 	DS_BAD_RDN_ATT_ID_SYNTAX 8392
+	/// Schema deletion failed: class is used as auxiliary class.
 	DS_EXISTS_IN_AUX_CLS 8393
+	/// Schema deletion failed: class is used as sub class.
 	DS_EXISTS_IN_SUB_CLS 8394
+	/// Schema deletion failed: class is used as poss superior.
 	DS_EXISTS_IN_POSS_SUP 8395
+	/// Schema update failed in recalculating validation cache.
 	DS_RECALCSCHEMA_FAILED 8396
+	/// The tree deletion is not finished. The request must be made again to continue deleting the tree.
 	DS_TREE_DELETE_NOT_FINISHED 8397
+	/// The requested delete operation could not be performed.
 	DS_CANT_DELETE 8398
+	/// Cannot read the governs class identifier for the schema record.
 	DS_ATT_SCHEMA_REQ_ID 8399
+	/// The attribute schema has bad syntax.
 	DS_BAD_ATT_SCHEMA_SYNTAX 8400
+	/// The attribute could not be cached.
 	DS_CANT_CACHE_ATT 8401
+	/// The class could not be cached.
 	DS_CANT_CACHE_CLASS 8402
+	/// The attribute could not be removed from the cache.
 	DS_CANT_REMOVE_ATT_CACHE 8403
+	/// The class could not be removed from the cache.
 	DS_CANT_REMOVE_CLASS_CACHE 8404
+	/// The distinguished name attribute could not be read.
 	DS_CANT_RETRIEVE_DN 8405
+	/// No superior reference has been configured for the directory service. The directory service is therefore unable to issue referrals to objects outside this forest.
 	DS_MISSING_SUPREF 8406
+	/// The instance type attribute could not be retrieved.
 	DS_CANT_RETRIEVE_INSTANCE 8407
+	/// An internal error has occurred.
 	DS_CODE_INCONSISTENCY 8408
+	/// A database error has occurred.
 	DS_DATABASE_ERROR 8409
+	/// The attribute GOVERNSID is missing.
 	DS_GOVERNSID_MISSING 8410
+	/// An expected attribute is missing.
 	DS_MISSING_EXPECTED_ATT 8411
+	/// The specified naming context is missing a cross reference.
 	DS_NCNAME_MISSING_CR_REF 8412
+	/// A security checking error has occurred.
 	DS_SECURITY_CHECKING_ERROR 8413
+	/// The schema is not loaded.
 	DS_SCHEMA_NOT_LOADED 8414
+	/// Schema allocation failed. Please check if the machine is running low on memory.
 	DS_SCHEMA_ALLOC_FAILED 8415
+	/// Failed to obtain the required syntax for the attribute schema.
 	DS_ATT_SCHEMA_REQ_SYNTAX 8416
+	/// The global catalog verification failed. The global catalog is not available or does not support the operation. Some part of the directory is currently not available.
 	DS_GCVERIFY_ERROR 8417
+	/// The replication operation failed because of a schema mismatch between the servers involved.
 	DS_DRA_SCHEMA_MISMATCH 8418
+	/// The DSA object could not be found.
 	DS_CANT_FIND_DSA_OBJ 8419
+	/// The naming context could not be found.
 	DS_CANT_FIND_EXPECTED_NC 8420
+	/// The naming context could not be found in the cache.
 	DS_CANT_FIND_NC_IN_CACHE 8421
+	/// The child object could not be retrieved.
 	DS_CANT_RETRIEVE_CHILD 8422
+	/// The modification was not permitted for security reasons.
 	DS_SECURITY_ILLEGAL_MODIFY 8423
+	/// The operation cannot replace the hidden record.
 	DS_CANT_REPLACE_HIDDEN_REC 8424
+	/// The hierarchy file is invalid.
 	DS_BAD_HIERARCHY_FILE 8425
+	/// The attempt to build the hierarchy table failed.
 	DS_BUILD_HIERARCHY_TABLE_FAILED 8426
+	/// The directory configuration parameter is missing from the registry.
 	DS_CONFIG_PARAM_MISSING 8427
+	/// The attempt to count the address book indices failed.
 	DS_COUNTING_AB_INDICES_FAILED 8428
+	/// The allocation of the hierarchy table failed.
 	DS_HIERARCHY_TABLE_MALLOC_FAILED 8429
+	/// The directory service encountered an internal failure.
 	DS_INTERNAL_FAILURE 8430
+	/// The directory service encountered an unknown failure.
 	DS_UNKNOWN_ERROR 8431
+	/// A root object requires a class of 'top'.
 	DS_ROOT_REQUIRES_CLASS_TOP 8432
+	/// This directory server is shutting down, and cannot take ownership of new floating single-master operation roles.
 	DS_REFUSING_FSMO_ROLES 8433
+	/// The directory service is missing mandatory configuration information, and is unable to determine the ownership of floating single-master operation roles.
 	DS_MISSING_FSMO_SETTINGS 8434
+	/// The directory service was unable to transfer ownership of one or more floating single-master operation roles to other servers.
 	DS_UNABLE_TO_SURRENDER_ROLES 8435
+	/// The replication operation failed.
 	DS_DRA_GENERIC 8436
+	/// An invalid parameter was specified for this replication operation.
 	DS_DRA_INVALID_PARAMETER 8437
+	/// The directory service is too busy to complete the replication operation at this time.
 	DS_DRA_BUSY 8438
+	/// The distinguished name specified for this replication operation is invalid.
 	DS_DRA_BAD_DN 8439
+	/// The naming context specified for this replication operation is invalid.
 	DS_DRA_BAD_NC 8440
+	/// The distinguished name specified for this replication operation already exists.
 	DS_DRA_DN_EXISTS 8441
+	/// The replication system encountered an internal error.
 	DS_DRA_INTERNAL_ERROR 8442
+	/// The replication operation encountered a database inconsistency.
 	DS_DRA_INCONSISTENT_DIT 8443
+	/// The server specified for this replication operation could not be contacted.
 	DS_DRA_CONNECTION_FAILED 8444
+	/// The replication operation encountered an object with an invalid instance type.
 	DS_DRA_BAD_INSTANCE_TYPE 8445
+	/// The replication operation failed to allocate memory.
 	DS_DRA_OUT_OF_MEM 8446
+	/// The replication operation encountered an error with the mail system.
 	DS_DRA_MAIL_PROBLEM 8447
+	/// The replication reference information for the target server already exists.
 	DS_DRA_REF_ALREADY_EXISTS 8448
+	/// The replication reference information for the target server does not exist.
 	DS_DRA_REF_NOT_FOUND 8449
+	/// The naming context cannot be removed because it is replicated to another server.
 	DS_DRA_OBJ_IS_REP_SOURCE 8450
+	/// The replication operation encountered a database error.
 	DS_DRA_DB_ERROR 8451
+	/// The naming context is in the process of being removed or is not replicated from the specified server.
 	DS_DRA_NO_REPLICA 8452
+	/// Replication access was denied.
 	DS_DRA_ACCESS_DENIED 8453
+	/// The requested operation is not supported by this version of the directory service.
 	DS_DRA_NOT_SUPPORTED 8454
+	/// The replication remote procedure call was cancelled.
 	DS_DRA_RPC_CANCELLED 8455
+	/// The source server is currently rejecting replication requests.
 	DS_DRA_SOURCE_DISABLED 8456
+	/// The destination server is currently rejecting replication requests.
 	DS_DRA_SINK_DISABLED 8457
+	/// The replication operation failed due to a collision of object names.
 	DS_DRA_NAME_COLLISION 8458
+	/// The replication source has been reinstalled.
 	DS_DRA_SOURCE_REINSTALLED 8459
+	/// The replication operation failed because a required parent object is missing.
 	DS_DRA_MISSING_PARENT 8460
+	/// The replication operation was preempted.
 	DS_DRA_PREEMPTED 8461
+	/// The replication synchronization attempt was abandoned because of a lack of updates.
 	DS_DRA_ABANDON_SYNC 8462
+	/// The replication operation was terminated because the system is shutting down.
 	DS_DRA_SHUTDOWN 8463
+	/// Synchronization attempt failed because the destination DC is currently waiting to synchronize new partial attributes from source. This condition is normal if a recent schema change modified the partial attribute set. The destination partial attribute set is not a subset of source partial attribute set.
 	DS_DRA_INCOMPATIBLE_PARTIAL_SET 8464
+	/// The replication synchronization attempt failed because a master replica attempted to sync from a partial replica.
 	DS_DRA_SOURCE_IS_PARTIAL_REPLICA 8465
+	/// The server specified for this replication operation was contacted, but that server was unable to contact an additional server needed to complete the operation.
 	DS_DRA_EXTN_CONNECTION_FAILED 8466
+	/// The version of the directory service schema of the source forest is not compatible with the version of directory service on this computer.
 	DS_INSTALL_SCHEMA_MISMATCH 8467
+	/// Schema update failed: An attribute with the same link identifier already exists.
 	DS_DUP_LINK_ID 8468
+	/// Name translation: Generic processing error.
 	DS_NAME_ERROR_RESOLVING 8469
+	/// Name translation: Could not find the name or insufficient right to see name.
 	DS_NAME_ERROR_NOT_FOUND 8470
+	/// Name translation: Input name mapped to more than one output name.
 	DS_NAME_ERROR_NOT_UNIQUE 8471
+	/// Name translation: Input name found, but not the associated output format.
 	DS_NAME_ERROR_NO_MAPPING 8472
+	/// Name translation: Unable to resolve completely, only the domain was found.
 	DS_NAME_ERROR_DOMAIN_ONLY 8473
+	/// Name translation: Unable to perform purely syntactical mapping at the client without going out to the wire.
 	DS_NAME_ERROR_NO_SYNTACTICAL_MAPPING 8474
+	/// Modification of a constructed attribute is not allowed.
 	DS_CONSTRUCTED_ATT_MOD 8475
+	/// The OM-Object-Class specified is incorrect for an attribute with the specified syntax.
 	DS_WRONG_OM_OBJ_CLASS 8476
+	/// The replication request has been posted; waiting for reply.
 	DS_DRA_REPL_PENDING 8477
+	/// The requested operation requires a directory service, and none was available.
 	DS_DS_REQUIRED 8478
+	/// The LDAP display name of the class or attribute contains non-ASCII characters.
 	DS_INVALID_LDAP_DISPLAY_NAME 8479
+	/// The requested search operation is only supported for base searches.
 	DS_NON_BASE_SEARCH 8480
+	/// The search failed to retrieve attributes from the database.
 	DS_CANT_RETRIEVE_ATTS 8481
+	/// The schema update operation tried to add a backward link attribute that has no corresponding forward link.
 	DS_BACKLINK_WITHOUT_LINK 8482
+	/// Source and destination of a cross-domain move do not agree on the object's epoch number. Either source or destination does not have the latest version of the object.
 	DS_EPOCH_MISMATCH 8483
+	/// Source and destination of a cross-domain move do not agree on the object's current name. Either source or destination does not have the latest version of the object.
 	DS_SRC_NAME_MISMATCH 8484
+	/// Source and destination for the cross-domain move operation are identical. Caller should use local move operation instead of cross-domain move operation.
 	DS_SRC_AND_DST_NC_IDENTICAL 8485
+	/// Source and destination for a cross-domain move are not in agreement on the naming contexts in the forest. Either source or destination does not have the latest version of the Partitions container.
 	DS_DST_NC_MISMATCH 8486
+	/// Destination of a cross-domain move is not authoritative for the destination naming context.
 	DS_NOT_AUTHORITIVE_FOR_DST_NC 8487
+	/// Source and destination of a cross-domain move do not agree on the identity of the source object. Either source or destination does not have the latest version of the source object.
 	DS_SRC_GUID_MISMATCH 8488
+	/// Object being moved across-domains is already known to be deleted by the destination server. The source server does not have the latest version of the source object.
 	DS_CANT_MOVE_DELETED_OBJECT 8489
+	/// Another operation which requires exclusive access to the PDC FSMO is already in progress.
 	DS_PDC_OPERATION_IN_PROGRESS 8490
+	/// A cross-domain move operation failed such that two versions of the moved object exist - one each in the source and destination domains. The destination object needs to be removed to restore the system to a consistent state.
 	DS_CROSS_DOMAIN_CLEANUP_REQD 8491
+	/// This object may not be moved across domain boundaries either because cross-domain moves for this class are disallowed, or the object has some special characteristics, e.g.: trust account or restricted RID, which prevent its move.
 	DS_ILLEGAL_XDOM_MOVE_OPERATION 8492
+	/// Can't move objects with memberships across domain boundaries as once moved, this would violate the membership conditions of the account group. Remove the object from any account group memberships and retry.
 	DS_CANT_WITH_ACCT_GROUP_MEMBERSHPS 8493
+	/// A naming context head must be the immediate child of another naming context head, not of an interior node.
 	DS_NC_MUST_HAVE_NC_PARENT 8494
+	/// The directory cannot validate the proposed naming context name because it does not hold a replica of the naming context above the proposed naming context. Please ensure that the domain naming master role is held by a server that is configured as a global catalog server, and that the server is up to date with its replication partners. (Applies only to Windows 2000 Domain Naming masters.)
 	DS_CR_IMPOSSIBLE_TO_VALIDATE 8495
+	/// Destination domain must be in native mode.
 	DS_DST_DOMAIN_NOT_NATIVE 8496
+	/// The operation cannot be performed because the server does not have an infrastructure container in the domain of interest.
 	DS_MISSING_INFRASTRUCTURE_CONTAINER 8497
+	/// Cross-domain move of non-empty account groups is not allowed.
 	DS_CANT_MOVE_ACCOUNT_GROUP 8498
+	/// Cross-domain move of non-empty resource groups is not allowed.
 	DS_CANT_MOVE_RESOURCE_GROUP 8499
+	/// The search flags for the attribute are invalid. The ANR bit is valid only on attributes of Unicode or Teletex strings.
 	DS_INVALID_SEARCH_FLAG 8500
+	/// Tree deletions starting at an object which has an NC head as a descendant are not allowed.
 	DS_NO_TREE_DELETE_ABOVE_NC 8501
+	/// The directory service failed to lock a tree in preparation for a tree deletion because the tree was in use.
 	DS_COULDNT_LOCK_TREE_FOR_DELETE 8502
+	/// The directory service failed to identify the list of objects to delete while attempting a tree deletion.
 	DS_COULDNT_IDENTIFY_OBJECTS_FOR_TREE_DELETE 8503
+	/// Security Accounts Manager initialization failed because of the following error: %1. Error Status: 0x%2. Please shutdown this system and reboot into Directory Services Restore Mode, check the event log for more detailed information.
 	DS_SAM_INIT_FAILURE 8504
+	/// Only an administrator can modify the membership list of an administrative group.
 	DS_SENSITIVE_GROUP_VIOLATION 8505
+	/// Cannot change the primary group ID of a domain controller account.
 	DS_CANT_MOD_PRIMARYGROUPID 8506
+	/// An attempt is made to modify the base schema.
 	DS_ILLEGAL_BASE_SCHEMA_MOD 8507
+	/// Adding a new mandatory attribute to an existing class, deleting a mandatory attribute from an existing class, or adding an optional attribute to the special class Top that is not a backlink attribute (directly or through inheritance, for example, by adding or deleting an auxiliary class) is not allowed.
 	DS_NONSAFE_SCHEMA_CHANGE 8508
+	/// Schema update is not allowed on this DC because the DC is not the schema FSMO Role Owner.
 	DS_SCHEMA_UPDATE_DISALLOWED 8509
+	/// An object of this class cannot be created under the schema container. You can only create attribute-schema and class-schema objects under the schema container.
 	DS_CANT_CREATE_UNDER_SCHEMA 8510
+	/// The replica/child install failed to get the objectVersion attribute on the schema container on the source DC. Either the attribute is missing on the schema container or the credentials supplied do not have permission to read it.
 	DS_INSTALL_NO_SRC_SCH_VERSION 8511
+	/// The replica/child install failed to read the objectVersion attribute in the SCHEMA section of the file schema.ini in the system32 directory.
 	DS_INSTALL_NO_SCH_VERSION_IN_INIFILE 8512
+	/// The specified group type is invalid.
 	DS_INVALID_GROUP_TYPE 8513
+	/// You cannot nest global groups in a mixed domain if the group is security-enabled.
 	DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN 8514
+	/// You cannot nest local groups in a mixed domain if the group is security-enabled.
 	DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN 8515
+	/// A global group cannot have a local group as a member.
 	DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER 8516
+	/// A global group cannot have a universal group as a member.
 	DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER 8517
+	/// A universal group cannot have a local group as a member.
 	DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER 8518
+	/// A global group cannot have a cross-domain member.
 	DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER 8519
+	/// A local group cannot have another cross domain local group as a member.
 	DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER 8520
+	/// A group with primary members cannot change to a security-disabled group.
 	DS_HAVE_PRIMARY_MEMBERS 8521
+	/// The schema cache load failed to convert the string default SD on a class-schema object.
 	DS_STRING_SD_CONVERSION_FAILED 8522
+	/// Only DSAs configured to be Global Catalog servers should be allowed to hold the Domain Naming Master FSMO role. (Applies only to Windows 2000 servers.)
 	DS_NAMING_MASTER_GC 8523
+	/// The DSA operation is unable to proceed because of a DNS lookup failure.
 	DS_DNS_LOOKUP_FAILURE 8524
+	/// While processing a change to the DNS Host Name for an object, the Service Principal Name values could not be kept in sync.
 	DS_COULDNT_UPDATE_SPNS 8525
+	/// The Security Descriptor attribute could not be read.
 	DS_CANT_RETRIEVE_SD 8526
+	/// The object requested was not found, but an object with that key was found.
 	DS_KEY_NOT_UNIQUE 8527
+	/// The syntax of the linked attribute being added is incorrect. Forward links can only have syntax 2.5.5.1, 2.5.5.7, and 2.5.5.14, and backlinks can only have syntax 2.5.5.1.
 	DS_WRONG_LINKED_ATT_SYNTAX 8528
+	/// Security Account Manager needs to get the boot password.
 	DS_SAM_NEED_BOOTKEY_PASSWORD 8529
+	/// Security Account Manager needs to get the boot key from floppy disk.
 	DS_SAM_NEED_BOOTKEY_FLOPPY 8530
+	/// Directory Service cannot start.
 	DS_CANT_START 8531
+	/// Directory Services could not start.
 	DS_INIT_FAILURE 8532
+	/// The connection between client and server requires packet privacy or better.
 	DS_NO_PKT_PRIVACY_ON_CONNECTION 8533
+	/// The source domain may not be in the same forest as destination.
 	DS_SOURCE_DOMAIN_IN_FOREST 8534
+	/// The destination domain must be in the forest.
 	DS_DESTINATION_DOMAIN_NOT_IN_FOREST 8535
+	/// The operation requires that destination domain auditing be enabled.
 	DS_DESTINATION_AUDITING_NOT_ENABLED 8536
+	/// The operation couldn't locate a DC for the source domain.
 	DS_CANT_FIND_DC_FOR_SRC_DOMAIN 8537
+	/// The source object must be a group or user.
 	DS_SRC_OBJ_NOT_GROUP_OR_USER 8538
+	/// The source object's SID already exists in destination forest.
 	DS_SRC_SID_EXISTS_IN_FOREST 8539
+	/// The source and destination object must be of the same type.
 	DS_SRC_AND_DST_OBJECT_CLASS_MISMATCH 8540
+	/// Security Accounts Manager initialization failed because of the following error: %1. Error Status: 0x%2. Click OK to shut down the system and reboot into Safe Mode. Check the event log for detailed information.
 	SAM_INIT_FAILURE 8541
+	/// Schema information could not be included in the replication request.
 	DS_DRA_SCHEMA_INFO_SHIP 8542
+	/// The replication operation could not be completed due to a schema incompatibility.
 	DS_DRA_SCHEMA_CONFLICT 8543
+	/// The replication operation could not be completed due to a previous schema incompatibility.
 	DS_DRA_EARLIER_SCHEMA_CONFLICT 8544
+	/// The replication update could not be applied because either the source or the destination has not yet received information regarding a recent cross-domain move operation.
 	DS_DRA_OBJ_NC_MISMATCH 8545
+	/// The requested domain could not be deleted because there exist domain controllers that still host this domain.
 	DS_NC_STILL_HAS_DSAS 8546
+	/// The requested operation can be performed only on a global catalog server.
 	DS_GC_REQUIRED 8547
+	/// A local group can only be a member of other local groups in the same domain.
 	DS_LOCAL_MEMBER_OF_LOCAL_ONLY 8548
+	/// Foreign security principals cannot be members of universal groups.
 	DS_NO_FPO_IN_UNIVERSAL_GROUPS 8549
+	/// The attribute is not allowed to be replicated to the GC because of security reasons.
 	DS_CANT_ADD_TO_GC 8550
+	/// The checkpoint with the PDC could not be taken because there too many modifications being processed currently.
 	DS_NO_CHECKPOINT_WITH_PDC 8551
+	/// The operation requires that source domain auditing be enabled.
 	DS_SOURCE_AUDITING_NOT_ENABLED 8552
+	/// Security principal objects can only be created inside domain naming contexts.
 	DS_CANT_CREATE_IN_NONDOMAIN_NC 8553
+	/// A Service Principal Name (SPN) could not be constructed because the provided hostname is not in the necessary format.
 	DS_INVALID_NAME_FOR_SPN 8554
+	/// A Filter was passed that uses constructed attributes.
 	DS_FILTER_USES_CONTRUCTED_ATTRS 8555
+	/// The unicodePwd attribute value must be enclosed in double quotes.
 	DS_UNICODEPWD_NOT_IN_QUOTES 8556
+	/// Your computer could not be joined to the domain. You have exceeded the maximum number of computer accounts you are allowed to create in this domain. Contact your system administrator to have this limit reset or increased.
 	DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED 8557
+	/// For security reasons, the operation must be run on the destination DC.
 	DS_MUST_BE_RUN_ON_DST_DC 8558
+	/// For security reasons, the source DC must be NT4SP4 or greater.
 	DS_SRC_DC_MUST_BE_SP4_OR_GREATER 8559
+	/// Critical Directory Service System objects cannot be deleted during tree delete operations. The tree delete may have been partially performed.
 	DS_CANT_TREE_DELETE_CRITICAL_OBJ 8560
+	/// Directory Services could not start because of the following error: %1. Error Status: 0x%2. Please click OK to shutdown the system. You can use the recovery console to diagnose the system further.
 	DS_INIT_FAILURE_CONSOLE 8561
+	/// Security Accounts Manager initialization failed because of the following error: %1. Error Status: 0x%2. Please click OK to shutdown the system. You can use the recovery console to diagnose the system further.
 	DS_SAM_INIT_FAILURE_CONSOLE 8562
+	/// The version of the operating system is incompatible with the current AD DS forest functional level or AD LDS Configuration Set functional level. You must upgrade to a new version of the operating system before this server can become an AD DS Domain Controller or add an AD LDS Instance in this AD DS Forest or AD LDS Configuration Set.
 	DS_FOREST_VERSION_TOO_HIGH 8563
+	/// The version of the operating system installed is incompatible with the current domain functional level. You must upgrade to a new version of the operating system before this server can become a domain controller in this domain.
 	DS_DOMAIN_VERSION_TOO_HIGH 8564
+	/// The version of the operating system installed on this server no longer supports the current AD DS Forest functional level or AD LDS Configuration Set functional level. You must raise the AD DS Forest functional level or AD LDS Configuration Set functional level before this server can become an AD DS Domain Controller or an AD LDS Instance in this Forest or Configuration Set.
 	DS_FOREST_VERSION_TOO_LOW 8565
+	/// The version of the operating system installed on this server no longer supports the current domain functional level. You must raise the domain functional level before this server can become a domain controller in this domain.
 	DS_DOMAIN_VERSION_TOO_LOW 8566
+	/// The version of the operating system installed on this server is incompatible with the functional level of the domain or forest.
 	DS_INCOMPATIBLE_VERSION 8567
+	/// The functional level of the domain (or forest) cannot be raised to the requested value, because there exist one or more domain controllers in the domain (or forest) that are at a lower incompatible functional level.
 	DS_LOW_DSA_VERSION 8568
+	/// The forest functional level cannot be raised to the requested value since one or more domains are still in mixed domain mode. All domains in the forest must be in native mode, for you to raise the forest functional level.
 	DS_NO_BEHAVIOR_VERSION_IN_MIXEDDOMAIN 8569
+	/// The sort order requested is not supported.
 	DS_NOT_SUPPORTED_SORT_ORDER 8570
+	/// The requested name already exists as a unique identifier.
 	DS_NAME_NOT_UNIQUE 8571
+	/// The machine account was created pre-NT4. The account needs to be recreated.
 	DS_MACHINE_ACCOUNT_CREATED_PRENT4 8572
+	/// The database is out of version store.
 	DS_OUT_OF_VERSION_STORE 8573
+	/// Unable to continue operation because multiple conflicting controls were used.
 	DS_INCOMPATIBLE_CONTROLS_USED 8574
+	/// Unable to find a valid security descriptor reference domain for this partition.
 	DS_NO_REF_DOMAIN 8575
+	/// Schema update failed: The link identifier is reserved.
 	DS_RESERVED_LINK_ID 8576
+	/// Schema update failed: There are no link identifiers available.
 	DS_LINK_ID_NOT_AVAILABLE 8577
+	/// An account group cannot have a universal group as a member.
 	DS_AG_CANT_HAVE_UNIVERSAL_MEMBER 8578
+	/// Rename or move operations on naming context heads or read-only objects are not allowed.
 	DS_MODIFYDN_DISALLOWED_BY_INSTANCE_TYPE 8579
+	/// Move operations on objects in the schema naming context are not allowed.
 	DS_NO_OBJECT_MOVE_IN_SCHEMA_NC 8580
+	/// A system flag has been set on the object and does not allow the object to be moved or renamed.
 	DS_MODIFYDN_DISALLOWED_BY_FLAG 8581
+	/// This object is not allowed to change its grandparent container. Moves are not forbidden on this object, but are restricted to sibling containers.
 	DS_MODIFYDN_WRONG_GRANDPARENT 8582
+	/// Unable to resolve completely, a referral to another forest is generated.
 	DS_NAME_ERROR_TRUST_REFERRAL 8583
+	/// The requested action is not supported on standard server.
 	NOT_SUPPORTED_ON_STANDARD_SERVER 8584
+	/// Could not access a partition of the directory service located on a remote server. Make sure at least one server is running for the partition in question.
 	DS_CANT_ACCESS_REMOTE_PART_OF_AD 8585
+	/// The directory cannot validate the proposed naming context (or partition) name because it does not hold a replica nor can it contact a replica of the naming context above the proposed naming context. Please ensure that the parent naming context is properly registered in DNS, and at least one replica of this naming context is reachable by the Domain Naming master.
 	DS_CR_IMPOSSIBLE_TO_VALIDATE_V2 8586
+	/// The thread limit for this request was exceeded.
 	DS_THREAD_LIMIT_EXCEEDED 8587
+	/// The Global catalog server is not in the closest site.
 	DS_NOT_CLOSEST 8588
+	/// The DS cannot derive a service principal name (SPN) with which to mutually authenticate the target server because the corresponding server object in the local DS database has no serverReference attribute.
 	DS_CANT_DERIVE_SPN_WITHOUT_SERVER_REF 8589
+	/// The Directory Service failed to enter single user mode.
 	DS_SINGLE_USER_MODE_FAILED 8590
+	/// The Directory Service cannot parse the script because of a syntax error.
 	DS_NTDSCRIPT_SYNTAX_ERROR 8591
+	/// The Directory Service cannot process the script because of an error.
 	DS_NTDSCRIPT_PROCESS_ERROR 8592
+	/// The directory service cannot perform the requested operation because the servers involved are of different replication epochs (which is usually related to a domain rename that is in progress).
 	DS_DIFFERENT_REPL_EPOCHS 8593
+	/// The directory service binding must be renegotiated due to a change in the server extensions information.
 	DS_DRS_EXTENSIONS_CHANGED 8594
+	/// Operation not allowed on a disabled cross ref.
 	DS_REPLICA_SET_CHANGE_NOT_ALLOWED_ON_DISABLED_CR 8595
+	/// Schema update failed: No values for msDS-IntId are available.
 	DS_NO_MSDS_INTID 8596
+	/// Schema update failed: Duplicate msDS-INtId. Retry the operation.
 	DS_DUP_MSDS_INTID 8597
+	/// Schema deletion failed: attribute is used in rDNAttID.
 	DS_EXISTS_IN_RDNATTID 8598
+	/// The directory service failed to authorize the request.
 	DS_AUTHORIZATION_FAILED 8599
+	/// The Directory Service cannot process the script because it is invalid.
 	DS_INVALID_SCRIPT 8600
+	/// The remote create cross reference operation failed on the Domain Naming Master FSMO. The operation's error is in the extended data.
 	DS_REMOTE_CROSSREF_OP_FAILED 8601
+	/// A cross reference is in use locally with the same name.
 	DS_CROSS_REF_BUSY 8602
+	/// The DS cannot derive a service principal name (SPN) with which to mutually authenticate the target server because the server's domain has been deleted from the forest.
 	DS_CANT_DERIVE_SPN_FOR_DELETED_DOMAIN 8603
+	/// Writeable NCs prevent this DC from demoting.
 	DS_CANT_DEMOTE_WITH_WRITEABLE_NC 8604
+	/// The requested object has a non-unique identifier and cannot be retrieved.
 	DS_DUPLICATE_ID_FOUND 8605
+	/// Insufficient attributes were given to create an object. This object may not exist because it may have been deleted and already garbage collected.
 	DS_INSUFFICIENT_ATTR_TO_CREATE_OBJECT 8606
+	/// The group cannot be converted due to attribute restrictions on the requested group type.
 	DS_GROUP_CONVERSION_ERROR 8607
+	/// Cross-domain move of non-empty basic application groups is not allowed.
 	DS_CANT_MOVE_APP_BASIC_GROUP 8608
+	/// Cross-domain move of non-empty query based application groups is not allowed.
 	DS_CANT_MOVE_APP_QUERY_GROUP 8609
+	/// The FSMO role ownership could not be verified because its directory partition has not replicated successfully with at least one replication partner.
 	DS_ROLE_NOT_VERIFIED 8610
+	/// The target container for a redirection of a well known object container cannot already be a special container.
 	DS_WKO_CONTAINER_CANNOT_BE_SPECIAL 8611
+	/// The Directory Service cannot perform the requested operation because a domain rename operation is in progress.
 	DS_DOMAIN_RENAME_IN_PROGRESS 8612
+	/// The directory service detected a child partition below the requested partition name. The partition hierarchy must be created in a top down method.
 	DS_EXISTING_AD_CHILD_NC 8613
+	/// The directory service cannot replicate with this server because the time since the last replication with this server has exceeded the tombstone lifetime.
 	DS_REPL_LIFETIME_EXCEEDED 8614
+	/// The requested operation is not allowed on an object under the system container.
 	DS_DISALLOWED_IN_SYSTEM_CONTAINER 8615
+	/// The LDAP servers network send queue has filled up because the client is not processing the results of its requests fast enough. No more requests will be processed until the client catches up. If the client does not catch up then it will be disconnected.
 	DS_LDAP_SEND_QUEUE_FULL 8616
+	/// The scheduled replication did not take place because the system was too busy to execute the request within the schedule window. The replication queue is overloaded. Consider reducing the number of partners or decreasing the scheduled replication frequency.
 	DS_DRA_OUT_SCHEDULE_WINDOW 8617
+	/// At this time, it cannot be determined if the branch replication policy is available on the hub domain controller. Please retry at a later time to account for replication latencies.
 	DS_POLICY_NOT_KNOWN 8618
+	/// The site settings object for the specified site does not exist.
 	NO_SITE_SETTINGS_OBJECT 8619
+	/// The local account store does not contain secret material for the specified account.
 	NO_SECRETS 8620
+	/// Could not find a writable domain controller in the domain.
 	NO_WRITABLE_DC_FOUND 8621
+	/// The server object for the domain controller does not exist.
 	DS_NO_SERVER_OBJECT 8622
+	/// The NTDS Settings object for the domain controller does not exist.
 	DS_NO_NTDSA_OBJECT 8623
+	/// The requested search operation is not supported for ASQ searches.
 	DS_NON_ASQ_SEARCH 8624
+	/// A required audit event could not be generated for the operation.
 	DS_AUDIT_FAILURE 8625
+	/// The search flags for the attribute are invalid. The subtree index bit is valid only on single valued attributes.
 	DS_INVALID_SEARCH_FLAG_SUBTREE 8626
+	/// The search flags for the attribute are invalid. The tuple index bit is valid only on attributes of Unicode strings.
 	DS_INVALID_SEARCH_FLAG_TUPLE 8627
+	/// The address books are nested too deeply. Failed to build the hierarchy table.
 	DS_HIERARCHY_TABLE_TOO_DEEP 8628
+	/// The specified up-to-date-ness vector is corrupt.
 	DS_DRA_CORRUPT_UTD_VECTOR 8629
+	/// The request to replicate secrets is denied.
 	DS_DRA_SECRETS_DENIED 8630
+	/// Schema update failed: The MAPI identifier is reserved.
 	DS_RESERVED_MAPI_ID 8631
+	/// Schema update failed: There are no MAPI identifiers available.
 	DS_MAPI_ID_NOT_AVAILABLE 8632
+	/// The replication operation failed because the required attributes of the local krbtgt object are missing.
 	DS_DRA_MISSING_KRBTGT_SECRET 8633
+	/// The domain name of the trusted domain already exists in the forest.
 	DS_DOMAIN_NAME_EXISTS_IN_FOREST 8634
+	/// The flat name of the trusted domain already exists in the forest.
 	DS_FLAT_NAME_EXISTS_IN_FOREST 8635
+	/// The User Principal Name (UPN) is invalid.
 	INVALID_USER_PRINCIPAL_NAME 8636
+	/// OID mapped groups cannot have members.
 	DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS 8637
+	/// The specified OID cannot be found.
 	DS_OID_NOT_FOUND 8638
+	/// The replication operation failed because the target object referred by a link value is recycled.
 	DS_DRA_RECYCLED_TARGET 8639
+	/// The redirect operation failed because the target object is in a NC different from the domain NC of the current domain controller.
 	DS_DISALLOWED_NC_REDIRECT 8640
+	/// The functional level of the AD LDS configuration set cannot be lowered to the requested value.
 	DS_HIGH_ADLDS_FFL 8641
+	/// The functional level of the domain (or forest) cannot be lowered to the requested value.
 	DS_HIGH_DSA_VERSION 8642
+	/// The functional level of the AD LDS configuration set cannot be raised to the requested value, because there exist one or more ADLDS instances that are at a lower incompatible functional level.
 	DS_LOW_ADLDS_FFL 8643
+	/// The domain join cannot be completed because the SID of the domain you attempted to join was identical to the SID of this machine. This is a symptom of an improperly cloned operating system install. You should run sysprep on this machine in order to generate a new machine SID.
 	DOMAIN_SID_SAME_AS_LOCAL_WORKSTATION 8644
+	/// The undelete operation failed because the Sam Account Name or Additional Sam Account Name of the object being undeleted conflicts with an existing live object.
 	DS_UNDELETE_SAM_VALIDATION_FAILED 8645
+	/// The system is not authoritative for the specified account and therefore cannot complete the operation. Please retry the operation using the provider associated with this account. If this is an online provider please use the provider's online site.
 	INCORRECT_ACCOUNT_TYPE 8646
+	/// The operation failed because SPN value provided for addition/modification is not unique forest-wide.
 	DS_SPN_VALUE_NOT_UNIQUE_IN_FOREST 8647
+	/// The operation failed because UPN value provided for addition/modification is not unique forest-wide.
 	DS_UPN_VALUE_NOT_UNIQUE_IN_FOREST 8648
+	/// The operation failed because the addition/modification referenced an inbound forest-wide trust that is not present.
 	DS_MISSING_FOREST_TRUST 8649
+	/// The link value specified was not found, but a link value with that key was found.
 	DS_VALUE_KEY_NOT_UNIQUE 8650
+	/// The specified quick mode policy already exists.
 	IPSEC_QM_POLICY_EXISTS 13000
+	/// The specified quick mode policy was not found.
 	IPSEC_QM_POLICY_NOT_FOUND 13001
+	/// The specified quick mode policy is being used.
 	IPSEC_QM_POLICY_IN_USE 13002
+	/// The specified main mode policy already exists.
 	IPSEC_MM_POLICY_EXISTS 13003
+	/// The specified main mode policy was not found.
 	IPSEC_MM_POLICY_NOT_FOUND 13004
+	/// The specified main mode policy is being used.
 	IPSEC_MM_POLICY_IN_USE 13005
+	/// The specified main mode filter already exists.
 	IPSEC_MM_FILTER_EXISTS 13006
+	/// The specified main mode filter was not found.
 	IPSEC_MM_FILTER_NOT_FOUND 13007
+	/// The specified transport mode filter already exists.
 	IPSEC_TRANSPORT_FILTER_EXISTS 13008
+	/// The specified transport mode filter does not exist.
 	IPSEC_TRANSPORT_FILTER_NOT_FOUND 13009
+	/// The specified main mode authentication list exists.
 	IPSEC_MM_AUTH_EXISTS 13010
+	/// The specified main mode authentication list was not found.
 	IPSEC_MM_AUTH_NOT_FOUND 13011
+	/// The specified main mode authentication list is being used.
 	IPSEC_MM_AUTH_IN_USE 13012
+	/// The specified default main mode policy was not found.
 	IPSEC_DEFAULT_MM_POLICY_NOT_FOUND 13013
+	/// The specified default main mode authentication list was not found.
 	IPSEC_DEFAULT_MM_AUTH_NOT_FOUND 13014
+	/// The specified default quick mode policy was not found.
 	IPSEC_DEFAULT_QM_POLICY_NOT_FOUND 13015
+	/// The specified tunnel mode filter exists.
 	IPSEC_TUNNEL_FILTER_EXISTS 13016
+	/// The specified tunnel mode filter was not found.
 	IPSEC_TUNNEL_FILTER_NOT_FOUND 13017
+	/// The Main Mode filter is pending deletion.
 	IPSEC_MM_FILTER_PENDING_DELETION 13018
+	/// The transport filter is pending deletion.
 	IPSEC_TRANSPORT_FILTER_PENDING_DELETION 13019
+	/// The tunnel filter is pending deletion.
 	IPSEC_TUNNEL_FILTER_PENDING_DELETION 13020
+	/// The Main Mode policy is pending deletion.
 	IPSEC_MM_POLICY_PENDING_DELETION 13021
+	/// The Main Mode authentication bundle is pending deletion.
 	IPSEC_MM_AUTH_PENDING_DELETION 13022
+	/// The Quick Mode policy is pending deletion.
 	IPSEC_QM_POLICY_PENDING_DELETION 13023
+	/// ERROR_IPSEC_IKE_NEG_STATUS_BEGIN
 	IPSEC_IKE_NEG_STATUS_BEGIN 13800
+	/// IKE authentication credentials are unacceptable.
 	IPSEC_IKE_AUTH_FAIL 13801
+	/// IKE security attributes are unacceptable.
 	IPSEC_IKE_ATTRIB_FAIL 13802
+	/// IKE Negotiation in progress.
 	IPSEC_IKE_NEGOTIATION_PENDING 13803
+	/// General processing error.
 	IPSEC_IKE_GENERAL_PROCESSING_ERROR 13804
+	/// Negotiation timed out.
 	IPSEC_IKE_TIMED_OUT 13805
+	/// IKE failed to find valid machine certificate. Contact your Network Security Administrator about installing a valid certificate in the appropriate Certificate Store.
 	IPSEC_IKE_NO_CERT 13806
+	/// IKE SA deleted by peer before establishment completed.
 	IPSEC_IKE_SA_DELETED 13807
+	/// IKE SA deleted before establishment completed.
 	IPSEC_IKE_SA_REAPED 13808
+	/// Negotiation request sat in Queue too long.
 	IPSEC_IKE_MM_ACQUIRE_DROP 13809
+	/// Negotiation request sat in Queue too long.
 	IPSEC_IKE_QM_ACQUIRE_DROP 13810
+	/// Negotiation request sat in Queue too long.
 	IPSEC_IKE_QUEUE_DROP_MM 13811
+	/// Negotiation request sat in Queue too long.
 	IPSEC_IKE_QUEUE_DROP_NO_MM 13812
+	/// No response from peer.
 	IPSEC_IKE_DROP_NO_RESPONSE 13813
+	/// Negotiation took too long.
 	IPSEC_IKE_MM_DELAY_DROP 13814
+	/// Negotiation took too long.
 	IPSEC_IKE_QM_DELAY_DROP 13815
+	/// Unknown error occurred.
 	IPSEC_IKE_ERROR 13816
+	/// Certificate Revocation Check failed.
 	IPSEC_IKE_CRL_FAILED 13817
+	/// Invalid certificate key usage.
 	IPSEC_IKE_INVALID_KEY_USAGE 13818
+	/// Invalid certificate type.
 	IPSEC_IKE_INVALID_CERT_TYPE 13819
+	/// IKE negotiation failed because the machine certificate used does not have a private key. IPsec certificates require a private key. Contact your Network Security administrator about replacing with a certificate that has a private key.
 	IPSEC_IKE_NO_PRIVATE_KEY 13820
+	/// Simultaneous rekeys were detected.
 	IPSEC_IKE_SIMULTANEOUS_REKEY 13821
+	/// Failure in Diffie-Hellman computation.
 	IPSEC_IKE_DH_FAIL 13822
+	/// Don't know how to process critical payload.
 	IPSEC_IKE_CRITICAL_PAYLOAD_NOT_RECOGNIZED 13823
+	/// Invalid header.
 	IPSEC_IKE_INVALID_HEADER 13824
+	/// No policy configured.
 	IPSEC_IKE_NO_POLICY 13825
+	/// Failed to verify signature.
 	IPSEC_IKE_INVALID_SIGNATURE 13826
+	/// Failed to authenticate using Kerberos.
 	IPSEC_IKE_KERBEROS_ERROR 13827
+	/// Peer's certificate did not have a public key.
 	IPSEC_IKE_NO_PUBLIC_KEY 13828
+	/// Error processing error payload.
 	IPSEC_IKE_PROCESS_ERR 13829
+	/// Error processing SA payload.
 	IPSEC_IKE_PROCESS_ERR_SA 13830
+	/// Error processing Proposal payload.
 	IPSEC_IKE_PROCESS_ERR_PROP 13831
+	/// Error processing Transform payload.
 	IPSEC_IKE_PROCESS_ERR_TRANS 13832
+	/// Error processing KE payload.
 	IPSEC_IKE_PROCESS_ERR_KE 13833
+	/// Error processing ID payload.
 	IPSEC_IKE_PROCESS_ERR_ID 13834
+	/// Error processing Cert payload.
 	IPSEC_IKE_PROCESS_ERR_CERT 13835
+	/// Error processing Certificate Request payload.
 	IPSEC_IKE_PROCESS_ERR_CERT_REQ 13836
+	/// Error processing Hash payload.
 	IPSEC_IKE_PROCESS_ERR_HASH 13837
+	/// Error processing Signature payload.
 	IPSEC_IKE_PROCESS_ERR_SIG 13838
+	/// Error processing Nonce payload.
 	IPSEC_IKE_PROCESS_ERR_NONCE 13839
+	/// Error processing Notify payload.
 	IPSEC_IKE_PROCESS_ERR_NOTIFY 13840
+	/// Error processing Delete Payload.
 	IPSEC_IKE_PROCESS_ERR_DELETE 13841
+	/// Error processing VendorId payload.
 	IPSEC_IKE_PROCESS_ERR_VENDOR 13842
+	/// Invalid payload received.
 	IPSEC_IKE_INVALID_PAYLOAD 13843
+	/// Soft SA loaded.
 	IPSEC_IKE_LOAD_SOFT_SA 13844
+	/// Soft SA torn down.
 	IPSEC_IKE_SOFT_SA_TORN_DOWN 13845
+	/// Invalid cookie received.
 	IPSEC_IKE_INVALID_COOKIE 13846
+	/// Peer failed to send valid machine certificate.
 	IPSEC_IKE_NO_PEER_CERT 13847
+	/// Certification Revocation check of peer's certificate failed.
 	IPSEC_IKE_PEER_CRL_FAILED 13848
+	/// New policy invalidated SAs formed with old policy.
 	IPSEC_IKE_POLICY_CHANGE 13849
+	/// There is no available Main Mode IKE policy.
 	IPSEC_IKE_NO_MM_POLICY 13850
+	/// Failed to enabled TCB privilege.
 	IPSEC_IKE_NOTCBPRIV 13851
+	/// Failed to load SECURITY.DLL.
 	IPSEC_IKE_SECLOADFAIL 13852
+	/// Failed to obtain security function table dispatch address from SSPI.
 	IPSEC_IKE_FAILSSPINIT 13853
+	/// Failed to query Kerberos package to obtain max token size.
 	IPSEC_IKE_FAILQUERYSSP 13854
+	/// Failed to obtain Kerberos server credentials for ISAKMP/ERROR_IPSEC_IKE service. Kerberos authentication will not function. The most likely reason for this is lack of domain membership. This is normal if your computer is a member of a workgroup.
 	IPSEC_IKE_SRVACQFAIL 13855
+	/// Failed to determine SSPI principal name for ISAKMP/ERROR_IPSEC_IKE service (QueryCredentialsAttributes).
 	IPSEC_IKE_SRVQUERYCRED 13856
+	/// Failed to obtain new SPI for the inbound SA from IPsec driver. The most common cause for this is that the driver does not have the correct filter. Check your policy to verify the filters.
 	IPSEC_IKE_GETSPIFAIL 13857
+	/// Given filter is invalid.
 	IPSEC_IKE_INVALID_FILTER 13858
+	/// Memory allocation failed.
 	IPSEC_IKE_OUT_OF_MEMORY 13859
+	/// Failed to add Security Association to IPsec Driver. The most common cause for this is if the IKE negotiation took too long to complete. If the problem persists, reduce the load on the faulting machine.
 	IPSEC_IKE_ADD_UPDATE_KEY_FAILED 13860
+	/// Invalid policy.
 	IPSEC_IKE_INVALID_POLICY 13861
+	/// Invalid DOI.
 	IPSEC_IKE_UNKNOWN_DOI 13862
+	/// Invalid situation.
 	IPSEC_IKE_INVALID_SITUATION 13863
+	/// Diffie-Hellman failure.
 	IPSEC_IKE_DH_FAILURE 13864
+	/// Invalid Diffie-Hellman group.
 	IPSEC_IKE_INVALID_GROUP 13865
+	/// Error encrypting payload.
 	IPSEC_IKE_ENCRYPT 13866
+	/// Error decrypting payload.
 	IPSEC_IKE_DECRYPT 13867
+	/// Policy match error.
 	IPSEC_IKE_POLICY_MATCH 13868
+	/// Unsupported ID.
 	IPSEC_IKE_UNSUPPORTED_ID 13869
+	/// Hash verification failed.
 	IPSEC_IKE_INVALID_HASH 13870
+	/// Invalid hash algorithm.
 	IPSEC_IKE_INVALID_HASH_ALG 13871
+	/// Invalid hash size.
 	IPSEC_IKE_INVALID_HASH_SIZE 13872
+	/// Invalid encryption algorithm.
 	IPSEC_IKE_INVALID_ENCRYPT_ALG 13873
+	/// Invalid authentication algorithm.
 	IPSEC_IKE_INVALID_AUTH_ALG 13874
+	/// Invalid certificate signature.
 	IPSEC_IKE_INVALID_SIG 13875
+	/// Load failed.
 	IPSEC_IKE_LOAD_FAILED 13876
+	/// Deleted via RPC call.
 	IPSEC_IKE_RPC_DELETE 13877
+	/// Temporary state created to perform reinitialization. This is not a real failure.
 	IPSEC_IKE_BENIGN_REINIT 13878
+	/// The lifetime value received in the Responder Lifetime Notify is below the Windows 2000 configured minimum value. Please fix the policy on the peer machine.
 	IPSEC_IKE_INVALID_RESPONDER_LIFETIME_NOTIFY 13879
+	/// The recipient cannot handle version of IKE specified in the header.
 	IPSEC_IKE_INVALID_MAJOR_VERSION 13880
+	/// Key length in certificate is too small for configured security requirements.
 	IPSEC_IKE_INVALID_CERT_KEYLEN 13881
+	/// Max number of established MM SAs to peer exceeded.
 	IPSEC_IKE_MM_LIMIT 13882
+	/// IKE received a policy that disables negotiation.
 	IPSEC_IKE_NEGOTIATION_DISABLED 13883
+	/// Reached maximum quick mode limit for the main mode. New main mode will be started.
 	IPSEC_IKE_QM_LIMIT 13884
+	/// Main mode SA lifetime expired or peer sent a main mode delete.
 	IPSEC_IKE_MM_EXPIRED 13885
+	/// Main mode SA assumed to be invalid because peer stopped responding.
 	IPSEC_IKE_PEER_MM_ASSUMED_INVALID 13886
+	/// Certificate doesn't chain to a trusted root in IPsec policy.
 	IPSEC_IKE_CERT_CHAIN_POLICY_MISMATCH 13887
+	/// Received unexpected message ID.
 	IPSEC_IKE_UNEXPECTED_MESSAGE_ID 13888
+	/// Received invalid authentication offers.
 	IPSEC_IKE_INVALID_AUTH_PAYLOAD 13889
+	/// Sent DoS cookie notify to initiator.
 	IPSEC_IKE_DOS_COOKIE_SENT 13890
+	/// IKE service is shutting down.
 	IPSEC_IKE_SHUTTING_DOWN 13891
+	/// Could not verify binding between CGA address and certificate.
 	IPSEC_IKE_CGA_AUTH_FAILED 13892
+	/// Error processing NatOA payload.
 	IPSEC_IKE_PROCESS_ERR_NATOA 13893
+	/// Parameters of the main mode are invalid for this quick mode.
 	IPSEC_IKE_INVALID_MM_FOR_QM 13894
+	/// Quick mode SA was expired by IPsec driver.
 	IPSEC_IKE_QM_EXPIRED 13895
+	/// Too many dynamically added IKEEXT filters were detected.
 	IPSEC_IKE_TOO_MANY_FILTERS 13896
+	/// ERROR_IPSEC_IKE_NEG_STATUS_END
 	IPSEC_IKE_NEG_STATUS_END 13897
+	/// NAP reauth succeeded and must delete the dummy NAP IKEv2 tunnel.
 	IPSEC_IKE_KILL_DUMMY_NAP_TUNNEL 13898
+	/// Error in assigning inner IP address to initiator in tunnel mode.
 	IPSEC_IKE_INNER_IP_ASSIGNMENT_FAILURE 13899
+	/// Require configuration payload missing.
 	IPSEC_IKE_REQUIRE_CP_PAYLOAD_MISSING 13900
+	/// A negotiation running as the security principle who issued the connection is in progress.
 	IPSEC_KEY_MODULE_IMPERSONATION_NEGOTIATION_PENDING 13901
+	/// SA was deleted due to IKEv1/AuthIP co-existence suppress check.
 	IPSEC_IKE_COEXISTENCE_SUPPRESS 13902
+	/// Incoming SA request was dropped due to peer IP address rate limiting.
 	IPSEC_IKE_RATELIMIT_DROP 13903
+	/// Peer does not support MOBIKE.
 	IPSEC_IKE_PEER_DOESNT_SUPPORT_MOBIKE 13904
+	/// SA establishment is not authorized.
 	IPSEC_IKE_AUTHORIZATION_FAILURE 13905
+	/// SA establishment is not authorized because there is not a sufficiently strong PKINIT-based credential.
 	IPSEC_IKE_STRONG_CRED_AUTHORIZATION_FAILURE 13906
+	/// SA establishment is not authorized. You may need to enter updated or different credentials such as a smartcard.
 	IPSEC_IKE_AUTHORIZATION_FAILURE_WITH_OPTIONAL_RETRY 13907
+	/// SA establishment is not authorized because there is not a sufficiently strong PKINIT-based credential. This might be related to certificate-to-account mapping failure for the SA.
 	IPSEC_IKE_STRONG_CRED_AUTHORIZATION_AND_CERTMAP_FAILURE 13908
+	/// ERROR_IPSEC_IKE_NEG_STATUS_EXTENDED_END
 	IPSEC_IKE_NEG_STATUS_EXTENDED_END 13909
+	/// The SPI in the packet does not match a valid IPsec SA.
 	IPSEC_BAD_SPI 13910
+	/// Packet was received on an IPsec SA whose lifetime has expired.
 	IPSEC_SA_LIFETIME_EXPIRED 13911
+	/// Packet was received on an IPsec SA that does not match the packet characteristics.
 	IPSEC_WRONG_SA 13912
+	/// Packet sequence number replay check failed.
 	IPSEC_REPLAY_CHECK_FAILED 13913
+	/// IPsec header and/or trailer in the packet is invalid.
 	IPSEC_INVALID_PACKET 13914
+	/// IPsec integrity check failed.
 	IPSEC_INTEGRITY_CHECK_FAILED 13915
+	/// IPsec dropped a clear text packet.
 	IPSEC_CLEAR_TEXT_DROP 13916
+	/// IPsec dropped an incoming ESP packet in authenticated firewall mode. This drop is benign.
 	IPSEC_AUTH_FIREWALL_DROP 13917
+	/// IPsec dropped a packet due to DoS throttling.
 	IPSEC_THROTTLE_DROP 13918
+	/// IPsec DoS Protection matched an explicit block rule.
 	IPSEC_DOSP_BLOCK 13925
+	/// IPsec DoS Protection received an IPsec specific multicast packet which is not allowed.
 	IPSEC_DOSP_RECEIVED_MULTICAST 13926
+	/// IPsec DoS Protection received an incorrectly formatted packet.
 	IPSEC_DOSP_INVALID_PACKET 13927
+	/// IPsec DoS Protection failed to look up state.
 	IPSEC_DOSP_STATE_LOOKUP_FAILED 13928
+	/// IPsec DoS Protection failed to create state because the maximum number of entries allowed by policy has been reached.
 	IPSEC_DOSP_MAX_ENTRIES 13929
+	/// IPsec DoS Protection received an IPsec negotiation packet for a keying module which is not allowed by policy.
 	IPSEC_DOSP_KEYMOD_NOT_ALLOWED 13930
+	/// IPsec DoS Protection has not been enabled.
 	IPSEC_DOSP_NOT_INSTALLED 13931
+	/// IPsec DoS Protection failed to create a per internal IP rate limit queue because the maximum number of queues allowed by policy has been reached.
 	IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES 13932
+	/// The requested section was not present in the activation context.
 	SXS_SECTION_NOT_FOUND 14000
+	/// The application has failed to start because its side-by-side configuration is incorrect. Please see the application event log or use the command-line sxstrace.exe tool for more detail.
 	SXS_CANT_GEN_ACTCTX 14001
+	/// The application binding data format is invalid.
 	SXS_INVALID_ACTCTXDATA_FORMAT 14002
+	/// The referenced assembly is not installed on your system.
 	SXS_ASSEMBLY_NOT_FOUND 14003
+	/// The manifest file does not begin with the required tag and format information.
 	SXS_MANIFEST_FORMAT_ERROR 14004
+	/// The manifest file contains one or more syntax errors.
 	SXS_MANIFEST_PARSE_ERROR 14005
+	/// The application attempted to activate a disabled activation context.
 	SXS_ACTIVATION_CONTEXT_DISABLED 14006
+	/// The requested lookup key was not found in any active activation context.
 	SXS_KEY_NOT_FOUND 14007
+	/// A component version required by the application conflicts with another component version already active.
 	SXS_VERSION_CONFLICT 14008
+	/// The type requested activation context section does not match the query API used.
 	SXS_WRONG_SECTION_TYPE 14009
+	/// Lack of system resources has required isolated activation to be disabled for the current thread of execution.
 	SXS_THREAD_QUERIES_DISABLED 14010
+	/// An attempt to set the process default activation context failed because the process default activation context was already set.
 	SXS_PROCESS_DEFAULT_ALREADY_SET 14011
+	/// The encoding group identifier specified is not recognized.
 	SXS_UNKNOWN_ENCODING_GROUP 14012
+	/// The encoding requested is not recognized.
 	SXS_UNKNOWN_ENCODING 14013
+	/// The manifest contains a reference to an invalid URI.
 	SXS_INVALID_XML_NAMESPACE_URI 14014
+	/// The application manifest contains a reference to a dependent assembly which is not installed.
 	SXS_ROOT_MANIFEST_DEPENDENCY_NOT_INSTALLED 14015
+	/// The manifest for an assembly used by the application has a reference to a dependent assembly which is not installed.
 	SXS_LEAF_MANIFEST_DEPENDENCY_NOT_INSTALLED 14016
+	/// The manifest contains an attribute for the assembly identity which is not valid.
 	SXS_INVALID_ASSEMBLY_IDENTITY_ATTRIBUTE 14017
+	/// The manifest is missing the required default namespace specification on the assembly element.
 	SXS_MANIFEST_MISSING_REQUIRED_DEFAULT_NAMESPACE 14018
+	/// The manifest has a default namespace specified on the assembly element but its value is not "urn:schemas-microsoft-com:asm.v1".
 	SXS_MANIFEST_INVALID_REQUIRED_DEFAULT_NAMESPACE 14019
+	/// The private manifest probed has crossed a path with an unsupported reparse point.
 	SXS_PRIVATE_MANIFEST_CROSS_PATH_WITH_REPARSE_POINT 14020
+	/// Two or more components referenced directly or indirectly by the application manifest have files by the same name.
 	SXS_DUPLICATE_DLL_NAME 14021
+	/// Two or more components referenced directly or indirectly by the application manifest have window classes with the same name.
 	SXS_DUPLICATE_WINDOWCLASS_NAME 14022
+	/// Two or more components referenced directly or indirectly by the application manifest have the same COM server CLSIDs.
 	SXS_DUPLICATE_CLSID 14023
+	/// Two or more components referenced directly or indirectly by the application manifest have proxies for the same COM interface IIDs.
 	SXS_DUPLICATE_IID 14024
+	/// Two or more components referenced directly or indirectly by the application manifest have the same COM type library TLBIDs.
 	SXS_DUPLICATE_TLBID 14025
+	/// Two or more components referenced directly or indirectly by the application manifest have the same COM ProgIDs.
 	SXS_DUPLICATE_PROGID 14026
+	/// Two or more components referenced directly or indirectly by the application manifest are different versions of the same component which is not permitted.
 	SXS_DUPLICATE_ASSEMBLY_NAME 14027
+	/// A component's file does not match the verification information present in the component manifest.
 	SXS_FILE_HASH_MISMATCH 14028
+	/// The policy manifest contains one or more syntax errors.
 	SXS_POLICY_PARSE_ERROR 14029
+	/// Manifest Parse Error : A string literal was expected, but no opening quote character was found.
 	SXS_XML_E_MISSINGQUOTE 14030
+	/// Manifest Parse Error : Incorrect syntax was used in a comment.
 	SXS_XML_E_COMMENTSYNTAX 14031
+	/// Manifest Parse Error : A name started with an invalid character.
 	SXS_XML_E_BADSTARTNAMECHAR 14032
+	/// Manifest Parse Error : A name contained an invalid character.
 	SXS_XML_E_BADNAMECHAR 14033
+	/// Manifest Parse Error : A string literal contained an invalid character.
 	SXS_XML_E_BADCHARINSTRING 14034
+	/// Manifest Parse Error : Invalid syntax for an xml declaration.
 	SXS_XML_E_XMLDECLSYNTAX 14035
+	/// Manifest Parse Error : An Invalid character was found in text content.
 	SXS_XML_E_BADCHARDATA 14036
+	/// Manifest Parse Error : Required white space was missing.
 	SXS_XML_E_MISSINGWHITESPACE 14037
+	/// Manifest Parse Error : The character ')' was expected.
 	SXS_XML_E_EXPECTINGTAGEND 14038
+	/// Manifest Parse Error : A semi colon character was expected.
 	SXS_XML_E_MISSINGSEMICOLON 14039
+	/// Manifest Parse Error : Unbalanced parentheses.
 	SXS_XML_E_UNBALANCEDPAREN 14040
+	/// Manifest Parse Error : Internal error.
 	SXS_XML_E_INTERNALERROR 14041
+	/// Manifest Parse Error : Whitespace is not allowed at this location.
 	SXS_XML_E_UNEXPECTED_WHITESPACE 14042
+	/// Manifest Parse Error : End of file reached in invalid state for current encoding.
 	SXS_XML_E_INCOMPLETE_ENCODING 14043
+	/// Manifest Parse Error : Missing parenthesis.
 	SXS_XML_E_MISSING_PAREN 14044
+	/// Manifest Parse Error : A single or double closing quote character is missing.
 	SXS_XML_E_EXPECTINGCLOSEQUOTE 14045
+	/// Manifest Parse Error : Multiple colons are not allowed in a name.
 	SXS_XML_E_MULTIPLE_COLONS 14046
+	/// Manifest Parse Error : Invalid character for decimal digit.
 	SXS_XML_E_INVALID_DECIMAL 14047
+	/// Manifest Parse Error : Invalid character for hexadecimal digit.
 	SXS_XML_E_INVALID_HEXIDECIMAL 14048
+	/// Manifest Parse Error : Invalid unicode character value for this platform.
 	SXS_XML_E_INVALID_UNICODE 14049
+	/// Manifest Parse Error : Expecting whitespace or '?'.
 	SXS_XML_E_WHITESPACEORQUESTIONMARK 14050
+	/// Manifest Parse Error : End tag was not expected at this location.
 	SXS_XML_E_UNEXPECTEDENDTAG 14051
+	/// Manifest Parse Error : The following tags were not closed: %1.
 	SXS_XML_E_UNCLOSEDTAG 14052
+	/// Manifest Parse Error : Duplicate attribute.
 	SXS_XML_E_DUPLICATEATTRIBUTE 14053
+	/// Manifest Parse Error : Only one top level element is allowed in an XML document.
 	SXS_XML_E_MULTIPLEROOTS 14054
+	/// Manifest Parse Error : Invalid at the top level of the document.
 	SXS_XML_E_INVALIDATROOTLEVEL 14055
+	/// Manifest Parse Error : Invalid xml declaration.
 	SXS_XML_E_BADXMLDECL 14056
+	/// Manifest Parse Error : XML document must have a top level element.
 	SXS_XML_E_MISSINGROOT 14057
+	/// Manifest Parse Error : Unexpected end of file.
 	SXS_XML_E_UNEXPECTEDEOF 14058
+	/// Manifest Parse Error : Parameter entities cannot be used inside markup declarations in an internal subset.
 	SXS_XML_E_BADPEREFINSUBSET 14059
+	/// Manifest Parse Error : Element was not closed.
 	SXS_XML_E_UNCLOSEDSTARTTAG 14060
+	/// Manifest Parse Error : End element was missing the character ')'.
 	SXS_XML_E_UNCLOSEDENDTAG 14061
+	/// Manifest Parse Error : A string literal was not closed.
 	SXS_XML_E_UNCLOSEDSTRING 14062
+	/// Manifest Parse Error : A comment was not closed.
 	SXS_XML_E_UNCLOSEDCOMMENT 14063
+	/// Manifest Parse Error : A declaration was not closed.
 	SXS_XML_E_UNCLOSEDDECL 14064
+	/// Manifest Parse Error : A CDATA section was not closed.
 	SXS_XML_E_UNCLOSEDCDATA 14065
+	/// Manifest Parse Error : The namespace prefix is not allowed to start with the reserved string "xml".
 	SXS_XML_E_RESERVEDNAMESPACE 14066
+	/// Manifest Parse Error : System does not support the specified encoding.
 	SXS_XML_E_INVALIDENCODING 14067
+	/// Manifest Parse Error : Switch from current encoding to specified encoding not supported.
 	SXS_XML_E_INVALIDSWITCH 14068
+	/// Manifest Parse Error : The name 'xml' is reserved and must be lower case.
 	SXS_XML_E_BADXMLCASE 14069
+	/// Manifest Parse Error : The standalone attribute must have the value 'yes' or 'no'.
 	SXS_XML_E_INVALID_STANDALONE 14070
+	/// Manifest Parse Error : The standalone attribute cannot be used in external entities.
 	SXS_XML_E_UNEXPECTED_STANDALONE 14071
+	/// Manifest Parse Error : Invalid version number.
 	SXS_XML_E_INVALID_VERSION 14072
+	/// Manifest Parse Error : Missing equals sign between attribute and attribute value.
 	SXS_XML_E_MISSINGEQUALS 14073
+	/// Assembly Protection Error : Unable to recover the specified assembly.
 	SXS_PROTECTION_RECOVERY_FAILED 14074
+	/// Assembly Protection Error : The public key for an assembly was too short to be allowed.
 	SXS_PROTECTION_PUBLIC_KEY_TOO_SHORT 14075
+	/// Assembly Protection Error : The catalog for an assembly is not valid, or does not match the assembly's manifest.
 	SXS_PROTECTION_CATALOG_NOT_VALID 14076
+	/// An HRESULT could not be translated to a corresponding Win32 error code.
 	SXS_UNTRANSLATABLE_HRESULT 14077
+	/// Assembly Protection Error : The catalog for an assembly is missing.
 	SXS_PROTECTION_CATALOG_FILE_MISSING 14078
+	/// The supplied assembly identity is missing one or more attributes which must be present in this context.
 	SXS_MISSING_ASSEMBLY_IDENTITY_ATTRIBUTE 14079
+	/// The supplied assembly identity has one or more attribute names that contain characters not permitted in XML names.
 	SXS_INVALID_ASSEMBLY_IDENTITY_ATTRIBUTE_NAME 14080
+	/// The referenced assembly could not be found.
 	SXS_ASSEMBLY_MISSING 14081
+	/// The activation context activation stack for the running thread of execution is corrupt.
 	SXS_CORRUPT_ACTIVATION_STACK 14082
+	/// The application isolation metadata for this process or thread has become corrupt.
 	SXS_CORRUPTION 14083
+	/// The activation context being deactivated is not the most recently activated one.
 	SXS_EARLY_DEACTIVATION 14084
+	/// The activation context being deactivated is not active for the current thread of execution.
 	SXS_INVALID_DEACTIVATION 14085
+	/// The activation context being deactivated has already been deactivated.
 	SXS_MULTIPLE_DEACTIVATION 14086
+	/// A component used by the isolation facility has requested to terminate the process.
 	SXS_PROCESS_TERMINATION_REQUESTED 14087
+	/// A kernel mode component is releasing a reference on an activation context.
 	SXS_RELEASE_ACTIVATION_CONTEXT 14088
+	/// The activation context of system default assembly could not be generated.
 	SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY 14089
+	/// The value of an attribute in an identity is not within the legal range.
 	SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE 14090
+	/// The name of an attribute in an identity is not within the legal range.
 	SXS_INVALID_IDENTITY_ATTRIBUTE_NAME 14091
+	/// An identity contains two definitions for the same attribute.
 	SXS_IDENTITY_DUPLICATE_ATTRIBUTE 14092
+	/// The identity string is malformed. This may be due to a trailing comma, more than two unnamed attributes, missing attribute name or missing attribute value.
 	SXS_IDENTITY_PARSE_ERROR 14093
+	/// A string containing localized substitutable content was malformed. Either a dollar sign ($) was followed by something other than a left parenthesis or another dollar sign or an substitution's right parenthesis was not found.
 	MALFORMED_SUBSTITUTION_STRING 14094
+	/// The public key token does not correspond to the public key specified.
 	SXS_INCORRECT_PUBLIC_KEY_TOKEN 14095
+	/// A substitution string had no mapping.
 	UNMAPPED_SUBSTITUTION_STRING 14096
+	/// The component must be locked before making the request.
 	SXS_ASSEMBLY_NOT_LOCKED 14097
+	/// The component store has been corrupted.
 	SXS_COMPONENT_STORE_CORRUPT 14098
+	/// An advanced installer failed during setup or servicing.
 	ADVANCED_INSTALLER_FAILED 14099
+	/// The character encoding in the XML declaration did not match the encoding used in the document.
 	XML_ENCODING_MISMATCH 14100
+	/// The identities of the manifests are identical but the contents are different.
 	SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT 14101
+	/// The component identities are different.
 	SXS_IDENTITIES_DIFFERENT 14102
+	/// The assembly is not a deployment.
 	SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT 14103
+	/// The file is not a part of the assembly.
 	SXS_FILE_NOT_PART_OF_ASSEMBLY 14104
+	/// The size of the manifest exceeds the maximum allowed.
 	SXS_MANIFEST_TOO_BIG 14105
+	/// The setting is not registered.
 	SXS_SETTING_NOT_REGISTERED 14106
+	/// One or more required members of the transaction are not present.
 	SXS_TRANSACTION_CLOSURE_INCOMPLETE 14107
+	/// The SMI primitive installer failed during setup or servicing.
 	SMI_PRIMITIVE_INSTALLER_FAILED 14108
+	/// A generic command executable returned a result that indicates failure.
 	GENERIC_COMMAND_FAILED 14109
+	/// A component is missing file verification information in its manifest.
 	SXS_FILE_HASH_MISSING 14110
+	/// Two or more components referenced directly or indirectly by the application manifest have the same WinRT ActivatableClass IDs.
 	SXS_DUPLICATE_ACTIVATABLE_CLASS 14111
+	/// The specified channel path is invalid.
 	EVT_INVALID_CHANNEL_PATH 15000
+	/// The specified query is invalid.
 	EVT_INVALID_QUERY 15001
+	/// The publisher metadata cannot be found in the resource.
 	EVT_PUBLISHER_METADATA_NOT_FOUND 15002
+	/// The template for an event definition cannot be found in the resource (error = %1).
 	EVT_EVENT_TEMPLATE_NOT_FOUND 15003
+	/// The specified publisher name is invalid.
 	EVT_INVALID_PUBLISHER_NAME 15004
+	/// The event data raised by the publisher is not compatible with the event template definition in the publisher's manifest.
 	EVT_INVALID_EVENT_DATA 15005
+	/// The specified channel could not be found.
 	EVT_CHANNEL_NOT_FOUND 15007
+	/// The specified XML text was not well-formed. See Extended Error for more details.
 	EVT_MALFORMED_XML_TEXT 15008
+	/// The events for a direct channel go directly to a log file and cannot be subscribed to.
 	EVT_SUBSCRIPTION_TO_DIRECT_CHANNEL 15009
+	/// Configuration error.
 	EVT_CONFIGURATION_ERROR 15010
+	/// The query result is stale or invalid. This may be due to the log being cleared or rolling over after the query result was created. Users should handle this code by releasing the query result object and reissuing the query.
 	EVT_QUERY_RESULT_STALE 15011
+	/// Query result is currently at an invalid position.
 	EVT_QUERY_RESULT_INVALID_POSITION 15012
+	/// Registered MSXML doesn't support validation.
 	EVT_NON_VALIDATING_MSXML 15013
+	/// An expression can only be followed by a change of scope operation if it itself evaluates to a node set and is not already part of some other change of scope operation.
 	EVT_FILTER_ALREADYSCOPED 15014
+	/// Can't perform a step operation from a term that does not represent an element set.
 	EVT_FILTER_NOTELTSET 15015
+	/// Left hand side arguments to binary operators must be either attributes, nodes or variables and right hand side arguments must be constants.
 	EVT_FILTER_INVARG 15016
+	/// A step operation must involve either a node test or, in the case of a predicate, an algebraic expression against which to test each node in the node set identified by the preceding node set can be evaluated.
 	EVT_FILTER_INVTEST 15017
+	/// This data type is currently unsupported.
 	EVT_FILTER_INVTYPE 15018
+	/// A syntax error occurred at position %1!d!
 	EVT_FILTER_PARSEERR 15019
+	/// This operator is unsupported by this implementation of the filter.
 	EVT_FILTER_UNSUPPORTEDOP 15020
+	/// The token encountered was unexpected.
 	EVT_FILTER_UNEXPECTEDTOKEN 15021
+	/// The requested operation cannot be performed over an enabled direct channel. The channel must first be disabled.
 	EVT_INVALID_OPERATION_OVER_ENABLED_DIRECT_CHANNEL 15022
+	/// Channel property %1!s! contains an invalid value. The value has an invalid type, is outside of its valid range, cannot be changed, or is not supported by this type of channel.
 	EVT_INVALID_CHANNEL_PROPERTY_VALUE 15023
+	/// Publisher property %1!s! contains an invalid value. The value has an invalid type, is outside of its valid range, cannot be changed, or is not supported by this type of publisher.
 	EVT_INVALID_PUBLISHER_PROPERTY_VALUE 15024
+	/// The channel failed to activate.
 	EVT_CHANNEL_CANNOT_ACTIVATE 15025
+	/// The XPath expression exceeded the supported complexity. Simplify the expression or split it into two or more simpler expressions.
 	EVT_FILTER_TOO_COMPLEX 15026
+	/// The message resource is present but the message was not found in the message table.
 	EVT_MESSAGE_NOT_FOUND 15027
+	/// The message ID for the desired message could not be found.
 	EVT_MESSAGE_ID_NOT_FOUND 15028
+	/// The substitution string for insert index (%1) could not be found.
 	EVT_UNRESOLVED_VALUE_INSERT 15029
+	/// The description string for parameter reference (%1) could not be found.
 	EVT_UNRESOLVED_PARAMETER_INSERT 15030
+	/// The maximum number of replacements has been reached.
 	EVT_MAX_INSERTS_REACHED 15031
+	/// The event definition could not be found for the event ID (%1).
 	EVT_EVENT_DEFINITION_NOT_FOUND 15032
+	/// The locale specific resource for the desired message is not present.
 	EVT_MESSAGE_LOCALE_NOT_FOUND 15033
+	/// The resource is too old to be compatible.
 	EVT_VERSION_TOO_OLD 15034
+	/// The resource is too new to be compatible.
 	EVT_VERSION_TOO_NEW 15035
+	/// The channel at index %1!d! of the query can't be opened.
 	EVT_CANNOT_OPEN_CHANNEL_OF_QUERY 15036
+	/// The publisher has been disabled and its resource is not available. This usually occurs when the publisher is in the process of being uninstalled or upgraded.
 	EVT_PUBLISHER_DISABLED 15037
+	/// Attempted to create a numeric type that is outside of its valid range.
 	EVT_FILTER_OUT_OF_RANGE 15038
+	/// The subscription fails to activate.
 	EC_SUBSCRIPTION_CANNOT_ACTIVATE 15080
+	/// The log of the subscription is in disabled state, and can not be used to forward events to. The log must first be enabled before the subscription can be activated.
 	EC_LOG_DISABLED 15081
+	/// When forwarding events from local machine to itself, the query of the subscription can't contain target log of the subscription.
 	EC_CIRCULAR_FORWARDING 15082
+	/// The credential store that is used to save credentials is full.
 	EC_CREDSTORE_FULL 15083
+	/// The credential used by this subscription can't be found in credential store.
 	EC_CRED_NOT_FOUND 15084
+	/// No active channel is found for the query.
 	EC_NO_ACTIVE_CHANNEL 15085
+	/// The resource loader failed to find MUI file.
 	MUI_FILE_NOT_FOUND 15100
+	/// The resource loader failed to load MUI file because the file fail to pass validation.
 	MUI_INVALID_FILE 15101
+	/// The RC Manifest is corrupted with garbage data or unsupported version or missing required item.
 	MUI_INVALID_RC_CONFIG 15102
+	/// The RC Manifest has invalid culture name.
 	MUI_INVALID_LOCALE_NAME 15103
+	/// The RC Manifest has invalid ultimatefallback name.
 	MUI_INVALID_ULTIMATEFALLBACK_NAME 15104
+	/// The resource loader cache doesn't have loaded MUI entry.
 	MUI_FILE_NOT_LOADED 15105
+	/// User stopped resource enumeration.
 	RESOURCE_ENUM_USER_STOP 15106
+	/// UI language installation failed.
 	MUI_INTLSETTINGS_UILANG_NOT_INSTALLED 15107
+	/// Locale installation failed.
 	MUI_INTLSETTINGS_INVALID_LOCALE_NAME 15108
+	/// A resource does not have default or neutral value.
 	MRM_RUNTIME_NO_DEFAULT_OR_NEUTRAL_RESOURCE 15110
+	/// Invalid PRI config file.
 	MRM_INVALID_PRICONFIG 15111
+	/// Invalid file type.
 	MRM_INVALID_FILE_TYPE 15112
+	/// Unknown qualifier.
 	MRM_UNKNOWN_QUALIFIER 15113
+	/// Invalid qualifier value.
 	MRM_INVALID_QUALIFIER_VALUE 15114
+	/// No Candidate found.
 	MRM_NO_CANDIDATE 15115
+	/// The ResourceMap or NamedResource has an item that does not have default or neutral resource.
 	MRM_NO_MATCH_OR_DEFAULT_CANDIDATE 15116
+	/// Invalid ResourceCandidate type.
 	MRM_RESOURCE_TYPE_MISMATCH 15117
+	/// Duplicate Resource Map.
 	MRM_DUPLICATE_MAP_NAME 15118
+	/// Duplicate Entry.
 	MRM_DUPLICATE_ENTRY 15119
+	/// Invalid Resource Identifier.
 	MRM_INVALID_RESOURCE_IDENTIFIER 15120
+	/// Filepath too long.
 	MRM_FILEPATH_TOO_LONG 15121
+	/// Unsupported directory type.
 	MRM_UNSUPPORTED_DIRECTORY_TYPE 15122
+	/// Invalid PRI File.
 	MRM_INVALID_PRI_FILE 15126
+	/// NamedResource Not Found.
 	MRM_NAMED_RESOURCE_NOT_FOUND 15127
+	/// ResourceMap Not Found.
 	MRM_MAP_NOT_FOUND 15135
+	/// Unsupported MRT profile type.
 	MRM_UNSUPPORTED_PROFILE_TYPE 15136
+	/// Invalid qualifier operator.
 	MRM_INVALID_QUALIFIER_OPERATOR 15137
+	/// Unable to determine qualifier value or qualifier value has not been set.
 	MRM_INDETERMINATE_QUALIFIER_VALUE 15138
+	/// Automerge is enabled in the PRI file.
 	MRM_AUTOMERGE_ENABLED 15139
+	/// Too many resources defined for package.
 	MRM_TOO_MANY_RESOURCES 15140
+	/// Resource File can not be used for merge operation.
 	MRM_UNSUPPORTED_FILE_TYPE_FOR_MERGE 15141
+	/// Load/UnloadPriFiles cannot be used with resource packages.
 	MRM_UNSUPPORTED_FILE_TYPE_FOR_LOAD_UNLOAD_PRI_FILE 15142
+	/// Resource Contexts may not be created on threads that do not have a CoreWindow.
 	MRM_NO_CURRENT_VIEW_ON_THREAD 15143
+	/// The singleton Resource Manager with different profile is already created.
 	DIFFERENT_PROFILE_RESOURCE_MANAGER_EXIST 15144
+	/// The system component cannot operate given API operation.
 	OPERATION_NOT_ALLOWED_FROM_SYSTEM_COMPONENT 15145
+	/// The resource is a direct reference to a non-default resource candidate.
 	MRM_DIRECT_REF_TO_NON_DEFAULT_RESOURCE 15146
+	/// Resource Map has been re-generated and the query string is not valid anymore.
 	MRM_GENERATION_COUNT_MISMATCH 15147
+	/// The PRI files to be merged have incompatible versions.
 	PRI_MERGE_VERSION_MISMATCH 15148
+	/// The primary PRI files to be merged does not contain a schema.
 	PRI_MERGE_MISSING_SCHEMA 15149
+	/// Unable to load one of the PRI files to be merged.
 	PRI_MERGE_LOAD_FILE_FAILED 15150
+	/// Unable to add one of the PRI files to the merged file.
 	PRI_MERGE_ADD_FILE_FAILED 15151
+	/// Unable to create the merged PRI file.
 	PRI_MERGE_WRITE_FILE_FAILED 15152
+	/// Packages for a PRI file merge must all be from the same package family.
 	PRI_MERGE_MULTIPLE_PACKAGE_FAMILIES_NOT_ALLOWED 15153
+	/// Packages for a PRI file merge must not include multiple main packages.
 	PRI_MERGE_MULTIPLE_MAIN_PACKAGES_NOT_ALLOWED 15154
+	/// Packages for a PRI file merge must not include bundle packages.
 	PRI_MERGE_BUNDLE_PACKAGES_NOT_ALLOWED 15155
+	/// Packages for a PRI file merge must include one main package.
 	PRI_MERGE_MAIN_PACKAGE_REQUIRED 15156
+	/// Packages for a PRI file merge must include at least one resource package.
 	PRI_MERGE_RESOURCE_PACKAGE_REQUIRED 15157
+	/// Invalid name supplied for a canonical merged PRI file.
 	PRI_MERGE_INVALID_FILE_NAME 15158
+	/// Unable to find the specified package.
 	MRM_PACKAGE_NOT_FOUND 15159
+	/// No default value for language was specified.
 	MRM_MISSING_DEFAULT_LANGUAGE 15160
+	/// The monitor returned a DDC/CI capabilities string that did not comply with the ACCESS.bus 3.0, DDC/CI 1.1 or MCCS 2 Revision 1 specification.
 	MCA_INVALID_CAPABILITIES_STRING 15200
+	/// The monitor's VCP Version (0xDF) VCP code returned an invalid version value.
 	MCA_INVALID_VCP_VERSION 15201
+	/// The monitor does not comply with the MCCS specification it claims to support.
 	MCA_MONITOR_VIOLATES_MCCS_SPECIFICATION 15202
+	/// The MCCS version in a monitor's mccs_ver capability does not match the MCCS version the monitor reports when the VCP Version (0xDF) VCP code is used.
 	MCA_MCCS_VERSION_MISMATCH 15203
+	/// The Monitor Configuration API only works with monitors that support the MCCS 1.0 specification, MCCS 2.0 specification or the MCCS 2.0 Revision 1 specification.
 	MCA_UNSUPPORTED_MCCS_VERSION 15204
+	/// An internal Monitor Configuration API error occurred.
 	MCA_INTERNAL_ERROR 15205
+	/// The monitor returned an invalid monitor technology type. CRT, Plasma and LCD (TFT) are examples of monitor technology types. This error implies that the monitor violated the MCCS 2.0 or MCCS 2.0 Revision 1 specification.
 	MCA_INVALID_TECHNOLOGY_TYPE_RETURNED 15206
+	/// The caller of SetMonitorColorTemperature specified a color temperature that the current monitor did not support. This error implies that the monitor violated the MCCS 2.0 or MCCS 2.0 Revision 1 specification.
 	MCA_UNSUPPORTED_COLOR_TEMPERATURE 15207
+	/// The requested system device cannot be identified due to multiple indistinguishable devices potentially matching the identification criteria.
 	AMBIGUOUS_SYSTEM_DEVICE 15250
+	/// The requested system device cannot be found.
 	SYSTEM_DEVICE_NOT_FOUND 15299
+	/// Hash generation for the specified hash version and hash type is not enabled on the server.
 	HASH_NOT_SUPPORTED 15300
+	/// The hash requested from the server is not available or no longer valid.
 	HASH_NOT_PRESENT 15301
+	/// The secondary interrupt controller instance that manages the specified interrupt is not registered.
 	SECONDARY_IC_PROVIDER_NOT_REGISTERED 15321
+	/// The information supplied by the GPIO client driver is invalid.
 	GPIO_CLIENT_INFORMATION_INVALID 15322
+	/// The version specified by the GPIO client driver is not supported.
 	GPIO_VERSION_NOT_SUPPORTED 15323
+	/// The registration packet supplied by the GPIO client driver is not valid.
 	GPIO_INVALID_REGISTRATION_PACKET 15324
+	/// The requested operation is not supported for the specified handle.
 	GPIO_OPERATION_DENIED 15325
+	/// The requested connect mode conflicts with an existing mode on one or more of the specified pins.
 	GPIO_INCOMPATIBLE_CONNECT_MODE 15326
+	/// The interrupt requested to be unmasked is not masked.
 	GPIO_INTERRUPT_ALREADY_UNMASKED 15327
+	/// The requested run level switch cannot be completed successfully.
 	CANNOT_SWITCH_RUNLEVEL 15400
+	/// The service has an invalid run level setting. The run level for a service must not be higher than the run level of its dependent services.
 	INVALID_RUNLEVEL_SETTING 15401
+	/// The requested run level switch cannot be completed successfully since one or more services will not stop or restart within the specified timeout.
 	RUNLEVEL_SWITCH_TIMEOUT 15402
+	/// A run level switch agent did not respond within the specified timeout.
 	RUNLEVEL_SWITCH_AGENT_TIMEOUT 15403
+	/// A run level switch is currently in progress.
 	RUNLEVEL_SWITCH_IN_PROGRESS 15404
+	/// One or more services failed to start during the service startup phase of a run level switch.
 	SERVICES_FAILED_AUTOSTART 15405
+	/// The task stop request cannot be completed immediately since task needs more time to shutdown.
 	COM_TASK_STOP_PENDING 15501
+	/// Package could not be opened.
 	INSTALL_OPEN_PACKAGE_FAILED 15600
+	/// Package was not found.
 	INSTALL_PACKAGE_NOT_FOUND 15601
+	/// Package data is invalid.
 	INSTALL_INVALID_PACKAGE 15602
+	/// Package failed updates, dependency or conflict validation.
 	INSTALL_RESOLVE_DEPENDENCY_FAILED 15603
+	/// There is not enough disk space on your computer. Please free up some space and try again.
 	INSTALL_OUT_OF_DISK_SPACE 15604
+	/// There was a problem downloading your product.
 	INSTALL_NETWORK_FAILURE 15605
+	/// Package could not be registered.
 	INSTALL_REGISTRATION_FAILURE 15606
+	/// Package could not be unregistered.
 	INSTALL_DEREGISTRATION_FAILURE 15607
+	/// User cancelled the install request.
 	INSTALL_CANCEL 15608
+	/// Install failed. Please contact your software vendor.
 	INSTALL_FAILED 15609
+	/// Removal failed. Please contact your software vendor.
 	REMOVE_FAILED 15610
+	/// The provided package is already installed, and reinstallation of the package was blocked. Check the AppXDeployment-Server event log for details.
 	PACKAGE_ALREADY_EXISTS 15611
+	/// The application cannot be started. Try reinstalling the application to fix the problem.
 	NEEDS_REMEDIATION 15612
+	/// A Prerequisite for an install could not be satisfied.
 	INSTALL_PREREQUISITE_FAILED 15613
+	/// The package repository is corrupted.
 	PACKAGE_REPOSITORY_CORRUPTED 15614
+	/// To install this application you need either a Windows developer license or a sideloading-enabled system.
 	INSTALL_POLICY_FAILURE 15615
+	/// The application cannot be started because it is currently updating.
 	PACKAGE_UPDATING 15616
+	/// The package deployment operation is blocked by policy. Please contact your system administrator.
 	DEPLOYMENT_BLOCKED_BY_POLICY 15617
+	/// The package could not be installed because resources it modifies are currently in use.
 	PACKAGES_IN_USE 15618
+	/// The package could not be recovered because necessary data for recovery have been corrupted.
 	RECOVERY_FILE_CORRUPT 15619
+	/// The signature is invalid. To register in developer mode, AppxSignature.p7x and AppxBlockMap.xml must be valid or should not be present.
 	INVALID_STAGED_SIGNATURE 15620
+	/// An error occurred while deleting the package's previously existing application data.
 	DELETING_EXISTING_APPLICATIONDATA_STORE_FAILED 15621
+	/// The package could not be installed because a higher version of this package is already installed.
 	INSTALL_PACKAGE_DOWNGRADE 15622
+	/// An error in a system binary was detected. Try refreshing the PC to fix the problem.
 	SYSTEM_NEEDS_REMEDIATION 15623
+	/// A corrupted CLR NGEN binary was detected on the system.
 	APPX_INTEGRITY_FAILURE_CLR_NGEN 15624
+	/// The operation could not be resumed because necessary data for recovery have been corrupted.
 	RESILIENCY_FILE_CORRUPT 15625
+	/// The package could not be installed because the Windows Firewall service is not running. Enable the Windows Firewall service and try again.
 	INSTALL_FIREWALL_SERVICE_NOT_RUNNING 15626
+	/// Package move failed.
 	PACKAGE_MOVE_FAILED 15627
+	/// The deployment operation failed because the volume is not empty.
 	INSTALL_VOLUME_NOT_EMPTY 15628
+	/// The deployment operation failed because the volume is offline.
 	INSTALL_VOLUME_OFFLINE 15629
+	/// The deployment operation failed because the specified volume is corrupt.
 	INSTALL_VOLUME_CORRUPT 15630
+	/// The deployment operation failed because the specified application needs to be registered first.
 	NEEDS_REGISTRATION 15631
+	/// The deployment operation failed because the package targets the wrong processor architecture.
 	INSTALL_WRONG_PROCESSOR_ARCHITECTURE 15632
+	/// You have reached the maximum number of developer sideloaded packages allowed on this device. Please uninstall a sideloaded package and try again.
 	DEV_SIDELOAD_LIMIT_EXCEEDED 15633
+	/// A main app package is required to install this optional package. Install the main package first and try again.
 	INSTALL_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE 15634
+	/// This app package type is not supported on this filesystem.
 	PACKAGE_NOT_SUPPORTED_ON_FILESYSTEM 15635
+	/// Package move operation is blocked until the application has finished streaming.
 	PACKAGE_MOVE_BLOCKED_BY_STREAMING 15636
+	/// A main or another optional app package has the same application ID as this optional package. Change the application ID for the optional package to avoid conflicts.
 	INSTALL_OPTIONAL_PACKAGE_APPLICATIONID_NOT_UNIQUE 15637
+	/// This staging session has been held to allow another staging operation to be prioritized.
 	PACKAGE_STAGING_ONHOLD 15638
+	/// A related set cannot be updated because the updated set is invalid. All packages in the related set must be updated at the same time.
 	INSTALL_INVALID_RELATED_SET_UPDATE 15639
+	/// An optional package with a FullTrust entry point requires the main package to have the runFullTrust capability.
 	INSTALL_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE_FULLTRUST_CAPABILITY 15640
+	/// An error occurred because a user was logged off.
 	DEPLOYMENT_BLOCKED_BY_USER_LOG_OFF 15641
+	/// An optional package provision requires the dependency main package to also be provisioned.
 	PROVISION_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE_PROVISIONED 15642
+	/// The packages failed the SmartScreen reputation check.
 	PACKAGES_REPUTATION_CHECK_FAILED 15643
+	/// The SmartScreen reputation check operation timed out.
 	PACKAGES_REPUTATION_CHECK_TIMEDOUT 15644
+	/// The current deployment option is not supported.
 	DEPLOYMENT_OPTION_NOT_SUPPORTED 15645
+	/// Activation is blocked due to the .appinstaller update settings for this app.
 	APPINSTALLER_ACTIVATION_BLOCKED 15646
+	/// Remote drives are not supported; use \\server\share to register a remote package.
 	REGISTRATION_FROM_REMOTE_DRIVE_NOT_SUPPORTED 15647
+	/// Failed to process and write downloaded APPX package data to disk.
 	APPX_RAW_DATA_WRITE_FAILED 15648
+	/// The deployment operation was blocked due to a per-package-family policy restricting deployments on a non-system volume. Per policy, this app must be installed to the system drive, but that's not set as the default. In Storage Settings, make the system drive the default location to save new content, then retry the install.
 	DEPLOYMENT_BLOCKED_BY_VOLUME_POLICY_PACKAGE 15649
+	/// The deployment operation was blocked due to a machine-wide policy restricting deployments on a non-system volume. Per policy, this app must be installed to the system drive, but that's not set as the default. In Storage Settings, make the system drive the default location to save new content, then retry the install.
 	DEPLOYMENT_BLOCKED_BY_VOLUME_POLICY_MACHINE 15650
+	/// The deployment operation was blocked because Special profile deployment is not allowed. Please try logging into an account that is not a Special profile. You can try logging out and logging back into the current account, or try logging into a different account.
 	DEPLOYMENT_BLOCKED_BY_PROFILE_POLICY 15651
+	/// The deployment operation failed due to a conflicting package's mutable package directory. To install this package remove the existing package with the conflicting mutable package directory.
 	DEPLOYMENT_FAILED_CONFLICTING_MUTABLE_PACKAGE_DIRECTORY 15652
+	/// The package installation failed because a singleton resource was specified and another user with that package installed is logged in. Please make sure that all active users with the package installed are logged out and retry installation.
 	SINGLETON_RESOURCE_INSTALLED_IN_ACTIVE_USER 15653
+	/// The package installation failed because a different version of the service is installed. Try installing a newer version of the package.
 	DIFFERENT_VERSION_OF_PACKAGED_SERVICE_INSTALLED 15654
+	/// The package installation failed because a version of the service exists outside of APPX packaging. Please contact your software vendor.
 	SERVICE_EXISTS_AS_NON_PACKAGED_SERVICE 15655
+	/// The package installation failed because administrator privileges are required. Please contact an administrator to install this package.
 	PACKAGED_SERVICE_REQUIRES_ADMIN_PRIVILEGES 15656
+	/// Loading the state store failed.
 	STATE_LOAD_STORE_FAILED 15800
+	/// Retrieving the state version for the application failed.
 	STATE_GET_VERSION_FAILED 15801
+	/// Setting the state version for the application failed.
 	STATE_SET_VERSION_FAILED 15802
+	/// Resetting the structured state of the application failed.
 	STATE_STRUCTURED_RESET_FAILED 15803
+	/// State Manager failed to open the container.
 	STATE_OPEN_CONTAINER_FAILED 15804
+	/// State Manager failed to create the container.
 	STATE_CREATE_CONTAINER_FAILED 15805
+	/// State Manager failed to delete the container.
 	STATE_DELETE_CONTAINER_FAILED 15806
+	/// State Manager failed to read the setting.
 	STATE_READ_SETTING_FAILED 15807
+	/// State Manager failed to write the setting.
 	STATE_WRITE_SETTING_FAILED 15808
+	/// State Manager failed to delete the setting.
 	STATE_DELETE_SETTING_FAILED 15809
+	/// State Manager failed to query the setting.
 	STATE_QUERY_SETTING_FAILED 15810
+	/// State Manager failed to read the composite setting.
 	STATE_READ_COMPOSITE_SETTING_FAILED 15811
+	/// State Manager failed to write the composite setting.
 	STATE_WRITE_COMPOSITE_SETTING_FAILED 15812
+	/// State Manager failed to enumerate the containers.
 	STATE_ENUMERATE_CONTAINER_FAILED 15813
+	/// State Manager failed to enumerate the settings.
 	STATE_ENUMERATE_SETTINGS_FAILED 15814
+	/// The size of the state manager composite setting value has exceeded the limit.
 	STATE_COMPOSITE_SETTING_VALUE_SIZE_LIMIT_EXCEEDED 15815
+	/// The size of the state manager setting value has exceeded the limit.
 	STATE_SETTING_VALUE_SIZE_LIMIT_EXCEEDED 15816
+	/// The length of the state manager setting name has exceeded the limit.
 	STATE_SETTING_NAME_SIZE_LIMIT_EXCEEDED 15817
+	/// The length of the state manager container name has exceeded the limit.
 	STATE_CONTAINER_NAME_SIZE_LIMIT_EXCEEDED 15818
+	/// This API cannot be used in the context of the caller's application type.
 	API_UNAVAILABLE 15841
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::io::ErrorKind as K;
+
+	#[test]
+	fn io_error_kind() {
+		assert_eq!(K::from(ERROR::FILE_NOT_FOUND), K::NotFound);
+		assert_eq!(K::from(ERROR::PATH_NOT_FOUND), K::NotFound);
+		assert_eq!(K::from(ERROR::ACCESS_DENIED), K::PermissionDenied);
+		assert_eq!(K::from(ERROR::ALREADY_EXISTS), K::AlreadyExists);
+		assert_eq!(K::from(ERROR::BROKEN_PIPE), K::BrokenPipe);
+		assert_eq!(K::from(ERROR::INVALID_PARAMETER), K::InvalidInput);
+		assert_eq!(K::from(ERROR::INVALID_NAME), K::InvalidInput);
+		assert_eq!(K::from(ERROR::HANDLE_EOF), K::UnexpectedEof);
+		assert_eq!(K::from(ERROR::IO_PENDING), K::WouldBlock);
+		assert_eq!(K::from(ERROR::TIMEOUT), K::TimedOut);
+		assert_eq!(K::from(ERROR::NOT_ENOUGH_MEMORY), K::OutOfMemory);
+		assert_eq!(K::from(ERROR::OPERATION_ABORTED), K::Interrupted);
+		assert_eq!(K::from(ERROR::DIR_NOT_EMPTY), K::DirectoryNotEmpty);
+		assert_eq!(K::from(ERROR::WRITE_PROTECT), K::ReadOnlyFilesystem);
+		assert_eq!(K::from(ERROR::INVALID_FUNCTION), K::Other);
+		assert_eq!(K::from(unsafe { ERROR::from_raw(0xffff_fff0) }), K::Other);
+	}
+
+	#[cfg(feature = "msg-table")]
+	#[test]
+	fn description() {
+		assert_eq!(ERROR::SUCCESS.name(), Some("SUCCESS"));
+		assert_eq!(ERROR::FILE_NOT_FOUND.name(), Some("FILE_NOT_FOUND"));
+		assert_eq!(
+			ERROR::FILE_NOT_FOUND.description(),
+			Some("The system cannot find the file specified."),
+		);
+		assert_eq!(ERROR::API_UNAVAILABLE.name(), Some("API_UNAVAILABLE"));
+
+		let unknown = unsafe { ERROR::from_raw(0xffff_fff0) };
+		assert_eq!(unknown.name(), None);
+		assert_eq!(unknown.description(), None);
+	}
+
+	#[cfg(feature = "msg-table")]
+	#[test]
+	fn description_table() {
+		for (val, name, desc) in ERROR_MSGS.iter() {
+			assert!(!name.is_empty() && !desc.is_empty(), "{}", val);
+			assert_eq!(
+				ERROR_MSGS.iter().filter(|(v, _, _)| v == val).count(),
+				1,
+				"{} duplicated",
+				name,
+			);
+		}
+	}
+}
@@ -1,6 +1,8 @@
 use crate::co;
 use crate::decl::*;
-use crate::kernel::privs::*;
+use crate::guard::*;
+use crate::kernel::{privs::*, structs::DEBUG_EVENT};
+use crate::prelude::*;
 
 /// Variable parameter for:
 ///
@@ -33,6 +35,211 @@ pub enum ClaimSecurityAttr<'a> {
 	OctetString(&'a [CLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE<'a>]),
 }
 
+/// A debugging event, retrieved by
+/// [`WaitForDebugEventEx`](crate::WaitForDebugEventEx).
+///
+/// The enum values match the native `DEBUG_EVENT` union members. The process
+/// and thread handles are owned by the system, and are closed when the
+/// corresponding exit event is continued; the file handles are owned by the
+/// debugger, and closed automatically.
+pub enum DebugEvent {
+	Exception {
+		/// The exception information.
+		record: EXCEPTION_RECORD,
+		/// Whether the debugger is seeing this exception for the first time.
+		first_chance: bool,
+	},
+	CreateThread {
+		hthread: HTHREAD,
+		thread_local_base: *mut std::ffi::c_void,
+		start_address: *mut std::ffi::c_void,
+	},
+	CreateProcess {
+		/// The executable file, if available.
+		hfile: Option<CloseHandleGuard<HFILE>>,
+		hprocess: HPROCESS,
+		hthread: HTHREAD,
+		base_of_image: *mut std::ffi::c_void,
+		thread_local_base: *mut std::ffi::c_void,
+		start_address: *mut std::ffi::c_void,
+	},
+	ExitThread {
+		exit_code: u32,
+	},
+	ExitProcess {
+		exit_code: u32,
+	},
+	LoadDll {
+		/// The DLL file, if available.
+		hfile: Option<CloseHandleGuard<HFILE>>,
+		base_of_dll: *mut std::ffi::c_void,
+	},
+	UnloadDll {
+		base_of_dll: *mut std::ffi::c_void,
+	},
+	/// The string lives in the address space of the debuggee, use
+	/// [`read_debug_string`](crate::DebugEvent::read_debug_string) to retrieve
+	/// it.
+	OutputDebugString {
+		/// Address of the string in the debuggee.
+		address: *mut std::ffi::c_void,
+		/// Whether the string is UTF-16, otherwise it's ANSI.
+		unicode: bool,
+		/// Length of the string, in chars, including the terminating null.
+		len: u16,
+	},
+	Rip {
+		error: co::ERROR,
+		kind: u32,
+	},
+	/// An event code unknown to this library; the event data is not decoded.
+	Other {
+		code: u32,
+	},
+}
+
+impl DebugEvent {
+	/// Decodes the native struct, taking ownership of the file handles.
+	///
+	/// # Safety
+	///
+	/// The struct must have been filled by `WaitForDebugEventEx`.
+	#[must_use]
+	pub(in crate::kernel) unsafe fn from_raw(de: &DEBUG_EVENT) -> Self {
+		let hfile = |h: *mut std::ffi::c_void| {
+			if h.is_null() {
+				None
+			} else {
+				Some(unsafe { CloseHandleGuard::new(HFILE::from_ptr(h)) })
+			}
+		};
+
+		unsafe {
+			match de.dwDebugEventCode {
+				1 => Self::Exception {
+					record: de.u.Exception.ExceptionRecord.clone(),
+					first_chance: de.u.Exception.dwFirstChance != 0,
+				},
+				2 => Self::CreateThread {
+					hthread: HTHREAD::from_ptr(de.u.CreateThread.hThread),
+					thread_local_base: de.u.CreateThread.lpThreadLocalBase,
+					start_address: de.u.CreateThread.lpStartAddress,
+				},
+				3 => Self::CreateProcess {
+					hfile: hfile(de.u.CreateProcessInfo.hFile),
+					hprocess: HPROCESS::from_ptr(de.u.CreateProcessInfo.hProcess),
+					hthread: HTHREAD::from_ptr(de.u.CreateProcessInfo.hThread),
+					base_of_image: de.u.CreateProcessInfo.lpBaseOfImage,
+					thread_local_base: de.u.CreateProcessInfo.lpThreadLocalBase,
+					start_address: de.u.CreateProcessInfo.lpStartAddress,
+				},
+				4 => Self::ExitThread { exit_code: de.u.ExitThread.dwExitCode },
+				5 => Self::ExitProcess { exit_code: de.u.ExitProcess.dwExitCode },
+				6 => Self::LoadDll {
+					hfile: hfile(de.u.LoadDll.hFile),
+					base_of_dll: de.u.LoadDll.lpBaseOfDll,
+				},
+				7 => Self::UnloadDll { base_of_dll: de.u.UnloadDll.lpBaseOfDll },
+				8 => Self::OutputDebugString {
+					address: de.u.DebugString.lpDebugStringData,
+					unicode: de.u.DebugString.fUnicode != 0,
+					len: de.u.DebugString.nDebugStringLength,
+				},
+				9 => Self::Rip {
+					error: de.u.RipInfo.dwError,
+					kind: de.u.RipInfo.dwType,
+				},
+				code => Self::Other { code },
+			}
+		}
+	}
+
+	/// If the event is an
+	/// [`OutputDebugString`](crate::DebugEvent::OutputDebugString), reads the
+	/// string from the address space of the debuggee with
+	/// [`HPROCESS::ReadProcessMemory`](crate::prelude::kernel_Hprocess::ReadProcessMemory).
+	/// Otherwise, returns `None`.
+	///
+	/// # Examples
+	///
+	/// Capturing the debug output of a child process:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let mut si = w::STARTUPINFO::default();
+	/// let pi = w::HPROCESS::CreateProcess(
+	///     None,
+	///     Some("C:\\Temp\\child.exe"),
+	///     None,
+	///     None,
+	///     false,
+	///     co::CREATE::DEBUG_ONLY_THIS_PROCESS,
+	///     None,
+	///     None,
+	///     &mut si,
+	/// )?;
+	///
+	/// loop {
+	///     let (pid, tid, event) = w::WaitForDebugEventEx(None)?;
+	///     if let Some(text) = event.read_debug_string(&pi.hProcess)? {
+	///         print!("{}", text);
+	///     }
+	///
+	///     w::ContinueDebugEvent(pid, tid, co::DBG::CONTINUE)?;
+	///     if let w::DebugEvent::ExitProcess { .. } = event {
+	///         break;
+	///     }
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn read_debug_string(&self, hprocess: &HPROCESS) -> SysResult<Option<String>> {
+		let (address, unicode, len) = match self {
+			Self::OutputDebugString { address, unicode, len } => {
+				(*address, *unicode, *len as usize)
+			},
+			_ => return Ok(None),
+		};
+
+		let mut buf = vec![0u8; if unicode { len * 2 } else { len }];
+		let num_read = hprocess.ReadProcessMemory(address, &mut buf)?;
+		buf.truncate(num_read);
+
+		Ok(Some(if unicode {
+			let wchars = buf
+				.chunks_exact(2)
+				.map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+				.collect::<Vec<_>>();
+			WString::from_wchars_slice(&wchars).to_string()
+		} else {
+			let len = buf.iter().position(|ch| *ch == 0).unwrap_or(buf.len());
+			WString::from_wchars_slice(&MultiByteToWideChar(
+				co::CP::ACP,
+				co::MBC::NoValue,
+				&buf[..len],
+			)?)
+			.to_string()
+		}))
+	}
+}
+
+/// Information retrieved by
+/// [`HFILE::GetFileInformationByHandleEx`](crate::prelude::kernel_Hfile::GetFileInformationByHandleEx).
+///
+/// The enum values match those in
+/// [`co::FILE_INFO_BY_HANDLE_CLASS`](crate::co::FILE_INFO_BY_HANDLE_CLASS)
+/// constant type.
+#[derive(Clone, PartialEq, Eq)]
+pub enum FileInfo {
+	Basic(FILE_BASIC_INFO),
+	Standard(FILE_STANDARD_INFO),
+	/// The file name, with the path relative to the volume root, and without
+	/// the drive letter.
+	Name(String),
+	AttributeTag(FILE_ATTRIBUTE_TAG_INFO),
+	Id(FILE_ID_INFO),
+}
+
 /// A resource identifier.
 ///
 /// Variable parameter for:
@@ -133,6 +340,222 @@ pub enum PowerSettingLid {
 	Opened,
 }
 
+/// Decoded contents of a
+/// [`REPARSE_DATA_BUFFER`](https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/ns-ntifs-_reparse_data_buffer)
+/// struct.
+///
+/// Returned by:
+///
+/// * [`HFILE::fsctl_get_reparse_point`](crate::prelude::kernel_Hfile::fsctl_get_reparse_point).
+///
+/// Variant parameter for:
+///
+/// * [`HFILE::fsctl_set_reparse_point`](crate::prelude::kernel_Hfile::fsctl_set_reparse_point).
+#[derive(Clone, PartialEq, Eq)]
+pub enum ReparseData {
+	/// A [`co::IO_REPARSE_TAG::MOUNT_POINT`](crate::co::IO_REPARSE_TAG::MOUNT_POINT),
+	/// which is a junction or a mounted folder.
+	MountPoint {
+		/// The target path, usually in NT form, like `\??\C:\Target`.
+		substitute_name: String,
+		/// The target path to be displayed to the user.
+		print_name: String,
+	},
+	/// A [`co::IO_REPARSE_TAG::SYMLINK`](crate::co::IO_REPARSE_TAG::SYMLINK).
+	SymbolicLink {
+		/// The target path, usually in NT form, like `\??\C:\Target`.
+		substitute_name: String,
+		/// The target path to be displayed to the user.
+		print_name: String,
+		/// Whether the target path is relative to the directory of the link.
+		relative: bool,
+	},
+	/// Any other tag, with the raw reparse data.
+	Other(co::IO_REPARSE_TAG, Vec<u8>),
+}
+
+impl ReparseData {
+	/// Decodes the reparse data from the raw bytes of a `REPARSE_DATA_BUFFER`
+	/// struct.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		let tag = unsafe { co::IO_REPARSE_TAG::from_raw(u32::from_le_bytes(le_bytes(buf, 0)?)) };
+		let data_len = u16::from_le_bytes(le_bytes(buf, 4)?) as usize;
+		let data = buf.get(8..8 + data_len).ok_or(co::ERROR::INVALID_DATA)?;
+
+		let path_buf_at = match tag {
+			co::IO_REPARSE_TAG::MOUNT_POINT => 8,
+			co::IO_REPARSE_TAG::SYMLINK => 12,
+			_ => return Ok(Self::Other(tag, data.to_vec())),
+		};
+		let path_str = |pos: usize| -> SysResult<String> {
+			let offset = u16::from_le_bytes(le_bytes(data, pos)?) as usize;
+			let len = u16::from_le_bytes(le_bytes(data, pos + 2)?) as usize;
+			let bytes = data
+				.get(path_buf_at + offset..path_buf_at + offset + len)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+			let wchars = bytes
+				.chunks_exact(2)
+				.map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+				.collect::<Vec<_>>();
+			Ok(WString::from_wchars_slice(&wchars).to_string())
+		};
+
+		let substitute_name = path_str(0)?;
+		let print_name = path_str(4)?;
+		Ok(if tag == co::IO_REPARSE_TAG::MOUNT_POINT {
+			Self::MountPoint { substitute_name, print_name }
+		} else {
+			let flags = u32::from_le_bytes(le_bytes(data, 8)?);
+			Self::SymbolicLink {
+				substitute_name,
+				print_name,
+				relative: flags & SYMLINK_FLAG_RELATIVE != 0,
+			}
+		})
+	}
+
+	/// Creates a [`ReparseData::MountPoint`](crate::ReparseData::MountPoint)
+	/// for a junction pointing to the given absolute directory path, like
+	/// `C:\Target`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let junction = w::ReparseData::new_junction("C:\\Target");
+	/// assert_eq!(junction.target().unwrap(), "C:\\Target");
+	/// ```
+	#[must_use]
+	pub fn new_junction(target: &str) -> Self {
+		let print_name = Self::strip_nt_prefix(target).to_owned();
+		Self::MountPoint {
+			substitute_name: format!("\\??\\{}", print_name),
+			print_name,
+		}
+	}
+
+	/// Creates a [`ReparseData::SymbolicLink`](crate::ReparseData::SymbolicLink)
+	/// pointing to the given path. If `relative` is `true`, the path is
+	/// relative to the directory of the link, otherwise it must be absolute.
+	#[must_use]
+	pub fn new_symbolic_link(target: &str, relative: bool) -> Self {
+		let print_name = Self::strip_nt_prefix(target).to_owned();
+		Self::SymbolicLink {
+			substitute_name: if relative {
+				print_name.clone()
+			} else {
+				format!("\\??\\{}", print_name)
+			},
+			print_name,
+			relative,
+		}
+	}
+
+	/// Returns the reparse tag.
+	#[must_use]
+	pub const fn tag(&self) -> co::IO_REPARSE_TAG {
+		match self {
+			Self::MountPoint { .. } => co::IO_REPARSE_TAG::MOUNT_POINT,
+			Self::SymbolicLink { .. } => co::IO_REPARSE_TAG::SYMLINK,
+			Self::Other(tag, _) => *tag,
+		}
+	}
+
+	/// Returns the target path of a junction or a symbolic link, preferably
+	/// the print name, without the NT `\??\` prefix.
+	///
+	/// Returns `None` for other reparse tags.
+	#[must_use]
+	pub fn target(&self) -> Option<String> {
+		match self {
+			Self::MountPoint { substitute_name, print_name }
+			| Self::SymbolicLink { substitute_name, print_name, .. } => Some(
+				if print_name.is_empty() {
+					Self::strip_nt_prefix(substitute_name)
+				} else {
+					print_name
+				}
+				.to_owned(),
+			),
+			Self::Other(_, _) => None,
+		}
+	}
+
+	/// Encodes the reparse data into the raw bytes of a `REPARSE_DATA_BUFFER`
+	/// struct, to be passed to
+	/// [`HFILE::fsctl_set_reparse_point`](crate::prelude::kernel_Hfile::fsctl_set_reparse_point).
+	///
+	/// Fails with [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the data exceeds the maximum reparse buffer size of 16 KB.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let (tag, data) = match self {
+			Self::MountPoint { substitute_name, print_name } => (
+				co::IO_REPARSE_TAG::MOUNT_POINT,
+				Self::encode_paths(substitute_name, print_name, None)?,
+			),
+			Self::SymbolicLink { substitute_name, print_name, relative } => (
+				co::IO_REPARSE_TAG::SYMLINK,
+				Self::encode_paths(
+					substitute_name,
+					print_name,
+					Some(if *relative { SYMLINK_FLAG_RELATIVE } else { 0 }),
+				)?,
+			),
+			Self::Other(tag, data) => (*tag, data.clone()),
+		};
+		if 8 + data.len() > MAXIMUM_REPARSE_DATA_BUFFER_SIZE {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		let mut buf = Vec::with_capacity(8 + data.len());
+		buf.extend_from_slice(&tag.raw().to_le_bytes());
+		buf.extend_from_slice(&(data.len() as u16).to_le_bytes());
+		buf.extend_from_slice(&[0, 0]); // Reserved
+		buf.extend_from_slice(&data);
+		Ok(buf)
+	}
+
+	/// Encodes the name offsets, the optional flags and the path buffer, with
+	/// the substitute name followed by the print name, both null-terminated.
+	fn encode_paths(
+		substitute_name: &str,
+		print_name: &str,
+		flags: Option<u32>,
+	) -> SysResult<Vec<u8>> {
+		let to_bytes =
+			|s: &str| -> Vec<u8> { s.encode_utf16().flat_map(|ch| ch.to_le_bytes()).collect() };
+		let sub = to_bytes(substitute_name);
+		let print = to_bytes(print_name);
+
+		let header_len = if flags.is_some() { 12 } else { 8 };
+		let data_len = header_len + sub.len() + 2 + print.len() + 2;
+		if 8 + data_len > MAXIMUM_REPARSE_DATA_BUFFER_SIZE {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		let mut data = Vec::with_capacity(data_len);
+		data.extend_from_slice(&0u16.to_le_bytes()); // SubstituteNameOffset
+		data.extend_from_slice(&(sub.len() as u16).to_le_bytes());
+		data.extend_from_slice(&((sub.len() + 2) as u16).to_le_bytes()); // PrintNameOffset
+		data.extend_from_slice(&(print.len() as u16).to_le_bytes());
+		if let Some(flags) = flags {
+			data.extend_from_slice(&flags.to_le_bytes());
+		}
+		data.extend_from_slice(&sub);
+		data.extend_from_slice(&[0, 0]);
+		data.extend_from_slice(&print);
+		data.extend_from_slice(&[0, 0]);
+		Ok(data)
+	}
+
+	fn strip_nt_prefix(path: &str) -> &str {
+		path.strip_prefix("\\??\\")
+			.or_else(|| path.strip_prefix("\\\\?\\"))
+			.unwrap_or(path)
+	}
+}
+
 /// A predefined resource identifier.
 ///
 /// Variant parameter for:
@@ -191,3 +614,149 @@ impl RtStr {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn round_trip(data: &ReparseData) -> ReparseData {
+		ReparseData::from_bytes(&data.to_bytes().unwrap()).unwrap()
+	}
+
+	fn u16_at(buf: &[u8], pos: usize) -> u16 {
+		u16::from_le_bytes([buf[pos], buf[pos + 1]])
+	}
+
+	#[test]
+	fn symlink_absolute() {
+		let link = ReparseData::new_symbolic_link("C:\\Target\\dir", false);
+		assert!(
+			link == ReparseData::SymbolicLink {
+				substitute_name: "\\??\\C:\\Target\\dir".to_owned(),
+				print_name: "C:\\Target\\dir".to_owned(),
+				relative: false,
+			}
+		);
+
+		let buf = link.to_bytes().unwrap();
+		assert_eq!(buf[..4], co::IO_REPARSE_TAG::SYMLINK.raw().to_le_bytes());
+		assert_eq!(u16_at(&buf, 4) as usize, buf.len() - 8); // ReparseDataLength
+		assert_eq!(buf[16..20], [0, 0, 0, 0]); // Flags
+		assert!(ReparseData::from_bytes(&buf).unwrap() == link);
+		assert_eq!(link.target().unwrap(), "C:\\Target\\dir");
+	}
+
+	#[test]
+	fn symlink_relative() {
+		let link = ReparseData::new_symbolic_link("..\\sibling\\file.txt", true);
+		let buf = link.to_bytes().unwrap();
+		assert_eq!(buf[16..20], SYMLINK_FLAG_RELATIVE.to_le_bytes());
+		assert_eq!(u16_at(&buf, 8), 0); // SubstituteNameOffset
+		assert_eq!(u16_at(&buf, 10), 38); // SubstituteNameLength, in bytes
+		assert_eq!(u16_at(&buf, 12), 40); // PrintNameOffset, after the null
+
+		let decoded = round_trip(&link);
+		assert!(decoded == link);
+		assert_eq!(decoded.target().unwrap(), "..\\sibling\\file.txt");
+	}
+
+	#[test]
+	fn mount_point() {
+		let junction = ReparseData::new_junction("\\??\\X:"); // prefix is not doubled
+		assert!(
+			junction
+				== ReparseData::MountPoint {
+					substitute_name: "\\??\\X:".to_owned(),
+					print_name: "X:".to_owned(),
+				}
+		);
+		assert_eq!(
+			junction.to_bytes().unwrap(),
+			[
+				0x03, 0x00, 0x00, 0xa0, // tag
+				28, 0, 0, 0, // ReparseDataLength, Reserved
+				0, 0, 12, 0, 14, 0, 4, 0, // offsets and lengths, no flags
+				b'\\', 0, b'?', 0, b'?', 0, b'\\', 0, b'X', 0, b':', 0, 0,
+				0, // substitute name
+				b'X', 0, b':', 0, 0, 0, // print name
+			],
+		);
+		assert!(round_trip(&junction) == junction);
+
+		let junction = ReparseData::new_junction("D:\\Pastas\\Ação");
+		assert!(round_trip(&junction) == junction);
+		assert_eq!(junction.target().unwrap(), "D:\\Pastas\\Ação");
+	}
+
+	#[test]
+	fn empty_print_name() {
+		let link = ReparseData::SymbolicLink {
+			substitute_name: "\\??\\C:\\X".to_owned(),
+			print_name: String::new(),
+			relative: false,
+		};
+		let decoded = round_trip(&link);
+		assert!(decoded == link);
+		assert_eq!(decoded.target().unwrap(), "C:\\X");
+	}
+
+	#[test]
+	fn other_tag() {
+		let tag = unsafe { co::IO_REPARSE_TAG::from_raw(0x8000_0017) };
+		let other = ReparseData::Other(tag, vec![1, 2, 3]);
+		assert_eq!(other.to_bytes().unwrap(), [0x17, 0, 0, 0x80, 3, 0, 0, 0, 1, 2, 3]);
+		assert!(round_trip(&other) == other);
+		assert!(other.target().is_none());
+	}
+
+	#[test]
+	fn truncated_buffers() {
+		for data in [
+			ReparseData::new_symbolic_link("C:\\Target", false),
+			ReparseData::new_junction("C:\\Target"),
+		] {
+			let buf = data.to_bytes().unwrap();
+			for len in 0..buf.len() {
+				assert!(
+					ReparseData::from_bytes(&buf[..len]) == Err(co::ERROR::INVALID_DATA),
+					"length {}",
+					len,
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn invalid_buffers() {
+		let buf = ReparseData::new_symbolic_link("C:\\Target", false)
+			.to_bytes()
+			.unwrap();
+
+		let mut bad = buf.clone();
+		bad[12..14].copy_from_slice(&1000u16.to_le_bytes()); // PrintNameOffset out of bounds
+		assert!(ReparseData::from_bytes(&bad) == Err(co::ERROR::INVALID_DATA));
+
+		let mut bad = buf.clone();
+		bad[4..6].copy_from_slice(&8u16.to_le_bytes()); // symlink header without flags
+		assert!(ReparseData::from_bytes(&bad) == Err(co::ERROR::INVALID_DATA));
+
+		let mut odd = buf.clone();
+		odd[10..12].copy_from_slice(&3u16.to_le_bytes()); // odd length drops the last byte
+		assert!(matches!(
+			ReparseData::from_bytes(&odd),
+			Ok(ReparseData::SymbolicLink { substitute_name, .. }) if substitute_name == "\\",
+		));
+	}
+
+	#[test]
+	fn size_limit() {
+		let link = ReparseData::new_symbolic_link(&"a".repeat(5000), false);
+		assert!(link.to_bytes() == Err(co::ERROR::INVALID_PARAMETER));
+
+		let tag = unsafe { co::IO_REPARSE_TAG::from_raw(0x8000_0017) };
+		let max = ReparseData::Other(tag, vec![0; MAXIMUM_REPARSE_DATA_BUFFER_SIZE - 8]);
+		assert_eq!(max.to_bytes().unwrap().len(), MAXIMUM_REPARSE_DATA_BUFFER_SIZE);
+		let over = ReparseData::Other(tag, vec![0; MAXIMUM_REPARSE_DATA_BUFFER_SIZE - 7]);
+		assert!(over.to_bytes() == Err(co::ERROR::INVALID_PARAMETER));
+	}
+}
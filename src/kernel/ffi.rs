@@ -4,30 +4,47 @@ extern_sys! { "kernel32";
 	BeginUpdateResourceW(PCSTR, BOOL) -> HANDLE
 	CheckRemoteDebuggerPresent(HANDLE, *mut BOOL) -> BOOL
 	CloseHandle(HANDLE) -> BOOL
+	CompareStringEx(PCSTR, u32, PCSTR, i32, PCSTR, i32, PVOID, PVOID, isize) -> i32
+	ContinueDebugEvent(u32, u32, u32) -> BOOL
 	CopyFileW(PCSTR, PCSTR, BOOL) -> BOOL
 	CreateDirectoryW(PCSTR, PVOID) -> BOOL
 	CreateEventExW(PCVOID, PCSTR, u32, u32) -> HANDLE
 	CreateEventW(PCVOID, BOOL, BOOL, PCSTR) -> HANDLE
 	CreateFileMappingFromApp(HANDLE, PVOID, u32, u64, PCSTR) -> HANDLE
 	CreateFileW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE) -> HANDLE
+	CreateHardLinkW(PCSTR, PCSTR, PVOID) -> BOOL
 	CreatePipe(*mut HANDLE, *mut HANDLE, PVOID, u32) -> BOOL
 	CreateProcessW(PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
+	CreateSymbolicLinkW(PCSTR, PCSTR, u32) -> u8
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
+	DebugActiveProcess(u32) -> BOOL
+	DebugActiveProcessStop(u32) -> BOOL
 	DeleteFileW(PCSTR) -> BOOL
+	DeleteVolumeMountPointW(PCSTR) -> BOOL
+	DeviceIoControl(HANDLE, u32, PCVOID, u32, PVOID, u32, *mut u32, PVOID) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
 	EnumResourceNamesW(HANDLE, PCSTR, PFUNC, isize) -> BOOL
 	EnumResourceTypesW(HANDLE, PFUNC, isize) -> BOOL
+	EnumSystemLocalesEx(PFUNC, u32, isize, PVOID) -> BOOL
 	ExitProcess(u32)
 	ExitThread(u32)
 	ExpandEnvironmentStringsW(PCSTR, PSTR, u32) -> u32
 	FileTimeToSystemTime(PCVOID, PVOID) -> BOOL
 	FindClose(HANDLE) -> BOOL
+	FindFirstFileExW(PCSTR, u32, PVOID, u32, PVOID, u32) -> HANDLE
+	FindFirstFileNameW(PCSTR, u32, *mut u32, PSTR) -> HANDLE
 	FindFirstFileW(PCSTR, PVOID) -> HANDLE
+	FindFirstStreamW(PCSTR, u32, PVOID, u32) -> HANDLE
+	FindFirstVolumeW(PSTR, u32) -> HANDLE
+	FindNextFileNameW(HANDLE, *mut u32, PSTR) -> BOOL
 	FindNextFileW(HANDLE, PVOID) -> BOOL
+	FindNextStreamW(HANDLE, PVOID) -> BOOL
+	FindNextVolumeW(HANDLE, PSTR, u32) -> BOOL
 	FindResourceExW(HANDLE, PCSTR, PCSTR, u16) -> HANDLE
 	FindResourceW(HANDLE, PCSTR, PCSTR) -> HANDLE
+	FindVolumeClose(HANDLE) -> BOOL
 	FlushConsoleInputBuffer(HANDLE) -> BOOL
 	FlushInstructionCache(HANDLE, PCVOID, usize) -> BOOL
 	FlushProcessWriteBuffers()
@@ -39,20 +56,24 @@ extern_sys! { "kernel32";
 	GetCommandLineW() -> PCSTR
 	GetComputerNameW(PSTR, *mut u32) -> BOOL
 	GetConsoleMode(HANDLE, *mut u32) -> BOOL
+	GetCurrencyFormatEx(PCSTR, u32, PCSTR, PCVOID, PSTR, i32) -> i32
 	GetCurrentDirectoryW(u32, PSTR) -> u32
 	GetCurrentProcess() -> HANDLE
 	GetCurrentProcessId() -> u32
 	GetCurrentThread() -> HANDLE
 	GetCurrentThreadId() -> u32
+	GetDateFormatEx(PCSTR, u32, PCVOID, PCSTR, PSTR, i32, PCSTR) -> i32
 	GetDiskFreeSpaceExW(PCSTR, *mut u64, *mut u64, *mut u64) -> BOOL
 	GetDiskSpaceInformationW(PCSTR, PVOID) -> u32
 	GetDriveTypeW(PCSTR) -> u32
+	GetDynamicTimeZoneInformation(PVOID) -> u32
 	GetEnvironmentStringsW() -> *mut u16
 	GetExitCodeProcess(HANDLE, *mut u32) -> BOOL
 	GetExitCodeThread(HANDLE, *mut u32) -> BOOL
 	GetFileAttributesExW(PCSTR, u32, PVOID) -> BOOL
 	GetFileAttributesW(PCSTR) -> u32
 	GetFileInformationByHandle(HANDLE, PVOID) -> BOOL
+	GetFileInformationByHandleEx(HANDLE, u32, PVOID, u32) -> BOOL
 	GetFileSizeEx(HANDLE, *mut i64) -> BOOL
 	GetFileTime(HANDLE, PVOID, PVOID, PVOID) -> BOOL
 	GetFileType(HANDLE) -> u32
@@ -60,6 +81,7 @@ extern_sys! { "kernel32";
 	GetGuiResources(HANDLE, u32) -> u32
 	GetLargePageMinimum() -> usize
 	GetLastError() -> u32
+	GetLocaleInfoEx(PCSTR, u32, PSTR, i32) -> i32
 	GetLocalTime(PVOID)
 	GetLogicalDrives() -> u32
 	GetLogicalDriveStringsW(u32, PSTR) -> u32
@@ -68,6 +90,7 @@ extern_sys! { "kernel32";
 	GetModuleHandleExW(u32, PCSTR, *mut HANDLE) -> BOOL
 	GetModuleHandleW(PCSTR) -> HANDLE
 	GetNativeSystemInfo(PVOID)
+	GetNumberFormatEx(PCSTR, u32, PCSTR, PCVOID, PSTR, i32) -> i32
 	GetPriorityClass(HANDLE) -> u32
 	GetPrivateProfileSectionNamesW(PSTR, u32, PCSTR) -> u32
 	GetPrivateProfileSectionW(PCSTR, PSTR, u32, PCSTR) -> u32
@@ -90,10 +113,16 @@ extern_sys! { "kernel32";
 	GetSystemTimes(PVOID, PVOID, PVOID) -> BOOL
 	GetTempFileNameW(PCSTR, PCSTR, u32, PSTR) -> u32
 	GetTempPathW(u32, PSTR) -> u32
+	GetThreadContext(HANDLE, PVOID) -> BOOL
 	GetThreadId(HANDLE) -> u32
 	GetThreadTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
 	GetTickCount64() -> u64
+	GetTimeFormatEx(PCSTR, u32, PCVOID, PCSTR, PSTR, i32) -> i32
+	GetTimeZoneInformation(PVOID) -> u32
+	GetUserDefaultLocaleName(PSTR, i32) -> i32
 	GetVolumeInformationW(PCSTR, PSTR, u32, *mut u32, *mut u32, *mut u32, PSTR, u32) -> BOOL
+	GetVolumeNameForVolumeMountPointW(PCSTR, PSTR, u32) -> BOOL
+	GetVolumePathNamesForVolumeNameW(PCSTR, PSTR, u32, *mut u32) -> BOOL
 	GetVolumePathNameW(PCSTR, PSTR, u32) -> BOOL
 	GlobalAlloc(u32, usize) -> HANDLE
 	GlobalFlags(HANDLE) -> u32
@@ -121,6 +150,7 @@ extern_sys! { "kernel32";
 	IsNativeVhdBoot(*mut BOOL) -> BOOL
 	IsProcessCritical(HANDLE, *mut BOOL) -> BOOL
 	IsWow64Process(HANDLE, *mut BOOL) -> BOOL
+	LCMapStringEx(PCSTR, u32, PCSTR, i32, PSTR, i32, PVOID, PVOID, isize) -> i32
 	LoadLibraryW(PCSTR) -> HANDLE
 	LoadResource(HANDLE, HANDLE) -> HANDLE
 	LocalAlloc(u32, usize) -> HANDLE
@@ -142,6 +172,7 @@ extern_sys! { "kernel32";
 	MulDiv(i32, i32, i32) -> i32
 	MultiByteToWideChar(u32, u32, *const u8, i32, PSTR, i32) -> i32
 	OpenEventW(u32, BOOL, PCSTR) -> HANDLE
+	OpenFileById(HANDLE, PCVOID, u32, u32, PVOID, u32) -> HANDLE
 	OpenProcess(u32, BOOL, u32) -> HANDLE
 	OutputDebugStringW(PCSTR)
 	Process32FirstW(HANDLE, PVOID) -> BOOL
@@ -156,6 +187,7 @@ extern_sys! { "kernel32";
 	QueryUnbiasedInterruptTime(&mut u64) -> BOOL
 	ReadConsoleW(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
 	ReadFile(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReadProcessMemory(HANDLE, PCVOID, PVOID, usize, *mut usize) -> BOOL
 	ReplaceFileW(PCSTR, PCSTR, PCSTR, u32, PVOID, PVOID) -> BOOL
 	ResetEvent(HANDLE) -> BOOL
 	ResumeThread(HANDLE) -> u32
@@ -170,10 +202,13 @@ extern_sys! { "kernel32";
 	SetPriorityClass(HANDLE, u32) -> BOOL
 	SetProcessAffinityUpdateMode(HANDLE, u32) -> BOOL
 	SetProcessPriorityBoost(HANDLE, BOOL) -> BOOL
+	SetThreadContext(HANDLE, PCVOID) -> BOOL
 	SetThreadIdealProcessor(HANDLE, u32) -> u32
 	SetThreadIdealProcessorEx(HANDLE, PCVOID, PVOID) -> BOOL
 	SetThreadPriorityBoost(HANDLE, BOOL) -> BOOL
 	SetThreadStackGuarantee(*mut u32) -> BOOL
+	SetUnhandledExceptionFilter(PCVOID) -> PCVOID
+	SetVolumeMountPointW(PCSTR, PCSTR) -> BOOL
 	SizeofResource(HANDLE, HANDLE) -> u32
 	Sleep(u32)
 	SuspendThread(HANDLE) -> u32
@@ -184,11 +219,13 @@ extern_sys! { "kernel32";
 	TerminateThread(HANDLE, u32) -> BOOL
 	Thread32First(HANDLE, PVOID) -> BOOL
 	Thread32Next(HANDLE, PVOID) -> BOOL
+	TzSpecificLocalTimeToSystemTime(PCVOID, PCVOID, PVOID) -> BOOL
 	UnlockFile(HANDLE, u32, u32, u32, u32) -> BOOL
 	UnmapViewOfFile(PCVOID) -> BOOL
 	UpdateResourceW(HANDLE, PCSTR, PCSTR, u16, PVOID, u32) -> BOOL
 	VerifyVersionInfoW(PVOID, u32, u64) -> BOOL
 	VerSetConditionMask(u64, u32, u8) -> u64
+	WaitForDebugEventEx(PVOID, u32) -> BOOL
 	WaitForSingleObject(HANDLE, u32) -> u32
 	WideCharToMultiByte(u32, u32, PCSTR, i32, PSTR, i32, *const u8, *mut BOOL) -> i32
 	WriteConsoleW(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
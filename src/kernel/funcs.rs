@@ -3,9 +3,79 @@
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
-use crate::kernel::{ffi, ffi_types::*, privs::*};
+use crate::kernel::{ffi, ffi_types::*, privs::*, proc, structs::DEBUG_EVENT};
 use crate::prelude::*;
 
+/// [`CompareStringEx`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-comparestringex)
+/// function.
+///
+/// If `locale_name` is `None`, the user default locale is used.
+///
+/// # Examples
+///
+/// Sorting file names like Windows Explorer:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut names = vec!["file10.txt", "File2.txt", "file1.txt"];
+/// names.sort_by(|a, b| {
+///     w::CompareStringEx(
+///         None,
+///         co::NORM::IGNORECASE | co::NORM::SORT_DIGITSASNUMBERS,
+///         a,
+///         b,
+///     ).unwrap()
+/// });
+/// ```
+///
+/// # Related functions
+///
+/// * [`LCMapStringEx`](crate::LCMapStringEx)
+/// * [`StrCmpLogical`](crate::StrCmpLogical)
+#[must_use]
+pub fn CompareStringEx(
+	locale_name: Option<&str>,
+	flags: co::NORM,
+	string1: &str,
+	string2: &str,
+) -> SysResult<std::cmp::Ordering> {
+	match unsafe {
+		ffi::CompareStringEx(
+			WString::from_opt_str(locale_name).as_ptr(),
+			flags.raw(),
+			WString::from_str(string1).as_ptr(),
+			-1,
+			WString::from_str(string2).as_ptr(),
+			-1,
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			0,
+		)
+	} {
+		0 => Err(GetLastError()),
+		1 => Ok(std::cmp::Ordering::Less),
+		2 => Ok(std::cmp::Ordering::Equal),
+		_ => Ok(std::cmp::Ordering::Greater),
+	}
+}
+
+/// [`ContinueDebugEvent`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-continuedebugevent)
+/// function.
+///
+/// # Related functions
+///
+/// * [`WaitForDebugEventEx`](crate::WaitForDebugEventEx)
+pub fn ContinueDebugEvent(
+	process_id: u32,
+	thread_id: u32,
+	continue_status: co::DBG,
+) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::ContinueDebugEvent(process_id, thread_id, continue_status.raw())
+	})
+}
+
 /// [`CopyFile`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-copyfilew)
 /// function.
 ///
@@ -39,6 +109,77 @@ pub fn CreateDirectory(
 	})
 }
 
+/// [`CreateHardLink`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createhardlinkw)
+/// function.
+///
+/// # Related functions
+///
+/// * [`CreateSymbolicLink`](crate::CreateSymbolicLink)
+pub fn CreateHardLink(file_name: &str, existing_file_name: &str) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::CreateHardLinkW(
+			WString::from_str(file_name).as_ptr(),
+			WString::from_str(existing_file_name).as_ptr(),
+			std::ptr::null_mut(),
+		)
+	})
+}
+
+/// [`CreateSymbolicLink`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createsymboliclinkw)
+/// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::CreateSymbolicLink(
+///     "C:\\Temp\\link_dir",
+///     "C:\\Temp\\target_dir",
+///     Some(co::SYMBOLIC_LINK_FLAG::DIRECTORY
+///         | co::SYMBOLIC_LINK_FLAG::ALLOW_UNPRIVILEGED_CREATE),
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`CreateHardLink`](crate::CreateHardLink)
+pub fn CreateSymbolicLink(
+	symlink_file_name: &str,
+	target_file_name: &str,
+	flags: Option<co::SYMBOLIC_LINK_FLAG>,
+) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::CreateSymbolicLinkW(
+			WString::from_str(symlink_file_name).as_ptr(),
+			WString::from_str(target_file_name).as_ptr(),
+			flags.unwrap_or_default().raw(),
+		) as _
+	})
+}
+
+/// [`DebugActiveProcess`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-debugactiveprocess)
+/// function.
+///
+/// # Related functions
+///
+/// * [`DebugActiveProcessStop`](crate::DebugActiveProcessStop)
+/// * [`WaitForDebugEventEx`](crate::WaitForDebugEventEx)
+pub fn DebugActiveProcess(process_id: u32) -> SysResult<()> {
+	bool_to_sysresult(unsafe { ffi::DebugActiveProcess(process_id) })
+}
+
+/// [`DebugActiveProcessStop`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-debugactiveprocessstop)
+/// function.
+///
+/// # Related functions
+///
+/// * [`DebugActiveProcess`](crate::DebugActiveProcess)
+pub fn DebugActiveProcessStop(process_id: u32) -> SysResult<()> {
+	bool_to_sysresult(unsafe { ffi::DebugActiveProcessStop(process_id) })
+}
+
 /// [`DeleteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-deletefilew)
 /// function.
 ///
@@ -52,6 +193,55 @@ pub fn DeleteFile(file_name: &str) -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::DeleteFileW(WString::from_str(file_name).as_ptr()) })
 }
 
+/// [`DeleteVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-deletevolumemountpointw)
+/// function.
+///
+/// # Related functions
+///
+/// * [`GetVolumeNameForVolumeMountPoint`](crate::GetVolumeNameForVolumeMountPoint)
+/// * [`SetVolumeMountPoint`](crate::SetVolumeMountPoint)
+pub fn DeleteVolumeMountPoint(volume_mount_point: &str) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::DeleteVolumeMountPointW(WString::from_str(volume_mount_point).as_ptr())
+	})
+}
+
+/// [`EnumSystemLocalesEx`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-enumsystemlocalesex)
+/// function.
+///
+/// The closure receives the locale name and its flags, and must return
+/// `true` to continue the enumeration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut names = Vec::<String>::new();
+///
+/// w::EnumSystemLocalesEx(
+///     co::LOCALE::WINDOWS,
+///     |name: String, _flags: co::LOCALE| -> bool {
+///         names.push(name);
+///         true
+///     },
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn EnumSystemLocalesEx<F>(flags: co::LOCALE, func: F) -> SysResult<()>
+where
+	F: FnMut(String, co::LOCALE) -> bool,
+{
+	bool_to_sysresult(unsafe {
+		ffi::EnumSystemLocalesEx(
+			proc::enum_system_locales_ex::<F> as _,
+			flags.raw(),
+			&func as *const _ as _,
+			std::ptr::null_mut(),
+		)
+	})
+}
+
 /// [`ExitProcess`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-exitprocess)
 /// function.
 pub fn ExitProcess(exit_code: u32) {
@@ -200,6 +390,50 @@ pub fn GetComputerName() -> SysResult<String> {
 		.map(|_| buf.to_string())
 }
 
+/// [`GetCurrencyFormatEx`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-getcurrencyformatex)
+/// function.
+///
+/// The `value` must be a number string, like `"-1234.5"`. If `locale_name` is
+/// `None`, the user default locale is used. If `format` is `None`, the
+/// locale's defaults are used, and `flags` can be
+/// [`co::LOCALE::NOUSEROVERRIDE`](crate::co::LOCALE::NOUSEROVERRIDE).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let money = w::GetCurrencyFormatEx(Some("en-US"), co::LOCALE::ALL, "1234.5", None)?;
+/// assert_eq!(money, "$1,234.50");
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`GetDateFormatEx`](crate::GetDateFormatEx)
+/// * [`GetNumberFormatEx`](crate::GetNumberFormatEx)
+/// * [`GetTimeFormatEx`](crate::GetTimeFormatEx)
+#[must_use]
+pub fn GetCurrencyFormatEx(
+	locale_name: Option<&str>,
+	flags: co::LOCALE,
+	value: &str,
+	format: Option<&CURRENCYFMT>,
+) -> SysResult<String> {
+	let wlocale_name = WString::from_opt_str(locale_name);
+	let wvalue = WString::from_str(value);
+	nls_str_buf(|buf, sz| unsafe {
+		ffi::GetCurrencyFormatEx(
+			wlocale_name.as_ptr(),
+			flags.raw(),
+			wvalue.as_ptr(),
+			format.map_or(std::ptr::null(), |f| f as *const _ as _),
+			buf,
+			sz,
+		)
+	})
+}
+
 /// [`GetCurrentDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getcurrentdirectory)
 /// function.
 #[must_use]
@@ -238,6 +472,64 @@ pub fn GetCurrentThreadId() -> u32 {
 	unsafe { ffi::GetCurrentThreadId() }
 }
 
+/// [`GetDateFormatEx`](https://learn.microsoft.com/en-us/windows/win32/api/datetimeapi/nf-datetimeapi-getdateformatex)
+/// function.
+///
+/// If `locale_name` is `None`, the user default locale is used. If `date` is
+/// `None`, the current local date is used. If `format` is `None`, the format
+/// is chosen by `flags`, otherwise it's a picture string like
+/// `"dddd, MMMM dd"`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let today = w::GetDateFormatEx(None, co::DATE::LONGDATE, None, None)?;
+///
+/// let st = w::SYSTEMTIME {
+///     wYear: 2024,
+///     wMonth: 3,
+///     wDay: 15,
+///     ..Default::default()
+/// };
+/// let custom = w::GetDateFormatEx(
+///     Some("en-US"),
+///     co::DATE::NoValue,
+///     Some(&st),
+///     Some("yyyy-MM-dd"),
+/// )?;
+/// assert_eq!(custom, "2024-03-15");
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`GetCurrencyFormatEx`](crate::GetCurrencyFormatEx)
+/// * [`GetNumberFormatEx`](crate::GetNumberFormatEx)
+/// * [`GetTimeFormatEx`](crate::GetTimeFormatEx)
+#[must_use]
+pub fn GetDateFormatEx(
+	locale_name: Option<&str>,
+	flags: co::DATE,
+	date: Option<&SYSTEMTIME>,
+	format: Option<&str>,
+) -> SysResult<String> {
+	let wlocale_name = WString::from_opt_str(locale_name);
+	let wformat = WString::from_opt_str(format);
+	nls_str_buf(|buf, sz| unsafe {
+		ffi::GetDateFormatEx(
+			wlocale_name.as_ptr(),
+			flags.raw(),
+			date.map_or(std::ptr::null(), |st| st as *const _ as _),
+			wformat.as_ptr(),
+			buf,
+			sz,
+			std::ptr::null(),
+		)
+	})
+}
+
 /// [`GetDriveType`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getdrivetypew)
 /// function.
 #[must_use]
@@ -281,6 +573,22 @@ pub fn GetDiskSpaceInformation(root_path: &str) -> SysResult<DISK_SPACE_INFORMAT
 	}
 }
 
+/// [`GetDynamicTimeZoneInformation`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-getdynamictimezoneinformation)
+/// function.
+///
+/// # Related functions
+///
+/// * [`GetTimeZoneInformation`](crate::GetTimeZoneInformation)
+#[must_use]
+pub fn GetDynamicTimeZoneInformation(
+) -> SysResult<(co::TIME_ZONE_ID, DYNAMIC_TIME_ZONE_INFORMATION)> {
+	let mut dtzi = DYNAMIC_TIME_ZONE_INFORMATION::default();
+	match unsafe { ffi::GetDynamicTimeZoneInformation(&mut dtzi as *mut _ as _) } {
+		TIME_ZONE_ID_INVALID => Err(GetLastError()),
+		id => Ok((unsafe { co::TIME_ZONE_ID::from_raw(id) }, dtzi)),
+	}
+}
+
 /// [`GetEnvironmentStrings`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getenvironmentstringsw)
 /// function.
 ///
@@ -395,6 +703,31 @@ pub fn GetLastError() -> co::ERROR {
 	unsafe { co::ERROR::from_raw(ffi::GetLastError()) }
 }
 
+/// [`GetLocaleInfoEx`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-getlocaleinfoex)
+/// function.
+///
+/// If `locale_name` is `None`, the user default locale is used. Numeric
+/// values, like [`co::LCTYPE::IDIGITS`](crate::co::LCTYPE::IDIGITS), are
+/// returned as strings.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let decimal_sep = w::GetLocaleInfoEx(None, co::LCTYPE::SDECIMAL)?;
+/// let lang_name = w::GetLocaleInfoEx(Some("pt-BR"), co::LCTYPE::SENGLISHLANGUAGENAME)?;
+/// assert_eq!(lang_name, "Portuguese");
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn GetLocaleInfoEx(locale_name: Option<&str>, lc_type: co::LCTYPE) -> SysResult<String> {
+	let wlocale_name = WString::from_opt_str(locale_name);
+	nls_str_buf(|buf, sz| unsafe {
+		ffi::GetLocaleInfoEx(wlocale_name.as_ptr(), lc_type.raw(), buf, sz)
+	})
+}
+
 /// [`GetLocalTime`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getlocaltime)
 /// function.
 ///
@@ -469,6 +802,50 @@ pub fn GetNativeSystemInfo() -> SYSTEM_INFO {
 	si
 }
 
+/// [`GetNumberFormatEx`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-getnumberformatex)
+/// function.
+///
+/// The `value` must be a number string, like `"-1234.5"`. If `locale_name` is
+/// `None`, the user default locale is used. If `format` is `None`, the
+/// locale's defaults are used, and `flags` can be
+/// [`co::LOCALE::NOUSEROVERRIDE`](crate::co::LOCALE::NOUSEROVERRIDE).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let num = w::GetNumberFormatEx(Some("de-DE"), co::LOCALE::ALL, "1234.5", None)?;
+/// assert_eq!(num, "1.234,50");
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`GetCurrencyFormatEx`](crate::GetCurrencyFormatEx)
+/// * [`GetDateFormatEx`](crate::GetDateFormatEx)
+/// * [`GetTimeFormatEx`](crate::GetTimeFormatEx)
+#[must_use]
+pub fn GetNumberFormatEx(
+	locale_name: Option<&str>,
+	flags: co::LOCALE,
+	value: &str,
+	format: Option<&NUMBERFMT>,
+) -> SysResult<String> {
+	let wlocale_name = WString::from_opt_str(locale_name);
+	let wvalue = WString::from_str(value);
+	nls_str_buf(|buf, sz| unsafe {
+		ffi::GetNumberFormatEx(
+			wlocale_name.as_ptr(),
+			flags.raw(),
+			wvalue.as_ptr(),
+			format.map_or(std::ptr::null(), |f| f as *const _ as _),
+			buf,
+			sz,
+		)
+	})
+}
+
 /// [`GetPrivateProfileSection`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getprivateprofilesectionw)
 /// function.
 ///
@@ -805,6 +1182,77 @@ pub fn GetTickCount64() -> u64 {
 	unsafe { ffi::GetTickCount64() }
 }
 
+/// [`GetTimeFormatEx`](https://learn.microsoft.com/en-us/windows/win32/api/datetimeapi/nf-datetimeapi-gettimeformatex)
+/// function.
+///
+/// If `locale_name` is `None`, the user default locale is used. If `time` is
+/// `None`, the current local time is used. If `format` is `None`, the
+/// locale's format is used, otherwise it's a picture string like
+/// `"HH':'mm"`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let now = w::GetTimeFormatEx(None, co::TIME::NOSECONDS, None, None)?;
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`GetCurrencyFormatEx`](crate::GetCurrencyFormatEx)
+/// * [`GetDateFormatEx`](crate::GetDateFormatEx)
+/// * [`GetNumberFormatEx`](crate::GetNumberFormatEx)
+#[must_use]
+pub fn GetTimeFormatEx(
+	locale_name: Option<&str>,
+	flags: co::TIME,
+	time: Option<&SYSTEMTIME>,
+	format: Option<&str>,
+) -> SysResult<String> {
+	let wlocale_name = WString::from_opt_str(locale_name);
+	let wformat = WString::from_opt_str(format);
+	nls_str_buf(|buf, sz| unsafe {
+		ffi::GetTimeFormatEx(
+			wlocale_name.as_ptr(),
+			flags.raw(),
+			time.map_or(std::ptr::null(), |st| st as *const _ as _),
+			wformat.as_ptr(),
+			buf,
+			sz,
+		)
+	})
+}
+
+/// [`GetTimeZoneInformation`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-gettimezoneinformation)
+/// function.
+///
+/// # Related functions
+///
+/// * [`GetDynamicTimeZoneInformation`](crate::GetDynamicTimeZoneInformation)
+/// * [`SystemTimeToTzSpecificLocalTime`](crate::SystemTimeToTzSpecificLocalTime)
+/// * [`TzSpecificLocalTimeToSystemTime`](crate::TzSpecificLocalTimeToSystemTime)
+#[must_use]
+pub fn GetTimeZoneInformation() -> SysResult<(co::TIME_ZONE_ID, TIME_ZONE_INFORMATION)> {
+	let mut tzi = TIME_ZONE_INFORMATION::default();
+	match unsafe { ffi::GetTimeZoneInformation(&mut tzi as *mut _ as _) } {
+		TIME_ZONE_ID_INVALID => Err(GetLastError()),
+		id => Ok((unsafe { co::TIME_ZONE_ID::from_raw(id) }, tzi)),
+	}
+}
+
+/// [`GetUserDefaultLocaleName`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-getuserdefaultlocalename)
+/// function.
+#[must_use]
+pub fn GetUserDefaultLocaleName() -> SysResult<String> {
+	let mut buf = WString::new_alloc_buf(LOCALE_NAME_MAX_LENGTH);
+	match unsafe { ffi::GetUserDefaultLocaleName(buf.as_mut_ptr(), buf.buf_len() as _) } {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
 /// [`GetVolumeInformation`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumeinformationw)
 /// function.
 ///
@@ -895,6 +1343,72 @@ pub fn GetVolumePathName(file_name: &str) -> SysResult<String> {
 	.map(|_| buf.to_string())
 }
 
+/// [`GetVolumeNameForVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumenameforvolumemountpointw)
+/// function.
+///
+/// Returns the volume GUID path, like
+/// `\\?\Volume{26a21bda-a627-11d7-9931-806e6f6e6963}\`.
+///
+/// # Related functions
+///
+/// * [`DeleteVolumeMountPoint`](crate::DeleteVolumeMountPoint)
+/// * [`SetVolumeMountPoint`](crate::SetVolumeMountPoint)
+#[must_use]
+pub fn GetVolumeNameForVolumeMountPoint(volume_mount_point: &str) -> SysResult<String> {
+	let mut buf = WString::new_alloc_buf(50); // volume GUID paths have 49 chars
+	bool_to_sysresult(unsafe {
+		ffi::GetVolumeNameForVolumeMountPointW(
+			WString::from_str(volume_mount_point).as_ptr(),
+			buf.as_mut_ptr(),
+			buf.buf_len() as _,
+		)
+	})
+	.map(|_| buf.to_string())
+}
+
+/// [`GetVolumePathNamesForVolumeName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumepathnamesforvolumenamew)
+/// function.
+///
+/// Returns the drive letters and mounted folder paths of the given volume GUID
+/// path.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// for vol_name in w::HFINDVOLUME::iter_volumes() {
+///     let vol_name = vol_name?;
+///     for path in w::GetVolumePathNamesForVolumeName(&vol_name)? {
+///         println!("{} {}", vol_name, path);
+///     }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn GetVolumePathNamesForVolumeName(volume_name: &str) -> SysResult<Vec<String>> {
+	let volume_name_w = WString::from_str(volume_name);
+	let mut len = MAX_PATH as u32 + 1;
+
+	loop {
+		let mut buf = WString::new_alloc_buf(len as _);
+		match unsafe {
+			ffi::GetVolumePathNamesForVolumeNameW(
+				volume_name_w.as_ptr(),
+				buf.as_mut_ptr(),
+				buf.buf_len() as _,
+				&mut len,
+			)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::MORE_DATA => continue, // len now has the required size
+				err => return Err(err),
+			},
+			_ => return Ok(unsafe { parse_multi_z_str(buf.as_ptr(), Some(len as _)) }),
+		}
+	}
+}
+
 /// [`GlobalMemoryStatusEx`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-globalmemorystatusex)
 /// function.
 #[must_use]
@@ -1177,6 +1691,43 @@ pub const fn MAKEWORD(lo: u8, hi: u8) -> u16 {
 	(lo as u16 & 0xff) | ((hi as u16 & 0xff) << 8) as u16
 }
 
+/// [`LCMapStringEx`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-lcmapstringex)
+/// function.
+///
+/// If `locale_name` is `None`, the user default locale is used.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let upper = w::LCMapStringEx(Some("tr-TR"), co::LCMAP::UPPERCASE, "istanbul")?;
+/// assert_eq!(upper, "İSTANBUL");
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`CompareStringEx`](crate::CompareStringEx)
+#[must_use]
+pub fn LCMapStringEx(locale_name: Option<&str>, flags: co::LCMAP, src: &str) -> SysResult<String> {
+	let wlocale_name = WString::from_opt_str(locale_name);
+	let wsrc = WString::from_str(src);
+	nls_str_buf(|buf, sz| unsafe {
+		ffi::LCMapStringEx(
+			wlocale_name.as_ptr(),
+			flags.raw(),
+			wsrc.as_ptr(),
+			-1, // null-terminated, so the output is also null-terminated
+			buf,
+			sz,
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			0,
+		)
+	})
+}
+
 /// [`MoveFile`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-movefilew)
 /// function.
 ///
@@ -1379,6 +1930,40 @@ pub fn SetThreadStackGuarantee(stack_size_in_bytes: u32) -> SysResult<u32> {
 	bool_to_sysresult(unsafe { ffi::SetThreadStackGuarantee(&mut sz) }).map(|_| sz)
 }
 
+/// [`SetUnhandledExceptionFilter`](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setunhandledexceptionfilter)
+/// function.
+///
+/// Returns the previous filter, if any. The filter must return
+/// `EXCEPTION_EXECUTE_HANDLER` (1), `EXCEPTION_CONTINUE_SEARCH` (0) or
+/// `EXCEPTION_CONTINUE_EXECUTION` (-1).
+pub fn SetUnhandledExceptionFilter(
+	top_level_exception_filter: Option<LPTOP_LEVEL_EXCEPTION_FILTER>,
+) -> Option<LPTOP_LEVEL_EXCEPTION_FILTER> {
+	unsafe {
+		std::mem::transmute::<_, Option<LPTOP_LEVEL_EXCEPTION_FILTER>>(
+			ffi::SetUnhandledExceptionFilter(
+				top_level_exception_filter.map_or(std::ptr::null(), |f| f as _),
+			),
+		)
+	}
+}
+
+/// [`SetVolumeMountPoint`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-setvolumemountpointw)
+/// function.
+///
+/// # Related functions
+///
+/// * [`DeleteVolumeMountPoint`](crate::DeleteVolumeMountPoint)
+/// * [`GetVolumeNameForVolumeMountPoint`](crate::GetVolumeNameForVolumeMountPoint)
+pub fn SetVolumeMountPoint(volume_mount_point: &str, volume_name: &str) -> SysResult<()> {
+	bool_to_sysresult(unsafe {
+		ffi::SetVolumeMountPointW(
+			WString::from_str(volume_mount_point).as_ptr(),
+			WString::from_str(volume_name).as_ptr(),
+		)
+	})
+}
+
 /// [`Sleep`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-sleep)
 /// function.
 pub fn Sleep(milliseconds: u32) {
@@ -1418,6 +2003,7 @@ pub fn SystemTimeToFileTime(st: &SYSTEMTIME) -> SysResult<FILETIME> {
 /// * [`GetLocalTime`](crate::GetLocalTime)
 /// * [`GetSystemTime`](crate::GetSystemTime)
 /// * [`SystemTimeToFileTime`](crate::SystemTimeToFileTime)
+/// * [`TzSpecificLocalTimeToSystemTime`](crate::TzSpecificLocalTimeToSystemTime)
 #[must_use]
 pub fn SystemTimeToTzSpecificLocalTime(
 	time_zone: Option<&TIME_ZONE_INFORMATION>,
@@ -1434,6 +2020,30 @@ pub fn SystemTimeToTzSpecificLocalTime(
 	.map(|_| local_time)
 }
 
+/// [`TzSpecificLocalTimeToSystemTime`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-tzspecificlocaltimetosystemtime)
+/// function.
+///
+/// # Related functions
+///
+/// * [`GetTimeZoneInformation`](crate::GetTimeZoneInformation)
+/// * [`SystemTimeToFileTime`](crate::SystemTimeToFileTime)
+/// * [`SystemTimeToTzSpecificLocalTime`](crate::SystemTimeToTzSpecificLocalTime)
+#[must_use]
+pub fn TzSpecificLocalTimeToSystemTime(
+	time_zone: Option<&TIME_ZONE_INFORMATION>,
+	local_time: &SYSTEMTIME,
+) -> SysResult<SYSTEMTIME> {
+	let mut universal_time = SYSTEMTIME::default();
+	bool_to_sysresult(unsafe {
+		ffi::TzSpecificLocalTimeToSystemTime(
+			time_zone.map_or(std::ptr::null(), |lp| lp as *const _ as _),
+			local_time as *const _ as _,
+			&mut universal_time as *mut _ as _,
+		)
+	})
+	.map(|_| universal_time)
+}
+
 /// [`VerifyVersionInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-verifyversioninfow)
 /// function.
 #[must_use]
@@ -1463,6 +2073,57 @@ pub fn VerSetConditionMask(
 	unsafe { ffi::VerSetConditionMask(condition_mask, type_mask.raw(), condition.raw()) }
 }
 
+/// [`WaitForDebugEventEx`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-waitfordebugeventex)
+/// function.
+///
+/// Returns the process ID, the thread ID and the event itself. If the timeout
+/// elapses, returns
+/// [`co::ERROR::SEM_TIMEOUT`](crate::co::ERROR::SEM_TIMEOUT).
+///
+/// Each event must be followed by a call to
+/// [`ContinueDebugEvent`](crate::ContinueDebugEvent).
+///
+/// # Examples
+///
+/// Attaching to a running process and logging its events:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let pid: u32 = 1234;
+/// w::DebugActiveProcess(pid)?;
+///
+/// loop {
+///     let (pid, tid, event) = w::WaitForDebugEventEx(Some(5000))?;
+///     let status = match &event {
+///         w::DebugEvent::Exception { record, first_chance } => {
+///             println!("Exception {} first chance: {}", record.ExceptionCode, first_chance);
+///             co::DBG::EXCEPTION_NOT_HANDLED
+///         },
+///         _ => co::DBG::CONTINUE,
+///     };
+///
+///     w::ContinueDebugEvent(pid, tid, status)?;
+///     if let w::DebugEvent::ExitProcess { .. } = event {
+///         break;
+///     }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`ContinueDebugEvent`](crate::ContinueDebugEvent)
+/// * [`DebugActiveProcess`](crate::DebugActiveProcess)
+/// * [`DebugActiveProcessStop`](crate::DebugActiveProcessStop)
+pub fn WaitForDebugEventEx(milliseconds: Option<u32>) -> SysResult<(u32, u32, DebugEvent)> {
+	let mut de = DEBUG_EVENT::default();
+	bool_to_sysresult(unsafe {
+		ffi::WaitForDebugEventEx(&mut de as *mut _ as _, milliseconds.unwrap_or(INFINITE))
+	})
+	.map(|_| (de.dwProcessId, de.dwThreadId, unsafe { DebugEvent::from_raw(&de) }))
+}
+
 /// [`WideCharToMultiByte`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-widechartomultibyte)
 /// function.
 ///
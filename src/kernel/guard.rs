@@ -200,6 +200,14 @@ handle_guard! { FindCloseGuard: HFINDFILE;
 	/// when the object goes out of scope.
 }
 
+handle_guard! { FindVolumeCloseGuard: HFINDVOLUME;
+	ffi::FindVolumeClose;
+	/// RAII implementation for [`HFINDVOLUME`](crate::HFINDVOLUME) which
+	/// automatically calls
+	/// [`FindVolumeClose`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findvolumeclose)
+	/// when the object goes out of scope.
+}
+
 handle_guard! { FreeLibraryGuard: HINSTANCE;
 	ffi::FreeLibrary;
 	/// RAII implementation for [`HINSTANCE`](crate::HINSTANCE) which
@@ -26,6 +26,178 @@ impl kernel_Hfile for HFILE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hfile: Handle {
+	/// Calls
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl)
+	/// with
+	/// [`co::IOCTL::FSCTL_DELETE_REPARSE_POINT`](crate::co::IOCTL::FSCTL_DELETE_REPARSE_POINT),
+	/// removing the reparse point with the given Microsoft tag; the file or
+	/// directory itself is kept.
+	///
+	/// The file must be opened with
+	/// [`co::FILE_FLAG::OPEN_REPARSE_POINT`](crate::co::FILE_FLAG::OPEN_REPARSE_POINT)
+	/// and
+	/// [`co::FILE_FLAG::BACKUP_SEMANTICS`](crate::co::FILE_FLAG::BACKUP_SEMANTICS).
+	fn fsctl_delete_reparse_point(&self, tag: co::IO_REPARSE_TAG) -> SysResult<()> {
+		let buf = ReparseData::Other(tag, Vec::new()).to_bytes()?; // header only
+		self.DeviceIoControl(co::IOCTL::FSCTL_DELETE_REPARSE_POINT, Some(&buf), None)
+			.map(|_| ())
+	}
+
+	/// Calls
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl)
+	/// with
+	/// [`co::IOCTL::FSCTL_GET_REPARSE_POINT`](crate::co::IOCTL::FSCTL_GET_REPARSE_POINT),
+	/// returning the decoded reparse data.
+	///
+	/// The file must be opened with
+	/// [`co::FILE_FLAG::OPEN_REPARSE_POINT`](crate::co::FILE_FLAG::OPEN_REPARSE_POINT)
+	/// and
+	/// [`co::FILE_FLAG::BACKUP_SEMANTICS`](crate::co::FILE_FLAG::BACKUP_SEMANTICS).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let (hfile, _) = w::HFILE::CreateFile(
+	///     "C:\\Temp\\my_junction",
+	///     co::GENERIC::READ,
+	///     Some(co::FILE_SHARE::READ | co::FILE_SHARE::WRITE),
+	///     None,
+	///     co::DISPOSITION::OPEN_EXISTING,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     Some(co::FILE_FLAG::OPEN_REPARSE_POINT | co::FILE_FLAG::BACKUP_SEMANTICS),
+	///     None,
+	///     None,
+	/// )?;
+	///
+	/// if let w::ReparseData::MountPoint { print_name, .. } = hfile.fsctl_get_reparse_point()? {
+	///     println!("Target: {}", print_name);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn fsctl_get_reparse_point(&self) -> SysResult<ReparseData> {
+		let mut buf = vec![0u8; MAXIMUM_REPARSE_DATA_BUFFER_SIZE];
+		let num_bytes =
+			self.DeviceIoControl(co::IOCTL::FSCTL_GET_REPARSE_POINT, None, Some(&mut buf))?;
+		ReparseData::from_bytes(&buf[..num_bytes as usize])
+	}
+
+	/// Calls
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl)
+	/// with
+	/// [`co::IOCTL::FSCTL_SET_REPARSE_POINT`](crate::co::IOCTL::FSCTL_SET_REPARSE_POINT),
+	/// writing the encoded reparse data.
+	///
+	/// The file must be opened with
+	/// [`co::FILE_FLAG::OPEN_REPARSE_POINT`](crate::co::FILE_FLAG::OPEN_REPARSE_POINT)
+	/// and
+	/// [`co::FILE_FLAG::BACKUP_SEMANTICS`](crate::co::FILE_FLAG::BACKUP_SEMANTICS).
+	///
+	/// # Examples
+	///
+	/// Creating a junction, which must be an empty directory:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// w::CreateDirectory("C:\\Temp\\my_junction", None)?;
+	///
+	/// let (hdir, _) = w::HFILE::CreateFile(
+	///     "C:\\Temp\\my_junction",
+	///     co::GENERIC::WRITE,
+	///     None,
+	///     None,
+	///     co::DISPOSITION::OPEN_EXISTING,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     Some(co::FILE_FLAG::OPEN_REPARSE_POINT | co::FILE_FLAG::BACKUP_SEMANTICS),
+	///     None,
+	///     None,
+	/// )?;
+	///
+	/// hdir.fsctl_set_reparse_point(&w::ReparseData::new_junction("C:\\Target"))?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn fsctl_set_reparse_point(&self, data: &ReparseData) -> SysResult<()> {
+		let buf = data.to_bytes()?;
+		self.DeviceIoControl(co::IOCTL::FSCTL_SET_REPARSE_POINT, Some(&buf), None)
+			.map(|_| ())
+	}
+
+	/// Calls
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl)
+	/// with
+	/// [`co::IOCTL::DISK_GET_DRIVE_GEOMETRY_EX`](crate::co::IOCTL::DISK_GET_DRIVE_GEOMETRY_EX),
+	/// returning the decoded struct.
+	///
+	/// The handle must be a physical drive or a volume, like
+	/// `\\.\PhysicalDrive0`.
+	#[must_use]
+	fn ioctl_disk_get_drive_geometry_ex(&self) -> SysResult<DISK_GEOMETRY_EX> {
+		let mut buf = [0u8; 256]; // room for the partition and detection info
+		let num_bytes =
+			self.DeviceIoControl(co::IOCTL::DISK_GET_DRIVE_GEOMETRY_EX, None, Some(&mut buf))?;
+		DISK_GEOMETRY_EX::from_bytes(&buf[..num_bytes as usize])
+	}
+
+	/// Calls
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl)
+	/// with
+	/// [`co::IOCTL::STORAGE_QUERY_PROPERTY`](crate::co::IOCTL::STORAGE_QUERY_PROPERTY),
+	/// returning the raw bytes of the descriptor, which are allocated with the
+	/// size informed by the device.
+	///
+	/// # Examples
+	///
+	/// Retrieving the storage device descriptor:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let (hdrive, _) = w::HFILE::CreateFile(
+	///     "\\\\.\\PhysicalDrive0",
+	///     co::GENERIC::READ,
+	///     Some(co::FILE_SHARE::READ | co::FILE_SHARE::WRITE),
+	///     None,
+	///     co::DISPOSITION::OPEN_EXISTING,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     None,
+	///     None,
+	///     None,
+	/// )?;
+	///
+	/// let raw = hdrive.ioctl_storage_query_property(&w::STORAGE_PROPERTY_QUERY {
+	///     PropertyId: co::STORAGE_PROPERTY_ID::Device,
+	///     QueryType: co::STORAGE_QUERY_TYPE::Standard,
+	///     ..Default::default()
+	/// })?;
+	///
+	/// let desc = w::STORAGE_DEVICE_DESCRIPTOR::from_bytes(&raw)?;
+	/// println!("{:?} {:?}", desc.ProductId, desc.SerialNumber);
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn ioctl_storage_query_property(&self, query: &STORAGE_PROPERTY_QUERY) -> SysResult<Vec<u8>> {
+		let query_buf = query.to_bytes();
+		let mut header_buf = [0u8; std::mem::size_of::<STORAGE_DESCRIPTOR_HEADER>()];
+		self.DeviceIoControl(
+			co::IOCTL::STORAGE_QUERY_PROPERTY,
+			Some(&query_buf),
+			Some(&mut header_buf),
+		)?;
+		let header = STORAGE_DESCRIPTOR_HEADER::from_bytes(&header_buf)?;
+
+		let mut buf = vec![0u8; (header.Size as usize).max(header_buf.len())];
+		let num_bytes = self.DeviceIoControl(
+			co::IOCTL::STORAGE_QUERY_PROPERTY,
+			Some(&query_buf),
+			Some(&mut buf),
+		)?;
+		buf.truncate(num_bytes as _);
+		Ok(buf)
+	}
+
 	/// [`CreateFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-createfilew)
 	/// function.
 	///
@@ -130,6 +302,47 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`DeviceIoControl`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-deviceiocontrol)
+	/// function.
+	///
+	/// Returns the number of bytes written to `out_buf`.
+	///
+	/// Some control codes have typed wrappers:
+	///
+	/// * [`HFILE::fsctl_get_reparse_point`](crate::prelude::kernel_Hfile::fsctl_get_reparse_point);
+	/// * [`HFILE::ioctl_disk_get_drive_geometry_ex`](crate::prelude::kernel_Hfile::ioctl_disk_get_drive_geometry_ex);
+	/// * [`HFILE::ioctl_storage_query_property`](crate::prelude::kernel_Hfile::ioctl_storage_query_property).
+	///
+	/// Note that asynchronous operation – which use the
+	/// [`OVERLAPPED`](crate::OVERLAPPED) struct – is not currently supported by
+	/// this method, because the buffers must remain untouched until the async
+	/// operation is complete, thus making the method unsound.
+	fn DeviceIoControl(
+		&self,
+		control_code: co::IOCTL,
+		in_buf: Option<&[u8]>,
+		out_buf: Option<&mut [u8]>,
+	) -> SysResult<u32> {
+		let (in_ptr, in_len) = in_buf.map_or((std::ptr::null(), 0), |b| (vec_ptr(b), b.len()));
+		let (out_ptr, out_len) =
+			out_buf.map_or((std::ptr::null_mut(), 0), |b| (b.as_mut_ptr(), b.len()));
+		let mut bytes_returned = u32::default();
+
+		bool_to_sysresult(unsafe {
+			ffi::DeviceIoControl(
+				self.ptr(),
+				control_code.raw(),
+				in_ptr as _,
+				in_len as _,
+				out_ptr as _,
+				out_len as _,
+				&mut bytes_returned,
+				std::ptr::null_mut(),
+			)
+		})
+		.map(|_| bytes_returned)
+	}
+
 	/// [`GetFileInformationByHandle`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfileinformationbyhandle)
 	/// function.
 	fn GetFileInformationByHandle(&self) -> SysResult<BY_HANDLE_FILE_INFORMATION> {
@@ -140,6 +353,87 @@ pub trait kernel_Hfile: Handle {
 		.map(|_| fi)
 	}
 
+	/// [`GetFileInformationByHandleEx`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getfileinformationbyhandleex)
+	/// function.
+	///
+	/// # Panics
+	///
+	/// Panics if `info_class` is not one of the implemented classes, listed
+	/// in [`FileInfo`](crate::FileInfo).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let f = w::File::open("C:\\Temp\\file.txt", w::FileAccess::ExistingReadOnly)?;
+	///
+	/// if let w::FileInfo::Standard(info) =
+	///     f.hfile().GetFileInformationByHandleEx(co::FILE_INFO_BY_HANDLE_CLASS::StandardInfo)?
+	/// {
+	///     println!("Hard links: {}", info.NumberOfLinks);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn GetFileInformationByHandleEx(
+		&self,
+		info_class: co::FILE_INFO_BY_HANDLE_CLASS,
+	) -> SysResult<FileInfo> {
+		let mut buf = vec![
+			0u8;
+			match info_class {
+				co::FILE_INFO_BY_HANDLE_CLASS::BasicInfo => std::mem::size_of::<FILE_BASIC_INFO>(),
+				co::FILE_INFO_BY_HANDLE_CLASS::StandardInfo => {
+					std::mem::size_of::<FILE_STANDARD_INFO>()
+				},
+				co::FILE_INFO_BY_HANDLE_CLASS::NameInfo => {
+					std::mem::size_of::<u32>() + (MAX_PATH + 1) * std::mem::size_of::<u16>()
+				},
+				co::FILE_INFO_BY_HANDLE_CLASS::AttributeTagInfo => {
+					std::mem::size_of::<FILE_ATTRIBUTE_TAG_INFO>()
+				},
+				co::FILE_INFO_BY_HANDLE_CLASS::IdInfo => std::mem::size_of::<FILE_ID_INFO>(),
+				_ => panic!("co::FILE_INFO_BY_HANDLE_CLASS not implemented yet: {}", info_class),
+			}
+		];
+
+		loop {
+			match bool_to_sysresult(unsafe {
+				ffi::GetFileInformationByHandleEx(
+					self.ptr(),
+					info_class.raw(),
+					buf.as_mut_ptr() as _,
+					buf.len() as _,
+				)
+			}) {
+				Ok(_) => break,
+				Err(co::ERROR::MORE_DATA) => buf.resize(buf.len() * 2, 0), // name too long, try again
+				Err(e) => return Err(e),
+			}
+		}
+
+		fn read<T>(buf: &[u8]) -> T {
+			unsafe { std::ptr::read_unaligned(buf.as_ptr() as *const T) } // buffer is large enough
+		}
+		Ok(match info_class {
+			co::FILE_INFO_BY_HANDLE_CLASS::BasicInfo => FileInfo::Basic(read(&buf)),
+			co::FILE_INFO_BY_HANDLE_CLASS::StandardInfo => FileInfo::Standard(read(&buf)),
+			co::FILE_INFO_BY_HANDLE_CLASS::NameInfo => {
+				let num_bytes = u32::from_le_bytes(le_bytes(&buf, 0)?) as usize;
+				let wchars = buf
+					.get(4..4 + num_bytes)
+					.ok_or(co::ERROR::INVALID_DATA)?
+					.chunks_exact(2)
+					.map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+					.collect::<Vec<_>>();
+				FileInfo::Name(WString::from_wchars_slice(&wchars).to_string())
+			},
+			co::FILE_INFO_BY_HANDLE_CLASS::AttributeTagInfo => FileInfo::AttributeTag(read(&buf)),
+			_ => FileInfo::Id(read(&buf)),
+		})
+	}
+
 	/// [`GetFileSizeEx`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfilesizeex)
 	/// function.
 	#[must_use]
@@ -244,6 +538,35 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`OpenFileById`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-openfilebyid)
+	/// function.
+	///
+	/// This method is called on a handle to any file or directory in the
+	/// volume where the file is located.
+	#[must_use]
+	fn OpenFileById(
+		&self,
+		file_id: &FILE_ID_DESCRIPTOR,
+		desired_access: co::GENERIC,
+		share_mode: Option<co::FILE_SHARE>,
+		security_attributes: Option<&mut SECURITY_ATTRIBUTES>,
+		flags: Option<co::FILE_FLAG>,
+	) -> SysResult<CloseHandleGuard<HFILE>> {
+		unsafe {
+			match HFILE::from_ptr(ffi::OpenFileById(
+				self.ptr(),
+				file_id as *const _ as _,
+				desired_access.raw(),
+				share_mode.unwrap_or_default().raw(),
+				security_attributes.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				flags.unwrap_or_default().raw(),
+			)) {
+				HFILE::NULL | HFILE::INVALID => Err(GetLastError()),
+				handle => Ok(CloseHandleGuard::new(handle)),
+			}
+		}
+	}
+
 	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
 	/// function.
 	///
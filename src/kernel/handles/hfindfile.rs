@@ -3,7 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
-use crate::kernel::ffi;
+use crate::kernel::{ffi, iterators::*, privs::*};
 use crate::prelude::*;
 
 handle! { HFINDFILE;
@@ -23,6 +23,58 @@ impl kernel_Hfindfile for HFINDFILE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hfindfile: Handle {
+	/// Returns an iterator over the names of all the hard links to the given
+	/// file, by calling
+	/// [`HFINDFILE::FindFirstFileName`](crate::prelude::kernel_Hfindfile::FindFirstFileName)
+	/// and then
+	/// [`HFINDFILE::FindNextFileName`](crate::prelude::kernel_Hfindfile::FindNextFileName)
+	/// consecutively.
+	///
+	/// The names are relative to the volume root, without the drive letter,
+	/// like `\Temp\file.txt`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for link in w::HFINDFILE::iter_file_names("C:\\Temp\\file.txt") {
+	///     let link = link?;
+	///     println!("{}", link);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn iter_file_names(file_name: &str) -> impl Iterator<Item = SysResult<String>> {
+		HfindfileFileNameIter::new(file_name)
+	}
+
+	/// Returns an iterator over the name and the size of each data stream of
+	/// the given file, including the default unnamed stream, by calling
+	/// [`HFINDFILE::FindFirstStream`](crate::prelude::kernel_Hfindfile::FindFirstStream)
+	/// and then
+	/// [`HFINDFILE::FindNextStream`](crate::prelude::kernel_Hfindfile::FindNextStream)
+	/// consecutively.
+	///
+	/// The names have the form `:name:$DATA`, and the default stream is
+	/// `::$DATA`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for stream in w::HFINDFILE::iter_streams("C:\\Temp\\file.txt") {
+	///     let (name, size) = stream?;
+	///     println!("{} {}", name, size);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn iter_streams(file_name: &str) -> impl Iterator<Item = SysResult<(String, u64)>> {
+		HfindfileStreamIter::new(file_name)
+	}
+
 	/// [`FindFirstFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstfilew)
 	/// function.
 	///
@@ -55,6 +107,103 @@ pub trait kernel_Hfindfile: Handle {
 		}
 	}
 
+	/// [`FindFirstFileEx`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstfileexw)
+	/// function.
+	///
+	/// This method is rather tricky, consider using
+	/// [`path::DirWalker`](crate::path::DirWalker).
+	#[must_use]
+	fn FindFirstFileEx(
+		file_name: &str,
+		info_level: co::FINDEX_INFO_LEVELS,
+		wfd: &mut WIN32_FIND_DATA,
+		search_op: co::FINDEX_SEARCH_OPS,
+		additional_flags: Option<co::FIND_FIRST_EX>,
+	) -> SysResult<(FindCloseGuard, bool)> {
+		unsafe {
+			match HFINDFILE::from_ptr(ffi::FindFirstFileExW(
+				WString::from_str(file_name).as_ptr(),
+				info_level.raw(),
+				wfd as *mut _ as _,
+				search_op.raw(),
+				std::ptr::null_mut(),
+				additional_flags.unwrap_or_default().raw(),
+			)) {
+				HFINDFILE::NULL | HFINDFILE::INVALID => match GetLastError() {
+					co::ERROR::FILE_NOT_FOUND => Ok((
+						FindCloseGuard::new(HFINDFILE::NULL), // not an error, first file not found
+						false,
+					)),
+					err => Err(err),
+				},
+				h => Ok((FindCloseGuard::new(h), true)),
+			}
+		}
+	}
+
+	/// [`FindFirstFileName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstfilenamew)
+	/// function.
+	///
+	/// Returns the handle and the first hard link name.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDFILE::iter_file_names`](crate::prelude::kernel_Hfindfile::iter_file_names).
+	#[must_use]
+	fn FindFirstFileName(file_name: &str) -> SysResult<(FindCloseGuard, String)> {
+		let wfile_name = WString::from_str(file_name);
+		let mut buf_sz = MAX_PATH as u32;
+
+		loop {
+			let mut buf = WString::new_alloc_buf(buf_sz as _);
+			let mut len = buf_sz;
+			unsafe {
+				match HFINDFILE::from_ptr(ffi::FindFirstFileNameW(
+					wfile_name.as_ptr(),
+					0,
+					&mut len,
+					buf.as_mut_ptr(),
+				)) {
+					HFINDFILE::NULL | HFINDFILE::INVALID => match GetLastError() {
+						co::ERROR::MORE_DATA => buf_sz = len, // buffer too small, try again
+						err => return Err(err),
+					},
+					h => return Ok((FindCloseGuard::new(h), buf.to_string())),
+				}
+			}
+		}
+	}
+
+	/// [`FindFirstStream`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirststreamw)
+	/// function.
+	///
+	/// Returns `false` if the file has no streams.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDFILE::iter_streams`](crate::prelude::kernel_Hfindfile::iter_streams).
+	#[must_use]
+	fn FindFirstStream(
+		file_name: &str,
+		wfsd: &mut WIN32_FIND_STREAM_DATA,
+	) -> SysResult<(FindCloseGuard, bool)> {
+		unsafe {
+			match HFINDFILE::from_ptr(ffi::FindFirstStreamW(
+				WString::from_str(file_name).as_ptr(),
+				0, // FindStreamInfoStandard
+				wfsd as *mut _ as _,
+				0,
+			)) {
+				HFINDFILE::NULL | HFINDFILE::INVALID => match GetLastError() {
+					co::ERROR::HANDLE_EOF => Ok((
+						FindCloseGuard::new(HFINDFILE::NULL), // not an error, no streams found
+						false,
+					)),
+					err => Err(err),
+				},
+				h => Ok((FindCloseGuard::new(h), true)),
+			}
+		}
+	}
+
 	/// [`FindNextFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findnextfilew)
 	/// function.
 	///
@@ -70,4 +219,45 @@ pub trait kernel_Hfindfile: Handle {
 			_ => Ok(true),
 		}
 	}
+
+	/// [`FindNextFileName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findnextfilenamew)
+	/// function.
+	///
+	/// Returns `None` if there are no more hard links.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDFILE::iter_file_names`](crate::prelude::kernel_Hfindfile::iter_file_names).
+	#[must_use]
+	fn FindNextFileName(&self) -> SysResult<Option<String>> {
+		let mut buf_sz = MAX_PATH as u32;
+
+		loop {
+			let mut buf = WString::new_alloc_buf(buf_sz as _);
+			let mut len = buf_sz;
+			match unsafe { ffi::FindNextFileNameW(self.ptr(), &mut len, buf.as_mut_ptr()) } {
+				0 => match GetLastError() {
+					co::ERROR::MORE_DATA => buf_sz = len, // buffer too small, try again
+					co::ERROR::HANDLE_EOF => return Ok(None), // not an error, no further links found
+					err => return Err(err),
+				},
+				_ => return Ok(Some(buf.to_string())),
+			}
+		}
+	}
+
+	/// [`FindNextStream`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findnextstreamw)
+	/// function.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDFILE::iter_streams`](crate::prelude::kernel_Hfindfile::iter_streams).
+	#[must_use]
+	fn FindNextStream(&self, wfsd: &mut WIN32_FIND_STREAM_DATA) -> SysResult<bool> {
+		match unsafe { ffi::FindNextStreamW(self.ptr(), wfsd as *mut _ as _) } {
+			0 => match GetLastError() {
+				co::ERROR::HANDLE_EOF => Ok(false), // not an error, no further streams found
+				err => Err(err),
+			},
+			_ => Ok(true),
+		}
+	}
 }
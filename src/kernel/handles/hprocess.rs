@@ -250,6 +250,29 @@ pub trait kernel_Hprocess: Handle {
 		bool_to_sysresult(unsafe { ffi::QueryProcessCycleTime(self.ptr(), &mut t) }).map(|_| t)
 	}
 
+	/// [`ReadProcessMemory`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-readprocessmemory)
+	/// function.
+	///
+	/// Reads from the given address in the address space of the process until
+	/// the buffer is filled, returning the number of bytes actually read.
+	fn ReadProcessMemory(
+		&self,
+		base_address: *const std::ffi::c_void,
+		buffer: &mut [u8],
+	) -> SysResult<usize> {
+		let mut num_read = usize::default();
+		bool_to_sysresult(unsafe {
+			ffi::ReadProcessMemory(
+				self.ptr(),
+				base_address as _,
+				buffer.as_mut_ptr() as _,
+				buffer.len(),
+				&mut num_read,
+			)
+		})
+		.map(|_| num_read)
+	}
+
 	/// [`SetPriorityClass`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setpriorityclass)
 	/// function.
 	fn SetPriorityClass(&self, prority_class: co::PRIORITY_CLASS) -> SysResult<()> {
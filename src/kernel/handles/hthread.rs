@@ -75,6 +75,29 @@ pub trait kernel_Hthread: Handle {
 		}
 	}
 
+	/// [`GetThreadContext`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getthreadcontext)
+	/// function.
+	///
+	/// The registers to be retrieved are chosen by the `ContextFlags` field of
+	/// the struct, which must be set beforehand.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hthread: w::HTHREAD; // initialized somewhere
+	/// # let hthread = w::HTHREAD::NULL;
+	///
+	/// let mut ctx = w::CONTEXT::default();
+	/// ctx.ContextFlags = co::CONTEXT::FULL;
+	/// hthread.GetThreadContext(&mut ctx)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn GetThreadContext(&self, context: &mut CONTEXT) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::GetThreadContext(self.ptr(), context as *mut _ as _) })
+	}
+
 	/// [`GetThreadId`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getthreadid)
 	/// function.
 	#[must_use]
@@ -136,6 +159,12 @@ pub trait kernel_Hthread: Handle {
 		minus1_as_error(unsafe { ffi::ResumeThread(self.ptr()) })
 	}
 
+	/// [`SetThreadContext`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadcontext)
+	/// function.
+	fn SetThreadContext(&self, context: &CONTEXT) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::SetThreadContext(self.ptr(), context as *const _ as _) })
+	}
+
 	/// [`SetThreadIdealProcessor`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadidealprocessor)
 	/// function.
 	///
@@ -4,6 +4,7 @@ mod hfile;
 mod hfilemap;
 mod hfilemapview;
 mod hfindfile;
+mod hfindvolume;
 mod hglobal;
 mod hheap;
 mod hinstance;
@@ -21,6 +22,7 @@ pub mod decl {
 	pub use super::hfilemap::HFILEMAP;
 	pub use super::hfilemapview::HFILEMAPVIEW;
 	pub use super::hfindfile::HFINDFILE;
+	pub use super::hfindvolume::HFINDVOLUME;
 	pub use super::hglobal::HGLOBAL;
 	pub use super::hheap::HHEAP;
 	pub use super::hinstance::HINSTANCE;
@@ -58,6 +60,7 @@ pub mod traits {
 	pub use super::hfilemap::kernel_Hfilemap;
 	pub use super::hfilemapview::kernel_Hfilemapview;
 	pub use super::hfindfile::kernel_Hfindfile;
+	pub use super::hfindvolume::kernel_Hfindvolume;
 	pub use super::hglobal::kernel_Hglobal;
 	pub use super::hheap::kernel_Hheap;
 	pub use super::hinstance::kernel_Hinstance;
@@ -2,6 +2,7 @@ use crate::co;
 use crate::decl::*;
 use crate::guard::*;
 use crate::kernel::ffi;
+use crate::path::{DirWalkEntry, DirWalker};
 use crate::prelude::*;
 
 pub(in crate::kernel) struct DirListIter<'a> {
@@ -149,6 +150,361 @@ impl<'a> DirWalkIter<'a> {
 	}
 }
 
+struct DirWalkerFrame {
+	entries: std::vec::IntoIter<DirWalkEntry>,
+	dir: Option<DirWalkEntry>, // yielded when the frame is done, in post-order
+	id: Option<(u32, u64)>,    // volume serial number and file index, for cycle detection
+}
+
+pub(in crate::kernel) struct DirWalkerIter {
+	opts: DirWalker,
+	stack: Vec<DirWalkerFrame>,
+	queued: std::collections::VecDeque<SysResult<DirWalkEntry>>,
+	started: bool,
+}
+
+impl Iterator for DirWalkerIter {
+	type Item = SysResult<DirWalkEntry>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(queued) = self.queued.pop_front() {
+			return Some(queued);
+		}
+
+		if !self.started {
+			// First pass, read the root directory.
+			self.started = true;
+			let root = self.opts.dir_path.clone();
+			let id = if self.opts.follow_reparse_points {
+				Self::dir_id(&root).ok() // children are still checked against each other
+			} else {
+				None
+			};
+			match self.read_dir(&root, 1) {
+				Err(e) => return Some(Err(e)),
+				Ok(entries) => self.stack.push(DirWalkerFrame { entries, dir: None, id }),
+			}
+		}
+
+		loop {
+			let frame = self.stack.last_mut()?; // empty stack, walk is finished
+			let entry = match frame.entries.next() {
+				Some(entry) => entry,
+				None => {
+					// All entries of this directory were processed.
+					if let Some(dir) = self.stack.pop().and_then(|frame| frame.dir) {
+						return Some(Ok(dir));
+					}
+					continue;
+				},
+			};
+
+			if !entry.is_directory() {
+				return Some(Ok(entry));
+			}
+
+			let within_depth = self.opts.max_depth.is_none_or(|max| entry.depth < max);
+			let descend =
+				within_depth && (self.opts.follow_reparse_points || !entry.is_reparse_point());
+			if !descend {
+				if self.opts.files_only {
+					continue;
+				}
+				return Some(Ok(entry));
+			}
+
+			let id = if self.opts.follow_reparse_points {
+				match Self::dir_id(&entry.path) {
+					Err(e) => {
+						// Without the identity a cycle can't be detected, so don't
+						// descend; yield the directory followed by the error.
+						if self.opts.files_only {
+							return Some(Err(e));
+						}
+						self.queued.push_back(Err(e));
+						return Some(Ok(entry));
+					},
+					Ok(id) if self.stack.iter().any(|frame| frame.id == Some(id)) => {
+						// Directory is one of its own ancestors, don't descend.
+						if self.opts.files_only {
+							continue;
+						}
+						return Some(Ok(entry));
+					},
+					Ok(id) => Some(id),
+				}
+			} else {
+				None
+			};
+
+			match self.read_dir(&entry.path, entry.depth + 1) {
+				Err(e) => {
+					// Directory can't be read, yield it followed by the error.
+					if self.opts.files_only {
+						return Some(Err(e));
+					}
+					self.queued.push_back(Err(e));
+					return Some(Ok(entry));
+				},
+				Ok(entries) => {
+					if self.opts.files_only {
+						self.stack.push(DirWalkerFrame { entries, dir: None, id });
+					} else if self.opts.post_order {
+						self.stack
+							.push(DirWalkerFrame { entries, dir: Some(entry), id });
+					} else {
+						self.stack.push(DirWalkerFrame { entries, dir: None, id });
+						return Some(Ok(entry));
+					}
+				},
+			}
+		}
+	}
+}
+
+impl DirWalkerIter {
+	#[must_use]
+	pub(in crate::kernel) fn new(opts: DirWalker) -> Self {
+		Self {
+			opts,
+			stack: Vec::new(),
+			queued: std::collections::VecDeque::new(),
+			started: false,
+		}
+	}
+
+	/// Reads all the entries of the directory, filtered and sorted.
+	fn read_dir(
+		&mut self,
+		dir_path: &str,
+		depth: usize,
+	) -> SysResult<std::vec::IntoIter<DirWalkEntry>> {
+		let info_level = if self.opts.basic_info {
+			co::FINDEX_INFO_LEVELS::Basic
+		} else {
+			co::FINDEX_INFO_LEVELS::Standard
+		};
+		let flags = if self.opts.large_fetch { Some(co::FIND_FIRST_EX::LARGE_FETCH) } else { None };
+
+		let mut wfd = WIN32_FIND_DATA::default();
+		let (hfind, mut found) = HFINDFILE::FindFirstFileEx(
+			&format!("{}\\*", dir_path),
+			info_level,
+			&mut wfd,
+			co::FINDEX_SEARCH_OPS::NameMatch,
+			flags,
+		)?;
+
+		let mut entries = Vec::<DirWalkEntry>::new();
+		while found {
+			let file_name = wfd.cFileName();
+			if file_name != "." && file_name != ".." {
+				let entry = DirWalkEntry {
+					path: format!("{}\\{}", dir_path, file_name),
+					depth,
+					wfd: std::mem::take(&mut wfd),
+				};
+				if self.accepts(&entry) {
+					entries.push(entry);
+				}
+			}
+			found = hfind.FindNextFile(&mut wfd)?;
+		}
+
+		if let Some(sort_by) = &mut self.opts.sort_by {
+			entries.sort_by(|a, b| sort_by(a, b));
+		}
+		Ok(entries.into_iter())
+	}
+
+	/// Tells whether the entry passes the attribute and pattern filters.
+	#[must_use]
+	fn accepts(&self, entry: &DirWalkEntry) -> bool {
+		let attrs = entry.attributes();
+		if (self.opts.skip_hidden && attrs.has(co::FILE_ATTRIBUTE::HIDDEN))
+			|| (self.opts.skip_system && attrs.has(co::FILE_ATTRIBUTE::SYSTEM))
+		{
+			return false;
+		}
+
+		let rel_path = entry.path[self.opts.dir_path.len()..].trim_start_matches('\\');
+		let file_name = entry.file_name();
+		if self
+			.opts
+			.excludes
+			.iter()
+			.any(|glob| glob.matches(rel_path, file_name))
+		{
+			return false;
+		}
+
+		entry.is_directory()
+			|| self.opts.includes.is_empty()
+			|| self
+				.opts
+				.includes
+				.iter()
+				.any(|glob| glob.matches(rel_path, file_name))
+	}
+
+	/// Retrieves the volume serial number and the file index of the directory.
+	fn dir_id(dir_path: &str) -> SysResult<(u32, u64)> {
+		let (hdir, _) = HFILE::CreateFile(
+			dir_path,
+			co::GENERIC::default(), // no access rights are needed to query the file information
+			Some(co::FILE_SHARE::READ | co::FILE_SHARE::WRITE | co::FILE_SHARE::DELETE),
+			None,
+			co::DISPOSITION::OPEN_EXISTING,
+			co::FILE_ATTRIBUTE::NORMAL,
+			Some(co::FILE_FLAG::BACKUP_SEMANTICS),
+			None,
+			None,
+		)?;
+		let fi = hdir.GetFileInformationByHandle()?;
+		Ok((fi.dwVolumeSerialNumber, fi.nFileIndex()))
+	}
+}
+
+pub(in crate::kernel) struct HfindfileFileNameIter {
+	file_name: String,
+	hfind: Option<FindCloseGuard>,
+	no_more: bool,
+}
+
+impl Iterator for HfindfileFileNameIter {
+	type Item = SysResult<String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		let found = match &self.hfind {
+			None => HFINDFILE::FindFirstFileName(&self.file_name).map(|(hfind, name)| {
+				self.hfind = Some(hfind); // store our find handle
+				Some(name)
+			}),
+			Some(hfind) => hfind.FindNextFileName(),
+		};
+
+		match found {
+			Err(e) => {
+				self.no_more = true; // prevent further iterations
+				Some(Err(e))
+			},
+			Ok(None) => {
+				self.no_more = true;
+				None // no more links, halt
+			},
+			Ok(Some(name)) => Some(Ok(name)),
+		}
+	}
+}
+
+impl HfindfileFileNameIter {
+	#[must_use]
+	pub(in crate::kernel) fn new(file_name: &str) -> Self {
+		Self {
+			file_name: file_name.to_owned(),
+			hfind: None,
+			no_more: false,
+		}
+	}
+}
+
+pub(in crate::kernel) struct HfindfileStreamIter {
+	file_name: String,
+	hfind: Option<FindCloseGuard>,
+	wfsd: WIN32_FIND_STREAM_DATA,
+	no_more: bool,
+}
+
+impl Iterator for HfindfileStreamIter {
+	type Item = SysResult<(String, u64)>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		let found = match &self.hfind {
+			None => {
+				HFINDFILE::FindFirstStream(&self.file_name, &mut self.wfsd).map(|(hfind, found)| {
+					self.hfind = Some(hfind); // store our find handle
+					found
+				})
+			},
+			Some(hfind) => hfind.FindNextStream(&mut self.wfsd),
+		};
+
+		match found {
+			Err(e) => {
+				self.no_more = true; // prevent further iterations
+				Some(Err(e))
+			},
+			Ok(false) => {
+				self.no_more = true;
+				None // no more streams, halt
+			},
+			Ok(true) => Some(Ok((self.wfsd.cStreamName(), self.wfsd.StreamSize as _))),
+		}
+	}
+}
+
+impl HfindfileStreamIter {
+	#[must_use]
+	pub(in crate::kernel) fn new(file_name: &str) -> Self {
+		Self {
+			file_name: file_name.to_owned(),
+			hfind: None,
+			wfsd: WIN32_FIND_STREAM_DATA::default(),
+			no_more: false,
+		}
+	}
+}
+
+pub(in crate::kernel) struct HfindvolumeIter {
+	hfind: Option<FindVolumeCloseGuard>,
+	no_more: bool,
+}
+
+impl Iterator for HfindvolumeIter {
+	type Item = SysResult<String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		let found = match &self.hfind {
+			None => HFINDVOLUME::FindFirstVolume().map(|(hfind, name)| {
+				self.hfind = Some(hfind); // store our find handle
+				Some(name)
+			}),
+			Some(hfind) => hfind.FindNextVolume(),
+		};
+
+		match found {
+			Err(e) => {
+				self.no_more = true; // prevent further iterations
+				Some(Err(e))
+			},
+			Ok(None) => {
+				self.no_more = true;
+				None // no more volumes, halt
+			},
+			Ok(Some(name)) => Some(Ok(name)),
+		}
+	}
+}
+
+impl HfindvolumeIter {
+	#[must_use]
+	pub(in crate::kernel) const fn new() -> Self {
+		Self { hfind: None, no_more: false }
+	}
+}
+
 pub(in crate::kernel) struct HheapHeapwalkIter<'a, H>
 where
 	H: kernel_Hheap,
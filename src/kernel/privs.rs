@@ -11,10 +11,14 @@ const_values_num_privs! {
 	INFINITE u32 = 0xffff_ffff
 	INVALID_FILE_ATTRIBUTES i32 = -1
 	LMEM_INVALID_HANDLE u32 = 0x8000
+	LOCALE_NAME_MAX_LENGTH usize = 85
 	MAX_COMPUTERNAME_LENGTH usize = 15
 	MAX_MODULE_NAME32 usize = 255
 	MAX_PATH usize = 260
+	MAXIMUM_REPARSE_DATA_BUFFER_SIZE usize = 16 * 1024
 	SECURITY_SQOS_PRESENT u32 = 0x0010_0000
+	SYMLINK_FLAG_RELATIVE u32 = 0x0000_0001
+	TIME_ZONE_ID_INVALID u32 = 0xffff_ffff
 }
 
 /// [`IS_INTRESOURCE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-is_intresource)
@@ -91,6 +95,33 @@ pub(crate) fn minus1_as_error(dword: u32) -> SysResult<u32> {
 	}
 }
 
+/// Copies `N` bytes from the buffer, starting at the given offset, to be
+/// passed to a `from_le_bytes` function. If the buffer is too small, yields
+/// `Err(ERROR::INVALID_DATA)`.
+pub(crate) fn le_bytes<const N: usize>(buf: &[u8], offset: usize) -> SysResult<[u8; N]> {
+	buf.get(offset..offset + N)
+		.and_then(|chunk| chunk.try_into().ok())
+		.ok_or(co::ERROR::INVALID_DATA)
+}
+
+/// Calls an NLS function twice: first with a null buffer to retrieve the
+/// required size in chars, including the terminating null, then with the
+/// allocated buffer. A zero return yields `Err(GetLastError())`.
+pub(crate) fn nls_str_buf<F>(func: F) -> SysResult<String>
+where
+	F: Fn(*mut u16, i32) -> i32,
+{
+	let num_chars = match func(std::ptr::null_mut(), 0) {
+		0 => return Err(GetLastError()),
+		n => n,
+	};
+	let mut buf = WString::new_alloc_buf(num_chars as _);
+	match func(unsafe { buf.as_mut_ptr() }, num_chars) {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
 /// Converts a string to an ISO-8859-1 null-terminated byte array.
 #[must_use]
 pub(crate) fn str_to_iso88591(s: &str) -> Vec<u8> {
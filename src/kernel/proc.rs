@@ -1,3 +1,4 @@
+use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi_types::*;
 
@@ -39,3 +40,17 @@ where
 	let func = unsafe { &mut *(lparam as *mut F) };
 	func(unsafe { RtStr::from_ptr(resource_type) }) as _
 }
+
+pub(in crate::kernel) extern "system" fn enum_system_locales_ex<F>(
+	locale_name: *mut u16,
+	flags: u32,
+	lparam: isize,
+) -> BOOL
+where
+	F: FnMut(String, co::LOCALE) -> bool,
+{
+	let func = unsafe { &mut *(lparam as *mut F) };
+	func(unsafe { WString::from_wchars_nullt(locale_name) }.to_string(), unsafe {
+		co::LOCALE::from_raw(flags)
+	}) as _
+}
@@ -0,0 +1,182 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+
+/// [`CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-context)
+/// struct for x64.
+///
+/// Set `ContextFlags` to choose which registers are retrieved by
+/// [`HTHREAD::GetThreadContext`](crate::prelude::kernel_Hthread::GetThreadContext).
+#[cfg(target_arch = "x86_64")]
+#[repr(C, align(16))]
+#[derive(Clone)]
+pub struct CONTEXT {
+	pub P1Home: u64,
+	pub P2Home: u64,
+	pub P3Home: u64,
+	pub P4Home: u64,
+	pub P5Home: u64,
+	pub P6Home: u64,
+	pub ContextFlags: co::CONTEXT,
+	pub MxCsr: u32,
+	pub SegCs: u16,
+	pub SegDs: u16,
+	pub SegEs: u16,
+	pub SegFs: u16,
+	pub SegGs: u16,
+	pub SegSs: u16,
+	pub EFlags: u32,
+	pub Dr0: u64,
+	pub Dr1: u64,
+	pub Dr2: u64,
+	pub Dr3: u64,
+	pub Dr6: u64,
+	pub Dr7: u64,
+	pub Rax: u64,
+	pub Rcx: u64,
+	pub Rdx: u64,
+	pub Rbx: u64,
+	pub Rsp: u64,
+	pub Rbp: u64,
+	pub Rsi: u64,
+	pub Rdi: u64,
+	pub R8: u64,
+	pub R9: u64,
+	pub R10: u64,
+	pub R11: u64,
+	pub R12: u64,
+	pub R13: u64,
+	pub R14: u64,
+	pub R15: u64,
+	pub Rip: u64,
+	pub FltSave: XMM_SAVE_AREA32,
+	pub VectorRegister: [M128A; 26],
+	pub VectorControl: u64,
+	pub DebugControl: u64,
+	pub LastBranchToRip: u64,
+	pub LastBranchFromRip: u64,
+	pub LastExceptionToRip: u64,
+	pub LastExceptionFromRip: u64,
+}
+
+/// [`CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-context)
+/// struct for x86.
+///
+/// Set `ContextFlags` to choose which registers are retrieved by
+/// [`HTHREAD::GetThreadContext`](crate::prelude::kernel_Hthread::GetThreadContext).
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Clone)]
+pub struct CONTEXT {
+	pub ContextFlags: co::CONTEXT,
+	pub Dr0: u32,
+	pub Dr1: u32,
+	pub Dr2: u32,
+	pub Dr3: u32,
+	pub Dr6: u32,
+	pub Dr7: u32,
+	pub FloatSave: FLOATING_SAVE_AREA,
+	pub SegGs: u32,
+	pub SegFs: u32,
+	pub SegEs: u32,
+	pub SegDs: u32,
+	pub Edi: u32,
+	pub Esi: u32,
+	pub Ebx: u32,
+	pub Edx: u32,
+	pub Ecx: u32,
+	pub Eax: u32,
+	pub Ebp: u32,
+	pub Eip: u32,
+	pub SegCs: u32,
+	pub EFlags: u32,
+	pub Esp: u32,
+	pub SegSs: u32,
+	pub ExtendedRegisters: [u8; 512],
+}
+
+/// [`CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-arm64_nt_context)
+/// struct for ARM64.
+///
+/// Set `ContextFlags` to choose which registers are retrieved by
+/// [`HTHREAD::GetThreadContext`](crate::prelude::kernel_Hthread::GetThreadContext).
+///
+/// The `X` array holds the registers `X0` to `X28`, followed by `Fp` and `Lr`.
+#[cfg(target_arch = "aarch64")]
+#[repr(C, align(16))]
+#[derive(Clone)]
+pub struct CONTEXT {
+	pub ContextFlags: co::CONTEXT,
+	pub Cpsr: u32,
+	pub X: [u64; 31],
+	pub Sp: u64,
+	pub Pc: u64,
+	pub V: [M128A; 32],
+	pub Fpcr: u32,
+	pub Fpsr: u32,
+	pub Bcr: [u32; 8],
+	pub Bvr: [u64; 8],
+	pub Wcr: [u32; 2],
+	pub Wvr: [u64; 2],
+}
+
+impl_default!(CONTEXT);
+
+/// [`FLOATING_SAVE_AREA`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-context)
+/// struct.
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Clone)]
+pub struct FLOATING_SAVE_AREA {
+	pub ControlWord: u32,
+	pub StatusWord: u32,
+	pub TagWord: u32,
+	pub ErrorOffset: u32,
+	pub ErrorSelector: u32,
+	pub DataOffset: u32,
+	pub DataSelector: u32,
+	pub RegisterArea: [u8; 80],
+	pub Spare0: u32,
+}
+
+#[cfg(target_arch = "x86")]
+impl_default!(FLOATING_SAVE_AREA);
+
+/// [`M128A`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-context)
+/// struct, a 128-bit register.
+///
+/// On ARM64, this is the `ARM64_NT_NEON128` struct.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[repr(C, align(16))]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct M128A {
+	pub Low: u64,
+	pub High: i64,
+}
+
+/// [`XMM_SAVE_AREA32`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-context)
+/// struct.
+#[cfg(target_arch = "x86_64")]
+#[repr(C, align(16))]
+#[derive(Clone)]
+pub struct XMM_SAVE_AREA32 {
+	pub ControlWord: u16,
+	pub StatusWord: u16,
+	pub TagWord: u8,
+	Reserved1: u8,
+	pub ErrorOpcode: u16,
+	pub ErrorOffset: u32,
+	pub ErrorSelector: u16,
+	Reserved2: u16,
+	pub DataOffset: u32,
+	pub DataSelector: u16,
+	Reserved3: u16,
+	pub MxCsr: u32,
+	pub MxCsr_Mask: u32,
+	pub FloatRegisters: [M128A; 8],
+	pub XmmRegisters: [M128A; 16],
+	Reserved4: [u8; 96],
+}
+
+#[cfg(target_arch = "x86_64")]
+impl_default!(XMM_SAVE_AREA32);
@@ -0,0 +1,106 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+
+/// [`DEBUG_EVENT`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-debug_event)
+/// struct.
+///
+/// Not public, the events are exposed as [`DebugEvent`](crate::DebugEvent).
+#[repr(C)]
+pub(in crate::kernel) struct DEBUG_EVENT {
+	pub(in crate::kernel) dwDebugEventCode: u32,
+	pub(in crate::kernel) dwProcessId: u32,
+	pub(in crate::kernel) dwThreadId: u32,
+	pub(in crate::kernel) u: DEBUG_EVENT_union0,
+}
+
+#[repr(C)]
+pub(in crate::kernel) union DEBUG_EVENT_union0 {
+	pub(in crate::kernel) Exception: std::mem::ManuallyDrop<EXCEPTION_DEBUG_INFO>,
+	pub(in crate::kernel) CreateThread: CREATE_THREAD_DEBUG_INFO,
+	pub(in crate::kernel) CreateProcessInfo: CREATE_PROCESS_DEBUG_INFO,
+	pub(in crate::kernel) ExitThread: EXIT_THREAD_DEBUG_INFO,
+	pub(in crate::kernel) ExitProcess: EXIT_PROCESS_DEBUG_INFO,
+	pub(in crate::kernel) LoadDll: LOAD_DLL_DEBUG_INFO,
+	pub(in crate::kernel) UnloadDll: UNLOAD_DLL_DEBUG_INFO,
+	pub(in crate::kernel) DebugString: OUTPUT_DEBUG_STRING_INFO,
+	pub(in crate::kernel) RipInfo: RIP_INFO,
+}
+
+impl_default!(DEBUG_EVENT);
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct CREATE_PROCESS_DEBUG_INFO {
+	pub(in crate::kernel) hFile: HANDLE,
+	pub(in crate::kernel) hProcess: HANDLE,
+	pub(in crate::kernel) hThread: HANDLE,
+	pub(in crate::kernel) lpBaseOfImage: PVOID,
+	pub(in crate::kernel) dwDebugInfoFileOffset: u32,
+	pub(in crate::kernel) nDebugInfoSize: u32,
+	pub(in crate::kernel) lpThreadLocalBase: PVOID,
+	pub(in crate::kernel) lpStartAddress: PVOID,
+	pub(in crate::kernel) lpImageName: PVOID,
+	pub(in crate::kernel) fUnicode: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct CREATE_THREAD_DEBUG_INFO {
+	pub(in crate::kernel) hThread: HANDLE,
+	pub(in crate::kernel) lpThreadLocalBase: PVOID,
+	pub(in crate::kernel) lpStartAddress: PVOID,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub(in crate::kernel) struct EXCEPTION_DEBUG_INFO {
+	pub(in crate::kernel) ExceptionRecord: EXCEPTION_RECORD,
+	pub(in crate::kernel) dwFirstChance: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct EXIT_PROCESS_DEBUG_INFO {
+	pub(in crate::kernel) dwExitCode: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct EXIT_THREAD_DEBUG_INFO {
+	pub(in crate::kernel) dwExitCode: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct LOAD_DLL_DEBUG_INFO {
+	pub(in crate::kernel) hFile: HANDLE,
+	pub(in crate::kernel) lpBaseOfDll: PVOID,
+	pub(in crate::kernel) dwDebugInfoFileOffset: u32,
+	pub(in crate::kernel) nDebugInfoSize: u32,
+	pub(in crate::kernel) lpImageName: PVOID,
+	pub(in crate::kernel) fUnicode: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct OUTPUT_DEBUG_STRING_INFO {
+	pub(in crate::kernel) lpDebugStringData: PVOID,
+	pub(in crate::kernel) fUnicode: u16,
+	pub(in crate::kernel) nDebugStringLength: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct RIP_INFO {
+	pub(in crate::kernel) dwError: co::ERROR,
+	pub(in crate::kernel) dwType: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(in crate::kernel) struct UNLOAD_DLL_DEBUG_INFO {
+	pub(in crate::kernel) lpBaseOfDll: PVOID,
+}
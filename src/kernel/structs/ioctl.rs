@@ -0,0 +1,306 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+
+/// [`DISK_GEOMETRY`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ns-winioctl-disk_geometry)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct DISK_GEOMETRY {
+	pub Cylinders: i64,
+	pub MediaType: co::MEDIA_TYPE,
+	pub TracksPerCylinder: u32,
+	pub SectorsPerTrack: u32,
+	pub BytesPerSector: u32,
+}
+
+impl DISK_GEOMETRY {
+	/// Decodes the struct from the raw bytes returned by
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl).
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			Cylinders: i64::from_le_bytes(le_bytes(buf, 0)?),
+			MediaType: unsafe { co::MEDIA_TYPE::from_raw(u32::from_le_bytes(le_bytes(buf, 8)?)) },
+			TracksPerCylinder: u32::from_le_bytes(le_bytes(buf, 12)?),
+			SectorsPerTrack: u32::from_le_bytes(le_bytes(buf, 16)?),
+			BytesPerSector: u32::from_le_bytes(le_bytes(buf, 20)?),
+		})
+	}
+}
+
+/// [`DISK_GEOMETRY_EX`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ns-winioctl-disk_geometry_ex)
+/// struct.
+///
+/// The variable-length `Data` member, with the partition and detection
+/// information, is not included.
+#[repr(C)]
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct DISK_GEOMETRY_EX {
+	pub Geometry: DISK_GEOMETRY,
+	pub DiskSize: i64,
+}
+
+impl DISK_GEOMETRY_EX {
+	/// Decodes the struct from the raw bytes returned by
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl).
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			Geometry: DISK_GEOMETRY::from_bytes(buf)?,
+			DiskSize: i64::from_le_bytes(le_bytes(buf, 24)?),
+		})
+	}
+}
+
+/// [`STORAGE_DESCRIPTOR_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ns-winioctl-storage_descriptor_header)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct STORAGE_DESCRIPTOR_HEADER {
+	pub Version: u32,
+	pub Size: u32,
+}
+
+impl STORAGE_DESCRIPTOR_HEADER {
+	/// Decodes the struct from the raw bytes returned by
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl).
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			Version: u32::from_le_bytes(le_bytes(buf, 0)?),
+			Size: u32::from_le_bytes(le_bytes(buf, 4)?),
+		})
+	}
+}
+
+/// [`STORAGE_DEVICE_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ns-winioctl-storage_device_descriptor)
+/// struct.
+///
+/// The native struct has variable length, with the strings stored after it
+/// and referenced by offsets; this is the decoded form, where the strings are
+/// owned. The raw device properties are not included.
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct STORAGE_DEVICE_DESCRIPTOR {
+	pub Version: u32,
+	pub Size: u32,
+	pub DeviceType: u8,
+	pub DeviceTypeModifier: u8,
+	pub RemovableMedia: bool,
+	pub CommandQueueing: bool,
+	pub VendorId: Option<String>,
+	pub ProductId: Option<String>,
+	pub ProductRevision: Option<String>,
+	pub SerialNumber: Option<String>,
+	pub BusType: co::STORAGE_BUS_TYPE,
+}
+
+impl STORAGE_DEVICE_DESCRIPTOR {
+	/// Decodes the struct from the raw bytes returned by
+	/// [`HFILE::ioctl_storage_query_property`](crate::prelude::kernel_Hfile::ioctl_storage_query_property)
+	/// with [`co::STORAGE_PROPERTY_ID::Device`](crate::co::STORAGE_PROPERTY_ID::Device).
+	///
+	/// A string whose offset points past the end of the buffer, which happens
+	/// when the buffer was too small for the whole descriptor, is `None`.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		let ansi_at = |offset_pos: usize| -> SysResult<Option<String>> {
+			let offset = u32::from_le_bytes(le_bytes(buf, offset_pos)?) as usize;
+			let tail = match buf.get(offset..) {
+				Some(tail) if offset != 0 && !tail.is_empty() => tail,
+				_ => return Ok(None), // the string is not present, or was truncated away
+			};
+			let len = tail.iter().position(|ch| *ch == 0).unwrap_or(tail.len());
+			Ok(Some(
+				tail[..len]
+					.iter()
+					.map(|ch| *ch as char)
+					.collect::<String>()
+					.trim()
+					.to_owned(),
+			))
+		};
+
+		Ok(Self {
+			Version: u32::from_le_bytes(le_bytes(buf, 0)?),
+			Size: u32::from_le_bytes(le_bytes(buf, 4)?),
+			DeviceType: le_bytes::<1>(buf, 8)?[0],
+			DeviceTypeModifier: le_bytes::<1>(buf, 9)?[0],
+			RemovableMedia: le_bytes::<1>(buf, 10)?[0] != 0,
+			CommandQueueing: le_bytes::<1>(buf, 11)?[0] != 0,
+			VendorId: ansi_at(12)?,
+			ProductId: ansi_at(16)?,
+			ProductRevision: ansi_at(20)?,
+			SerialNumber: ansi_at(24)?,
+			BusType: unsafe {
+				co::STORAGE_BUS_TYPE::from_raw(u32::from_le_bytes(le_bytes(buf, 28)?))
+			},
+		})
+	}
+}
+
+/// [`STORAGE_PROPERTY_QUERY`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ns-winioctl-storage_property_query)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct STORAGE_PROPERTY_QUERY {
+	pub PropertyId: co::STORAGE_PROPERTY_ID,
+	pub QueryType: co::STORAGE_QUERY_TYPE,
+	pub AdditionalParameters: [u8; 1],
+}
+
+impl STORAGE_PROPERTY_QUERY {
+	/// Decodes the struct from its raw bytes.
+	pub fn from_bytes(buf: &[u8]) -> SysResult<Self> {
+		Ok(Self {
+			PropertyId: unsafe {
+				co::STORAGE_PROPERTY_ID::from_raw(u32::from_le_bytes(le_bytes(buf, 0)?))
+			},
+			QueryType: unsafe {
+				co::STORAGE_QUERY_TYPE::from_raw(u32::from_le_bytes(le_bytes(buf, 4)?))
+			},
+			AdditionalParameters: le_bytes(buf, 8)?,
+		})
+	}
+
+	/// Encodes the struct into the raw bytes to be passed to
+	/// [`HFILE::DeviceIoControl`](crate::prelude::kernel_Hfile::DeviceIoControl).
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut buf = Vec::with_capacity(std::mem::size_of::<Self>());
+		buf.extend_from_slice(&self.PropertyId.raw().to_le_bytes());
+		buf.extend_from_slice(&self.QueryType.raw().to_le_bytes());
+		buf.extend_from_slice(&self.AdditionalParameters);
+		buf.resize(std::mem::size_of::<Self>(), 0); // struct padding
+		buf
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn disk_geometry_bytes() -> Vec<u8> {
+		let mut buf = Vec::new();
+		buf.extend_from_slice(&30_401i64.to_le_bytes());
+		buf.extend_from_slice(&12u32.to_le_bytes()); // FixedMedia
+		buf.extend_from_slice(&255u32.to_le_bytes());
+		buf.extend_from_slice(&63u32.to_le_bytes());
+		buf.extend_from_slice(&512u32.to_le_bytes());
+		buf
+	}
+
+	/// Fixed part of the descriptor, followed by the strings.
+	fn device_descriptor_bytes(offsets: [u32; 4]) -> Vec<u8> {
+		let mut buf = Vec::new();
+		buf.extend_from_slice(&1u32.to_le_bytes()); // Version
+		buf.extend_from_slice(&80u32.to_le_bytes()); // Size
+		buf.extend_from_slice(&[0x00, 0x00, 1, 1]); // type, modifier, removable, queueing
+		for offset in offsets {
+			buf.extend_from_slice(&offset.to_le_bytes());
+		}
+		buf.extend_from_slice(&0x11u32.to_le_bytes()); // BusType: Nvme
+		buf.extend_from_slice(&0u32.to_le_bytes()); // RawPropertiesLength
+		buf.extend_from_slice(&[0; 4]); // RawDeviceProperties, padded
+		buf.extend_from_slice(b"Samsung SSD 970   \0"); // offset 40
+		buf.extend_from_slice(b"2B2QEXM7\0"); // offset 59
+		buf.extend_from_slice(b" S4EWNX0R \0"); // offset 68
+		buf
+	}
+
+	#[test]
+	fn disk_geometry() {
+		let geo = DISK_GEOMETRY::from_bytes(&disk_geometry_bytes()).unwrap();
+		assert_eq!(geo.Cylinders, 30_401);
+		assert_eq!(geo.MediaType, co::MEDIA_TYPE::FixedMedia);
+		assert_eq!(geo.TracksPerCylinder, 255);
+		assert_eq!(geo.SectorsPerTrack, 63);
+		assert_eq!(geo.BytesPerSector, 512);
+
+		let buf = disk_geometry_bytes();
+		assert_eq!(
+			DISK_GEOMETRY::from_bytes(&buf[..buf.len() - 1]).err(),
+			Some(co::ERROR::INVALID_DATA),
+		);
+		assert_eq!(DISK_GEOMETRY::from_bytes(&[]).err(), Some(co::ERROR::INVALID_DATA));
+	}
+
+	#[test]
+	fn disk_geometry_ex() {
+		let mut buf = disk_geometry_bytes();
+		buf.extend_from_slice(&250_059_350_016i64.to_le_bytes());
+		buf.extend_from_slice(&[0xaa; 16]); // variable-length data, ignored
+
+		let geo = DISK_GEOMETRY_EX::from_bytes(&buf).unwrap();
+		assert!(geo.Geometry == DISK_GEOMETRY::from_bytes(&buf).unwrap());
+		assert_eq!(geo.DiskSize, 250_059_350_016);
+
+		assert_eq!(DISK_GEOMETRY_EX::from_bytes(&buf[..31]).err(), Some(co::ERROR::INVALID_DATA),);
+	}
+
+	#[test]
+	fn storage_descriptor_header() {
+		let buf = [1, 0, 0, 0, 0x00, 0x04, 0, 0];
+		let hdr = STORAGE_DESCRIPTOR_HEADER::from_bytes(&buf).unwrap();
+		assert_eq!((hdr.Version, hdr.Size), (1, 1024));
+
+		assert_eq!(
+			STORAGE_DESCRIPTOR_HEADER::from_bytes(&buf[..7]).err(),
+			Some(co::ERROR::INVALID_DATA),
+		);
+	}
+
+	#[test]
+	fn storage_device_descriptor() {
+		let buf = device_descriptor_bytes([0, 40, 59, 68]);
+		let desc = STORAGE_DEVICE_DESCRIPTOR::from_bytes(&buf).unwrap();
+		assert_eq!((desc.Version, desc.Size), (1, 80));
+		assert_eq!((desc.DeviceType, desc.DeviceTypeModifier), (0, 0));
+		assert!(desc.RemovableMedia);
+		assert!(desc.CommandQueueing);
+		assert_eq!(desc.VendorId, None);
+		assert_eq!(desc.ProductId.as_deref(), Some("Samsung SSD 970"));
+		assert_eq!(desc.ProductRevision.as_deref(), Some("2B2QEXM7"));
+		assert_eq!(desc.SerialNumber.as_deref(), Some("S4EWNX0R"));
+		assert_eq!(desc.BusType, co::STORAGE_BUS_TYPE::Nvme);
+	}
+
+	#[test]
+	fn storage_device_descriptor_truncated() {
+		// Strings cut off by a small buffer are not present.
+		let buf = device_descriptor_bytes([0, 40, 59, 4096]);
+		let desc = STORAGE_DEVICE_DESCRIPTOR::from_bytes(&buf).unwrap();
+		assert_eq!(desc.ProductId.as_deref(), Some("Samsung SSD 970"));
+		assert_eq!(desc.SerialNumber, None);
+
+		// The last string has no terminating null.
+		let buf = device_descriptor_bytes([0, 40, 59, 68]);
+		let desc = STORAGE_DEVICE_DESCRIPTOR::from_bytes(&buf[..75]).unwrap();
+		assert_eq!(desc.SerialNumber.as_deref(), Some("S4EWNX"));
+
+		// Only the header fits.
+		let desc = STORAGE_DEVICE_DESCRIPTOR::from_bytes(&buf[..40]).unwrap();
+		assert_eq!((desc.ProductId, desc.ProductRevision), (None, None));
+
+		// The fixed part doesn't fit.
+		assert_eq!(
+			STORAGE_DEVICE_DESCRIPTOR::from_bytes(&buf[..31]).err(),
+			Some(co::ERROR::INVALID_DATA),
+		);
+	}
+
+	#[test]
+	fn storage_property_query() {
+		let query = STORAGE_PROPERTY_QUERY {
+			PropertyId: co::STORAGE_PROPERTY_ID::Adapter,
+			QueryType: co::STORAGE_QUERY_TYPE::Exists,
+			AdditionalParameters: [7],
+		};
+		let buf = query.to_bytes();
+		assert_eq!(buf, [1, 0, 0, 0, 1, 0, 0, 0, 7, 0, 0, 0]);
+		assert!(STORAGE_PROPERTY_QUERY::from_bytes(&buf).unwrap() == query);
+
+		assert_eq!(
+			STORAGE_PROPERTY_QUERY::from_bytes(&buf[..8]).err(),
+			Some(co::ERROR::INVALID_DATA),
+		);
+	}
+}
@@ -1,5 +1,16 @@
+mod context;
+mod debug;
 mod guid;
+mod ioctl;
 mod structs_other;
+mod time;
 
+pub use context::*;
+pub(in crate::kernel) use debug::DEBUG_EVENT;
 pub use guid::GUID;
+pub use ioctl::{
+	DISK_GEOMETRY, DISK_GEOMETRY_EX, STORAGE_DESCRIPTOR_HEADER, STORAGE_DEVICE_DESCRIPTOR,
+	STORAGE_PROPERTY_QUERY,
+};
 pub use structs_other::*;
+pub use time::{DYNAMIC_TIME_ZONE_INFORMATION, FILETIME, SYSTEMTIME, TIME_ZONE_INFORMATION};
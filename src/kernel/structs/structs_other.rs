@@ -187,6 +187,32 @@ pub struct CONSOLE_READCONSOLE_CONTROL {
 	pub dwControlKeyState: u32,
 }
 
+/// [`CURRENCYFMT`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/ns-winnls-currencyfmtw)
+/// struct.
+#[repr(C)]
+pub struct CURRENCYFMT<'a, 'b, 'c> {
+	pub NumDigits: u32,
+	pub LeadingZero: u32,
+	pub Grouping: u32,
+	lpDecimalSep: *mut u16,
+	lpThousandSep: *mut u16,
+	pub NegativeOrder: u32,
+	pub PositiveOrder: u32,
+	lpCurrencySymbol: *mut u16,
+
+	_lpDecimalSep: PhantomData<&'a mut u16>,
+	_lpThousandSep: PhantomData<&'b mut u16>,
+	_lpCurrencySymbol: PhantomData<&'c mut u16>,
+}
+
+impl_default!(CURRENCYFMT, 'a, 'b, 'c);
+
+impl<'a, 'b, 'c> CURRENCYFMT<'a, 'b, 'c> {
+	pub_fn_string_ptr_get_set!('a, lpDecimalSep, set_lpDecimalSep);
+	pub_fn_string_ptr_get_set!('b, lpThousandSep, set_lpThousandSep);
+	pub_fn_string_ptr_get_set!('c, lpCurrencySymbol, set_lpCurrencySymbol);
+}
+
 /// [`DEV_BROADCAST_HDR`](https://learn.microsoft.com/en-us/windows/win32/api/dbt/ns-dbt-dev_broadcast_hdr)
 /// struct.
 #[repr(C)]
@@ -217,65 +243,168 @@ pub struct DISK_SPACE_INFORMATION {
 	pub BytesPerSector: u32,
 }
 
-/// [`FILETIME`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime)
+/// [`EXCEPTION_POINTERS`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-exception_pointers)
+/// struct.
+#[repr(C)]
+pub struct EXCEPTION_POINTERS {
+	pub ExceptionRecord: *mut EXCEPTION_RECORD,
+	pub ContextRecord: *mut CONTEXT,
+}
+
+impl_default!(EXCEPTION_POINTERS);
+
+/// [`EXCEPTION_RECORD`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-exception_record)
+/// struct.
+#[repr(C)]
+#[derive(Clone)]
+pub struct EXCEPTION_RECORD {
+	pub ExceptionCode: co::EXCEPTION,
+	pub ExceptionFlags: u32,
+	pub ExceptionRecord: *mut EXCEPTION_RECORD,
+	pub ExceptionAddress: *mut std::ffi::c_void,
+	pub NumberParameters: u32,
+	pub ExceptionInformation: [usize; 15],
+}
+
+impl_default!(EXCEPTION_RECORD);
+
+/// [`FILE_ATTRIBUTE_TAG_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-file_attribute_tag_info)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct FILE_ATTRIBUTE_TAG_INFO {
+	pub FileAttributes: co::FILE_ATTRIBUTE,
+	pub ReparseTag: co::IO_REPARSE_TAG,
+}
+
+/// [`FILE_BASIC_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-file_basic_info)
 /// struct.
 ///
-/// Can be converted to [`SYSTEMTIME`](crate::SYSTEMTIME) with
-/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime) function.
+/// The times are expressed in 100-nanosecond intervals since January 1, 1601,
+/// the same unit of [`FILETIME`](crate::FILETIME).
 #[repr(C)]
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct FILETIME {
-	pub dwLowDateTime: u32,
-	pub dwHighDateTime: u32,
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct FILE_BASIC_INFO {
+	pub CreationTime: i64,
+	pub LastAccessTime: i64,
+	pub LastWriteTime: i64,
+	pub ChangeTime: i64,
+	pub FileAttributes: co::FILE_ATTRIBUTE,
 }
 
-impl From<u64> for FILETIME {
-	fn from(v: u64) -> Self {
-		Self {
-			dwLowDateTime: LODWORD(v),
-			dwHighDateTime: HIDWORD(v),
-		}
-	}
+/// [`FILE_ID_128`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-file_id_128)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FILE_ID_128 {
+	pub Identifier: [u8; 16],
 }
 
-impl From<FILETIME> for u64 {
-	fn from(v: FILETIME) -> Self {
-		MAKEQWORD(v.dwLowDateTime, v.dwHighDateTime)
-	}
+/// [`FILE_ID_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-file_id_descriptor)
+/// struct.
+///
+/// The union field is selected by the `Type` field, and both are set together
+/// by the setter methods.
+#[repr(C)]
+pub struct FILE_ID_DESCRIPTOR {
+	dwSize: u32,
+	Type: co::FILE_ID_TYPE,
+	union0: FILE_ID_DESCRIPTOR_union0,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union FILE_ID_DESCRIPTOR_union0 {
+	FileId: i64,
+	ObjectId: GUID,
+	ExtendedFileId: FILE_ID_128,
 }
 
-impl FILETIME {
-	/// Returns a new `FILETIME` with the milliseconds difference.
+impl_default!(FILE_ID_DESCRIPTOR, dwSize);
+
+impl FILE_ID_DESCRIPTOR {
+	/// Returns the `Type` field.
 	#[must_use]
-	pub fn add_ms(self, ms: i64) -> Self {
-		Self::from((u64::from(self) as i64 + (ms * 10_000)) as u64)
+	pub const fn Type(&self) -> co::FILE_ID_TYPE {
+		self.Type
 	}
 
-	/// Returns a new `FILETIME` with the seconds difference.
+	/// Retrieves the `FileId` union field.
 	#[must_use]
-	pub fn add_secs(self, secs: i64) -> Self {
-		self.add_ms(secs * 1000)
+	pub fn FileId(&self) -> Option<i64> {
+		if self.Type == co::FILE_ID_TYPE::FileId {
+			Some(unsafe { self.union0.FileId })
+		} else {
+			None
+		}
 	}
 
-	/// Returns a new `FILETIME` with the minutes difference.
+	/// Retrieves the `ObjectId` union field.
 	#[must_use]
-	pub fn add_mins(self, mins: i64) -> Self {
-		self.add_secs(mins * 60)
+	pub fn ObjectId(&self) -> Option<GUID> {
+		if self.Type == co::FILE_ID_TYPE::ObjectId {
+			Some(unsafe { self.union0.ObjectId })
+		} else {
+			None
+		}
 	}
 
-	/// Returns a new `FILETIME` with the hours difference.
+	/// Retrieves the `ExtendedFileId` union field.
 	#[must_use]
-	pub fn add_hours(self, hours: i64) -> Self {
-		self.add_mins(hours * 60)
+	pub fn ExtendedFileId(&self) -> Option<FILE_ID_128> {
+		if self.Type == co::FILE_ID_TYPE::ExtendedFileId {
+			Some(unsafe { self.union0.ExtendedFileId })
+		} else {
+			None
+		}
 	}
 
-	/// Returns a new `FILETIME` with the days difference.
-	#[must_use]
-	pub fn add_days(self, days: i64) -> Self {
-		self.add_hours(days * 24)
+	/// Sets the `FileId` union field, and the `Type` field accordingly.
+	pub fn set_FileId(&mut self, file_id: i64) {
+		self.Type = co::FILE_ID_TYPE::FileId;
+		self.union0.FileId = file_id;
+	}
+
+	/// Sets the `ObjectId` union field, and the `Type` field accordingly.
+	pub fn set_ObjectId(&mut self, object_id: GUID) {
+		self.Type = co::FILE_ID_TYPE::ObjectId;
+		self.union0.ObjectId = object_id;
+	}
+
+	/// Sets the `ExtendedFileId` union field, and the `Type` field
+	/// accordingly.
+	pub fn set_ExtendedFileId(&mut self, extended_file_id: FILE_ID_128) {
+		self.Type = co::FILE_ID_TYPE::ExtendedFileId;
+		self.union0.ExtendedFileId = extended_file_id;
 	}
 }
 
+/// [`FILE_ID_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-file_id_info)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FILE_ID_INFO {
+	pub VolumeSerialNumber: u64,
+	pub FileId: FILE_ID_128,
+}
+
+/// [`FILE_STANDARD_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-file_standard_info)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct FILE_STANDARD_INFO {
+	pub AllocationSize: i64,
+	pub EndOfFile: i64,
+	pub NumberOfLinks: u32,
+	DeletePending: u8,
+	Directory: u8,
+}
+
+impl FILE_STANDARD_INFO {
+	pub_fn_bool_get_set!(DeletePending, set_DeletePending);
+	pub_fn_bool_get_set!(Directory, set_Directory);
+}
+
 /// [`HEAPLIST32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-heaplist32)
 /// struct.
 #[repr(C)]
@@ -470,6 +599,28 @@ pub struct MEMORYSTATUSEX {
 
 impl_default!(MEMORYSTATUSEX, dwLength);
 
+/// [`NUMBERFMT`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/ns-winnls-numberfmtw)
+/// struct.
+#[repr(C)]
+pub struct NUMBERFMT<'a, 'b> {
+	pub NumDigits: u32,
+	pub LeadingZero: u32,
+	pub Grouping: u32,
+	lpDecimalSep: *mut u16,
+	lpThousandSep: *mut u16,
+	pub NegativeOrder: u32,
+
+	_lpDecimalSep: PhantomData<&'a mut u16>,
+	_lpThousandSep: PhantomData<&'b mut u16>,
+}
+
+impl_default!(NUMBERFMT, 'a, 'b);
+
+impl<'a, 'b> NUMBERFMT<'a, 'b> {
+	pub_fn_string_ptr_get_set!('a, lpDecimalSep, set_lpDecimalSep);
+	pub_fn_string_ptr_get_set!('b, lpThousandSep, set_lpThousandSep);
+}
+
 /// [`OSVERSIONINFOEX`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-osversioninfoexw)
 /// struct.
 #[repr(C)]
@@ -797,91 +948,6 @@ pub struct SYSTEM_INFO {
 
 impl_default!(SYSTEM_INFO);
 
-/// [`SYSTEMTIME`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-systemtime)
-/// struct.
-///
-/// Can be converted to [`FILETIME`](crate::FILETIME) with
-/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime) function.
-#[repr(C)]
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SYSTEMTIME {
-	/// The year. The valid values for this member are 1,601 through 30,827.
-	pub wYear: u16,
-	/// The month. January = 1.
-	pub wMonth: u16,
-	/// The day of the week. Sunday = 0.
-	pub wDayOfWeek: u16,
-	/// The day of the month. The valid values for this member are 1 through 31.
-	pub wDay: u16,
-	/// The hour. The valid values for this member are 0 through 23.
-	pub wHour: u16,
-	/// The minute. The valid values for this member are 0 through 59.
-	pub wMinute: u16,
-	/// The second. The valid values for this member are 0 through 59.
-	pub wSecond: u16,
-	/// The millisecond. The valid values for this member are 0 through 999.
-	pub wMilliseconds: u16,
-}
-
-impl std::fmt::Display for SYSTEMTIME {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}",
-			self.wYear,
-			self.wMonth,
-			self.wDay,
-			self.wHour,
-			self.wMinute,
-			self.wSecond,
-			self.wMilliseconds
-		)
-	}
-}
-
-impl SYSTEMTIME {
-	/// Returns a new `SYSTEMTIME` with the milliseconds difference.
-	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
-	#[must_use]
-	pub fn add_ms(self, ms: i64) -> SysResult<Self> {
-		let ft = SystemTimeToFileTime(&self)?;
-		FileTimeToSystemTime(&ft.add_ms(ms))
-	}
-
-	/// Returns a new `SYSTEMTIME` with the seconds difference.
-	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
-	#[must_use]
-	pub fn add_secs(self, secs: i64) -> SysResult<Self> {
-		self.add_ms(secs * 1000)
-	}
-
-	/// Returns a new `SYSTEMTIME` with the minutes difference.
-	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
-	#[must_use]
-	pub fn add_mins(self, mins: i64) -> SysResult<Self> {
-		self.add_secs(mins * 60)
-	}
-
-	/// Returns a new `SYSTEMTIME` with the hours difference.
-	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
-	#[must_use]
-	pub fn add_hours(self, hours: i64) -> SysResult<Self> {
-		self.add_mins(hours * 60)
-	}
-
-	/// Returns a new `SYSTEMTIME` with the days difference.
-	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
-	#[must_use]
-	pub fn add_days(self, days: i64) -> SysResult<Self> {
-		self.add_hours(days * 24)
-	}
-}
-
 /// [`THREADENTRY32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-threadentry32)
 /// struct.
 #[repr(C)]
@@ -897,25 +963,6 @@ pub struct THREADENTRY32 {
 
 impl_default!(THREADENTRY32, dwSize);
 
-/// [`TIME_ZONE_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/ns-timezoneapi-time_zone_information)
-/// struct.
-#[repr(C)]
-#[derive(Default)]
-pub struct TIME_ZONE_INFORMATION {
-	pub bias: i32,
-	standardName: [u16; 32],
-	pub standardDate: SYSTEMTIME,
-	pub standardBias: i32,
-	daylightName: [u16; 32],
-	pub daylightDate: SYSTEMTIME,
-	pub daylightBias: i32,
-}
-
-impl TIME_ZONE_INFORMATION {
-	pub_fn_string_arr_get_set!(standardName, set_standardName);
-	pub_fn_string_arr_get_set!(daylightName, set_daylightName);
-}
-
 /// [`WIN32_FILE_ATTRIBUTE_DATA`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/ns-fileapi-win32_file_attribute_data)
 /// struct.
 #[repr(C)]
@@ -977,3 +1024,17 @@ impl WIN32_FIND_DATA {
 	pub_fn_string_arr_get_set!(cFileName, set_cFileName);
 	pub_fn_string_arr_get_set!(cAlternateFileName, set_cAlternateFileName);
 }
+
+/// [`WIN32_FIND_STREAM_DATA`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/ns-fileapi-win32_find_stream_data)
+/// struct.
+#[repr(C)]
+pub struct WIN32_FIND_STREAM_DATA {
+	pub StreamSize: i64,
+	cStreamName: [u16; MAX_PATH + 36],
+}
+
+impl_default!(WIN32_FIND_STREAM_DATA);
+
+impl WIN32_FIND_STREAM_DATA {
+	pub_fn_string_arr_get_set!(cStreamName, set_cStreamName);
+}
@@ -0,0 +1,983 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::co;
+use crate::decl::*;
+
+/// Number of 100-nanosecond intervals in a millisecond.
+const TICKS_PER_MS: i64 = 10_000;
+/// Number of 100-nanosecond intervals in a minute.
+const TICKS_PER_MIN: i64 = TICKS_PER_MS * 1000 * 60;
+/// Number of 100-nanosecond intervals in a day.
+const TICKS_PER_DAY: i64 = TICKS_PER_MIN * 60 * 24;
+/// Number of days between 1601-01-01 and 1970-01-01.
+const DAYS_1601_TO_1970: i64 = 134_774;
+/// Greatest `FILETIME` value accepted by the conversion functions.
+const MAX_TICKS: i64 = i64::MAX;
+
+/// Tells whether the given year is a leap year, in the proleptic Gregorian
+/// calendar.
+#[must_use]
+const fn is_leap_year(year: u16) -> bool {
+	(year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in the given month. January = 1.
+#[must_use]
+const fn days_in_month(year: u16, month: u16) -> u16 {
+	match month {
+		2 => {
+			if is_leap_year(year) {
+				29
+			} else {
+				28
+			}
+		},
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	}
+}
+
+/// Returns the number of days elapsed since 1601-01-01.
+#[must_use]
+const fn days_from_civil(year: u16, month: u16, day: u16) -> i64 {
+	// https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+	let y = if month <= 2 { year as i64 - 1 } else { year as i64 };
+	let era = y.div_euclid(400);
+	let yoe = y - era * 400;
+	let m = month as i64;
+	let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 719_468 + DAYS_1601_TO_1970
+}
+
+/// Returns the year, month and day of the given number of days elapsed since
+/// 1601-01-01.
+#[must_use]
+const fn civil_from_days(days: i64) -> (u16, u16, u16) {
+	// https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+	let z = days - DAYS_1601_TO_1970 + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z - era * 146_097;
+	let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let d = doy - (153 * mp + 2) / 5 + 1;
+	let m = if mp < 10 { mp + 3 } else { mp - 9 };
+	let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+	(y as _, m as _, d as _)
+}
+
+/// Returns the day of the week, Sunday = 0.
+#[must_use]
+const fn day_of_week(year: u16, month: u16, day: u16) -> u16 {
+	// 1601-01-01 was a Monday.
+	((days_from_civil(year, month, day) + 1).rem_euclid(7)) as _
+}
+
+/// Converts a number of 100-nanosecond intervals since 1601-01-01 into a
+/// `SYSTEMTIME`.
+fn ticks_to_st(ticks: i64) -> SysResult<SYSTEMTIME> {
+	if ticks < 0 {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let days = ticks / TICKS_PER_DAY;
+	let rem_ms = (ticks % TICKS_PER_DAY) / TICKS_PER_MS;
+	let (year, month, day) = civil_from_days(days);
+
+	Ok(SYSTEMTIME {
+		wYear: year,
+		wMonth: month,
+		wDayOfWeek: day_of_week(year, month, day),
+		wDay: day,
+		wHour: (rem_ms / 3_600_000) as _,
+		wMinute: ((rem_ms / 60_000) % 60) as _,
+		wSecond: ((rem_ms / 1000) % 60) as _,
+		wMilliseconds: (rem_ms % 1000) as _,
+	})
+}
+
+/// Converts a `SYSTEMTIME` into a number of 100-nanosecond intervals since
+/// 1601-01-01. The `wDayOfWeek` member is ignored.
+fn st_to_ticks(st: &SYSTEMTIME) -> SysResult<i64> {
+	if !st.is_valid() {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let ms = st.wHour as i64 * 3_600_000
+		+ st.wMinute as i64 * 60_000
+		+ st.wSecond as i64 * 1000
+		+ st.wMilliseconds as i64;
+	days_from_civil(st.wYear, st.wMonth, st.wDay)
+		.checked_mul(TICKS_PER_DAY)
+		.and_then(|t| t.checked_add(ms * TICKS_PER_MS))
+		.ok_or(co::ERROR::INVALID_PARAMETER)
+}
+
+/// [`FILETIME`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime)
+/// struct.
+///
+/// Can be converted to [`SYSTEMTIME`](crate::SYSTEMTIME) with
+/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime) function, or through
+/// the pure Rust [`TryFrom`](std::convert::TryFrom) implementation, which
+/// doesn't call the operating system.
+///
+/// Can also be converted from and into
+/// [`std::time::SystemTime`](std::time::SystemTime).
+#[repr(C)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FILETIME {
+	pub dwLowDateTime: u32,
+	pub dwHighDateTime: u32,
+}
+
+impl From<u64> for FILETIME {
+	fn from(v: u64) -> Self {
+		Self {
+			dwLowDateTime: LODWORD(v),
+			dwHighDateTime: HIDWORD(v),
+		}
+	}
+}
+
+impl From<FILETIME> for u64 {
+	fn from(v: FILETIME) -> Self {
+		MAKEQWORD(v.dwLowDateTime, v.dwHighDateTime)
+	}
+}
+
+impl TryFrom<SYSTEMTIME> for FILETIME {
+	type Error = co::ERROR;
+
+	/// Pure Rust equivalent of
+	/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime).
+	fn try_from(v: SYSTEMTIME) -> SysResult<Self> {
+		st_to_ticks(&v).map(|ticks| Self::from(ticks as u64))
+	}
+}
+
+impl TryFrom<SystemTime> for FILETIME {
+	type Error = co::ERROR;
+
+	fn try_from(v: SystemTime) -> SysResult<Self> {
+		let nanos = match v.duration_since(UNIX_EPOCH) {
+			Ok(dur) => dur.as_nanos() as i128,
+			Err(e) => -(e.duration().as_nanos() as i128),
+		};
+		let ticks = nanos.div_euclid(100) + (DAYS_1601_TO_1970 * TICKS_PER_DAY) as i128;
+		if ticks < 0 || ticks > MAX_TICKS as i128 {
+			Err(co::ERROR::INVALID_PARAMETER)
+		} else {
+			Ok(Self::from(ticks as u64))
+		}
+	}
+}
+
+impl TryFrom<FILETIME> for SystemTime {
+	type Error = co::ERROR;
+
+	fn try_from(v: FILETIME) -> SysResult<Self> {
+		let ticks = u64::from(v) as i128 - (DAYS_1601_TO_1970 * TICKS_PER_DAY) as i128;
+		let dur = Duration::new(
+			(ticks.unsigned_abs() / 10_000_000) as _,
+			((ticks.unsigned_abs() % 10_000_000) * 100) as _,
+		);
+		if ticks >= 0 { UNIX_EPOCH.checked_add(dur) } else { UNIX_EPOCH.checked_sub(dur) }
+			.ok_or(co::ERROR::INVALID_PARAMETER)
+	}
+}
+
+impl FILETIME {
+	/// Returns a new `FILETIME` with the milliseconds difference.
+	#[must_use]
+	pub fn add_ms(self, ms: i64) -> Self {
+		Self::from((u64::from(self) as i64 + (ms * 10_000)) as u64)
+	}
+
+	/// Returns a new `FILETIME` with the seconds difference.
+	#[must_use]
+	pub fn add_secs(self, secs: i64) -> Self {
+		self.add_ms(secs * 1000)
+	}
+
+	/// Returns a new `FILETIME` with the minutes difference.
+	#[must_use]
+	pub fn add_mins(self, mins: i64) -> Self {
+		self.add_secs(mins * 60)
+	}
+
+	/// Returns a new `FILETIME` with the hours difference.
+	#[must_use]
+	pub fn add_hours(self, hours: i64) -> Self {
+		self.add_mins(hours * 60)
+	}
+
+	/// Returns a new `FILETIME` with the days difference.
+	#[must_use]
+	pub fn add_days(self, days: i64) -> Self {
+		self.add_hours(days * 24)
+	}
+
+	/// Returns a new `FILETIME` with the duration added, or `None` if the
+	/// result overflows.
+	#[must_use]
+	pub fn checked_add(self, dur: Duration) -> Option<Self> {
+		i64::try_from(dur.as_nanos() / 100)
+			.ok()
+			.and_then(|ticks| (u64::from(self) as i64).checked_add(ticks))
+			.map(|ticks| Self::from(ticks as u64))
+	}
+
+	/// Returns a new `FILETIME` with the duration subtracted, or `None` if the
+	/// result is before 1601-01-01.
+	#[must_use]
+	pub fn checked_sub(self, dur: Duration) -> Option<Self> {
+		u64::try_from(dur.as_nanos() / 100)
+			.ok()
+			.and_then(|ticks| u64::from(self).checked_sub(ticks))
+			.map(Self::from)
+	}
+
+	/// Returns the duration elapsed since an earlier `FILETIME`, or `None` if
+	/// `earlier` is actually later than `self`.
+	#[must_use]
+	pub fn duration_since(self, earlier: FILETIME) -> Option<Duration> {
+		u64::from(self)
+			.checked_sub(u64::from(earlier))
+			.map(|ticks| Duration::new(ticks / 10_000_000, ((ticks % 10_000_000) * 100) as _))
+	}
+
+	/// Creates a new `FILETIME` from a
+	/// [Unix time](https://en.wikipedia.org/wiki/Unix_time), the number of
+	/// seconds elapsed since 1970-01-01 UTC.
+	#[must_use]
+	pub fn from_unix_time(secs: i64) -> SysResult<Self> {
+		secs.checked_mul(TICKS_PER_MS * 1000)
+			.and_then(|ticks| ticks.checked_add(DAYS_1601_TO_1970 * TICKS_PER_DAY))
+			.filter(|ticks| *ticks >= 0)
+			.map(|ticks| Self::from(ticks as u64))
+			.ok_or(co::ERROR::INVALID_PARAMETER)
+	}
+
+	/// Returns the [Unix time](https://en.wikipedia.org/wiki/Unix_time), the
+	/// number of seconds elapsed since 1970-01-01 UTC. Fractions of seconds
+	/// are truncated towards the past.
+	#[must_use]
+	pub fn unix_time(&self) -> i64 {
+		(u64::from(*self) as i128 - (DAYS_1601_TO_1970 * TICKS_PER_DAY) as i128)
+			.div_euclid((TICKS_PER_MS * 1000) as _) as _
+	}
+}
+
+/// [`SYSTEMTIME`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-systemtime)
+/// struct.
+///
+/// Can be converted to [`FILETIME`](crate::FILETIME) with
+/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime) function, or through
+/// the pure Rust [`TryFrom`](std::convert::TryFrom) implementation, which
+/// doesn't call the operating system.
+///
+/// The [`FromStr`](std::str::FromStr) implementation parses the
+/// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format, which is written
+/// by [`to_iso8601`](crate::SYSTEMTIME::to_iso8601).
+#[repr(C)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SYSTEMTIME {
+	/// The year. The valid values for this member are 1,601 through 30,827.
+	pub wYear: u16,
+	/// The month. January = 1.
+	pub wMonth: u16,
+	/// The day of the week. Sunday = 0.
+	pub wDayOfWeek: u16,
+	/// The day of the month. The valid values for this member are 1 through 31.
+	pub wDay: u16,
+	/// The hour. The valid values for this member are 0 through 23.
+	pub wHour: u16,
+	/// The minute. The valid values for this member are 0 through 59.
+	pub wMinute: u16,
+	/// The second. The valid values for this member are 0 through 59.
+	pub wSecond: u16,
+	/// The millisecond. The valid values for this member are 0 through 999.
+	pub wMilliseconds: u16,
+}
+
+impl std::fmt::Display for SYSTEMTIME {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}",
+			self.wYear,
+			self.wMonth,
+			self.wDay,
+			self.wHour,
+			self.wMinute,
+			self.wSecond,
+			self.wMilliseconds
+		)
+	}
+}
+
+impl std::str::FromStr for SYSTEMTIME {
+	type Err = co::ERROR;
+
+	/// Parses an [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) date, with
+	/// optional time, like:
+	///
+	/// * `2024-02-29`
+	/// * `2024-02-29T13:45`
+	/// * `2024-02-29T13:45:10.250`
+	/// * `2024-02-29T13:45:10Z`
+	/// * `2024-02-29T13:45:10-03:00`
+	///
+	/// A space is also accepted as date and time separator. If a UTC offset is
+	/// present, the returned `SYSTEMTIME` is converted to UTC.
+	fn from_str(s: &str) -> SysResult<Self> {
+		let mut p = IsoParser { s: s.as_bytes(), pos: 0 };
+		let year = p.num(4)?;
+		p.expect(b'-')?;
+		let month = p.num(2)?;
+		p.expect(b'-')?;
+		let day = p.num(2)?;
+		let mut st = SYSTEMTIME {
+			wYear: year,
+			wMonth: month,
+			wDay: day,
+			..Default::default()
+		};
+
+		let mut offset_mins = 0i64;
+		if p.accept(b'T') || p.accept(b' ') {
+			st.wHour = p.num(2)?;
+			p.expect(b':')?;
+			st.wMinute = p.num(2)?;
+			if p.accept(b':') {
+				st.wSecond = p.num(2)?;
+				if p.accept(b'.') || p.accept(b',') {
+					let (frac, n) = p.digits()?;
+					st.wMilliseconds = match n {
+						1 => frac * 100,
+						2 => frac * 10,
+						_ => frac,
+					} as _;
+				}
+			}
+
+			if p.accept(b'Z') {
+				// UTC, nothing to do
+			} else if let Some(sign) = p.sign() {
+				let h: u16 = p.num(2)?;
+				p.accept(b':');
+				let m: u16 = p.num(2)?;
+				offset_mins = sign * (h as i64 * 60 + m as i64);
+			}
+		}
+
+		if p.pos != p.s.len() || !st.is_valid() {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		st.wDayOfWeek = st.day_of_week();
+		if offset_mins == 0 {
+			Ok(st)
+		} else {
+			ticks_to_st(st_to_ticks(&st)? - offset_mins * TICKS_PER_MIN)
+		}
+	}
+}
+
+impl TryFrom<FILETIME> for SYSTEMTIME {
+	type Error = co::ERROR;
+
+	/// Pure Rust equivalent of
+	/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime).
+	fn try_from(v: FILETIME) -> SysResult<Self> {
+		let ticks = u64::from(v);
+		if ticks > MAX_TICKS as u64 {
+			Err(co::ERROR::INVALID_PARAMETER)
+		} else {
+			ticks_to_st(ticks as _)
+		}
+	}
+}
+
+impl SYSTEMTIME {
+	/// Returns a new `SYSTEMTIME` with the milliseconds difference.
+	///
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	#[must_use]
+	pub fn add_ms(self, ms: i64) -> SysResult<Self> {
+		let ft = FILETIME::try_from(self)?;
+		SYSTEMTIME::try_from(ft.add_ms(ms))
+	}
+
+	/// Returns a new `SYSTEMTIME` with the seconds difference.
+	///
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	#[must_use]
+	pub fn add_secs(self, secs: i64) -> SysResult<Self> {
+		self.add_ms(secs * 1000)
+	}
+
+	/// Returns a new `SYSTEMTIME` with the minutes difference.
+	///
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	#[must_use]
+	pub fn add_mins(self, mins: i64) -> SysResult<Self> {
+		self.add_secs(mins * 60)
+	}
+
+	/// Returns a new `SYSTEMTIME` with the hours difference.
+	///
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	#[must_use]
+	pub fn add_hours(self, hours: i64) -> SysResult<Self> {
+		self.add_mins(hours * 60)
+	}
+
+	/// Returns a new `SYSTEMTIME` with the days difference.
+	///
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	#[must_use]
+	pub fn add_days(self, days: i64) -> SysResult<Self> {
+		self.add_hours(days * 24)
+	}
+
+	/// Returns a new `SYSTEMTIME` with the duration added, or `None` if the
+	/// date is invalid or the result overflows.
+	#[must_use]
+	pub fn checked_add(self, dur: Duration) -> Option<Self> {
+		FILETIME::try_from(self)
+			.ok()
+			.and_then(|ft| ft.checked_add(dur))
+			.and_then(|ft| SYSTEMTIME::try_from(ft).ok())
+	}
+
+	/// Returns a new `SYSTEMTIME` with the duration subtracted, or `None` if
+	/// the date is invalid or the result is before 1601-01-01.
+	#[must_use]
+	pub fn checked_sub(self, dur: Duration) -> Option<Self> {
+		FILETIME::try_from(self)
+			.ok()
+			.and_then(|ft| ft.checked_sub(dur))
+			.and_then(|ft| SYSTEMTIME::try_from(ft).ok())
+	}
+
+	/// Computes the day of the week of the date, Sunday = 0, ignoring the
+	/// current value of `wDayOfWeek`.
+	///
+	/// The date is assumed to be valid, see
+	/// [`is_valid`](crate::SYSTEMTIME::is_valid).
+	#[must_use]
+	pub const fn day_of_week(&self) -> u16 {
+		day_of_week(self.wYear, self.wMonth, self.wDay)
+	}
+
+	/// Formats the date and time in the
+	/// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format, like
+	/// `2024-02-29T13:45:10.250`, which can be parsed back by the
+	/// [`FromStr`](std::str::FromStr) implementation.
+	///
+	/// Unlike [`Display`](std::fmt::Display), which uses a space, the date and
+	/// time are separated by `T`.
+	#[must_use]
+	pub fn to_iso8601(&self) -> String {
+		format!(
+			"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}",
+			self.wYear,
+			self.wMonth,
+			self.wDay,
+			self.wHour,
+			self.wMinute,
+			self.wSecond,
+			self.wMilliseconds
+		)
+	}
+
+	/// Returns `true` if the year is a leap year.
+	#[must_use]
+	pub const fn is_leap_year(&self) -> bool {
+		is_leap_year(self.wYear)
+	}
+
+	/// Returns `true` if all the members, except `wDayOfWeek`, are within
+	/// their valid ranges, and the day exists in the month.
+	#[must_use]
+	pub const fn is_valid(&self) -> bool {
+		self.wYear >= 1601
+			&& self.wYear <= 30827
+			&& self.wMonth >= 1
+			&& self.wMonth <= 12
+			&& self.wDay >= 1
+			&& self.wDay <= days_in_month(self.wYear, self.wMonth)
+			&& self.wHour <= 23
+			&& self.wMinute <= 59
+			&& self.wSecond <= 59
+			&& self.wMilliseconds <= 999
+	}
+}
+
+/// Cursor over the bytes of an ISO 8601 string.
+struct IsoParser<'a> {
+	s: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> IsoParser<'a> {
+	fn accept(&mut self, ch: u8) -> bool {
+		if self.s.get(self.pos) == Some(&ch) {
+			self.pos += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn expect(&mut self, ch: u8) -> SysResult<()> {
+		if self.accept(ch) {
+			Ok(())
+		} else {
+			Err(co::ERROR::INVALID_PARAMETER)
+		}
+	}
+
+	fn sign(&mut self) -> Option<i64> {
+		if self.accept(b'+') {
+			Some(1)
+		} else if self.accept(b'-') {
+			Some(-1)
+		} else {
+			None
+		}
+	}
+
+	/// Parses exactly `count` digits.
+	fn num(&mut self, count: usize) -> SysResult<u16> {
+		let mut n = 0u16;
+		for _ in 0..count {
+			match self.s.get(self.pos) {
+				Some(ch) if ch.is_ascii_digit() => n = n * 10 + (ch - b'0') as u16,
+				_ => return Err(co::ERROR::INVALID_PARAMETER),
+			}
+			self.pos += 1;
+		}
+		Ok(n)
+	}
+
+	/// Parses one or more fraction digits, keeping only the first 3. Returns
+	/// the number and how many digits were kept.
+	fn digits(&mut self) -> SysResult<(u16, usize)> {
+		let start = self.pos;
+		let mut n = 0u16;
+		while let Some(ch) = self.s.get(self.pos).filter(|ch| ch.is_ascii_digit()) {
+			if self.pos - start < 3 {
+				n = n * 10 + (ch - b'0') as u16;
+			}
+			self.pos += 1;
+		}
+		match self.pos - start {
+			0 => Err(co::ERROR::INVALID_PARAMETER),
+			len => Ok((n, len.min(3))),
+		}
+	}
+}
+
+/// [`TIME_ZONE_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/ns-timezoneapi-time_zone_information)
+/// struct.
+///
+/// Besides the native [`GetTimeZoneInformation`](crate::GetTimeZoneInformation)
+/// and [`SystemTimeToTzSpecificLocalTime`](crate::SystemTimeToTzSpecificLocalTime)
+/// functions, the transition rules can be evaluated in pure Rust, which gives
+/// deterministic results on any platform.
+#[repr(C)]
+#[derive(Default, Clone)]
+pub struct TIME_ZONE_INFORMATION {
+	pub bias: i32,
+	standardName: [u16; 32],
+	pub standardDate: SYSTEMTIME,
+	pub standardBias: i32,
+	daylightName: [u16; 32],
+	pub daylightDate: SYSTEMTIME,
+	pub daylightBias: i32,
+}
+
+impl TIME_ZONE_INFORMATION {
+	pub_fn_string_arr_get_set!(standardName, set_standardName);
+	pub_fn_string_arr_get_set!(daylightName, set_daylightName);
+
+	/// Evaluates the transition rules for the given year, returning the local
+	/// moments when daylight saving time starts and ends, respectively.
+	///
+	/// The start moment is expressed in local standard time, and the end
+	/// moment in local daylight time, just like the `daylightDate` and
+	/// `standardDate` members.
+	///
+	/// Returns `None` if the time zone doesn't observe daylight saving time in
+	/// the given year, or
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if
+	/// a rule is malformed, like a "day-in-month" rule with `wDayOfWeek`
+	/// greater than 6, or `wDay` out of the 1 to 5 range.
+	#[must_use]
+	pub fn transitions(&self, year: u16) -> SysResult<Option<(SYSTEMTIME, SYSTEMTIME)>> {
+		Ok(
+			match (
+				Self::rule_date(&self.daylightDate, year)?,
+				Self::rule_date(&self.standardDate, year)?,
+			) {
+				(Some(start), Some(end)) => Some((start, end)),
+				_ => None,
+			},
+		)
+	}
+
+	/// Tells whether daylight saving time is in effect at the given UTC
+	/// moment.
+	#[must_use]
+	pub fn is_daylight(&self, utc: &SYSTEMTIME) -> SysResult<bool> {
+		self.is_daylight_ticks(st_to_ticks(utc)?)
+	}
+
+	/// Converts a local time of this time zone into UTC.
+	///
+	/// During the ambiguous hour which repeats when daylight saving time ends,
+	/// the local time is assumed to be in daylight time. Local times which
+	/// don't exist, skipped when daylight saving time starts, are assumed to
+	/// be in standard time.
+	///
+	/// Pure Rust equivalent of
+	/// [`TzSpecificLocalTimeToSystemTime`](crate::TzSpecificLocalTimeToSystemTime).
+	#[must_use]
+	pub fn local_to_utc(&self, local: &SYSTEMTIME) -> SysResult<SYSTEMTIME> {
+		let ticks = st_to_ticks(local)?;
+		let utc_dst = ticks + (self.bias + self.daylightBias) as i64 * TICKS_PER_MIN;
+		if self.is_daylight_ticks(utc_dst)? {
+			return ticks_to_st(utc_dst);
+		}
+		ticks_to_st(ticks + (self.bias + self.standardBias) as i64 * TICKS_PER_MIN)
+	}
+
+	/// Converts a UTC time into the local time of this time zone.
+	///
+	/// Pure Rust equivalent of
+	/// [`SystemTimeToTzSpecificLocalTime`](crate::SystemTimeToTzSpecificLocalTime).
+	#[must_use]
+	pub fn utc_to_local(&self, utc: &SYSTEMTIME) -> SysResult<SYSTEMTIME> {
+		let ticks = st_to_ticks(utc)?;
+		let bias = self.bias
+			+ if self.is_daylight_ticks(ticks)? { self.daylightBias } else { self.standardBias };
+		ticks_to_st(ticks - bias as i64 * TICKS_PER_MIN)
+	}
+
+	fn is_daylight_ticks(&self, utc_ticks: i64) -> SysResult<bool> {
+		let std_ticks = utc_ticks - (self.bias + self.standardBias) as i64 * TICKS_PER_MIN;
+		let year = ticks_to_st(std_ticks)?.wYear;
+		let (start, end) = match self.transitions(year)? {
+			Some(trans) => trans,
+			None => return Ok(false),
+		};
+
+		let start_utc =
+			st_to_ticks(&start)? + (self.bias + self.standardBias) as i64 * TICKS_PER_MIN;
+		let end_utc = st_to_ticks(&end)? + (self.bias + self.daylightBias) as i64 * TICKS_PER_MIN;
+
+		Ok(if start_utc < end_utc {
+			utc_ticks >= start_utc && utc_ticks < end_utc // northern hemisphere
+		} else {
+			utc_ticks >= start_utc || utc_ticks < end_utc // southern hemisphere
+		})
+	}
+
+	/// Resolves a transition rule, either absolute or in the "day-in-month"
+	/// format, to an actual date of the given year.
+	fn rule_date(rule: &SYSTEMTIME, year: u16) -> SysResult<Option<SYSTEMTIME>> {
+		if rule.wMonth == 0 {
+			return Ok(None); // no daylight saving time
+		}
+
+		let mut st = *rule;
+		if rule.wYear != 0 {
+			if rule.wYear != year {
+				return Ok(None); // absolute date, happens only once
+			}
+		} else {
+			if rule.wDayOfWeek > 6 || !(1..=5).contains(&rule.wDay) || rule.wMonth > 12 {
+				return Err(co::ERROR::INVALID_PARAMETER);
+			}
+
+			// wDay is the occurrence of wDayOfWeek within the month; 5 means the last one.
+			let first = day_of_week(year, rule.wMonth, 1);
+			let mut day = 1 + (rule.wDayOfWeek + 7 - first) % 7 + (rule.wDay - 1) * 7;
+			while day > days_in_month(year, rule.wMonth) {
+				day -= 7;
+			}
+			st.wYear = year;
+			st.wDay = day;
+		}
+
+		if !st.is_valid() {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		st.wDayOfWeek = st.day_of_week();
+		Ok(Some(st))
+	}
+}
+
+/// [`DYNAMIC_TIME_ZONE_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/ns-timezoneapi-dynamic_time_zone_information)
+/// struct.
+///
+/// The transition rules can be evaluated in pure Rust by converting it into a
+/// [`TIME_ZONE_INFORMATION`](crate::TIME_ZONE_INFORMATION), which honors the
+/// `DynamicDaylightTimeDisabled` member.
+#[repr(C)]
+#[derive(Clone)]
+pub struct DYNAMIC_TIME_ZONE_INFORMATION {
+	pub bias: i32,
+	standardName: [u16; 32],
+	pub standardDate: SYSTEMTIME,
+	pub standardBias: i32,
+	daylightName: [u16; 32],
+	pub daylightDate: SYSTEMTIME,
+	pub daylightBias: i32,
+	timeZoneKeyName: [u16; 128],
+	dynamicDaylightTimeDisabled: u8,
+}
+
+impl_default!(DYNAMIC_TIME_ZONE_INFORMATION);
+
+impl From<&DYNAMIC_TIME_ZONE_INFORMATION> for TIME_ZONE_INFORMATION {
+	fn from(v: &DYNAMIC_TIME_ZONE_INFORMATION) -> Self {
+		let disabled = v.dynamicDaylightTimeDisabled();
+		Self {
+			bias: v.bias,
+			standardName: v.standardName,
+			standardDate: if disabled { SYSTEMTIME::default() } else { v.standardDate },
+			standardBias: v.standardBias,
+			daylightName: v.daylightName,
+			daylightDate: if disabled { SYSTEMTIME::default() } else { v.daylightDate },
+			daylightBias: v.daylightBias,
+		}
+	}
+}
+
+impl DYNAMIC_TIME_ZONE_INFORMATION {
+	pub_fn_string_arr_get_set!(standardName, set_standardName);
+	pub_fn_string_arr_get_set!(daylightName, set_daylightName);
+	pub_fn_string_arr_get_set!(timeZoneKeyName, set_timeZoneKeyName);
+	pub_fn_bool_get_set!(dynamicDaylightTimeDisabled, set_dynamicDaylightTimeDisabled);
+
+	/// Evaluates the transition rules for the given year; see
+	/// [`TIME_ZONE_INFORMATION::transitions`](crate::TIME_ZONE_INFORMATION::transitions).
+	#[must_use]
+	pub fn transitions(&self, year: u16) -> SysResult<Option<(SYSTEMTIME, SYSTEMTIME)>> {
+		TIME_ZONE_INFORMATION::from(self).transitions(year)
+	}
+
+	/// Converts a local time of this time zone into UTC; see
+	/// [`TIME_ZONE_INFORMATION::local_to_utc`](crate::TIME_ZONE_INFORMATION::local_to_utc).
+	#[must_use]
+	pub fn local_to_utc(&self, local: &SYSTEMTIME) -> SysResult<SYSTEMTIME> {
+		TIME_ZONE_INFORMATION::from(self).local_to_utc(local)
+	}
+
+	/// Converts a UTC time into the local time of this time zone; see
+	/// [`TIME_ZONE_INFORMATION::utc_to_local`](crate::TIME_ZONE_INFORMATION::utc_to_local).
+	#[must_use]
+	pub fn utc_to_local(&self, utc: &SYSTEMTIME) -> SysResult<SYSTEMTIME> {
+		TIME_ZONE_INFORMATION::from(self).utc_to_local(utc)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn st(s: &str) -> SYSTEMTIME {
+		s.parse().unwrap()
+	}
+
+	/// "Day-in-month" rule: the `week`-th `dow` of `month`, at `hour`.
+	fn rule(month: u16, week: u16, dow: u16, hour: u16) -> SYSTEMTIME {
+		SYSTEMTIME {
+			wMonth: month,
+			wDayOfWeek: dow,
+			wDay: week,
+			wHour: hour,
+			..Default::default()
+		}
+	}
+
+	/// US Eastern: DST from the 2nd Sunday of March to the 1st Sunday of
+	/// November, both at 2:00.
+	fn eastern() -> TIME_ZONE_INFORMATION {
+		TIME_ZONE_INFORMATION {
+			bias: 300,
+			standardDate: rule(11, 1, 0, 2),
+			daylightDate: rule(3, 2, 0, 2),
+			daylightBias: -60,
+			..Default::default()
+		}
+	}
+
+	/// Central Europe: DST from the last Sunday of March at 2:00 to the last
+	/// Sunday of October at 3:00.
+	fn central_europe() -> TIME_ZONE_INFORMATION {
+		TIME_ZONE_INFORMATION {
+			bias: -60,
+			standardDate: rule(10, 5, 0, 3),
+			daylightDate: rule(3, 5, 0, 2),
+			daylightBias: -60,
+			..Default::default()
+		}
+	}
+
+	/// Sydney: DST from the 1st Sunday of October at 2:00 to the 1st Sunday of
+	/// April at 3:00.
+	fn sydney() -> TIME_ZONE_INFORMATION {
+		TIME_ZONE_INFORMATION {
+			bias: -600,
+			standardDate: rule(4, 1, 0, 3),
+			daylightDate: rule(10, 1, 0, 2),
+			daylightBias: -60,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn display_and_iso8601() {
+		let t = st("2024-02-29T13:45:10.250");
+		assert_eq!(t.to_string(), "2024-02-29 13:45:10.250");
+		assert_eq!(t.to_iso8601(), "2024-02-29T13:45:10.250");
+		assert_eq!(st(&t.to_string()), t);
+		assert_eq!(st(&t.to_iso8601()), t);
+	}
+
+	#[test]
+	fn parse() {
+		let t = st("2024-02-29");
+		assert_eq!((t.wYear, t.wMonth, t.wDay, t.wHour), (2024, 2, 29, 0));
+		assert_eq!(t.wDayOfWeek, 4); // Thursday
+		assert_eq!(st("2024-02-29T13:45:10.2").wMilliseconds, 200);
+		assert_eq!(st("2024-02-29T13:45:10-03:00"), st("2024-02-29T16:45:10"));
+		assert_eq!(st("2024-03-01T01:00+02:00"), st("2024-02-29T23:00"));
+		assert!("2023-02-29".parse::<SYSTEMTIME>().is_err());
+		assert!("2024-02-29T24:00".parse::<SYSTEMTIME>().is_err());
+		assert!("2024-02-29x".parse::<SYSTEMTIME>().is_err());
+	}
+
+	#[test]
+	fn calendar() {
+		assert_eq!(day_of_week(1601, 1, 1), 1); // Monday
+		assert_eq!(day_of_week(1970, 1, 1), 4); // Thursday
+		assert!(is_leap_year(2000) && !is_leap_year(1900) && is_leap_year(2024));
+		for days in [0, 1, 59, 365, DAYS_1601_TO_1970, 153_000, 3_000_000] {
+			let (y, m, d) = civil_from_days(days);
+			assert_eq!(days_from_civil(y, m, d), days);
+		}
+	}
+
+	#[test]
+	fn filetime_conversions() {
+		let epoch = FILETIME::try_from(st("1970-01-01")).unwrap();
+		assert_eq!(u64::from(epoch), 116_444_736_000_000_000);
+		assert_eq!(epoch.unix_time(), 0);
+		assert_eq!(FILETIME::from_unix_time(86_400).unwrap(), epoch.add_days(1));
+		assert_eq!(SYSTEMTIME::try_from(epoch.add_ms(-1)).unwrap(), st("1969-12-31T23:59:59.999"));
+		assert_eq!(SystemTime::try_from(epoch).unwrap(), UNIX_EPOCH);
+		assert_eq!(st("2024-02-28T23:30").add_hours(1).unwrap(), st("2024-02-29T00:30"));
+		assert!(SYSTEMTIME::try_from(FILETIME::from(u64::MAX)).is_err());
+	}
+
+	#[test]
+	fn transitions_day_in_month() {
+		assert_eq!(
+			eastern().transitions(2024).unwrap(),
+			Some((st("2024-03-10T02:00"), st("2024-11-03T02:00"))),
+		);
+		assert_eq!(
+			central_europe().transitions(2024).unwrap(), // 5 means the last one
+			Some((st("2024-03-31T02:00"), st("2024-10-27T03:00"))),
+		);
+		assert_eq!(TIME_ZONE_INFORMATION::default().transitions(2024).unwrap(), None);
+	}
+
+	#[test]
+	fn transitions_absolute() {
+		let mut tz = eastern();
+		tz.daylightDate = st("2024-04-01T02:00");
+		tz.standardDate = st("2024-10-01T02:00");
+		assert_eq!(tz.transitions(2024).unwrap(), Some((tz.daylightDate, tz.standardDate)));
+		assert_eq!(tz.transitions(2025).unwrap(), None);
+	}
+
+	#[test]
+	fn malformed_rules() {
+		for bad in [rule(3, 2, 7, 2), rule(3, 2, u16::MAX, 2), rule(3, 0, 0, 2), rule(3, 6, 0, 2)] {
+			let mut tz = eastern();
+			tz.daylightDate = bad;
+			assert_eq!(tz.transitions(2024), Err(co::ERROR::INVALID_PARAMETER));
+			assert!(tz.utc_to_local(&st("2024-06-01")).is_err());
+		}
+
+		let mut tz = eastern();
+		tz.standardDate = rule(13, 1, 0, 2);
+		assert!(tz.transitions(2024).is_err());
+	}
+
+	#[test]
+	fn dst_start() {
+		let tz = eastern();
+		assert_eq!(tz.utc_to_local(&st("2024-03-10T06:59:59")).unwrap(), st("2024-03-10T01:59:59"));
+		assert_eq!(tz.utc_to_local(&st("2024-03-10T07:00")).unwrap(), st("2024-03-10T03:00"));
+		assert!(!tz.is_daylight(&st("2024-03-10T06:59")).unwrap());
+		assert!(tz.is_daylight(&st("2024-03-10T07:00")).unwrap());
+
+		// 2:30 doesn't exist, and is taken as standard time
+		assert_eq!(tz.local_to_utc(&st("2024-03-10T02:30")).unwrap(), st("2024-03-10T07:30"));
+		assert_eq!(tz.local_to_utc(&st("2024-03-10T03:00")).unwrap(), st("2024-03-10T07:00"));
+	}
+
+	#[test]
+	fn dst_end() {
+		let tz = eastern();
+		assert_eq!(tz.utc_to_local(&st("2024-11-03T05:30")).unwrap(), st("2024-11-03T01:30"));
+		assert_eq!(tz.utc_to_local(&st("2024-11-03T06:00")).unwrap(), st("2024-11-03T01:00"));
+		assert!(tz.is_daylight(&st("2024-11-03T05:59")).unwrap());
+		assert!(!tz.is_daylight(&st("2024-11-03T06:00")).unwrap());
+
+		// 1:30 happens twice, and is taken as daylight time
+		assert_eq!(tz.local_to_utc(&st("2024-11-03T01:30")).unwrap(), st("2024-11-03T05:30"));
+		assert_eq!(tz.local_to_utc(&st("2024-11-03T02:00")).unwrap(), st("2024-11-03T07:00"));
+	}
+
+	#[test]
+	fn southern_hemisphere() {
+		let tz = sydney();
+		assert_eq!(
+			tz.transitions(2024).unwrap(),
+			Some((st("2024-10-06T02:00"), st("2024-04-07T03:00"))),
+		);
+		assert!(tz.is_daylight(&st("2024-01-15")).unwrap());
+		assert!(!tz.is_daylight(&st("2024-07-15")).unwrap());
+		assert!(tz.is_daylight(&st("2024-12-31T12:00")).unwrap()); // across the year boundary
+		assert_eq!(tz.utc_to_local(&st("2024-01-15T00:00")).unwrap(), st("2024-01-15T11:00"));
+		assert_eq!(tz.utc_to_local(&st("2024-07-15T00:00")).unwrap(), st("2024-07-15T10:00"));
+		assert_eq!(tz.utc_to_local(&st("2024-10-05T15:59")).unwrap(), st("2024-10-06T01:59"));
+		assert_eq!(tz.utc_to_local(&st("2024-10-05T16:00")).unwrap(), st("2024-10-06T03:00"));
+	}
+
+	#[test]
+	fn dynamic_disabled() {
+		let tz = eastern();
+		let mut dtz = DYNAMIC_TIME_ZONE_INFORMATION::default();
+		dtz.bias = tz.bias;
+		dtz.standardDate = tz.standardDate;
+		dtz.daylightDate = tz.daylightDate;
+		dtz.daylightBias = tz.daylightBias;
+		assert_eq!(dtz.utc_to_local(&st("2024-07-01T12:00")).unwrap(), st("2024-07-01T08:00"));
+
+		dtz.set_dynamicDaylightTimeDisabled(true);
+		assert_eq!(dtz.transitions(2024).unwrap(), None);
+		assert_eq!(dtz.utc_to_local(&st("2024-07-01T12:00")).unwrap(), st("2024-07-01T07:00"));
+	}
+}
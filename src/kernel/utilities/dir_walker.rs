@@ -0,0 +1,390 @@
+use std::cmp::Ordering;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::iterators::*;
+
+type SortFn = Box<dyn FnMut(&DirWalkEntry, &DirWalkEntry) -> Ordering>;
+
+/// Configurable, recursive directory walker, which yields
+/// [`DirWalkEntry`](crate::path::DirWalkEntry) objects carrying the
+/// [`WIN32_FIND_DATA`](crate::WIN32_FIND_DATA) metadata of each file and
+/// directory.
+///
+/// This is a high-level abstraction over [`HFINDFILE`](crate::HFINDFILE)
+/// iteration functions. For a simpler walker, which returns only the file
+/// paths, see [`path::dir_walk`](crate::path::dir_walk).
+///
+/// Errors are yielded by the iterator, and the walk goes on with the next
+/// entry.
+///
+/// # Examples
+///
+/// Listing all text files, up to 3 levels deep, skipping hidden ones and
+/// `.git` directories:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let walker = w::path::DirWalker::new("C:\\Temp")
+///     .max_depth(3)
+///     .include("*.txt")
+///     .exclude(".git")
+///     .skip_hidden(true)
+///     .sort_by(|a, b| a.file_name().cmp(b.file_name()));
+///
+/// for entry in walker.walk() {
+///     let entry = entry?;
+///     println!("{} {}", entry.path(), entry.size());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub struct DirWalker {
+	pub(in crate::kernel) dir_path: String,
+	pub(in crate::kernel) max_depth: Option<usize>,
+	pub(in crate::kernel) includes: Vec<Glob>,
+	pub(in crate::kernel) excludes: Vec<Glob>,
+	pub(in crate::kernel) post_order: bool,
+	pub(in crate::kernel) files_only: bool,
+	pub(in crate::kernel) skip_hidden: bool,
+	pub(in crate::kernel) skip_system: bool,
+	pub(in crate::kernel) follow_reparse_points: bool,
+	pub(in crate::kernel) large_fetch: bool,
+	pub(in crate::kernel) basic_info: bool,
+	pub(in crate::kernel) sort_by: Option<SortFn>,
+}
+
+impl DirWalker {
+	/// Creates a new walker over the given directory, with default options:
+	/// unlimited depth, pre-order, all files and directories are yielded, and
+	/// reparse points are not followed.
+	#[must_use]
+	pub fn new(dir_path: &str) -> Self {
+		Self {
+			dir_path: path::rtrim_backslash(dir_path).to_owned(),
+			max_depth: None,
+			includes: Vec::new(),
+			excludes: Vec::new(),
+			post_order: false,
+			files_only: false,
+			skip_hidden: false,
+			skip_system: false,
+			follow_reparse_points: false,
+			large_fetch: false,
+			basic_info: false,
+			sort_by: None,
+		}
+	}
+
+	/// Queries the directories with
+	/// [`co::FINDEX_INFO_LEVELS::Basic`](crate::co::FINDEX_INFO_LEVELS::Basic),
+	/// which doesn't retrieve the short file names, improving speed.
+	///
+	/// Default is `false`.
+	#[must_use]
+	pub const fn basic_info(mut self, basic_info: bool) -> Self {
+		self.basic_info = basic_info;
+		self
+	}
+
+	/// Adds a wildcard pattern of entries to be skipped, both files and
+	/// directories; an excluded directory is not descended into. Can be
+	/// called multiple times.
+	///
+	/// See [`include`](crate::path::DirWalker::include) for the pattern
+	/// syntax.
+	#[must_use]
+	pub fn exclude(mut self, pattern: &str) -> Self {
+		self.excludes.push(Glob::new(pattern));
+		self
+	}
+
+	/// Yields only files, not directories.
+	///
+	/// Default is `false`.
+	#[must_use]
+	pub const fn files_only(mut self, files_only: bool) -> Self {
+		self.files_only = files_only;
+		self
+	}
+
+	/// Descends into directories which are reparse points, like symbolic
+	/// links and junctions. Cycles are detected by comparing the volume serial
+	/// number and the file index of the directory with those of its
+	/// ancestors, and not descended into. If these can't be read, the
+	/// directory is not descended into either, and the error is yielded after
+	/// it.
+	///
+	/// Default is `false`, reparse points are yielded but not descended into.
+	#[must_use]
+	pub const fn follow_reparse_points(mut self, follow: bool) -> Self {
+		self.follow_reparse_points = follow;
+		self
+	}
+
+	/// Adds a wildcard pattern of files to be yielded; if no patterns are
+	/// added, all files are yielded. Directories are not affected. Can be
+	/// called multiple times.
+	///
+	/// Patterns are case-insensitive, and accept `?` for any single char and
+	/// `*` for any sequence of chars. If the pattern has a backslash, it's
+	/// matched against the path relative to the root directory, and `**`
+	/// matches any sequence of directories; otherwise, it's matched against
+	/// the file name only.
+	#[must_use]
+	pub fn include(mut self, pattern: &str) -> Self {
+		self.includes.push(Glob::new(pattern));
+		self
+	}
+
+	/// Queries the directories with
+	/// [`co::FIND_FIRST_EX::LARGE_FETCH`](crate::co::FIND_FIRST_EX::LARGE_FETCH),
+	/// which uses a larger buffer and can increase performance.
+	///
+	/// Default is `false`.
+	#[must_use]
+	pub const fn large_fetch(mut self, large_fetch: bool) -> Self {
+		self.large_fetch = large_fetch;
+		self
+	}
+
+	/// Sets the maximum depth: 1 yields only the immediate children of the
+	/// root directory.
+	///
+	/// Default is unlimited.
+	#[must_use]
+	pub const fn max_depth(mut self, max_depth: usize) -> Self {
+		self.max_depth = Some(max_depth);
+		self
+	}
+
+	/// Yields directories after their contents, instead of before.
+	///
+	/// Default is `false`.
+	#[must_use]
+	pub const fn post_order(mut self, post_order: bool) -> Self {
+		self.post_order = post_order;
+		self
+	}
+
+	/// Skips entries with [`co::FILE_ATTRIBUTE::HIDDEN`](crate::co::FILE_ATTRIBUTE::HIDDEN).
+	///
+	/// Default is `false`.
+	#[must_use]
+	pub const fn skip_hidden(mut self, skip: bool) -> Self {
+		self.skip_hidden = skip;
+		self
+	}
+
+	/// Skips entries with [`co::FILE_ATTRIBUTE::SYSTEM`](crate::co::FILE_ATTRIBUTE::SYSTEM).
+	///
+	/// Default is `false`.
+	#[must_use]
+	pub const fn skip_system(mut self, skip: bool) -> Self {
+		self.skip_system = skip;
+		self
+	}
+
+	/// Sorts the entries of each directory with the given comparison
+	/// function.
+	///
+	/// Default is the order returned by the file system.
+	#[must_use]
+	pub fn sort_by<F>(mut self, compare: F) -> Self
+	where
+		F: FnMut(&DirWalkEntry, &DirWalkEntry) -> Ordering + 'static,
+	{
+		self.sort_by = Some(Box::new(compare));
+		self
+	}
+
+	/// Consumes the walker, returning the iterator over the entries.
+	pub fn walk(self) -> impl Iterator<Item = SysResult<DirWalkEntry>> {
+		DirWalkerIter::new(self)
+	}
+}
+
+/// A file or directory found by [`DirWalker`](crate::path::DirWalker).
+pub struct DirWalkEntry {
+	pub(in crate::kernel) path: String,
+	pub(in crate::kernel) depth: usize,
+	pub(in crate::kernel) wfd: WIN32_FIND_DATA,
+}
+
+impl DirWalkEntry {
+	/// Returns the file attributes.
+	#[must_use]
+	pub const fn attributes(&self) -> co::FILE_ATTRIBUTE {
+		self.wfd.dwFileAttributes
+	}
+
+	/// Returns the depth of the entry, where the immediate children of the
+	/// root directory have depth 1.
+	#[must_use]
+	pub const fn depth(&self) -> usize {
+		self.depth
+	}
+
+	/// Returns the file name, without the path.
+	#[must_use]
+	pub fn file_name(&self) -> &str {
+		path::get_file_name(&self.path).unwrap_or_default()
+	}
+
+	/// Returns the underlying find data, which includes the file times.
+	#[must_use]
+	pub const fn find_data(&self) -> &WIN32_FIND_DATA {
+		&self.wfd
+	}
+
+	/// Returns `true` if the entry is a directory.
+	#[must_use]
+	pub const fn is_directory(&self) -> bool {
+		self.wfd.dwFileAttributes.has(co::FILE_ATTRIBUTE::DIRECTORY)
+	}
+
+	/// Returns `true` if the entry is a reparse point, like a symbolic link or
+	/// a junction.
+	#[must_use]
+	pub const fn is_reparse_point(&self) -> bool {
+		self.wfd
+			.dwFileAttributes
+			.has(co::FILE_ATTRIBUTE::REPARSE_POINT)
+	}
+
+	/// Returns the full path of the entry.
+	#[must_use]
+	pub fn path(&self) -> &str {
+		&self.path
+	}
+
+	/// Returns the file size, in bytes.
+	#[must_use]
+	pub const fn size(&self) -> u64 {
+		self.wfd.nFileSize()
+	}
+}
+
+/// A compiled wildcard pattern.
+pub(in crate::kernel) struct Glob {
+	chars: Vec<char>,
+	has_sep: bool,
+}
+
+impl Glob {
+	#[must_use]
+	pub(in crate::kernel) fn new(pattern: &str) -> Self {
+		let chars = Self::fold(pattern);
+		let has_sep = chars.contains(&'\\');
+		Self { chars, has_sep }
+	}
+
+	/// Matches the pattern against the relative path or the file name,
+	/// depending on whether the pattern has a separator.
+	#[must_use]
+	pub(in crate::kernel) fn matches(&self, rel_path: &str, file_name: &str) -> bool {
+		let text = Self::fold(if self.has_sep { rel_path } else { file_name });
+		Self::match_chars(&self.chars, &text)
+	}
+
+	fn fold(s: &str) -> Vec<char> {
+		s.to_uppercase()
+			.chars()
+			.map(|ch| if ch == '/' { '\\' } else { ch })
+			.collect()
+	}
+
+	/// Evaluates each pair of pattern and text suffixes once, from the end, so
+	/// patterns like `*a*a*a*b` don't backtrack exponentially.
+	fn match_chars(pat: &[char], text: &[char]) -> bool {
+		// matched[p * cols + t] tells whether pat[p..] matches text[t..]
+		let cols = text.len() + 1;
+		let mut matched = vec![false; (pat.len() + 1) * cols];
+		matched[pat.len() * cols + text.len()] = true;
+
+		for p in (0..pat.len()).rev() {
+			let double_star = pat[p] == '*' && pat.get(p + 1) == Some(&'*');
+			let mut rest_matches_any = false; // for "**", rest matches text[t..] or beyond
+			for t in (0..cols).rev() {
+				let has_ch = t < text.len();
+				matched[p * cols + t] = match pat[p] {
+					'*' if double_star => {
+						let rest = p + 2;
+						rest_matches_any |= matched[rest * cols + t];
+						rest_matches_any
+							|| (pat.get(rest) == Some(&'\\') && matched[(rest + 1) * cols + t])
+					},
+					'*' => {
+						// a single star doesn't cross directories
+						matched[(p + 1) * cols + t]
+							|| (has_ch && text[t] != '\\' && matched[p * cols + t + 1])
+					},
+					'?' => has_ch && text[t] != '\\' && matched[(p + 1) * cols + t + 1],
+					ch => has_ch && text[t] == ch && matched[(p + 1) * cols + t + 1],
+				};
+			}
+		}
+		matched[0]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn name_matches(pattern: &str, file_name: &str) -> bool {
+		Glob::new(pattern).matches(file_name, file_name)
+	}
+
+	fn path_matches(pattern: &str, rel_path: &str) -> bool {
+		Glob::new(pattern).matches(rel_path, "")
+	}
+
+	#[test]
+	fn literals_and_case() {
+		assert!(name_matches("readme.md", "README.md"));
+		assert!(!name_matches("readme.md", "readme.mdx"));
+		assert!(!name_matches("readme.md", "readme.m"));
+		assert!(name_matches("", ""));
+		assert!(!name_matches("", "a"));
+	}
+
+	#[test]
+	fn question_mark() {
+		assert!(name_matches("file?.txt", "file1.txt"));
+		assert!(!name_matches("file?.txt", "file.txt"));
+		assert!(!path_matches("a?b", "a\\b"));
+	}
+
+	#[test]
+	fn single_star() {
+		assert!(name_matches("*.txt", "notes.txt"));
+		assert!(name_matches("*.txt", ".txt"));
+		assert!(name_matches("*", ""));
+		assert!(name_matches("a*b*c", "aXXbYYc"));
+		assert!(!name_matches("a*b*c", "aXXbYY"));
+		assert!(path_matches("src\\*.rs", "src\\lib.rs"));
+		assert!(!path_matches("src\\*.rs", "src\\kernel\\mod.rs"));
+		assert!(path_matches("*\\*.rs", "src/lib.rs"));
+	}
+
+	#[test]
+	fn double_star() {
+		assert!(path_matches("src\\**\\*.rs", "src\\lib.rs"));
+		assert!(path_matches("src\\**\\*.rs", "src\\kernel\\utilities\\mod.rs"));
+		assert!(!path_matches("src\\**\\*.rs", "doc\\kernel\\mod.rs"));
+		assert!(path_matches("**\\target", "target"));
+		assert!(path_matches("**\\target", "a\\b\\target"));
+		assert!(!path_matches("**\\target", "a\\btarget"));
+		assert!(path_matches("src\\**", "src\\a\\b"));
+	}
+
+	#[test]
+	fn no_exponential_backtracking() {
+		let text = "a".repeat(200);
+		assert!(!name_matches("*a*a*a*a*a*a*a*b", &text));
+		assert!(name_matches("*a*a*a*a*a*a*a*", &text));
+		let path = vec!["a"; 100].join("\\");
+		assert!(!path_matches("**a**a**a**a**a**b", &path));
+	}
+}
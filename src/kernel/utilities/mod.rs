@@ -1,7 +1,9 @@
+mod dir_walker;
 mod encoding;
 mod file;
 mod file_mapped;
 mod w_string;
+mod win_path;
 
 pub mod path;
 
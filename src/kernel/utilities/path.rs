@@ -2,12 +2,18 @@
 //!
 //! Some of the functions are similar to [`std::path::Path`] ones, but here they
 //! work directly upon [`&str`](str) instead of [`&OsStr`](std::ffi::OsStr).
+//!
+//! For a path type with full Win32 semantics – drive-relative, UNC, device and
+//! verbatim forms – see [`WinPath`] and [`WinPathBuf`].
 
 use crate::co;
 use crate::decl::*;
 use crate::kernel::iterators::*;
 use crate::prelude::*;
 
+pub use super::dir_walker::{DirWalkEntry, DirWalker};
+pub use super::win_path::{WinPath, WinPathBuf, WinPathKind};
+
 /// Returns an iterator over the files and folders within a directory.
 /// Optionally, a wildcard can be specified to filter files by name.
 ///
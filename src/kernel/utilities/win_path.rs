@@ -0,0 +1,711 @@
+use std::borrow::Borrow;
+use std::ops::Deref;
+
+use crate::kernel::privs::*;
+
+/// The kind of a [`WinPath`](crate::path::WinPath), determined by its prefix.
+///
+/// See
+/// [File path formats on Windows systems](https://learn.microsoft.com/en-us/dotnet/standard/io/file-path-formats).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WinPathKind<'a> {
+	/// Relative to the current directory, like `foo\bar.txt`.
+	Relative,
+	/// Relative to the root of the current drive, like `\foo\bar.txt`.
+	RootRelative,
+	/// Relative to the current directory of the given drive, like
+	/// `C:foo\bar.txt`.
+	DriveRelative(char),
+	/// Absolute path with a drive letter, like `C:\foo\bar.txt`.
+	Disk(char),
+	/// Universal Naming Convention path, like `\\server\share\foo.txt`.
+	Unc { server: &'a str, share: &'a str },
+	/// Win32 device namespace, like `\\.\COM1` or `\\.\PhysicalDrive0`.
+	Device(&'a str),
+	/// Verbatim path with a drive letter, like `\\?\C:\foo\bar.txt`.
+	VerbatimDisk(char),
+	/// Verbatim UNC path, like `\\?\UNC\server\share\foo.txt`.
+	VerbatimUnc { server: &'a str, share: &'a str },
+	/// Any other verbatim path, like `\\?\Volume{…}\foo.txt`.
+	Verbatim(&'a str),
+}
+
+impl<'a> WinPathKind<'a> {
+	/// Returns `true` if the path doesn't depend on the current directory or
+	/// on the current drive.
+	#[must_use]
+	pub const fn is_absolute(&self) -> bool {
+		!matches!(self, Self::Relative | Self::RootRelative | Self::DriveRelative(_))
+	}
+
+	/// Returns `true` for the `\\?\` forms, which are passed straight to the
+	/// file system, without any normalization.
+	#[must_use]
+	pub const fn is_verbatim(&self) -> bool {
+		matches!(self, Self::VerbatimDisk(_) | Self::VerbatimUnc { .. } | Self::Verbatim(_))
+	}
+}
+
+/// A borrowed Windows path, which understands all the Win32 path forms listed
+/// in [`WinPathKind`](crate::path::WinPathKind). The owned counterpart is
+/// [`WinPathBuf`](crate::path::WinPathBuf).
+///
+/// Unlike [`std::path::Path`], the parsing is always performed with Windows
+/// semantics, regardless of the platform the code is running on. No system
+/// calls are made.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let p = w::path::WinPath::new("C:\\Temp\\..\\Users/foo.txt");
+///
+/// let n = p.normalize(); // C:\Users\foo.txt
+/// let l = n.to_long_path(); // \\?\C:\Users\foo.txt
+/// let e = p.extension(); // Some("txt")
+/// ```
+#[repr(transparent)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct WinPath {
+	inner: str,
+}
+
+impl std::fmt::Display for WinPath {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(&self.inner, f)
+	}
+}
+impl std::fmt::Debug for WinPath {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Debug::fmt(&self.inner, f)
+	}
+}
+
+impl AsRef<str> for WinPath {
+	fn as_ref(&self) -> &str {
+		&self.inner
+	}
+}
+impl AsRef<WinPath> for WinPath {
+	fn as_ref(&self) -> &WinPath {
+		self
+	}
+}
+impl AsRef<WinPath> for str {
+	fn as_ref(&self) -> &WinPath {
+		WinPath::new(self)
+	}
+}
+impl AsRef<WinPath> for String {
+	fn as_ref(&self) -> &WinPath {
+		WinPath::new(self)
+	}
+}
+
+impl ToOwned for WinPath {
+	type Owned = WinPathBuf;
+
+	fn to_owned(&self) -> WinPathBuf {
+		WinPathBuf { inner: self.inner.to_owned() }
+	}
+}
+
+impl WinPath {
+	/// Wraps a string slice as a `WinPath`. No allocation is made.
+	#[must_use]
+	pub fn new(s: &(impl AsRef<str> + ?Sized)) -> &Self {
+		let s: &str = s.as_ref();
+		unsafe { &*(s as *const str as *const Self) } // repr(transparent) over str
+	}
+
+	/// Returns the underlying string slice.
+	#[must_use]
+	pub const fn as_str(&self) -> &str {
+		&self.inner
+	}
+
+	/// Returns an iterator over the components after the prefix, skipping
+	/// empty ones.
+	///
+	/// Both slashes and backslashes are separators, except in verbatim paths,
+	/// where only backslashes are.
+	pub fn components(&self) -> impl Iterator<Item = &str> {
+		let (kind, plen) = parse_prefix(&self.inner);
+		let verbatim = kind.is_verbatim();
+		self.inner[plen..]
+			.split(move |ch| ch == '\\' || (!verbatim && ch == '/'))
+			.filter(|comp| !comp.is_empty())
+	}
+
+	/// Returns the drive letter, if any, uppercase.
+	#[must_use]
+	pub fn drive(&self) -> Option<char> {
+		match self.kind() {
+			WinPathKind::DriveRelative(d) | WinPathKind::Disk(d) | WinPathKind::VerbatimDisk(d) => {
+				Some(d.to_ascii_uppercase())
+			},
+			_ => None,
+		}
+	}
+
+	/// Returns the extension of the file name, without the dot, if any.
+	#[must_use]
+	pub fn extension(&self) -> Option<&str> {
+		self.file_name().and_then(|name| match name.rfind('.') {
+			None | Some(0) => None,
+			Some(idx) => Some(&name[idx + 1..]),
+		})
+	}
+
+	/// Returns the last component, if any. Returns `None` if the path ends in
+	/// `..`.
+	#[must_use]
+	pub fn file_name(&self) -> Option<&str> {
+		self.components().last().filter(|comp| *comp != "..")
+	}
+
+	/// Returns the file name without its extension, if any.
+	#[must_use]
+	pub fn file_stem(&self) -> Option<&str> {
+		self.file_name().map(|name| match name.rfind('.') {
+			None | Some(0) => name,
+			Some(idx) => &name[..idx],
+		})
+	}
+
+	/// Returns `true` if the path doesn't depend on the current directory or
+	/// on the current drive.
+	#[must_use]
+	pub fn is_absolute(&self) -> bool {
+		self.kind().is_absolute()
+	}
+
+	/// Returns `true` if the file name is a reserved DOS device name, like
+	/// `CON`, `NUL` or `COM1`, which the Win32 layer redirects to the device
+	/// regardless of the directory or the extension.
+	///
+	/// Verbatim paths are never considered reserved, since they bypass this
+	/// redirection.
+	#[must_use]
+	pub fn is_reserved_name(&self) -> bool {
+		if self.kind().is_verbatim() {
+			return false;
+		}
+		let name = match self.file_name() {
+			Some(name) => name,
+			None => return false,
+		};
+		let stem = name
+			.split(['.', ':'])
+			.next()
+			.unwrap_or("")
+			.trim_end_matches(' ');
+		let stem = stem.to_ascii_uppercase();
+
+		match stem.as_str() {
+			"CON" | "PRN" | "AUX" | "NUL" | "CONIN$" | "CONOUT$" => true,
+			_ => {
+				let mut chars = stem.chars();
+				let head: String = chars.by_ref().take(3).collect();
+				let tail: Vec<char> = chars.collect();
+				(head == "COM" || head == "LPT")
+					&& tail.len() == 1
+					&& matches!(tail[0], '1'..='9' | '¹' | '²' | '³')
+			},
+		}
+	}
+
+	/// Returns the kind of the path, determined by its prefix.
+	#[must_use]
+	pub fn kind(&self) -> WinPathKind<'_> {
+		parse_prefix(&self.inner).0
+	}
+
+	/// Creates a new path by joining `other` to this one, following the Win32
+	/// rules:
+	///
+	/// * if `other` is absolute, it replaces this path;
+	/// * if `other` is root relative, like `\foo`, it keeps only the drive or
+	///   the UNC share of this path;
+	/// * if `other` is drive relative, like `C:foo`, it's appended only if
+	///   this path is on the same drive, otherwise it replaces this path.
+	#[must_use]
+	pub fn join(&self, other: impl AsRef<str>) -> WinPathBuf {
+		let other = WinPath::new(other.as_ref());
+		let mut buf = self.to_owned();
+		match other.kind() {
+			WinPathKind::Relative => buf.push_str_sep(&other.inner),
+			WinPathKind::RootRelative => {
+				let (kind, plen) = parse_prefix(&self.inner);
+				buf.inner = match kind {
+					WinPathKind::Relative | WinPathKind::RootRelative => String::new(),
+					WinPathKind::Disk(d) | WinPathKind::DriveRelative(d) => format!("{}:", d),
+					_ => trim_end_seps(&self.inner[..plen]).to_owned(),
+				};
+				buf.inner.push_str(&other.inner);
+			},
+			WinPathKind::DriveRelative(d) if self.drive() == Some(d.to_ascii_uppercase()) => {
+				buf.push_str_sep(&other.inner[2..]);
+			},
+			_ => buf.inner = other.inner.to_owned(),
+		}
+		buf
+	}
+
+	/// Returns `true` if the path is too long to be passed to most Win32
+	/// functions without the `\\?\` prefix; that is, it has `MAX_PATH` or more
+	/// chars.
+	///
+	/// See also [`to_long_path`](crate::path::WinPath::to_long_path).
+	#[must_use]
+	pub fn needs_long_path(&self) -> bool {
+		!self.kind().is_verbatim() && self.inner.encode_utf16().count() >= MAX_PATH
+	}
+
+	/// Returns a new normalized path:
+	///
+	/// * slashes are converted into backslashes;
+	/// * repeated separators are folded;
+	/// * `.` components are removed;
+	/// * `..` components remove the previous component, never going above the
+	///   root;
+	/// * trailing separators are removed.
+	///
+	/// Verbatim paths are returned unchanged, since Windows doesn't normalize
+	/// them.
+	#[must_use]
+	pub fn normalize(&self) -> WinPathBuf {
+		let (kind, plen) = parse_prefix(&self.inner);
+		if kind.is_verbatim() {
+			return self.to_owned();
+		}
+
+		let mut stack = Vec::<&str>::new();
+		for comp in self.components() {
+			match comp {
+				"." => {},
+				".." => match stack.last() {
+					Some(last) if *last != ".." => {
+						stack.pop();
+					},
+					_ => {
+						if !matches!(kind, WinPathKind::Relative | WinPathKind::DriveRelative(_)) {
+							// can't go above the root
+						} else {
+							stack.push(comp);
+						}
+					},
+				},
+				_ => stack.push(comp),
+			}
+		}
+
+		let mut inner = self.inner[..plen].replace('/', "\\");
+		inner.push_str(&stack.join("\\"));
+		if inner.is_empty() {
+			inner.push('.');
+		}
+		WinPathBuf { inner }
+	}
+
+	/// Returns the path without its last component, or `None` if the path
+	/// has no components.
+	#[must_use]
+	pub fn parent(&self) -> Option<&WinPath> {
+		let (kind, plen) = parse_prefix(&self.inner);
+		let is_sep = |ch: char| ch == '\\' || (!kind.is_verbatim() && ch == '/');
+
+		let rest = self.inner[plen..].trim_end_matches(is_sep);
+		if rest.is_empty() {
+			return None;
+		}
+		let parent = match rest.rfind(is_sep) {
+			None => &self.inner[..plen],
+			Some(idx) => &self.inner[..plen + rest[..idx].trim_end_matches(is_sep).len()],
+		};
+		Some(WinPath::new(parent))
+	}
+
+	/// Returns the prefix, including the root separator, if any. For example,
+	/// `C:\`, `\\server\share\` or `\\?\C:\`.
+	#[must_use]
+	pub fn prefix(&self) -> &str {
+		&self.inner[..parse_prefix(&self.inner).1]
+	}
+
+	/// Returns the remaining path after removing `base`, comparing each
+	/// component case-insensitively. Returns `None` if `base` is not a prefix.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let p = w::path::WinPath::new("C:\\Temp\\foo\\a.txt");
+	/// let r = p.strip_prefix("c:/temp").unwrap(); // foo\a.txt
+	/// ```
+	#[must_use]
+	pub fn strip_prefix(&self, base: impl AsRef<str>) -> Option<&WinPath> {
+		let base = WinPath::new(base.as_ref());
+		if !eq_no_case(&prefix_key(&self.inner), &prefix_key(&base.inner)) {
+			return None;
+		}
+
+		let (kind, plen) = parse_prefix(&self.inner);
+
+		let is_sep = |ch: char| ch == '\\' || (!kind.is_verbatim() && ch == '/');
+		let mut rest = &self.inner[plen..];
+		for base_comp in base.components() {
+			rest = rest.trim_start_matches(is_sep);
+			let end = rest.find(is_sep).unwrap_or(rest.len());
+			if !eq_no_case(&rest[..end], base_comp) {
+				return None;
+			}
+			rest = &rest[end..];
+		}
+		Some(WinPath::new(rest.trim_start_matches(is_sep)))
+	}
+
+	/// Returns the path in its long form, prefixed with `\\?\`, which allows
+	/// Win32 functions to bypass the `MAX_PATH` limit. The path is normalized
+	/// beforehand, since verbatim paths are not.
+	///
+	/// Only absolute disk and UNC paths are converted; other paths are
+	/// returned unchanged. Relative paths must be made absolute first, with
+	/// [`GetFullPathName`](crate::GetFullPathName).
+	#[must_use]
+	pub fn to_long_path(&self) -> WinPathBuf {
+		let norm = self.normalize();
+		let inner = match self.kind() {
+			WinPathKind::Disk(_) => format!("\\\\?\\{}", norm.inner),
+			WinPathKind::Unc { .. } => format!("\\\\?\\UNC\\{}", &norm.inner[2..]),
+			_ => return self.to_owned(),
+		};
+		WinPathBuf { inner }
+	}
+}
+
+/// An owned, mutable Windows path. The borrowed counterpart is
+/// [`WinPath`](crate::path::WinPath), whose methods are available through
+/// [`Deref`](std::ops::Deref).
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct WinPathBuf {
+	inner: String,
+}
+
+impl std::fmt::Display for WinPathBuf {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(&self.inner, f)
+	}
+}
+impl std::fmt::Debug for WinPathBuf {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Debug::fmt(&self.inner, f)
+	}
+}
+
+impl Deref for WinPathBuf {
+	type Target = WinPath;
+
+	fn deref(&self) -> &Self::Target {
+		WinPath::new(&self.inner)
+	}
+}
+
+impl Borrow<WinPath> for WinPathBuf {
+	fn borrow(&self) -> &WinPath {
+		self
+	}
+}
+
+impl AsRef<str> for WinPathBuf {
+	fn as_ref(&self) -> &str {
+		&self.inner
+	}
+}
+impl AsRef<WinPath> for WinPathBuf {
+	fn as_ref(&self) -> &WinPath {
+		self
+	}
+}
+
+impl From<&str> for WinPathBuf {
+	fn from(s: &str) -> Self {
+		Self { inner: s.to_owned() }
+	}
+}
+impl From<String> for WinPathBuf {
+	fn from(s: String) -> Self {
+		Self { inner: s }
+	}
+}
+impl From<WinPathBuf> for String {
+	fn from(p: WinPathBuf) -> Self {
+		p.inner
+	}
+}
+
+impl WinPathBuf {
+	/// Creates a new, empty path.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { inner: String::new() }
+	}
+
+	/// Consumes the path, returning the underlying `String`.
+	#[must_use]
+	pub fn into_string(self) -> String {
+		self.inner
+	}
+
+	/// Removes the last component. Returns `false` if there was nothing to
+	/// remove.
+	pub fn pop(&mut self) -> bool {
+		match self.parent().map(|p| p.inner.len()) {
+			Some(len) => {
+				self.inner.truncate(len);
+				true
+			},
+			None => false,
+		}
+	}
+
+	/// Joins `other` to the path in place; see
+	/// [`WinPath::join`](crate::path::WinPath::join).
+	pub fn push(&mut self, other: impl AsRef<str>) {
+		*self = self.join(other);
+	}
+
+	/// Replaces the extension of the file name, if any. An empty extension
+	/// removes it. Returns `false` if the path has no file name.
+	pub fn set_extension(&mut self, extension: &str) -> bool {
+		let (start, end) = match (self.file_name(), self.file_stem()) {
+			(Some(name), Some(stem)) => {
+				let name_start = name.as_ptr() as usize - self.inner.as_ptr() as usize;
+				(name_start + stem.len(), name_start + name.len())
+			},
+			_ => return false,
+		};
+
+		let extension = extension.trim_start_matches('.');
+		let new_ext = if extension.is_empty() { String::new() } else { format!(".{}", extension) };
+		self.inner.replace_range(start..end, &new_ext);
+		true
+	}
+
+	/// Replaces the file name. If the path has no file name, the new one is
+	/// simply appended.
+	pub fn set_file_name(&mut self, file_name: &str) {
+		if self.file_name().is_some() {
+			self.pop();
+		}
+		self.push(file_name);
+	}
+
+	/// Appends a string, inserting a backslash if needed.
+	fn push_str_sep(&mut self, s: &str) {
+		let (kind, plen) = parse_prefix(&self.inner);
+		let is_drive_only =
+			matches!(kind, WinPathKind::DriveRelative(_)) && plen == self.inner.len(); // "C:" stays relative
+		let needs_sep =
+			!(self.inner.is_empty() || self.inner.ends_with(['\\', '/']) || is_drive_only);
+		if needs_sep {
+			self.inner.push('\\');
+		}
+		self.inner.push_str(s);
+	}
+}
+
+/// Parses the prefix of the path, returning its kind and the length of the
+/// prefix, including the root separator, if any.
+fn parse_prefix(s: &str) -> (WinPathKind<'_>, usize) {
+	let b = s.as_bytes();
+	let is_sep = |idx: usize| matches!(b.get(idx), Some(b'\\' | b'/'));
+	let drive_at = |idx: usize| match (b.get(idx), b.get(idx + 1)) {
+		(Some(ch), Some(b':')) if ch.is_ascii_alphabetic() => Some(*ch as char),
+		_ => None,
+	};
+
+	if let Some(rest) = s.strip_prefix("\\\\?\\") {
+		let is_unc = rest
+			.as_bytes()
+			.get(..3)
+			.is_some_and(|b| b.eq_ignore_ascii_case(b"UNC"));
+		if is_unc && is_sep(7) {
+			let (server, share, len) = server_share(&s[8..], |ch| ch == '\\');
+			(WinPathKind::VerbatimUnc { server, share }, 8 + len)
+		} else if let Some(d) = drive_at(4).filter(|_| b.len() == 6 || b[6] == b'\\') {
+			(WinPathKind::VerbatimDisk(d), if b.len() > 6 { 7 } else { 6 })
+		} else {
+			let (name, len) = first_comp(rest, |ch| ch == '\\');
+			(WinPathKind::Verbatim(name), 4 + len)
+		}
+	} else if is_sep(0) && is_sep(1) && matches!(b.get(2), Some(b'.' | b'?')) && is_sep(3) {
+		let (name, len) = first_comp(&s[4..], |ch| ch == '\\' || ch == '/');
+		(WinPathKind::Device(name), 4 + len)
+	} else if is_sep(0) && is_sep(1) {
+		let (server, share, len) = server_share(&s[2..], |ch| ch == '\\' || ch == '/');
+		(WinPathKind::Unc { server, share }, 2 + len)
+	} else if is_sep(0) {
+		(WinPathKind::RootRelative, 1)
+	} else if let Some(d) = drive_at(0) {
+		if is_sep(2) {
+			(WinPathKind::Disk(d), 3)
+		} else {
+			(WinPathKind::DriveRelative(d), 2)
+		}
+	} else {
+		(WinPathKind::Relative, 0)
+	}
+}
+
+/// Returns the first component and the length consumed, including the
+/// following separator, if any.
+fn first_comp(s: &str, is_sep: impl Fn(char) -> bool) -> (&str, usize) {
+	match s.find(&is_sep) {
+		None => (s, s.len()),
+		Some(idx) => (&s[..idx], idx + 1),
+	}
+}
+
+/// Returns the server and share names and the length consumed, including the
+/// following separator, if any.
+fn server_share(s: &str, is_sep: impl Fn(char) -> bool + Copy) -> (&str, &str, usize) {
+	let (server, len1) = first_comp(s, is_sep);
+	let (share, len2) = first_comp(&s[len1..], is_sep);
+	(server, share, len1 + len2)
+}
+
+fn trim_end_seps(s: &str) -> &str {
+	s.trim_end_matches(['\\', '/'])
+}
+
+fn eq_no_case(a: &str, b: &str) -> bool {
+	a.to_uppercase() == b.to_uppercase()
+}
+
+/// Returns the prefix with slashes converted, and without the trailing
+/// separator for the forms where it's optional.
+fn prefix_key(s: &str) -> String {
+	let (kind, plen) = parse_prefix(s);
+	let prefix = s[..plen].replace('/', "\\");
+	match kind {
+		WinPathKind::Unc { .. }
+		| WinPathKind::VerbatimUnc { .. }
+		| WinPathKind::Device(_)
+		| WinPathKind::Verbatim(_) => trim_end_seps(&prefix).to_owned(),
+		_ => prefix,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn kind(s: &str) -> WinPathKind<'_> {
+		WinPath::new(s).kind()
+	}
+
+	#[test]
+	fn parse_prefix_kinds() {
+		assert_eq!(kind("foo\\bar.txt"), WinPathKind::Relative);
+		assert_eq!(kind(""), WinPathKind::Relative);
+		assert_eq!(kind("\\foo"), WinPathKind::RootRelative);
+		assert_eq!(kind("/foo"), WinPathKind::RootRelative);
+		assert_eq!(kind("c:foo"), WinPathKind::DriveRelative('c'));
+		assert_eq!(kind("C:\\foo"), WinPathKind::Disk('C'));
+		assert_eq!(kind("C:/foo"), WinPathKind::Disk('C'));
+		assert_eq!(
+			kind("\\\\server\\share\\foo.txt"),
+			WinPathKind::Unc { server: "server", share: "share" },
+		);
+		assert_eq!(kind("//server/share"), WinPathKind::Unc { server: "server", share: "share" });
+		assert_eq!(kind("\\\\.\\COM1"), WinPathKind::Device("COM1"));
+		assert_eq!(kind("\\\\?\\C:\\foo"), WinPathKind::VerbatimDisk('C'));
+		assert_eq!(kind("\\\\?\\C:"), WinPathKind::VerbatimDisk('C'));
+		assert_eq!(
+			kind("\\\\?\\unc\\server\\share\\foo"),
+			WinPathKind::VerbatimUnc { server: "server", share: "share" },
+		);
+		assert_eq!(kind("\\\\?\\Volume{x}\\foo"), WinPathKind::Verbatim("Volume{x}"));
+		assert_eq!(kind("\\\\?\\C:/foo"), WinPathKind::Verbatim("C:/foo")); // no slashes in verbatim paths
+	}
+
+	#[test]
+	fn parse_prefix_multibyte() {
+		assert_eq!(kind("\\\\?\\ab€x"), WinPathKind::Verbatim("ab€x"));
+		assert_eq!(kind("\\\\?\\€"), WinPathKind::Verbatim("€"));
+		assert_eq!(kind("\\\\?\\UN"), WinPathKind::Verbatim("UN"));
+		assert_eq!(kind("€:\\foo"), WinPathKind::Relative);
+		assert_eq!(WinPath::new("\\\\?\\ab€x\\é.txt").file_name(), Some("é.txt"));
+	}
+
+	#[test]
+	fn prefix_and_components() {
+		let p = WinPath::new("\\\\server\\share\\a/b\\\\c.txt");
+		assert_eq!(p.prefix(), "\\\\server\\share\\");
+		assert_eq!(p.components().collect::<Vec<_>>(), ["a", "b", "c.txt"]);
+		assert_eq!(p.file_stem(), Some("c"));
+		assert_eq!(p.extension(), Some("txt"));
+		assert_eq!(
+			WinPath::new("\\\\?\\C:\\a/b")
+				.components()
+				.collect::<Vec<_>>(),
+			["a/b"]
+		);
+		assert_eq!(WinPath::new("C:\\.hidden").extension(), None);
+		assert_eq!(WinPath::new("C:\\foo\\..").file_name(), None);
+	}
+
+	#[test]
+	fn normalize() {
+		let norm = |s: &str| WinPath::new(s).normalize().into_string();
+		assert_eq!(norm("C:\\Temp\\..\\Users/./foo.txt"), "C:\\Users\\foo.txt");
+		assert_eq!(norm("C:\\..\\foo"), "C:\\foo");
+		assert_eq!(norm("..\\a\\..\\..\\b"), "..\\..\\b");
+		assert_eq!(norm("a\\.."), ".");
+		assert_eq!(norm("\\\\?\\C:\\a\\..\\b"), "\\\\?\\C:\\a\\..\\b");
+	}
+
+	#[test]
+	fn join_and_parent() {
+		let join = |a: &str, b: &str| WinPath::new(a).join(b).into_string();
+		assert_eq!(join("C:\\foo", "bar"), "C:\\foo\\bar");
+		assert_eq!(join("C:\\foo\\", "bar"), "C:\\foo\\bar");
+		assert_eq!(join("C:", "bar"), "C:bar");
+		assert_eq!(join("C:\\foo", "\\bar"), "C:\\bar");
+		assert_eq!(join("\\\\srv\\shr\\foo", "\\bar"), "\\\\srv\\shr\\bar");
+		assert_eq!(join("C:\\foo", "c:bar"), "C:\\foo\\bar");
+		assert_eq!(join("C:\\foo", "D:bar"), "D:bar");
+		assert_eq!(join("C:\\foo", "D:\\bar"), "D:\\bar");
+
+		assert_eq!(WinPath::new("C:\\foo\\bar").parent().map(WinPath::as_str), Some("C:\\foo"));
+		assert_eq!(WinPath::new("C:\\foo").parent().map(WinPath::as_str), Some("C:\\"));
+		assert_eq!(WinPath::new("C:\\").parent(), None);
+	}
+
+	#[test]
+	fn strip_prefix_and_long_path() {
+		let p = WinPath::new("C:\\Temp\\foo\\a.txt");
+		assert_eq!(p.strip_prefix("c:/temp").map(WinPath::as_str), Some("foo\\a.txt"));
+		assert_eq!(p.strip_prefix("D:\\Temp"), None);
+		assert_eq!(p.strip_prefix("C:\\Tem"), None);
+
+		assert_eq!(WinPath::new("C:\\a\\..\\b").to_long_path().into_string(), "\\\\?\\C:\\b",);
+		assert_eq!(
+			WinPath::new("\\\\srv\\shr\\a").to_long_path().into_string(),
+			"\\\\?\\UNC\\srv\\shr\\a",
+		);
+		assert_eq!(WinPath::new("a\\b").to_long_path().into_string(), "a\\b");
+	}
+
+	#[test]
+	fn reserved_names() {
+		assert!(WinPath::new("C:\\foo\\con.txt").is_reserved_name());
+		assert!(WinPath::new("COM1").is_reserved_name());
+		assert!(WinPath::new("lpt²").is_reserved_name());
+		assert!(!WinPath::new("COM10").is_reserved_name());
+		assert!(!WinPath::new("\\\\?\\C:\\NUL").is_reserved_name());
+	}
+}
@@ -11,15 +11,19 @@
 
 #[cfg(feature = "advapi")] mod advapi;
 #[cfg(feature = "comctl")] mod comctl;
+#[cfg(feature = "dbghelp")] mod dbghelp;
 #[cfg(feature = "dshow")] mod dshow;
 #[cfg(feature = "dwm")] mod dwm;
 #[cfg(feature = "dxgi")] mod dxgi;
 #[cfg(feature = "gdi")] mod gdi;
 #[cfg(feature = "kernel")] mod kernel;
 #[cfg(feature = "mf")] mod mf;
+#[cfg(feature = "netapi")] mod netapi;
 #[cfg(feature = "ole")] mod ole;
 #[cfg(feature = "oleaut")] mod oleaut;
+#[cfg(feature = "pdh")] mod pdh;
 #[cfg(feature = "psapi")] mod psapi;
+#[cfg(feature = "richedit")] mod richedit;
 #[cfg(feature = "shell")] mod shell;
 #[cfg(feature = "taskschd")] mod taskschd;
 #[cfg(feature = "user")] mod user;
@@ -41,15 +45,19 @@
 mod decl {
 	#[cfg(feature = "advapi")] pub use super::advapi::decl::*;
 	#[cfg(feature = "comctl")] pub use super::comctl::decl::*;
+	#[cfg(feature = "dbghelp")] pub use super::dbghelp::decl::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::decl::*;
 	#[cfg(feature = "dwm")] pub use super::dwm::decl::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::decl::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::decl::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::decl::*;
 	#[cfg(feature = "mf")] pub use super::mf::decl::*;
+	#[cfg(feature = "netapi")] pub use super::netapi::decl::*;
 	#[cfg(feature = "ole")] pub use super::ole::decl::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::decl::*;
+	#[cfg(feature = "pdh")] pub use super::pdh::decl::*;
 	#[cfg(feature = "psapi")] pub use super::psapi::decl::*;
+	#[cfg(feature = "richedit")] pub use super::richedit::decl::*;
 	#[cfg(feature = "shell")] pub use super::shell::decl::*;
 	#[cfg(feature = "taskschd")] pub use super::taskschd::decl::*;
 	#[cfg(feature = "user")] pub use super::user::decl::*;
@@ -71,14 +79,18 @@ pub mod co {
 
 	#[cfg(feature = "advapi")] pub use super::advapi::co::*;
 	#[cfg(feature = "comctl")] pub use super::comctl::co::*;
+	#[cfg(feature = "dbghelp")] pub use super::dbghelp::co::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::co::*;
 	#[cfg(feature = "dwm")] pub use super::dwm::co::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::co::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::co::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::co::*;
 	#[cfg(feature = "mf")] pub use super::mf::co::*;
+	#[cfg(feature = "netapi")] pub use super::netapi::co::*;
 	#[cfg(feature = "ole")] pub use super::ole::co::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::co::*;
+	#[cfg(feature = "pdh")] pub use super::pdh::co::*;
+	#[cfg(feature = "richedit")] pub use super::richedit::co::*;
 	#[cfg(feature = "shell")] pub use super::shell::co::*;
 	#[cfg(feature = "taskschd")] pub use super::taskschd::co::*;
 	#[cfg(feature = "user")] pub use super::user::co::*;
@@ -100,7 +112,9 @@ pub mod guard {
 	#[cfg(feature = "gdi")] pub use super::gdi::guard::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::guard::*;
 	#[cfg(feature = "mf")] pub use super::mf::guard::*;
+	#[cfg(feature = "netapi")] pub use super::netapi::guard::*;
 	#[cfg(feature = "ole")] pub use super::ole::guard::*;
+	#[cfg(feature = "pdh")] pub use super::pdh::guard::*;
 	#[cfg(feature = "shell")] pub use super::shell::guard::*;
 	#[cfg(feature = "user")] pub use super::user::guard::*;
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::guard::*;
@@ -114,6 +128,15 @@ pub mod msg {
 
 	pub use super::user::messages::WndMsg;
 
+	#[cfg(feature = "comctl")]
+	pub mod acm {
+		//! Animation control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-messages),
+		//! whose constants have [`ACM`](crate::co::ACM) prefix.
+
+		pub use super::super::comctl::messages::acm::*;
+	}
+
 	#[cfg(feature = "user")]
 	pub mod bm {
 		//! Button control
@@ -135,6 +158,15 @@ pub mod msg {
 		#[cfg(feature = "comctl")] pub use super::super::comctl::messages::cb::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod cbem {
+		//! ComboBoxEx control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-comboboxex-control-reference-messages),
+		//! whose constants have [`CBEM`](crate::co::CBEM) prefix.
+
+		pub use super::super::comctl::messages::cbem::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod dtm {
 		//! Date and time picker control
@@ -153,6 +185,7 @@ pub mod msg {
 
 		pub use super::super::user::messages::em::*;
 		#[cfg(feature = "comctl")] pub use super::super::comctl::messages::em::*;
+		#[cfg(feature = "richedit")] pub use super::super::richedit::messages::em::*;
 	}
 
 	#[cfg(feature = "comctl")]
@@ -164,6 +197,24 @@ pub mod msg {
 		pub use super::super::comctl::messages::hdm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod hkm {
+		//! Hot key control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-hot-key-control-reference-messages),
+		//! whose constants have [`HKM`](crate::co::HKM) prefix.
+
+		pub use super::super::comctl::messages::hkm::*;
+	}
+
+	#[cfg(feature = "comctl")]
+	pub mod ipm {
+		//! IP address control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-ip-address-control-reference-messages),
+		//! whose constants have [`IPM`](crate::co::IPM) prefix.
+
+		pub use super::super::comctl::messages::ipm::*;
+	}
+
 	#[cfg(feature = "user")]
 	pub mod lb {
 		//! ListBox control
@@ -173,6 +224,15 @@ pub mod msg {
 		pub use super::super::user::messages::lb::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod lm {
+		//! SysLink control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-syslink-control-reference-messages),
+		//! whose constants have [`LM`](crate::co::LM) prefix.
+
+		pub use super::super::comctl::messages::lm::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod lvm {
 		//! List view control
@@ -200,6 +260,15 @@ pub mod msg {
 		pub use super::super::comctl::messages::pbm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod rb {
+		//! Rebar control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-messages),
+		//! whose constants have [`RB`](crate::co::RB) prefix.
+
+		pub use super::super::comctl::messages::rb::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod sb {
 		//! Status bar control
@@ -248,6 +317,15 @@ pub mod msg {
 		pub use super::super::comctl::messages::trbm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod ttm {
+		//! Tooltip control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages),
+		//! whose constants have [`TTM`](crate::co::TTM) prefix.
+
+		pub use super::super::comctl::messages::ttm::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod tvm {
 		//! Tree view control
@@ -293,6 +371,7 @@ pub mod prelude {
 
 	#[cfg(feature = "advapi")] pub use super::advapi::traits::*;
 	#[cfg(feature = "comctl")] pub use super::comctl::traits::*;
+	#[cfg(feature = "dbghelp")] pub use super::dbghelp::traits::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::traits::*;
 	#[cfg(feature = "dwm")] pub use super::dwm::traits::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::traits::*;
@@ -300,8 +379,10 @@ pub mod prelude {
 	#[cfg(feature = "gui")] pub use super::gui::traits::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::traits::*;
 	#[cfg(feature = "mf")] pub use super::mf::traits::*;
+	#[cfg(feature = "netapi")] pub use super::netapi::traits::*;
 	#[cfg(feature = "ole")] pub use super::ole::traits::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::traits::*;
+	#[cfg(feature = "pdh")] pub use super::pdh::traits::*;
 	#[cfg(feature = "psapi")] pub use super::psapi::traits::*;
 	#[cfg(feature = "shell")] pub use super::shell::traits::*;
 	#[cfg(feature = "taskschd")] pub use super::taskschd::traits::*;
@@ -130,6 +130,31 @@ macro_rules! const_values_pub {
 	};
 }
 
+/// Writes multiple public values of a numeric newtype constant, like
+/// `const_values_pub`. If the `msg-table` feature is enabled, also writes a
+/// table with the value, the name and the doc comment of each constant.
+macro_rules! const_values_pub_msgs {
+	(
+		$name:ident, $table:ident;
+		$(
+			$( #[doc = $valdoc:literal] )*
+			$valname:ident $val:expr
+		)*
+	) => {
+		impl $name {
+			$(
+				$( #[doc = $valdoc] )*
+				pub const $valname: Self = unsafe { Self::from_raw($val) };
+			)*
+		}
+
+		#[cfg(feature = "msg-table")]
+		pub(crate) const $table: &[(u32, &str, &str)] = &[
+			$( ($val, stringify!($valname), concat!($( $valdoc, )* "")), )*
+		];
+	};
+}
+
 /// Writes multiple pub(crate) values of a numeric newtype constant.
 macro_rules! const_values_pubcrate {
 	(
@@ -278,7 +303,7 @@ macro_rules! const_cmd {
 		}
 		impl From<$name> for crate::co::CMD {
 			fn from(v: $name) -> Self {
-				Self(v.0)
+				unsafe { Self::from_raw(v.0) }
 			}
 		}
 	};
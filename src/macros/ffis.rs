@@ -11,7 +11,8 @@ macro_rules! one_func {
 	};
 }
 
-/// Builds a block of FFI bindings.
+/// Builds a block of FFI bindings. The DLLs are linked only when targeting
+/// Windows, so the pure Rust parts of the crate can be used elsewhere.
 macro_rules! extern_sys {
 	(
 		$dll:expr;
@@ -19,8 +20,8 @@ macro_rules! extern_sys {
 			$func:ident( $( $parm:ty ),* ) $( -> $ret:ty )?
 		)*
 	) => {
-		#[cfg_attr(not(feature = "raw-dylib"), link(name = $dll))]
-		#[cfg_attr(feature = "raw-dylib", link(name = $dll, kind = "raw-dylib"))]
+		#[cfg_attr(all(windows, not(feature = "raw-dylib")), link(name = $dll))]
+		#[cfg_attr(all(windows, feature = "raw-dylib"), link(name = $dll, kind = "raw-dylib"))]
 		extern "system" {
 			$(
 				one_func!( $func( $( $parm ),* ) $(-> $ret)? );
@@ -1,352 +1,700 @@
 use crate::co::*;
 
-const_values_pub! { HRESULT;
+const_values_pub_msgs! { HRESULT, HRESULT_MSGS_MF;
+	/// Platform not initialized. Please call MFStartup().
 	MF_E_PLATFORM_NOT_INITIALIZED 0xc00d_36b0
+	/// The buffer was too small to carry out the requested action.
 	MF_E_BUFFERTOOSMALL 0xc00d_36b1
+	/// The request is invalid in the current state.
 	MF_E_INVALIDREQUEST 0xc00d_36b2
+	/// The stream number provided was invalid.
 	MF_E_INVALIDSTREAMNUMBER 0xc00d_36b3
+	/// The data specified for the media type is invalid, inconsistent, or not supported by this object.
 	MF_E_INVALIDMEDIATYPE 0xc00d_36b4
+	/// The callee is currently not accepting further input.
 	MF_E_NOTACCEPTING 0xc00d_36b5
+	/// This object needs to be initialized before the requested operation can be carried out.
 	MF_E_NOT_INITIALIZED 0xc00d_36b6
+	/// The requested representation is not supported by this object.
 	MF_E_UNSUPPORTED_REPRESENTATION 0xc00d_36b7
+	/// An object ran out of media types to suggest therefore the requested chain of streaming objects cannot be completed.
 	MF_E_NO_MORE_TYPES 0xc00d_36b9
+	/// The object does not support the specified service.
 	MF_E_UNSUPPORTED_SERVICE 0xc00d_36ba
+	/// An unexpected error has occurred in the operation requested.
 	MF_E_UNEXPECTED 0xc00d_36bb
+	/// Invalid name.
 	MF_E_INVALIDNAME 0xc00d_36bc
+	/// Invalid type.
 	MF_E_INVALIDTYPE 0xc00d_36bd
+	/// The file does not conform to the relevant file format specification.
 	MF_E_INVALID_FILE_FORMAT 0xc00d_36be
+	/// Invalid index.
 	MF_E_INVALIDINDEX 0xc00d_36bf
+	/// An invalid timestamp was given.
 	MF_E_INVALID_TIMESTAMP 0xc00d_36c0
+	/// The scheme of the given URL is unsupported.
 	MF_E_UNSUPPORTED_SCHEME 0xc00d_36c3
+	/// The byte stream type of the given URL is unsupported.
 	MF_E_UNSUPPORTED_BYTESTREAM_TYPE 0xc00d_36c4
+	/// The given time format is unsupported.
 	MF_E_UNSUPPORTED_TIME_FORMAT 0xc00d_36c5
+	/// The Media Sample does not have a timestamp.
 	MF_E_NO_SAMPLE_TIMESTAMP 0xc00d_36c8
+	/// The Media Sample does not have a duration.
 	MF_E_NO_SAMPLE_DURATION 0xc00d_36c9
+	/// The request failed because the data in the stream is corrupt.
 	MF_E_INVALID_STREAM_DATA 0xc00d_36cb
+	/// Real time services are not available.
 	MF_E_RT_UNAVAILABLE 0xc00d_36cf
+	/// The specified rate is not supported.
 	MF_E_UNSUPPORTED_RATE 0xc00d_36d0
+	/// This component does not support stream-thinning.
 	MF_E_THINNING_UNSUPPORTED 0xc00d_36d1
+	/// The call failed because no reverse playback rates are available.
 	MF_E_REVERSE_UNSUPPORTED 0xc00d_36d2
+	/// The requested rate transition cannot occur in the current state.
 	MF_E_UNSUPPORTED_RATE_TRANSITION 0xc00d_36d3
+	/// The requested rate change has been pre-empted and will not occur.
 	MF_E_RATE_CHANGE_PREEMPTED 0xc00d_36d4
+	/// The specified object or value does not exist.
 	MF_E_NOT_FOUND 0xc00d_36d5
+	/// The requested value is not available.
 	MF_E_NOT_AVAILABLE 0xc00d_36d6
+	/// The specified operation requires a clock and no clock is available.
 	MF_E_NO_CLOCK 0xc00d_36d7
+	/// This callback and state had already been passed in to this event generator earlier.
 	MF_S_MULTIPLE_BEGIN 0x000d_36d8
+	/// This callback has already been passed in to this event generator.
 	MF_E_MULTIPLE_BEGIN 0xc00d_36d9
+	/// Some component is already listening to events on this event generator.
 	MF_E_MULTIPLE_SUBSCRIBERS 0xc00d_36da
+	/// This timer was orphaned before its callback time arrived.
 	MF_E_TIMER_ORPHANED 0xc00d_36db
+	/// A state transition is already pending.
 	MF_E_STATE_TRANSITION_PENDING 0xc00d_36dc
+	/// The requested state transition is unsupported.
 	MF_E_UNSUPPORTED_STATE_TRANSITION 0xc00d_36dd
+	/// An unrecoverable error has occurred.
 	MF_E_UNRECOVERABLE_ERROR_OCCURRED 0xc00d_36de
+	/// The provided sample has too many buffers.
 	MF_E_SAMPLE_HAS_TOO_MANY_BUFFERS 0xc00d_36df
+	/// The provided sample is not writable.
 	MF_E_SAMPLE_NOT_WRITABLE 0xc00d_36e0
+	/// The specified key is not valid.
 	MF_E_INVALID_KEY 0xc00d_36e2
+	/// You are calling MFStartup with the wrong MF_VERSION. Mismatched bits?
 	MF_E_BAD_STARTUP_VERSION 0xc00d_36e3
+	/// The caption of the given URL is unsupported.
 	MF_E_UNSUPPORTED_CAPTION 0xc00d_36e4
+	/// The operation on the current offset is not permitted.
 	MF_E_INVALID_POSITION 0xc00d_36e5
+	/// The requested attribute was not found.
 	MF_E_ATTRIBUTENOTFOUND 0xc00d_36e6
+	/// The specified property type is not allowed in this context.
 	MF_E_PROPERTY_TYPE_NOT_ALLOWED 0xc00d_36e7
+	/// The specified property type is not supported.
 	MF_E_PROPERTY_TYPE_NOT_SUPPORTED 0xc00d_36e8
+	/// The specified property is empty.
 	MF_E_PROPERTY_EMPTY 0xc00d_36e9
+	/// The specified property is not empty.
 	MF_E_PROPERTY_NOT_EMPTY 0xc00d_36ea
+	/// The vector property specified is not allowed in this context.
 	MF_E_PROPERTY_VECTOR_NOT_ALLOWED 0xc00d_36eb
+	/// A vector property is required in this context.
 	MF_E_PROPERTY_VECTOR_REQUIRED 0xc00d_36ec
+	/// The operation is cancelled.
 	MF_E_OPERATION_CANCELLED 0xc00d_36ed
+	/// The provided bytestream was expected to be seekable and it was not.
 	MF_E_BYTESTREAM_NOT_SEEKABLE 0xc00d_36ee
+	/// The Media Foundation platform is disabled when the system is running in Safe Mode.
 	MF_E_DISABLED_IN_SAFEMODE 0xc00d_36ef
+	/// The Media Source could not parse the byte stream.
 	MF_E_CANNOT_PARSE_BYTESTREAM 0xc00d_36f0
+	/// Mutually exclusive flags have been specified to source resolver. This flag combination is invalid.
 	MF_E_SOURCERESOLVER_MUTUALLY_EXCLUSIVE_FLAGS 0xc00d_36f1
+	/// MediaProc is in the wrong state.
 	MF_E_MEDIAPROC_WRONGSTATE 0xc00d_36f2
+	/// Real time I/O service can not provide requested throughput.
 	MF_E_RT_THROUGHPUT_NOT_AVAILABLE 0xc00d_36f3
+	/// The workqueue cannot be registered with more classes.
 	MF_E_RT_TOO_MANY_CLASSES 0xc00d_36f4
+	/// This operation cannot succeed because another thread owns this object.
 	MF_E_RT_WOULDBLOCK 0xc00d_36f5
+	/// Internal. Bitpump not found.
 	MF_E_NO_BITPUMP 0xc00d_36f6
+	/// No more RT memory available.
 	MF_E_RT_OUTOFMEMORY 0xc00d_36f7
+	/// An MMCSS class has not been set for this work queue.
 	MF_E_RT_WORKQUEUE_CLASS_NOT_SPECIFIED 0xc00d_36f8
+	/// Insufficient memory for response.
 	MF_E_INSUFFICIENT_BUFFER 0xc00d_7170
+	/// Activate failed to create mediasink. Call OutputNode::GetUINT32(MF_TOPONODE_MAJORTYPE) for more information.
 	MF_E_CANNOT_CREATE_SINK 0xc00d_36fa
+	/// The length of the provided bytestream is unknown.
 	MF_E_BYTESTREAM_UNKNOWN_LENGTH 0xc00d_36fb
+	/// The media session cannot pause from a stopped state.
 	MF_E_SESSION_PAUSEWHILESTOPPED 0xc00d_36fc
+	/// The activate could not be created in the remote process for some reason; it was replaced with an empty one.
 	MF_S_ACTIVATE_REPLACED 0x000d_36fd
+	/// The data specified for the media type is supported, but would require a format change, which is not supported by this object.
 	MF_E_FORMAT_CHANGE_NOT_SUPPORTED 0xc00d_36fe
+	/// The operation failed because an invalid combination of workqueue ID and flags was specified.
 	MF_E_INVALID_WORKQUEUE 0xc00d_36ff
+	/// No DRM support is available.
 	MF_E_DRM_UNSUPPORTED 0xc00d_3700
+	/// This operation is not authorized.
 	MF_E_UNAUTHORIZED 0xc00d_3701
+	/// The value is not in the specified or valid range.
 	MF_E_OUT_OF_RANGE 0xc00d_3702
+	/// The registered codec merit is not valid.
 	MF_E_INVALID_CODEC_MERIT 0xc00d_3703
+	/// Hardware MFT failed to start streaming due to lack of hardware resources.
 	MF_E_HW_MFT_FAILED_START_STREAMING 0xc00d_3704
+	/// The operation is already in progress.
 	MF_E_OPERATION_IN_PROGRESS 0xc00d_3705
+	/// No Hardware DRM support is available.
 	MF_E_HARDWARE_DRM_UNSUPPORTED 0xc00d_3706
+	/// The specified duration is too long.
 	MF_E_DURATION_TOO_LONG 0xc00d_3707
+	/// The operation failed because D3D_FEATURE_LEVEL_11_0 or higher is not available.
 	MF_E_OPERATION_UNSUPPORTED_AT_D3D_FEATURE_LEVEL 0xc00d_3708
+	/// The specified media type is not supported at the current D3D feature level.
 	MF_E_UNSUPPORTED_MEDIATYPE_AT_D3D_FEATURE_LEVEL 0xc00d_3709
+	/// Parsing is still in progress and is not yet complete.
 	MF_S_ASF_PARSEINPROGRESS 0x400d_3a98
+	/// Not enough data has been parsed to carry out the requested action.
 	MF_E_ASF_PARSINGINCOMPLETE 0xc00d_3a98
+	/// There is a gap in the ASF data provided.
 	MF_E_ASF_MISSINGDATA 0xc00d_3a99
+	/// The data provided are not valid ASF.
 	MF_E_ASF_INVALIDDATA 0xc00d_3a9a
+	/// The packet is opaque, so the requested information cannot be returned.
 	MF_E_ASF_OPAQUEPACKET 0xc00d_3a9b
+	/// The requested operation failed since there is no appropriate ASF index.
 	MF_E_ASF_NOINDEX 0xc00d_3a9c
+	/// The value supplied is out of range for this operation.
 	MF_E_ASF_OUTOFRANGE 0xc00d_3a9d
+	/// The index entry requested needs to be loaded before it can be available.
 	MF_E_ASF_INDEXNOTLOADED 0xc00d_3a9e
+	/// The packet has reached the maximum number of payloads.
 	MF_E_ASF_TOO_MANY_PAYLOADS 0xc00d_3a9f
+	/// Stream type is not supported.
 	MF_E_ASF_UNSUPPORTED_STREAM_TYPE 0xc00d_3aa0
+	/// One or more ASF packets were dropped.
 	MF_E_ASF_DROPPED_PACKET 0xc00d_3aa1
+	/// There are no events available in the queue.
 	MF_E_NO_EVENTS_AVAILABLE 0xc00d_3e80
+	/// A media source cannot go from the stopped state to the paused state.
 	MF_E_INVALID_STATE_TRANSITION 0xc00d_3e82
+	/// The media stream cannot process any more samples because there are no more samples in the stream.
 	MF_E_END_OF_STREAM 0xc00d_3e84
+	/// The request is invalid because Shutdown() has been called.
 	MF_E_SHUTDOWN 0xc00d_3e85
+	/// The MP3 object was not found.
 	MF_E_MP3_NOTFOUND 0xc00d_3e86
+	/// The MP3 parser ran out of data before finding the MP3 object.
 	MF_E_MP3_OUTOFDATA 0xc00d_3e87
+	/// The file is not really a MP3 file.
 	MF_E_MP3_NOTMP3 0xc00d_3e88
+	/// The MP3 file is not supported.
 	MF_E_MP3_NOTSUPPORTED 0xc00d_3e89
+	/// The Media stream has no duration.
 	MF_E_NO_DURATION 0xc00d_3e8a
+	/// The Media format is recognized but is invalid.
 	MF_E_INVALID_FORMAT 0xc00d_3e8c
+	/// The property requested was not found.
 	MF_E_PROPERTY_NOT_FOUND 0xc00d_3e8d
+	/// The property is read only.
 	MF_E_PROPERTY_READ_ONLY 0xc00d_3e8e
+	/// The specified property is not allowed in this context.
 	MF_E_PROPERTY_NOT_ALLOWED 0xc00d_3e8f
+	/// The media source is not started.
 	MF_E_MEDIA_SOURCE_NOT_STARTED 0xc00d_3e91
+	/// The Media format is recognized but not supported.
 	MF_E_UNSUPPORTED_FORMAT 0xc00d_3e98
+	/// The MPEG frame has bad CRC.
 	MF_E_MP3_BAD_CRC 0xc00d_3e99
+	/// The file is not protected.
 	MF_E_NOT_PROTECTED 0xc00d_3e9a
+	/// The media source is in the wrong state.
 	MF_E_MEDIA_SOURCE_WRONGSTATE 0xc00d_3e9b
+	/// The media stream cannot be started because no streams are selected.
 	MF_E_MEDIA_SOURCE_NO_STREAMS_SELECTED 0xc00d_3e9c
+	/// Cannot find the keyframe sample.
 	MF_E_CANNOT_FIND_KEYFRAME_SAMPLE 0xc00d_3e9d
+	/// The characteristics of the media source are not supported.
 	MF_E_UNSUPPORTED_CHARACTERISTICS 0xc00d_3e9e
+	/// No audio recording device was found.
 	MF_E_NO_AUDIO_RECORDING_DEVICE 0xc00d_3e9f
+	/// The requested audio recording device is currently in use.
 	MF_E_AUDIO_RECORDING_DEVICE_IN_USE 0xc00d_3ea0
+	/// The audio recording device is no longer present.
 	MF_E_AUDIO_RECORDING_DEVICE_INVALIDATED 0xc00d_3ea1
+	/// The video recording device is no longer present.
 	MF_E_VIDEO_RECORDING_DEVICE_INVALIDATED 0xc00d_3ea2
+	/// The video recording device is preempted by another immersive application.
 	MF_E_VIDEO_RECORDING_DEVICE_PREEMPTED 0xc00d_3ea3
+	/// An attempt to acquire a network resource failed.
 	MF_E_NETWORK_RESOURCE_FAILURE 0xc00d_4268
+	/// Error writing to the network.
 	MF_E_NET_WRITE 0xc00d_4269
+	/// Error reading from the network.
 	MF_E_NET_READ 0xc00d_426a
+	/// Internal. Entry cannot complete operation without network.
 	MF_E_NET_REQUIRE_NETWORK 0xc00d_426b
+	/// Internal. Async op is required.
 	MF_E_NET_REQUIRE_ASYNC 0xc00d_426c
+	/// Internal. Bandwidth levels are not supported.
 	MF_E_NET_BWLEVEL_NOT_SUPPORTED 0xc00d_426d
+	/// Internal. Stream groups are not supported.
 	MF_E_NET_STREAMGROUPS_NOT_SUPPORTED 0xc00d_426e
+	/// Manual stream selection is not supported.
 	MF_E_NET_MANUALSS_NOT_SUPPORTED 0xc00d_426f
+	/// Invalid presentation descriptor.
 	MF_E_NET_INVALID_PRESENTATION_DESCRIPTOR 0xc00d_4270
+	/// Cannot find the cache stream.
 	MF_E_NET_CACHESTREAM_NOT_FOUND 0xc00d_4271
+	/// The proxy setting is manual.
 	MF_I_MANUAL_PROXY 0x400d_4272
+	/// Internal. Entry cannot complete operation without input.
 	MF_E_NET_REQUIRE_INPUT 0xc00d_4274
+	/// The client redirected to another server.
 	MF_E_NET_REDIRECT 0xc00d_4275
+	/// The client is redirected to a proxy server.
 	MF_E_NET_REDIRECT_TO_PROXY 0xc00d_4276
+	/// The client reached the maximum redirection limit.
 	MF_E_NET_TOO_MANY_REDIRECTS 0xc00d_4277
+	/// The server, a computer set up to offer multimedia content to other computers, could not handle your request for multimedia content in a timely manner. Please try again later.
 	MF_E_NET_TIMEOUT 0xc00d_4278
+	/// The control socket is closed by the client.
 	MF_E_NET_CLIENT_CLOSE 0xc00d_4279
+	/// The server received invalid data from the client on the control connection.
 	MF_E_NET_BAD_CONTROL_DATA 0xc00d_427a
+	/// The server is not a compatible streaming media server.
 	MF_E_NET_INCOMPATIBLE_SERVER 0xc00d_427b
+	/// Url.
 	MF_E_NET_UNSAFE_URL 0xc00d_427c
+	/// Data is not available.
 	MF_E_NET_CACHE_NO_DATA 0xc00d_427d
+	/// End of line.
 	MF_E_NET_EOL 0xc00d_427e
+	/// The request could not be understood by the server.
 	MF_E_NET_BAD_REQUEST 0xc00d_427f
+	/// The server encountered an unexpected condition which prevented it from fulfilling the request.
 	MF_E_NET_INTERNAL_SERVER_ERROR 0xc00d_4280
+	/// Session not found.
 	MF_E_NET_SESSION_NOT_FOUND 0xc00d_4281
+	/// There is no connection established with the Windows Media server. The operation failed.
 	MF_E_NET_NOCONNECTION 0xc00d_4282
+	/// The network connection has failed.
 	MF_E_NET_CONNECTION_FAILURE 0xc00d_4283
+	/// The Server service that received the HTTP push request is not a compatible version of Windows Media Services (WMS). This error may indicate the push request was received by IIS instead of WMS. Ensure WMS is started and has the HTTP Server control protocol properly enabled and try again.
 	MF_E_NET_INCOMPATIBLE_PUSHSERVER 0xc00d_4284
+	/// The Windows Media server is denying access. The username and/or password might be incorrect.
 	MF_E_NET_SERVER_ACCESSDENIED 0xc00d_4285
+	/// The proxy server is denying access. The username and/or password might be incorrect.
 	MF_E_NET_PROXY_ACCESSDENIED 0xc00d_4286
+	/// Unable to establish a connection to the server.
 	MF_E_NET_CANNOTCONNECT 0xc00d_4287
+	/// The specified push template is invalid.
 	MF_E_NET_INVALID_PUSH_TEMPLATE 0xc00d_4288
+	/// The specified push publishing point is invalid.
 	MF_E_NET_INVALID_PUSH_PUBLISHING_POINT 0xc00d_4289
+	/// The requested resource is in use.
 	MF_E_NET_BUSY 0xc00d_428a
+	/// The Publishing Point or file on the Windows Media Server is no longer available.
 	MF_E_NET_RESOURCE_GONE 0xc00d_428b
+	/// The proxy experienced an error while attempting to contact the media server.
 	MF_E_NET_ERROR_FROM_PROXY 0xc00d_428c
+	/// The proxy did not receive a timely response while attempting to contact the media server.
 	MF_E_NET_PROXY_TIMEOUT 0xc00d_428d
+	/// The server is currently unable to handle the request due to a temporary overloading or maintenance of the server.
 	MF_E_NET_SERVER_UNAVAILABLE 0xc00d_428e
+	/// The encoding process was unable to keep up with the amount of supplied data.
 	MF_E_NET_TOO_MUCH_DATA 0xc00d_428f
+	/// Session not found.
 	MF_E_NET_SESSION_INVALID 0xc00d_4290
+	/// The requested URL is not available in offline mode.
 	MF_E_OFFLINE_MODE 0xc00d_4291
+	/// A device in the network is blocking UDP traffic.
 	MF_E_NET_UDP_BLOCKED 0xc00d_4292
+	/// The specified configuration value is not supported.
 	MF_E_NET_UNSUPPORTED_CONFIGURATION 0xc00d_4293
+	/// The networking protocol is disabled.
 	MF_E_NET_PROTOCOL_DISABLED 0xc00d_4294
+	/// The companion driver asked the OS to disconnect from the receiver.
 	MF_E_NET_COMPANION_DRIVER_DISCONNECT 0xc00d_4295
+	/// This object has already been initialized and cannot be re-initialized at this time.
 	MF_E_ALREADY_INITIALIZED 0xc00d_4650
+	/// The amount of data passed in exceeds the given bitrate and buffer window.
 	MF_E_BANDWIDTH_OVERRUN 0xc00d_4651
+	/// The sample was passed in too late to be correctly processed.
 	MF_E_LATE_SAMPLE 0xc00d_4652
+	/// The requested action cannot be carried out until the object is flushed and the queue is emptied.
 	MF_E_FLUSH_NEEDED 0xc00d_4653
+	/// The profile is invalid.
 	MF_E_INVALID_PROFILE 0xc00d_4654
+	/// The index that is being generated needs to be committed before the requested action can be carried out.
 	MF_E_INDEX_NOT_COMMITTED 0xc00d_4655
+	/// The index that is necessary for the requested action is not found.
 	MF_E_NO_INDEX 0xc00d_4656
+	/// The requested index cannot be added in-place to the specified ASF content.
 	MF_E_CANNOT_INDEX_IN_PLACE 0xc00d_4657
+	/// The ASF leaky bucket parameters must be specified in order to carry out this request.
 	MF_E_MISSING_ASF_LEAKYBUCKET 0xc00d_4658
+	/// The stream id is invalid. The valid range for ASF stream id is from 1 to 127.
 	MF_E_INVALID_ASF_STREAMID 0xc00d_4659
+	/// The requested Stream Sink has been removed and cannot be used.
 	MF_E_STREAMSINK_REMOVED 0xc00d_4a38
+	/// The various Stream Sinks in this Media Sink are too far out of sync for the requested action to take place.
 	MF_E_STREAMSINKS_OUT_OF_SYNC 0xc00d_4a3a
+	/// Stream Sinks cannot be added to or removed from this Media Sink because its set of streams is fixed.
 	MF_E_STREAMSINKS_FIXED 0xc00d_4a3b
+	/// The given Stream Sink already exists.
 	MF_E_STREAMSINK_EXISTS 0xc00d_4a3c
+	/// Sample allocations have been canceled.
 	MF_E_SAMPLEALLOCATOR_CANCELED 0xc00d_4a3d
+	/// The sample allocator is currently empty, due to outstanding requests.
 	MF_E_SAMPLEALLOCATOR_EMPTY 0xc00d_4a3e
+	/// When IMFMediaSink::Stop is called, the Media Sink must be in the started or paused state.
 	MF_E_SINK_ALREADYSTOPPED 0xc00d_4a3f
+	/// The ASF file sink could not reserve AVIO because the bitrate is unknown.
 	MF_E_ASF_FILESINK_BITRATE_UNKNOWN 0xc00d_4a40
+	/// The media sink has no streams.
 	MF_E_SINK_NO_STREAMS 0xc00d_4a41
+	/// The stream sink was not finalized before shut down. This may cause the sink to generate corrupt content.
 	MF_S_SINK_NOT_FINALIZED 0x000d_4a42
+	/// A metadata item was too long to write to the output container.
 	MF_E_METADATA_TOO_LONG 0xc00d_4a43
+	/// The operation failed because no samples were processed by the sink.
 	MF_E_SINK_NO_SAMPLES_PROCESSED 0xc00d_4a44
+	/// Sink has not processed any samples and therefore could not write the headers.
 	MF_E_SINK_HEADERS_NOT_FOUND 0xc00d_4a45
+	/// There is no available procamp hardware with which to perform color correction.
 	MF_E_VIDEO_REN_NO_PROCAMP_HW 0xc00d_4e20
+	/// There is no available deinterlacing hardware with which to deinterlace the video stream.
 	MF_E_VIDEO_REN_NO_DEINTERLACE_HW 0xc00d_4e21
+	/// A video stream requires copy protection to be enabled, but there was a failure in attempting to enable copy protection.
 	MF_E_VIDEO_REN_COPYPROT_FAILED 0xc00d_4e22
+	/// A component is attempting to access a surface for sharing that is not shared.
 	MF_E_VIDEO_REN_SURFACE_NOT_SHARED 0xc00d_4e23
+	/// A component is attempting to access a shared device that is already locked by another component.
 	MF_E_VIDEO_DEVICE_LOCKED 0xc00d_4e24
+	/// The device is no longer available. The handle should be closed and a new one opened.
 	MF_E_NEW_VIDEO_DEVICE 0xc00d_4e25
+	/// A video sample is not currently queued on a stream that is required for mixing.
 	MF_E_NO_VIDEO_SAMPLE_AVAILABLE 0xc00d_4e26
+	/// No audio playback device was found.
 	MF_E_NO_AUDIO_PLAYBACK_DEVICE 0xc00d_4e84
+	/// The requested audio playback device is currently in use.
 	MF_E_AUDIO_PLAYBACK_DEVICE_IN_USE 0xc00d_4e85
+	/// The audio playback device is no longer present.
 	MF_E_AUDIO_PLAYBACK_DEVICE_INVALIDATED 0xc00d_4e86
+	/// The audio service is not running.
 	MF_E_AUDIO_SERVICE_NOT_RUNNING 0xc00d_4e87
+	/// The audio renderer was unable to allocate a buffer of the requested size.
 	MF_E_AUDIO_BUFFER_SIZE_ERROR 0xc00d_4e88
+	/// The audio client wrapper detected an attempt to spoof the audio client.
 	MF_E_AUDIO_CLIENT_WRAPPER_SPOOF_ERROR 0xc00d_4e89
+	/// The topology contains an invalid optional node. Possible reasons are incorrect number of outputs and inputs or optional node is at the beginning or end of a segment.
 	MF_E_TOPO_INVALID_OPTIONAL_NODE 0xc00d_520e
+	/// No suitable transform was found to decrypt the content.
 	MF_E_TOPO_CANNOT_FIND_DECRYPTOR 0xc00d_5211
+	/// No suitable transform was found to encode or decode the content.
 	MF_E_TOPO_CODEC_NOT_FOUND 0xc00d_5212
+	/// Unable to find a way to connect nodes.
 	MF_E_TOPO_CANNOT_CONNECT 0xc00d_5213
+	/// Unsupported operations in the topoloader.
 	MF_E_TOPO_UNSUPPORTED 0xc00d_5214
+	/// The topology or its nodes contain incorrectly set time attributes.
 	MF_E_TOPO_INVALID_TIME_ATTRIBUTES 0xc00d_5215
+	/// The topology contains loops, which are not supported in media foundation topologies.
 	MF_E_TOPO_LOOPS_IN_TOPOLOGY 0xc00d_5216
+	/// A source stream node in the topology does not have a presentation descriptor.
 	MF_E_TOPO_MISSING_PRESENTATION_DESCRIPTOR 0xc00d_5217
+	/// A source stream node in the topology does not have a stream descriptor.
 	MF_E_TOPO_MISSING_STREAM_DESCRIPTOR 0xc00d_5218
+	/// A stream descriptor was set on a source stream node but it was not selected on the presentation descriptor.
 	MF_E_TOPO_STREAM_DESCRIPTOR_NOT_SELECTED 0xc00d_5219
+	/// A source stream node in the topology does not have a source.
 	MF_E_TOPO_MISSING_SOURCE 0xc00d_521a
+	/// The topology loader does not support sink activates on output nodes.
 	MF_E_TOPO_SINK_ACTIVATES_UNSUPPORTED 0xc00d_521b
+	/// The sequencer cannot find a segment with the given ID.
 	MF_E_SEQUENCER_UNKNOWN_SEGMENT_ID 0xc00d_61ac
+	/// The context was canceled.
 	MF_S_SEQUENCER_CONTEXT_CANCELED 0x000d_61ad
+	/// Cannot find source in the source cache.
 	MF_E_NO_SOURCE_IN_CACHE 0xc00d_61ae
+	/// Cannot update topology flags.
 	MF_S_SEQUENCER_SEGMENT_AT_END_OF_STREAM 0x000d_61af
+	/// A valid type has not been set for this stream or a stream that it depends on.
 	MF_E_TRANSFORM_TYPE_NOT_SET 0xc00d_6d60
+	/// A stream change has occurred. Output cannot be produced until the streams have been renegotiated.
 	MF_E_TRANSFORM_STREAM_CHANGE 0xc00d_6d61
+	/// The transform cannot take the requested action until all of the input data it currently holds is processed or flushed.
 	MF_E_TRANSFORM_INPUT_REMAINING 0xc00d_6d62
+	/// The transform requires a profile but no profile was supplied or found.
 	MF_E_TRANSFORM_PROFILE_MISSING 0xc00d_6d63
+	/// The transform requires a profile but the supplied profile was invalid or corrupt.
 	MF_E_TRANSFORM_PROFILE_INVALID_OR_CORRUPT 0xc00d_6d64
+	/// The transform requires a profile but the supplied profile ended unexpectedly while parsing.
 	MF_E_TRANSFORM_PROFILE_TRUNCATED 0xc00d_6d65
+	/// The property ID does not match any property supported by the transform.
 	MF_E_TRANSFORM_PROPERTY_PID_NOT_RECOGNIZED 0xc00d_6d66
+	/// The variant does not have the type expected for this property ID.
 	MF_E_TRANSFORM_PROPERTY_VARIANT_TYPE_WRONG 0xc00d_6d67
+	/// An attempt was made to set the value on a read-only property.
 	MF_E_TRANSFORM_PROPERTY_NOT_WRITEABLE 0xc00d_6d68
+	/// The array property value has an unexpected number of dimensions.
 	MF_E_TRANSFORM_PROPERTY_ARRAY_VALUE_WRONG_NUM_DIM 0xc00d_6d69
+	/// The array or blob property value has an unexpected size.
 	MF_E_TRANSFORM_PROPERTY_VALUE_SIZE_WRONG 0xc00d_6d6a
+	/// The property value is out of range for this transform.
 	MF_E_TRANSFORM_PROPERTY_VALUE_OUT_OF_RANGE 0xc00d_6d6b
+	/// The property value is incompatible with some other property or media type set on the transform.
 	MF_E_TRANSFORM_PROPERTY_VALUE_INCOMPATIBLE 0xc00d_6d6c
+	/// The requested operation is not supported for the currently set output media type.
 	MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_OUTPUT_MEDIATYPE 0xc00d_6d6d
+	/// The requested operation is not supported for the currently set input media type.
 	MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_INPUT_MEDIATYPE 0xc00d_6d6e
+	/// The requested operation is not supported for the currently set combination of media types.
 	MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_MEDIATYPE_COMBINATION 0xc00d_6d6f
+	/// The requested feature is not supported in combination with some other currently enabled feature.
 	MF_E_TRANSFORM_CONFLICTS_WITH_OTHER_CURRENTLY_ENABLED_FEATURES 0xc00d_6d70
+	/// The transform cannot produce output until it gets more input samples.
 	MF_E_TRANSFORM_NEED_MORE_INPUT 0xc00d_6d72
+	/// The requested operation is not supported for the current speaker configuration.
 	MF_E_TRANSFORM_NOT_POSSIBLE_FOR_CURRENT_SPKR_CONFIG 0xc00d_6d73
+	/// The transform cannot accept media type changes in the middle of processing.
 	MF_E_TRANSFORM_CANNOT_CHANGE_MEDIATYPE_WHILE_PROCESSING 0xc00d_6d74
+	/// The caller should not propagate this event to downstream components.
 	MF_S_TRANSFORM_DO_NOT_PROPAGATE_EVENT 0x000d_6d75
+	/// The input type is not supported for D3D device.
 	MF_E_UNSUPPORTED_D3D_TYPE 0xc00d_6d76
+	/// The caller does not appear to support this transform's asynchronous capabilities.
 	MF_E_TRANSFORM_ASYNC_LOCKED 0xc00d_6d77
+	/// An audio compression manager driver could not be initialized by the transform.
 	MF_E_TRANSFORM_CANNOT_INITIALIZE_ACM_DRIVER 0xc00d_6d78
+	/// The input stream has invalid and illegal resolution. Output should stop on next ProcessOutput call after the invalid and illegal resolution is detected.
 	MF_E_TRANSFORM_STREAM_INVALID_RESOLUTION 0xc00d_6d79
+	/// The transform cannot be asynchronous in current context.
 	MF_E_TRANSFORM_ASYNC_MFT_NOT_SUPPORTED 0xc00d_6d7a
+	/// It is not supported in the current context to have the transform copy attributes from an input sample to an output sample.
 	MF_E_TRANSFORM_EXATTRIBUTE_NOT_SUPPORTED 0xc00d_6d7c
+	/// You are not allowed to open this file. Contact the content provider for further assistance.
 	MF_E_LICENSE_INCORRECT_RIGHTS 0xc00d_7148
+	/// The license for this media file has expired. Get a new license or contact the content provider for further assistance.
 	MF_E_LICENSE_OUTOFDATE 0xc00d_7149
+	/// You need a license to perform the requested operation on this media file.
 	MF_E_LICENSE_REQUIRED 0xc00d_714a
+	/// The licenses for your media files are corrupted. Contact Microsoft product support.
 	MF_E_DRM_HARDWARE_INCONSISTENT 0xc00d_714b
+	/// The APP needs to provide IMFContentProtectionManager callback to access the protected media file.
 	MF_E_NO_CONTENT_PROTECTION_MANAGER 0xc00d_714c
+	/// Client does not have rights to restore licenses.
 	MF_E_LICENSE_RESTORE_NO_RIGHTS 0xc00d_714d
+	/// Licenses are restricted and hence can not be backed up.
 	MF_E_BACKUP_RESTRICTED_LICENSE 0xc00d_714e
+	/// License restore requires the machine to be individualized.
 	MF_E_LICENSE_RESTORE_NEEDS_INDIVIDUALIZATION 0xc00d_714f
+	/// Protection for stream is not required.
 	MF_S_PROTECTION_NOT_REQUIRED 0x000d_7150
+	/// Component is revoked.
 	MF_E_COMPONENT_REVOKED 0xc00d_7151
+	/// Trusted functionality is currently disabled on this component.
 	MF_E_TRUST_DISABLED 0xc00d_7152
+	/// No Action is set on WMDRM Output Trust Authority.
 	MF_E_WMDRMOTA_NO_ACTION 0xc00d_7153
+	/// Action is already set on WMDRM Output Trust Authority.
 	MF_E_WMDRMOTA_ACTION_ALREADY_SET 0xc00d_7154
+	/// DRM Header is not available.
 	MF_E_WMDRMOTA_DRM_HEADER_NOT_AVAILABLE 0xc00d_7155
+	/// Current encryption scheme is not supported.
 	MF_E_WMDRMOTA_DRM_ENCRYPTION_SCHEME_NOT_SUPPORTED 0xc00d_7156
+	/// Action does not match with current configuration.
 	MF_E_WMDRMOTA_ACTION_MISMATCH 0xc00d_7157
+	/// Invalid policy for WMDRM Output Trust Authority.
 	MF_E_WMDRMOTA_INVALID_POLICY 0xc00d_7158
+	/// The policies that the Input Trust Authority requires to be enforced are unsupported by the outputs.
 	MF_E_POLICY_UNSUPPORTED 0xc00d_7159
+	/// The OPL that the license requires to be enforced are not supported by the Input Trust Authority.
 	MF_E_OPL_NOT_SUPPORTED 0xc00d_715a
+	/// The topology could not be successfully verified.
 	MF_E_TOPOLOGY_VERIFICATION_FAILED 0xc00d_715b
+	/// Signature verification could not be completed successfully for this component.
 	MF_E_SIGNATURE_VERIFICATION_FAILED 0xc00d_715c
+	/// Running this process under a debugger while using protected content is not allowed.
 	MF_E_DEBUGGING_NOT_ALLOWED 0xc00d_715d
+	/// MF component has expired.
 	MF_E_CODE_EXPIRED 0xc00d_715e
+	/// The current GRL on the machine does not meet the minimum version requirements.
 	MF_E_GRL_VERSION_TOO_LOW 0xc00d_715f
+	/// The current GRL on the machine does not contain any renewal entries for the specified revocation.
 	MF_E_GRL_RENEWAL_NOT_FOUND 0xc00d_7160
+	/// The current GRL on the machine does not contain any extensible entries for the specified extension GUID.
 	MF_E_GRL_EXTENSIBLE_ENTRY_NOT_FOUND 0xc00d_7161
+	/// The kernel isn't secure for high security level content.
 	MF_E_KERNEL_UNTRUSTED 0xc00d_7162
+	/// The response from the protected environment driver isn't valid.
 	MF_E_PEAUTH_UNTRUSTED 0xc00d_7163
+	/// A non-PE process tried to talk to PEAuth.
 	MF_E_NON_PE_PROCESS 0xc00d_7165
+	/// We need to reboot the machine.
 	MF_E_REBOOT_REQUIRED 0xc00d_7167
+	/// Protection for this stream is not guaranteed to be enforced until the MEPolicySet event is fired.
 	MF_S_WAIT_FOR_POLICY_SET 0x000d_7168
+	/// This video stream is disabled because it is being sent to an unknown software output.
 	MF_S_VIDEO_DISABLED_WITH_UNKNOWN_SOFTWARE_OUTPUT 0x000d_7169
+	/// The GRL file is not correctly formed, it may have been corrupted or overwritten.
 	MF_E_GRL_INVALID_FORMAT 0xc00d_716a
+	/// The GRL file is in a format newer than those recognized by this GRL Reader.
 	MF_E_GRL_UNRECOGNIZED_FORMAT 0xc00d_716b
+	/// The GRL was reloaded and required all processes that can run protected media to restart.
 	MF_E_ALL_PROCESS_RESTART_REQUIRED 0xc00d_716c
+	/// The GRL was reloaded and the current process needs to restart.
 	MF_E_PROCESS_RESTART_REQUIRED 0xc00d_716d
+	/// The user space is untrusted for protected content play.
 	MF_E_USERMODE_UNTRUSTED 0xc00d_716e
+	/// PEAuth communication session hasn't been started.
 	MF_E_PEAUTH_SESSION_NOT_STARTED 0xc00d_716f
+	/// PEAuth's public key is revoked.
 	MF_E_PEAUTH_PUBLICKEY_REVOKED 0xc00d_7171
+	/// The GRL is absent.
 	MF_E_GRL_ABSENT 0xc00d_7172
+	/// The Protected Environment is trusted.
 	MF_S_PE_TRUSTED 0x000d_7173
+	/// The Protected Environment is untrusted.
 	MF_E_PE_UNTRUSTED 0xc00d_7174
+	/// The Protected Environment Authorization service (PEAUTH) has not been started.
 	MF_E_PEAUTH_NOT_STARTED 0xc00d_7175
+	/// The sample protection algorithms supported by components are not compatible.
 	MF_E_INCOMPATIBLE_SAMPLE_PROTECTION 0xc00d_7176
+	/// No more protected environment sessions can be supported.
 	MF_E_PE_SESSIONS_MAXED 0xc00d_7177
+	/// WMDRM ITA does not allow protected content with high security level for this release.
 	MF_E_HIGH_SECURITY_LEVEL_CONTENT_NOT_ALLOWED 0xc00d_7178
+	/// WMDRM ITA cannot allow the requested action for the content as one or more components is not properly signed.
 	MF_E_TEST_SIGNED_COMPONENTS_NOT_ALLOWED 0xc00d_7179
+	/// WMDRM ITA does not support the requested action.
 	MF_E_ITA_UNSUPPORTED_ACTION 0xc00d_717a
+	/// WMDRM ITA encountered an error in parsing the Secure Audio Path parameters.
 	MF_E_ITA_ERROR_PARSING_SAP_PARAMETERS 0xc00d_717b
+	/// The Policy Manager action passed in is invalid.
 	MF_E_POLICY_MGR_ACTION_OUTOFBOUNDS 0xc00d_717c
+	/// The structure specifying Output Protection Level is not the correct format.
 	MF_E_BAD_OPL_STRUCTURE_FORMAT 0xc00d_717d
+	/// WMDRM ITA does not recognize the Explicite Analog Video Output Protection guid specified in the license.
 	MF_E_ITA_UNRECOGNIZED_ANALOG_VIDEO_PROTECTION_GUID 0xc00d_717e
+	/// IMFPMPHost object not available.
 	MF_E_NO_PMP_HOST 0xc00d_717f
+	/// WMDRM ITA could not initialize the Output Protection Level data.
 	MF_E_ITA_OPL_DATA_NOT_INITIALIZED 0xc00d_7180
+	/// WMDRM ITA does not recognize the Analog Video Output specified by the OTA.
 	MF_E_ITA_UNRECOGNIZED_ANALOG_VIDEO_OUTPUT 0xc00d_7181
+	/// WMDRM ITA does not recognize the Digital Video Output specified by the OTA.
 	MF_E_ITA_UNRECOGNIZED_DIGITAL_VIDEO_OUTPUT 0xc00d_7182
+	/// The protected stream cannot be resolved without the callback PKEY_PMP_Creation_Callback in the configuration property store.
 	MF_E_RESOLUTION_REQUIRES_PMP_CREATION_CALLBACK 0xc00d_7183
+	/// A valid hostname and port number could not be found in the DTCP parameters.
 	MF_E_INVALID_AKE_CHANNEL_PARAMETERS 0xc00d_7184
+	/// The content protection system was not enabled by the application.
 	MF_E_CONTENT_PROTECTION_SYSTEM_NOT_ENABLED 0xc00d_7185
+	/// The content protection system is not supported.
 	MF_E_UNSUPPORTED_CONTENT_PROTECTION_SYSTEM 0xc00d_7186
+	/// DRM migration is not supported for the content.
 	MF_E_DRM_MIGRATION_NOT_SUPPORTED 0xc00d_7187
+	/// Authentication of the HDCP link failed.
 	MF_E_HDCP_AUTHENTICATION_FAILURE 0xc00d_7188
+	/// The HDCP link failed after being established.
 	MF_E_HDCP_LINK_FAILURE 0xc00d_7189
+	/// The continuity key supplied is not currently valid.
 	MF_E_CLOCK_INVALID_CONTINUITY_KEY 0xc00d_9c40
+	/// No Presentation Time Source has been specified.
 	MF_E_CLOCK_NO_TIME_SOURCE 0xc00d_9c41
+	/// The clock is already in the requested state.
 	MF_E_CLOCK_STATE_ALREADY_SET 0xc00d_9c42
+	/// The clock has too many time sources to be converted to a simple clock.
 	MF_E_CLOCK_NOT_SIMPLE 0xc00d_9c43
+	/// Timer::SetTimer returns this success code if called happened while timer is stopped. Timer is not going to be dispatched until clock is running.
 	MF_S_CLOCK_STOPPED 0x000d_9c44
+	/// The clock position could not be determined due to unexpected IAudioClock::GetPosition behavior.
 	MF_E_CLOCK_AUDIO_DEVICE_POSITION_UNEXPECTED 0x000d_9c45
+	/// The clock position could not be determined due to unexpected audio render position behavior.
 	MF_E_CLOCK_AUDIO_RENDER_POSITION_UNEXPECTED 0x000d_9c46
+	/// The clock position could not be determined due to unexpected audio render time behavior.
 	MF_E_CLOCK_AUDIO_RENDER_TIME_UNEXPECTED 0x000d_9c47
+	/// The component does not support any more drop modes.
 	MF_E_NO_MORE_DROP_MODES 0xc00d_a028
+	/// The component does not support any more quality levels.
 	MF_E_NO_MORE_QUALITY_LEVELS 0xc00d_a029
+	/// The component does not support drop time functionality.
 	MF_E_DROPTIME_NOT_SUPPORTED 0xc00d_a02a
+	/// The Quality Manager needs to wait longer before bumping the Quality Level up.
 	MF_E_QUALITYKNOB_WAIT_LONGER 0xc00d_a02b
+	/// The Quality Manager is in an invalid state. Quality Management is off at this moment.
 	MF_E_QM_INVALIDSTATE 0xc00d_a02c
+	/// No transcode output container type is specified.
 	MF_E_TRANSCODE_NO_CONTAINERTYPE 0xc00d_a410
+	/// The profile does not have a media type configuration for any selected source streams.
 	MF_E_TRANSCODE_PROFILE_NO_MATCHING_STREAMS 0xc00d_a411
+	/// Cannot find an encoder MFT that accepts the user preferred output type.
 	MF_E_TRANSCODE_NO_MATCHING_ENCODER 0xc00d_a412
+	/// The profile is invalid.
 	MF_E_TRANSCODE_INVALID_PROFILE 0xc00d_a413
+	/// Memory allocator is not initialized.
 	MF_E_ALLOCATOR_NOT_INITIALIZED 0xc00d_a7f8
+	/// Memory allocator is not committed yet.
 	MF_E_ALLOCATOR_NOT_COMMITED 0xc00d_a7f9
+	/// Memory allocator has already been committed.
 	MF_E_ALLOCATOR_ALREADY_COMMITED 0xc00d_a7fa
+	/// An error occurred in the media stream.
 	MF_E_STREAM_ERROR 0xc00d_a7fb
+	/// The stream is not in a state to handle the request.
 	MF_E_INVALID_STREAM_STATE 0xc00d_a7fc
+	/// The hardware stream is not connected yet.
 	MF_E_HW_STREAM_NOT_CONNECTED 0xc00d_a7fd
+	/// No capture devices are available.
 	MF_E_NO_CAPTURE_DEVICES_AVAILABLE 0xc00d_abe0
+	/// No output was set for recording.
 	MF_E_CAPTURE_SINK_OUTPUT_NOT_SET 0xc00d_abe1
+	/// The current capture sink configuration does not support mirroring.
 	MF_E_CAPTURE_SINK_MIRROR_ERROR 0xc00d_abe2
+	/// The current capture sink configuration does not support rotation.
 	MF_E_CAPTURE_SINK_ROTATE_ERROR 0xc00d_abe3
+	/// The op is invalid.
 	MF_E_CAPTURE_ENGINE_INVALID_OP 0xc00d_abe4
+	/// The effects previously added were incompatible with the new topology which caused all effects to be removed.
 	MF_E_CAPTURE_ENGINE_ALL_EFFECTS_REMOVED 0xc00d_abe5
+	/// The current capture source does not have an independent photo stream.
 	MF_E_CAPTURE_SOURCE_NO_INDEPENDENT_PHOTO_STREAM_PRESENT 0xc00d_abe6
+	/// The current capture source does not have a video stream.
 	MF_E_CAPTURE_SOURCE_NO_VIDEO_STREAM_PRESENT 0xc00d_abe7
+	/// The current capture source does not have an audio stream.
 	MF_E_CAPTURE_SOURCE_NO_AUDIO_STREAM_PRESENT 0xc00d_abe8
+	/// The capture source device has an asynchronous extended property operation in progress.
 	MF_E_CAPTURE_SOURCE_DEVICE_EXTENDEDPROP_OP_IN_PROGRESS 0xc00d_abe9
+	/// A property cannot be set because a photo or photo sequence is in progress.
 	MF_E_CAPTURE_PROPERTY_SET_DURING_PHOTO 0xc00d_abea
+	/// No more samples in queue.
 	MF_E_CAPTURE_NO_SAMPLES_IN_QUEUE 0xc00d_abeb
+	/// Hardware accelerated thumbnail generation is not supported for the currently selected mediatype on the mediacapture stream.
 	MF_E_HW_ACCELERATED_THUMBNAIL_NOT_SUPPORTED 0xc00d_abec
+	/// Capture device that is present on the system is not supported by Media Foundation.
 	MF_E_UNSUPPORTED_CAPTURE_DEVICE_PRESENT 0xc00d_abed
+	/// Media Source type is not supported in Media Timeline Controller scenarios.
 	MF_E_TIMELINECONTROLLER_UNSUPPORTED_SOURCE_TYPE 0xc00d_afc8
+	/// Operation is not allowed when Media Timeline Controller is attached.
 	MF_E_TIMELINECONTROLLER_NOT_ALLOWED 0xc00d_afc9
+	/// Attaching Media Timeline Controller is blocked because of the current state of the object.
 	MF_E_TIMELINECONTROLLER_CANNOT_ATTACH 0xc00d_afca
+	/// Connection to app service providing a media extension failed.
 	MF_E_MEDIA_EXTENSION_APPSERVICE_CONNECTION_FAILED 0xc00d_b3b0
+	/// App service providing a media extension failed to process the request.
 	MF_E_MEDIA_EXTENSION_APPSERVICE_REQUEST_FAILED 0xc00d_b3b1
+	/// Package integrity check for app failed.
 	MF_E_MEDIA_EXTENSION_PACKAGE_INTEGRITY_CHECK_FAILED 0xc00d_b3b2
+	/// License check for app failed.
 	MF_E_MEDIA_EXTENSION_PACKAGE_LICENSE_INVALID 0xc00d_b3b3
 }
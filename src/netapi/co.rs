@@ -0,0 +1,81 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::*;
+
+const_values_pub! { ERROR;
+	/// The user name or group name parameter is invalid.
+	NERR_BadUsername 2202
+	/// The password parameter is invalid.
+	NERR_BadPassword 2203
+	/// The group name could not be found.
+	NERR_GroupNotFound 2220
+	/// The user name could not be found.
+	NERR_UserNotFound 2221
+	/// The group already exists.
+	NERR_GroupExists 2223
+	/// The user account already exists.
+	NERR_UserExists 2224
+	/// This operation is only allowed on the primary domain controller of the
+	/// domain.
+	NERR_NotPrimary 2226
+	/// This operation is not allowed on this special group.
+	NERR_SpeGroupOp 2234
+	/// The password does not meet the password policy requirements.
+	NERR_PasswordTooShort 2245
+	/// This computer name is invalid.
+	NERR_InvalidComputer 2351
+	/// This operation is not allowed on the last administrative account.
+	NERR_LastAdmin 2452
+}
+
+const_bitflag! { FILTER: u32;
+	/// [`NetUserEnum`](crate::NetUserEnum) `filter` (`u32`).
+	///
+	/// Originally has `FILTER` prefix.
+	=>
+	/// None of the actual values (zero); all normal user accounts are
+	/// returned.
+	NoValue 0
+	TEMP_DUPLICATE_ACCOUNT 0x0001
+	NORMAL_ACCOUNT 0x0002
+	INTERDOMAIN_TRUST_ACCOUNT 0x0008
+	WORKSTATION_TRUST_ACCOUNT 0x0010
+	SERVER_TRUST_ACCOUNT 0x0020
+}
+
+const_bitflag! { UF: u32;
+	/// User account
+	/// [flags](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-user_info_1)
+	/// (`u32`).
+	=>
+	SCRIPT 0x0000_0001
+	ACCOUNTDISABLE 0x0000_0002
+	HOMEDIR_REQUIRED 0x0000_0008
+	LOCKOUT 0x0000_0010
+	PASSWD_NOTREQD 0x0000_0020
+	PASSWD_CANT_CHANGE 0x0000_0040
+	ENCRYPTED_TEXT_PASSWORD_ALLOWED 0x0000_0080
+	TEMP_DUPLICATE_ACCOUNT 0x0000_0100
+	NORMAL_ACCOUNT 0x0000_0200
+	INTERDOMAIN_TRUST_ACCOUNT 0x0000_0800
+	WORKSTATION_TRUST_ACCOUNT 0x0000_1000
+	SERVER_TRUST_ACCOUNT 0x0000_2000
+	DONT_EXPIRE_PASSWD 0x0001_0000
+	MNS_LOGON_ACCOUNT 0x0002_0000
+	SMARTCARD_REQUIRED 0x0004_0000
+	TRUSTED_FOR_DELEGATION 0x0008_0000
+	NOT_DELEGATED 0x0010_0000
+	USE_DES_KEY_ONLY 0x0020_0000
+	DONT_REQUIRE_PREAUTH 0x0040_0000
+	PASSWORD_EXPIRED 0x0080_0000
+}
+
+const_ordinary! { USER_PRIV: u32;
+	/// [`USER_INFO_1`](crate::USER_INFO_1) `usri1_priv` (`u32`).
+	///
+	/// Originally has `USER_PRIV` prefix.
+	=>
+	GUEST 0
+	USER 1
+	ADMIN 2
+}
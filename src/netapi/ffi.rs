@@ -0,0 +1,13 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "netapi32";
+	NetApiBufferFree(PVOID) -> u32
+	NetLocalGroupAddMembers(PCSTR, PCSTR, u32, PCVOID, u32) -> u32
+	NetLocalGroupDelMembers(PCSTR, PCSTR, u32, PCVOID, u32) -> u32
+	NetLocalGroupEnum(PCSTR, u32, *mut PVOID, u32, *mut u32, *mut u32, *mut usize) -> u32
+	NetLocalGroupGetMembers(PCSTR, PCSTR, u32, *mut PVOID, u32, *mut u32, *mut u32, *mut usize) -> u32
+	NetUserAdd(PCSTR, u32, PCVOID, *mut u32) -> u32
+	NetUserDel(PCSTR, PCSTR) -> u32
+	NetUserEnum(PCSTR, u32, u32, *mut PVOID, u32, *mut u32, *mut u32, *mut u32) -> u32
+	NetUserGetInfo(PCSTR, PCSTR, u32, *mut PVOID) -> u32
+}
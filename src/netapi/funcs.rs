@@ -0,0 +1,337 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::netapi::ffi;
+use crate::prelude::*;
+
+const MAX_PREFERRED_LENGTH: u32 = u32::MAX;
+
+/// [`NetLocalGroupAddMembers`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netlocalgroupaddmembers)
+/// function.
+///
+/// # Examples
+///
+/// Adding a user to the local Administrators group, whose name depends on the
+/// system language:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let admins_sid = w::CreateWellKnownSid(
+///     co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators, None)?;
+/// let (admins_name, _, _) = w::LookupAccountSid(None, &admins_sid)?;
+///
+/// let (_, user_sid, _) = w::LookupAccountName(None, "john")?;
+/// let mut member = w::LOCALGROUP_MEMBERS_INFO_0::default();
+/// member.set_lgrmi0_sid(Some(&user_sid));
+///
+/// w::NetLocalGroupAddMembers(None, &admins_name, &[member])?;
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`NetLocalGroupDelMembers`](crate::NetLocalGroupDelMembers)
+/// * [`NetLocalGroupEnum`](crate::NetLocalGroupEnum)
+/// * [`NetLocalGroupGetMembers`](crate::NetLocalGroupGetMembers)
+pub fn NetLocalGroupAddMembers<T>(
+	server_name: Option<&str>,
+	group_name: &str,
+	members: &[T],
+) -> SysResult<()>
+where
+	T: NetLocalGroupMembersInfo,
+{
+	error_to_sysresult(unsafe {
+		ffi::NetLocalGroupAddMembers(
+			WString::from_opt_str(server_name).as_ptr(),
+			WString::from_str(group_name).as_ptr(),
+			T::LEVEL,
+			vec_ptr(members) as _,
+			members.len() as _,
+		)
+	} as _)
+}
+
+/// [`NetLocalGroupDelMembers`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netlocalgroupdelmembers)
+/// function.
+///
+/// # Related functions
+///
+/// * [`NetLocalGroupAddMembers`](crate::NetLocalGroupAddMembers)
+/// * [`NetLocalGroupEnum`](crate::NetLocalGroupEnum)
+/// * [`NetLocalGroupGetMembers`](crate::NetLocalGroupGetMembers)
+pub fn NetLocalGroupDelMembers<T>(
+	server_name: Option<&str>,
+	group_name: &str,
+	members: &[T],
+) -> SysResult<()>
+where
+	T: NetLocalGroupMembersInfo,
+{
+	error_to_sysresult(unsafe {
+		ffi::NetLocalGroupDelMembers(
+			WString::from_opt_str(server_name).as_ptr(),
+			WString::from_str(group_name).as_ptr(),
+			T::LEVEL,
+			vec_ptr(members) as _,
+			members.len() as _,
+		)
+	} as _)
+}
+
+/// [`NetLocalGroupEnum`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netlocalgroupenum)
+/// function.
+///
+/// The information level is given by the struct type.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let groups = w::NetLocalGroupEnum::<w::LOCALGROUP_INFO_1>(None)?;
+/// for group in groups.iter() {
+///     println!("{} - {}",
+///         group.lgrpi1_name().unwrap_or_default(),
+///         group.lgrpi1_comment().unwrap_or_default());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`NetLocalGroupAddMembers`](crate::NetLocalGroupAddMembers)
+/// * [`NetLocalGroupDelMembers`](crate::NetLocalGroupDelMembers)
+/// * [`NetLocalGroupGetMembers`](crate::NetLocalGroupGetMembers)
+#[must_use]
+pub fn NetLocalGroupEnum<T>(server_name: Option<&str>) -> SysResult<NetApiBufferFreeGuard<T>>
+where
+	T: NetLocalGroupInfo,
+{
+	let mut pbuf = std::ptr::null_mut();
+	let mut entries_read = u32::default();
+	let mut total_entries = u32::default();
+
+	unsafe {
+		error_to_sysresult(ffi::NetLocalGroupEnum(
+			WString::from_opt_str(server_name).as_ptr(),
+			T::LEVEL,
+			&mut pbuf,
+			MAX_PREFERRED_LENGTH,
+			&mut entries_read,
+			&mut total_entries,
+			std::ptr::null_mut(),
+		) as _)
+		.map(|_| NetApiBufferFreeGuard::new(pbuf as _, entries_read as _))
+	}
+}
+
+/// [`NetLocalGroupGetMembers`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netlocalgroupgetmembers)
+/// function.
+///
+/// The information level is given by the struct type.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let members = w::NetLocalGroupGetMembers::<w::LOCALGROUP_MEMBERS_INFO_2>(
+///     None, "Users")?;
+/// for member in members.iter() {
+///     println!("{} {}",
+///         member.lgrmi2_domainandname().unwrap_or_default(),
+///         member.lgrmi2_sid().unwrap());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`NetLocalGroupAddMembers`](crate::NetLocalGroupAddMembers)
+/// * [`NetLocalGroupDelMembers`](crate::NetLocalGroupDelMembers)
+/// * [`NetLocalGroupEnum`](crate::NetLocalGroupEnum)
+#[must_use]
+pub fn NetLocalGroupGetMembers<T>(
+	server_name: Option<&str>,
+	group_name: &str,
+) -> SysResult<NetApiBufferFreeGuard<T>>
+where
+	T: NetLocalGroupMembersInfo,
+{
+	let mut pbuf = std::ptr::null_mut();
+	let mut entries_read = u32::default();
+	let mut total_entries = u32::default();
+
+	unsafe {
+		error_to_sysresult(ffi::NetLocalGroupGetMembers(
+			WString::from_opt_str(server_name).as_ptr(),
+			WString::from_str(group_name).as_ptr(),
+			T::LEVEL,
+			&mut pbuf,
+			MAX_PREFERRED_LENGTH,
+			&mut entries_read,
+			&mut total_entries,
+			std::ptr::null_mut(),
+		) as _)
+		.map(|_| NetApiBufferFreeGuard::new(pbuf as _, entries_read as _))
+	}
+}
+
+/// [`NetUserAdd`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netuseradd)
+/// function.
+///
+/// The information level is given by the struct type, which must implement
+/// [`NetUserAddInfo`](crate::prelude::NetUserAddInfo).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut name = w::WString::from_str("john");
+/// let mut password = w::WString::from_str("Str0ng-Passw0rd!");
+///
+/// let mut info = w::USER_INFO_1::default();
+/// info.set_usri1_name(Some(&mut name));
+/// info.set_usri1_password(Some(&mut password));
+/// info.usri1_priv = co::USER_PRIV::USER;
+/// info.usri1_flags = co::UF::SCRIPT | co::UF::DONT_EXPIRE_PASSWD;
+///
+/// w::NetUserAdd(None, &info)?;
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`NetUserDel`](crate::NetUserDel)
+/// * [`NetUserEnum`](crate::NetUserEnum)
+/// * [`NetUserGetInfo`](crate::NetUserGetInfo)
+pub fn NetUserAdd<T>(server_name: Option<&str>, info: &T) -> SysResult<()>
+where
+	T: NetUserAddInfo,
+{
+	error_to_sysresult(unsafe {
+		ffi::NetUserAdd(
+			WString::from_opt_str(server_name).as_ptr(),
+			T::LEVEL,
+			info as *const _ as _,
+			std::ptr::null_mut(),
+		)
+	} as _)
+}
+
+/// [`NetUserDel`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netuserdel)
+/// function.
+///
+/// # Related functions
+///
+/// * [`NetUserAdd`](crate::NetUserAdd)
+/// * [`NetUserEnum`](crate::NetUserEnum)
+/// * [`NetUserGetInfo`](crate::NetUserGetInfo)
+pub fn NetUserDel(server_name: Option<&str>, user_name: &str) -> SysResult<()> {
+	error_to_sysresult(unsafe {
+		ffi::NetUserDel(
+			WString::from_opt_str(server_name).as_ptr(),
+			WString::from_str(user_name).as_ptr(),
+		)
+	} as _)
+}
+
+/// [`NetUserEnum`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netuserenum)
+/// function.
+///
+/// The information level is given by the struct type, which must implement
+/// [`NetUserEnumInfo`](crate::prelude::NetUserEnumInfo).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let users = w::NetUserEnum::<w::USER_INFO_1>(
+///     None, co::FILTER::NORMAL_ACCOUNT)?;
+/// for user in users.iter() {
+///     println!("{} disabled: {}",
+///         user.usri1_name().unwrap_or_default(),
+///         user.usri1_flags.has(co::UF::ACCOUNTDISABLE));
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`NetUserAdd`](crate::NetUserAdd)
+/// * [`NetUserDel`](crate::NetUserDel)
+/// * [`NetUserGetInfo`](crate::NetUserGetInfo)
+#[must_use]
+pub fn NetUserEnum<T>(
+	server_name: Option<&str>,
+	filter: co::FILTER,
+) -> SysResult<NetApiBufferFreeGuard<T>>
+where
+	T: NetUserEnumInfo,
+{
+	let mut pbuf = std::ptr::null_mut();
+	let mut entries_read = u32::default();
+	let mut total_entries = u32::default();
+
+	unsafe {
+		error_to_sysresult(ffi::NetUserEnum(
+			WString::from_opt_str(server_name).as_ptr(),
+			T::LEVEL,
+			filter.raw(),
+			&mut pbuf,
+			MAX_PREFERRED_LENGTH,
+			&mut entries_read,
+			&mut total_entries,
+			std::ptr::null_mut(),
+		) as _)
+		.map(|_| NetApiBufferFreeGuard::new(pbuf as _, entries_read as _))
+	}
+}
+
+/// [`NetUserGetInfo`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/nf-lmaccess-netusergetinfo)
+/// function.
+///
+/// The information level is given by the struct type. The returned guard
+/// holds a single entry.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let info = w::NetUserGetInfo::<w::USER_INFO_1>(None, "john")?;
+/// let disabled = info[0].usri1_flags.has(co::UF::ACCOUNTDISABLE);
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`NetUserAdd`](crate::NetUserAdd)
+/// * [`NetUserDel`](crate::NetUserDel)
+/// * [`NetUserEnum`](crate::NetUserEnum)
+#[must_use]
+pub fn NetUserGetInfo<T>(
+	server_name: Option<&str>,
+	user_name: &str,
+) -> SysResult<NetApiBufferFreeGuard<T>>
+where
+	T: NetUserInfo,
+{
+	let mut pbuf = std::ptr::null_mut();
+	unsafe {
+		error_to_sysresult(ffi::NetUserGetInfo(
+			WString::from_opt_str(server_name).as_ptr(),
+			WString::from_str(user_name).as_ptr(),
+			T::LEVEL,
+			&mut pbuf,
+		) as _)
+		.map(|_| NetApiBufferFreeGuard::new(pbuf as _, 1))
+	}
+}
@@ -0,0 +1,64 @@
+use std::ops::Deref;
+
+use crate::netapi::ffi;
+
+/// RAII implementation for the buffer returned by the network management
+/// functions, which automatically calls
+/// [`NetApiBufferFree`](https://learn.microsoft.com/en-us/windows/win32/api/lmapibuf/nf-lmapibuf-netapibufferfree)
+/// when the object goes out of scope.
+///
+/// Derefs to a slice with the returned entries.
+pub struct NetApiBufferFreeGuard<T> {
+	pbuf: *mut T,
+	count: usize,
+}
+
+impl<T> Drop for NetApiBufferFreeGuard<T> {
+	fn drop(&mut self) {
+		if !self.pbuf.is_null() {
+			unsafe {
+				ffi::NetApiBufferFree(self.pbuf as _); // ignore errors
+			}
+		}
+	}
+}
+
+impl<T> Deref for NetApiBufferFreeGuard<T> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		if self.pbuf.is_null() {
+			&[]
+		} else {
+			unsafe { std::slice::from_raw_parts(self.pbuf, self.count) }
+		}
+	}
+}
+
+impl<T> NetApiBufferFreeGuard<T> {
+	/// Constructs the guard by taking ownership of the buffer.
+	///
+	/// # Safety
+	///
+	/// Be sure the buffer must be freed with
+	/// [`NetApiBufferFree`](https://learn.microsoft.com/en-us/windows/win32/api/lmapibuf/nf-lmapibuf-netapibufferfree),
+	/// and it contains `count` entries of `T`.
+	#[must_use]
+	pub const unsafe fn new(pbuf: *mut T, count: usize) -> Self {
+		Self { pbuf, count }
+	}
+
+	/// Ejects the underlying buffer pointer and count, leaving null and zero in
+	/// their places.
+	///
+	/// Since the internal pointer will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// memory leak.
+	#[must_use]
+	pub fn leak(&mut self) -> (*mut T, usize) {
+		(
+			std::mem::replace(&mut self.pbuf, std::ptr::null_mut()),
+			std::mem::replace(&mut self.count, 0),
+		)
+	}
+}
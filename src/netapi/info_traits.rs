@@ -0,0 +1,50 @@
+/// A `USER_INFO_*` struct, whose information level is passed to
+/// [`NetUserGetInfo`](crate::NetUserGetInfo), which accepts all levels.
+///
+/// # Safety
+///
+/// The level must match the memory layout of the struct.
+pub unsafe trait NetUserInfo {
+	/// The information level of the struct.
+	const LEVEL: u32;
+}
+
+/// A `USER_INFO_*` struct whose information level is accepted by
+/// [`NetUserEnum`](crate::NetUserEnum): 0, 1, 2, 3, 10, 11 or 20.
+///
+/// # Safety
+///
+/// The level must be one of the above.
+pub unsafe trait NetUserEnumInfo: NetUserInfo {}
+
+/// A `USER_INFO_*` struct whose information level is accepted by
+/// [`NetUserAdd`](crate::NetUserAdd): 1, 2, 3 or 4.
+///
+/// # Safety
+///
+/// The level must be one of the above.
+pub unsafe trait NetUserAddInfo: NetUserInfo {}
+
+/// A `LOCALGROUP_INFO_*` struct, whose information level is passed to
+/// [`NetLocalGroupEnum`](crate::NetLocalGroupEnum).
+///
+/// # Safety
+///
+/// The level must match the memory layout of the struct.
+pub unsafe trait NetLocalGroupInfo {
+	/// The information level of the struct.
+	const LEVEL: u32;
+}
+
+/// A `LOCALGROUP_MEMBERS_INFO_*` struct, whose information level is passed to
+/// [`NetLocalGroupAddMembers`](crate::NetLocalGroupAddMembers),
+/// [`NetLocalGroupDelMembers`](crate::NetLocalGroupDelMembers) and
+/// [`NetLocalGroupGetMembers`](crate::NetLocalGroupGetMembers).
+///
+/// # Safety
+///
+/// The level must match the memory layout of the struct.
+pub unsafe trait NetLocalGroupMembersInfo {
+	/// The information level of the struct.
+	const LEVEL: u32;
+}
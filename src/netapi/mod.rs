@@ -0,0 +1,18 @@
+#![cfg(feature = "netapi")]
+
+mod funcs;
+mod info_traits;
+mod structs;
+
+pub mod co;
+pub(in crate::netapi) mod ffi;
+pub mod guard;
+
+pub mod decl {
+	pub use super::funcs::*;
+	pub use super::structs::*;
+}
+
+pub mod traits {
+	pub use super::info_traits::*;
+}
@@ -0,0 +1,227 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// [`LOCALGROUP_INFO_0`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-localgroup_info_0)
+/// struct.
+#[repr(C)]
+pub struct LOCALGROUP_INFO_0<'a> {
+	lgrpi0_name: *mut u16,
+
+	_lgrpi0_name: PhantomData<&'a mut u16>,
+}
+
+impl_default!(LOCALGROUP_INFO_0, 'a);
+
+unsafe impl<'a> NetLocalGroupInfo for LOCALGROUP_INFO_0<'a> {
+	const LEVEL: u32 = 0;
+}
+
+impl<'a> LOCALGROUP_INFO_0<'a> {
+	pub_fn_string_ptr_get_set!('a, lgrpi0_name, set_lgrpi0_name);
+}
+
+/// [`LOCALGROUP_INFO_1`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-localgroup_info_1)
+/// struct.
+#[repr(C)]
+pub struct LOCALGROUP_INFO_1<'a, 'b> {
+	lgrpi1_name: *mut u16,
+	lgrpi1_comment: *mut u16,
+
+	_lgrpi1_name: PhantomData<&'a mut u16>,
+	_lgrpi1_comment: PhantomData<&'b mut u16>,
+}
+
+impl_default!(LOCALGROUP_INFO_1, 'a, 'b);
+
+unsafe impl<'a, 'b> NetLocalGroupInfo for LOCALGROUP_INFO_1<'a, 'b> {
+	const LEVEL: u32 = 1;
+}
+
+impl<'a, 'b> LOCALGROUP_INFO_1<'a, 'b> {
+	pub_fn_string_ptr_get_set!('a, lgrpi1_name, set_lgrpi1_name);
+	pub_fn_string_ptr_get_set!('b, lgrpi1_comment, set_lgrpi1_comment);
+}
+
+/// [`LOCALGROUP_MEMBERS_INFO_0`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-localgroup_members_info_0)
+/// struct.
+#[repr(C)]
+pub struct LOCALGROUP_MEMBERS_INFO_0<'a> {
+	lgrmi0_sid: *mut SID,
+
+	_lgrmi0_sid: PhantomData<&'a SID>,
+}
+
+impl_default!(LOCALGROUP_MEMBERS_INFO_0, 'a);
+
+unsafe impl<'a> NetLocalGroupMembersInfo for LOCALGROUP_MEMBERS_INFO_0<'a> {
+	const LEVEL: u32 = 0;
+}
+
+impl<'a> LOCALGROUP_MEMBERS_INFO_0<'a> {
+	/// Returns the `lgrmi0_sid` field.
+	#[must_use]
+	pub fn lgrmi0_sid(&self) -> Option<&SID> {
+		unsafe { self.lgrmi0_sid.as_ref() }
+	}
+
+	/// Sets the `lgrmi0_sid` field.
+	pub fn set_lgrmi0_sid(&mut self, sid: Option<&'a SID>) {
+		self.lgrmi0_sid = sid.map_or(std::ptr::null_mut(), |sid| sid as *const _ as _);
+	}
+}
+
+/// [`LOCALGROUP_MEMBERS_INFO_2`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-localgroup_members_info_2)
+/// struct.
+#[repr(C)]
+pub struct LOCALGROUP_MEMBERS_INFO_2<'a, 'b> {
+	lgrmi2_sid: *mut SID,
+	pub lgrmi2_sidusage: co::SID_NAME_USE,
+	lgrmi2_domainandname: *mut u16,
+
+	_lgrmi2_sid: PhantomData<&'a SID>,
+	_lgrmi2_domainandname: PhantomData<&'b mut u16>,
+}
+
+impl_default!(LOCALGROUP_MEMBERS_INFO_2, 'a, 'b);
+
+unsafe impl<'a, 'b> NetLocalGroupMembersInfo for LOCALGROUP_MEMBERS_INFO_2<'a, 'b> {
+	const LEVEL: u32 = 2;
+}
+
+impl<'a, 'b> LOCALGROUP_MEMBERS_INFO_2<'a, 'b> {
+	/// Returns the `lgrmi2_sid` field.
+	#[must_use]
+	pub fn lgrmi2_sid(&self) -> Option<&SID> {
+		unsafe { self.lgrmi2_sid.as_ref() }
+	}
+
+	/// Sets the `lgrmi2_sid` field.
+	pub fn set_lgrmi2_sid(&mut self, sid: Option<&'a SID>) {
+		self.lgrmi2_sid = sid.map_or(std::ptr::null_mut(), |sid| sid as *const _ as _);
+	}
+
+	pub_fn_string_ptr_get_set!('b, lgrmi2_domainandname, set_lgrmi2_domainandname);
+}
+
+/// [`LOCALGROUP_MEMBERS_INFO_3`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-localgroup_members_info_3)
+/// struct.
+#[repr(C)]
+pub struct LOCALGROUP_MEMBERS_INFO_3<'a> {
+	lgrmi3_domainandname: *mut u16,
+
+	_lgrmi3_domainandname: PhantomData<&'a mut u16>,
+}
+
+impl_default!(LOCALGROUP_MEMBERS_INFO_3, 'a);
+
+unsafe impl<'a> NetLocalGroupMembersInfo for LOCALGROUP_MEMBERS_INFO_3<'a> {
+	const LEVEL: u32 = 3;
+}
+
+impl<'a> LOCALGROUP_MEMBERS_INFO_3<'a> {
+	pub_fn_string_ptr_get_set!('a, lgrmi3_domainandname, set_lgrmi3_domainandname);
+}
+
+/// [`USER_INFO_0`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-user_info_0)
+/// struct.
+#[repr(C)]
+pub struct USER_INFO_0<'a> {
+	usri0_name: *mut u16,
+
+	_usri0_name: PhantomData<&'a mut u16>,
+}
+
+impl_default!(USER_INFO_0, 'a);
+
+unsafe impl<'a> NetUserInfo for USER_INFO_0<'a> {
+	const LEVEL: u32 = 0;
+}
+
+unsafe impl<'a> NetUserEnumInfo for USER_INFO_0<'a> {}
+
+impl<'a> USER_INFO_0<'a> {
+	pub_fn_string_ptr_get_set!('a, usri0_name, set_usri0_name);
+}
+
+/// [`USER_INFO_1`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-user_info_1)
+/// struct.
+///
+/// The password is never returned by the system.
+#[repr(C)]
+pub struct USER_INFO_1<'a, 'b, 'c, 'd, 'e> {
+	usri1_name: *mut u16,
+	usri1_password: *mut u16,
+	pub usri1_password_age: u32,
+	pub usri1_priv: co::USER_PRIV,
+	usri1_home_dir: *mut u16,
+	usri1_comment: *mut u16,
+	pub usri1_flags: co::UF,
+	usri1_script_path: *mut u16,
+
+	_usri1_name: PhantomData<&'a mut u16>,
+	_usri1_password: PhantomData<&'b mut u16>,
+	_usri1_home_dir: PhantomData<&'c mut u16>,
+	_usri1_comment: PhantomData<&'d mut u16>,
+	_usri1_script_path: PhantomData<&'e mut u16>,
+}
+
+impl_default!(USER_INFO_1, 'a, 'b, 'c, 'd, 'e);
+
+unsafe impl<'a, 'b, 'c, 'd, 'e> NetUserInfo for USER_INFO_1<'a, 'b, 'c, 'd, 'e> {
+	const LEVEL: u32 = 1;
+}
+
+unsafe impl<'a, 'b, 'c, 'd, 'e> NetUserEnumInfo for USER_INFO_1<'a, 'b, 'c, 'd, 'e> {}
+unsafe impl<'a, 'b, 'c, 'd, 'e> NetUserAddInfo for USER_INFO_1<'a, 'b, 'c, 'd, 'e> {}
+
+impl<'a, 'b, 'c, 'd, 'e> USER_INFO_1<'a, 'b, 'c, 'd, 'e> {
+	pub_fn_string_ptr_get_set!('a, usri1_name, set_usri1_name);
+	pub_fn_string_ptr_get_set!('b, usri1_password, set_usri1_password);
+	pub_fn_string_ptr_get_set!('c, usri1_home_dir, set_usri1_home_dir);
+	pub_fn_string_ptr_get_set!('d, usri1_comment, set_usri1_comment);
+	pub_fn_string_ptr_get_set!('e, usri1_script_path, set_usri1_script_path);
+}
+
+/// [`USER_INFO_23`](https://learn.microsoft.com/en-us/windows/win32/api/lmaccess/ns-lmaccess-user_info_23)
+/// struct.
+#[repr(C)]
+pub struct USER_INFO_23<'a, 'b, 'c, 'd> {
+	usri23_name: *mut u16,
+	usri23_full_name: *mut u16,
+	usri23_comment: *mut u16,
+	pub usri23_flags: co::UF,
+	usri23_user_sid: *mut SID,
+
+	_usri23_name: PhantomData<&'a mut u16>,
+	_usri23_full_name: PhantomData<&'b mut u16>,
+	_usri23_comment: PhantomData<&'c mut u16>,
+	_usri23_user_sid: PhantomData<&'d SID>,
+}
+
+impl_default!(USER_INFO_23, 'a, 'b, 'c, 'd);
+
+unsafe impl<'a, 'b, 'c, 'd> NetUserInfo for USER_INFO_23<'a, 'b, 'c, 'd> {
+	const LEVEL: u32 = 23;
+}
+
+impl<'a, 'b, 'c, 'd> USER_INFO_23<'a, 'b, 'c, 'd> {
+	pub_fn_string_ptr_get_set!('a, usri23_name, set_usri23_name);
+	pub_fn_string_ptr_get_set!('b, usri23_full_name, set_usri23_full_name);
+	pub_fn_string_ptr_get_set!('c, usri23_comment, set_usri23_comment);
+
+	/// Returns the `usri23_user_sid` field.
+	#[must_use]
+	pub fn usri23_user_sid(&self) -> Option<&SID> {
+		unsafe { self.usri23_user_sid.as_ref() }
+	}
+
+	/// Sets the `usri23_user_sid` field.
+	pub fn set_usri23_user_sid(&mut self, sid: Option<&'d SID>) {
+		self.usri23_user_sid = sid.map_or(std::ptr::null_mut(), |sid| sid as *const _ as _);
+	}
+}
@@ -97,3241 +97,6630 @@ impl HRESULT {
 		(self.0 & 0xffff) as u16
 	}
 
+	/// Returns the English description of the error code, taken from the
+	/// embedded message table, if available.
+	///
+	/// If the code has no entry of its own, but it's a
+	/// [`co::FACILITY::WIN32`](crate::co::FACILITY::WIN32) code, the
+	/// description of the original [`ERROR`](crate::co::ERROR) is returned.
+	///
+	/// Unlike [`FormatMessage`](crate::prelude::SystemError::FormatMessage),
+	/// this method doesn't call the system, so the text doesn't depend on the
+	/// system language.
+	///
+	/// Every constant declared by the enabled features has a description;
+	/// `None` is returned only for codes unknown to this crate. The
+	/// descriptions follow the English system messages, but small wording
+	/// differences are possible. The [`Display`](std::fmt::Display) and
+	/// [`Debug`](std::fmt::Debug) implementations always use `FormatMessage`,
+	/// regardless of this feature.
+	///
+	/// This method is available with the `msg-table` feature.
+	#[cfg(feature = "msg-table")]
+	#[must_use]
+	pub fn description(self) -> Option<&'static str> {
+		self.msg_entry()
+			.map(|(_, desc)| desc.trim())
+			.filter(|desc| !desc.is_empty())
+	}
+
 	/// [`HRESULT_FACILITY`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_facility)
 	/// macro.
 	pub const fn facility(self) -> co::FACILITY {
 		unsafe { co::FACILITY::from_raw((self.0 >> 16) & 0x1fff) }
 	}
 
+	/// [`MAKE_HRESULT`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-make_hresult)
+	/// macro.
+	#[must_use]
+	pub const fn from_parts(severity: co::SEVERITY, facility: co::FACILITY, code: u16) -> Self {
+		Self(((severity.raw() as u32) << 31) | ((facility.raw() & 0x1fff) << 16) | code as u32)
+	}
+
+	/// Returns the symbolic name of the error code, taken from the embedded
+	/// message table, if available.
+	///
+	/// If the code has no entry of its own, but it's a
+	/// [`co::FACILITY::WIN32`](crate::co::FACILITY::WIN32) code, the name of
+	/// the original [`ERROR`](crate::co::ERROR) is returned.
+	///
+	/// This method is available with the `msg-table` feature.
+	#[cfg(feature = "msg-table")]
+	#[must_use]
+	pub fn name(self) -> Option<&'static str> {
+		self.msg_entry().map(|(name, _)| name)
+	}
+
 	/// [`HRESULT_SEVERITY`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_severity)
 	/// macro.
 	pub const fn severity(self) -> co::SEVERITY {
 		unsafe { co::SEVERITY::from_raw(((self.0 >> 31) & 0x1) as _) }
 	}
+
+	/// If this is a [`co::FACILITY::WIN32`](crate::co::FACILITY::WIN32) code,
+	/// returns the original [`ERROR`](crate::co::ERROR) it was created from;
+	/// this is the inverse of
+	/// [`ERROR::to_hresult`](crate::co::ERROR::to_hresult).
+	#[must_use]
+	pub const fn to_error(self) -> Option<co::ERROR> {
+		if self.0 & 0x9fff_0000 == 0x8007_0000 {
+			Some(unsafe { co::ERROR::from_raw(self.code() as _) })
+		} else {
+			None
+		}
+	}
+
+	#[cfg(feature = "msg-table")]
+	#[must_use]
+	fn msg_entry(self) -> Option<(&'static str, &'static str)> {
+		#[allow(unused_mut)]
+		let mut tables: Vec<&[(u32, &str, &str)]> = vec![HRESULT_MSGS_OLE];
+		#[cfg(feature = "dshow")]
+		tables.push(crate::dshow::co::HRESULT_MSGS_DSHOW);
+		#[cfg(feature = "dxgi")]
+		tables.push(crate::dxgi::co::HRESULT_MSGS_DXGI);
+		#[cfg(feature = "mf")]
+		tables.push(crate::mf::co::HRESULT_MSGS_MF);
+		#[cfg(feature = "oleaut")]
+		tables.push(crate::oleaut::co::HRESULT_MSGS_OLEAUT);
+
+		tables
+			.iter()
+			.flat_map(|table| table.iter())
+			.find(|(val, _, _)| *val == self.0)
+			.map(|(_, name, desc)| (*name, *desc))
+			.or_else(|| self.to_error().and_then(|err| err.msg_entry()))
+	}
+}
+
+impl From<HRESULT> for std::io::ErrorKind {
+	fn from(hr: HRESULT) -> Self {
+		use std::io::ErrorKind as K;
+		if let Some(err) = hr.to_error() {
+			return err.into();
+		}
+		match hr {
+			HRESULT::E_NOTIMPL => K::Unsupported,
+			HRESULT::E_POINTER | HRESULT::E_BOUNDS => K::InvalidInput,
+			HRESULT::E_ABORT => K::Interrupted,
+			HRESULT::E_PENDING => K::WouldBlock,
+			_ => K::Other,
+		}
+	}
 }
 
 // https://learn.microsoft.com/en-us/windows/win32/com/com-error-codes-1
 
-const_values_pub! { HRESULT;
+const_values_pub_msgs! { HRESULT, HRESULT_MSGS_OLE;
 	/// Operation successful.
 	S_OK 0
+	/// Operation successful, but the result is false or empty.
 	S_FALSE 1
 
+	/// Catastrophic failure.
 	E_UNEXPECTED 0x8000_ffff
+	/// Not implemented.
 	E_NOTIMPL 0x8000_4001
+	/// Failed to allocate necessary memory.
 	E_OUTOFMEMORY 0x8007_000e
+	/// One or more arguments are not valid.
 	E_INVALIDARG 0x8007_0057
+	/// No such interface supported.
 	E_NOINTERFACE 0x8000_4002
+	/// Pointer that is not valid.
 	E_POINTER 0x8000_4003
+	/// Handle that is not valid.
 	E_HANDLE 0x8007_0006
+	/// Operation aborted.
 	E_ABORT 0x8000_4004
+	/// Unspecified failure.
 	E_FAIL 0x8000_4005
+	/// General access denied error.
 	E_ACCESSDENIED 0x8007_0005
+	/// The data necessary to complete this operation is not yet available.
 	E_PENDING 0x8000_000a
+	/// The operation attempted to access data outside the valid range.
 	E_BOUNDS 0x8000_000b
+	/// A concurrent or interleaved operation changed the state of the object, invalidating this operation.
 	E_CHANGED_STATE 0x8000_000c
+	/// An illegal state change was requested.
 	E_ILLEGAL_STATE_CHANGE 0x8000_000d
+	/// A method was called at an unexpected time.
 	E_ILLEGAL_METHOD_CALL 0x8000_000e
+	/// Typename or Namespace was not found in metadata file.
 	RO_E_METADATA_NAME_NOT_FOUND 0x8000_000f
+	/// Name is an existing namespace rather than a typename.
 	RO_E_METADATA_NAME_IS_NAMESPACE 0x8000_0010
+	/// Typename has an invalid format.
 	RO_E_METADATA_INVALID_TYPE_FORMAT 0x8000_0011
+	/// Metadata file is invalid or corrupted.
 	RO_E_INVALID_METADATA_FILE 0x8000_0012
+	/// The object has been closed.
 	RO_E_CLOSED 0x8000_0013
+	/// Only one thread may access the object during a write operation.
 	RO_E_EXCLUSIVE_WRITE 0x8000_0014
+	/// Operation is prohibited during change notification.
 	RO_E_CHANGE_NOTIFICATION_IN_PROGRESS 0x8000_0015
+	/// The text associated with this error code could not be found.
 	RO_E_ERROR_STRING_NOT_FOUND 0x8000_0016
+	/// String not null terminated.
 	E_STRING_NOT_NULL_TERMINATED 0x8000_0017
+	/// A delegate was assigned when not allowed.
 	E_ILLEGAL_DELEGATE_ASSIGNMENT 0x8000_0018
+	/// An async operation was not properly started.
 	E_ASYNC_OPERATION_NOT_STARTED 0x8000_0019
+	/// The application is exiting and cannot service this request.
 	E_APPLICATION_EXITING 0x8000_001a
+	/// The application view is exiting and cannot service this request.
 	E_APPLICATION_VIEW_EXITING 0x8000_001b
+	/// The object must support the IAgileObject interface.
 	RO_E_MUST_BE_AGILE 0x8000_001c
+	/// Activating a single-threaded class from MTA is not supported.
 	RO_E_UNSUPPORTED_FROM_MTA 0x8000_001d
+	/// The object has been committed.
 	RO_E_COMMITTED 0x8000_001e
+	/// A COM call to an ASTA was blocked because the call chain originated in or passed through another ASTA. This pattern is deadlock-prone and disallowed by apartment call control.
 	RO_E_BLOCKED_CROSS_ASTA_CALL 0x8000_001f
+	/// A universal application process cannot activate a packaged WinRT server that is declared to run full trust.
 	RO_E_CANNOT_ACTIVATE_FULL_TRUST_SERVER 0x8000_0020
+	/// A full trust packaged application process cannot activate a packaged WinRT server unless it is also declared to run full trust.
 	RO_E_CANNOT_ACTIVATE_UNIVERSAL_APPLICATION_SERVER 0x8000_0021
+	/// Thread local storage failure.
 	CO_E_INIT_TLS 0x8000_4006
+	/// Get shared memory allocator failure.
 	CO_E_INIT_SHARED_ALLOCATOR 0x8000_4007
+	/// Get memory allocator failure.
 	CO_E_INIT_MEMORY_ALLOCATOR 0x8000_4008
+	/// Unable to initialize class cache.
 	CO_E_INIT_CLASS_CACHE 0x8000_4009
+	/// Unable to initialize RPC services.
 	CO_E_INIT_RPC_CHANNEL 0x8000_400a
+	/// Cannot set thread local storage channel control.
 	CO_E_INIT_TLS_SET_CHANNEL_CONTROL 0x8000_400b
+	/// Could not allocate thread local storage channel control.
 	CO_E_INIT_TLS_CHANNEL_CONTROL 0x8000_400c
+	/// The user supplied memory allocator is unacceptable.
 	CO_E_INIT_UNACCEPTED_USER_ALLOCATOR 0x8000_400d
+	/// The OLE service mutex already exists.
 	CO_E_INIT_SCM_MUTEX_EXISTS 0x8000_400e
+	/// The OLE service file mapping already exists.
 	CO_E_INIT_SCM_FILE_MAPPING_EXISTS 0x8000_400f
+	/// Unable to map view of file for OLE service.
 	CO_E_INIT_SCM_MAP_VIEW_OF_FILE 0x8000_4010
+	/// Failure attempting to launch OLE service.
 	CO_E_INIT_SCM_EXEC_FAILURE 0x8000_4011
+	/// There was an attempt to call CoInitialize a second time while single threaded.
 	CO_E_INIT_ONLY_SINGLE_THREADED 0x8000_4012
+	/// A Remote activation was necessary but was not allowed.
 	CO_E_CANT_REMOTE 0x8000_4013
+	/// A Remote activation was necessary but the server name provided was invalid.
 	CO_E_BAD_SERVER_NAME 0x8000_4014
+	/// The class is configured to run as a security id different from the caller.
 	CO_E_WRONG_SERVER_IDENTITY 0x8000_4015
+	/// Use of Ole1 services requiring DDE windows is disabled.
 	CO_E_OLE1DDE_DISABLED 0x8000_4016
+	/// A RunAs specification must be (domain name)\(user name) or simply (user name).
 	CO_E_RUNAS_SYNTAX 0x8000_4017
+	/// The server process could not be started. The pathname may be incorrect.
 	CO_E_CREATEPROCESS_FAILURE 0x8000_4018
+	/// The server process could not be started as the configured identity. The pathname may be incorrect or unavailable.
 	CO_E_RUNAS_CREATEPROCESS_FAILURE 0x8000_4019
+	/// The server process could not be started because the configured identity is incorrect. Check the user name and password.
 	CO_E_RUNAS_LOGON_FAILURE 0x8000_401a
+	/// The client is not allowed to launch this server.
 	CO_E_LAUNCH_PERMSSION_DENIED 0x8000_401b
+	/// The service providing this server could not be started.
 	CO_E_START_SERVICE_FAILURE 0x8000_401c
+	/// This computer was unable to communicate with the computer providing the server.
 	CO_E_REMOTE_COMMUNICATION_FAILURE 0x8000_401d
+	/// The server did not respond after being launched.
 	CO_E_SERVER_START_TIMEOUT 0x8000_401e
+	/// The registration information for this server is inconsistent or incomplete.
 	CO_E_CLSREG_INCONSISTENT 0x8000_401f
+	/// The registration information for this interface is inconsistent or incomplete.
 	CO_E_IIDREG_INCONSISTENT 0x8000_4020
+	/// The operation attempted is not supported.
 	CO_E_NOT_SUPPORTED 0x8000_4021
+	/// A dll must be loaded.
 	CO_E_RELOAD_DLL 0x8000_4022
+	/// A Microsoft Software Installer error was encountered.
 	CO_E_MSI_ERROR 0x8000_4023
+	/// The specified activation could not occur in the client context as specified.
 	CO_E_ATTEMPT_TO_CREATE_OUTSIDE_CLIENT_CONTEXT 0x8000_4024
+	/// Activations on the server are paused.
 	CO_E_SERVER_PAUSED 0x8000_4025
+	/// Activations on the server are not paused.
 	CO_E_SERVER_NOT_PAUSED 0x8000_4026
+	/// The component or application containing the component has been disabled.
 	CO_E_CLASS_DISABLED 0x8000_4027
+	/// The common language runtime is not available.
 	CO_E_CLRNOTAVAILABLE 0x8000_4028
+	/// The thread-pool rejected the submitted asynchronous work.
 	CO_E_ASYNC_WORK_REJECTED 0x8000_4029
+	/// The server started, but did not finish initializing in a timely fashion.
 	CO_E_SERVER_INIT_TIMEOUT 0x8000_402a
+	/// Unable to complete the call since there is no COM+ security context inside IObjectControl.Activate.
 	CO_E_NO_SECCTX_IN_ACTIVATE 0x8000_402b
+	/// The provided tracker configuration is invalid.
 	CO_E_TRACKER_CONFIG 0x8000_4030
+	/// The provided thread pool configuration is invalid.
 	CO_E_THREADPOOL_CONFIG 0x8000_4031
+	/// The provided side-by-side configuration is invalid.
 	CO_E_SXS_CONFIG 0x8000_4032
+	/// The server principal name (SPN) obtained during security negotiation is malformed.
 	CO_E_MALFORMED_SPN 0x8000_4033
+	/// The caller failed to revoke a per-apartment registration before apartment shutdown.
 	CO_E_UNREVOKED_REGISTRATION_ON_APARTMENT_SHUTDOWN 0x8000_4034
+	/// The object has been rundown by the stub manager while there are external clients.
 	CO_E_PREMATURE_STUB_RUNDOWN 0x8000_4035
+	/// Invalid OLEVERB structure.
 	OLE_E_OLEVERB 0x8004_0000
+	/// Invalid advise flags.
 	OLE_E_ADVF 0x8004_0001
+	/// Can't enumerate any more, because the associated data is missing.
 	OLE_E_ENUM_NOMORE 0x8004_0002
+	/// This implementation doesn't take advises.
 	OLE_E_ADVISENOTSUPPORTED 0x8004_0003
+	/// There is no connection for this connection ID.
 	OLE_E_NOCONNECTION 0x8004_0004
+	/// Need to run the object to perform this operation.
 	OLE_E_NOTRUNNING 0x8004_0005
+	/// There is no cache to operate on.
 	OLE_E_NOCACHE 0x8004_0006
+	/// Uninitialized object.
 	OLE_E_BLANK 0x8004_0007
+	/// Linked object's source class has changed.
 	OLE_E_CLASSDIFF 0x8004_0008
+	/// Not able to get the moniker of the object.
 	OLE_E_CANT_GETMONIKER 0x8004_0009
+	/// Not able to bind to the source.
 	OLE_E_CANT_BINDTOSOURCE 0x8004_000a
+	/// Object is static; operation not allowed.
 	OLE_E_STATIC 0x8004_000b
+	/// User canceled out of save dialog.
 	OLE_E_PROMPTSAVECANCELLED 0x8004_000c
+	/// Invalid rectangle.
 	OLE_E_INVALIDRECT 0x8004_000d
+	/// compobj.dll is too old for the ole2.dll initialized.
 	OLE_E_WRONGCOMPOBJ 0x8004_000e
+	/// Invalid window handle.
 	OLE_E_INVALIDHWND 0x8004_000f
+	/// Object is not in any of the inplace active states.
 	OLE_E_NOT_INPLACEACTIVE 0x8004_0010
+	/// Not able to convert object.
 	OLE_E_CANTCONVERT 0x8004_0011
+	/// Not able to perform the operation because object is not given storage yet.
 	OLE_E_NOSTORAGE 0x8004_0012
+	/// Invalid FORMATETC structure.
 	DV_E_FORMATETC 0x8004_0064
+	/// Invalid DVTARGETDEVICE structure.
 	DV_E_DVTARGETDEVICE 0x8004_0065
+	/// Invalid STDGMEDIUM structure.
 	DV_E_STGMEDIUM 0x8004_0066
+	/// Invalid STATDATA structure.
 	DV_E_STATDATA 0x8004_0067
+	/// Invalid lindex.
 	DV_E_LINDEX 0x8004_0068
+	/// Invalid tymed.
 	DV_E_TYMED 0x8004_0069
+	/// Invalid clipboard format.
 	DV_E_CLIPFORMAT 0x8004_006a
+	/// Invalid aspect(s).
 	DV_E_DVASPECT 0x8004_006b
+	/// tdSize parameter of the DVTARGETDEVICE structure is invalid.
 	DV_E_DVTARGETDEVICE_SIZE 0x8004_006c
+	/// Object doesn't support IViewObject interface.
 	DV_E_NOIVIEWOBJECT 0x8004_006d
+	/// Trying to revoke a drop target that has not been registered.
 	DRAGDROP_E_NOTREGISTERED 0x8004_0100
+	/// This window has already been registered as a drop target.
 	DRAGDROP_E_ALREADYREGISTERED 0x8004_0101
+	/// Invalid window handle.
 	DRAGDROP_E_INVALIDHWND 0x8004_0102
+	/// A drag operation is already in progress.
 	DRAGDROP_E_CONCURRENT_DRAG_ATTEMPTED 0x8004_0103
+	/// Class does not support aggregation (or class object is remote).
 	CLASS_E_NOAGGREGATION 0x8004_0110
+	/// ClassFactory cannot supply requested class.
 	CLASS_E_CLASSNOTAVAILABLE 0x8004_0111
+	/// Class is not licensed for use.
 	CLASS_E_NOTLICENSED 0x8004_0112
+	/// Error drawing view.
 	VIEW_E_DRAW 0x8004_0140
+	/// Could not read key from registry.
 	REGDB_E_READREGDB 0x8004_0150
+	/// Could not write key to registry.
 	REGDB_E_WRITEREGDB 0x8004_0151
+	/// Could not find the key in the registry.
 	REGDB_E_KEYMISSING 0x8004_0152
+	/// Invalid value for registry.
 	REGDB_E_INVALIDVALUE 0x8004_0153
+	/// Class not registered.
 	REGDB_E_CLASSNOTREG 0x8004_0154
+	/// Interface not registered.
 	REGDB_E_IIDNOTREG 0x8004_0155
+	/// Threading model entry is not valid.
 	REGDB_E_BADTHREADINGMODEL 0x8004_0156
+	/// A registration in a package violates package-specific policies.
 	REGDB_E_PACKAGEPOLICYVIOLATION 0x8004_0157
+	/// CATID does not exist.
 	CAT_E_CATIDNOEXIST 0x8004_0160
+	/// Description not found.
 	CAT_E_NODESCRIPTION 0x8004_0161
+	/// No package in the software installation data in Active Directory meets this criteria.
 	CS_E_PACKAGE_NOTFOUND 0x8004_0164
+	/// Deleting this will break the referential integrity of the software installation data in Active Directory.
 	CS_E_NOT_DELETABLE 0x8004_0165
+	/// The CLSID was not found in the software installation data in Active Directory.
 	CS_E_CLASS_NOTFOUND 0x8004_0166
+	/// The software installation data in Active Directory is corrupt.
 	CS_E_INVALID_VERSION 0x8004_0167
+	/// There is no software installation data in Active Directory.
 	CS_E_NO_CLASSSTORE 0x8004_0168
+	/// There is no software installation data object in Active Directory.
 	CS_E_OBJECT_NOTFOUND 0x8004_0169
+	/// The software installation data object in Active Directory already exists.
 	CS_E_OBJECT_ALREADY_EXISTS 0x8004_016a
+	/// The path to the software installation data in Active Directory is not correct.
 	CS_E_INVALID_PATH 0x8004_016b
+	/// A network error interrupted the operation.
 	CS_E_NETWORK_ERROR 0x8004_016c
+	/// The size of this object exceeds the maximum size set by the Administrator.
 	CS_E_ADMIN_LIMIT_EXCEEDED 0x8004_016d
+	/// The schema for the software installation data in Active Directory does not match the required schema.
 	CS_E_SCHEMA_MISMATCH 0x8004_016e
+	/// An error occurred in the software installation data in Active Directory.
 	CS_E_INTERNAL_ERROR 0x8004_016f
+	/// Cache not updated.
 	CACHE_E_NOCACHE_UPDATED 0x8004_0170
+	/// No verbs for OLE object.
 	OLEOBJ_E_NOVERBS 0x8004_0180
+	/// Invalid verb for OLE object.
 	OLEOBJ_E_INVALIDVERB 0x8004_0181
+	/// Undo is not available.
 	INPLACE_E_NOTUNDOABLE 0x8004_01a0
+	/// Space for tools is not available.
 	INPLACE_E_NOTOOLSPACE 0x8004_01a1
+	/// OLESTREAM Get method failed.
 	CONVERT10_E_OLESTREAM_GET 0x8004_01c0
+	/// OLESTREAM Put method failed.
 	CONVERT10_E_OLESTREAM_PUT 0x8004_01c1
+	/// Contents of the OLESTREAM not in correct format.
 	CONVERT10_E_OLESTREAM_FMT 0x8004_01c2
+	/// There was an error in a Windows GDI call while converting the bitmap to a DIB.
 	CONVERT10_E_OLESTREAM_BITMAP_TO_DIB 0x8004_01c3
+	/// Contents of the IStorage not in correct format.
 	CONVERT10_E_STG_FMT 0x8004_01c4
+	/// Contents of IStorage is missing one of the standard streams.
 	CONVERT10_E_STG_NO_STD_STREAM 0x8004_01c5
+	/// There was an error in a Windows GDI call while converting the DIB to a bitmap.
 	CONVERT10_E_STG_DIB_TO_BITMAP 0x8004_01c6
+	/// OpenClipboard Failed.
 	CLIPBRD_E_CANT_OPEN 0x8004_01d0
+	/// EmptyClipboard Failed.
 	CLIPBRD_E_CANT_EMPTY 0x8004_01d1
+	/// SetClipboard Failed.
 	CLIPBRD_E_CANT_SET 0x8004_01d2
+	/// Data on clipboard is invalid.
 	CLIPBRD_E_BAD_DATA 0x8004_01d3
+	/// CloseClipboard Failed.
 	CLIPBRD_E_CANT_CLOSE 0x8004_01d4
+	/// Moniker needs to be connected manually.
 	MK_E_CONNECTMANUALLY 0x8004_01e0
+	/// Operation exceeded deadline.
 	MK_E_EXCEEDEDDEADLINE 0x8004_01e1
+	/// Moniker needs to be generic.
 	MK_E_NEEDGENERIC 0x8004_01e2
+	/// Operation unavailable.
 	MK_E_UNAVAILABLE 0x8004_01e3
+	/// Invalid syntax.
 	MK_E_SYNTAX 0x8004_01e4
+	/// No object for moniker.
 	MK_E_NOOBJECT 0x8004_01e5
+	/// Bad extension for file.
 	MK_E_INVALIDEXTENSION 0x8004_01e6
+	/// Intermediate operation failed.
 	MK_E_INTERMEDIATEINTERFACENOTSUPPORTED 0x8004_01e7
+	/// Moniker is not bindable.
 	MK_E_NOTBINDABLE 0x8004_01e8
+	/// Moniker is not bound.
 	MK_E_NOTBOUND 0x8004_01e9
+	/// Moniker cannot open file.
 	MK_E_CANTOPENFILE 0x8004_01ea
+	/// User input required for operation to succeed.
 	MK_E_MUSTBOTHERUSER 0x8004_01eb
+	/// Moniker class has no inverse.
 	MK_E_NOINVERSE 0x8004_01ec
+	/// Moniker does not refer to storage.
 	MK_E_NOSTORAGE 0x8004_01ed
+	/// No common prefix.
 	MK_E_NOPREFIX 0x8004_01ee
+	/// Moniker could not be enumerated.
 	MK_E_ENUMERATION_FAILED 0x8004_01ef
+	/// CoInitialize has not been called.
 	CO_E_NOTINITIALIZED 0x8004_01f0
+	/// CoInitialize has already been called.
 	CO_E_ALREADYINITIALIZED 0x8004_01f1
+	/// Could not determine the class of the object.
 	CO_E_CANTDETERMINECLASS 0x8004_01f2
+	/// Invalid class string.
 	CO_E_CLASSSTRING 0x8004_01f3
+	/// Invalid interface string.
 	CO_E_IIDSTRING 0x8004_01f4
+	/// Application not found.
 	CO_E_APPNOTFOUND 0x8004_01f5
+	/// Application cannot be run more than once.
 	CO_E_APPSINGLEUSE 0x8004_01f6
+	/// Some error in application program.
 	CO_E_ERRORINAPP 0x8004_01f7
+	/// DLL for class not found.
 	CO_E_DLLNOTFOUND 0x8004_01f8
+	/// Error in the DLL.
 	CO_E_ERRORINDLL 0x8004_01f9
+	/// Wrong operating system or operating system version for the application.
 	CO_E_WRONGOSFORAPP 0x8004_01fa
+	/// Object is not registered.
 	CO_E_OBJNOTREG 0x8004_01fb
+	/// Object is already registered.
 	CO_E_OBJISREG 0x8004_01fc
+	/// Object is not connected to server.
 	CO_E_OBJNOTCONNECTED 0x8004_01fd
+	/// Application was launched but it didn't register a class factory.
 	CO_E_APPDIDNTREG 0x8004_01fe
+	/// Object has been released.
 	CO_E_RELEASED 0x8004_01ff
+	/// An event was able to invoke some but not all of the subscribers.
 	EVENT_S_SOME_SUBSCRIBERS_FAILED 0x0004_0200
+	/// An event was unable to invoke any of the subscribers.
 	EVENT_E_ALL_SUBSCRIBERS_FAILED 0x8004_0201
+	/// An event was delivered but there were no subscribers.
 	EVENT_S_NOSUBSCRIBERS 0x0004_0202
+	/// A syntax error occurred trying to evaluate a query string.
 	EVENT_E_QUERYSYNTAX 0x8004_0203
+	/// An invalid field name was used in a query string.
 	EVENT_E_QUERYFIELD 0x8004_0204
+	/// An unexpected exception was raised.
 	EVENT_E_INTERNALEXCEPTION 0x8004_0205
+	/// An unexpected internal error was detected.
 	EVENT_E_INTERNALERROR 0x8004_0206
+	/// The owner SID on a per-user subscription doesn't exist.
 	EVENT_E_INVALID_PER_USER_SID 0x8004_0207
+	/// A user-supplied component or subscriber raised an exception.
 	EVENT_E_USER_EXCEPTION 0x8004_0208
+	/// An interface has too many methods to fire events from.
 	EVENT_E_TOO_MANY_METHODS 0x8004_0209
+	/// A subscription cannot be stored unless its event class already exists.
 	EVENT_E_MISSING_EVENTCLASS 0x8004_020a
+	/// Not all the objects requested could be removed.
 	EVENT_E_NOT_ALL_REMOVED 0x8004_020b
+	/// COM+ is required for this operation, but is not installed.
 	EVENT_E_COMPLUS_NOT_INSTALLED 0x8004_020c
+	/// Cannot modify or delete an object that was not added using the COM+ Admin SDK.
 	EVENT_E_CANT_MODIFY_OR_DELETE_UNCONFIGURED_OBJECT 0x8004_020d
+	/// Cannot modify or delete an object that was added using the COM+ Admin SDK.
 	EVENT_E_CANT_MODIFY_OR_DELETE_CONFIGURED_OBJECT 0x8004_020e
+	/// The event class for this subscription is in an invalid partition.
 	EVENT_E_INVALID_EVENT_CLASS_PARTITION 0x8004_020f
+	/// The owner of the PerUser subscription is not logged on to the system specified.
 	EVENT_E_PER_USER_SID_NOT_LOGGED_ON 0x8004_0210
+	/// TabletPC inking error code. The property was not found, or supported by the recognizer.
 	TPC_E_INVALID_PROPERTY 0x8004_0241
+	/// TabletPC inking error code. No default tablet.
 	TPC_E_NO_DEFAULT_TABLET 0x8004_0212
+	/// TabletPC inking error code. Unknown property specified.
 	TPC_E_UNKNOWN_PROPERTY 0x8004_021b
+	/// TabletPC inking error code. An invalid input rectangle was specified.
 	TPC_E_INVALID_INPUT_RECT 0x8004_0219
+	/// TabletPC inking error code. The stroke object was deleted.
 	TPC_E_INVALID_STROKE 0x8004_0222
+	/// TabletPC inking error code. Initialization failure.
 	TPC_E_INITIALIZE_FAIL 0x8004_0223
+	/// TabletPC inking error code. The data required for the operation was not supplied.
 	TPC_E_NOT_RELEVANT 0x8004_0232
+	/// TabletPC inking error code. Invalid packet description.
 	TPC_E_INVALID_PACKET_DESCRIPTION 0x8004_0233
+	/// TabletPC inking error code. There are no handwriting recognizers registered.
 	TPC_E_RECOGNIZER_NOT_REGISTERED 0x8004_0235
+	/// TabletPC inking error code. User does not have the necessary rights to read recognizer information.
 	TPC_E_INVALID_RIGHTS 0x8004_0236
+	/// TabletPC inking error code. API calls were made in an incorrect order.
 	TPC_E_OUT_OF_ORDER_CALL 0x8004_0237
+	/// TabletPC inking error code. Queue is full.
 	TPC_E_QUEUE_FULL 0x8004_0238
+	/// TabletPC inking error code. RtpEnabled called multiple times.
 	TPC_E_INVALID_CONFIGURATION 0x8004_0239
+	/// TabletPC inking error code. A recognizer returned invalid data.
 	TPC_E_INVALID_DATA_FROM_RECOGNIZER 0x8004_023a
+	/// TabletPC inking error code. String was truncated.
 	TPC_S_TRUNCATED 0x0004_0252
+	/// TabletPC inking error code. Recognition or training was interrupted.
 	TPC_S_INTERRUPTED 0x0004_0253
+	/// TabletPC inking error code. No personalization update to the recognizer because no training data found.
 	TPC_S_NO_DATA_TO_PROCESS 0x0004_0254
+	/// Another single phase resource manager has already been enlisted in this transaction.
 	XACT_E_ALREADYOTHERSINGLEPHASE 0x8004_d000
+	/// A retaining commit or abort is not supported.
 	XACT_E_CANTRETAIN 0x8004_d001
+	/// The transaction failed to commit for an unknown reason. The transaction was aborted.
 	XACT_E_COMMITFAILED 0x8004_d002
+	/// Cannot call commit on this transaction object because the calling application did not initiate the transaction.
 	XACT_E_COMMITPREVENTED 0x8004_d003
+	/// Instead of committing, the resource heuristically aborted.
 	XACT_E_HEURISTICABORT 0x8004_d004
+	/// Instead of aborting, the resource heuristically committed.
 	XACT_E_HEURISTICCOMMIT 0x8004_d005
+	/// Some of the states of the resource were committed while others were aborted, likely because of heuristic decisions.
 	XACT_E_HEURISTICDAMAGE 0x8004_d006
+	/// Some of the states of the resource may have been committed while others may have been aborted, likely because of heuristic decisions.
 	XACT_E_HEURISTICDANGER 0x8004_d007
+	/// The requested isolation level is not valid or supported.
 	XACT_E_ISOLATIONLEVEL 0x8004_d008
+	/// The transaction manager doesn't support an asynchronous operation for this method.
 	XACT_E_NOASYNC 0x8004_d009
+	/// Unable to enlist in the transaction.
 	XACT_E_NOENLIST 0x8004_d00a
+	/// The requested semantics of retention of isolation across retaining commit and abort boundaries cannot be supported by this transaction implementation, or isoFlags was not equal to zero.
 	XACT_E_NOISORETAIN 0x8004_d00b
+	/// There is no resource presently associated with this enlistment.
 	XACT_E_NORESOURCE 0x8004_d00c
+	/// The transaction failed to commit due to the failure of optimistic concurrency control in at least one of the resource managers.
 	XACT_E_NOTCURRENT 0x8004_d00d
+	/// The transaction has already been implicitly or explicitly committed or aborted.
 	XACT_E_NOTRANSACTION 0x8004_d00e
+	/// An invalid combination of flags was specified.
 	XACT_E_NOTSUPPORTED 0x8004_d00f
+	/// The resource manager id is not associated with this transaction or the transaction manager.
 	XACT_E_UNKNOWNRMGRID 0x8004_d010
+	/// This method was called in the wrong state.
 	XACT_E_WRONGSTATE 0x8004_d011
+	/// The indicated unit of work does not match the unit of work expected by the resource manager.
 	XACT_E_WRONGUOW 0x8004_d012
+	/// An enlistment in a transaction already exists.
 	XACT_E_XTIONEXISTS 0x8004_d013
+	/// An import object for the transaction could not be found.
 	XACT_E_NOIMPORTOBJECT 0x8004_d014
+	/// The transaction cookie is invalid.
 	XACT_E_INVALIDCOOKIE 0x8004_d015
+	/// The transaction status is in doubt. A communication failure occurred, or a transaction manager or resource manager has failed.
 	XACT_E_INDOUBT 0x8004_d016
+	/// A time-out was specified, but time-outs are not supported.
 	XACT_E_NOTIMEOUT 0x8004_d017
+	/// The requested operation is already in progress for the transaction.
 	XACT_E_ALREADYINPROGRESS 0x8004_d018
+	/// The transaction has already been aborted.
 	XACT_E_ABORTED 0x8004_d019
+	/// The Transaction Manager returned a log full error.
 	XACT_E_LOGFULL 0x8004_d01a
+	/// The Transaction Manager is not available.
 	XACT_E_TMNOTAVAILABLE 0x8004_d01b
+	/// A connection with the transaction manager was lost.
 	XACT_E_CONNECTION_DOWN 0x8004_d01c
+	/// A request to establish a connection with the transaction manager was denied.
 	XACT_E_CONNECTION_DENIED 0x8004_d01d
+	/// Resource manager reenlistment to determine transaction status timed out.
 	XACT_E_REENLISTTIMEOUT 0x8004_d01e
+	/// The transaction manager failed to establish a connection with another TIP transaction manager.
 	XACT_E_TIP_CONNECT_FAILED 0x8004_d01f
+	/// The transaction manager encountered a protocol error with another TIP transaction manager.
 	XACT_E_TIP_PROTOCOL_ERROR 0x8004_d020
+	/// The transaction manager could not propagate a transaction from another TIP transaction manager.
 	XACT_E_TIP_PULL_FAILED 0x8004_d021
+	/// The Transaction Manager on the destination machine is not available.
 	XACT_E_DEST_TMNOTAVAILABLE 0x8004_d022
+	/// The Transaction Manager has disabled its support for TIP.
 	XACT_E_TIP_DISABLED 0x8004_d023
+	/// The transaction manager has disabled its support for remote/network transactions.
 	XACT_E_NETWORK_TX_DISABLED 0x8004_d024
+	/// The partner transaction manager has disabled its support for remote/network transactions.
 	XACT_E_PARTNER_NETWORK_TX_DISABLED 0x8004_d025
+	/// The transaction manager has disabled its support for XA transactions.
 	XACT_E_XA_TX_DISABLED 0x8004_d026
+	/// MSDTC was unable to read its configuration information.
 	XACT_E_UNABLE_TO_READ_DTC_CONFIG 0x8004_d027
+	/// MSDTC was unable to load the dtc proxy dll.
 	XACT_E_UNABLE_TO_LOAD_DTC_PROXY 0x8004_d028
+	/// The local transaction has aborted.
 	XACT_E_ABORTING 0x8004_d029
+	/// The MSDTC transaction manager was unable to push the transaction to the destination transaction manager due to communication problems.
 	XACT_E_PUSH_COMM_FAILURE 0x8004_d02a
+	/// The MSDTC transaction manager was unable to pull the transaction from the source transaction manager due to communication problems.
 	XACT_E_PULL_COMM_FAILURE 0x8004_d02b
+	/// The MSDTC transaction manager has disabled its support for SNA LU 6.2 transactions.
 	XACT_E_LU_TX_DISABLED 0x8004_d02c
+	/// XACT_E_CLERKNOTFOUND
 	XACT_E_CLERKNOTFOUND 0x8004_d080
+	/// XACT_E_CLERKEXISTS
 	XACT_E_CLERKEXISTS 0x8004_d081
+	/// XACT_E_RECOVERYINPROGRESS
 	XACT_E_RECOVERYINPROGRESS 0x8004_d082
+	/// XACT_E_TRANSACTIONCLOSED
 	XACT_E_TRANSACTIONCLOSED 0x8004_d083
+	/// XACT_E_INVALIDLSN
 	XACT_E_INVALIDLSN 0x8004_d084
+	/// XACT_E_REPLAYREQUEST
 	XACT_E_REPLAYREQUEST 0x8004_d085
+	/// An asynchronous operation was specified. The operation has begun, but its outcome is not known yet.
 	XACT_S_ASYNC 0x0004_d000
+	/// XACT_S_DEFECT
 	XACT_S_DEFECT 0x0004_d001
+	/// The method call succeeded because the transaction was read-only.
 	XACT_S_READONLY 0x0004_d002
+	/// The transaction was successfully aborted. However, this is a coordinated transaction, and some number of enlisted resources were aborted outright because they could not support abort-retaining semantics.
 	XACT_S_SOMENORETAIN 0x0004_d003
+	/// No changes were made during this call, but the sink wants another chance to look if any other sinks make further changes.
 	XACT_S_OKINFORM 0x0004_d004
+	/// The sink is content and wishes the transaction to proceed. Changes were made to one or more resources during this call.
 	XACT_S_MADECHANGESCONTENT 0x0004_d005
+	/// The sink is for the moment and wishes the transaction to proceed, but if other changes are made following this return by other event sinks then this sink wants another chance to look.
 	XACT_S_MADECHANGESINFORM 0x0004_d006
+	/// The transaction was successfully aborted. However, the abort was non-retaining.
 	XACT_S_ALLNORETAIN 0x0004_d007
+	/// An abort operation was already in progress.
 	XACT_S_ABORTING 0x0004_d008
+	/// The resource manager has performed a single-phase commit of the transaction.
 	XACT_S_SINGLEPHASE 0x0004_d009
+	/// The local transaction has not aborted.
 	XACT_S_LOCALLY_OK 0x0004_d00a
+	/// The resource manager has requested to be the coordinator (last resource manager) for the transaction.
 	XACT_S_LASTRESOURCEMANAGER 0x0004_d010
+	/// The root transaction wanted to commit, but transaction aborted.
 	CONTEXT_E_ABORTED 0x8004_e002
+	/// You made a method call on a COM+ component that has a transaction that has already aborted or in the process of aborting.
 	CONTEXT_E_ABORTING 0x8004_e003
+	/// There is no MTS object context.
 	CONTEXT_E_NOCONTEXT 0x8004_e004
+	/// The component is configured to use synchronization and this method call would cause a deadlock to occur.
 	CONTEXT_E_WOULD_DEADLOCK 0x8004_e005
+	/// The component is configured to use synchronization and a thread has timed out waiting to enter the context.
 	CONTEXT_E_SYNCH_TIMEOUT 0x8004_e006
+	/// You made a method call on a COM+ component that has a transaction that has already committed or aborted.
 	CONTEXT_E_OLDREF 0x8004_e007
+	/// The specified role was not configured for the application.
 	CONTEXT_E_ROLENOTFOUND 0x8004_e00c
+	/// COM+ was unable to talk to the Microsoft Distributed Transaction Coordinator.
 	CONTEXT_E_TMNOTAVAILABLE 0x8004_e00f
+	/// An unexpected error occurred during COM+ Activation.
 	CO_E_ACTIVATIONFAILED 0x8004_e021
+	/// COM+ Activation failed. Check the event log for more information.
 	CO_E_ACTIVATIONFAILED_EVENTLOGGED 0x8004_e022
+	/// COM+ Activation failed due to a catalog or configuration error.
 	CO_E_ACTIVATIONFAILED_CATALOGERROR 0x8004_e023
+	/// COM+ activation failed because the activation could not be completed in the specified amount of time.
 	CO_E_ACTIVATIONFAILED_TIMEOUT 0x8004_e024
+	/// COM+ Activation failed because an initialization function failed. Check the event log for more information.
 	CO_E_INITIALIZATIONFAILED 0x8004_e025
+	/// The requested operation requires that JIT be in the current context and it is not.
 	CONTEXT_E_NOJIT 0x8004_e026
+	/// The requested operation requires that the current context have a Transaction, and it does not.
 	CONTEXT_E_NOTRANSACTION 0x8004_e027
+	/// The components threading model has changed after install into a COM+ Application. Please re-install component.
 	CO_E_THREADINGMODEL_CHANGED 0x8004_e028
+	/// IIS intrinsics not available. Start your work with IIS.
 	CO_E_NOIISINTRINSICS 0x8004_e029
+	/// An attempt to write a cookie failed.
 	CO_E_NOCOOKIES 0x8004_e02a
+	/// An attempt to use a database generated a database specific error.
 	CO_E_DBERROR 0x8004_e02b
+	/// The COM+ component you created must use object pooling to work.
 	CO_E_NOTPOOLED 0x8004_e02c
+	/// The COM+ component you created must use object construction to work correctly.
 	CO_E_NOTCONSTRUCTED 0x8004_e02d
+	/// The COM+ component requires synchronization, and it is not configured for it.
 	CO_E_NOSYNCHRONIZATION 0x8004_e02e
+	/// The TxIsolation Level property for the COM+ component being created is stronger than the TxIsolationLevel for the "root" component for the transaction. The creation failed.
 	CO_E_ISOLEVELMISMATCH 0x8004_e02f
+	/// The component attempted to make a cross-context call between invocations of EnterTransactionScope and ExitTransactionScope. This is not allowed. Cross-context calls cannot be made while inside of a transaction scope.
 	CO_E_CALL_OUT_OF_TX_SCOPE_NOT_ALLOWED 0x8004_e030
+	/// The component made a call to EnterTransactionScope, but did not make a corresponding call to ExitTransactionScope before returning.
 	CO_E_EXIT_TRANSACTION_SCOPE_NOT_CALLED 0x8004_e031
+	/// Use the registry database to provide the requested information.
 	OLE_S_USEREG 0x0004_0000
+	/// Success, but static.
 	OLE_S_STATIC 0x0004_0001
+	/// Macintosh clipboard format.
 	OLE_S_MAC_CLIPFORMAT 0x0004_0002
+	/// Successful drop took place.
 	DRAGDROP_S_DROP 0x0004_0100
+	/// Drag-drop operation canceled.
 	DRAGDROP_S_CANCEL 0x0004_0101
+	/// Use the default cursor.
 	DRAGDROP_S_USEDEFAULTCURSORS 0x0004_0102
+	/// Data has same FORMATETC.
 	DATA_S_SAMEFORMATETC 0x0004_0130
+	/// View is already frozen.
 	VIEW_S_ALREADY_FROZEN 0x0004_0140
+	/// FORMATETC not supported.
 	CACHE_S_FORMATETC_NOTSUPPORTED 0x0004_0170
+	/// Same cache.
 	CACHE_S_SAMECACHE 0x0004_0171
+	/// Some cache(s) not updated.
 	CACHE_S_SOMECACHES_NOTUPDATED 0x0004_0172
+	/// Invalid verb for OLE object.
 	OLEOBJ_S_INVALIDVERB 0x0004_0180
+	/// Verb number is valid but verb cannot be done now.
 	OLEOBJ_S_CANNOT_DOVERB_NOW 0x0004_0181
+	/// Invalid window handle passed.
 	OLEOBJ_S_INVALIDHWND 0x0004_0182
+	/// Message is too long; some of it had to be truncated before displaying.
 	INPLACE_S_TRUNCATED 0x0004_01a0
+	/// Unable to convert OLESTREAM to IStorage.
 	CONVERT10_S_NO_PRESENTATION 0x0004_01c0
+	/// Moniker reduced to itself.
 	MK_S_REDUCED_TO_SELF 0x0004_01e2
+	/// Common prefix is this moniker.
 	MK_S_ME 0x0004_01e4
+	/// Common prefix is input moniker.
 	MK_S_HIM 0x0004_01e5
+	/// Common prefix is both monikers.
 	MK_S_US 0x0004_01e6
+	/// Moniker is already registered in running object table.
 	MK_S_MONIKERALREADYREGISTERED 0x0004_01e7
+	/// The task is ready to run at its next scheduled time.
 	SCHED_S_TASK_READY 0x0004_1300
+	/// The task is currently running.
 	SCHED_S_TASK_RUNNING 0x0004_1301
+	/// The task will not run at the scheduled times because it has been disabled.
 	SCHED_S_TASK_DISABLED 0x0004_1302
+	/// The task has not yet run.
 	SCHED_S_TASK_HAS_NOT_RUN 0x0004_1303
+	/// There are no more runs scheduled for this task.
 	SCHED_S_TASK_NO_MORE_RUNS 0x0004_1304
+	/// One or more of the properties that are needed to run this task on a schedule have not been set.
 	SCHED_S_TASK_NOT_SCHEDULED 0x0004_1305
+	/// The last run of the task was terminated by the user.
 	SCHED_S_TASK_TERMINATED 0x0004_1306
+	/// Either the task has no triggers or the existing triggers are disabled or not set.
 	SCHED_S_TASK_NO_VALID_TRIGGERS 0x0004_1307
+	/// Event triggers don't have set run times.
 	SCHED_S_EVENT_TRIGGER 0x0004_1308
+	/// Trigger not found.
 	SCHED_E_TRIGGER_NOT_FOUND 0x8004_1309
+	/// One or more of the properties that are needed to run this task have not been set.
 	SCHED_E_TASK_NOT_READY 0x8004_130a
+	/// There is no running instance of the task.
 	SCHED_E_TASK_NOT_RUNNING 0x8004_130b
+	/// The Task Scheduler Service is not installed on this computer.
 	SCHED_E_SERVICE_NOT_INSTALLED 0x8004_130c
+	/// The task object could not be opened.
 	SCHED_E_CANNOT_OPEN_TASK 0x8004_130d
+	/// The object is either an invalid task object or is not a task object.
 	SCHED_E_INVALID_TASK 0x8004_130e
+	/// No account information could be found in the Task Scheduler security database for the task indicated.
 	SCHED_E_ACCOUNT_INFORMATION_NOT_SET 0x8004_130f
+	/// Unable to establish existence of the account specified.
 	SCHED_E_ACCOUNT_NAME_NOT_FOUND 0x8004_1310
+	/// Corruption was detected in the Task Scheduler security database; the database has been reset.
 	SCHED_E_ACCOUNT_DBASE_CORRUPT 0x8004_1311
+	/// Task Scheduler security services are available only on Windows NT.
 	SCHED_E_NO_SECURITY_SERVICES 0x8004_1312
+	/// The task object version is either unsupported or invalid.
 	SCHED_E_UNKNOWN_OBJECT_VERSION 0x8004_1313
+	/// The task has been configured with an unsupported combination of account settings and run time options.
 	SCHED_E_UNSUPPORTED_ACCOUNT_OPTION 0x8004_1314
+	/// The Task Scheduler Service is not running.
 	SCHED_E_SERVICE_NOT_RUNNING 0x8004_1315
+	/// The task XML contains an unexpected node.
 	SCHED_E_UNEXPECTEDNODE 0x8004_1316
+	/// The task XML contains an element or attribute from an unexpected namespace.
 	SCHED_E_NAMESPACE 0x8004_1317
+	/// The task XML contains a value which is incorrectly formatted or out of range.
 	SCHED_E_INVALIDVALUE 0x8004_1318
+	/// The task XML is missing a required element or attribute.
 	SCHED_E_MISSINGNODE 0x8004_1319
+	/// The task XML is malformed.
 	SCHED_E_MALFORMEDXML 0x8004_131a
+	/// The task is registered, but not all specified triggers will start the task.
 	SCHED_S_SOME_TRIGGERS_FAILED 0x0004_131b
+	/// The task is registered, but may fail to start. Batch logon privilege needs to be enabled for the task principal.
 	SCHED_S_BATCH_LOGON_PROBLEM 0x0004_131c
+	/// The task XML contains too many nodes of the same type.
 	SCHED_E_TOO_MANY_NODES 0x8004_131d
+	/// The task cannot be started after the trigger end boundary.
 	SCHED_E_PAST_END_BOUNDARY 0x8004_131e
+	/// An instance of this task is already running.
 	SCHED_E_ALREADY_RUNNING 0x8004_131f
+	/// The task will not run because the user is not logged on.
 	SCHED_E_USER_NOT_LOGGED_ON 0x8004_1320
+	/// The task image is corrupt or has been tampered with.
 	SCHED_E_INVALID_TASK_HASH 0x8004_1321
+	/// The Task Scheduler service is not available.
 	SCHED_E_SERVICE_NOT_AVAILABLE 0x8004_1322
+	/// The Task Scheduler service is too busy to handle your request. Please try again later.
 	SCHED_E_SERVICE_TOO_BUSY 0x8004_1323
+	/// The Task Scheduler service attempted to run the task, but the task did not run due to one of the constraints in the task definition.
 	SCHED_E_TASK_ATTEMPTED 0x8004_1324
+	/// The Task Scheduler service has asked the task to run.
 	SCHED_S_TASK_QUEUED 0x0004_1325
+	/// The task is disabled.
 	SCHED_E_TASK_DISABLED 0x8004_1326
+	/// The task has properties that are not compatible with previous versions of Windows.
 	SCHED_E_TASK_NOT_V1_COMPAT 0x8004_1327
+	/// The task settings do not allow the task to start on demand.
 	SCHED_E_START_ON_DEMAND 0x8004_1328
+	/// The combination of properties that task is using is not compatible with the scheduling engine.
 	SCHED_E_TASK_NOT_UBPM_COMPAT 0x8004_1329
+	/// The task definition uses a deprecated feature.
 	SCHED_E_DEPRECATED_FEATURE_USED 0x8004_1330
+	/// Attempt to create a class object failed.
 	CO_E_CLASS_CREATE_FAILED 0x8008_0001
+	/// OLE service could not bind object.
 	CO_E_SCM_ERROR 0x8008_0002
+	/// RPC communication failed with OLE service.
 	CO_E_SCM_RPC_FAILURE 0x8008_0003
+	/// Bad path to object.
 	CO_E_BAD_PATH 0x8008_0004
+	/// Server execution failed.
 	CO_E_SERVER_EXEC_FAILURE 0x8008_0005
+	/// OLE service could not communicate with the object server.
 	CO_E_OBJSRV_RPC_FAILURE 0x8008_0006
+	/// Moniker path could not be normalized.
 	MK_E_NO_NORMALIZED 0x8008_0007
+	/// Object server is stopping when OLE service contacts it.
 	CO_E_SERVER_STOPPING 0x8008_0008
+	/// An invalid root block pointer was specified.
 	MEM_E_INVALID_ROOT 0x8008_0009
+	/// An allocation chain contained an invalid link pointer.
 	MEM_E_INVALID_LINK 0x8008_0010
+	/// The requested allocation size was too large.
 	MEM_E_INVALID_SIZE 0x8008_0011
+	/// Not all the requested interfaces were available.
 	CO_S_NOTALLINTERFACES 0x0008_0012
+	/// The specified machine name was not found in the cache.
 	CO_S_MACHINENAMENOTFOUND 0x0008_0013
+	/// The activation requires a display name to be present under the class key.
 	CO_E_MISSING_DISPLAYNAME 0x8008_0015
+	/// The activation requires that the RunAs value for the application is Activate As Activator.
 	CO_E_RUNAS_VALUE_MUST_BE_AAA 0x8008_0016
+	/// The class is not configured to support Elevated activation.
 	CO_E_ELEVATION_DISABLED 0x8008_0017
+	/// Appx packaging API has encountered an internal error.
 	APPX_E_PACKAGING_INTERNAL 0x8008_0200
+	/// The file is not a valid Appx package because its contents are interleaved.
 	APPX_E_INTERLEAVING_NOT_ALLOWED 0x8008_0201
+	/// The file is not a valid Appx package because it contains OPC relationships.
 	APPX_E_RELATIONSHIPS_NOT_ALLOWED 0x8008_0202
+	/// The file is not a valid Appx package because it is missing a manifest or block map, or missing a signature file when the code integrity file is present.
 	APPX_E_MISSING_REQUIRED_FILE 0x8008_0203
+	/// The Appx package's manifest is invalid.
 	APPX_E_INVALID_MANIFEST 0x8008_0204
+	/// The Appx package's block map is invalid.
 	APPX_E_INVALID_BLOCKMAP 0x8008_0205
+	/// The Appx package's content cannot be read because it is corrupt.
 	APPX_E_CORRUPT_CONTENT 0x8008_0206
+	/// The computed hash value of the block does not match the one stored in the block map.
 	APPX_E_BLOCK_HASH_INVALID 0x8008_0207
+	/// The requested byte range is over 4GB when translated to byte range of blocks.
 	APPX_E_REQUESTED_RANGE_TOO_LARGE 0x8008_0208
+	/// The SIP_SUBJECTINFO structure used to sign the package didn't contain the required data.
 	APPX_E_INVALID_SIP_CLIENT_DATA 0x8008_0209
+	/// The APPX_KEY_INFO structure used to encrypt or decrypt the package contains invalid data.
 	APPX_E_INVALID_KEY_INFO 0x8008_020a
+	/// The Appx package's content group map is invalid.
 	APPX_E_INVALID_CONTENTGROUPMAP 0x8008_020b
+	/// The .appinstaller file is invalid.
 	APPX_E_INVALID_APPINSTALLER 0x8008_020c
+	/// The baseline package version in delta package does not match the version in the baseline package to be updated.
 	APPX_E_DELTA_BASELINE_VERSION_MISMATCH 0x8008_020d
+	/// The delta package is missing a file from the updated package.
 	APPX_E_DELTA_PACKAGE_MISSING_FILE 0x8008_020e
+	/// The delta package is invalid.
 	APPX_E_INVALID_DELTA_PACKAGE 0x8008_020f
+	/// The delta appended package is not allowed for the current operation.
 	APPX_E_DELTA_APPENDED_PACKAGE_NOT_ALLOWED 0x8008_0210
+	/// The packaging layout file is invalid.
 	APPX_E_INVALID_PACKAGING_LAYOUT 0x8008_0211
+	/// The packageSignConfig file is invalid.
 	APPX_E_INVALID_PACKAGESIGNCONFIG 0x8008_0212
+	/// The resources.pri file is not allowed when there are no resource elements in the package manifest.
 	APPX_E_RESOURCESPRI_NOT_ALLOWED 0x8008_0213
+	/// The compression state of file on disk and in the package is inconsistent.
 	APPX_E_FILE_COMPRESSION_MISMATCH 0x8008_0214
+	/// Non appx extensions are not allowed for payload packages targeting older platforms.
 	APPX_E_INVALID_PAYLOAD_PACKAGE_EXTENSION 0x8008_0215
+	/// The encryptionExclusionFileList file is invalid.
 	APPX_E_INVALID_ENCRYPTION_EXCLUSION_FILE_LIST 0x8008_0216
+	/// The background task activation is spurious.
 	BT_E_SPURIOUS_ACTIVATION 0x8008_0300
+	/// Unknown interface.
 	DISP_E_UNKNOWNINTERFACE 0x8002_0001
+	/// Member not found.
 	DISP_E_MEMBERNOTFOUND 0x8002_0003
+	/// Parameter not found.
 	DISP_E_PARAMNOTFOUND 0x8002_0004
+	/// Type mismatch.
 	DISP_E_TYPEMISMATCH 0x8002_0005
+	/// Unknown name.
 	DISP_E_UNKNOWNNAME 0x8002_0006
+	/// No named arguments.
 	DISP_E_NONAMEDARGS 0x8002_0007
+	/// Bad variable type.
 	DISP_E_BADVARTYPE 0x8002_0008
+	/// Exception occurred.
 	DISP_E_EXCEPTION 0x8002_0009
+	/// Out of present range.
 	DISP_E_OVERFLOW 0x8002_000a
+	/// Invalid index.
 	DISP_E_BADINDEX 0x8002_000b
+	/// Unknown language.
 	DISP_E_UNKNOWNLCID 0x8002_000c
+	/// Memory is locked.
 	DISP_E_ARRAYISLOCKED 0x8002_000d
+	/// Invalid number of parameters.
 	DISP_E_BADPARAMCOUNT 0x8002_000e
+	/// Parameter not optional.
 	DISP_E_PARAMNOTOPTIONAL 0x8002_000f
+	/// Invalid callee.
 	DISP_E_BADCALLEE 0x8002_0010
+	/// Does not support a collection.
 	DISP_E_NOTACOLLECTION 0x8002_0011
+	/// Division by zero.
 	DISP_E_DIVBYZERO 0x8002_0012
+	/// Buffer too small.
 	DISP_E_BUFFERTOOSMALL 0x8002_0013
+	/// Buffer too small.
 	TYPE_E_BUFFERTOOSMALL 0x8002_8016
+	/// Field name not defined in the record.
 	TYPE_E_FIELDNOTFOUND 0x8002_8017
+	/// Old format or invalid type library.
 	TYPE_E_INVDATAREAD 0x8002_8018
+	/// Old format or invalid type library.
 	TYPE_E_UNSUPFORMAT 0x8002_8019
+	/// Error accessing the OLE registry.
 	TYPE_E_REGISTRYACCESS 0x8002_801c
+	/// Library not registered.
 	TYPE_E_LIBNOTREGISTERED 0x8002_801d
+	/// Bound to unknown type.
 	TYPE_E_UNDEFINEDTYPE 0x8002_8027
+	/// Qualified name disallowed.
 	TYPE_E_QUALIFIEDNAMEDISALLOWED 0x8002_8028
+	/// Invalid forward reference, or reference to uncompiled type.
 	TYPE_E_INVALIDSTATE 0x8002_8029
+	/// Type mismatch.
 	TYPE_E_WRONGTYPEKIND 0x8002_802a
+	/// Element not found.
 	TYPE_E_ELEMENTNOTFOUND 0x8002_802b
+	/// Name already exists in the library.
 	TYPE_E_AMBIGUOUSNAME 0x8002_802c
+	/// Name already exists in the library.
 	TYPE_E_NAMECONFLICT 0x8002_802d
+	/// Unknown LCID.
 	TYPE_E_UNKNOWNLCID 0x8002_802e
+	/// Function not defined in specified DLL.
 	TYPE_E_DLLFUNCTIONNOTFOUND 0x8002_802f
+	/// Wrong module kind for the operation.
 	TYPE_E_BADMODULEKIND 0x8002_88bd
+	/// Size may not exceed 64K.
 	TYPE_E_SIZETOOBIG 0x8002_88c5
+	/// Duplicate ID in inheritance hierarchy.
 	TYPE_E_DUPLICATEID 0x8002_88c6
+	/// Incorrect inheritance depth in standard OLE hmember.
 	TYPE_E_INVALIDID 0x8002_88cf
+	/// Type mismatch.
 	TYPE_E_TYPEMISMATCH 0x8002_8ca0
+	/// Invalid number of arguments.
 	TYPE_E_OUTOFBOUNDS 0x8002_8ca1
+	/// I/O Error.
 	TYPE_E_IOERROR 0x8002_8ca2
+	/// Error creating unique tmp file.
 	TYPE_E_CANTCREATETMPFILE 0x8002_8ca3
+	/// Error loading type library/DLL.
 	TYPE_E_CANTLOADLIBRARY 0x8002_9c4a
+	/// Inconsistent property functions.
 	TYPE_E_INCONSISTENTPROPFUNCS 0x8002_9c83
+	/// Circular dependency between types/modules.
 	TYPE_E_CIRCULARTYPE 0x8002_9c84
+	/// Unable to perform requested operation.
 	STG_E_INVALIDFUNCTION 0x8003_0001
+	/// %1 could not be found.
 	STG_E_FILENOTFOUND 0x8003_0002
+	/// The path %1 could not be found.
 	STG_E_PATHNOTFOUND 0x8003_0003
+	/// There are insufficient resources to open another file.
 	STG_E_TOOMANYOPENFILES 0x8003_0004
+	/// Access Denied.
 	STG_E_ACCESSDENIED 0x8003_0005
+	/// Attempted an operation on an invalid object.
 	STG_E_INVALIDHANDLE 0x8003_0006
+	/// There is insufficient memory available to complete operation.
 	STG_E_INSUFFICIENTMEMORY 0x8003_0008
+	/// Invalid pointer error.
 	STG_E_INVALIDPOINTER 0x8003_0009
+	/// There are no more entries to return.
 	STG_E_NOMOREFILES 0x8003_0012
+	/// Disk is write-protected.
 	STG_E_DISKISWRITEPROTECTED 0x8003_0013
+	/// An error occurred during a seek operation.
 	STG_E_SEEKERROR 0x8003_0019
+	/// A disk error occurred during a write operation.
 	STG_E_WRITEFAULT 0x8003_001d
+	/// A disk error occurred during a read operation.
 	STG_E_READFAULT 0x8003_001e
+	/// A share violation has occurred.
 	STG_E_SHAREVIOLATION 0x8003_0020
+	/// A lock violation has occurred.
 	STG_E_LOCKVIOLATION 0x8003_0021
+	/// %1 already exists.
 	STG_E_FILEALREADYEXISTS 0x8003_0050
+	/// Invalid parameter error.
 	STG_E_INVALIDPARAMETER 0x8003_0057
+	/// There is insufficient disk space to complete operation.
 	STG_E_MEDIUMFULL 0x8003_0070
+	/// Illegal write of non-simple property to simple property set.
 	STG_E_PROPSETMISMATCHED 0x8003_00f0
+	/// An API call exited abnormally.
 	STG_E_ABNORMALAPIEXIT 0x8003_00fa
+	/// The file %1 is not a valid compound file.
 	STG_E_INVALIDHEADER 0x8003_00fb
+	/// The name %1 is not valid.
 	STG_E_INVALIDNAME 0x8003_00fc
+	/// An unexpected error occurred.
 	STG_E_UNKNOWN 0x8003_00fd
+	/// That function is not implemented.
 	STG_E_UNIMPLEMENTEDFUNCTION 0x8003_00fe
+	/// Invalid flag error.
 	STG_E_INVALIDFLAG 0x8003_00ff
+	/// Attempted to use an object that is busy.
 	STG_E_INUSE 0x8003_0100
+	/// The storage has been changed since the last commit.
 	STG_E_NOTCURRENT 0x8003_0101
+	/// Attempted to use an object that has ceased to exist.
 	STG_E_REVERTED 0x8003_0102
+	/// Can't save.
 	STG_E_CANTSAVE 0x8003_0103
+	/// The compound file %1 was produced with an incompatible version of storage.
 	STG_E_OLDFORMAT 0x8003_0104
+	/// The compound file %1 was produced with a newer version of storage.
 	STG_E_OLDDLL 0x8003_0105
+	/// Share.exe or equivalent is required for operation.
 	STG_E_SHAREREQUIRED 0x8003_0106
+	/// Illegal operation called on non-file based storage.
 	STG_E_NOTFILEBASEDSTORAGE 0x8003_0107
+	/// Illegal operation called on object with extant marshallings.
 	STG_E_EXTANTMARSHALLINGS 0x8003_0108
+	/// The docfile has been corrupted.
 	STG_E_DOCFILECORRUPT 0x8003_0109
+	/// OLE32.DLL has been loaded at the wrong address.
 	STG_E_BADBASEADDRESS 0x8003_0110
+	/// The compound file is too large for the current implementation.
 	STG_E_DOCFILETOOLARGE 0x8003_0111
+	/// The compound file was not created with the STGM_SIMPLE flag.
 	STG_E_NOTSIMPLEFORMAT 0x8003_0112
+	/// The file download was aborted abnormally. The file is incomplete.
 	STG_E_INCOMPLETE 0x8003_0201
+	/// The file download has been terminated.
 	STG_E_TERMINATED 0x8003_0202
+	/// The underlying file was converted to compound file format.
 	STG_S_CONVERTED 0x0003_0200
+	/// The storage operation should block until more data is available.
 	STG_S_BLOCK 0x0003_0201
+	/// The storage operation should retry immediately.
 	STG_S_RETRYNOW 0x0003_0202
+	/// The notified event sink will not influence the storage operation.
 	STG_S_MONITORING 0x0003_0203
+	/// Multiple opens prevent consolidated (commit succeeded).
 	STG_S_MULTIPLEOPENS 0x0003_0204
+	/// Consolidation of the storage file failed (commit succeeded).
 	STG_S_CONSOLIDATIONFAILED 0x0003_0205
+	/// Consolidation of the storage file is inappropriate (commit succeeded).
 	STG_S_CANNOTCONSOLIDATE 0x0003_0206
+	/// The device needs to be power cycled (commit succeeded).
 	STG_S_POWER_CYCLE_REQUIRED 0x0003_0207
+	/// The specified firmware slot is invalid.
 	STG_E_FIRMWARE_SLOT_INVALID 0x8003_0208
+	/// The specified firmware image is invalid.
 	STG_E_FIRMWARE_IMAGE_INVALID 0x8003_0209
+	/// The storage device is unresponsive.
 	STG_E_DEVICE_UNRESPONSIVE 0x8003_020a
+	/// Generic Copy Protection Error.
 	STG_E_STATUS_COPY_PROTECTION_FAILURE 0x8003_0305
+	/// Copy Protection Error - DVD CSS Authentication failed.
 	STG_E_CSS_AUTHENTICATION_FAILURE 0x8003_0306
+	/// Copy Protection Error - The given sector does not have a valid CSS key.
 	STG_E_CSS_KEY_NOT_PRESENT 0x8003_0307
+	/// Copy Protection Error - DVD session key not established.
 	STG_E_CSS_KEY_NOT_ESTABLISHED 0x8003_0308
+	/// Copy Protection Error - The read failed because the sector is encrypted.
 	STG_E_CSS_SCRAMBLED_SECTOR 0x8003_0309
+	/// Copy Protection Error - The current DVD's region does not correspond to the region setting of the drive.
 	STG_E_CSS_REGION_MISMATCH 0x8003_030a
+	/// Copy Protection Error - The drive's region setting may be permanent or the number of user resets has been exhausted.
 	STG_E_RESETS_EXHAUSTED 0x8003_030b
+	/// Call was rejected by callee.
 	RPC_E_CALL_REJECTED 0x8001_0001
+	/// Call was canceled by the message filter.
 	RPC_E_CALL_CANCELED 0x8001_0002
+	/// The caller is dispatching an intertask SendMessage call and cannot call out via PostMessage.
 	RPC_E_CANTPOST_INSENDCALL 0x8001_0003
+	/// The caller is dispatching an asynchronous call and cannot make an outgoing call on behalf of this call.
 	RPC_E_CANTCALLOUT_INASYNCCALL 0x8001_0004
+	/// It is illegal to call out while inside message filter.
 	RPC_E_CANTCALLOUT_INEXTERNALCALL 0x8001_0005
+	/// The connection terminated or is in a bogus state and cannot be used any more. Other connections are still valid.
 	RPC_E_CONNECTION_TERMINATED 0x8001_0006
+	/// The callee (server (not server application)) is not available and disappeared; all connections are invalid. The call may have executed.
 	RPC_E_SERVER_DIED 0x8001_0007
+	/// The caller (client) disappeared while the callee (server) was processing a call.
 	RPC_E_CLIENT_DIED 0x8001_0008
+	/// The data packet with the marshalled parameter data is incorrect.
 	RPC_E_INVALID_DATAPACKET 0x8001_0009
+	/// The call was not transmitted properly; the message queue was full and was not emptied after yielding.
 	RPC_E_CANTTRANSMIT_CALL 0x8001_000a
+	/// The client (caller) cannot marshall the parameter data - low memory, etc.
 	RPC_E_CLIENT_CANTMARSHAL_DATA 0x8001_000b
+	/// The client (caller) cannot unmarshall the return data - low memory, etc.
 	RPC_E_CLIENT_CANTUNMARSHAL_DATA 0x8001_000c
+	/// The server (callee) cannot marshall the return data - low memory, etc.
 	RPC_E_SERVER_CANTMARSHAL_DATA 0x8001_000d
+	/// The server (callee) cannot unmarshall the parameter data - low memory, etc.
 	RPC_E_SERVER_CANTUNMARSHAL_DATA 0x8001_000e
+	/// Received data is invalid; could be server or client data.
 	RPC_E_INVALID_DATA 0x8001_000f
+	/// A particular parameter is invalid and cannot be (un)marshalled.
 	RPC_E_INVALID_PARAMETER 0x8001_0010
+	/// There is no second outgoing call on same channel in DDE conversation.
 	RPC_E_CANTCALLOUT_AGAIN 0x8001_0011
+	/// The callee (server (not server application)) is not available and disappeared; all connections are invalid. The call did not execute.
 	RPC_E_SERVER_DIED_DNE 0x8001_0012
+	/// System call failed.
 	RPC_E_SYS_CALL_FAILED 0x8001_0100
+	/// Could not allocate some required resource (memory, events, ...)
 	RPC_E_OUT_OF_RESOURCES 0x8001_0101
+	/// Attempted to make calls on more than one thread in single threaded mode.
 	RPC_E_ATTEMPTED_MULTITHREAD 0x8001_0102
+	/// The requested interface is not registered on the server object.
 	RPC_E_NOT_REGISTERED 0x8001_0103
+	/// RPC could not call the server or could not return the results of calling the server.
 	RPC_E_FAULT 0x8001_0104
+	/// The server threw an exception.
 	RPC_E_SERVERFAULT 0x8001_0105
+	/// Cannot change thread mode after it is set.
 	RPC_E_CHANGED_MODE 0x8001_0106
+	/// The method called does not exist on the server.
 	RPC_E_INVALIDMETHOD 0x8001_0107
+	/// The object invoked has disconnected from its clients.
 	RPC_E_DISCONNECTED 0x8001_0108
+	/// The object invoked chose not to process the call now. Try again later.
 	RPC_E_RETRY 0x8001_0109
+	/// The message filter indicated that the application is busy.
 	RPC_E_SERVERCALL_RETRYLATER 0x8001_010a
+	/// The message filter rejected the call.
 	RPC_E_SERVERCALL_REJECTED 0x8001_010b
+	/// A call control interfaces was called with invalid data.
 	RPC_E_INVALID_CALLDATA 0x8001_010c
+	/// An outgoing call cannot be made since the application is dispatching an input-synchronous call.
 	RPC_E_CANTCALLOUT_ININPUTSYNCCALL 0x8001_010d
+	/// The application called an interface that was marshalled for a different thread.
 	RPC_E_WRONG_THREAD 0x8001_010e
+	/// CoInitialize has not been called on the current thread.
 	RPC_E_THREAD_NOT_INIT 0x8001_010f
+	/// The version of OLE on the client and server machines does not match.
 	RPC_E_VERSION_MISMATCH 0x8001_0110
+	/// OLE received a packet with an invalid header.
 	RPC_E_INVALID_HEADER 0x8001_0111
+	/// OLE received a packet with an invalid extension.
 	RPC_E_INVALID_EXTENSION 0x8001_0112
+	/// The requested object or interface does not exist.
 	RPC_E_INVALID_IPID 0x8001_0113
+	/// The requested object does not exist.
 	RPC_E_INVALID_OBJECT 0x8001_0114
+	/// OLE has sent a request and is waiting for a reply.
 	RPC_S_CALLPENDING 0x8001_0115
+	/// OLE is waiting before retrying a request.
 	RPC_S_WAITONTIMER 0x8001_0116
+	/// Call context cannot be accessed after call completed.
 	RPC_E_CALL_COMPLETE 0x8001_0117
+	/// Impersonate on unsecure calls is not supported.
 	RPC_E_UNSECURE_CALL 0x8001_0118
+	/// Security must be initialized before any interfaces are marshalled or unmarshalled. It cannot be changed once initialized.
 	RPC_E_TOO_LATE 0x8001_0119
+	/// No security packages are installed on this machine or the user is not logged on or there are no compatible security packages between the client and server.
 	RPC_E_NO_GOOD_SECURITY_PACKAGES 0x8001_011a
+	/// Access is denied.
 	RPC_E_ACCESS_DENIED 0x8001_011b
+	/// Remote calls are not allowed for this process.
 	RPC_E_REMOTE_DISABLED 0x8001_011c
+	/// The marshaled interface data packet (OBJREF) has an invalid or unknown format.
 	RPC_E_INVALID_OBJREF 0x8001_011d
+	/// No context is associated with this call. This happens for some custom marshalled calls and on the client side of the call.
 	RPC_E_NO_CONTEXT 0x8001_011e
+	/// This operation returned because the timeout period expired.
 	RPC_E_TIMEOUT 0x8001_011f
+	/// There are no synchronize objects to wait on.
 	RPC_E_NO_SYNC 0x8001_0120
+	/// Full subject issuer chain SSL principal name expected from the server.
 	RPC_E_FULLSIC_REQUIRED 0x8001_0121
+	/// Principal name is not a valid MSSTD name.
 	RPC_E_INVALID_STD_NAME 0x8001_0122
+	/// Unable to impersonate DCOM client.
 	CO_E_FAILEDTOIMPERSONATE 0x8001_0123
+	/// Unable to obtain server's security context.
 	CO_E_FAILEDTOGETSECCTX 0x8001_0124
+	/// Unable to open the access token of the current thread.
 	CO_E_FAILEDTOOPENTHREADTOKEN 0x8001_0125
+	/// Unable to obtain user info from an access token.
 	CO_E_FAILEDTOGETTOKENINFO 0x8001_0126
+	/// The client who called IAccessControl::IsAccessPermitted was not the trustee provided to the method.
 	CO_E_TRUSTEEDOESNTMATCHCLIENT 0x8001_0127
+	/// Unable to obtain the client's security blanket.
 	CO_E_FAILEDTOQUERYCLIENTBLANKET 0x8001_0128
+	/// Unable to set a discretionary ACL into a security descriptor.
 	CO_E_FAILEDTOSETDACL 0x8001_0129
+	/// The system function, AccessCheck, returned false.
 	CO_E_ACCESSCHECKFAILED 0x8001_012a
+	/// Either NetAccessDel or NetAccessAdd returned an error code.
 	CO_E_NETACCESSAPIFAILED 0x8001_012b
+	/// One of the trustee strings provided by the user did not conform to the (Domain)\(Name) syntax and it was not the "*" string.
 	CO_E_WRONGTRUSTEENAMESYNTAX 0x8001_012c
+	/// One of the security identifiers provided by the user was invalid.
 	CO_E_INVALIDSID 0x8001_012d
+	/// Unable to convert a wide character trustee string to a multibyte trustee string.
 	CO_E_CONVERSIONFAILED 0x8001_012e
+	/// Unable to find a security identifier that corresponds to a trustee string provided by the user.
 	CO_E_NOMATCHINGSIDFOUND 0x8001_012f
+	/// The system function, LookupAccountSID, failed.
 	CO_E_LOOKUPACCSIDFAILED 0x8001_0130
+	/// Unable to find a trustee name that corresponds to a security identifier provided by the user.
 	CO_E_NOMATCHINGNAMEFOUND 0x8001_0131
+	/// The system function, LookupAccountName, failed.
 	CO_E_LOOKUPACCNAMEFAILED 0x8001_0132
+	/// Unable to set or reset a serialization handle.
 	CO_E_SETSERLHNDLFAILED 0x8001_0133
+	/// Unable to obtain the Windows directory.
 	CO_E_FAILEDTOGETWINDIR 0x8001_0134
+	/// Path too long.
 	CO_E_PATHTOOLONG 0x8001_0135
+	/// Unable to generate a uuid.
 	CO_E_FAILEDTOGENUUID 0x8001_0136
+	/// Unable to create file.
 	CO_E_FAILEDTOCREATEFILE 0x8001_0137
+	/// Unable to close a serialization handle or a file handle.
 	CO_E_FAILEDTOCLOSEHANDLE 0x8001_0138
+	/// The number of ACEs in an ACL exceeds the system limit.
 	CO_E_EXCEEDSYSACLLIMIT 0x8001_0139
+	/// Not all the DENY_ACCESS ACEs are arranged in front of the GRANT_ACCESS ACEs in the stream.
 	CO_E_ACESINWRONGORDER 0x8001_013a
+	/// The version of ACL format in the stream is not supported by this implementation of IAccessControl.
 	CO_E_INCOMPATIBLESTREAMVERSION 0x8001_013b
+	/// Unable to open the access token of the server process.
 	CO_E_FAILEDTOOPENPROCESSTOKEN 0x8001_013c
+	/// Unable to decode the ACL in the stream provided by the user.
 	CO_E_DECODEFAILED 0x8001_013d
+	/// The COM IAccessControl object is not initialized.
 	CO_E_ACNOTINITIALIZED 0x8001_013f
+	/// Call Cancellation is disabled.
 	CO_E_CANCEL_DISABLED 0x8001_0140
+	/// An internal error occurred.
 	RPC_E_UNEXPECTED 0x8001_ffff
+	/// The specified event is currently not being audited.
 	ERROR_AUDITING_DISABLED 0xc009_0001
+	/// The SID filtering operation removed all SIDs.
 	ERROR_ALL_SIDS_FILTERED 0xc009_0002
+	/// Business rule scripts are disabled for the calling application.
 	ERROR_BIZRULES_NOT_ENABLED 0xc009_0003
+	/// Bad UID.
 	NTE_BAD_UID 0x8009_0001
+	/// Bad Hash.
 	NTE_BAD_HASH 0x8009_0002
+	/// Bad Key.
 	NTE_BAD_KEY 0x8009_0003
+	/// Bad Length.
 	NTE_BAD_LEN 0x8009_0004
+	/// Bad Data.
 	NTE_BAD_DATA 0x8009_0005
+	/// Invalid Signature.
 	NTE_BAD_SIGNATURE 0x8009_0006
+	/// Bad Version of provider.
 	NTE_BAD_VER 0x8009_0007
+	/// Invalid algorithm specified.
 	NTE_BAD_ALGID 0x8009_0008
+	/// Invalid flags specified.
 	NTE_BAD_FLAGS 0x8009_0009
+	/// Invalid type specified.
 	NTE_BAD_TYPE 0x8009_000a
+	/// Key not valid for use in specified state.
 	NTE_BAD_KEY_STATE 0x8009_000b
+	/// Hash not valid for use in specified state.
 	NTE_BAD_HASH_STATE 0x8009_000c
+	/// Key does not exist.
 	NTE_NO_KEY 0x8009_000d
+	/// Insufficient memory available for the operation.
 	NTE_NO_MEMORY 0x8009_000e
+	/// Object already exists.
 	NTE_EXISTS 0x8009_000f
+	/// Access denied.
 	NTE_PERM 0x8009_0010
+	/// Object was not found.
 	NTE_NOT_FOUND 0x8009_0011
+	/// Data already encrypted.
 	NTE_DOUBLE_ENCRYPT 0x8009_0012
+	/// Invalid provider specified.
 	NTE_BAD_PROVIDER 0x8009_0013
+	/// Invalid provider type specified.
 	NTE_BAD_PROV_TYPE 0x8009_0014
+	/// Provider's public key is invalid.
 	NTE_BAD_PUBLIC_KEY 0x8009_0015
+	/// Keyset does not exist.
 	NTE_BAD_KEYSET 0x8009_0016
+	/// Provider type not defined.
 	NTE_PROV_TYPE_NOT_DEF 0x8009_0017
+	/// Provider type as registered is invalid.
 	NTE_PROV_TYPE_ENTRY_BAD 0x8009_0018
+	/// The keyset is not defined.
 	NTE_KEYSET_NOT_DEF 0x8009_0019
+	/// Keyset as registered is invalid.
 	NTE_KEYSET_ENTRY_BAD 0x8009_001a
+	/// Provider type does not match registered value.
 	NTE_PROV_TYPE_NO_MATCH 0x8009_001b
+	/// The digital signature file is corrupt.
 	NTE_SIGNATURE_FILE_BAD 0x8009_001c
+	/// Provider DLL failed to initialize correctly.
 	NTE_PROVIDER_DLL_FAIL 0x8009_001d
+	/// Provider DLL could not be found.
 	NTE_PROV_DLL_NOT_FOUND 0x8009_001e
+	/// The Keyset parameter is invalid.
 	NTE_BAD_KEYSET_PARAM 0x8009_001f
+	/// An internal error occurred.
 	NTE_FAIL 0x8009_0020
+	/// A base error occurred.
 	NTE_SYS_ERR 0x8009_0021
+	/// Provider could not perform the action since the context was acquired as silent.
 	NTE_SILENT_CONTEXT 0x8009_0022
+	/// The security token does not have storage space available for an additional container.
 	NTE_TOKEN_KEYSET_STORAGE_FULL 0x8009_0023
+	/// The profile for the user is a temporary profile.
 	NTE_TEMPORARY_PROFILE 0x8009_0024
+	/// The key parameters could not be set because the CSP uses fixed parameters.
 	NTE_FIXEDPARAMETER 0x8009_0025
+	/// The supplied handle is invalid.
 	NTE_INVALID_HANDLE 0x8009_0026
+	/// The parameter is incorrect.
 	NTE_INVALID_PARAMETER 0x8009_0027
+	/// The buffer supplied to a function was too small.
 	NTE_BUFFER_TOO_SMALL 0x8009_0028
+	/// The requested operation is not supported.
 	NTE_NOT_SUPPORTED 0x8009_0029
+	/// No more data is available.
 	NTE_NO_MORE_ITEMS 0x8009_002a
+	/// The supplied buffers overlap incorrectly.
 	NTE_BUFFERS_OVERLAP 0x8009_002b
+	/// The specified data could not be decrypted.
 	NTE_DECRYPTION_FAILURE 0x8009_002c
+	/// An internal consistency check failed.
 	NTE_INTERNAL_ERROR 0x8009_002d
+	/// This operation requires input from the user.
 	NTE_UI_REQUIRED 0x8009_002e
+	/// The cryptographic provider does not support HMAC.
 	NTE_HMAC_NOT_SUPPORTED 0x8009_002f
+	/// The device that is required by this cryptographic provider is not ready for use.
 	NTE_DEVICE_NOT_READY 0x8009_0030
+	/// The dictionary attack mitigation is triggered and the provided authorization was ignored by the provider.
 	NTE_AUTHENTICATION_IGNORED 0x8009_0031
+	/// The validation of the provided data failed the integrity or signature validation.
 	NTE_VALIDATION_FAILED 0x8009_0032
+	/// Incorrect password.
 	NTE_INCORRECT_PASSWORD 0x8009_0033
+	/// Encryption failed.
 	NTE_ENCRYPTION_FAILURE 0x8009_0034
+	/// The device that is required by this cryptographic provider is not found on this platform.
 	NTE_DEVICE_NOT_FOUND 0x8009_0035
+	/// The action was cancelled by the user.
 	NTE_USER_CANCELLED 0x8009_0036
+	/// The password is no longer valid and must be changed.
 	NTE_PASSWORD_CHANGE_REQUIRED 0x8009_0037
+	/// The operation cannot be completed from Terminal Server client sessions.
 	NTE_NOT_ACTIVE_CONSOLE 0x8009_0038
+	/// Not enough memory is available to complete this request.
 	SEC_E_INSUFFICIENT_MEMORY 0x8009_0300
+	/// The handle specified is invalid.
 	SEC_E_INVALID_HANDLE 0x8009_0301
+	/// The function requested is not supported.
 	SEC_E_UNSUPPORTED_FUNCTION 0x8009_0302
+	/// The specified target is unknown or unreachable.
 	SEC_E_TARGET_UNKNOWN 0x8009_0303
+	/// The Local Security Authority cannot be contacted.
 	SEC_E_INTERNAL_ERROR 0x8009_0304
+	/// The requested security package does not exist.
 	SEC_E_SECPKG_NOT_FOUND 0x8009_0305
+	/// The caller is not the owner of the desired credentials.
 	SEC_E_NOT_OWNER 0x8009_0306
+	/// The security package failed to initialize, and cannot be installed.
 	SEC_E_CANNOT_INSTALL 0x8009_0307
+	/// The token supplied to the function is invalid.
 	SEC_E_INVALID_TOKEN 0x8009_0308
+	/// The security package is not able to marshall the logon buffer, so the logon attempt has failed.
 	SEC_E_CANNOT_PACK 0x8009_0309
+	/// The per-message Quality of Protection is not supported by the security package.
 	SEC_E_QOP_NOT_SUPPORTED 0x8009_030a
+	/// The security context does not allow impersonation of the client.
 	SEC_E_NO_IMPERSONATION 0x8009_030b
+	/// The logon attempt failed.
 	SEC_E_LOGON_DENIED 0x8009_030c
+	/// The credentials supplied to the package were not recognized.
 	SEC_E_UNKNOWN_CREDENTIALS 0x8009_030d
+	/// No credentials are available in the security package.
 	SEC_E_NO_CREDENTIALS 0x8009_030e
+	/// The message or signature supplied for verification has been altered.
 	SEC_E_MESSAGE_ALTERED 0x8009_030f
+	/// The message supplied for verification is out of sequence.
 	SEC_E_OUT_OF_SEQUENCE 0x8009_0310
+	/// No authority could be contacted for authentication.
 	SEC_E_NO_AUTHENTICATING_AUTHORITY 0x8009_0311
+	/// The function completed successfully, but must be called again to complete the context.
 	SEC_I_CONTINUE_NEEDED 0x0009_0312
+	/// The function completed successfully, but CompleteToken must be called.
 	SEC_I_COMPLETE_NEEDED 0x0009_0313
+	/// The function completed successfully, but both CompleteToken and this function must be called to complete the context.
 	SEC_I_COMPLETE_AND_CONTINUE 0x0009_0314
+	/// The logon was completed, but no network authority was available. The logon was made using locally known information.
 	SEC_I_LOCAL_LOGON 0x0009_0315
+	/// Schannel has received a TLS extension the SSPI caller subscribed to.
 	SEC_I_GENERIC_EXTENSION_RECEIVED 0x0009_0316
+	/// The requested security package does not exist.
 	SEC_E_BAD_PKGID 0x8009_0316
+	/// The context has expired and can no longer be used.
 	SEC_E_CONTEXT_EXPIRED 0x8009_0317
+	/// The context has expired and can no longer be used.
 	SEC_I_CONTEXT_EXPIRED 0x0009_0317
+	/// The supplied message is incomplete. The signature was not verified.
 	SEC_E_INCOMPLETE_MESSAGE 0x8009_0318
+	/// The credentials supplied were not complete, and could not be verified. The context could not be initialized.
 	SEC_E_INCOMPLETE_CREDENTIALS 0x8009_0320
+	/// The buffers supplied to a function was too small.
 	SEC_E_BUFFER_TOO_SMALL 0x8009_0321
+	/// The credentials supplied were not complete, and could not be verified. Additional information can be returned from the context.
 	SEC_I_INCOMPLETE_CREDENTIALS 0x0009_0320
+	/// The context data must be renegotiated with the peer.
 	SEC_I_RENEGOTIATE 0x0009_0321
+	/// The target principal name is incorrect.
 	SEC_E_WRONG_PRINCIPAL 0x8009_0322
+	/// There is no LSA mode context associated with this context.
 	SEC_I_NO_LSA_CONTEXT 0x0009_0323
+	/// The clocks on the client and server machines are skewed.
 	SEC_E_TIME_SKEW 0x8009_0324
+	/// The certificate chain was issued by an authority that is not trusted.
 	SEC_E_UNTRUSTED_ROOT 0x8009_0325
+	/// The message received was unexpected or badly formatted.
 	SEC_E_ILLEGAL_MESSAGE 0x8009_0326
+	/// An unknown error occurred while processing the certificate.
 	SEC_E_CERT_UNKNOWN 0x8009_0327
+	/// The received certificate has expired.
 	SEC_E_CERT_EXPIRED 0x8009_0328
+	/// The specified data could not be encrypted.
 	SEC_E_ENCRYPT_FAILURE 0x8009_0329
+	/// The specified data could not be decrypted.
 	SEC_E_DECRYPT_FAILURE 0x8009_0330
+	/// The client and server cannot communicate, because they do not possess a common algorithm.
 	SEC_E_ALGORITHM_MISMATCH 0x8009_0331
+	/// The security context could not be established due to a failure in the requested quality of service (e.g. mutual authentication or delegation).
 	SEC_E_SECURITY_QOS_FAILED 0x8009_0332
+	/// A security context was deleted before the context was completed. This is considered a logon failure.
 	SEC_E_UNFINISHED_CONTEXT_DELETED 0x8009_0333
+	/// The client is trying to negotiate a context and the server requires user-to-user but didn't send a TGT reply.
 	SEC_E_NO_TGT_REPLY 0x8009_0334
+	/// Unable to accomplish the requested task because the local machine does not have any IP addresses.
 	SEC_E_NO_IP_ADDRESSES 0x8009_0335
+	/// The supplied credential handle does not match the credential associated with the security context.
 	SEC_E_WRONG_CREDENTIAL_HANDLE 0x8009_0336
+	/// The crypto system or checksum function is invalid because a required function is unavailable.
 	SEC_E_CRYPTO_SYSTEM_INVALID 0x8009_0337
+	/// The number of maximum ticket referrals has been exceeded.
 	SEC_E_MAX_REFERRALS_EXCEEDED 0x8009_0338
+	/// The local machine must be a Kerberos KDC (domain controller) and it is not.
 	SEC_E_MUST_BE_KDC 0x8009_0339
+	/// The other end of the security negotiation is requires strong crypto but it is not supported on the local machine.
 	SEC_E_STRONG_CRYPTO_NOT_SUPPORTED 0x8009_033a
+	/// The KDC reply contained more than one principal name.
 	SEC_E_TOO_MANY_PRINCIPALS 0x8009_033b
+	/// Expected to find PA data for a hint of what etype to use, but it was not found.
 	SEC_E_NO_PA_DATA 0x8009_033c
+	/// The client certificate does not contain a valid UPN, or does not match the client name in the logon request. Please contact your administrator.
 	SEC_E_PKINIT_NAME_MISMATCH 0x8009_033d
+	/// Smartcard logon is required and was not used.
 	SEC_E_SMARTCARD_LOGON_REQUIRED 0x8009_033e
+	/// A system shutdown is in progress.
 	SEC_E_SHUTDOWN_IN_PROGRESS 0x8009_033f
+	/// An invalid request was sent to the KDC.
 	SEC_E_KDC_INVALID_REQUEST 0x8009_0340
+	/// The KDC was unable to generate a referral for the service requested.
 	SEC_E_KDC_UNABLE_TO_REFER 0x8009_0341
+	/// The encryption type requested is not supported by the KDC.
 	SEC_E_KDC_UNKNOWN_ETYPE 0x8009_0342
+	/// An unsupported preauthentication mechanism was presented to the Kerberos package.
 	SEC_E_UNSUPPORTED_PREAUTH 0x8009_0343
+	/// The requested operation cannot be completed. The computer must be trusted for delegation and the current user account must be configured to allow delegation.
 	SEC_E_DELEGATION_REQUIRED 0x8009_0345
+	/// Client's supplied SSPI channel bindings were incorrect.
 	SEC_E_BAD_BINDINGS 0x8009_0346
+	/// The received certificate was mapped to multiple accounts.
 	SEC_E_MULTIPLE_ACCOUNTS 0x8009_0347
+	/// No Kerberos key was found.
 	SEC_E_NO_KERB_KEY 0x8009_0348
+	/// The certificate is not valid for the requested usage.
 	SEC_E_CERT_WRONG_USAGE 0x8009_0349
+	/// The system cannot contact a domain controller to service the authentication request. Please try again later.
 	SEC_E_DOWNGRADE_DETECTED 0x8009_0350
+	/// The smartcard certificate used for authentication has been revoked. Please contact your system administrator. There may be additional information in the event log.
 	SEC_E_SMARTCARD_CERT_REVOKED 0x8009_0351
+	/// An untrusted certificate authority was detected while processing the smartcard certificate used for authentication. Please contact your system administrator.
 	SEC_E_ISSUING_CA_UNTRUSTED 0x8009_0352
+	/// The revocation status of the smartcard certificate used for authentication could not be determined. Please contact your system administrator.
 	SEC_E_REVOCATION_OFFLINE_C 0x8009_0353
+	/// The smartcard certificate used for authentication was not trusted. Please contact your system administrator.
 	SEC_E_PKINIT_CLIENT_FAILURE 0x8009_0354
+	/// The smartcard certificate used for authentication has expired. Please contact your system administrator.
 	SEC_E_SMARTCARD_CERT_EXPIRED 0x8009_0355
+	/// The Kerberos subsystem encountered an error. A service for user protocol request was made against a domain controller which does not support service for user.
 	SEC_E_NO_S4U_PROT_SUPPORT 0x8009_0356
+	/// An attempt was made by this server to make a Kerberos constrained delegation request for a target outside of the server's realm. This is not supported, and indicates a misconfiguration on this server's allowed to delegate to list. Please contact your administrator.
 	SEC_E_CROSSREALM_DELEGATION_FAILURE 0x8009_0357
+	/// The revocation status of the domain controller certificate used for smartcard authentication could not be determined. There is additional information in the system event log. Please contact your system administrator.
 	SEC_E_REVOCATION_OFFLINE_KDC 0x8009_0358
+	/// An untrusted certificate authority was detected while processing the domain controller certificate used for authentication. There is additional information in the system event log. Please contact your system administrator.
 	SEC_E_ISSUING_CA_UNTRUSTED_KDC 0x8009_0359
+	/// The domain controller certificate used for smartcard logon has expired. Please contact your system administrator with the contents of your system event log.
 	SEC_E_KDC_CERT_EXPIRED 0x8009_035a
+	/// The domain controller certificate used for smartcard logon has been revoked. Please contact your system administrator with the contents of your system event log.
 	SEC_E_KDC_CERT_REVOKED 0x8009_035b
+	/// A signature operation must be performed before the user can authenticate.
 	SEC_I_SIGNATURE_NEEDED 0x0009_035c
+	/// One or more of the parameters passed to the function was invalid.
 	SEC_E_INVALID_PARAMETER 0x8009_035d
+	/// Client policy does not allow credential delegation to target server.
 	SEC_E_DELEGATION_POLICY 0x8009_035e
+	/// Client policy does not allow credential delegation to target server with NLTM only authentication.
 	SEC_E_POLICY_NLTM_ONLY 0x8009_035f
+	/// The recipient rejected the renegotiation request.
 	SEC_I_NO_RENEGOTIATION 0x0009_0360
+	/// The required security context does not exist.
 	SEC_E_NO_CONTEXT 0x8009_0361
+	/// The PKU2U protocol encountered an error while attempting to utilize the associated certificates.
 	SEC_E_PKU2U_CERT_FAILURE 0x8009_0362
+	/// The identity of the server computer could not be verified.
 	SEC_E_MUTUAL_AUTH_FAILED 0x8009_0363
+	/// The returned buffer is only a fragment of the message. More fragments need to be returned.
 	SEC_I_MESSAGE_FRAGMENT 0x0009_0364
+	/// Only https scheme is allowed.
 	SEC_E_ONLY_HTTPS_ALLOWED 0x8009_0365
+	/// The function completed successfully, but must be called again to complete the context. Early start can be used.
 	SEC_I_CONTINUE_NEEDED_MESSAGE_OK 0x0009_0366
+	/// No common application protocol exists between the client and the server. Application protocol negotiation failed.
 	SEC_E_APPLICATION_PROTOCOL_MISMATCH 0x8009_0367
+	/// An asynchronous SSPI routine has been called and the work is pending completion.
 	SEC_I_ASYNC_CALL_PENDING 0x0009_0368
+	/// You can't sign in with a user ID in this format. Try using your email address instead.
 	SEC_E_INVALID_UPN_NAME 0x8009_0369
+	/// The buffer supplied by the SSPI caller to receive generic extensions is too small.
 	SEC_E_EXT_BUFFER_TOO_SMALL 0x8009_036a
+	/// Not enough secbuffers were supplied to generate a token.
 	SEC_E_INSUFFICIENT_BUFFERS 0x8009_036b
+	/// An error occurred while performing an operation on a cryptographic message.
 	CRYPT_E_MSG_ERROR 0x8009_1001
+	/// Unknown cryptographic algorithm.
 	CRYPT_E_UNKNOWN_ALGO 0x8009_1002
+	/// The object identifier is poorly formatted.
 	CRYPT_E_OID_FORMAT 0x8009_1003
+	/// Invalid cryptographic message type.
 	CRYPT_E_INVALID_MSG_TYPE 0x8009_1004
+	/// Unexpected cryptographic message encoding.
 	CRYPT_E_UNEXPECTED_ENCODING 0x8009_1005
+	/// The cryptographic message does not contain an expected authenticated attribute.
 	CRYPT_E_AUTH_ATTR_MISSING 0x8009_1006
+	/// The hash value is not correct.
 	CRYPT_E_HASH_VALUE 0x8009_1007
+	/// The index value is not valid.
 	CRYPT_E_INVALID_INDEX 0x8009_1008
+	/// The content of the cryptographic message has already been decrypted.
 	CRYPT_E_ALREADY_DECRYPTED 0x8009_1009
+	/// The content of the cryptographic message has not been decrypted yet.
 	CRYPT_E_NOT_DECRYPTED 0x8009_100a
+	/// The enveloped-data message does not contain the specified recipient.
 	CRYPT_E_RECIPIENT_NOT_FOUND 0x8009_100b
+	/// Invalid control type.
 	CRYPT_E_CONTROL_TYPE 0x8009_100c
+	/// Invalid issuer and/or serial number.
 	CRYPT_E_ISSUER_SERIALNUMBER 0x8009_100d
+	/// Cannot find the original signer.
 	CRYPT_E_SIGNER_NOT_FOUND 0x8009_100e
+	/// The cryptographic message does not contain all of the requested attributes.
 	CRYPT_E_ATTRIBUTES_MISSING 0x8009_100f
+	/// The streamed cryptographic message is not ready to return data.
 	CRYPT_E_STREAM_MSG_NOT_READY 0x8009_1010
+	/// The streamed cryptographic message requires more data to complete the decode operation.
 	CRYPT_E_STREAM_INSUFFICIENT_DATA 0x8009_1011
+	/// The protected data needs to be re-protected.
 	CRYPT_I_NEW_PROTECTION_REQUIRED 0x0009_1012
+	/// The length specified for the output data was insufficient.
 	CRYPT_E_BAD_LEN 0x8009_2001
+	/// An error occurred during encode or decode operation.
 	CRYPT_E_BAD_ENCODE 0x8009_2002
+	/// An error occurred while reading or writing to a file.
 	CRYPT_E_FILE_ERROR 0x8009_2003
+	/// Cannot find object or property.
 	CRYPT_E_NOT_FOUND 0x8009_2004
+	/// The object or property already exists.
 	CRYPT_E_EXISTS 0x8009_2005
+	/// No provider was specified for the store or object.
 	CRYPT_E_NO_PROVIDER 0x8009_2006
+	/// The specified certificate is self signed.
 	CRYPT_E_SELF_SIGNED 0x8009_2007
+	/// The previous certificate or CRL context was deleted.
 	CRYPT_E_DELETED_PREV 0x8009_2008
+	/// Cannot find the requested object.
 	CRYPT_E_NO_MATCH 0x8009_2009
+	/// The certificate does not have a property that references a private key.
 	CRYPT_E_UNEXPECTED_MSG_TYPE 0x8009_200a
+	/// Cannot find the certificate and private key for decryption.
 	CRYPT_E_NO_KEY_PROPERTY 0x8009_200b
+	/// Cannot find the certificate and private key to use for decryption.
 	CRYPT_E_NO_DECRYPT_CERT 0x8009_200c
+	/// Not a cryptographic message or the cryptographic message is not formatted correctly.
 	CRYPT_E_BAD_MSG 0x8009_200d
+	/// The signed cryptographic message does not have a signer for the specified signer index.
 	CRYPT_E_NO_SIGNER 0x8009_200e
+	/// Final closure is pending until additional frees or closes.
 	CRYPT_E_PENDING_CLOSE 0x8009_200f
+	/// The certificate is revoked.
 	CRYPT_E_REVOKED 0x8009_2010
+	/// No Dll or exported function was found to verify revocation.
 	CRYPT_E_NO_REVOCATION_DLL 0x8009_2011
+	/// The revocation function was unable to check revocation for the certificate.
 	CRYPT_E_NO_REVOCATION_CHECK 0x8009_2012
+	/// The revocation function was unable to check revocation because the revocation server was offline.
 	CRYPT_E_REVOCATION_OFFLINE 0x8009_2013
+	/// The certificate is not in the revocation server's database.
 	CRYPT_E_NOT_IN_REVOCATION_DATABASE 0x8009_2014
+	/// The string contains a non-numeric character.
 	CRYPT_E_INVALID_NUMERIC_STRING 0x8009_2020
+	/// The string contains a non-printable character.
 	CRYPT_E_INVALID_PRINTABLE_STRING 0x8009_2021
+	/// The string contains a character not in the 7 bit ASCII character set.
 	CRYPT_E_INVALID_IA5_STRING 0x8009_2022
+	/// The string contains an invalid X500 name attribute key, oid, value or delimiter.
 	CRYPT_E_INVALID_X500_STRING 0x8009_2023
+	/// The dwValueType for the CERT_NAME_VALUE is not one of the character strings. Most likely it is either a CERT_RDN_ENCODED_BLOB or CERT_RDN_OCTET_STRING.
 	CRYPT_E_NOT_CHAR_STRING 0x8009_2024
+	/// The Put operation cannot continue. The file needs to be resized. However, there is already a signature present. A complete signing operation must be done.
 	CRYPT_E_FILERESIZED 0x8009_2025
+	/// The cryptographic operation failed due to a local security option setting.
 	CRYPT_E_SECURITY_SETTINGS 0x8009_2026
+	/// No DLL or exported function was found to verify subject usage.
 	CRYPT_E_NO_VERIFY_USAGE_DLL 0x8009_2027
+	/// The called function was unable to do a usage check on the subject.
 	CRYPT_E_NO_VERIFY_USAGE_CHECK 0x8009_2028
+	/// Since the server was offline, the called function was unable to complete the usage check.
 	CRYPT_E_VERIFY_USAGE_OFFLINE 0x8009_2029
+	/// The subject was not found in a Certificate Trust List (CTL).
 	CRYPT_E_NOT_IN_CTL 0x8009_202a
+	/// None of the signers of the cryptographic message or certificate trust list is trusted.
 	CRYPT_E_NO_TRUSTED_SIGNER 0x8009_202b
+	/// The public key's algorithm parameters are missing.
 	CRYPT_E_MISSING_PUBKEY_PARA 0x8009_202c
+	/// An object could not be located using the object locator infrastructure with the given name.
 	CRYPT_E_OBJECT_LOCATOR_OBJECT_NOT_FOUND 0x8009_202d
+	/// OSS Certificate encode/decode error code base.
 	CRYPT_E_OSS_ERROR 0x8009_3000
+	/// OSS ASN.1 Error: Output Buffer is too small.
 	OSS_MORE_BUF 0x8009_3001
+	/// OSS ASN.1 Error: Signed integer is encoded as a unsigned integer.
 	OSS_NEGATIVE_UINTEGER 0x8009_3002
+	/// OSS ASN.1 Error: Unknown ASN.1 data type.
 	OSS_PDU_RANGE 0x8009_3003
+	/// OSS ASN.1 Error: Output buffer is too small, the decoded data has been truncated.
 	OSS_MORE_INPUT 0x8009_3004
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_DATA_ERROR 0x8009_3005
+	/// OSS ASN.1 Error: Invalid argument.
 	OSS_BAD_ARG 0x8009_3006
+	/// OSS ASN.1 Error: Encode/Decode version mismatch.
 	OSS_BAD_VERSION 0x8009_3007
+	/// OSS ASN.1 Error: Out of memory.
 	OSS_OUT_MEMORY 0x8009_3008
+	/// OSS ASN.1 Error: Encode/Decode Error.
 	OSS_PDU_MISMATCH 0x8009_3009
+	/// OSS ASN.1 Error: Internal Error.
 	OSS_LIMITED 0x8009_300a
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_BAD_PTR 0x8009_300b
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_BAD_TIME 0x8009_300c
+	/// OSS ASN.1 Error: Unsupported BER indefinite-length encoding.
 	OSS_INDEFINITE_NOT_SUPPORTED 0x8009_300d
+	/// OSS ASN.1 Error: Access violation.
 	OSS_MEM_ERROR 0x8009_300e
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_BAD_TABLE 0x8009_300f
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_TOO_LONG 0x8009_3010
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_CONSTRAINT_VIOLATED 0x8009_3011
+	/// OSS ASN.1 Error: Internal Error.
 	OSS_FATAL_ERROR 0x8009_3012
+	/// OSS ASN.1 Error: Multi-threading conflict.
 	OSS_ACCESS_SERIALIZATION_ERROR 0x8009_3013
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_NULL_TBL 0x8009_3014
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_NULL_FCN 0x8009_3015
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_BAD_ENCRULES 0x8009_3016
+	/// OSS ASN.1 Error: Encode/Decode function not implemented.
 	OSS_UNAVAIL_ENCRULES 0x8009_3017
+	/// OSS ASN.1 Error: Trace file error.
 	OSS_CANT_OPEN_TRACE_WINDOW 0x8009_3018
+	/// OSS ASN.1 Error: Function not implemented.
 	OSS_UNIMPLEMENTED 0x8009_3019
+	/// OSS ASN.1 Error: Program link error.
 	OSS_OID_DLL_NOT_LINKED 0x8009_301a
+	/// OSS ASN.1 Error: Trace file error.
 	OSS_CANT_OPEN_TRACE_FILE 0x8009_301b
+	/// OSS ASN.1 Error: Trace file error.
 	OSS_TRACE_FILE_ALREADY_OPEN 0x8009_301c
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_TABLE_MISMATCH 0x8009_301d
+	/// OSS ASN.1 Error: Invalid data.
 	OSS_TYPE_NOT_SUPPORTED 0x8009_301e
+	/// OSS ASN.1 Error: Program link error.
 	OSS_REAL_DLL_NOT_LINKED 0x8009_301f
+	/// OSS ASN.1 Error: Program link error.
 	OSS_REAL_CODE_NOT_LINKED 0x8009_3020
+	/// OSS ASN.1 Error: Program link error.
 	OSS_OUT_OF_RANGE 0x8009_3021
+	/// OSS ASN.1 Error: Program link error.
 	OSS_COPIER_DLL_NOT_LINKED 0x8009_3022
+	/// OSS ASN.1 Error: Program link error.
 	OSS_CONSTRAINT_DLL_NOT_LINKED 0x8009_3023
+	/// OSS ASN.1 Error: Program link error.
 	OSS_COMPARATOR_DLL_NOT_LINKED 0x8009_3024
+	/// OSS ASN.1 Error: Program link error.
 	OSS_COMPARATOR_CODE_NOT_LINKED 0x8009_3025
+	/// OSS ASN.1 Error: Program link error.
 	OSS_MEM_MGR_DLL_NOT_LINKED 0x8009_3026
+	/// OSS ASN.1 Error: Program link error.
 	OSS_PDV_DLL_NOT_LINKED 0x8009_3027
+	/// OSS ASN.1 Error: Program link error.
 	OSS_PDV_CODE_NOT_LINKED 0x8009_3028
+	/// OSS ASN.1 Error: Program link error.
 	OSS_API_DLL_NOT_LINKED 0x8009_3029
+	/// OSS ASN.1 Error: Program link error.
 	OSS_BERDER_DLL_NOT_LINKED 0x8009_302a
+	/// OSS ASN.1 Error: Program link error.
 	OSS_PER_DLL_NOT_LINKED 0x8009_302b
+	/// OSS ASN.1 Error: Program link error.
 	OSS_OPEN_TYPE_ERROR 0x8009_302c
+	/// OSS ASN.1 Error: System resource error.
 	OSS_MUTEX_NOT_CREATED 0x8009_302d
+	/// OSS ASN.1 Error: Trace file error.
 	OSS_CANT_CLOSE_TRACE_FILE 0x8009_302e
+	/// ASN1 Certificate encode/decode error code base.
 	CRYPT_E_ASN1_ERROR 0x8009_3100
+	/// ASN1 internal encode or decode error.
 	CRYPT_E_ASN1_INTERNAL 0x8009_3101
+	/// ASN1 unexpected end of data.
 	CRYPT_E_ASN1_EOD 0x8009_3102
+	/// ASN1 corrupted data.
 	CRYPT_E_ASN1_CORRUPT 0x8009_3103
+	/// ASN1 value too large.
 	CRYPT_E_ASN1_LARGE 0x8009_3104
+	/// ASN1 constraint violated.
 	CRYPT_E_ASN1_CONSTRAINT 0x8009_3105
+	/// ASN1 out of memory.
 	CRYPT_E_ASN1_MEMORY 0x8009_3106
+	/// ASN1 buffer overflow.
 	CRYPT_E_ASN1_OVERFLOW 0x8009_3107
+	/// ASN1 function not supported for this PDU.
 	CRYPT_E_ASN1_BADPDU 0x8009_3108
+	/// ASN1 bad arguments to function call.
 	CRYPT_E_ASN1_BADARGS 0x8009_3109
+	/// ASN1 bad real value.
 	CRYPT_E_ASN1_BADREAL 0x8009_310a
+	/// ASN1 bad tag value met.
 	CRYPT_E_ASN1_BADTAG 0x8009_310b
+	/// ASN1 bad choice value.
 	CRYPT_E_ASN1_CHOICE 0x8009_310c
+	/// ASN1 bad encoding rule.
 	CRYPT_E_ASN1_RULE 0x8009_310d
+	/// ASN1 bad unicode (UTF8).
 	CRYPT_E_ASN1_UTF8 0x8009_310e
+	/// ASN1 bad PDU type.
 	CRYPT_E_ASN1_PDU_TYPE 0x8009_3133
+	/// ASN1 not yet implemented.
 	CRYPT_E_ASN1_NYI 0x8009_3134
+	/// ASN1 skipped unknown extension(s).
 	CRYPT_E_ASN1_EXTENDED 0x8009_3201
+	/// ASN1 end of data expected.
 	CRYPT_E_ASN1_NOEOD 0x8009_3202
+	/// The request subject name is invalid or too long.
 	CERTSRV_E_BAD_REQUESTSUBJECT 0x8009_4001
+	/// The request does not exist.
 	CERTSRV_E_NO_REQUEST 0x8009_4002
+	/// The request's current status does not allow this operation.
 	CERTSRV_E_BAD_REQUESTSTATUS 0x8009_4003
+	/// The requested property value is empty.
 	CERTSRV_E_PROPERTY_EMPTY 0x8009_4004
+	/// The certification authority's certificate contains invalid data.
 	CERTSRV_E_INVALID_CA_CERTIFICATE 0x8009_4005
+	/// Certificate service has been suspended for a database restore operation.
 	CERTSRV_E_SERVER_SUSPENDED 0x8009_4006
+	/// The certificate contains an encoded length that is potentially incompatible with older enrollment software.
 	CERTSRV_E_ENCODING_LENGTH 0x8009_4007
+	/// The operation is denied. The user has multiple roles assigned and the certification authority is configured to enforce role separation.
 	CERTSRV_E_ROLECONFLICT 0x8009_4008
+	/// The operation is denied. It can only be performed by a certificate manager that is allowed to manage certificates for the current requester.
 	CERTSRV_E_RESTRICTEDOFFICER 0x8009_4009
+	/// Cannot archive private key. The certification authority is not configured for key archival.
 	CERTSRV_E_KEY_ARCHIVAL_NOT_CONFIGURED 0x8009_400a
+	/// Cannot archive private key. The certification authority could not verify one or more key recovery certificates.
 	CERTSRV_E_NO_VALID_KRA 0x8009_400b
+	/// The request is incorrectly formatted. The encrypted private key must be in an unauthenticated attribute in an outermost signature.
 	CERTSRV_E_BAD_REQUEST_KEY_ARCHIVAL 0x8009_400c
+	/// At least one security principal must have the permission to manage this CA.
 	CERTSRV_E_NO_CAADMIN_DEFINED 0x8009_400d
+	/// The request contains an invalid renewal certificate attribute.
 	CERTSRV_E_BAD_RENEWAL_CERT_ATTRIBUTE 0x8009_400e
+	/// An attempt was made to open a Certification Authority database session, but there are already too many active sessions. The server may need to be configured to allow additional sessions.
 	CERTSRV_E_NO_DB_SESSIONS 0x8009_400f
+	/// A memory reference caused a data alignment fault.
 	CERTSRV_E_ALIGNMENT_FAULT 0x8009_4010
+	/// The permissions on this certification authority do not allow the current user to enroll for certificates.
 	CERTSRV_E_ENROLL_DENIED 0x8009_4011
+	/// The permissions on the certificate template do not allow the current user to enroll for this type of certificate.
 	CERTSRV_E_TEMPLATE_DENIED 0x8009_4012
+	/// The contacted domain controller cannot support signed LDAP traffic. Update the domain controller or configure Certificate Services to use SSL for Active Directory access.
 	CERTSRV_E_DOWNLEVEL_DC_SSL_OR_UPGRADE 0x8009_4013
+	/// The request was denied by a certificate manager or CA administrator.
 	CERTSRV_E_ADMIN_DENIED_REQUEST 0x8009_4014
+	/// An enrollment policy server cannot be located.
 	CERTSRV_E_NO_POLICY_SERVER 0x8009_4015
+	/// A signature algorithm or public key length does not meet the system's minimum required strength.
 	CERTSRV_E_WEAK_SIGNATURE_OR_KEY 0x8009_4016
+	/// Failed to create an attested key. This computer or the cryptographic provider may not meet the hardware requirements to support key attestation.
 	CERTSRV_E_KEY_ATTESTATION_NOT_SUPPORTED 0x8009_4017
+	/// No encryption certificate was specified.
 	CERTSRV_E_ENCRYPTION_CERT_REQUIRED 0x8009_4018
+	/// The requested certificate template is not supported by this CA.
 	CERTSRV_E_UNSUPPORTED_CERT_TYPE 0x8009_4800
+	/// The request contains no certificate template information.
 	CERTSRV_E_NO_CERT_TYPE 0x8009_4801
+	/// The request contains conflicting template information.
 	CERTSRV_E_TEMPLATE_CONFLICT 0x8009_4802
+	/// The request is missing a required Subject Alternate name extension.
 	CERTSRV_E_SUBJECT_ALT_NAME_REQUIRED 0x8009_4803
+	/// The request is missing a required private key for archival by the server.
 	CERTSRV_E_ARCHIVED_KEY_REQUIRED 0x8009_4804
+	/// The request is missing a required SMIME capabilities extension.
 	CERTSRV_E_SMIME_REQUIRED 0x8009_4805
+	/// The request was made on behalf of a subject other than the caller. The certificate template must be configured to require at least one signature to authorize the request.
 	CERTSRV_E_BAD_RENEWAL_SUBJECT 0x8009_4806
+	/// The request template version is newer than the supported template version.
 	CERTSRV_E_BAD_TEMPLATE_VERSION 0x8009_4807
+	/// The template is missing a required signature policy attribute.
 	CERTSRV_E_TEMPLATE_POLICY_REQUIRED 0x8009_4808
+	/// The request is missing required signature policy information.
 	CERTSRV_E_SIGNATURE_POLICY_REQUIRED 0x8009_4809
+	/// The request is missing one or more required signatures.
 	CERTSRV_E_SIGNATURE_COUNT 0x8009_480a
+	/// One or more signatures did not include the required application or issuance policies. The request is missing one or more required valid signatures.
 	CERTSRV_E_SIGNATURE_REJECTED 0x8009_480b
+	/// The request is missing one or more required signature issuance policies.
 	CERTSRV_E_ISSUANCE_POLICY_REQUIRED 0x8009_480c
+	/// The UPN is unavailable and cannot be added to the Subject Alternate name.
 	CERTSRV_E_SUBJECT_UPN_REQUIRED 0x8009_480d
+	/// The Active Directory GUID is unavailable and cannot be added to the Subject Alternate name.
 	CERTSRV_E_SUBJECT_DIRECTORY_GUID_REQUIRED 0x8009_480e
+	/// The DNS name is unavailable and cannot be added to the Subject Alternate name.
 	CERTSRV_E_SUBJECT_DNS_REQUIRED 0x8009_480f
+	/// The request includes a private key for archival by the server, but key archival is not enabled for the specified certificate template.
 	CERTSRV_E_ARCHIVED_KEY_UNEXPECTED 0x8009_4810
+	/// The public key does not meet the minimum size required by the specified certificate template.
 	CERTSRV_E_KEY_LENGTH 0x8009_4811
+	/// The EMail name is unavailable and cannot be added to the Subject or Subject Alternate name.
 	CERTSRV_E_SUBJECT_EMAIL_REQUIRED 0x8009_4812
+	/// One or more certificate templates to be enabled on this certification authority could not be found.
 	CERTSRV_E_UNKNOWN_CERT_TYPE 0x8009_4813
+	/// The certificate template renewal period is longer than the certificate validity period. The template should be reconfigured or the CA certificate renewed.
 	CERTSRV_E_CERT_TYPE_OVERLAP 0x8009_4814
+	/// The certificate template requires too many RA signatures. Only one RA signature is allowed.
 	CERTSRV_E_TOO_MANY_SIGNATURES 0x8009_4815
+	/// The certificate template requires renewal with the same public key, but the request uses a different public key.
 	CERTSRV_E_RENEWAL_BAD_PUBLIC_KEY 0x8009_4816
+	/// The certification authority cannot interpret or verify the endorsement key information supplied in the request, or the information is inconsistent.
 	CERTSRV_E_INVALID_EK 0x8009_4817
+	/// The certification authority cannot validate the Attestation Identity Key Id Binding.
 	CERTSRV_E_INVALID_IDBINDING 0x8009_4818
+	/// The certification authority cannot validate the private key attestation data.
 	CERTSRV_E_INVALID_ATTESTATION 0x8009_4819
+	/// The request does not support private key attestation as defined in the certificate template.
 	CERTSRV_E_KEY_ATTESTATION 0x8009_481a
+	/// The request public key is not consistent with the private key attestation data.
 	CERTSRV_E_CORRUPT_KEY_ATTESTATION 0x8009_481b
+	/// The private key attestation challenge cannot be validated because the encryption certificate has expired, or the certificate or key is unavailable.
 	CERTSRV_E_EXPIRED_CHALLENGE 0x8009_481c
+	/// The attestation response could not be validated. It is either unexpected or incorrect.
 	CERTSRV_E_INVALID_RESPONSE 0x8009_481d
+	/// A valid Request ID was not detected in the request attributes, or an invalid one was submitted.
 	CERTSRV_E_INVALID_REQUESTID 0x8009_481e
+	/// The request is not consistent with the previously generated precertificate.
 	CERTSRV_E_REQUEST_PRECERTIFICATE_MISMATCH 0x8009_481f
+	/// The request is locked by another client response.
 	CERTSRV_E_PENDING_CLIENT_RESPONSE 0x8009_4820
+	/// The key is not exportable.
 	XENROLL_E_KEY_NOT_EXPORTABLE 0x8009_5000
+	/// You cannot add the root CA certificate into your local store.
 	XENROLL_E_CANNOT_ADD_ROOT_CERT 0x8009_5001
+	/// The key archival hash attribute was not found in the response.
 	XENROLL_E_RESPONSE_KA_HASH_NOT_FOUND 0x8009_5002
+	/// An unexpected key archival hash attribute was found in the response.
 	XENROLL_E_RESPONSE_UNEXPECTED_KA_HASH 0x8009_5003
+	/// There is a key archival hash mismatch between the request and the response.
 	XENROLL_E_RESPONSE_KA_HASH_MISMATCH 0x8009_5004
+	/// The signing certificate cannot be used to sign S/MIME because of a key specification mismatch.
 	XENROLL_E_KEYSPEC_SMIME_MISMATCH 0x8009_5005
+	/// A system-level error occurred while verifying trust.
 	TRUST_E_SYSTEM_ERROR 0x8009_6001
+	/// The certificate for the signer of the message is invalid or not found.
 	TRUST_E_NO_SIGNER_CERT 0x8009_6002
+	/// One of the counter signatures was invalid.
 	TRUST_E_COUNTER_SIGNER 0x8009_6003
+	/// The signature of the certificate cannot be verified.
 	TRUST_E_CERT_SIGNATURE 0x8009_6004
+	/// The timestamp signature and/or certificate could not be verified or is malformed.
 	TRUST_E_TIME_STAMP 0x8009_6005
+	/// The digital signature of the object did not verify.
 	TRUST_E_BAD_DIGEST 0x8009_6010
+	/// The digital signature of the object is malformed. For technical detail, see security bulletin MS13-098.
 	TRUST_E_MALFORMED_SIGNATURE 0x8009_6011
+	/// A certificate's basic constraint extension has not been observed.
 	TRUST_E_BASIC_CONSTRAINTS 0x8009_6019
+	/// The certificate does not meet or contain the Authenticode(tm) financial extensions.
 	TRUST_E_FINANCIAL_CRITERIA 0x8009_601e
+	/// Tried to reference a part of the file outside the proper range.
 	MSSIPOTF_E_OUTOFMEMRANGE 0x8009_7001
+	/// Could not retrieve an object from the file.
 	MSSIPOTF_E_CANTGETOBJECT 0x8009_7002
+	/// Could not find the head table in the file.
 	MSSIPOTF_E_NOHEADTABLE 0x8009_7003
+	/// The magic number in the head table is incorrect.
 	MSSIPOTF_E_BAD_MAGICNUMBER 0x8009_7004
+	/// The offset table has incorrect values.
 	MSSIPOTF_E_BAD_OFFSET_TABLE 0x8009_7005
+	/// Duplicate table tags or tags out of alphabetical order.
 	MSSIPOTF_E_TABLE_TAGORDER 0x8009_7006
+	/// A table does not start on a long word boundary.
 	MSSIPOTF_E_TABLE_LONGWORD 0x8009_7007
+	/// First table does not appear after header information.
 	MSSIPOTF_E_BAD_FIRST_TABLE_PLACEMENT 0x8009_7008
+	/// Two or more tables overlap.
 	MSSIPOTF_E_TABLES_OVERLAP 0x8009_7009
+	/// Too many pad bytes between tables or pad bytes are not 0.
 	MSSIPOTF_E_TABLE_PADBYTES 0x8009_700a
+	/// File is too small to contain the last table.
 	MSSIPOTF_E_FILETOOSMALL 0x8009_700b
+	/// A table checksum is incorrect.
 	MSSIPOTF_E_TABLE_CHECKSUM 0x8009_700c
+	/// The file checksum is incorrect.
 	MSSIPOTF_E_FILE_CHECKSUM 0x8009_700d
+	/// The signature does not have the correct attributes for the policy.
 	MSSIPOTF_E_FAILED_POLICY 0x8009_7010
+	/// The file did not pass the hints check.
 	MSSIPOTF_E_FAILED_HINTS_CHECK 0x8009_7011
+	/// The file is not an OpenType file.
 	MSSIPOTF_E_NOT_OPENTYPE 0x8009_7012
+	/// Failed on a file operation (open, map, read, write).
 	MSSIPOTF_E_FILE 0x8009_7013
+	/// A call to a CryptoAPI function failed.
 	MSSIPOTF_E_CRYPT 0x8009_7014
+	/// There is a bad version number in the file.
 	MSSIPOTF_E_BADVERSION 0x8009_7015
+	/// The structure of the DSIG table is incorrect.
 	MSSIPOTF_E_DSIG_STRUCTURE 0x8009_7016
+	/// A check failed in a partially constant table.
 	MSSIPOTF_E_PCONST_CHECK 0x8009_7017
+	/// Some kind of structural error.
 	MSSIPOTF_E_STRUCTURE 0x8009_7018
+	/// The requested credential requires confirmation.
 	ERROR_CRED_REQUIRES_CONFIRMATION 0x8009_7019
+	/// Unknown trust provider.
 	TRUST_E_PROVIDER_UNKNOWN 0x800b_0001
+	/// The trust verification action specified is not supported by the specified trust provider.
 	TRUST_E_ACTION_UNKNOWN 0x800b_0002
+	/// The form specified for the subject is not one supported or known by the specified trust provider.
 	TRUST_E_SUBJECT_FORM_UNKNOWN 0x800b_0003
+	/// The subject is not trusted for the specified action.
 	TRUST_E_SUBJECT_NOT_TRUSTED 0x800b_0004
+	/// Error due to problem in ASN.1 encoding process.
 	DIGSIG_E_ENCODE 0x800b_0005
+	/// Error due to problem in ASN.1 decoding process.
 	DIGSIG_E_DECODE 0x800b_0006
+	/// Reading / writing Extensions where Attributes are appropriate, and vice versa.
 	DIGSIG_E_EXTENSIBILITY 0x800b_0007
+	/// Unspecified cryptographic failure.
 	DIGSIG_E_CRYPTO 0x800b_0008
+	/// The size of the data could not be determined.
 	PERSIST_E_SIZEDEFINITE 0x800b_0009
+	/// The size of the indefinite-sized data could not be determined.
 	PERSIST_E_SIZEINDEFINITE 0x800b_000a
+	/// This object does not read and write self-sizing data.
 	PERSIST_E_NOTSELFSIZING 0x800b_000b
+	/// No signature was present in the subject.
 	TRUST_E_NOSIGNATURE 0x800b_0100
+	/// A required certificate is not within its validity period when verifying against the current system clock or the timestamp in the signed file.
 	CERT_E_EXPIRED 0x800b_0101
+	/// The validity periods of the certification chain do not nest correctly.
 	CERT_E_VALIDITYPERIODNESTING 0x800b_0102
+	/// A certificate that can only be used as an end-entity is being used as a CA or vice versa.
 	CERT_E_ROLE 0x800b_0103
+	/// A path length constraint in the certification chain has been violated.
 	CERT_E_PATHLENCONST 0x800b_0104
+	/// A certificate contains an unknown extension that is marked 'critical'.
 	CERT_E_CRITICAL 0x800b_0105
+	/// A certificate being used for a purpose other than the ones specified by its CA.
 	CERT_E_PURPOSE 0x800b_0106
+	/// A parent of a given certificate in fact did not issue that child certificate.
 	CERT_E_ISSUERCHAINING 0x800b_0107
+	/// A certificate is missing or has an empty value for an important field, such as a subject or issuer name.
 	CERT_E_MALFORMED 0x800b_0108
+	/// A certificate chain processed, but terminated in a root certificate which is not trusted by the trust provider.
 	CERT_E_UNTRUSTEDROOT 0x800b_0109
+	/// A certificate chain could not be built to a trusted root authority.
 	CERT_E_CHAINING 0x800b_010a
+	/// Generic trust failure.
 	TRUST_E_FAIL 0x800b_010b
+	/// A certificate was explicitly revoked by its issuer.
 	CERT_E_REVOKED 0x800b_010c
+	/// The certification path terminates with the test root which is not trusted with the current policy settings.
 	CERT_E_UNTRUSTEDTESTROOT 0x800b_010d
+	/// The revocation process could not continue - the certificate(s) could not be checked.
 	CERT_E_REVOCATION_FAILURE 0x800b_010e
+	/// The certificate's CN name does not match the passed value.
 	CERT_E_CN_NO_MATCH 0x800b_010f
+	/// The certificate is not valid for the requested usage.
 	CERT_E_WRONG_USAGE 0x800b_0110
+	/// The certificate was explicitly marked as untrusted by the user.
 	TRUST_E_EXPLICIT_DISTRUST 0x800b_0111
+	/// A certification chain processed correctly, but one of the CA certificates is not trusted by the policy provider.
 	CERT_E_UNTRUSTEDCA 0x800b_0112
+	/// The certificate has invalid policy.
 	CERT_E_INVALID_POLICY 0x800b_0113
+	/// The certificate has an invalid name. The name is not included in the permitted list or is explicitly excluded.
 	CERT_E_INVALID_NAME 0x800b_0114
+	/// A non-empty line was encountered in the INF before the start of a section.
 	SPAPI_E_EXPECTED_SECTION_NAME 0x800f_0000
+	/// A section name marker in the INF is not complete, or does not exist on a line by itself.
 	SPAPI_E_BAD_SECTION_NAME_LINE 0x800f_0001
+	/// An INF section was encountered whose name exceeds the maximum section name length.
 	SPAPI_E_SECTION_NAME_TOO_LONG 0x800f_0002
+	/// The syntax of the INF is invalid.
 	SPAPI_E_GENERAL_SYNTAX 0x800f_0003
+	/// The style of the INF is different than what was requested.
 	SPAPI_E_WRONG_INF_STYLE 0x800f_0100
+	/// The required section was not found in the INF.
 	SPAPI_E_SECTION_NOT_FOUND 0x800f_0101
+	/// The required line was not found in the INF.
 	SPAPI_E_LINE_NOT_FOUND 0x800f_0102
+	/// The files affected by the installation of this file queue have not been backed up for uninstall.
 	SPAPI_E_NO_BACKUP 0x800f_0103
+	/// The INF or the device information set or element does not have an associated install class.
 	SPAPI_E_NO_ASSOCIATED_CLASS 0x800f_0200
+	/// The INF or the device information set or element does not match the specified install class.
 	SPAPI_E_CLASS_MISMATCH 0x800f_0201
+	/// An existing device was found that is a duplicate of the device being manually installed.
 	SPAPI_E_DUPLICATE_FOUND 0x800f_0202
+	/// There is no driver selected for the device information set or element.
 	SPAPI_E_NO_DRIVER_SELECTED 0x800f_0203
+	/// The requested device registry key does not exist.
 	SPAPI_E_KEY_DOES_NOT_EXIST 0x800f_0204
+	/// The device instance name is invalid.
 	SPAPI_E_INVALID_DEVINST_NAME 0x800f_0205
+	/// The install class is not present or is invalid.
 	SPAPI_E_INVALID_CLASS 0x800f_0206
+	/// The device instance cannot be created because it already exists.
 	SPAPI_E_DEVINST_ALREADY_EXISTS 0x800f_0207
+	/// The operation cannot be performed on a device information element that has not been registered.
 	SPAPI_E_DEVINFO_NOT_REGISTERED 0x800f_0208
+	/// The device property code is invalid.
 	SPAPI_E_INVALID_REG_PROPERTY 0x800f_0209
+	/// The INF from which a driver list is to be built does not exist.
 	SPAPI_E_NO_INF 0x800f_020a
+	/// The device instance does not exist in the hardware tree.
 	SPAPI_E_NO_SUCH_DEVINST 0x800f_020b
+	/// The icon representing this install class cannot be loaded.
 	SPAPI_E_CANT_LOAD_CLASS_ICON 0x800f_020c
+	/// The class installer registry entry is invalid.
 	SPAPI_E_INVALID_CLASS_INSTALLER 0x800f_020d
+	/// The class installer has indicated that the default action should be performed for this installation request.
 	SPAPI_E_DI_DO_DEFAULT 0x800f_020e
+	/// The operation does not require any files to be copied.
 	SPAPI_E_DI_NOFILECOPY 0x800f_020f
+	/// The specified hardware profile does not exist.
 	SPAPI_E_INVALID_HWPROFILE 0x800f_0210
+	/// There is no device information element currently selected for this device information set.
 	SPAPI_E_NO_DEVICE_SELECTED 0x800f_0211
+	/// The operation cannot be performed because the device information set is locked.
 	SPAPI_E_DEVINFO_LIST_LOCKED 0x800f_0212
+	/// The operation cannot be performed because the device information element is locked.
 	SPAPI_E_DEVINFO_DATA_LOCKED 0x800f_0213
+	/// The specified path does not contain any applicable device INFs.
 	SPAPI_E_DI_BAD_PATH 0x800f_0214
+	/// No class installer parameters have been set for the device information set or element.
 	SPAPI_E_NO_CLASSINSTALL_PARAMS 0x800f_0215
+	/// The operation cannot be performed because the file queue is locked.
 	SPAPI_E_FILEQUEUE_LOCKED 0x800f_0216
+	/// A service installation section in this INF is invalid.
 	SPAPI_E_BAD_SERVICE_INSTALLSECT 0x800f_0217
+	/// There is no class driver list for the device information element.
 	SPAPI_E_NO_CLASS_DRIVER_LIST 0x800f_0218
+	/// The installation failed because a function driver was not specified for this device instance.
 	SPAPI_E_NO_ASSOCIATED_SERVICE 0x800f_0219
+	/// There is presently no default device interface designated for this interface class.
 	SPAPI_E_NO_DEFAULT_DEVICE_INTERFACE 0x800f_021a
+	/// The operation cannot be performed because the device interface is currently active.
 	SPAPI_E_DEVICE_INTERFACE_ACTIVE 0x800f_021b
+	/// The operation cannot be performed because the device interface has been removed from the system.
 	SPAPI_E_DEVICE_INTERFACE_REMOVED 0x800f_021c
+	/// An interface installation section in this INF is invalid.
 	SPAPI_E_BAD_INTERFACE_INSTALLSECT 0x800f_021d
+	/// This interface class does not exist in the system.
 	SPAPI_E_NO_SUCH_INTERFACE_CLASS 0x800f_021e
+	/// The reference string supplied for this interface device is invalid.
 	SPAPI_E_INVALID_REFERENCE_STRING 0x800f_021f
+	/// The specified machine name does not conform to UNC naming conventions.
 	SPAPI_E_INVALID_MACHINENAME 0x800f_0220
+	/// A general remote communication error occurred.
 	SPAPI_E_REMOTE_COMM_FAILURE 0x800f_0221
+	/// The machine selected for remote communication is not available at this time.
 	SPAPI_E_MACHINE_UNAVAILABLE 0x800f_0222
+	/// The Plug and Play service is not available on the remote machine.
 	SPAPI_E_NO_CONFIGMGR_SERVICES 0x800f_0223
+	/// The property page provider registry entry is invalid.
 	SPAPI_E_INVALID_PROPPAGE_PROVIDER 0x800f_0224
+	/// The requested device interface is not present in the system.
 	SPAPI_E_NO_SUCH_DEVICE_INTERFACE 0x800f_0225
+	/// The device's co-installer has additional work to perform after installation is complete.
 	SPAPI_E_DI_POSTPROCESSING_REQUIRED 0x800f_0226
+	/// The device's co-installer is invalid.
 	SPAPI_E_INVALID_COINSTALLER 0x800f_0227
+	/// There are no compatible drivers for this device.
 	SPAPI_E_NO_COMPAT_DRIVERS 0x800f_0228
+	/// There is no icon that represents this device or device type.
 	SPAPI_E_NO_DEVICE_ICON 0x800f_0229
+	/// A logical configuration specified in this INF is invalid.
 	SPAPI_E_INVALID_INF_LOGCONFIG 0x800f_022a
+	/// The class installer has denied the request to install or upgrade this device.
 	SPAPI_E_DI_DONT_INSTALL 0x800f_022b
+	/// One of the filter drivers installed for this device is invalid.
 	SPAPI_E_INVALID_FILTER_DRIVER 0x800f_022c
+	/// The driver selected for this device does not support this version of Windows.
 	SPAPI_E_NON_WINDOWS_NT_DRIVER 0x800f_022d
+	/// The driver selected for this device does not support Windows.
 	SPAPI_E_NON_WINDOWS_DRIVER 0x800f_022e
+	/// The third-party INF does not contain digital signature information.
 	SPAPI_E_NO_CATALOG_FOR_OEM_INF 0x800f_022f
+	/// An invalid attempt was made to use a device installation file queue for verification of digital signatures relative to other platforms.
 	SPAPI_E_DEVINSTALL_QUEUE_NONNATIVE 0x800f_0230
+	/// The device cannot be disabled.
 	SPAPI_E_NOT_DISABLEABLE 0x800f_0231
+	/// The device could not be dynamically removed.
 	SPAPI_E_CANT_REMOVE_DEVINST 0x800f_0232
+	/// Cannot copy to specified target.
 	SPAPI_E_INVALID_TARGET 0x800f_0233
+	/// Driver is not intended for this platform.
 	SPAPI_E_DRIVER_NONNATIVE 0x800f_0234
+	/// Operation not allowed in WOW64.
 	SPAPI_E_IN_WOW64 0x800f_0235
+	/// The operation involving unsigned file copying was rolled back, so that a system restore point could be set.
 	SPAPI_E_SET_SYSTEM_RESTORE_POINT 0x800f_0236
+	/// An INF was copied into the Windows INF directory in an improper manner.
 	SPAPI_E_INCORRECTLY_COPIED_INF 0x800f_0237
+	/// The Security Configuration Editor (SCE) APIs have been disabled on this Embedded product.
 	SPAPI_E_SCE_DISABLED 0x800f_0238
+	/// An unknown exception was encountered.
 	SPAPI_E_UNKNOWN_EXCEPTION 0x800f_0239
+	/// A problem was encountered when accessing the Plug and Play registry database.
 	SPAPI_E_PNP_REGISTRY_ERROR 0x800f_023a
+	/// The requested operation is not supported for a remote machine.
 	SPAPI_E_REMOTE_REQUEST_UNSUPPORTED 0x800f_023b
+	/// The specified file is not an installed OEM INF.
 	SPAPI_E_NOT_AN_INSTALLED_OEM_INF 0x800f_023c
+	/// One or more devices are presently installed using the specified INF.
 	SPAPI_E_INF_IN_USE_BY_DEVICES 0x800f_023d
+	/// The requested device install operation is obsolete.
 	SPAPI_E_DI_FUNCTION_OBSOLETE 0x800f_023e
+	/// A file could not be verified because it does not have an associated catalog signed via Authenticode(tm).
 	SPAPI_E_NO_AUTHENTICODE_CATALOG 0x800f_023f
+	/// Authenticode(tm) signature verification is not supported for the specified INF.
 	SPAPI_E_AUTHENTICODE_DISALLOWED 0x800f_0240
+	/// The INF was signed with an Authenticode(tm) catalog from a trusted publisher.
 	SPAPI_E_AUTHENTICODE_TRUSTED_PUBLISHER 0x800f_0241
+	/// The publisher of an Authenticode(tm) signed catalog has not yet been established as trusted.
 	SPAPI_E_AUTHENTICODE_TRUST_NOT_ESTABLISHED 0x800f_0242
+	/// The publisher of an Authenticode(tm) signed catalog was not established as trusted.
 	SPAPI_E_AUTHENTICODE_PUBLISHER_NOT_TRUSTED 0x800f_0243
+	/// The software was tested for compliance with Windows Logo requirements on a different version of Windows, and may not be compatible with this version.
 	SPAPI_E_SIGNATURE_OSATTRIBUTE_MISMATCH 0x800f_0244
+	/// The file may only be validated by a catalog signed via Authenticode(tm).
 	SPAPI_E_ONLY_VALIDATE_VIA_AUTHENTICODE 0x800f_0245
+	/// One of the installers for this device cannot perform the installation at this time.
 	SPAPI_E_DEVICE_INSTALLER_NOT_READY 0x800f_0246
+	/// A problem was encountered while attempting to add the driver to the store.
 	SPAPI_E_DRIVER_STORE_ADD_FAILED 0x800f_0247
+	/// The installation of this device is forbidden by system policy. Contact your system administrator.
 	SPAPI_E_DEVICE_INSTALL_BLOCKED 0x800f_0248
+	/// The installation of this driver is forbidden by system policy. Contact your system administrator.
 	SPAPI_E_DRIVER_INSTALL_BLOCKED 0x800f_0249
+	/// The specified INF is the wrong type for this operation.
 	SPAPI_E_WRONG_INF_TYPE 0x800f_024a
+	/// The hash for the file is not present in the specified catalog file. The file is likely corrupt or the victim of tampering.
 	SPAPI_E_FILE_HASH_NOT_IN_CATALOG 0x800f_024b
+	/// A problem was encountered while attempting to delete the driver from the store.
 	SPAPI_E_DRIVER_STORE_DELETE_FAILED 0x800f_024c
+	/// An unrecoverable stack overflow was encountered.
 	SPAPI_E_UNRECOVERABLE_STACK_OVERFLOW 0x800f_0300
+	/// No installed components were detected.
 	SPAPI_E_ERROR_NOT_INSTALLED 0x800f_1000
+	/// An internal consistency check failed.
 	SCARD_F_INTERNAL_ERROR 0x8010_0001
+	/// The action was cancelled by an SCardCancel request.
 	SCARD_E_CANCELLED 0x8010_0002
+	/// The supplied handle was invalid.
 	SCARD_E_INVALID_HANDLE 0x8010_0003
+	/// One or more of the supplied parameters could not be properly interpreted.
 	SCARD_E_INVALID_PARAMETER 0x8010_0004
+	/// Registry startup information is missing or invalid.
 	SCARD_E_INVALID_TARGET 0x8010_0005
+	/// Not enough memory available to complete this command.
 	SCARD_E_NO_MEMORY 0x8010_0006
+	/// An internal consistency timer has expired.
 	SCARD_F_WAITED_TOO_LONG 0x8010_0007
+	/// The data buffer to receive returned data is too small for the returned data.
 	SCARD_E_INSUFFICIENT_BUFFER 0x8010_0008
+	/// The specified reader name is not recognized.
 	SCARD_E_UNKNOWN_READER 0x8010_0009
+	/// The user-specified timeout value has expired.
 	SCARD_E_TIMEOUT 0x8010_000a
+	/// The smart card cannot be accessed because of other connections outstanding.
 	SCARD_E_SHARING_VIOLATION 0x8010_000b
+	/// The operation requires a Smart Card, but no Smart Card is currently in the device.
 	SCARD_E_NO_SMARTCARD 0x8010_000c
+	/// The specified smart card name is not recognized.
 	SCARD_E_UNKNOWN_CARD 0x8010_000d
+	/// The system could not dispose of the media in the requested manner.
 	SCARD_E_CANT_DISPOSE 0x8010_000e
+	/// The requested protocols are incompatible with the protocol currently in use with the smart card.
 	SCARD_E_PROTO_MISMATCH 0x8010_000f
+	/// The reader or smart card is not ready to accept commands.
 	SCARD_E_NOT_READY 0x8010_0010
+	/// One or more of the supplied parameters values could not be properly interpreted.
 	SCARD_E_INVALID_VALUE 0x8010_0011
+	/// The action was cancelled by the system, presumably to log off or shut down.
 	SCARD_E_SYSTEM_CANCELLED 0x8010_0012
+	/// An internal communications error has been detected.
 	SCARD_F_COMM_ERROR 0x8010_0013
+	/// An internal error has been detected, but the source is unknown.
 	SCARD_F_UNKNOWN_ERROR 0x8010_0014
+	/// An ATR obtained from the registry is not a valid ATR string.
 	SCARD_E_INVALID_ATR 0x8010_0015
+	/// An attempt was made to end a non-existent transaction.
 	SCARD_E_NOT_TRANSACTED 0x8010_0016
+	/// The specified reader is not currently available for use.
 	SCARD_E_READER_UNAVAILABLE 0x8010_0017
+	/// The operation has been aborted to allow the server application to exit.
 	SCARD_P_SHUTDOWN 0x8010_0018
+	/// The PCI Receive buffer was too small.
 	SCARD_E_PCI_TOO_SMALL 0x8010_0019
+	/// The reader driver does not meet minimal requirements for support.
 	SCARD_E_READER_UNSUPPORTED 0x8010_001a
+	/// The reader driver did not produce a unique reader name.
 	SCARD_E_DUPLICATE_READER 0x8010_001b
+	/// The smart card does not meet minimal requirements for support.
 	SCARD_E_CARD_UNSUPPORTED 0x8010_001c
+	/// The Smart Card Resource Manager is not running.
 	SCARD_E_NO_SERVICE 0x8010_001d
+	/// The Smart Card Resource Manager has shut down.
 	SCARD_E_SERVICE_STOPPED 0x8010_001e
+	/// An unexpected card error has occurred.
 	SCARD_E_UNEXPECTED 0x8010_001f
+	/// No Primary Provider can be found for the smart card.
 	SCARD_E_ICC_INSTALLATION 0x8010_0020
+	/// The requested order of object creation is not supported.
 	SCARD_E_ICC_CREATEORDER 0x8010_0021
+	/// This smart card does not support the requested feature.
 	SCARD_E_UNSUPPORTED_FEATURE 0x8010_0022
+	/// The identified directory does not exist in the smart card.
 	SCARD_E_DIR_NOT_FOUND 0x8010_0023
+	/// The identified file does not exist in the smart card.
 	SCARD_E_FILE_NOT_FOUND 0x8010_0024
+	/// The supplied path does not represent a smart card directory.
 	SCARD_E_NO_DIR 0x8010_0025
+	/// The supplied path does not represent a smart card file.
 	SCARD_E_NO_FILE 0x8010_0026
+	/// Access is denied to this file.
 	SCARD_E_NO_ACCESS 0x8010_0027
+	/// The smart card does not have enough memory to store the information.
 	SCARD_E_WRITE_TOO_MANY 0x8010_0028
+	/// There was an error trying to set the smart card file object pointer.
 	SCARD_E_BAD_SEEK 0x8010_0029
+	/// The supplied PIN is incorrect.
 	SCARD_E_INVALID_CHV 0x8010_002a
+	/// An unrecognized error code was returned from a layered component.
 	SCARD_E_UNKNOWN_RES_MNG 0x8010_002b
+	/// The requested certificate does not exist.
 	SCARD_E_NO_SUCH_CERTIFICATE 0x8010_002c
+	/// The requested certificate could not be obtained.
 	SCARD_E_CERTIFICATE_UNAVAILABLE 0x8010_002d
+	/// Cannot find a smart card reader.
 	SCARD_E_NO_READERS_AVAILABLE 0x8010_002e
+	/// A communications error with the smart card has been detected. Retry the operation.
 	SCARD_E_COMM_DATA_LOST 0x8010_002f
+	/// The requested key container does not exist on the smart card.
 	SCARD_E_NO_KEY_CONTAINER 0x8010_0030
+	/// The Smart Card Resource Manager is too busy to complete this operation.
 	SCARD_E_SERVER_TOO_BUSY 0x8010_0031
+	/// The smart card PIN cache has expired.
 	SCARD_E_PIN_CACHE_EXPIRED 0x8010_0032
+	/// The smart card PIN cannot be cached.
 	SCARD_E_NO_PIN_CACHE 0x8010_0033
+	/// The smart card is read only and cannot be written to.
 	SCARD_E_READ_ONLY_CARD 0x8010_0034
+	/// The reader cannot communicate with the smart card, due to ATR configuration conflicts.
 	SCARD_W_UNSUPPORTED_CARD 0x8010_0065
+	/// The smart card is not responding to a reset.
 	SCARD_W_UNRESPONSIVE_CARD 0x8010_0066
+	/// Power has been removed from the smart card, so that further communication is not possible.
 	SCARD_W_UNPOWERED_CARD 0x8010_0067
+	/// The smart card has been reset, so any shared state information is invalid.
 	SCARD_W_RESET_CARD 0x8010_0068
+	/// The smart card has been removed, so that further communication is not possible.
 	SCARD_W_REMOVED_CARD 0x8010_0069
+	/// Access was denied because of a security violation.
 	SCARD_W_SECURITY_VIOLATION 0x8010_006a
+	/// The card cannot be accessed because the wrong PIN was presented.
 	SCARD_W_WRONG_CHV 0x8010_006b
+	/// The card cannot be accessed because the maximum number of PIN entry attempts has been reached.
 	SCARD_W_CHV_BLOCKED 0x8010_006c
+	/// The end of the smart card file has been reached.
 	SCARD_W_EOF 0x8010_006d
+	/// The action was cancelled by the user.
 	SCARD_W_CANCELLED_BY_USER 0x8010_006e
+	/// No PIN was presented to the smart card.
 	SCARD_W_CARD_NOT_AUTHENTICATED 0x8010_006f
+	/// The requested item could not be found in the cache.
 	SCARD_W_CACHE_ITEM_NOT_FOUND 0x8010_0070
+	/// The requested cache item is too old and was deleted from the cache.
 	SCARD_W_CACHE_ITEM_STALE 0x8010_0071
+	/// The new cache item exceeds the maximum per-item size defined for the cache.
 	SCARD_W_CACHE_ITEM_TOO_BIG 0x8010_0072
+	/// Errors occurred accessing one or more objects - the ErrorInfo collection may have more detail.
 	COMADMIN_E_OBJECTERRORS 0x8011_0401
+	/// One or more of the object's properties are missing or invalid.
 	COMADMIN_E_OBJECTINVALID 0x8011_0402
+	/// The object was not found in the catalog.
 	COMADMIN_E_KEYMISSING 0x8011_0403
+	/// The object is already registered.
 	COMADMIN_E_ALREADYINSTALLED 0x8011_0404
+	/// An error occurred writing to the application file.
 	COMADMIN_E_APP_FILE_WRITEFAIL 0x8011_0407
+	/// An error occurred reading the application file.
 	COMADMIN_E_APP_FILE_READFAIL 0x8011_0408
+	/// Invalid version number in application file.
 	COMADMIN_E_APP_FILE_VERSION 0x8011_0409
+	/// The file path is invalid.
 	COMADMIN_E_BADPATH 0x8011_040a
+	/// The application is already installed.
 	COMADMIN_E_APPLICATIONEXISTS 0x8011_040b
+	/// The role already exists.
 	COMADMIN_E_ROLEEXISTS 0x8011_040c
+	/// An error occurred copying the file.
 	COMADMIN_E_CANTCOPYFILE 0x8011_040d
+	/// One or more users are not valid.
 	COMADMIN_E_NOUSER 0x8011_040f
+	/// One or more users in the application file are not valid.
 	COMADMIN_E_INVALIDUSERIDS 0x8011_0410
+	/// The component's CLSID is missing or corrupt.
 	COMADMIN_E_NOREGISTRYCLSID 0x8011_0411
+	/// The component's progID is missing or corrupt.
 	COMADMIN_E_BADREGISTRYPROGID 0x8011_0412
+	/// Unable to set required authentication level for update request.
 	COMADMIN_E_AUTHENTICATIONLEVEL 0x8011_0413
+	/// The identity or password set on the application is not valid.
 	COMADMIN_E_USERPASSWDNOTVALID 0x8011_0414
+	/// Application file CLSIDs or IIDs do not match corresponding DLLs.
 	COMADMIN_E_CLSIDORIIDMISMATCH 0x8011_0418
+	/// Interface information is either missing or changed.
 	COMADMIN_E_REMOTEINTERFACE 0x8011_0419
+	/// DllRegisterServer failed on component install.
 	COMADMIN_E_DLLREGISTERSERVER 0x8011_041a
+	/// No server file share available.
 	COMADMIN_E_NOSERVERSHARE 0x8011_041b
+	/// DLL could not be loaded.
 	COMADMIN_E_DLLLOADFAILED 0x8011_041d
+	/// The registered TypeLib ID is not valid.
 	COMADMIN_E_BADREGISTRYLIBID 0x8011_041e
+	/// Application install directory not found.
 	COMADMIN_E_APPDIRNOTFOUND 0x8011_041f
+	/// Errors occurred while in the component registrar.
 	COMADMIN_E_REGISTRARFAILED 0x8011_0423
+	/// The file does not exist.
 	COMADMIN_E_COMPFILE_DOESNOTEXIST 0x8011_0424
+	/// The DLL could not be loaded.
 	COMADMIN_E_COMPFILE_LOADDLLFAIL 0x8011_0425
+	/// GetClassObject failed in the DLL.
 	COMADMIN_E_COMPFILE_GETCLASSOBJ 0x8011_0426
+	/// The DLL does not support the components listed in the TypeLib.
 	COMADMIN_E_COMPFILE_CLASSNOTAVAIL 0x8011_0427
+	/// The TypeLib could not be loaded.
 	COMADMIN_E_COMPFILE_BADTLB 0x8011_0428
+	/// The file does not contain components or component information.
 	COMADMIN_E_COMPFILE_NOTINSTALLABLE 0x8011_0429
+	/// Changes to this object and its sub-objects have been disabled.
 	COMADMIN_E_NOTCHANGEABLE 0x8011_042a
+	/// The delete function has been disabled for this object.
 	COMADMIN_E_NOTDELETEABLE 0x8011_042b
+	/// The server catalog version is not supported.
 	COMADMIN_E_SESSION 0x8011_042c
+	/// The component move was disallowed, because the source or destination application is either a system application or currently locked against changes.
 	COMADMIN_E_COMP_MOVE_LOCKED 0x8011_042d
+	/// The component move failed because the destination application no longer exists.
 	COMADMIN_E_COMP_MOVE_BAD_DEST 0x8011_042e
+	/// The system was unable to register the TypeLib.
 	COMADMIN_E_REGISTERTLB 0x8011_0430
+	/// This operation cannot be performed on the system application.
 	COMADMIN_E_SYSTEMAPP 0x8011_0433
+	/// The component registrar referenced in this file is not available.
 	COMADMIN_E_COMPFILE_NOREGISTRAR 0x8011_0434
+	/// A component in the same DLL is already installed.
 	COMADMIN_E_COREQCOMPINSTALLED 0x8011_0435
+	/// The service is not installed.
 	COMADMIN_E_SERVICENOTINSTALLED 0x8011_0436
+	/// One or more property settings are either invalid or in conflict with each other.
 	COMADMIN_E_PROPERTYSAVEFAILED 0x8011_0437
+	/// The object you are attempting to add or rename already exists.
 	COMADMIN_E_OBJECTEXISTS 0x8011_0438
+	/// The component already exists.
 	COMADMIN_E_COMPONENTEXISTS 0x8011_0439
+	/// The registration file is corrupt.
 	COMADMIN_E_REGFILE_CORRUPT 0x8011_043b
+	/// The property value is too large.
 	COMADMIN_E_PROPERTY_OVERFLOW 0x8011_043c
+	/// Object was not found in registry.
 	COMADMIN_E_NOTINREGISTRY 0x8011_043e
+	/// This object is not poolable.
 	COMADMIN_E_OBJECTNOTPOOLABLE 0x8011_043f
+	/// A CLSID with the same GUID as the new application ID is already installed on this machine.
 	COMADMIN_E_APPLID_MATCHES_CLSID 0x8011_0446
+	/// A role assigned to a component, interface, or method did not exist in the application.
 	COMADMIN_E_ROLE_DOES_NOT_EXIST 0x8011_0447
+	/// You must have components in an application in order to start the application.
 	COMADMIN_E_START_APP_NEEDS_COMPONENTS 0x8011_0448
+	/// This operation is not enabled on this platform.
 	COMADMIN_E_REQUIRES_DIFFERENT_PLATFORM 0x8011_0449
+	/// Application Proxy is not exportable.
 	COMADMIN_E_CAN_NOT_EXPORT_APP_PROXY 0x8011_044a
+	/// Failed to start application because it is either a library application or an application proxy.
 	COMADMIN_E_CAN_NOT_START_APP 0x8011_044b
+	/// System application is not exportable.
 	COMADMIN_E_CAN_NOT_EXPORT_SYS_APP 0x8011_044c
+	/// Cannot subscribe to this component (the component may have been imported).
 	COMADMIN_E_CANT_SUBSCRIBE_TO_COMPONENT 0x8011_044d
+	/// An event class cannot also be a subscriber component.
 	COMADMIN_E_EVENTCLASS_CANT_BE_SUBSCRIBER 0x8011_044e
+	/// Library applications and application proxies are incompatible.
 	COMADMIN_E_LIB_APP_PROXY_INCOMPATIBLE 0x8011_044f
+	/// This function is valid for the base partition only.
 	COMADMIN_E_BASE_PARTITION_ONLY 0x8011_0450
+	/// You cannot start an application that has been disabled.
 	COMADMIN_E_START_APP_DISABLED 0x8011_0451
+	/// The specified partition name is already in use on this computer.
 	COMADMIN_E_CAT_DUPLICATE_PARTITION_NAME 0x8011_0457
+	/// The specified partition name is invalid. Check that the name contains at least one visible character.
 	COMADMIN_E_CAT_INVALID_PARTITION_NAME 0x8011_0458
+	/// The partition cannot be deleted because it is the default partition for one or more users.
 	COMADMIN_E_CAT_PARTITION_IN_USE 0x8011_0459
+	/// The partition cannot be exported, because one or more components in the partition have the same file name.
 	COMADMIN_E_FILE_PARTITION_DUPLICATE_FILES 0x8011_045a
+	/// Applications that contain one or more imported components cannot be installed into a non-base partition.
 	COMADMIN_E_CAT_IMPORTED_COMPONENTS_NOT_ALLOWED 0x8011_045b
+	/// The application name is not unique and cannot be resolved to an application id.
 	COMADMIN_E_AMBIGUOUS_APPLICATION_NAME 0x8011_045c
+	/// The partition name is not unique and cannot be resolved to a partition id.
 	COMADMIN_E_AMBIGUOUS_PARTITION_NAME 0x8011_045d
+	/// The COM+ registry database has not been initialized.
 	COMADMIN_E_REGDB_NOTINITIALIZED 0x8011_0472
+	/// The COM+ registry database is not open.
 	COMADMIN_E_REGDB_NOTOPEN 0x8011_0473
+	/// The COM+ registry database detected a system error.
 	COMADMIN_E_REGDB_SYSTEMERR 0x8011_0474
+	/// The COM+ registry database is already running.
 	COMADMIN_E_REGDB_ALREADYRUNNING 0x8011_0475
+	/// This version of the COM+ registry database cannot be migrated.
 	COMADMIN_E_MIG_VERSIONNOTSUPPORTED 0x8011_0480
+	/// The schema version to be migrated could not be found in the COM+ registry database.
 	COMADMIN_E_MIG_SCHEMANOTFOUND 0x8011_0481
+	/// There was a type mismatch between binaries.
 	COMADMIN_E_CAT_BITNESSMISMATCH 0x8011_0482
+	/// A binary of unknown or invalid type was provided.
 	COMADMIN_E_CAT_UNACCEPTABLEBITNESS 0x8011_0483
+	/// There was a type mismatch between a binary and an application.
 	COMADMIN_E_CAT_WRONGAPPBITNESS 0x8011_0484
+	/// The application cannot be paused or resumed.
 	COMADMIN_E_CAT_PAUSE_RESUME_NOT_SUPPORTED 0x8011_0485
+	/// The COM+ Catalog Server threw an exception during execution.
 	COMADMIN_E_CAT_SERVERFAULT 0x8011_0486
+	/// Only COM+ Applications marked "queued" can be invoked using the "queue" moniker.
 	COMQC_E_APPLICATION_NOT_QUEUED 0x8011_0600
+	/// At least one interface must be marked "queued" in order to create a queued component instance with the "queue" moniker.
 	COMQC_E_NO_QUEUEABLE_INTERFACES 0x8011_0601
+	/// MSMQ is required for the requested operation and is not installed.
 	COMQC_E_QUEUING_SERVICE_NOT_AVAILABLE 0x8011_0602
+	/// Unable to marshal an interface that does not support IPersistStream.
 	COMQC_E_NO_IPERSISTSTREAM 0x8011_0603
+	/// The message is improperly formatted or was damaged in transit.
 	COMQC_E_BAD_MESSAGE 0x8011_0604
+	/// An unauthenticated message was received by an application that accepts only authenticated messages.
 	COMQC_E_UNAUTHENTICATED 0x8011_0605
+	/// The message was requeued or moved by a user not in the "QC Trusted User" role.
 	COMQC_E_UNTRUSTED_ENQUEUER 0x8011_0606
+	/// Cannot create a duplicate resource of type Distributed Transaction Coordinator.
 	MSDTC_E_DUPLICATE_RESOURCE 0x8011_0701
+	/// One of the objects being inserted or updated does not belong to a valid parent collection.
 	COMADMIN_E_OBJECT_PARENT_MISSING 0x8011_0808
+	/// One of the specified objects cannot be found.
 	COMADMIN_E_OBJECT_DOES_NOT_EXIST 0x8011_0809
+	/// The specified application is not currently running.
 	COMADMIN_E_APP_NOT_RUNNING 0x8011_080a
+	/// The partition(s) specified are not valid.
 	COMADMIN_E_INVALID_PARTITION 0x8011_080b
+	/// COM+ applications that run as NT service may not be pooled or recycled.
 	COMADMIN_E_SVCAPP_NOT_POOLABLE_OR_RECYCLABLE 0x8011_080d
+	/// One or more users are already assigned to a local partition set.
 	COMADMIN_E_USER_IN_SET 0x8011_080e
+	/// Library applications may not be recycled.
 	COMADMIN_E_CANTRECYCLELIBRARYAPPS 0x8011_080f
+	/// Applications running as NT services may not be recycled.
 	COMADMIN_E_CANTRECYCLESERVICEAPPS 0x8011_0811
+	/// The process has already been recycled.
 	COMADMIN_E_PROCESSALREADYRECYCLED 0x8011_0812
+	/// A paused process may not be recycled.
 	COMADMIN_E_PAUSEDPROCESSMAYNOTBERECYCLED 0x8011_0813
+	/// Library applications may not be NT services.
 	COMADMIN_E_CANTMAKEINPROCSERVICE 0x8011_0814
+	/// The ProgID provided to the copy operation is invalid. The ProgID is in use by another registered CLSID.
 	COMADMIN_E_PROGIDINUSEBYCLSID 0x8011_0815
+	/// The partition specified as default is not a member of the partition set.
 	COMADMIN_E_DEFAULT_PARTITION_NOT_IN_SET 0x8011_0816
+	/// A recycled process may not be paused.
 	COMADMIN_E_RECYCLEDPROCESSMAYNOTBEPAUSED 0x8011_0817
+	/// Access to the specified partition is denied.
 	COMADMIN_E_PARTITION_ACCESSDENIED 0x8011_0818
+	/// Only Application Files (*.MSI files) can be installed into partitions.
 	COMADMIN_E_PARTITION_MSI_ONLY 0x8011_0819
+	/// Applications containing one or more legacy components may not be exported to 1.0 format.
 	COMADMIN_E_LEGACYCOMPS_NOT_ALLOWED_IN_1_0_FORMAT 0x8011_081a
+	/// Legacy components may not exist in non-base partitions.
 	COMADMIN_E_LEGACYCOMPS_NOT_ALLOWED_IN_NONBASE_PARTITIONS 0x8011_081b
+	/// A component cannot be moved (or copied) from the System Application, an application proxy or a non-changeable application.
 	COMADMIN_E_COMP_MOVE_SOURCE 0x8011_081c
+	/// A component cannot be moved (or copied) to the System Application, an application proxy or a non-changeable application.
 	COMADMIN_E_COMP_MOVE_DEST 0x8011_081d
+	/// A private component cannot be moved (or copied) to a library application or to the base partition.
 	COMADMIN_E_COMP_MOVE_PRIVATE 0x8011_081e
+	/// The Base Application Partition exists in all partition sets and cannot be removed.
 	COMADMIN_E_BASEPARTITION_REQUIRED_IN_SET 0x8011_081f
+	/// Alas, Event Class components cannot be aliased.
 	COMADMIN_E_CANNOT_ALIAS_EVENTCLASS 0x8011_0820
+	/// Access is denied because the component is private.
 	COMADMIN_E_PRIVATE_ACCESSDENIED 0x8011_0821
+	/// The specified SAFER level is invalid.
 	COMADMIN_E_SAFERINVALID 0x8011_0822
+	/// The specified user cannot write to the system registry.
 	COMADMIN_E_REGISTRY_ACCESSDENIED 0x8011_0823
+	/// COM+ partitions are currently disabled.
 	COMADMIN_E_PARTITIONS_DISABLED 0x8011_0824
+	/// Debugger was attached.
 	WER_S_REPORT_DEBUG 0x001b_0000
+	/// Report was uploaded.
 	WER_S_REPORT_UPLOADED 0x001b_0001
+	/// Report was queued.
 	WER_S_REPORT_QUEUED 0x001b_0002
+	/// Reporting was disabled.
 	WER_S_DISABLED 0x001b_0003
+	/// Reporting was temporarily suspended.
 	WER_S_SUSPENDED_UPLOAD 0x001b_0004
+	/// Report was not queued to queuing being disabled.
 	WER_S_DISABLED_QUEUE 0x001b_0005
+	/// Report was uploaded, but not archived due to archiving being disabled.
 	WER_S_DISABLED_ARCHIVE 0x001b_0006
+	/// Reporting was successfully spun off as an asynchronous operation.
 	WER_S_REPORT_ASYNC 0x001b_0007
+	/// The assertion was handled.
 	WER_S_IGNORE_ASSERT_INSTANCE 0x001b_0008
+	/// The assertion was handled and added to a permanent ignore list.
 	WER_S_IGNORE_ALL_ASSERTS 0x001b_0009
+	/// The assertion was resumed as unhandled.
 	WER_S_ASSERT_CONTINUE 0x001b_000a
+	/// Report was throttled.
 	WER_S_THROTTLED 0x001b_000b
+	/// Report was uploaded with cab.
 	WER_S_REPORT_UPLOADED_CAB 0x001b_000c
+	/// Crash reporting failed.
 	WER_E_CRASH_FAILURE 0x801b_8000
+	/// Report aborted due to user cancellation.
 	WER_E_CANCELED 0x801b_8001
+	/// Report aborted due to network failure.
 	WER_E_NETWORK_FAILURE 0x801b_8002
+	/// Report not initialized.
 	WER_E_NOT_INITIALIZED 0x801b_8003
+	/// Reporting is already in progress for the specified process.
 	WER_E_ALREADY_REPORTING 0x801b_8004
+	/// Dump not generated due to a throttle.
 	WER_E_DUMP_THROTTLED 0x801b_8005
+	/// Operation failed due to insufficient user consent.
 	WER_E_INSUFFICIENT_CONSENT 0x801b_8006
+	/// Report aborted due to performance criteria.
 	WER_E_TOO_HEAVY 0x801b_8007
+	/// The IO was completed by a filter.
 	ERROR_FLT_IO_COMPLETE 0x001f_0001
+	/// A handler was not defined by the filter for this operation.
 	ERROR_FLT_NO_HANDLER_DEFINED 0x801f_0001
+	/// A context is already defined for this object.
 	ERROR_FLT_CONTEXT_ALREADY_DEFINED 0x801f_0002
+	/// Asynchronous requests are not valid for this operation.
 	ERROR_FLT_INVALID_ASYNCHRONOUS_REQUEST 0x801f_0003
+	/// Disallow the Fast IO path for this operation.
 	ERROR_FLT_DISALLOW_FAST_IO 0x801f_0004
+	/// An invalid name request was made. The name requested cannot be retrieved at this time.
 	ERROR_FLT_INVALID_NAME_REQUEST 0x801f_0005
+	/// Posting this operation to a worker thread for further processing is not safe at this time because it could lead to a system deadlock.
 	ERROR_FLT_NOT_SAFE_TO_POST_OPERATION 0x801f_0006
+	/// The Filter Manager was not initialized when a filter tried to register. Make sure that the Filter Manager is getting loaded as a driver.
 	ERROR_FLT_NOT_INITIALIZED 0x801f_0007
+	/// The filter is not ready for attachment to volumes because it has not finished initializing (FltStartFiltering has not been called).
 	ERROR_FLT_FILTER_NOT_READY 0x801f_0008
+	/// The filter must cleanup any operation specific context at this time because it is being removed from the system before the operation is completed by the lower drivers.
 	ERROR_FLT_POST_OPERATION_CLEANUP 0x801f_0009
+	/// The Filter Manager had an internal error from which it cannot recover, therefore the operation has been failed. This is usually the result of a filter returning an invalid value from a pre-operation callback.
 	ERROR_FLT_INTERNAL_ERROR 0x801f_000a
+	/// The object specified for this action is in the process of being deleted, therefore the action requested cannot be completed at this time.
 	ERROR_FLT_DELETING_OBJECT 0x801f_000b
+	/// Non-paged pool must be used for this type of context.
 	ERROR_FLT_MUST_BE_NONPAGED_POOL 0x801f_000c
+	/// A duplicate handler definition has been provided for an operation.
 	ERROR_FLT_DUPLICATE_ENTRY 0x801f_000d
+	/// The callback data queue has been disabled.
 	ERROR_FLT_CBDQ_DISABLED 0x801f_000e
+	/// Do not attach the filter to the volume at this time.
 	ERROR_FLT_DO_NOT_ATTACH 0x801f_000f
+	/// Do not detach the filter from the volume at this time.
 	ERROR_FLT_DO_NOT_DETACH 0x801f_0010
+	/// An instance already exists at this altitude on the volume specified.
 	ERROR_FLT_INSTANCE_ALTITUDE_COLLISION 0x801f_0011
+	/// An instance already exists with this name on the volume specified.
 	ERROR_FLT_INSTANCE_NAME_COLLISION 0x801f_0012
+	/// The system could not find the filter specified.
 	ERROR_FLT_FILTER_NOT_FOUND 0x801f_0013
+	/// The system could not find the volume specified.
 	ERROR_FLT_VOLUME_NOT_FOUND 0x801f_0014
+	/// The system could not find the instance specified.
 	ERROR_FLT_INSTANCE_NOT_FOUND 0x801f_0015
+	/// No registered context allocation definition was found for the given request.
 	ERROR_FLT_CONTEXT_ALLOCATION_NOT_FOUND 0x801f_0016
+	/// An invalid parameter was specified during context registration.
 	ERROR_FLT_INVALID_CONTEXT_REGISTRATION 0x801f_0017
+	/// The name requested was not found in Filter Manager's name cache and could not be retrieved from the file system.
 	ERROR_FLT_NAME_CACHE_MISS 0x801f_0018
+	/// The requested device object does not exist for the given volume.
 	ERROR_FLT_NO_DEVICE_OBJECT 0x801f_0019
+	/// The specified volume is already mounted.
 	ERROR_FLT_VOLUME_ALREADY_MOUNTED 0x801f_001a
+	/// The specified Transaction Context is already enlisted in a transaction.
 	ERROR_FLT_ALREADY_ENLISTED 0x801f_001b
+	/// The specified context is already attached to another object.
 	ERROR_FLT_CONTEXT_ALREADY_LINKED 0x801f_001c
+	/// No waiter is present for the filter's reply to this message.
 	ERROR_FLT_NO_WAITER_FOR_REPLY 0x801f_0020
+	/// The filesystem database resource is in use. Registration cannot complete at this time.
 	ERROR_FLT_REGISTRATION_BUSY 0x801f_0023
+	/// (Display driver name) stopped working normally, save your work and reboot the system to restore full display functionality. The next time you reboot the machine a dialog will be displayed giving you a chance to report this failure to Microsoft.
 	ERROR_HUNG_DISPLAY_DRIVER_THREAD 0x8026_0001
+	/// Desktop composition is disabled. The operation could not be completed because desktop composition is disabled.
 	DWM_E_COMPOSITIONDISABLED 0x8026_3001
+	/// Some desktop composition APIs are not supported while remoting. The operation is not supported while running in a remote session.
 	DWM_E_REMOTING_NOT_SUPPORTED 0x8026_3002
+	/// No DWM redirection surface is available. The DWM was unable to provide a redirection surface to complete the DirectX present.
 	DWM_E_NO_REDIRECTION_SURFACE_AVAILABLE 0x8026_3003
+	/// DWM is not queuing presents for the specified window. The window specified is not currently using queued presents.
 	DWM_E_NOT_QUEUING_PRESENTS 0x8026_3004
+	/// The adapter specified by the LUID is not found. DWM can not find the adapter specified by the LUID.
 	DWM_E_ADAPTER_NOT_FOUND 0x8026_3005
+	/// GDI redirection surface was returned. GDI redirection surface of the top level window was returned.
 	DWM_S_GDI_REDIRECTION_SURFACE 0x0026_3005
+	/// Redirection surface can not be created. The size of the surface is larger than what is supported on this machine.
 	DWM_E_TEXTURE_TOO_LARGE 0x8026_3007
+	/// GDI redirection surface is either on a different adapter or in system memory. Perform blt via GDI.
 	DWM_S_GDI_REDIRECTION_SURFACE_BLT_VIA_GDI 0x0026_3008
+	/// Monitor descriptor could not be obtained.
 	ERROR_MONITOR_NO_DESCRIPTOR 0x0026_1001
+	/// Format of the obtained monitor descriptor is not supported by this release.
 	ERROR_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT 0x0026_1002
+	/// Checksum of the obtained monitor descriptor is invalid.
 	ERROR_MONITOR_INVALID_DESCRIPTOR_CHECKSUM 0xc026_1003
+	/// Monitor descriptor contains an invalid standard timing block.
 	ERROR_MONITOR_INVALID_STANDARD_TIMING_BLOCK 0xc026_1004
+	/// WMI data block registration failed for one of the MSMonitorClass WMI subclasses.
 	ERROR_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED 0xc026_1005
+	/// Provided monitor descriptor block is either corrupted or does not contain monitor's detailed serial number.
 	ERROR_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK 0xc026_1006
+	/// Provided monitor descriptor block is either corrupted or does not contain monitor's user friendly name.
 	ERROR_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK 0xc026_1007
+	/// There is no monitor descriptor data at the specified (offset, size) region.
 	ERROR_MONITOR_NO_MORE_DESCRIPTOR_DATA 0xc026_1008
+	/// Monitor descriptor contains an invalid detailed timing block.
 	ERROR_MONITOR_INVALID_DETAILED_TIMING_BLOCK 0xc026_1009
+	/// Monitor descriptor contains invalid manufacture date.
 	ERROR_MONITOR_INVALID_MANUFACTURE_DATE 0xc026_100a
+	/// Exclusive mode ownership is needed to create unmanaged primary allocation.
 	ERROR_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER 0xc026_2000
+	/// The driver needs more DMA buffer space in order to complete the requested operation.
 	ERROR_GRAPHICS_INSUFFICIENT_DMA_BUFFER 0xc026_2001
+	/// Specified display adapter handle is invalid.
 	ERROR_GRAPHICS_INVALID_DISPLAY_ADAPTER 0xc026_2002
+	/// Specified display adapter and all of its state has been reset.
 	ERROR_GRAPHICS_ADAPTER_WAS_RESET 0xc026_2003
+	/// The driver stack doesn't match the expected driver model.
 	ERROR_GRAPHICS_INVALID_DRIVER_MODEL 0xc026_2004
+	/// Present happened but ended up into the changed desktop mode.
 	ERROR_GRAPHICS_PRESENT_MODE_CHANGED 0xc026_2005
+	/// Nothing to present due to desktop occlusion.
 	ERROR_GRAPHICS_PRESENT_OCCLUDED 0xc026_2006
+	/// Not able to present due to denial of desktop access.
 	ERROR_GRAPHICS_PRESENT_DENIED 0xc026_2007
+	/// Not able to present with color conversion.
 	ERROR_GRAPHICS_CANNOTCOLORCONVERT 0xc026_2008
+	/// The kernel driver detected a version mismatch between it and the user mode driver.
 	ERROR_GRAPHICS_DRIVER_MISMATCH 0xc026_2009
+	/// Specified buffer is not big enough to contain entire requested dataset. Partial data populated up to the size of the buffer. Caller needs to provide buffer of size as specified in the partially populated buffer's content (interface specific).
 	ERROR_GRAPHICS_PARTIAL_DATA_POPULATED 0x4026_200a
+	/// Present redirection is disabled (desktop windowing management subsystem is off).
 	ERROR_GRAPHICS_PRESENT_REDIRECTION_DISABLED 0xc026_200b
+	/// Previous exclusive VidPn source owner has released its ownership.
 	ERROR_GRAPHICS_PRESENT_UNOCCLUDED 0xc026_200c
+	/// Window DC is not available for presentation.
 	ERROR_GRAPHICS_WINDOWDC_NOT_AVAILABLE 0xc026_200d
+	/// Windowless present is disabled (desktop windowing management subsystem is off).
 	ERROR_GRAPHICS_WINDOWLESS_PRESENT_DISABLED 0xc026_200e
+	/// Window handle is invalid.
 	ERROR_GRAPHICS_PRESENT_INVALID_WINDOW 0xc026_200f
+	/// No buffer is bound to composition surface.
 	ERROR_GRAPHICS_PRESENT_BUFFER_NOT_BOUND 0xc026_2010
+	/// Vail state has been changed.
 	ERROR_GRAPHICS_VAIL_STATE_CHANGED 0xc026_2011
+	/// Notifying indirect display UMDF class driver to abandon current swapchain.
 	ERROR_GRAPHICS_INDIRECT_DISPLAY_ABANDON_SWAPCHAIN 0xc026_2012
+	/// Notifying indirect display UMDF class driver that indirect display device has been stopped.
 	ERROR_GRAPHICS_INDIRECT_DISPLAY_DEVICE_STOPPED 0xc026_2013
+	/// Not enough video memory available to complete the operation.
 	ERROR_GRAPHICS_NO_VIDEO_MEMORY 0xc026_2100
+	/// Couldn't probe and lock the underlying memory of an allocation.
 	ERROR_GRAPHICS_CANT_LOCK_MEMORY 0xc026_2101
+	/// The allocation is currently busy.
 	ERROR_GRAPHICS_ALLOCATION_BUSY 0xc026_2102
+	/// An object being referenced has reach the maximum reference count already and can't be reference further.
 	ERROR_GRAPHICS_TOO_MANY_REFERENCES 0xc026_2103
+	/// A problem couldn't be solved due to some currently existing condition. The problem should be tried again later.
 	ERROR_GRAPHICS_TRY_AGAIN_LATER 0xc026_2104
+	/// A problem couldn't be solved due to some currently existing condition. The problem should be tried again immediately.
 	ERROR_GRAPHICS_TRY_AGAIN_NOW 0xc026_2105
+	/// The allocation is invalid.
 	ERROR_GRAPHICS_ALLOCATION_INVALID 0xc026_2106
+	/// No more unswizzling aperture are currently available.
 	ERROR_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE 0xc026_2107
+	/// The current allocation can't be unswizzled by an aperture.
 	ERROR_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED 0xc026_2108
+	/// The request failed because a pinned allocation can't be evicted.
 	ERROR_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION 0xc026_2109
+	/// The allocation can't be used from its current segment location for the specified operation.
 	ERROR_GRAPHICS_INVALID_ALLOCATION_USAGE 0xc026_2110
+	/// A locked allocation can't be used in the current command buffer.
 	ERROR_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION 0xc026_2111
+	/// The allocation being referenced has been closed permanently.
 	ERROR_GRAPHICS_ALLOCATION_CLOSED 0xc026_2112
+	/// An invalid allocation instance is being referenced.
 	ERROR_GRAPHICS_INVALID_ALLOCATION_INSTANCE 0xc026_2113
+	/// An invalid allocation handle is being referenced.
 	ERROR_GRAPHICS_INVALID_ALLOCATION_HANDLE 0xc026_2114
+	/// The allocation being referenced doesn't belong to the current device.
 	ERROR_GRAPHICS_WRONG_ALLOCATION_DEVICE 0xc026_2115
+	/// The specified allocation lost its content.
 	ERROR_GRAPHICS_ALLOCATION_CONTENT_LOST 0xc026_2116
+	/// GPU exception is detected on the given device. The device is not able to be scheduled.
 	ERROR_GRAPHICS_GPU_EXCEPTION_ON_DEVICE 0xc026_2200
+	/// Skip preparation of allocations referenced by the DMA buffer.
 	ERROR_GRAPHICS_SKIP_ALLOCATION_PREPARATION 0x4026_2201
+	/// Specified VidPN topology is invalid.
 	ERROR_GRAPHICS_INVALID_VIDPN_TOPOLOGY 0xc026_2300
+	/// Specified VidPN topology is valid but is not supported by this model of the display adapter.
 	ERROR_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED 0xc026_2301
+	/// Specified VidPN topology is valid but is not supported by the display adapter at this time, due to current allocation of its resources.
 	ERROR_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED 0xc026_2302
+	/// Specified VidPN handle is invalid.
 	ERROR_GRAPHICS_INVALID_VIDPN 0xc026_2303
+	/// Specified video present source is invalid.
 	ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE 0xc026_2304
+	/// Specified video present target is invalid.
 	ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET 0xc026_2305
+	/// Specified VidPN modality is not supported (e.g. at least two of the pinned modes are not cofunctional).
 	ERROR_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED 0xc026_2306
+	/// No mode is pinned on the specified VidPN source/target.
 	ERROR_GRAPHICS_MODE_NOT_PINNED 0x0026_2307
+	/// Specified VidPN source mode set is invalid.
 	ERROR_GRAPHICS_INVALID_VIDPN_SOURCEMODESET 0xc026_2308
+	/// Specified VidPN target mode set is invalid.
 	ERROR_GRAPHICS_INVALID_VIDPN_TARGETMODESET 0xc026_2309
+	/// Specified video signal frequency is invalid.
 	ERROR_GRAPHICS_INVALID_FREQUENCY 0xc026_230a
+	/// Specified video signal active region is invalid.
 	ERROR_GRAPHICS_INVALID_ACTIVE_REGION 0xc026_230b
+	/// Specified video signal total region is invalid.
 	ERROR_GRAPHICS_INVALID_TOTAL_REGION 0xc026_230c
+	/// Specified video present source mode is invalid.
 	ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE 0xc026_2310
+	/// Specified video present target mode is invalid.
 	ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE 0xc026_2311
+	/// Pinned mode must remain in the set on VidPN's cofunctional modality enumeration.
 	ERROR_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET 0xc026_2312
+	/// Specified video present path is already in VidPN's topology.
 	ERROR_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY 0xc026_2313
+	/// Specified mode is already in the mode set.
 	ERROR_GRAPHICS_MODE_ALREADY_IN_MODESET 0xc026_2314
+	/// Specified video present source set is invalid.
 	ERROR_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET 0xc026_2315
+	/// Specified video present target set is invalid.
 	ERROR_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET 0xc026_2316
+	/// Specified video present source is already in the video present source set.
 	ERROR_GRAPHICS_SOURCE_ALREADY_IN_SET 0xc026_2317
+	/// Specified video present target is already in the video present target set.
 	ERROR_GRAPHICS_TARGET_ALREADY_IN_SET 0xc026_2318
+	/// Specified VidPN present path is invalid.
 	ERROR_GRAPHICS_INVALID_VIDPN_PRESENT_PATH 0xc026_2319
+	/// Miniport has no recommendation for augmentation of the specified VidPN's topology.
 	ERROR_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY 0xc026_231a
+	/// Specified monitor frequency range set is invalid.
 	ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET 0xc026_231b
+	/// Specified monitor frequency range is invalid.
 	ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE 0xc026_231c
+	/// Specified frequency range is not in the specified monitor frequency range set.
 	ERROR_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET 0xc026_231d
+	/// Specified mode set does not specify preference for one of its modes.
 	ERROR_GRAPHICS_NO_PREFERRED_MODE 0x0026_231e
+	/// Specified frequency range is already in the specified monitor frequency range set.
 	ERROR_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET 0xc026_231f
+	/// Specified mode set is stale. Please reacquire the new mode set.
 	ERROR_GRAPHICS_STALE_MODESET 0xc026_2320
+	/// Specified monitor source mode set is invalid.
 	ERROR_GRAPHICS_INVALID_MONITOR_SOURCEMODESET 0xc026_2321
+	/// Specified monitor source mode is invalid.
 	ERROR_GRAPHICS_INVALID_MONITOR_SOURCE_MODE 0xc026_2322
+	/// Miniport does not have any recommendation regarding the request to provide a functional VidPN given the current display adapter configuration.
 	ERROR_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN 0xc026_2323
+	/// ID of the specified mode is already used by another mode in the set.
 	ERROR_GRAPHICS_MODE_ID_MUST_BE_UNIQUE 0xc026_2324
+	/// System failed to determine a mode that is supported by both the display adapter and the monitor connected to it.
 	ERROR_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION 0xc026_2325
+	/// Number of video present targets must be greater than or equal to the number of video present sources.
 	ERROR_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES 0xc026_2326
+	/// Specified present path is not in VidPN's topology.
 	ERROR_GRAPHICS_PATH_NOT_IN_TOPOLOGY 0xc026_2327
+	/// Display adapter must have at least one video present source.
 	ERROR_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE 0xc026_2328
+	/// Display adapter must have at least one video present target.
 	ERROR_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET 0xc026_2329
+	/// Specified monitor descriptor set is invalid.
 	ERROR_GRAPHICS_INVALID_MONITORDESCRIPTORSET 0xc026_232a
+	/// Specified monitor descriptor is invalid.
 	ERROR_GRAPHICS_INVALID_MONITORDESCRIPTOR 0xc026_232b
+	/// Specified descriptor is not in the specified monitor descriptor set.
 	ERROR_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET 0xc026_232c
+	/// Specified descriptor is already in the specified monitor descriptor set.
 	ERROR_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET 0xc026_232d
+	/// ID of the specified monitor descriptor is already used by another descriptor in the set.
 	ERROR_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE 0xc026_232e
+	/// Specified video present target subset type is invalid.
 	ERROR_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE 0xc026_232f
+	/// Two or more of the specified resources are not related to each other, as defined by the interface semantics.
 	ERROR_GRAPHICS_RESOURCES_NOT_RELATED 0xc026_2330
+	/// ID of the specified video present source is already used by another source in the set.
 	ERROR_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE 0xc026_2331
+	/// ID of the specified video present target is already used by another target in the set.
 	ERROR_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE 0xc026_2332
+	/// Specified VidPN source cannot be used because there is no available VidPN target to connect it to.
 	ERROR_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET 0xc026_2333
+	/// Newly arrived monitor could not be associated with a display adapter.
 	ERROR_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER 0xc026_2334
+	/// Display adapter in question does not have an associated VidPN manager.
 	ERROR_GRAPHICS_NO_VIDPNMGR 0xc026_2335
+	/// VidPN manager of the display adapter in question does not have an active VidPN.
 	ERROR_GRAPHICS_NO_ACTIVE_VIDPN 0xc026_2336
+	/// Specified VidPN topology is stale. Please reacquire the new topology.
 	ERROR_GRAPHICS_STALE_VIDPN_TOPOLOGY 0xc026_2337
+	/// There is no monitor connected on the specified video present target.
 	ERROR_GRAPHICS_MONITOR_NOT_CONNECTED 0xc026_2338
+	/// Specified source is not part of the specified VidPN's topology.
 	ERROR_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY 0xc026_2339
+	/// Specified primary surface size is invalid.
 	ERROR_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE 0xc026_233a
+	/// Specified visible region size is invalid.
 	ERROR_GRAPHICS_INVALID_VISIBLEREGION_SIZE 0xc026_233b
+	/// Specified stride is invalid.
 	ERROR_GRAPHICS_INVALID_STRIDE 0xc026_233c
+	/// Specified pixel format is invalid.
 	ERROR_GRAPHICS_INVALID_PIXELFORMAT 0xc026_233d
+	/// Specified color basis is invalid.
 	ERROR_GRAPHICS_INVALID_COLORBASIS 0xc026_233e
+	/// Specified pixel value access mode is invalid.
 	ERROR_GRAPHICS_INVALID_PIXELVALUEACCESSMODE 0xc026_233f
+	/// Specified target is not part of the specified VidPN's topology.
 	ERROR_GRAPHICS_TARGET_NOT_IN_TOPOLOGY 0xc026_2340
+	/// Failed to acquire display mode management interface.
 	ERROR_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT 0xc026_2341
+	/// Specified VidPN source is already owned by a DMM client and cannot be used until that client releases it.
 	ERROR_GRAPHICS_VIDPN_SOURCE_IN_USE 0xc026_2342
+	/// Specified VidPN is active and cannot be accessed.
 	ERROR_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN 0xc026_2343
+	/// Specified VidPN present path importance ordinal is invalid.
 	ERROR_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL 0xc026_2344
+	/// Specified VidPN present path content geometry transformation is invalid.
 	ERROR_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION 0xc026_2345
+	/// Specified content geometry transformation is not supported on the respective VidPN present path.
 	ERROR_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED 0xc026_2346
+	/// Specified gamma ramp is invalid.
 	ERROR_GRAPHICS_INVALID_GAMMA_RAMP 0xc026_2347
+	/// Specified gamma ramp is not supported on the respective VidPN present path.
 	ERROR_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED 0xc026_2348
+	/// Multi-sampling is not supported on the respective VidPN present path.
 	ERROR_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED 0xc026_2349
+	/// Specified mode is not in the specified mode set.
 	ERROR_GRAPHICS_MODE_NOT_IN_MODESET 0xc026_234a
+	/// Specified data set (e.g. mode set, frequency range set, descriptor set, topology, etc.) is empty.
 	ERROR_GRAPHICS_DATASET_IS_EMPTY 0x0026_234b
+	/// Specified data set (e.g. mode set, frequency range set, descriptor set, topology, etc.) does not contain any more elements.
 	ERROR_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET 0x0026_234c
+	/// Specified VidPN topology recommendation reason is invalid.
 	ERROR_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON 0xc026_234d
+	/// Specified VidPN present path content type is invalid.
 	ERROR_GRAPHICS_INVALID_PATH_CONTENT_TYPE 0xc026_234e
+	/// Specified VidPN present path copy protection type is invalid.
 	ERROR_GRAPHICS_INVALID_COPYPROTECTION_TYPE 0xc026_234f
+	/// No more than one unassigned mode set can exist at any given time for a given VidPN source/target.
 	ERROR_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS 0xc026_2350
+	/// Specified content transformation is not pinned on the specified VidPN present path.
 	ERROR_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED 0x0026_2351
+	/// Specified scanline ordering type is invalid.
 	ERROR_GRAPHICS_INVALID_SCANLINE_ORDERING 0xc026_2352
+	/// Topology changes are not allowed for the specified VidPN.
 	ERROR_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED 0xc026_2353
+	/// All available importance ordinals are already used in specified topology.
 	ERROR_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS 0xc026_2354
+	/// Specified primary surface has a different private format attribute than the current primary surface.
 	ERROR_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT 0xc026_2355
+	/// Specified mode pruning algorithm is invalid.
 	ERROR_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM 0xc026_2356
+	/// Specified monitor capability origin is invalid.
 	ERROR_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN 0xc026_2357
+	/// Specified monitor frequency range constraint is invalid.
 	ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT 0xc026_2358
+	/// Maximum supported number of present paths has been reached.
 	ERROR_GRAPHICS_MAX_NUM_PATHS_REACHED 0xc026_2359
+	/// Miniport requested that augmentation be cancelled for the specified source of the specified VidPN's topology.
 	ERROR_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION 0xc026_235a
+	/// Specified client type was not recognized.
 	ERROR_GRAPHICS_INVALID_CLIENT_TYPE 0xc026_235b
+	/// Client VidPN is not set on this adapter (e.g. no user mode initiated mode changes took place on this adapter yet).
 	ERROR_GRAPHICS_CLIENTVIDPN_NOT_SET 0xc026_235c
+	/// Specified display adapter child device already has an external device connected to it.
 	ERROR_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED 0xc026_2400
+	/// Specified display adapter child device does not support descriptor exposure.
 	ERROR_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED 0xc026_2401
+	/// Child device presence was not reliably detected.
 	ERROR_GRAPHICS_UNKNOWN_CHILD_STATUS 0x4026_242f
+	/// The display adapter is not linked to any other adapters.
 	ERROR_GRAPHICS_NOT_A_LINKED_ADAPTER 0xc026_2430
+	/// Lead adapter in a linked configuration was not enumerated yet.
 	ERROR_GRAPHICS_LEADLINK_NOT_ENUMERATED 0xc026_2431
+	/// Some chain adapters in a linked configuration were not enumerated yet.
 	ERROR_GRAPHICS_CHAINLINKS_NOT_ENUMERATED 0xc026_2432
+	/// The chain of linked adapters is not ready to start because of an unknown failure.
 	ERROR_GRAPHICS_ADAPTER_CHAIN_NOT_READY 0xc026_2433
+	/// An attempt was made to start a lead link display adapter when the chain links were not started yet.
 	ERROR_GRAPHICS_CHAINLINKS_NOT_STARTED 0xc026_2434
+	/// An attempt was made to power up a lead link display adapter when the chain links were powered down.
 	ERROR_GRAPHICS_CHAINLINKS_NOT_POWERED_ON 0xc026_2435
+	/// The adapter link was found to be in an inconsistent state. Not all adapters are in an expected PNP/Power state.
 	ERROR_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE 0xc026_2436
+	/// Starting the leadlink adapter has been deferred temporarily.
 	ERROR_GRAPHICS_LEADLINK_START_DEFERRED 0x4026_2437
+	/// The driver trying to start is not the same as the driver for the POSTed display adapter.
 	ERROR_GRAPHICS_NOT_POST_DEVICE_DRIVER 0xc026_2438
+	/// The display adapter is being polled for children too frequently at the same polling level.
 	ERROR_GRAPHICS_POLLING_TOO_FREQUENTLY 0x4026_2439
+	/// Starting the adapter has been deferred temporarily.
 	ERROR_GRAPHICS_START_DEFERRED 0x4026_243a
+	/// An operation is being attempted that requires the display adapter to be in a quiescent state.
 	ERROR_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED 0xc026_243b
+	/// We can depend on the child device presence returned by the driver.
 	ERROR_GRAPHICS_DEPENDABLE_CHILD_STATUS 0x4026_243c
+	/// The driver does not support OPM.
 	ERROR_GRAPHICS_OPM_NOT_SUPPORTED 0xc026_2500
+	/// The driver does not support COPP.
 	ERROR_GRAPHICS_COPP_NOT_SUPPORTED 0xc026_2501
+	/// The driver does not support UAB.
 	ERROR_GRAPHICS_UAB_NOT_SUPPORTED 0xc026_2502
+	/// The specified encrypted parameters are invalid.
 	ERROR_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS 0xc026_2503
+	/// The GDI display device passed to this function does not have any active video outputs.
 	ERROR_GRAPHICS_OPM_NO_VIDEO_OUTPUTS_EXIST 0xc026_2505
+	/// An internal error caused this operation to fail.
 	ERROR_GRAPHICS_OPM_INTERNAL_ERROR 0xc026_250b
+	/// The function failed because the caller passed in an invalid OPM user mode handle.
 	ERROR_GRAPHICS_OPM_INVALID_HANDLE 0xc026_250c
+	/// A certificate could not be returned because the certificate buffer passed to the function was too small.
 	ERROR_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH 0xc026_250e
+	/// A video output could not be created because the frame buffer is in spanning mode.
 	ERROR_GRAPHICS_OPM_SPANNING_MODE_ENABLED 0xc026_250f
+	/// A video output could not be created because the frame buffer is in theater mode.
 	ERROR_GRAPHICS_OPM_THEATER_MODE_ENABLED 0xc026_2510
+	/// The function failed because the display adapter's Hardware Functionality Scan failed to validate the graphics hardware.
 	ERROR_GRAPHICS_PVP_HFS_FAILED 0xc026_2511
+	/// The HDCP System Renewability Message passed to this function did not comply with section 5 of the HDCP 1.1 specification.
 	ERROR_GRAPHICS_OPM_INVALID_SRM 0xc026_2512
+	/// The video output cannot enable the High-bandwidth Digital Content Protection (HDCP) System because it does not support HDCP.
 	ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP 0xc026_2513
+	/// The video output cannot enable Analogue Copy Protection (ACP) because it does not support ACP.
 	ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP 0xc026_2514
+	/// The video output cannot enable the Content Generation Management System Analogue (CGMS-A) protection technology because it does not support CGMS-A.
 	ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA 0xc026_2515
+	/// The IOPMVideoOutput::GetInformation method cannot return the version of the SRM being used because the application never successfully passed an SRM to the video output.
 	ERROR_GRAPHICS_OPM_HDCP_SRM_NEVER_SET 0xc026_2516
+	/// The IOPMVideoOutput::Configure method cannot enable the specified output protection technology because the output's screen resolution is too high.
 	ERROR_GRAPHICS_OPM_RESOLUTION_TOO_HIGH 0xc026_2517
+	/// The IOPMVideoOutput::Configure method cannot enable HDCP because the display adapter's HDCP hardware is already being used by other physical outputs.
 	ERROR_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE 0xc026_2518
+	/// The operating system asynchronously destroyed this OPM video output because the operating system's state changed. This error typically occurs because the monitor PDO associated with this video output was removed, the monitor PDO associated with this video output was stopped, the video output's session became a non-console session or the video output's desktop became an inactive desktop.
 	ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_NO_LONGER_EXISTS 0xc026_251a
+	/// The method failed because the session is changing its type. No IOPMVideoOutput methods can be called when a session is changing its type. There are currently three types of sessions: console, disconnected and remote.
 	ERROR_GRAPHICS_OPM_SESSION_TYPE_CHANGE_IN_PROGRESS 0xc026_251b
+	/// Either the IOPMVideoOutput::COPPCompatibleGetInformation, IOPMVideoOutput::GetInformation, or IOPMVideoOutput::Configure method failed. This error is returned when the caller tries to use a COPP specific command while the video output has OPM semantics only.
 	ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS 0xc026_251c
+	/// The IOPMVideoOutput::GetInformation and IOPMVideoOutput::COPPCompatibleGetInformation methods return this error if the passed in sequence number is not the expected sequence number or the passed in OMAC value is invalid.
 	ERROR_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST 0xc026_251d
+	/// The method failed because an unexpected error occurred inside of a display driver.
 	ERROR_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR 0xc026_251e
+	/// Either the IOPMVideoOutput::COPPCompatibleGetInformation, IOPMVideoOutput::GetInformation, or IOPMVideoOutput::Configure method failed. This error is returned when the caller tries to use an OPM specific command while the video output has COPP semantics only.
 	ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS 0xc026_251f
+	/// The IOPMVideoOutput::COPPCompatibleGetInformation or IOPMVideoOutput::Configure method failed because the display driver does not support the OPM_GET_ACP_AND_CGMSA_SIGNALING and OPM_SET_ACP_AND_CGMSA_SIGNALING GUIDs.
 	ERROR_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED 0xc026_2520
+	/// The IOPMVideoOutput::Configure function returns this error code if the passed in sequence number is not the expected sequence number or the passed in OMAC value is invalid.
 	ERROR_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST 0xc026_2521
+	/// The monitor connected to the specified video output does not have an I2C bus.
 	ERROR_GRAPHICS_I2C_NOT_SUPPORTED 0xc026_2580
+	/// No device on the I2C bus has the specified address.
 	ERROR_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST 0xc026_2581
+	/// An error occurred while transmitting data to the device on the I2C bus.
 	ERROR_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA 0xc026_2582
+	/// An error occurred while receiving data from the device on the I2C bus.
 	ERROR_GRAPHICS_I2C_ERROR_RECEIVING_DATA 0xc026_2583
+	/// The monitor does not support the specified VCP code.
 	ERROR_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED 0xc026_2584
+	/// The data received from the monitor is invalid.
 	ERROR_GRAPHICS_DDCCI_INVALID_DATA 0xc026_2585
+	/// The function failed because a monitor returned an invalid Timing Status byte when the operating system used the DDC/CI Get Timing Report and Timing Message command to get a timing report from a monitor.
 	ERROR_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE 0xc026_2586
+	/// The monitor returned a DDC/CI capabilities string that did not comply with the ACCESS.bus 3.0, DDC/CI 1.1 or MCCS 2 Revision 1 specification.
 	ERROR_GRAPHICS_MCA_INVALID_CAPABILITIES_STRING 0xc026_2587
+	/// An internal Monitor Configuration API error occurred.
 	ERROR_GRAPHICS_MCA_INTERNAL_ERROR 0xc026_2588
+	/// An operation failed because a DDC/CI message had an invalid value in its command field.
 	ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND 0xc026_2589
+	/// An error occurred because the field length of a DDC/CI message contained an invalid value.
 	ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH 0xc026_258a
+	/// An error occurred because the checksum field in a DDC/CI message did not match the message's computed checksum value. This error implies that the data was corrupted while it was being transmitted from a monitor to a computer.
 	ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM 0xc026_258b
+	/// This function failed because an invalid monitor handle was passed to it.
 	ERROR_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE 0xc026_258c
+	/// The operating system asynchronously destroyed the monitor which corresponds to this handle because the operating system's state changed. This error typically occurs because the monitor PDO associated with this handle was removed, the monitor PDO associated with this handle was stopped, or a display mode change occurred.
 	ERROR_GRAPHICS_MONITOR_NO_LONGER_EXISTS 0xc026_258d
+	/// A continuous VCP code's current value is greater than its maximum value. This error code indicates that a monitor returned an invalid value.
 	ERROR_GRAPHICS_DDCCI_CURRENT_CURRENT_VALUE_GREATER_THAN_MAXIMUM_VALUE 0xc026_25d8
+	/// The monitor's VCP Version (0xDF) VCP code returned an invalid version value.
 	ERROR_GRAPHICS_MCA_INVALID_VCP_VERSION 0xc026_25d9
+	/// The monitor does not comply with the MCCS specification it claims to support.
 	ERROR_GRAPHICS_MCA_MONITOR_VIOLATES_MCCS_SPECIFICATION 0xc026_25da
+	/// The MCCS version in a monitor's mccs_ver capability does not match the MCCS version the monitor reports when the VCP Version (0xDF) VCP code is used.
 	ERROR_GRAPHICS_MCA_MCCS_VERSION_MISMATCH 0xc026_25db
+	/// The Monitor Configuration API only works with monitors that support the MCCS 1.0 specification, MCCS 2.0 specification or the MCCS 2.0 Revision 1 specification.
 	ERROR_GRAPHICS_MCA_UNSUPPORTED_MCCS_VERSION 0xc026_25dc
+	/// The monitor returned an invalid monitor technology type. CRT, Plasma and LCD (TFT) are examples of monitor technology types. This error implies that the monitor violated the MCCS 2.0 or MCCS 2.0 Revision 1 specification.
 	ERROR_GRAPHICS_MCA_INVALID_TECHNOLOGY_TYPE_RETURNED 0xc026_25de
+	/// SetMonitorColorTemperature()'s caller passed a color temperature to it that the current monitor did not support. This error implies that the monitor violated the MCCS 2.0 or MCCS 2.0 Revision 1 specification.
 	ERROR_GRAPHICS_MCA_UNSUPPORTED_COLOR_TEMPERATURE 0xc026_25df
+	/// This function can only be used if a program is running in the local console session. It cannot be used if the program is running on a remote desktop session or on a terminal server session.
 	ERROR_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED 0xc026_25e0
+	/// This function cannot find an actual GDI display device which corresponds to the specified GDI display device name.
 	ERROR_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME 0xc026_25e1
+	/// The function failed because the specified GDI display device was not attached to the Windows desktop.
 	ERROR_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP 0xc026_25e2
+	/// This function does not support GDI mirroring display devices because GDI mirroring display devices do not have any physical monitors associated with them.
 	ERROR_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED 0xc026_25e3
+	/// The function failed because an invalid pointer parameter was passed to it. A pointer parameter is invalid if it is NULL, points to an invalid address, points to a kernel mode address, or is not correctly aligned.
 	ERROR_GRAPHICS_INVALID_POINTER 0xc026_25e4
+	/// The function failed because the specified GDI display device did not have any monitors associated with it.
 	ERROR_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE 0xc026_25e5
+	/// An array passed to the function cannot hold all of the data that the function must copy into the array.
 	ERROR_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL 0xc026_25e6
+	/// An internal error caused an operation to fail.
 	ERROR_GRAPHICS_INTERNAL_ERROR 0xc026_25e7
+	/// The function failed because the current session is changing its type. This function cannot be called when the current session is changing its type. There are currently three types of sessions: console, disconnected and remote.
 	ERROR_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS 0xc026_05e8
+	/// The NAP SoH packet is invalid.
 	NAP_E_INVALID_PACKET 0x8027_0001
+	/// An SoH was missing from the NAP packet.
 	NAP_E_MISSING_SOH 0x8027_0002
+	/// The entity ID conflicts with an already registered id.
 	NAP_E_CONFLICTING_ID 0x8027_0003
+	/// No cached SoH is present.
 	NAP_E_NO_CACHED_SOH 0x8027_0004
+	/// The entity is still bound to the NAP system.
 	NAP_E_STILL_BOUND 0x8027_0005
+	/// The entity is not registered with the NAP system.
 	NAP_E_NOT_REGISTERED 0x8027_0006
+	/// The entity is not initialized with the NAP system.
 	NAP_E_NOT_INITIALIZED 0x8027_0007
+	/// The correlation id in the SoH-Request and SoH-Response do not match up.
 	NAP_E_MISMATCHED_ID 0x8027_0008
+	/// Completion was indicated on a request that is not currently pending.
 	NAP_E_NOT_PENDING 0x8027_0009
+	/// The NAP component's id was not found.
 	NAP_E_ID_NOT_FOUND 0x8027_000a
+	/// The maximum size of the connection is too small for an SoH packet.
 	NAP_E_MAXSIZE_TOO_SMALL 0x8027_000b
+	/// The NapAgent service is not running.
 	NAP_E_SERVICE_NOT_RUNNING 0x8027_000c
+	/// A certificate is already present in the cert store.
 	NAP_S_CERT_ALREADY_PRESENT 0x0027_000d
+	/// The entity is disabled with the NapAgent service.
 	NAP_E_ENTITY_DISABLED 0x8027_000e
+	/// Group Policy is not configured.
 	NAP_E_NETSH_GROUPPOLICY_ERROR 0x8027_000f
+	/// Too many simultaneous calls.
 	NAP_E_TOO_MANY_CALLS 0x8027_0010
+	/// SHV configuration already existed.
 	NAP_E_SHV_CONFIG_EXISTED 0x8027_0011
+	/// SHV configuration is not found.
 	NAP_E_SHV_CONFIG_NOT_FOUND 0x8027_0012
+	/// SHV timed out on the request.
 	NAP_E_SHV_TIMEOUT 0x8027_0013
+	/// This is an error mask to convert TPM hardware errors to win errors.
 	TPM_E_ERROR_MASK 0x8028_0000
+	/// Authentication failed.
 	TPM_E_AUTHFAIL 0x8028_0001
+	/// The index to a PCR, DIR or other register is incorrect.
 	TPM_E_BADINDEX 0x8028_0002
+	/// One or more parameter is bad.
 	TPM_E_BAD_PARAMETER 0x8028_0003
+	/// An operation completed successfully but the auditing of that operation failed.
 	TPM_E_AUDITFAILURE 0x8028_0004
+	/// The clear disable flag is set and all clear operations now require physical access.
 	TPM_E_CLEAR_DISABLED 0x8028_0005
+	/// Activate the Trusted Platform Module (TPM).
 	TPM_E_DEACTIVATED 0x8028_0006
+	/// Enable the Trusted Platform Module (TPM).
 	TPM_E_DISABLED 0x8028_0007
+	/// The target command has been disabled.
 	TPM_E_DISABLED_CMD 0x8028_0008
+	/// The operation failed.
 	TPM_E_FAIL 0x8028_0009
+	/// The ordinal was unknown or inconsistent.
 	TPM_E_BAD_ORDINAL 0x8028_000a
+	/// The ability to install an owner is disabled.
 	TPM_E_INSTALL_DISABLED 0x8028_000b
+	/// The key handle cannot be interpreted.
 	TPM_E_INVALID_KEYHANDLE 0x8028_000c
+	/// The key handle points to an invalid key.
 	TPM_E_KEYNOTFOUND 0x8028_000d
+	/// Unacceptable encryption scheme.
 	TPM_E_INAPPROPRIATE_ENC 0x8028_000e
+	/// Migration authorization failed.
 	TPM_E_MIGRATEFAIL 0x8028_000f
+	/// PCR information could not be interpreted.
 	TPM_E_INVALID_PCR_INFO 0x8028_0010
+	/// No room to load key.
 	TPM_E_NOSPACE 0x8028_0011
+	/// There is no Storage Root Key (SRK) set.
 	TPM_E_NOSRK 0x8028_0012
+	/// An encrypted blob is invalid or was not created by this TPM.
 	TPM_E_NOTSEALED_BLOB 0x8028_0013
+	/// The Trusted Platform Module (TPM) already has an owner.
 	TPM_E_OWNER_SET 0x8028_0014
+	/// The TPM has insufficient internal resources to perform the requested action.
 	TPM_E_RESOURCES 0x8028_0015
+	/// A random string was too short.
 	TPM_E_SHORTRANDOM 0x8028_0016
+	/// The TPM does not have the space to perform the operation.
 	TPM_E_SIZE 0x8028_0017
+	/// The named PCR value does not match the current PCR value.
 	TPM_E_WRONGPCRVAL 0x8028_0018
+	/// The paramSize argument to the command has the incorrect value.
 	TPM_E_BAD_PARAM_SIZE 0x8028_0019
+	/// There is no existing SHA-1 thread.
 	TPM_E_SHA_THREAD 0x8028_001a
+	/// The calculation is unable to proceed because the existing SHA-1 thread has already encountered an error.
 	TPM_E_SHA_ERROR 0x8028_001b
+	/// The TPM hardware device reported a failure during its internal self test. Try restarting the computer to resolve the problem. If the problem continues, check for the latest BIOS or firmware update for your TPM hardware. Consult the computer manufacturer's documentation for instructions.
 	TPM_E_FAILEDSELFTEST 0x8028_001c
+	/// The authorization for the second key in a 2 key function failed authorization.
 	TPM_E_AUTH2FAIL 0x8028_001d
+	/// The tag value sent to for a command is invalid.
 	TPM_E_BADTAG 0x8028_001e
+	/// An IO error occurred transmitting information to the TPM.
 	TPM_E_IOERROR 0x8028_001f
+	/// The encryption process had a problem.
 	TPM_E_ENCRYPT_ERROR 0x8028_0020
+	/// The decryption process did not complete.
 	TPM_E_DECRYPT_ERROR 0x8028_0021
+	/// An invalid handle was used.
 	TPM_E_INVALID_AUTHHANDLE 0x8028_0022
+	/// The TPM does not have an Endorsement Key (EK) installed.
 	TPM_E_NO_ENDORSEMENT 0x8028_0023
+	/// The usage of a key is not allowed.
 	TPM_E_INVALID_KEYUSAGE 0x8028_0024
+	/// The submitted entity type is not allowed.
 	TPM_E_WRONG_ENTITYTYPE 0x8028_0025
+	/// The command was received in the wrong sequence relative to TPM_Init and a subsequent TPM_Startup.
 	TPM_E_INVALID_POSTINIT 0x8028_0026
+	/// Signed data cannot include additional DER information.
 	TPM_E_INAPPROPRIATE_SIG 0x8028_0027
+	/// The key properties in TPM_KEY_PARMs are not supported by this TPM.
 	TPM_E_BAD_KEY_PROPERTY 0x8028_0028
+	/// The migration properties of this key are incorrect.
 	TPM_E_BAD_MIGRATION 0x8028_0029
+	/// The signature or encryption scheme for this key is incorrect or not permitted in this situation.
 	TPM_E_BAD_SCHEME 0x8028_002a
+	/// The size of the data (or blob) parameter is bad or inconsistent with the referenced key.
 	TPM_E_BAD_DATASIZE 0x8028_002b
+	/// A mode parameter is bad, such as capArea or subCapArea for TPM_GetCapability, physicalPresence parameter for TPM_PhysicalPresence, or migrationType for TPM_CreateMigrationBlob.
 	TPM_E_BAD_MODE 0x8028_002c
+	/// Either the physicalPresence or physicalPresenceLock bits have the wrong value.
 	TPM_E_BAD_PRESENCE 0x8028_002d
+	/// The TPM cannot perform this version of the capability.
 	TPM_E_BAD_VERSION 0x8028_002e
+	/// The TPM does not allow for wrapped transport sessions.
 	TPM_E_NO_WRAP_TRANSPORT 0x8028_002f
+	/// TPM audit construction failed and the underlying command was returning a failure code also.
 	TPM_E_AUDITFAIL_UNSUCCESSFUL 0x8028_0030
+	/// TPM audit construction failed and the underlying command was returning success.
 	TPM_E_AUDITFAIL_SUCCESSFUL 0x8028_0031
+	/// Attempt to reset a PCR register that does not have the resettable attribute.
 	TPM_E_NOTRESETABLE 0x8028_0032
+	/// Attempt to reset a PCR register that requires locality and locality modifier not part of command transport.
 	TPM_E_NOTLOCAL 0x8028_0033
+	/// Make identity blob not properly typed.
 	TPM_E_BAD_TYPE 0x8028_0034
+	/// When saving context identified resource type does not match actual resource.
 	TPM_E_INVALID_RESOURCE 0x8028_0035
+	/// The TPM is attempting to execute a command only available when in FIPS mode.
 	TPM_E_NOTFIPS 0x8028_0036
+	/// The command is attempting to use an invalid family ID.
 	TPM_E_INVALID_FAMILY 0x8028_0037
+	/// The permission to manipulate the NV storage is not available.
 	TPM_E_NO_NV_PERMISSION 0x8028_0038
+	/// The operation requires a signed command.
 	TPM_E_REQUIRES_SIGN 0x8028_0039
+	/// Wrong operation to load an NV key.
 	TPM_E_KEY_NOTSUPPORTED 0x8028_003a
+	/// NV_LoadKey blob requires both owner and blob authorization.
 	TPM_E_AUTH_CONFLICT 0x8028_003b
+	/// The NV area is locked and not writable.
 	TPM_E_AREA_LOCKED 0x8028_003c
+	/// The locality is incorrect for the attempted operation.
 	TPM_E_BAD_LOCALITY 0x8028_003d
+	/// The NV area is read only and can't be written to.
 	TPM_E_READ_ONLY 0x8028_003e
+	/// There is no protection on the write to the NV area.
 	TPM_E_PER_NOWRITE 0x8028_003f
+	/// The family count value does not match.
 	TPM_E_FAMILYCOUNT 0x8028_0040
+	/// The NV area has already been written to.
 	TPM_E_WRITE_LOCKED 0x8028_0041
+	/// The NV area attributes conflict.
 	TPM_E_BAD_ATTRIBUTES 0x8028_0042
+	/// The structure tag and version are invalid or inconsistent.
 	TPM_E_INVALID_STRUCTURE 0x8028_0043
+	/// The key is under control of the TPM Owner and can only be evicted by the TPM Owner.
 	TPM_E_KEY_OWNER_CONTROL 0x8028_0044
+	/// The counter handle is incorrect.
 	TPM_E_BAD_COUNTER 0x8028_0045
+	/// The write is not a complete write of the area.
 	TPM_E_NOT_FULLWRITE 0x8028_0046
+	/// The gap between saved context counts is too large.
 	TPM_E_CONTEXT_GAP 0x8028_0047
+	/// The maximum number of NV writes without an owner has been exceeded.
 	TPM_E_MAXNVWRITES 0x8028_0048
+	/// No operator AuthData value is set.
 	TPM_E_NOOPERATOR 0x8028_0049
+	/// The resource pointed to by context is not loaded.
 	TPM_E_RESOURCEMISSING 0x8028_004a
+	/// The delegate administration is locked.
 	TPM_E_DELEGATE_LOCK 0x8028_004b
+	/// Attempt to manage a family other than the delegated family.
 	TPM_E_DELEGATE_FAMILY 0x8028_004c
+	/// Delegation table management not enabled.
 	TPM_E_DELEGATE_ADMIN 0x8028_004d
+	/// There was a command executed outside of an exclusive transport session.
 	TPM_E_TRANSPORT_NOTEXCLUSIVE 0x8028_004e
+	/// Attempt to context save an owner evict controlled key.
 	TPM_E_OWNER_CONTROL 0x8028_004f
+	/// The DAA command has no resources available to execute the command.
 	TPM_E_DAA_RESOURCES 0x8028_0050
+	/// The consistency check on DAA parameter inputData0 has failed.
 	TPM_E_DAA_INPUT_DATA0 0x8028_0051
+	/// The consistency check on DAA parameter inputData1 has failed.
 	TPM_E_DAA_INPUT_DATA1 0x8028_0052
+	/// The consistency check on DAA_issuerSettings has failed.
 	TPM_E_DAA_ISSUER_SETTINGS 0x8028_0053
+	/// The consistency check on DAA_tpmSpecific has failed.
 	TPM_E_DAA_TPM_SETTINGS 0x8028_0054
+	/// The atomic process indicated by the submitted DAA command is not the expected process.
 	TPM_E_DAA_STAGE 0x8028_0055
+	/// The issuer's validity check has detected an inconsistency.
 	TPM_E_DAA_ISSUER_VALIDITY 0x8028_0056
+	/// The consistency check on w has failed.
 	TPM_E_DAA_WRONG_W 0x8028_0057
+	/// The handle is incorrect.
 	TPM_E_BAD_HANDLE 0x8028_0058
+	/// Delegation is not correct.
 	TPM_E_BAD_DELEGATE 0x8028_0059
+	/// The context blob is invalid.
 	TPM_E_BADCONTEXT 0x8028_005a
+	/// Too many contexts held by the TPM.
 	TPM_E_TOOMANYCONTEXTS 0x8028_005b
+	/// Migration authority signature validation failure.
 	TPM_E_MA_TICKET_SIGNATURE 0x8028_005c
+	/// Migration destination not authenticated.
 	TPM_E_MA_DESTINATION 0x8028_005d
+	/// Migration source incorrect.
 	TPM_E_MA_SOURCE 0x8028_005e
+	/// Incorrect migration authority.
 	TPM_E_MA_AUTHORITY 0x8028_005f
+	/// Attempt to revoke the EK and the EK is not revocable.
 	TPM_E_PERMANENTEK 0x8028_0061
+	/// Bad signature of CMK ticket.
 	TPM_E_BAD_SIGNATURE 0x8028_0062
+	/// There is no room in the context list for additional contexts.
 	TPM_E_NOCONTEXTSPACE 0x8028_0063
+	/// TPM 2.0: Asymmetric algorithm not supported or not correct.
 	TPM_20_E_ASYMMETRIC 0x8028_0081
+	/// TPM 2.0: Inconsistent attributes.
 	TPM_20_E_ATTRIBUTES 0x8028_0082
+	/// TPM 2.0: Hash algorithm not supported or not appropriate.
 	TPM_20_E_HASH 0x8028_0083
+	/// TPM 2.0: Value is out of range or is not correct for the context.
 	TPM_20_E_VALUE 0x8028_0084
+	/// TPM 2.0: Hierarchy is not enabled or is not correct for the use.
 	TPM_20_E_HIERARCHY 0x8028_0085
+	/// TPM 2.0: Key size is not supported.
 	TPM_20_E_KEY_SIZE 0x8028_0087
+	/// TPM 2.0: Mask generation function not supported.
 	TPM_20_E_MGF 0x8028_0088
+	/// TPM 2.0: Mode of operation not supported.
 	TPM_20_E_MODE 0x8028_0089
+	/// TPM 2.0: The type of the value is not appropriate for the use.
 	TPM_20_E_TYPE 0x8028_008a
+	/// TPM 2.0: The Handle is not correct for the use.
 	TPM_20_E_HANDLE 0x8028_008b
+	/// TPM 2.0: Unsupported key derivation function or function not appropriate for use.
 	TPM_20_E_KDF 0x8028_008c
+	/// TPM 2.0: Value was out of allowed range.
 	TPM_20_E_RANGE 0x8028_008d
+	/// TPM 2.0: The authorization HMAC check failed and DA counter incremented.
 	TPM_20_E_AUTH_FAIL 0x8028_008e
+	/// TPM 2.0: Invalid nonce size.
 	TPM_20_E_NONCE 0x8028_008f
+	/// TPM 2.0: Authorization requires assertion of PP.
 	TPM_20_E_PP 0x8028_0090
+	/// TPM 2.0: Unsupported or incompatible scheme.
 	TPM_20_E_SCHEME 0x8028_0092
+	/// TPM 2.0: Structure is wrong size.
 	TPM_20_E_SIZE 0x8028_0095
+	/// TPM 2.0: Unsupported symmetric algorithm or key size, or not appropriate for instance.
 	TPM_20_E_SYMMETRIC 0x8028_0096
+	/// TPM 2.0: Incorrect structure tag.
 	TPM_20_E_TAG 0x8028_0097
+	/// TPM 2.0: Union selector is incorrect.
 	TPM_20_E_SELECTOR 0x8028_0098
+	/// TPM 2.0: The TPM was unable to unmarshal a value because there were not enough octets in the input buffer.
 	TPM_20_E_INSUFFICIENT 0x8028_009a
+	/// TPM 2.0: The signature is not valid.
 	TPM_20_E_SIGNATURE 0x8028_009b
+	/// TPM 2.0: Key fields are not compatible with the selected use.
 	TPM_20_E_KEY 0x8028_009c
+	/// TPM 2.0: A policy check failed.
 	TPM_20_E_POLICY_FAIL 0x8028_009d
+	/// TPM 2.0: Integrity check failed.
 	TPM_20_E_INTEGRITY 0x8028_009f
+	/// TPM 2.0: Invalid ticket.
 	TPM_20_E_TICKET 0x8028_00a0
+	/// TPM 2.0: Reserved bits not set to zero as required.
 	TPM_20_E_RESERVED_BITS 0x8028_00a1
+	/// TPM 2.0: Authorization failure without DA implications.
 	TPM_20_E_BAD_AUTH 0x8028_00a2
+	/// TPM 2.0: The policy has expired.
 	TPM_20_E_EXPIRED 0x8028_00a3
+	/// TPM 2.0: The command code in the policy is not the command code of the command or the command code in a policy command references a command that is not implemented.
 	TPM_20_E_POLICY_CC 0x8028_00a4
+	/// TPM 2.0: Public and sensitive portions of an object are not cryptographically bound.
 	TPM_20_E_BINDING 0x8028_00a5
+	/// TPM 2.0: Curve not supported.
 	TPM_20_E_CURVE 0x8028_00a6
+	/// TPM 2.0: Point is not on the required curve.
 	TPM_20_E_ECC_POINT 0x8028_00a7
+	/// TPM 2.0: TPM not initialized.
 	TPM_20_E_INITIALIZE 0x8028_0100
+	/// TPM 2.0: Commands not being accepted because of a TPM failure.
 	TPM_20_E_FAILURE 0x8028_0101
+	/// TPM 2.0: Improper use of a sequence handle.
 	TPM_20_E_SEQUENCE 0x8028_0103
+	/// TPM 2.0: TPM_RC_PRIVATE error.
 	TPM_20_E_PRIVATE 0x8028_010b
+	/// TPM 2.0: TPM_RC_HMAC.
 	TPM_20_E_HMAC 0x8028_0119
+	/// TPM 2.0: TPM_RC_DISABLED.
 	TPM_20_E_DISABLED 0x8028_0120
+	/// TPM 2.0: Command failed because audit sequence required exclusivity.
 	TPM_20_E_EXCLUSIVE 0x8028_0121
+	/// TPM 2.0: Unsupported ECC curve.
 	TPM_20_E_ECC_CURVE 0x8028_0123
+	/// TPM 2.0: Authorization handle is not correct for command.
 	TPM_20_E_AUTH_TYPE 0x8028_0124
+	/// TPM 2.0: Command requires an authorization session for handle and is not present.
 	TPM_20_E_AUTH_MISSING 0x8028_0125
+	/// TPM 2.0: Policy failure in Math Operation or an invalid authPolicy value.
 	TPM_20_E_POLICY 0x8028_0126
+	/// TPM 2.0: PCR check fail.
 	TPM_20_E_PCR 0x8028_0127
+	/// TPM 2.0: PCR have changed since checked.
 	TPM_20_E_PCR_CHANGED 0x8028_0128
+	/// TPM 2.0: The TPM is not in the right mode for upgrade.
 	TPM_20_E_UPGRADE 0x8028_012d
+	/// TPM 2.0: Context ID counter is at maximum.
 	TPM_20_E_TOO_MANY_CONTEXTS 0x8028_012e
+	/// TPM 2.0: authValue or authPolicy is not available for selected entity.
 	TPM_20_E_AUTH_UNAVAILABLE 0x8028_012f
+	/// TPM 2.0: A _TPM_Init and Startup(CLEAR) is required before the TPM can resume operation.
 	TPM_20_E_REBOOT 0x8028_0130
+	/// TPM 2.0: The protection algorithms (hash and symmetric) are not reasonably balanced. The digest size of the hash must be larger than the key size of the symmetric algorithm.
 	TPM_20_E_UNBALANCED 0x8028_0131
+	/// TPM 2.0: The TPM command's commandSize value is inconsistent with contents of the command buffer; either the size is not the same as the bytes loaded by the hardware interface layer or the value is not large enough to hold a command header.
 	TPM_20_E_COMMAND_SIZE 0x8028_0142
+	/// TPM 2.0: Command code not supported.
 	TPM_20_E_COMMAND_CODE 0x8028_0143
+	/// TPM 2.0: The value of authorizationSize is out of range or the number of octets in the authorization Area is greater than required.
 	TPM_20_E_AUTHSIZE 0x8028_0144
+	/// TPM 2.0: Use of an authorization session with a context command or another command that cannot have an authorization session.
 	TPM_20_E_AUTH_CONTEXT 0x8028_0145
+	/// TPM 2.0: NV offset+size is out of range.
 	TPM_20_E_NV_RANGE 0x8028_0146
+	/// TPM 2.0: Requested allocation size is larger than allowed.
 	TPM_20_E_NV_SIZE 0x8028_0147
+	/// TPM 2.0: NV access locked.
 	TPM_20_E_NV_LOCKED 0x8028_0148
+	/// TPM 2.0: NV access authorization fails in command actions.
 	TPM_20_E_NV_AUTHORIZATION 0x8028_0149
+	/// TPM 2.0: An NV index is used before being initialized or the state saved by TPM2_Shutdown(STATE) could not be restored.
 	TPM_20_E_NV_UNINITIALIZED 0x8028_014a
+	/// TPM 2.0: Insufficient space for NV allocation.
 	TPM_20_E_NV_SPACE 0x8028_014b
+	/// TPM 2.0: NV index or persistent object already defined.
 	TPM_20_E_NV_DEFINED 0x8028_014c
+	/// TPM 2.0: Context in TPM2_ContextLoad() is not valid.
 	TPM_20_E_BAD_CONTEXT 0x8028_0150
+	/// TPM 2.0: chHash value already set or not correct for use.
 	TPM_20_E_CPHASH 0x8028_0151
+	/// TPM 2.0: Handle for parent is not a valid parent.
 	TPM_20_E_PARENT 0x8028_0152
+	/// TPM 2.0: Some function needs testing.
 	TPM_20_E_NEEDS_TEST 0x8028_0153
+	/// TPM 2.0: Cannot process a request due to an unspecified problem. This code is usually related to invalid parameters that are not properly filtered by the input unmarshaling code.
 	TPM_20_E_NO_RESULT 0x8028_0154
+	/// TPM 2.0: The sensitive area did not unmarshal correctly after decryption.
 	TPM_20_E_SENSITIVE 0x8028_0155
+	/// The command was blocked.
 	TPM_E_COMMAND_BLOCKED 0x8028_0400
+	/// The specified handle was not found.
 	TPM_E_INVALID_HANDLE 0x8028_0401
+	/// The TPM returned a duplicate handle and the command needs to be resubmitted.
 	TPM_E_DUPLICATE_VHANDLE 0x8028_0402
+	/// The command within the transport was blocked.
 	TPM_E_EMBEDDED_COMMAND_BLOCKED 0x8028_0403
+	/// The command within the transport is not supported.
 	TPM_E_EMBEDDED_COMMAND_UNSUPPORTED 0x8028_0404
+	/// The TPM is too busy to respond to the command immediately, but the command could be resubmitted at a later time.
 	TPM_E_RETRY 0x8028_0800
+	/// SelfTestFull has not been run.
 	TPM_E_NEEDS_SELFTEST 0x8028_0801
+	/// The TPM is currently executing a full selftest.
 	TPM_E_DOING_SELFTEST 0x8028_0802
+	/// The TPM is defending against dictionary attacks and is in a time-out period.
 	TPM_E_DEFEND_LOCK_RUNNING 0x8028_0803
+	/// TPM 2.0: Gap for context ID is too large.
 	TPM_20_E_CONTEXT_GAP 0x8028_0901
+	/// TPM 2.0: Out of memory for object contexts.
 	TPM_20_E_OBJECT_MEMORY 0x8028_0902
+	/// TPM 2.0: Out of memory for session contexts.
 	TPM_20_E_SESSION_MEMORY 0x8028_0903
+	/// TPM 2.0: Out of shared object/session memory or need space for internal operations.
 	TPM_20_E_MEMORY 0x8028_0904
+	/// TPM 2.0: Out of session handles - a session must be flushed before a new session may be created.
 	TPM_20_E_SESSION_HANDLES 0x8028_0905
+	/// TPM 2.0: Out of object handles - the handle space for objects is depleted and a reboot is required.
 	TPM_20_E_OBJECT_HANDLES 0x8028_0906
+	/// TPM 2.0: Bad locality.
 	TPM_20_E_LOCALITY 0x8028_0907
+	/// TPM 2.0: The TPM has suspended operation on the command; forward progress was made and the command may be retried.
 	TPM_20_E_YIELDED 0x8028_0908
+	/// TPM 2.0: The command was canceled.
 	TPM_20_E_CANCELED 0x8028_0909
+	/// TPM 2.0: TPM is performing self-tests.
 	TPM_20_E_TESTING 0x8028_090a
+	/// TPM 2.0: The TPM is rate-limiting accesses to prevent wearout of NV.
 	TPM_20_E_NV_RATE 0x8028_0920
+	/// TPM 2.0: Authorization for objects subject to DA protection are not allowed at this time because the TPM is in DA lockout mode.
 	TPM_20_E_LOCKOUT 0x8028_0921
+	/// TPM 2.0: The TPM was not able to start the command.
 	TPM_20_E_RETRY 0x8028_0922
+	/// TPM 2.0: The command may require writing of NV and NV is not current accessible.
 	TPM_20_E_NV_UNAVAILABLE 0x8028_0923
+	/// An internal error has occurred within the Trusted Platform Module support program.
 	TBS_E_INTERNAL_ERROR 0x8028_4001
+	/// One or more input parameters is bad.
 	TBS_E_BAD_PARAMETER 0x8028_4002
+	/// A specified output pointer is bad.
 	TBS_E_INVALID_OUTPUT_POINTER 0x8028_4003
+	/// The specified context handle does not refer to a valid context.
 	TBS_E_INVALID_CONTEXT 0x8028_4004
+	/// A specified output buffer is too small.
 	TBS_E_INSUFFICIENT_BUFFER 0x8028_4005
+	/// An error occurred while communicating with the TPM.
 	TBS_E_IOERROR 0x8028_4006
+	/// One or more context parameters is invalid.
 	TBS_E_INVALID_CONTEXT_PARAM 0x8028_4007
+	/// The TBS service is not running and could not be started.
 	TBS_E_SERVICE_NOT_RUNNING 0x8028_4008
+	/// A new context could not be created because there are too many open contexts.
 	TBS_E_TOO_MANY_TBS_CONTEXTS 0x8028_4009
+	/// A new virtual resource could not be created because there are too many open virtual resources.
 	TBS_E_TOO_MANY_RESOURCES 0x8028_400a
+	/// The TBS service has been started but is not yet running.
 	TBS_E_SERVICE_START_PENDING 0x8028_400b
+	/// The physical presence interface is not supported.
 	TBS_E_PPI_NOT_SUPPORTED 0x8028_400c
+	/// The command was canceled.
 	TBS_E_COMMAND_CANCELED 0x8028_400d
+	/// The input or output buffer is too large.
 	TBS_E_BUFFER_TOO_LARGE 0x8028_400e
+	/// A compatible Trusted Platform Module (TPM) Security Device cannot be found on this computer.
 	TBS_E_TPM_NOT_FOUND 0x8028_400f
+	/// The TBS service has been disabled.
 	TBS_E_SERVICE_DISABLED 0x8028_4010
+	/// No TCG event log is available.
 	TBS_E_NO_EVENT_LOG 0x8028_4011
+	/// The caller does not have the appropriate rights to perform the requested operation.
 	TBS_E_ACCESS_DENIED 0x8028_4012
+	/// The TPM provisioning action is not allowed by the specified flags. For provisioning to be successful, one of several actions may be required. The TPM management console (tpm.msc) action to make the TPM Ready may help. For further information, see the documentation for the Win32_Tpm WMI method 'Provision'. (The actions that may be required include importing the TPM Owner Authorization value into the system, calling the Win32_Tpm WMI method for provisioning the TPM and specifying TRUE for either 'ForceClear_Allowed' or 'PhysicalPresencePrompts_Allowed' (as indicated by the value returned in the Additional Information), or enabling the TPM in the system BIOS.)
 	TBS_E_PROVISIONING_NOT_ALLOWED 0x8028_4013
+	/// The Physical Presence Interface of this firmware does not support the requested method.
 	TBS_E_PPI_FUNCTION_UNSUPPORTED 0x8028_4014
+	/// The requested TPM OwnerAuth value was not found.
 	TBS_E_OWNERAUTH_NOT_FOUND 0x8028_4015
+	/// The TPM provisioning did not complete. For more information on completing the provisioning, call the Win32_Tpm WMI method for provisioning the TPM ('Provision') and check the returned Information.
 	TBS_E_PROVISIONING_INCOMPLETE 0x8028_4016
+	/// The command buffer is not in the correct state.
 	TPMAPI_E_INVALID_STATE 0x8029_0100
+	/// The command buffer does not contain enough data to satisfy the request.
 	TPMAPI_E_NOT_ENOUGH_DATA 0x8029_0101
+	/// The command buffer cannot contain any more data.
 	TPMAPI_E_TOO_MUCH_DATA 0x8029_0102
+	/// One or more output parameters was NULL or invalid.
 	TPMAPI_E_INVALID_OUTPUT_POINTER 0x8029_0103
+	/// One or more input parameters is invalid.
 	TPMAPI_E_INVALID_PARAMETER 0x8029_0104
+	/// Not enough memory was available to satisfy the request.
 	TPMAPI_E_OUT_OF_MEMORY 0x8029_0105
+	/// The specified buffer was too small.
 	TPMAPI_E_BUFFER_TOO_SMALL 0x8029_0106
+	/// An internal error was detected.
 	TPMAPI_E_INTERNAL_ERROR 0x8029_0107
+	/// The caller does not have the appropriate rights to perform the requested operation.
 	TPMAPI_E_ACCESS_DENIED 0x8029_0108
+	/// The specified authorization information was invalid.
 	TPMAPI_E_AUTHORIZATION_FAILED 0x8029_0109
+	/// The specified context handle was not valid.
 	TPMAPI_E_INVALID_CONTEXT_HANDLE 0x8029_010a
+	/// An error occurred while communicating with the TBS.
 	TPMAPI_E_TBS_COMMUNICATION_ERROR 0x8029_010b
+	/// The TPM returned an unexpected result.
 	TPMAPI_E_TPM_COMMAND_ERROR 0x8029_010c
+	/// The message was too large for the encoding scheme.
 	TPMAPI_E_MESSAGE_TOO_LARGE 0x8029_010d
+	/// The encoding in the blob was not recognized.
 	TPMAPI_E_INVALID_ENCODING 0x8029_010e
+	/// The key size is not valid.
 	TPMAPI_E_INVALID_KEY_SIZE 0x8029_010f
+	/// The encryption operation failed.
 	TPMAPI_E_ENCRYPTION_FAILED 0x8029_0110
+	/// The key parameters structure was not valid.
 	TPMAPI_E_INVALID_KEY_PARAMS 0x8029_0111
+	/// The requested supplied data does not appear to be a valid migration authorization blob.
 	TPMAPI_E_INVALID_MIGRATION_AUTHORIZATION_BLOB 0x8029_0112
+	/// The specified PCR index was invalid.
 	TPMAPI_E_INVALID_PCR_INDEX 0x8029_0113
+	/// The data given does not appear to be a valid delegate blob.
 	TPMAPI_E_INVALID_DELEGATE_BLOB 0x8029_0114
+	/// One or more of the specified context parameters was not valid.
 	TPMAPI_E_INVALID_CONTEXT_PARAMS 0x8029_0115
+	/// The data given does not appear to be a valid key blob.
 	TPMAPI_E_INVALID_KEY_BLOB 0x8029_0116
+	/// The specified PCR data was invalid.
 	TPMAPI_E_INVALID_PCR_DATA 0x8029_0117
+	/// The format of the owner auth data was invalid.
 	TPMAPI_E_INVALID_OWNER_AUTH 0x8029_0118
+	/// The random number generated did not pass FIPS RNG check.
 	TPMAPI_E_FIPS_RNG_CHECK_FAILED 0x8029_0119
+	/// The TCG Event Log does not contain any data.
 	TPMAPI_E_EMPTY_TCG_LOG 0x8029_011a
+	/// An entry in the TCG Event Log was invalid.
 	TPMAPI_E_INVALID_TCG_LOG_ENTRY 0x8029_011b
+	/// A TCG Separator was not found.
 	TPMAPI_E_TCG_SEPARATOR_ABSENT 0x8029_011c
+	/// A digest value in a TCG Log entry did not match hashed data.
 	TPMAPI_E_TCG_INVALID_DIGEST_ENTRY 0x8029_011d
+	/// The requested operation was blocked by current TPM policy. Please contact your system administrator for assistance.
 	TPMAPI_E_POLICY_DENIES_OPERATION 0x8029_011e
+	/// The Windows TPM NV Bits are not defined.
 	TPMAPI_E_NV_BITS_NOT_DEFINED 0x8029_011f
+	/// The Windows TPM NV Bits are not ready.
 	TPMAPI_E_NV_BITS_NOT_READY 0x8029_0120
+	/// The sealing key is not available.
 	TPMAPI_E_SEALING_KEY_NOT_AVAILABLE 0x8029_0121
+	/// The authorization chain could not be found.
 	TPMAPI_E_NO_AUTHORIZATION_CHAIN_FOUND 0x8029_0122
+	/// The SVN counter is not available.
 	TPMAPI_E_SVN_COUNTER_NOT_AVAILABLE 0x8029_0123
+	/// The TPM Owner Auth is not NULL.
 	TPMAPI_E_OWNER_AUTH_NOT_NULL 0x8029_0124
+	/// The TPM Endorsement Auth is not NULL.
 	TPMAPI_E_ENDORSEMENT_AUTH_NOT_NULL 0x8029_0125
+	/// The authorization was revoked.
 	TPMAPI_E_AUTHORIZATION_REVOKED 0x8029_0126
+	/// The authorization key is malformed.
 	TPMAPI_E_MALFORMED_AUTHORIZATION_KEY 0x8029_0127
+	/// The authorizing key is not supported.
 	TPMAPI_E_AUTHORIZING_KEY_NOT_SUPPORTED 0x8029_0128
+	/// The authorization signature is invalid.
 	TPMAPI_E_INVALID_AUTHORIZATION_SIGNATURE 0x8029_0129
+	/// The authorization policy is malformed.
 	TPMAPI_E_MALFORMED_AUTHORIZATION_POLICY 0x8029_012a
+	/// The authorization is malformed.
 	TPMAPI_E_MALFORMED_AUTHORIZATION_OTHER 0x8029_012b
+	/// The sealing key has changed.
 	TPMAPI_E_SEALING_KEY_CHANGED 0x8029_012c
+	/// The specified buffer was too small.
 	TBSIMP_E_BUFFER_TOO_SMALL 0x8029_0200
+	/// The context could not be cleaned up.
 	TBSIMP_E_CLEANUP_FAILED 0x8029_0201
+	/// The specified context handle is invalid.
 	TBSIMP_E_INVALID_CONTEXT_HANDLE 0x8029_0202
+	/// An invalid context parameter was specified.
 	TBSIMP_E_INVALID_CONTEXT_PARAM 0x8029_0203
+	/// An error occurred while communicating with the TPM.
 	TBSIMP_E_TPM_ERROR 0x8029_0204
+	/// No entry with the specified key was found.
 	TBSIMP_E_HASH_BAD_KEY 0x8029_0205
+	/// The specified virtual handle matches a virtual handle already in use.
 	TBSIMP_E_DUPLICATE_VHANDLE 0x8029_0206
+	/// The pointer to the returned handle location was NULL or invalid.
 	TBSIMP_E_INVALID_OUTPUT_POINTER 0x8029_0207
+	/// One or more parameters is invalid.
 	TBSIMP_E_INVALID_PARAMETER 0x8029_0208
+	/// The RPC subsystem could not be initialized.
 	TBSIMP_E_RPC_INIT_FAILED 0x8029_0209
+	/// The TBS scheduler is not running.
 	TBSIMP_E_SCHEDULER_NOT_RUNNING 0x8029_020a
+	/// The command was canceled.
 	TBSIMP_E_COMMAND_CANCELED 0x8029_020b
+	/// There was not enough memory to fulfill the request.
 	TBSIMP_E_OUT_OF_MEMORY 0x8029_020c
+	/// The specified list is empty, or the iteration has reached the end of the list.
 	TBSIMP_E_LIST_NO_MORE_ITEMS 0x8029_020d
+	/// The specified item was not found in the list.
 	TBSIMP_E_LIST_NOT_FOUND 0x8029_020e
+	/// The TPM does not have enough space to load the requested resource.
 	TBSIMP_E_NOT_ENOUGH_SPACE 0x8029_020f
+	/// There are too many TPM contexts in use.
 	TBSIMP_E_NOT_ENOUGH_TPM_CONTEXTS 0x8029_0210
+	/// The TPM command failed.
 	TBSIMP_E_COMMAND_FAILED 0x8029_0211
+	/// The TBS does not recognize the specified ordinal.
 	TBSIMP_E_UNKNOWN_ORDINAL 0x8029_0212
+	/// The requested resource is no longer available.
 	TBSIMP_E_RESOURCE_EXPIRED 0x8029_0213
+	/// The resource type did not match.
 	TBSIMP_E_INVALID_RESOURCE 0x8029_0214
+	/// The resource cannot be unloaded.
 	TBSIMP_E_NOTHING_TO_UNLOAD 0x8029_0215
+	/// No new entries can be added to the hash table.
 	TBSIMP_E_HASH_TABLE_FULL 0x8029_0216
+	/// A new TBS context could not be created because there are too many open contexts.
 	TBSIMP_E_TOO_MANY_TBS_CONTEXTS 0x8029_0217
+	/// A new virtual resource could not be created because there are too many open virtual resources.
 	TBSIMP_E_TOO_MANY_RESOURCES 0x8029_0218
+	/// The physical presence interface is not supported.
 	TBSIMP_E_PPI_NOT_SUPPORTED 0x8029_0219
+	/// TBS is not compatible with the version of TPM found on the system.
 	TBSIMP_E_TPM_INCOMPATIBLE 0x8029_021a
+	/// No TCG event log is available.
 	TBSIMP_E_NO_EVENT_LOG 0x8029_021b
+	/// A general error was detected when attempting to acquire the BIOS's response to a Physical Presence command.
 	TPM_E_PPI_ACPI_FAILURE 0x8029_0300
+	/// The user failed to confirm the TPM operation request.
 	TPM_E_PPI_USER_ABORT 0x8029_0301
+	/// The BIOS failure prevented the successful execution of the requested TPM operation (e.g. invalid TPM operation request, BIOS communication error with the TPM).
 	TPM_E_PPI_BIOS_FAILURE 0x8029_0302
+	/// The BIOS does not support the physical presence interface.
 	TPM_E_PPI_NOT_SUPPORTED 0x8029_0303
+	/// The Physical Presence command was blocked by current BIOS settings. The system owner may be able to reconfigure the BIOS settings to allow the command.
 	TPM_E_PPI_BLOCKED_IN_BIOS 0x8029_0304
+	/// This is an error mask to convert Platform Crypto Provider errors to win errors.
 	TPM_E_PCP_ERROR_MASK 0x8029_0400
+	/// The Platform Crypto Device is currently not ready. It needs to be fully provisioned to be operational.
 	TPM_E_PCP_DEVICE_NOT_READY 0x8029_0401
+	/// The handle provided to the Platform Crypto Provider is invalid.
 	TPM_E_PCP_INVALID_HANDLE 0x8029_0402
+	/// A parameter provided to the Platform Crypto Provider is invalid.
 	TPM_E_PCP_INVALID_PARAMETER 0x8029_0403
+	/// A provided flag to the Platform Crypto Provider is not supported.
 	TPM_E_PCP_FLAG_NOT_SUPPORTED 0x8029_0404
+	/// The requested operation is not supported by this Platform Crypto Provider.
 	TPM_E_PCP_NOT_SUPPORTED 0x8029_0405
+	/// The buffer is too small to contain all data. No information has been written to the buffer.
 	TPM_E_PCP_BUFFER_TOO_SMALL 0x8029_0406
+	/// An unexpected internal error has occurred in the Platform Crypto Provider.
 	TPM_E_PCP_INTERNAL_ERROR 0x8029_0407
+	/// The authorization to use a provider object has failed.
 	TPM_E_PCP_AUTHENTICATION_FAILED 0x8029_0408
+	/// The Platform Crypto Device has ignored the authorization for the provider object, to mitigate against a dictionary attack.
 	TPM_E_PCP_AUTHENTICATION_IGNORED 0x8029_0409
+	/// The referenced policy was not found.
 	TPM_E_PCP_POLICY_NOT_FOUND 0x8029_040a
+	/// The referenced profile was not found.
 	TPM_E_PCP_PROFILE_NOT_FOUND 0x8029_040b
+	/// The validation was not successful.
 	TPM_E_PCP_VALIDATION_FAILED 0x8029_040c
+	/// An attempt was made to import or load a key under an incorrect storage parent.
 	TPM_E_PCP_WRONG_PARENT 0x8029_040e
+	/// The TPM key is not loaded.
 	TPM_E_KEY_NOT_LOADED 0x8029_040f
+	/// The TPM key certification has not been generated.
 	TPM_E_NO_KEY_CERTIFICATION 0x8029_0410
+	/// The TPM key is not yet finalized.
 	TPM_E_KEY_NOT_FINALIZED 0x8029_0411
+	/// The TPM attestation challenge is not set.
 	TPM_E_ATTESTATION_CHALLENGE_NOT_SET 0x8029_0412
+	/// The TPM PCR info is not available.
 	TPM_E_NOT_PCR_BOUND 0x8029_0413
+	/// The TPM key is already finalized.
 	TPM_E_KEY_ALREADY_FINALIZED 0x8029_0414
+	/// The TPM key usage policy is not supported.
 	TPM_E_KEY_USAGE_POLICY_NOT_SUPPORTED 0x8029_0415
+	/// The TPM key usage policy is invalid.
 	TPM_E_KEY_USAGE_POLICY_INVALID 0x8029_0416
+	/// There was a problem with the software key being imported into the TPM.
 	TPM_E_SOFT_KEY_ERROR 0x8029_0417
+	/// The TPM key is not authenticated.
 	TPM_E_KEY_NOT_AUTHENTICATED 0x8029_0418
+	/// The TPM key is not an AIK.
 	TPM_E_PCP_KEY_NOT_AIK 0x8029_0419
+	/// The TPM key is not a signing key.
 	TPM_E_KEY_NOT_SIGNING_KEY 0x8029_041a
+	/// The TPM is locked out.
 	TPM_E_LOCKED_OUT 0x8029_041b
+	/// The claim type requested is not supported.
 	TPM_E_CLAIM_TYPE_NOT_SUPPORTED 0x8029_041c
+	/// TPM version is not supported.
 	TPM_E_VERSION_NOT_SUPPORTED 0x8029_041d
+	/// The buffer lengths do not match.
 	TPM_E_BUFFER_LENGTH_MISMATCH 0x8029_041e
+	/// The RSA key creation is blocked on this TPM due to known security vulnerabilities.
 	TPM_E_PCP_IFX_RSA_KEY_CREATION_BLOCKED 0x8029_041f
+	/// A ticket required to use a key was not provided.
 	TPM_E_PCP_TICKET_MISSING 0x8029_0420
+	/// This key has a raw policy so the KSP can't authenticate against it.
 	TPM_E_PCP_RAW_POLICY_NOT_SUPPORTED 0x8029_0421
+	/// The TPM key's handle was unexpectedly invalidated due to a hardware or firmware issue.
 	TPM_E_PCP_KEY_HANDLE_INVALIDATED 0x8029_0422
+	/// The requested salt size for signing with RSAPSS does not match what the TPM uses.
 	TPM_E_PCP_UNSUPPORTED_PSS_SALT 0x4029_0423
+	/// TPM related network operations are blocked as Zero Exhaust mode is enabled on client.
 	TPM_E_ZERO_EXHAUST_ENABLED 0x8029_0500
+	/// Data Collector Set was not found.
 	PLA_E_DCS_NOT_FOUND 0x8030_0002
+	/// The Data Collector Set or one of its dependencies is already in use.
 	PLA_E_DCS_IN_USE 0x8030_00aa
+	/// Unable to start Data Collector Set because there are too many folders.
 	PLA_E_TOO_MANY_FOLDERS 0x8030_0045
+	/// Not enough free disk space to start Data Collector Set.
 	PLA_E_NO_MIN_DISK 0x8030_0070
+	/// Data Collector Set already exists.
 	PLA_E_DCS_ALREADY_EXISTS 0x8030_00b7
+	/// Property value will be ignored.
 	PLA_S_PROPERTY_IGNORED 0x0030_0100
+	/// Property value conflict.
 	PLA_E_PROPERTY_CONFLICT 0x8030_0101
+	/// The current configuration for this Data Collector Set requires that it contain exactly one Data Collector.
 	PLA_E_DCS_SINGLETON_REQUIRED 0x8030_0102
+	/// A user account is required in order to commit the current Data Collector Set properties.
 	PLA_E_CREDENTIALS_REQUIRED 0x8030_0103
+	/// Data Collector Set is not running.
 	PLA_E_DCS_NOT_RUNNING 0x8030_0104
+	/// A conflict was detected in the list of include/exclude APIs. Do not specify the same API in both the include list and the exclude list.
 	PLA_E_CONFLICT_INCL_EXCL_API 0x8030_0105
+	/// The executable path you have specified refers to a network share or UNC path.
 	PLA_E_NETWORK_EXE_NOT_VALID 0x8030_0106
+	/// The executable path you have specified is already configured for API tracing.
 	PLA_E_EXE_ALREADY_CONFIGURED 0x8030_0107
+	/// The executable path you have specified does not exist. Verify that the specified path is correct.
 	PLA_E_EXE_PATH_NOT_VALID 0x8030_0108
+	/// Data Collector already exists.
 	PLA_E_DC_ALREADY_EXISTS 0x8030_0109
+	/// The wait for the Data Collector Set start notification has timed out.
 	PLA_E_DCS_START_WAIT_TIMEOUT 0x8030_010a
+	/// The wait for the Data Collector to start has timed out.
 	PLA_E_DC_START_WAIT_TIMEOUT 0x8030_010b
+	/// The wait for the report generation tool to finish has timed out.
 	PLA_E_REPORT_WAIT_TIMEOUT 0x8030_010c
+	/// Duplicate items are not allowed.
 	PLA_E_NO_DUPLICATES 0x8030_010d
+	/// When specifying the executable that you want to trace, you must specify a full path to the executable and not just a filename.
 	PLA_E_EXE_FULL_PATH_REQUIRED 0x8030_010e
+	/// The session name provided is invalid.
 	PLA_E_INVALID_SESSION_NAME 0x8030_010f
+	/// The Event Log channel Microsoft-Windows-Diagnosis-PLA/Operational must be enabled to perform this operation.
 	PLA_E_PLA_CHANNEL_NOT_ENABLED 0x8030_0110
+	/// The Event Log channel Microsoft-Windows-TaskScheduler must be enabled to perform this operation.
 	PLA_E_TASKSCHED_CHANNEL_NOT_ENABLED 0x8030_0111
+	/// The execution of the Rules Manager failed.
 	PLA_E_RULES_MANAGER_FAILED 0x8030_0112
+	/// An error occurred while attempting to compress or extract the data.
 	PLA_E_CABAPI_FAILURE 0x8030_0113
+	/// This drive is locked by BitLocker Drive Encryption. You must unlock this drive from Control Panel.
 	FVE_E_LOCKED_VOLUME 0x8031_0000
+	/// This drive is not encrypted.
 	FVE_E_NOT_ENCRYPTED 0x8031_0001
+	/// The BIOS did not correctly communicate with the Trusted Platform Module (TPM). Contact the computer manufacturer for BIOS upgrade instructions.
 	FVE_E_NO_TPM_BIOS 0x8031_0002
+	/// The BIOS did not correctly communicate with the master boot record (MBR). Contact the computer manufacturer for BIOS upgrade instructions.
 	FVE_E_NO_MBR_METRIC 0x8031_0003
+	/// A required TPM measurement is missing. If there is a bootable CD or DVD in your computer, remove it, restart the computer, and turn on BitLocker again. If the problem persists, ensure the master boot record is up to date.
 	FVE_E_NO_BOOTSECTOR_METRIC 0x8031_0004
+	/// The boot sector of this drive is not compatible with BitLocker Drive Encryption. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot manager (BOOTMGR).
 	FVE_E_NO_BOOTMGR_METRIC 0x8031_0005
+	/// The boot manager of this operating system is not compatible with BitLocker Drive Encryption. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot manager (BOOTMGR).
 	FVE_E_WRONG_BOOTMGR 0x8031_0006
+	/// At least one secure key protector is required for this operation to be performed.
 	FVE_E_SECURE_KEY_REQUIRED 0x8031_0007
+	/// BitLocker Drive Encryption is not enabled on this drive. Turn on BitLocker.
 	FVE_E_NOT_ACTIVATED 0x8031_0008
+	/// BitLocker Drive Encryption cannot perform the requested action. This condition may occur when two requests are issued at the same time. Wait a few moments and then try the action again.
 	FVE_E_ACTION_NOT_ALLOWED 0x8031_0009
+	/// The Active Directory Domain Services forest does not contain the required attributes and classes to host BitLocker Drive Encryption or Trusted Platform Module information. Contact your domain administrator to verify that any required BitLocker Active Directory schema extensions have been installed.
 	FVE_E_AD_SCHEMA_NOT_INSTALLED 0x8031_000a
+	/// The type of the data obtained from Active Directory was not expected. The BitLocker recovery information may be missing or corrupted.
 	FVE_E_AD_INVALID_DATATYPE 0x8031_000b
+	/// The size of the data obtained from Active Directory was not expected. The BitLocker recovery information may be missing or corrupted.
 	FVE_E_AD_INVALID_DATASIZE 0x8031_000c
+	/// The attribute read from Active Directory does not contain any values. The BitLocker recovery information may be missing or corrupted.
 	FVE_E_AD_NO_VALUES 0x8031_000d
+	/// The attribute was not set.
 	FVE_E_AD_ATTR_NOT_SET 0x8031_000e
+	/// The specified attribute cannot be found in Active Directory Domain Services. Contact your domain administrator to verify that any required BitLocker Active Directory schema extensions have been installed.
 	FVE_E_AD_GUID_NOT_FOUND 0x8031_000f
+	/// The BitLocker metadata for the encrypted drive is not valid. You can attempt to repair the drive to restore access.
 	FVE_E_BAD_INFORMATION 0x8031_0010
+	/// The drive cannot be encrypted because it does not have enough free space. Delete any unnecessary data on the drive to create additional free space and then try again.
 	FVE_E_TOO_SMALL 0x8031_0011
+	/// The drive cannot be encrypted because it contains system boot information. Create a separate partition for use as the system drive that contains the boot information and a second partition for use as the operating system drive and then encrypt the operating system drive.
 	FVE_E_SYSTEM_VOLUME 0x8031_0012
+	/// The drive cannot be encrypted because the file system is not supported.
 	FVE_E_FAILED_WRONG_FS 0x8031_0013
+	/// The file system size is larger than the partition size in the partition table. This drive may be corrupt or may have been tampered with. To use it with BitLocker, you must reformat the partition.
 	FVE_E_BAD_PARTITION_SIZE 0x8031_0014
+	/// This drive cannot be encrypted.
 	FVE_E_NOT_SUPPORTED 0x8031_0015
+	/// The data is not valid.
 	FVE_E_BAD_DATA 0x8031_0016
+	/// The data drive specified is not set to automatically unlock on the current computer and cannot be unlocked automatically.
 	FVE_E_VOLUME_NOT_BOUND 0x8031_0017
+	/// You must initialize the Trusted Platform Module (TPM) before you can use BitLocker Drive Encryption.
 	FVE_E_TPM_NOT_OWNED 0x8031_0018
+	/// The operation attempted cannot be performed on an operating system drive.
 	FVE_E_NOT_DATA_VOLUME 0x8031_0019
+	/// The buffer supplied to a function was insufficient to contain the returned data. Increase the buffer size before running the function again.
 	FVE_E_AD_INSUFFICIENT_BUFFER 0x8031_001a
+	/// A read operation failed while converting the drive. The drive was not converted. Please re-enable BitLocker.
 	FVE_E_CONV_READ 0x8031_001b
+	/// A write operation failed while converting the drive. The drive was not converted. Please re-enable BitLocker.
 	FVE_E_CONV_WRITE 0x8031_001c
+	/// One or more BitLocker key protectors are required. You cannot delete the last key on this drive.
 	FVE_E_KEY_REQUIRED 0x8031_001d
+	/// Cluster configurations are not supported by BitLocker Drive Encryption.
 	FVE_E_CLUSTERING_NOT_SUPPORTED 0x8031_001e
+	/// The drive specified is already configured to be automatically unlocked on the current computer.
 	FVE_E_VOLUME_BOUND_ALREADY 0x8031_001f
+	/// The operating system drive is not protected by BitLocker Drive Encryption.
 	FVE_E_OS_NOT_PROTECTED 0x8031_0020
+	/// BitLocker Drive Encryption has been suspended on this drive. All BitLocker key protectors configured for this drive are effectively disabled, and the drive will be automatically unlocked using an unencrypted (clear) key.
 	FVE_E_PROTECTION_DISABLED 0x8031_0021
+	/// The drive you are attempting to lock does not have any key protectors available for encryption because BitLocker protection is currently suspended. Re-enable BitLocker to lock this drive.
 	FVE_E_RECOVERY_KEY_REQUIRED 0x8031_0022
+	/// BitLocker cannot use the Trusted Platform Module (TPM) to protect a data drive. TPM protection can only be used with the operating system drive.
 	FVE_E_FOREIGN_VOLUME 0x8031_0023
+	/// The BitLocker metadata for the encrypted drive cannot be updated because it was locked for updating by another process. Please try this process again.
 	FVE_E_OVERLAPPED_UPDATE 0x8031_0024
+	/// The authorization data for the storage root key (SRK) of the Trusted Platform Module (TPM) is not zero and is therefore incompatible with BitLocker. Please initialize the TPM before attempting to use it with BitLocker.
 	FVE_E_TPM_SRK_AUTH_NOT_ZERO 0x8031_0025
+	/// The drive encryption algorithm cannot be used on this sector size.
 	FVE_E_FAILED_SECTOR_SIZE 0x8031_0026
+	/// The drive cannot be unlocked with the key provided. Confirm that you have provided the correct key and try again.
 	FVE_E_FAILED_AUTHENTICATION 0x8031_0027
+	/// The drive specified is not the operating system drive.
 	FVE_E_NOT_OS_VOLUME 0x8031_0028
+	/// BitLocker Drive Encryption cannot be turned off on the operating system drive until the auto unlock feature has been disabled for the fixed data drives and removable data drives associated with this computer.
 	FVE_E_AUTOUNLOCK_ENABLED 0x8031_0029
+	/// The system partition boot sector does not perform Trusted Platform Module (TPM) measurements. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot sector.
 	FVE_E_WRONG_BOOTSECTOR 0x8031_002a
+	/// BitLocker Drive Encryption operating system drives must be formatted with the NTFS file system in order to be encrypted. Convert the drive to NTFS, and then turn on BitLocker.
 	FVE_E_WRONG_SYSTEM_FS 0x8031_002b
+	/// Group Policy settings require that a recovery password be specified before encrypting the drive.
 	FVE_E_POLICY_PASSWORD_REQUIRED 0x8031_002c
+	/// The drive encryption algorithm and key cannot be set on a previously encrypted drive. To encrypt this drive with BitLocker Drive Encryption, remove the previous encryption and then turn on BitLocker.
 	FVE_E_CANNOT_SET_FVEK_ENCRYPTED 0x8031_002d
+	/// BitLocker Drive Encryption cannot encrypt the specified drive because an encryption key is not available. Add a key protector to encrypt this drive.
 	FVE_E_CANNOT_ENCRYPT_NO_KEY 0x8031_002e
+	/// BitLocker Drive Encryption detected bootable media (CD or DVD) in the computer. Remove the media and restart the computer before configuring BitLocker.
 	FVE_E_BOOTABLE_CDDVD 0x8031_0030
+	/// This key protector cannot be added. Only one key protector of this type is allowed for this drive.
 	FVE_E_PROTECTOR_EXISTS 0x8031_0031
+	/// The recovery password file was not found because a relative path was specified. Recovery passwords must be saved to a fully qualified path. Environment variables configured on the computer can be used in the path.
 	FVE_E_RELATIVE_PATH 0x8031_0032
+	/// The specified key protector was not found on the drive. Try another key protector.
 	FVE_E_PROTECTOR_NOT_FOUND 0x8031_0033
+	/// The recovery key provided is corrupt and cannot be used to access the drive. An alternative recovery method, such as recovery password, a data recovery agent, or a backup version of the recovery key must be used to recover access to the drive.
 	FVE_E_INVALID_KEY_FORMAT 0x8031_0034
+	/// The format of the recovery password provided is invalid. BitLocker recovery passwords are 48 digits. Verify that the recovery password is in the correct format and then try again.
 	FVE_E_INVALID_PASSWORD_FORMAT 0x8031_0035
+	/// The random number generator check test failed.
 	FVE_E_FIPS_RNG_CHECK_FAILED 0x8031_0036
+	/// The Group Policy setting requiring FIPS compliance prevents a local recovery password from being generated or used by BitLocker Drive Encryption. When operating in FIPS-compliant mode, BitLocker recovery options can be either a recovery key stored on a USB drive or recovery through a data recovery agent.
 	FVE_E_FIPS_PREVENTS_RECOVERY_PASSWORD 0x8031_0037
+	/// The Group Policy setting requiring FIPS compliance prevents the recovery password from being saved to Active Directory. When operating in FIPS-compliant mode, BitLocker recovery options can be either a recovery key stored on a USB drive or recovery through a data recovery agent. Check your Group Policy settings configuration.
 	FVE_E_FIPS_PREVENTS_EXTERNAL_KEY_EXPORT 0x8031_0038
+	/// The drive must be fully decrypted to complete this operation.
 	FVE_E_NOT_DECRYPTED 0x8031_0039
+	/// The key protector specified cannot be used for this operation.
 	FVE_E_INVALID_PROTECTOR_TYPE 0x8031_003a
+	/// No key protectors exist on the drive to perform the hardware test.
 	FVE_E_NO_PROTECTORS_TO_TEST 0x8031_003b
+	/// The BitLocker startup key or recovery password cannot be found on the USB device. Verify that you have the correct USB device, that the USB device is plugged into the computer on an active USB port, restart the computer, and then try again. If the problem persists, contact the computer manufacturer for BIOS upgrade instructions.
 	FVE_E_KEYFILE_NOT_FOUND 0x8031_003c
+	/// The BitLocker startup key or recovery password file provided is corrupt or invalid. Verify that you have the correct startup key or recovery password file and try again.
 	FVE_E_KEYFILE_INVALID 0x8031_003d
+	/// The BitLocker encryption key cannot be obtained from the startup key or recovery password. Verify that you have the correct startup key or recovery password and try again.
 	FVE_E_KEYFILE_NO_VMK 0x8031_003e
+	/// The Trusted Platform Module (TPM) is disabled. The TPM must be enabled, initialized, and have valid ownership before it can be used with BitLocker Drive Encryption.
 	FVE_E_TPM_DISABLED 0x8031_003f
+	/// The BitLocker configuration of the specified drive cannot be managed because this computer is currently operating in Safe Mode. While in Safe Mode, BitLocker Drive Encryption can only be used for recovery purposes.
 	FVE_E_NOT_ALLOWED_IN_SAFE_MODE 0x8031_0040
+	/// The Trusted Platform Module (TPM) was unable to unlock the drive. Either the system boot information changed after choosing BitLocker settings or the PIN did not match. If the problem persists after several tries, there may be a hardware or firmware problem.
 	FVE_E_TPM_INVALID_PCR 0x8031_0041
+	/// The BitLocker encryption key cannot be obtained from the Trusted Platform Module (TPM).
 	FVE_E_TPM_NO_VMK 0x8031_0042
+	/// The BitLocker encryption key cannot be obtained from the Trusted Platform Module (TPM) and PIN.
 	FVE_E_PIN_INVALID 0x8031_0043
+	/// A boot application has changed since BitLocker Drive Encryption was enabled.
 	FVE_E_AUTH_INVALID_APPLICATION 0x8031_0044
+	/// The Boot Configuration Data (BCD) settings have changed since BitLocker Drive Encryption was enabled.
 	FVE_E_AUTH_INVALID_CONFIG 0x8031_0045
+	/// The Group Policy setting requiring FIPS compliance prohibits the use of unencrypted keys, which prevents BitLocker from being suspended on this drive. Please contact your domain administrator for more information.
 	FVE_E_FIPS_DISABLE_PROTECTION_NOT_ALLOWED 0x8031_0046
+	/// This drive cannot be encrypted by BitLocker Drive Encryption because the file system does not extend to the end of the drive. Repartition this drive and then try again.
 	FVE_E_FS_NOT_EXTENDED 0x8031_0047
+	/// BitLocker Drive Encryption cannot be enabled on the operating system drive. Contact the computer manufacturer for BIOS upgrade instructions.
 	FVE_E_FIRMWARE_TYPE_NOT_SUPPORTED 0x8031_0048
+	/// This version of Windows does not include BitLocker Drive Encryption. To use BitLocker Drive Encryption, please upgrade the operating system.
 	FVE_E_NO_LICENSE 0x8031_0049
+	/// BitLocker Drive Encryption cannot be used because critical BitLocker system files are missing or corrupted. Use Windows Startup Repair to restore these files to your computer.
 	FVE_E_NOT_ON_STACK 0x8031_004a
+	/// The drive cannot be locked when the drive is in use.
 	FVE_E_FS_MOUNTED 0x8031_004b
+	/// The access token associated with the current thread is not an impersonated token.
 	FVE_E_TOKEN_NOT_IMPERSONATED 0x8031_004c
+	/// The BitLocker encryption key cannot be obtained. Verify that the Trusted Platform Module (TPM) is enabled and ownership has been taken. If this computer does not have a TPM, verify that the USB drive is inserted and available.
 	FVE_E_DRY_RUN_FAILED 0x8031_004d
+	/// You must restart your computer before continuing with BitLocker Drive Encryption.
 	FVE_E_REBOOT_REQUIRED 0x8031_004e
+	/// Drive encryption cannot occur while boot debugging is enabled. Use the bcdedit command-line tool to turn off boot debugging.
 	FVE_E_DEBUGGER_ENABLED 0x8031_004f
+	/// No action was taken as BitLocker Drive Encryption is in raw access mode.
 	FVE_E_RAW_ACCESS 0x8031_0050
+	/// BitLocker Drive Encryption cannot enter raw access mode for this drive because the drive is currently in use.
 	FVE_E_RAW_BLOCKED 0x8031_0051
+	/// The path specified in the Boot Configuration Data (BCD) for a BitLocker Drive Encryption integrity-protected application is incorrect. Please verify and correct your BCD settings and try again.
 	FVE_E_BCD_APPLICATIONS_PATH_INCORRECT 0x8031_0052
+	/// BitLocker Drive Encryption can only be used for limited provisioning or recovery purposes when the computer is running in pre-installation or recovery environments.
 	FVE_E_NOT_ALLOWED_IN_VERSION 0x8031_0053
+	/// The auto-unlock master key was not available from the operating system drive.
 	FVE_E_NO_AUTOUNLOCK_MASTER_KEY 0x8031_0054
+	/// The system firmware failed to enable clearing of system memory when the computer was restarted.
 	FVE_E_MOR_FAILED 0x8031_0055
+	/// The hidden drive cannot be encrypted.
 	FVE_E_HIDDEN_VOLUME 0x8031_0056
+	/// BitLocker encryption keys were ignored because the drive was in a transient state.
 	FVE_E_TRANSIENT_STATE 0x8031_0057
+	/// Public key based protectors are not allowed on this drive.
 	FVE_E_PUBKEY_NOT_ALLOWED 0x8031_0058
+	/// BitLocker Drive Encryption is already performing an operation on this drive. Please complete all operations before continuing.
 	FVE_E_VOLUME_HANDLE_OPEN 0x8031_0059
+	/// This version of Windows does not support this feature of BitLocker Drive Encryption. To use this feature, upgrade the operating system.
 	FVE_E_NO_FEATURE_LICENSE 0x8031_005a
+	/// The Group Policy settings for BitLocker startup options are in conflict and cannot be applied. Contact your system administrator for more information.
 	FVE_E_INVALID_STARTUP_OPTIONS 0x8031_005b
+	/// Group Policy settings do not permit the creation of a recovery password.
 	FVE_E_POLICY_RECOVERY_PASSWORD_NOT_ALLOWED 0x8031_005c
+	/// Group Policy settings require the creation of a recovery password.
 	FVE_E_POLICY_RECOVERY_PASSWORD_REQUIRED 0x8031_005d
+	/// Group Policy settings do not permit the creation of a recovery key.
 	FVE_E_POLICY_RECOVERY_KEY_NOT_ALLOWED 0x8031_005e
+	/// Group Policy settings require the creation of a recovery key.
 	FVE_E_POLICY_RECOVERY_KEY_REQUIRED 0x8031_005f
+	/// Group Policy settings do not permit the use of a PIN at startup. Please choose a different BitLocker startup option.
 	FVE_E_POLICY_STARTUP_PIN_NOT_ALLOWED 0x8031_0060
+	/// Group Policy settings require the use of a PIN at startup. Please choose this BitLocker startup option.
 	FVE_E_POLICY_STARTUP_PIN_REQUIRED 0x8031_0061
+	/// Group Policy settings do not permit the use of a startup key. Please choose a different BitLocker startup option.
 	FVE_E_POLICY_STARTUP_KEY_NOT_ALLOWED 0x8031_0062
+	/// Group Policy settings require the use of a startup key. Please choose this BitLocker startup option.
 	FVE_E_POLICY_STARTUP_KEY_REQUIRED 0x8031_0063
+	/// Group Policy settings do not permit the use of a startup key and PIN. Please choose a different BitLocker startup option.
 	FVE_E_POLICY_STARTUP_PIN_KEY_NOT_ALLOWED 0x8031_0064
+	/// Group Policy settings require the use of a startup key and PIN. Please choose this BitLocker startup option.
 	FVE_E_POLICY_STARTUP_PIN_KEY_REQUIRED 0x8031_0065
+	/// Group policy does not permit the use of TPM-only at startup. Please choose a different BitLocker startup option.
 	FVE_E_POLICY_STARTUP_TPM_NOT_ALLOWED 0x8031_0066
+	/// Group Policy settings require the use of TPM-only at startup. Please choose this BitLocker startup option.
 	FVE_E_POLICY_STARTUP_TPM_REQUIRED 0x8031_0067
+	/// The PIN provided does not meet minimum or maximum length requirements.
 	FVE_E_POLICY_INVALID_PIN_LENGTH 0x8031_0068
+	/// The key protector is not supported by the version of BitLocker Drive Encryption currently on the drive. Upgrade the drive to add the key protector.
 	FVE_E_KEY_PROTECTOR_NOT_SUPPORTED 0x8031_0069
+	/// Group Policy settings do not permit the creation of a password.
 	FVE_E_POLICY_PASSPHRASE_NOT_ALLOWED 0x8031_006a
+	/// Group Policy settings require the creation of a password.
 	FVE_E_POLICY_PASSPHRASE_REQUIRED 0x8031_006b
+	/// The Group Policy setting requiring FIPS compliance prevents passwords from being generated or used. Please contact your system administrator for more information.
 	FVE_E_FIPS_PREVENTS_PASSPHRASE 0x8031_006c
+	/// A password cannot be added to the operating system drive.
 	FVE_E_OS_VOLUME_PASSPHRASE_NOT_ALLOWED 0x8031_006d
+	/// The BitLocker object identifier (OID) on the drive appears to be invalid or corrupt. Use manage-BDE to reset the OID on this drive.
 	FVE_E_INVALID_BITLOCKER_OID 0x8031_006e
+	/// The drive is too small to be protected using BitLocker Drive Encryption.
 	FVE_E_VOLUME_TOO_SMALL 0x8031_006f
+	/// The selected discovery drive type is incompatible with the file system on the drive. BitLocker To Go discovery drives must be created on FAT formatted drives.
 	FVE_E_DV_NOT_SUPPORTED_ON_FS 0x8031_0070
+	/// The selected discovery drive type is not allowed by the computer's Group Policy settings. Verify that Group Policy settings allow the creation of discovery drives for use with BitLocker To Go.
 	FVE_E_DV_NOT_ALLOWED_BY_GP 0x8031_0071
+	/// Group Policy settings do not permit user certificates such as smart cards to be used with BitLocker Drive Encryption.
 	FVE_E_POLICY_USER_CERTIFICATE_NOT_ALLOWED 0x8031_0072
+	/// Group Policy settings require that you have a valid user certificate, such as a smart card, to be used with BitLocker Drive Encryption.
 	FVE_E_POLICY_USER_CERTIFICATE_REQUIRED 0x8031_0073
+	/// Group Policy settings requires that you use a smart card-based key protector with BitLocker Drive Encryption.
 	FVE_E_POLICY_USER_CERT_MUST_BE_HW 0x8031_0074
+	/// Group Policy settings do not permit BitLocker-protected fixed data drives to be automatically unlocked.
 	FVE_E_POLICY_USER_CONFIGURE_FDV_AUTOUNLOCK_NOT_ALLOWED 0x8031_0075
+	/// Group Policy settings do not permit BitLocker-protected removable data drives to be automatically unlocked.
 	FVE_E_POLICY_USER_CONFIGURE_RDV_AUTOUNLOCK_NOT_ALLOWED 0x8031_0076
+	/// Group Policy settings do not permit you to configure BitLocker Drive Encryption on removable data drives.
 	FVE_E_POLICY_USER_CONFIGURE_RDV_NOT_ALLOWED 0x8031_0077
+	/// Group Policy settings do not permit you to turn on BitLocker Drive Encryption on removable data drives. Please contact your system administrator if you need to turn on BitLocker.
 	FVE_E_POLICY_USER_ENABLE_RDV_NOT_ALLOWED 0x8031_0078
+	/// Group Policy settings do not permit turning off BitLocker Drive Encryption on removable data drives. Please contact your system administrator if you need to turn off BitLocker.
 	FVE_E_POLICY_USER_DISABLE_RDV_NOT_ALLOWED 0x8031_0079
+	/// Your password does not meet minimum password length requirements. By default, passwords must be at least 8 characters in length. Check with your system administrator for the password length requirement in your organization.
 	FVE_E_POLICY_INVALID_PASSPHRASE_LENGTH 0x8031_0080
+	/// Your password does not meet the complexity requirements set by your system administrator. Try adding upper and lowercase characters, numbers, and symbols.
 	FVE_E_POLICY_PASSPHRASE_TOO_SIMPLE 0x8031_0081
+	/// This drive cannot be encrypted because it is reserved for Windows System Recovery Options.
 	FVE_E_RECOVERY_PARTITION 0x8031_0082
+	/// BitLocker Drive Encryption cannot be applied to this drive because of conflicting Group Policy settings. BitLocker cannot be configured to automatically unlock fixed data drives when user recovery options are disabled.
 	FVE_E_POLICY_CONFLICT_FDV_RK_OFF_AUK_ON 0x8031_0083
+	/// BitLocker Drive Encryption cannot be applied to this drive because of conflicting Group Policy settings. BitLocker cannot be configured to automatically unlock removable data drives when user recovery option are disabled.
 	FVE_E_POLICY_CONFLICT_RDV_RK_OFF_AUK_ON 0x8031_0084
+	/// The Enhanced Key Usage (EKU) attribute of the specified certificate does not permit it to be used for BitLocker Drive Encryption. BitLocker does not require that a certificate have an EKU attribute, but if one is configured it must be set to an object identifier (OID) that matches the OID configured for BitLocker.
 	FVE_E_NON_BITLOCKER_OID 0x8031_0085
+	/// BitLocker Drive Encryption cannot be applied to this drive as currently configured because of Group Policy settings. The certificate you provided for drive encryption is self-signed. Current Group Policy settings do not permit the use of self-signed certificates. Obtain a new certificate from your certification authority before attempting to enable BitLocker.
 	FVE_E_POLICY_PROHIBITS_SELFSIGNED 0x8031_0086
+	/// BitLocker Encryption cannot be applied to this drive because of conflicting Group Policy settings. When write access to drives not protected by BitLocker is denied, the use of a USB startup key cannot be required. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker.
 	FVE_E_POLICY_CONFLICT_RO_AND_STARTUP_KEY_REQUIRED 0x8031_0087
+	/// BitLocker Drive Encryption failed to recover from an abruptly terminated conversion. This could be due to either all conversion logs being corrupted or the media being write-protected.
 	FVE_E_CONV_RECOVERY_FAILED 0x8031_0088
+	/// The requested virtualization size is too big.
 	FVE_E_VIRTUALIZED_SPACE_TOO_BIG 0x8031_0089
+	/// BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on operating system drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker.
 	FVE_E_POLICY_CONFLICT_OSV_RP_OFF_ADB_ON 0x8031_0090
+	/// BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on fixed data drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker.
 	FVE_E_POLICY_CONFLICT_FDV_RP_OFF_ADB_ON 0x8031_0091
+	/// BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on removable data drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker.
 	FVE_E_POLICY_CONFLICT_RDV_RP_OFF_ADB_ON 0x8031_0092
+	/// The Key Usage (KU) attribute of the specified certificate does not permit it to be used for BitLocker Drive Encryption. BitLocker does not require that a certificate have a KU attribute, but if one is configured it must be set to either Key Encipherment or Key Agreement.
 	FVE_E_NON_BITLOCKER_KU 0x8031_0093
+	/// The private key associated with the specified certificate cannot be authorized. The private key authorization was either not provided or the provided authorization was invalid.
 	FVE_E_PRIVATEKEY_AUTH_FAILED 0x8031_0094
+	/// Removal of the data recovery agent certificate must be done using the Certificates snap-in.
 	FVE_E_REMOVAL_OF_DRA_FAILED 0x8031_0095
+	/// This drive was encrypted using the version of BitLocker Drive Encryption included with Windows Vista and Windows Server 2008 which does not support organizational identifiers. To specify organizational identifiers for this drive upgrade the drive encryption to the latest version using the "manage-bde -upgrade" command.
 	FVE_E_OPERATION_NOT_SUPPORTED_ON_VISTA_VOLUME 0x8031_0096
+	/// The drive cannot be locked because it is automatically unlocked on this computer. Remove the automatic unlock protector to lock this drive.
 	FVE_E_CANT_LOCK_AUTOUNLOCK_ENABLED_VOLUME 0x8031_0097
+	/// The default BitLocker Key Derivation Function SP800-56A for ECC smart cards is not supported by your smart card. The Group Policy setting requiring FIPS-compliance prevents BitLocker from using any other key derivation function for encryption. You have to use a FIPS compliant smart card in FIPS restricted environments.
 	FVE_E_FIPS_HASH_KDF_NOT_ALLOWED 0x8031_0098
+	/// The BitLocker encryption key could not be obtained from the Trusted Platform Module (TPM) and enhanced PIN. Try using a PIN containing only numerals.
 	FVE_E_ENH_PIN_INVALID 0x8031_0099
+	/// The requested TPM PIN contains invalid characters.
 	FVE_E_INVALID_PIN_CHARS 0x8031_009a
+	/// The management information stored on the drive contained an unknown type. If you are using an old version of Windows, try accessing the drive from the latest version.
 	FVE_E_INVALID_DATUM_TYPE 0x8031_009b
+	/// The feature is only supported on EFI systems.
 	FVE_E_EFI_ONLY 0x8031_009c
+	/// More than one Network Key Protector certificate has been found on the system.
 	FVE_E_MULTIPLE_NKP_CERTS 0x8031_009d
+	/// Removal of the Network Key Protector certificate must be done using the Certificates snap-in.
 	FVE_E_REMOVAL_OF_NKP_FAILED 0x8031_009e
+	/// An invalid certificate has been found in the Network Key Protector certificate store.
 	FVE_E_INVALID_NKP_CERT 0x8031_009f
+	/// This drive isn't protected with a PIN.
 	FVE_E_NO_EXISTING_PIN 0x8031_00a0
+	/// Please enter the correct current PIN.
 	FVE_E_PROTECTOR_CHANGE_PIN_MISMATCH 0x8031_00a1
+	/// You must be logged on with an administrator account to change the PIN. Click the link to reset the PIN as an administrator.
 	FVE_E_PIN_PROTECTOR_CHANGE_BY_STD_USER_DISALLOWED 0x8031_00a2
+	/// BitLocker has disabled PIN changes after too many failed requests. Click the link to reset the PIN as an administrator.
 	FVE_E_PROTECTOR_CHANGE_MAX_PIN_CHANGE_ATTEMPTS_REACHED 0x8031_00a3
+	/// Your system administrator requires that passwords contain only printable ASCII characters. This includes unaccented letters (A-Z, a-z), numbers (0-9), space, arithmetic signs, common punctuation, separators, and the following symbols: # $ & @ ^ _ ~ .
 	FVE_E_POLICY_PASSPHRASE_REQUIRES_ASCII 0x8031_00a4
+	/// BitLocker Drive Encryption only supports used space only encryption on thin provisioned storage.
 	FVE_E_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE 0x8031_00a5
+	/// BitLocker Drive Encryption does not support wiping free space on thin provisioned storage.
 	FVE_E_WIPE_NOT_ALLOWED_ON_TP_STORAGE 0x8031_00a6
+	/// The required authentication key length is not supported by the drive.
 	FVE_E_KEY_LENGTH_NOT_SUPPORTED_BY_EDRIVE 0x8031_00a7
+	/// This drive isn't protected with a password.
 	FVE_E_NO_EXISTING_PASSPHRASE 0x8031_00a8
+	/// Please enter the correct current password.
 	FVE_E_PROTECTOR_CHANGE_PASSPHRASE_MISMATCH 0x8031_00a9
+	/// The password cannot exceed 256 characters.
 	FVE_E_PASSPHRASE_TOO_LONG 0x8031_00aa
+	/// A password key protector cannot be added because a TPM protector exists on the drive.
 	FVE_E_NO_PASSPHRASE_WITH_TPM 0x8031_00ab
+	/// A TPM key protector cannot be added because a password protector exists on the drive.
 	FVE_E_NO_TPM_WITH_PASSPHRASE 0x8031_00ac
+	/// This command can only be performed from the coordinator node for the specified CSV volume.
 	FVE_E_NOT_ALLOWED_ON_CSV_STACK 0x8031_00ad
+	/// This command cannot be performed on a volume when it is part of a cluster.
 	FVE_E_NOT_ALLOWED_ON_CLUSTER 0x8031_00ae
+	/// BitLocker did not revert to using BitLocker software encryption due to group policy configuration.
 	FVE_E_EDRIVE_NO_FAILOVER_TO_SW 0x8031_00af
+	/// The drive cannot be managed by BitLocker because the drive's hardware encryption feature is already in use.
 	FVE_E_EDRIVE_BAND_IN_USE 0x8031_00b0
+	/// Group Policy settings do not allow the use of hardware-based encryption.
 	FVE_E_EDRIVE_DISALLOWED_BY_GP 0x8031_00b1
+	/// The drive specified does not support hardware-based encryption.
 	FVE_E_EDRIVE_INCOMPATIBLE_VOLUME 0x8031_00b2
+	/// BitLocker cannot be upgraded during disk encryption or decryption.
 	FVE_E_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING 0x8031_00b3
+	/// Discovery Volumes are not supported for volumes using hardware encryption.
 	FVE_E_EDRIVE_DV_NOT_SUPPORTED 0x8031_00b4
+	/// No pre-boot keyboard detected. The user may not be able to provide required input to unlock the volume.
 	FVE_E_NO_PREBOOT_KEYBOARD_DETECTED 0x8031_00b5
+	/// No pre-boot keyboard or Windows Recovery Environment detected. The user may not be able to provide required input to unlock the volume.
 	FVE_E_NO_PREBOOT_KEYBOARD_OR_WINRE_DETECTED 0x8031_00b6
+	/// Group Policy settings require the creation of a startup PIN, but a pre-boot keyboard is not available on this device. The user may not be able to provide required input to unlock the volume.
 	FVE_E_POLICY_REQUIRES_STARTUP_PIN_ON_TOUCH_DEVICE 0x8031_00b7
+	/// Group Policy settings require the creation of a recovery password, but neither a pre-boot keyboard nor Windows Recovery Environment is available on this device. The user may not be able to provide required input to unlock the volume.
 	FVE_E_POLICY_REQUIRES_RECOVERY_PASSWORD_ON_TOUCH_DEVICE 0x8031_00b8
+	/// Wipe of free space is not currently taking place.
 	FVE_E_WIPE_CANCEL_NOT_APPLICABLE 0x8031_00b9
+	/// BitLocker cannot use Secure Boot for platform integrity because Secure Boot has been disabled.
 	FVE_E_SECUREBOOT_DISABLED 0x8031_00ba
+	/// BitLocker cannot use Secure Boot for platform integrity because the Secure Boot configuration does not meet the requirements for BitLocker.
 	FVE_E_SECUREBOOT_CONFIGURATION_INVALID 0x8031_00bb
+	/// Your computer doesn't support BitLocker hardware-based encryption. Check with your computer manufacturer for firmware updates.
 	FVE_E_EDRIVE_DRY_RUN_FAILED 0x8031_00bc
+	/// BitLocker cannot be enabled on the volume because it contains a Volume Shadow Copy. Remove all Volume Shadow Copies before encrypting the volume.
 	FVE_E_SHADOW_COPY_PRESENT 0x8031_00bd
+	/// BitLocker Drive Encryption cannot be applied to this drive because the Group Policy setting for Enhanced Boot Configuration Data contains invalid data. Please have your system administrator resolve this invalid configuration before attempting to enable BitLocker.
 	FVE_E_POLICY_INVALID_ENHANCED_BCD_SETTINGS 0x8031_00be
+	/// This PC's firmware is not capable of supporting hardware encryption.
 	FVE_E_EDRIVE_INCOMPATIBLE_FIRMWARE 0x8031_00bf
+	/// BitLocker has disabled password changes after too many failed requests. Click the link to reset the password as an administrator.
 	FVE_E_PROTECTOR_CHANGE_MAX_PASSPHRASE_CHANGE_ATTEMPTS_REACHED 0x8031_00c0
+	/// You must be logged on with an administrator account to change the password. Click the link to reset the password as an administrator.
 	FVE_E_PASSPHRASE_PROTECTOR_CHANGE_BY_STD_USER_DISALLOWED 0x8031_00c1
+	/// BitLocker cannot save the recovery password because the specified Microsoft account is Suspended.
 	FVE_E_LIVEID_ACCOUNT_SUSPENDED 0x8031_00c2
+	/// BitLocker cannot save the recovery password because the specified Microsoft account is Blocked.
 	FVE_E_LIVEID_ACCOUNT_BLOCKED 0x8031_00c3
+	/// This PC is not provisioned to support device encryption. Please enable BitLocker on all volumes to comply with device encryption policy.
 	FVE_E_NOT_PROVISIONED_ON_ALL_VOLUMES 0x8031_00c4
+	/// This PC cannot support device encryption because unencrypted fixed data volumes are present.
 	FVE_E_DE_FIXED_DATA_NOT_SUPPORTED 0x8031_00c5
+	/// This PC does not meet the hardware requirements to support device encryption.
 	FVE_E_DE_HARDWARE_NOT_COMPLIANT 0x8031_00c6
+	/// This PC cannot support device encryption because WinRE is not properly configured.
 	FVE_E_DE_WINRE_NOT_CONFIGURED 0x8031_00c7
+	/// Protection is enabled on the volume but has been suspended. This is likely to have happened due to an update being applied to your system. Please try again after a reboot.
 	FVE_E_DE_PROTECTION_SUSPENDED 0x8031_00c8
+	/// This PC is not provisioned to support device encryption.
 	FVE_E_DE_OS_VOLUME_NOT_PROTECTED 0x8031_00c9
+	/// Device Lock has been triggered due to too many incorrect password attempts.
 	FVE_E_DE_DEVICE_LOCKEDOUT 0x8031_00ca
+	/// Protection has not been enabled on the volume. Enabling protection requires a connected account. If you already have a connected account and are seeing this error, please refer to the event log for more information.
 	FVE_E_DE_PROTECTION_NOT_YET_ENABLED 0x8031_00cb
+	/// Your PIN can only contain numbers from 0 to 9.
 	FVE_E_INVALID_PIN_CHARS_DETAILED 0x8031_00cc
+	/// BitLocker cannot use hardware replay protection because no counter is available on your PC.
 	FVE_E_DEVICE_LOCKOUT_COUNTER_UNAVAILABLE 0x8031_00cd
+	/// Device Lockout state validation failed due to counter mismatch.
 	FVE_E_DEVICELOCKOUT_COUNTER_MISMATCH 0x8031_00ce
+	/// The input buffer is too large.
 	FVE_E_BUFFER_TOO_LARGE 0x8031_00cf
+	/// The target of an invocation does not support requested capability.
 	FVE_E_NO_SUCH_CAPABILITY_ON_TARGET 0x8031_00d0
+	/// Device encryption is currently blocked by this PC's configuration.
 	FVE_E_DE_PREVENTED_FOR_OS 0x8031_00d1
+	/// This drive has been opted out of device encryption.
 	FVE_E_DE_VOLUME_OPTED_OUT 0x8031_00d2
+	/// Device encryption isn't available for this drive.
 	FVE_E_DE_VOLUME_NOT_SUPPORTED 0x8031_00d3
+	/// The encrypt on write mode for BitLocker is not supported in this version of Windows. You can turn on BitLocker without using the encrypt on write mode.
 	FVE_E_EOW_NOT_SUPPORTED_IN_VERSION 0x8031_00d4
+	/// Group policy prevents you from backing up your recovery password to Active Directory for this drive type. For more info, contact your system administrator.
 	FVE_E_ADBACKUP_NOT_ENABLED 0x8031_00d5
+	/// Device encryption can't be turned off while this drive is being encrypted. Please try again later.
 	FVE_E_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT 0x8031_00d6
+	/// This action isn't supported because this drive isn't automatically managed with device encryption.
 	FVE_E_NOT_DE_VOLUME 0x8031_00d7
+	/// BitLocker can't be suspended on this drive until the next restart.
 	FVE_E_PROTECTION_CANNOT_BE_DISABLED 0x8031_00d8
+	/// BitLocker Drive Encryption policy does not allow KSR operation with protected OS volume.
 	FVE_E_OSV_KSR_NOT_ALLOWED 0x8031_00d9
+	/// Recovery key rotation cannot be performed because backup to Active Directory is not required by policy for operating system drives.
 	FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_OS_DRIVE 0x8031_00da
+	/// Recovery key rotation cannot be performed because backup to Active Directory is not required by policy for fixed data drives.
 	FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_FIXED_DRIVE 0x8031_00db
+	/// Recovery key rotation cannot be performed because backup to Active Directory is not required by policy for removable data drives.
 	FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_REMOVABLE_DRIVE 0x8031_00dc
+	/// Recovery key rotation is not supported for this drive.
 	FVE_E_KEY_ROTATION_NOT_SUPPORTED 0x8031_00dd
+	/// A request to rotate the recovery key was sent too soon after the previous request.
 	FVE_E_EXECUTE_REQUEST_SENT_TOO_SOON 0x8031_00de
+	/// Recovery key rotation is not enabled by policy.
 	FVE_E_KEY_ROTATION_NOT_ENABLED 0x8031_00df
+	/// Recovery key rotation cannot be performed because the device is not joined to Active Directory or Azure Active Directory.
 	FVE_E_DEVICE_NOT_JOINED 0x8031_00e0
+	/// The callout does not exist.
 	FWP_E_CALLOUT_NOT_FOUND 0x8032_0001
+	/// The filter condition does not exist.
 	FWP_E_CONDITION_NOT_FOUND 0x8032_0002
+	/// The filter does not exist.
 	FWP_E_FILTER_NOT_FOUND 0x8032_0003
+	/// The layer does not exist.
 	FWP_E_LAYER_NOT_FOUND 0x8032_0004
+	/// The provider does not exist.
 	FWP_E_PROVIDER_NOT_FOUND 0x8032_0005
+	/// The provider context does not exist.
 	FWP_E_PROVIDER_CONTEXT_NOT_FOUND 0x8032_0006
+	/// The sublayer does not exist.
 	FWP_E_SUBLAYER_NOT_FOUND 0x8032_0007
+	/// The object does not exist.
 	FWP_E_NOT_FOUND 0x8032_0008
+	/// An object with that GUID or LUID already exists.
 	FWP_E_ALREADY_EXISTS 0x8032_0009
+	/// The object is referenced by other objects so cannot be deleted.
 	FWP_E_IN_USE 0x8032_000a
+	/// The call is not allowed from within a dynamic session.
 	FWP_E_DYNAMIC_SESSION_IN_PROGRESS 0x8032_000b
+	/// The call was made from the wrong session so cannot be completed.
 	FWP_E_WRONG_SESSION 0x8032_000c
+	/// The call must be made from within an explicit transaction.
 	FWP_E_NO_TXN_IN_PROGRESS 0x8032_000d
+	/// The call is not allowed from within an explicit transaction.
 	FWP_E_TXN_IN_PROGRESS 0x8032_000e
+	/// The explicit transaction has been forcibly cancelled.
 	FWP_E_TXN_ABORTED 0x8032_000f
+	/// The session has been cancelled.
 	FWP_E_SESSION_ABORTED 0x8032_0010
+	/// The call is not allowed from within a read-only transaction.
 	FWP_E_INCOMPATIBLE_TXN 0x8032_0011
+	/// The call timed out while waiting to acquire the transaction lock.
 	FWP_E_TIMEOUT 0x8032_0012
+	/// Collection of network diagnostic events is disabled.
 	FWP_E_NET_EVENTS_DISABLED 0x8032_0013
+	/// The operation is not supported by the specified layer.
 	FWP_E_INCOMPATIBLE_LAYER 0x8032_0014
+	/// The call is allowed for kernel-mode callers only.
 	FWP_E_KM_CLIENTS_ONLY 0x8032_0015
+	/// The call tried to associate two objects with incompatible lifetimes.
 	FWP_E_LIFETIME_MISMATCH 0x8032_0016
+	/// The object is built in so cannot be deleted.
 	FWP_E_BUILTIN_OBJECT 0x8032_0017
+	/// The maximum number of callouts has been reached.
 	FWP_E_TOO_MANY_CALLOUTS 0x8032_0018
+	/// A notification could not be delivered because a message queue is at its maximum capacity.
 	FWP_E_NOTIFICATION_DROPPED 0x8032_0019
+	/// The traffic parameters do not match those for the security association context.
 	FWP_E_TRAFFIC_MISMATCH 0x8032_001a
+	/// The call is not allowed for the current security association state.
 	FWP_E_INCOMPATIBLE_SA_STATE 0x8032_001b
+	/// A required pointer is null.
 	FWP_E_NULL_POINTER 0x8032_001c
+	/// An enumerator is not valid.
 	FWP_E_INVALID_ENUMERATOR 0x8032_001d
+	/// The flags field contains an invalid value.
 	FWP_E_INVALID_FLAGS 0x8032_001e
+	/// A network mask is not valid.
 	FWP_E_INVALID_NET_MASK 0x8032_001f
+	/// An FWP_RANGE is not valid.
 	FWP_E_INVALID_RANGE 0x8032_0020
+	/// The time interval is not valid.
 	FWP_E_INVALID_INTERVAL 0x8032_0021
+	/// An array that must contain at least one element is zero length.
 	FWP_E_ZERO_LENGTH_ARRAY 0x8032_0022
+	/// The displayData.name field cannot be null.
 	FWP_E_NULL_DISPLAY_NAME 0x8032_0023
+	/// The action type is not one of the allowed action types for a filter.
 	FWP_E_INVALID_ACTION_TYPE 0x8032_0024
+	/// The filter weight is not valid.
 	FWP_E_INVALID_WEIGHT 0x8032_0025
+	/// A filter condition contains a match type that is not compatible with the operands.
 	FWP_E_MATCH_TYPE_MISMATCH 0x8032_0026
+	/// An FWP_VALUE or FWPM_CONDITION_VALUE is of the wrong type.
 	FWP_E_TYPE_MISMATCH 0x8032_0027
+	/// An integer value is outside the allowed range.
 	FWP_E_OUT_OF_BOUNDS 0x8032_0028
+	/// A reserved field is non-zero.
 	FWP_E_RESERVED 0x8032_0029
+	/// A filter cannot contain multiple conditions operating on a single field.
 	FWP_E_DUPLICATE_CONDITION 0x8032_002a
+	/// A policy cannot contain the same keying module more than once.
 	FWP_E_DUPLICATE_KEYMOD 0x8032_002b
+	/// The action type is not compatible with the layer.
 	FWP_E_ACTION_INCOMPATIBLE_WITH_LAYER 0x8032_002c
+	/// The action type is not compatible with the sublayer.
 	FWP_E_ACTION_INCOMPATIBLE_WITH_SUBLAYER 0x8032_002d
+	/// The raw context or the provider context is not compatible with the layer.
 	FWP_E_CONTEXT_INCOMPATIBLE_WITH_LAYER 0x8032_002e
+	/// The raw context or the provider context is not compatible with the callout.
 	FWP_E_CONTEXT_INCOMPATIBLE_WITH_CALLOUT 0x8032_002f
+	/// The authentication method is not compatible with the policy type.
 	FWP_E_INCOMPATIBLE_AUTH_METHOD 0x8032_0030
+	/// The Diffie-Hellman group is not compatible with the policy type.
 	FWP_E_INCOMPATIBLE_DH_GROUP 0x8032_0031
+	/// An IKE policy cannot contain an Extended Mode policy.
 	FWP_E_EM_NOT_SUPPORTED 0x8032_0032
+	/// The enumeration template or subscription will never match any objects.
 	FWP_E_NEVER_MATCH 0x8032_0033
+	/// The provider context is of the wrong type.
 	FWP_E_PROVIDER_CONTEXT_MISMATCH 0x8032_0034
+	/// The parameter is incorrect.
 	FWP_E_INVALID_PARAMETER 0x8032_0035
+	/// The maximum number of sublayers has been reached.
 	FWP_E_TOO_MANY_SUBLAYERS 0x8032_0036
+	/// The notification function for a callout returned an error.
 	FWP_E_CALLOUT_NOTIFICATION_FAILED 0x8032_0037
+	/// The IPsec authentication transform is not valid.
 	FWP_E_INVALID_AUTH_TRANSFORM 0x8032_0038
+	/// The IPsec cipher transform is not valid.
 	FWP_E_INVALID_CIPHER_TRANSFORM 0x8032_0039
+	/// The IPsec cipher transform is not compatible with the policy.
 	FWP_E_INCOMPATIBLE_CIPHER_TRANSFORM 0x8032_003a
+	/// The combination of IPsec transform types is not valid.
 	FWP_E_INVALID_TRANSFORM_COMBINATION 0x8032_003b
+	/// A policy cannot contain the same auth method more than once.
 	FWP_E_DUPLICATE_AUTH_METHOD 0x8032_003c
+	/// A tunnel endpoint configuration is invalid.
 	FWP_E_INVALID_TUNNEL_ENDPOINT 0x8032_003d
+	/// The WFP MAC Layers are not ready.
 	FWP_E_L2_DRIVER_NOT_READY 0x8032_003e
+	/// A key manager capable of key dictation is already registered.
 	FWP_E_KEY_DICTATOR_ALREADY_REGISTERED 0x8032_003f
+	/// A key manager dictated invalid keys.
 	FWP_E_KEY_DICTATION_INVALID_KEYING_MATERIAL 0x8032_0040
+	/// The BFE IPsec Connection Tracking is disabled.
 	FWP_E_CONNECTIONS_DISABLED 0x8032_0041
+	/// The DNS name is invalid.
 	FWP_E_INVALID_DNS_NAME 0x8032_0042
+	/// The engine option is still enabled due to other configuration settings.
 	FWP_E_STILL_ON 0x8032_0043
+	/// The IKEEXT service is not running. This service only runs when there is IPsec policy applied to the machine.
 	FWP_E_IKEEXT_NOT_RUNNING 0x8032_0044
+	/// The packet should be dropped, no ICMP should be sent.
 	FWP_E_DROP_NOICMP 0x8032_0104
+	/// The function call is completing asynchronously.
 	WS_S_ASYNC 0x003d_0000
+	/// There are no more messages available on the channel.
 	WS_S_END 0x003d_0001
+	/// The input data was not in the expected format or did not have the expected value.
 	WS_E_INVALID_FORMAT 0x803d_0000
+	/// The operation could not be completed because the object is in a faulted state due to a previous error.
 	WS_E_OBJECT_FAULTED 0x803d_0001
+	/// The operation could not be completed because it would lead to numeric overflow.
 	WS_E_NUMERIC_OVERFLOW 0x803d_0002
+	/// The operation is not allowed due to the current state of the object.
 	WS_E_INVALID_OPERATION 0x803d_0003
+	/// The operation was aborted.
 	WS_E_OPERATION_ABORTED 0x803d_0004
+	/// Access was denied by the remote endpoint.
 	WS_E_ENDPOINT_ACCESS_DENIED 0x803d_0005
+	/// The operation did not complete within the time allotted.
 	WS_E_OPERATION_TIMED_OUT 0x803d_0006
+	/// The operation was abandoned.
 	WS_E_OPERATION_ABANDONED 0x803d_0007
+	/// A quota was exceeded.
 	WS_E_QUOTA_EXCEEDED 0x803d_0008
+	/// The information was not available in the specified language.
 	WS_E_NO_TRANSLATION_AVAILABLE 0x803d_0009
+	/// Security verification was not successful for the received data.
 	WS_E_SECURITY_VERIFICATION_FAILURE 0x803d_000a
+	/// The address is already being used.
 	WS_E_ADDRESS_IN_USE 0x803d_000b
+	/// The address is not valid for this context.
 	WS_E_ADDRESS_NOT_AVAILABLE 0x803d_000c
+	/// The remote endpoint does not exist or could not be located.
 	WS_E_ENDPOINT_NOT_FOUND 0x803d_000d
+	/// The remote endpoint is not currently in service at this location.
 	WS_E_ENDPOINT_NOT_AVAILABLE 0x803d_000e
+	/// The remote endpoint could not process the request.
 	WS_E_ENDPOINT_FAILURE 0x803d_000f
+	/// The remote endpoint was not reachable.
 	WS_E_ENDPOINT_UNREACHABLE 0x803d_0010
+	/// The operation was not supported by the remote endpoint.
 	WS_E_ENDPOINT_ACTION_NOT_SUPPORTED 0x803d_0011
+	/// The remote endpoint is unable to process the request due to being overloaded.
 	WS_E_ENDPOINT_TOO_BUSY 0x803d_0012
+	/// A message containing a fault was received from the remote endpoint.
 	WS_E_ENDPOINT_FAULT_RECEIVED 0x803d_0013
+	/// The connection with the remote endpoint was terminated.
 	WS_E_ENDPOINT_DISCONNECTED 0x803d_0014
+	/// The HTTP proxy server could not process the request.
 	WS_E_PROXY_FAILURE 0x803d_0015
+	/// Access was denied by the HTTP proxy server.
 	WS_E_PROXY_ACCESS_DENIED 0x803d_0016
+	/// The requested feature is not available on this platform.
 	WS_E_NOT_SUPPORTED 0x803d_0017
+	/// The HTTP proxy server requires HTTP authentication scheme 'basic'.
 	WS_E_PROXY_REQUIRES_BASIC_AUTH 0x803d_0018
+	/// The HTTP proxy server requires HTTP authentication scheme 'digest'.
 	WS_E_PROXY_REQUIRES_DIGEST_AUTH 0x803d_0019
+	/// The HTTP proxy server requires HTTP authentication scheme 'NTLM'.
 	WS_E_PROXY_REQUIRES_NTLM_AUTH 0x803d_001a
+	/// The HTTP proxy server requires HTTP authentication scheme 'negotiate'.
 	WS_E_PROXY_REQUIRES_NEGOTIATE_AUTH 0x803d_001b
+	/// The remote endpoint requires HTTP authentication scheme 'basic'.
 	WS_E_SERVER_REQUIRES_BASIC_AUTH 0x803d_001c
+	/// The remote endpoint requires HTTP authentication scheme 'digest'.
 	WS_E_SERVER_REQUIRES_DIGEST_AUTH 0x803d_001d
+	/// The remote endpoint requires HTTP authentication scheme 'NTLM'.
 	WS_E_SERVER_REQUIRES_NTLM_AUTH 0x803d_001e
+	/// The remote endpoint requires HTTP authentication scheme 'negotiate'.
 	WS_E_SERVER_REQUIRES_NEGOTIATE_AUTH 0x803d_001f
+	/// The endpoint address URL is invalid.
 	WS_E_INVALID_ENDPOINT_URL 0x803d_0020
+	/// Unrecognized error occurred in the Windows Web Services framework.
 	WS_E_OTHER 0x803d_0021
+	/// A security token was rejected by the server because it has expired.
 	WS_E_SECURITY_TOKEN_EXPIRED 0x803d_0022
+	/// A security operation failed in the Windows Web Services framework.
 	WS_E_SECURITY_SYSTEM_FAILURE 0x803d_0023
+	/// The virtual machine or container exited unexpectedly while starting.
 	HCS_E_TERMINATED_DURING_START 0x8037_0100
+	/// The container operating system does not match the host operating system.
 	HCS_E_IMAGE_MISMATCH 0x8037_0101
+	/// The virtual machine could not be started because a required feature is not installed.
 	HCS_E_HYPERV_NOT_INSTALLED 0x8037_0102
+	/// The call to start an asynchronous operation succeeded and the completion handler was invoked with a result indicating that the operation is not valid in the current state.
 	HCS_E_INVALID_STATE 0x8037_0105
+	/// The virtual machine or container exited unexpectedly.
 	HCS_E_UNEXPECTED_EXIT 0x8037_0106
+	/// The virtual machine or container was forcefully exited.
 	HCS_E_TERMINATED 0x8037_0107
+	/// A connection could not be established with the container or virtual machine.
 	HCS_E_CONNECT_FAILED 0x8037_0108
+	/// The operation timed out because a response was not received from the virtual machine or container.
 	HCS_E_CONNECTION_TIMEOUT 0x8037_0109
+	/// The connection with the virtual machine or container was closed.
 	HCS_E_CONNECTION_CLOSED 0x8037_010a
+	/// An unknown internal message was received by the virtual machine or container.
 	HCS_E_UNKNOWN_MESSAGE 0x8037_010b
+	/// The virtual machine or container does not support an available version of the communication protocol with the host.
 	HCS_E_UNSUPPORTED_PROTOCOL_VERSION 0x8037_010c
+	/// The virtual machine or container JSON document is invalid.
 	HCS_E_INVALID_JSON 0x8037_010d
+	/// A virtual machine or container with the specified identifier does not exist.
 	HCS_E_SYSTEM_NOT_FOUND 0x8037_010e
+	/// A virtual machine or container with the specified identifier already exists.
 	HCS_E_SYSTEM_ALREADY_EXISTS 0x8037_010f
+	/// The virtual machine or container with the specified identifier is not running.
 	HCS_E_SYSTEM_ALREADY_STOPPED 0x8037_0110
+	/// A communication protocol error has occurred between the virtual machine or container and the host.
 	HCS_E_PROTOCOL_ERROR 0x8037_0111
+	/// The container image contains a layer with an unrecognized format.
 	HCS_E_INVALID_LAYER 0x8037_0112
+	/// To use this container image, you must join the Windows Insider Program.
 	HCS_E_WINDOWS_INSIDER_REQUIRED 0x8037_0113
+	/// The operation could not be started because a required feature is not installed.
 	HCS_E_SERVICE_NOT_AVAILABLE 0x8037_0114
+	/// The operation has not started.
 	HCS_E_OPERATION_NOT_STARTED 0x8037_0115
+	/// The operation is already running.
 	HCS_E_OPERATION_ALREADY_STARTED 0x8037_0116
+	/// The operation is still running.
 	HCS_E_OPERATION_PENDING 0x8037_0117
+	/// The operation did not complete in time.
 	HCS_E_OPERATION_TIMEOUT 0x8037_0118
+	/// An event callback has already been registered on this handle.
 	HCS_E_OPERATION_SYSTEM_CALLBACK_ALREADY_SET 0x8037_0119
+	/// Not enough memory available to return the result of the operation.
 	HCS_E_OPERATION_RESULT_ALLOCATION_FAILED 0x8037_011a
+	/// Insufficient privileges. Only administrators or users that are members of the Hyper-V Administrators user group are permitted to access virtual machines or containers.
 	HCS_E_ACCESS_DENIED 0x8037_011b
+	/// The virtual machine or container reported a critical error and was stopped or restarted.
 	HCS_E_GUEST_CRITICAL_ERROR 0x8037_011c
+	/// The specified capability does not exist.
 	WHV_E_UNKNOWN_CAPABILITY 0x8037_0300
+	/// The specified buffer is too small for the requested data.
 	WHV_E_INSUFFICIENT_BUFFER 0x8037_0301
+	/// The specified property does not exist.
 	WHV_E_UNKNOWN_PROPERTY 0x8037_0302
+	/// The configuration of the hypervisor on this system is not supported.
 	WHV_E_UNSUPPORTED_HYPERVISOR_CONFIG 0x8037_0303
+	/// The configuration of the partition is not valid.
 	WHV_E_INVALID_PARTITION_CONFIG 0x8037_0304
+	/// The specified GPA range was not found.
 	WHV_E_GPA_RANGE_NOT_FOUND 0x8037_0305
+	/// A virtual processor with the specified index already exists.
 	WHV_E_VP_ALREADY_EXISTS 0x8037_0306
+	/// A virtual processor with the specified index does not exist.
 	WHV_E_VP_DOES_NOT_EXIST 0x8037_0307
+	/// The virtual processor is not in the correct state to perform the requested operation.
 	WHV_E_INVALID_VP_STATE 0x8037_0308
+	/// A virtual processor register with the specified name does not exist.
 	WHV_E_INVALID_VP_REGISTER_NAME 0x8037_0309
+	/// The network was not found.
 	HCN_E_NETWORK_NOT_FOUND 0x803b_0001
+	/// The endpoint was not found.
 	HCN_E_ENDPOINT_NOT_FOUND 0x803b_0002
+	/// The network's underlying layer was not found.
 	HCN_E_LAYER_NOT_FOUND 0x803b_0003
+	/// The virtual switch was not found.
 	HCN_E_SWITCH_NOT_FOUND 0x803b_0004
+	/// The network does not have a subnet for this endpoint.
 	HCN_E_SUBNET_NOT_FOUND 0x803b_0005
+	/// An adapter was not found.
 	HCN_E_ADAPTER_NOT_FOUND 0x803b_0006
+	/// The switch-port was not found.
 	HCN_E_PORT_NOT_FOUND 0x803b_0007
+	/// An expected policy was not found.
 	HCN_E_POLICY_NOT_FOUND 0x803b_0008
+	/// A required VFP port setting was not found.
 	HCN_E_VFP_PORTSETTING_NOT_FOUND 0x803b_0009
+	/// The provided network configuration is invalid or missing parameters.
 	HCN_E_INVALID_NETWORK 0x803b_000a
+	/// Invalid network type.
 	HCN_E_INVALID_NETWORK_TYPE 0x803b_000b
+	/// The provided endpoint configuration is invalid or missing parameters.
 	HCN_E_INVALID_ENDPOINT 0x803b_000c
+	/// The provided policy configuration is invalid or missing parameters.
 	HCN_E_INVALID_POLICY 0x803b_000d
+	/// Invalid policy type.
 	HCN_E_INVALID_POLICY_TYPE 0x803b_000e
+	/// This requested operation is invalid for a remote endpoint.
 	HCN_E_INVALID_REMOTE_ENDPOINT_OPERATION 0x803b_000f
+	/// A network with this name already exists.
 	HCN_E_NETWORK_ALREADY_EXISTS 0x803b_0010
+	/// A network with this name already exists.
 	HCN_E_LAYER_ALREADY_EXISTS 0x803b_0011
+	/// Policy information already exists on this object.
 	HCN_E_POLICY_ALREADY_EXISTS 0x803b_0012
+	/// The specified port already exists.
 	HCN_E_PORT_ALREADY_EXISTS 0x803b_0013
+	/// This endpoint is already attached to the switch.
 	HCN_E_ENDPOINT_ALREADY_ATTACHED 0x803b_0014
+	/// The specified request is unsupported.
 	HCN_E_REQUEST_UNSUPPORTED 0x803b_0015
+	/// Port mapping is not supported on the given network.
 	HCN_E_MAPPING_NOT_SUPPORTED 0x803b_0016
+	/// There was an operation attempted on a degraded object.
 	HCN_E_DEGRADED_OPERATION 0x803b_0017
+	/// Cannot modify a switch shared by multiple networks.
 	HCN_E_SHARED_SWITCH_MODIFICATION 0x803b_0018
+	/// Failed to interpret a parameter as a GUID.
 	HCN_E_GUID_CONVERSION_FAILURE 0x803b_0019
+	/// Failed to process registry key.
 	HCN_E_REGKEY_FAILURE 0x803b_001a
+	/// Invalid JSON document string.
 	HCN_E_INVALID_JSON 0x803b_001b
+	/// The reference is invalid in the JSON document.
 	HCN_E_INVALID_JSON_REFERENCE 0x803b_001c
+	/// Endpoint sharing is disabled.
 	HCN_E_ENDPOINT_SHARING_DISABLED 0x803b_001d
+	/// IP address is either invalid or not part of any configured subnet(s).
 	HCN_E_INVALID_IP 0x803b_001e
+	/// The specified switch extension does not exist on this switch.
 	HCN_E_SWITCH_EXTENSION_NOT_FOUND 0x803b_001f
+	/// Operation cannot be performed while service is stopping.
 	HCN_E_MANAGER_STOPPED 0x803b_0020
+	/// The specified module was not found.
 	GCN_E_MODULE_NOT_FOUND 0x803b_0021
+	/// No request handlers were registered for the given type.
 	GCN_E_NO_REQUEST_HANDLERS 0x803b_0022
+	/// The specified request is unsupported.
 	GCN_E_REQUEST_UNSUPPORTED 0x803b_0023
+	/// Failed to add runtime keys.
 	GCN_E_RUNTIMEKEYS_FAILED 0x803b_0024
+	/// Timed out waiting for the network adapter.
 	GCN_E_NETADAPTER_TIMEOUT 0x803b_0025
+	/// The network adapter was not found.
 	GCN_E_NETADAPTER_NOT_FOUND 0x803b_0026
+	/// The network compartment was not found.
 	GCN_E_NETCOMPARTMENT_NOT_FOUND 0x803b_0027
+	/// The network interface was not found.
 	GCN_E_NETINTERFACE_NOT_FOUND 0x803b_0028
+	/// A default namespace already exists.
 	GCN_E_DEFAULTNAMESPACE_EXISTS 0x803b_0029
+	/// Internet Connection Sharing service (SharedAccess) is disabled and cannot be started.
 	HCN_E_ICS_DISABLED 0x803b_002a
+	/// This requested operation is invalid as endpoint is already part of a network namespace.
 	HCN_E_ENDPOINT_NAMESPACE_ALREADY_EXISTS 0x803b_002b
+	/// The specified entity cannot be removed while it still has references.
 	HCN_E_ENTITY_HAS_REFERENCES 0x803b_002c
+	/// The internal port must exist and cannot be zero.
 	HCN_E_INVALID_INTERNAL_PORT 0x803b_002d
+	/// The requested operation for attaching an endpoint to a namespace failed.
 	HCN_E_NAMESPACE_ATTACH_FAILED 0x803b_002e
+	/// An address provided is invalid or reserved.
 	HCN_E_ADDR_INVALID_OR_RESERVED 0x803b_002f
+	/// The notification channel has already been closed.
 	WPN_E_CHANNEL_CLOSED 0x803e_0100
+	/// The notification channel request did not complete successfully.
 	WPN_E_CHANNEL_REQUEST_NOT_COMPLETE 0x803e_0101
+	/// The application identifier provided is invalid.
 	WPN_E_INVALID_APP 0x803e_0102
+	/// A notification channel request for the provided application identifier is in progress.
 	WPN_E_OUTSTANDING_CHANNEL_REQUEST 0x803e_0103
+	/// The channel identifier is already tied to another application endpoint.
 	WPN_E_DUPLICATE_CHANNEL 0x803e_0104
+	/// The notification platform is unavailable.
 	WPN_E_PLATFORM_UNAVAILABLE 0x803e_0105
+	/// The notification has already been posted.
 	WPN_E_NOTIFICATION_POSTED 0x803e_0106
+	/// The notification has already been hidden.
 	WPN_E_NOTIFICATION_HIDDEN 0x803e_0107
+	/// The notification cannot be hidden until it has been shown.
 	WPN_E_NOTIFICATION_NOT_POSTED 0x803e_0108
+	/// Cloud notifications have been turned off.
 	WPN_E_CLOUD_DISABLED 0x803e_0109
+	/// The application does not have the cloud notification capability.
 	WPN_E_CLOUD_INCAPABLE 0x803e_0110
+	/// The notification platform is unable to retrieve the authentication credentials required to connect to the cloud notification service.
 	WPN_E_CLOUD_AUTH_UNAVAILABLE 0x803e_011a
+	/// The notification platform is unable to connect to the cloud notification service.
 	WPN_E_CLOUD_SERVICE_UNAVAILABLE 0x803e_011b
+	/// The notification platform is unable to initialize a callback for lock screen updates.
 	WPN_E_FAILED_LOCK_SCREEN_UPDATE_INTIALIZATION 0x803e_011c
+	/// Settings prevent the notification from being delivered.
 	WPN_E_NOTIFICATION_DISABLED 0x803e_0111
+	/// Application capabilities prevent the notification from being delivered.
 	WPN_E_NOTIFICATION_INCAPABLE 0x803e_0112
+	/// The application does not have the internet access capability.
 	WPN_E_INTERNET_INCAPABLE 0x803e_0113
+	/// Settings prevent the notification type from being delivered.
 	WPN_E_NOTIFICATION_TYPE_DISABLED 0x803e_0114
+	/// The size of the notification content is too large.
 	WPN_E_NOTIFICATION_SIZE 0x803e_0115
+	/// The size of the notification tag is too large.
 	WPN_E_TAG_SIZE 0x803e_0116
+	/// The notification platform doesn't have appropriate privilege on resources.
 	WPN_E_ACCESS_DENIED 0x803e_0117
+	/// The notification platform found application is already registered.
 	WPN_E_DUPLICATE_REGISTRATION 0x803e_0118
+	/// The application background task does not have the push notification capability.
 	WPN_E_PUSH_NOTIFICATION_INCAPABLE 0x803e_0119
+	/// The size of the developer id for scheduled notification is too large.
 	WPN_E_DEV_ID_SIZE 0x803e_0120
+	/// The notification tag is not alphanumeric.
 	WPN_E_TAG_ALPHANUMERIC 0x803e_012a
+	/// The notification platform has received invalid HTTP status code other than 2xx for polling.
 	WPN_E_INVALID_HTTP_STATUS_CODE 0x803e_012b
+	/// The notification platform has run out of presentation layer sessions.
 	WPN_E_OUT_OF_SESSION 0x803e_0200
+	/// The notification platform rejects image download request due to system in power save mode.
 	WPN_E_POWER_SAVE 0x803e_0201
+	/// The notification platform doesn't have the requested image in its cache.
 	WPN_E_IMAGE_NOT_FOUND_IN_CACHE 0x803e_0202
+	/// The notification platform cannot complete all of requested image.
 	WPN_E_ALL_URL_NOT_COMPLETED 0x803e_0203
+	/// A cloud image downloaded from the notification platform is invalid.
 	WPN_E_INVALID_CLOUD_IMAGE 0x803e_0204
+	/// Notification Id provided as filter is matched with what the notification platform maintains.
 	WPN_E_NOTIFICATION_ID_MATCHED 0x803e_0205
+	/// Notification callback interface is already registered.
 	WPN_E_CALLBACK_ALREADY_REGISTERED 0x803e_0206
+	/// Toast Notification was dropped without being displayed to the user.
 	WPN_E_TOAST_NOTIFICATION_DROPPED 0x803e_0207
+	/// The notification platform does not have the proper privileges to complete the request.
 	WPN_E_STORAGE_LOCKED 0x803e_0208
+	/// The size of the notification group is too large.
 	WPN_E_GROUP_SIZE 0x803e_0209
+	/// The notification group is not alphanumeric.
 	WPN_E_GROUP_ALPHANUMERIC 0x803e_020a
+	/// Cloud notifications have been disabled for the application due to a policy setting.
 	WPN_E_CLOUD_DISABLED_FOR_APP 0x803e_020b
+	/// Context is not activated.
 	E_MBN_CONTEXT_NOT_ACTIVATED 0x8054_8201
+	/// Bad SIM is inserted.
 	E_MBN_BAD_SIM 0x8054_8202
+	/// Requested data class is not available.
 	E_MBN_DATA_CLASS_NOT_AVAILABLE 0x8054_8203
+	/// Access point name (APN) or Access string is incorrect.
 	E_MBN_INVALID_ACCESS_STRING 0x8054_8204
+	/// Max activated contexts have reached.
 	E_MBN_MAX_ACTIVATED_CONTEXTS 0x8054_8205
+	/// Device is in packet detach state.
 	E_MBN_PACKET_SVC_DETACHED 0x8054_8206
+	/// Provider is not visible.
 	E_MBN_PROVIDER_NOT_VISIBLE 0x8054_8207
+	/// Radio is powered off.
 	E_MBN_RADIO_POWER_OFF 0x8054_8208
+	/// MBN subscription is not activated.
 	E_MBN_SERVICE_NOT_ACTIVATED 0x8054_8209
+	/// SIM is not inserted.
 	E_MBN_SIM_NOT_INSERTED 0x8054_820a
+	/// Voice call in progress.
 	E_MBN_VOICE_CALL_IN_PROGRESS 0x8054_820b
+	/// Visible provider cache is invalid.
 	E_MBN_INVALID_CACHE 0x8054_820c
+	/// Device is not registered.
 	E_MBN_NOT_REGISTERED 0x8054_820d
+	/// Providers not found.
 	E_MBN_PROVIDERS_NOT_FOUND 0x8054_820e
+	/// Pin is not supported.
 	E_MBN_PIN_NOT_SUPPORTED 0x8054_820f
+	/// Pin is required.
 	E_MBN_PIN_REQUIRED 0x8054_8210
+	/// PIN is disabled.
 	E_MBN_PIN_DISABLED 0x8054_8211
+	/// Generic Failure.
 	E_MBN_FAILURE 0x8054_8212
+	/// Profile is invalid.
 	E_MBN_INVALID_PROFILE 0x8054_8218
+	/// Default profile exist.
 	E_MBN_DEFAULT_PROFILE_EXIST 0x8054_8219
+	/// SMS encoding is not supported.
 	E_MBN_SMS_ENCODING_NOT_SUPPORTED 0x8054_8220
+	/// SMS filter is not supported.
 	E_MBN_SMS_FILTER_NOT_SUPPORTED 0x8054_8221
+	/// Invalid SMS memory index is used.
 	E_MBN_SMS_INVALID_MEMORY_INDEX 0x8054_8222
+	/// SMS language is not supported.
 	E_MBN_SMS_LANG_NOT_SUPPORTED 0x8054_8223
+	/// SMS memory failure occurred.
 	E_MBN_SMS_MEMORY_FAILURE 0x8054_8224
+	/// SMS network timeout happened.
 	E_MBN_SMS_NETWORK_TIMEOUT 0x8054_8225
+	/// Unknown SMSC address is used.
 	E_MBN_SMS_UNKNOWN_SMSC_ADDRESS 0x8054_8226
+	/// SMS format is not supported.
 	E_MBN_SMS_FORMAT_NOT_SUPPORTED 0x8054_8227
+	/// SMS operation is not allowed.
 	E_MBN_SMS_OPERATION_NOT_ALLOWED 0x8054_8228
+	/// Device SMS memory is full.
 	E_MBN_SMS_MEMORY_FULL 0x8054_8229
+	/// The IPv6 protocol is not installed.
 	PEER_E_IPV6_NOT_INSTALLED 0x8063_0001
+	/// The component has not been initialized.
 	PEER_E_NOT_INITIALIZED 0x8063_0002
+	/// The required service cannot be started.
 	PEER_E_CANNOT_START_SERVICE 0x8063_0003
+	/// The P2P protocol is not licensed to run on this OS.
 	PEER_E_NOT_LICENSED 0x8063_0004
+	/// The graph handle is invalid.
 	PEER_E_INVALID_GRAPH 0x8063_0010
+	/// The graph database name has changed.
 	PEER_E_DBNAME_CHANGED 0x8063_0011
+	/// A graph with the same ID already exists.
 	PEER_E_DUPLICATE_GRAPH 0x8063_0012
+	/// The graph is not ready.
 	PEER_E_GRAPH_NOT_READY 0x8063_0013
+	/// The graph is shutting down.
 	PEER_E_GRAPH_SHUTTING_DOWN 0x8063_0014
+	/// The graph is still in use.
 	PEER_E_GRAPH_IN_USE 0x8063_0015
+	/// The graph database is corrupt.
 	PEER_E_INVALID_DATABASE 0x8063_0016
+	/// Too many attributes have been used.
 	PEER_E_TOO_MANY_ATTRIBUTES 0x8063_0017
+	/// The connection can not be found.
 	PEER_E_CONNECTION_NOT_FOUND 0x8063_0103
+	/// The peer attempted to connect to itself.
 	PEER_E_CONNECT_SELF 0x8063_0106
+	/// The peer is already listening for connections.
 	PEER_E_ALREADY_LISTENING 0x8063_0107
+	/// The node was not found.
 	PEER_E_NODE_NOT_FOUND 0x8063_0108
+	/// The Connection attempt failed.
 	PEER_E_CONNECTION_FAILED 0x8063_0109
+	/// The peer connection could not be authenticated.
 	PEER_E_CONNECTION_NOT_AUTHENTICATED 0x8063_010a
+	/// The connection was refused.
 	PEER_E_CONNECTION_REFUSED 0x8063_010b
+	/// The peer name classifier is too long.
 	PEER_E_CLASSIFIER_TOO_LONG 0x8063_0201
+	/// The maximum number of identities have been created.
 	PEER_E_TOO_MANY_IDENTITIES 0x8063_0202
+	/// Unable to access a key.
 	PEER_E_NO_KEY_ACCESS 0x8063_0203
+	/// The group already exists.
 	PEER_E_GROUPS_EXIST 0x8063_0204
+	/// The requested record could not be found.
 	PEER_E_RECORD_NOT_FOUND 0x8063_0301
+	/// Access to the database was denied.
 	PEER_E_DATABASE_ACCESSDENIED 0x8063_0302
+	/// The Database could not be initialized.
 	PEER_E_DBINITIALIZATION_FAILED 0x8063_0303
+	/// The record is too big.
 	PEER_E_MAX_RECORD_SIZE_EXCEEDED 0x8063_0304
+	/// The database already exists.
 	PEER_E_DATABASE_ALREADY_PRESENT 0x8063_0305
+	/// The database could not be found.
 	PEER_E_DATABASE_NOT_PRESENT 0x8063_0306
+	/// The identity could not be found.
 	PEER_E_IDENTITY_NOT_FOUND 0x8063_0401
+	/// The event handle could not be found.
 	PEER_E_EVENT_HANDLE_NOT_FOUND 0x8063_0501
+	/// Invalid search.
 	PEER_E_INVALID_SEARCH 0x8063_0601
+	/// The search attributes are invalid.
 	PEER_E_INVALID_ATTRIBUTES 0x8063_0602
+	/// The invitation is not trusted.
 	PEER_E_INVITATION_NOT_TRUSTED 0x8063_0701
+	/// The certchain is too long.
 	PEER_E_CHAIN_TOO_LONG 0x8063_0703
+	/// The time period is invalid.
 	PEER_E_INVALID_TIME_PERIOD 0x8063_0705
+	/// A circular cert chain was detected.
 	PEER_E_CIRCULAR_CHAIN_DETECTED 0x8063_0706
+	/// The certstore is corrupted.
 	PEER_E_CERT_STORE_CORRUPTED 0x8063_0801
+	/// The specified PNRP cloud does not exist.
 	PEER_E_NO_CLOUD 0x8063_1001
+	/// The cloud name is ambiguous.
 	PEER_E_CLOUD_NAME_AMBIGUOUS 0x8063_1005
+	/// The record is invalid.
 	PEER_E_INVALID_RECORD 0x8063_2010
+	/// Not authorized.
 	PEER_E_NOT_AUTHORIZED 0x8063_2020
+	/// The password does not meet policy requirements.
 	PEER_E_PASSWORD_DOES_NOT_MEET_POLICY 0x8063_2021
+	/// The record validation has been deferred.
 	PEER_E_DEFERRED_VALIDATION 0x8063_2030
+	/// The group properties are invalid.
 	PEER_E_INVALID_GROUP_PROPERTIES 0x8063_2040
+	/// The peername is invalid.
 	PEER_E_INVALID_PEER_NAME 0x8063_2050
+	/// The classifier is invalid.
 	PEER_E_INVALID_CLASSIFIER 0x8063_2060
+	/// The friendly name is invalid.
 	PEER_E_INVALID_FRIENDLY_NAME 0x8063_2070
+	/// Invalid role property.
 	PEER_E_INVALID_ROLE_PROPERTY 0x8063_2071
+	/// Invalid classifier property.
 	PEER_E_INVALID_CLASSIFIER_PROPERTY 0x8063_2072
+	/// Invalid record expiration.
 	PEER_E_INVALID_RECORD_EXPIRATION 0x8063_2080
+	/// Invalid credential info.
 	PEER_E_INVALID_CREDENTIAL_INFO 0x8063_2081
+	/// Invalid credential.
 	PEER_E_INVALID_CREDENTIAL 0x8063_2082
+	/// Invalid record size.
 	PEER_E_INVALID_RECORD_SIZE 0x8063_2083
+	/// Unsupported version.
 	PEER_E_UNSUPPORTED_VERSION 0x8063_2090
+	/// The group is not ready.
 	PEER_E_GROUP_NOT_READY 0x8063_2091
+	/// The group is in use.
 	PEER_E_GROUP_IN_USE 0x8063_2092
+	/// The group is invalid.
 	PEER_E_INVALID_GROUP 0x8063_2093
+	/// No members were found.
 	PEER_E_NO_MEMBERS_FOUND 0x8063_2094
+	/// There are no member connections.
 	PEER_E_NO_MEMBER_CONNECTIONS 0x8063_2095
+	/// Unable to listen.
 	PEER_E_UNABLE_TO_LISTEN 0x8063_2096
+	/// The identity does not exist.
 	PEER_E_IDENTITY_DELETED 0x8063_20a0
+	/// The service is not available.
 	PEER_E_SERVICE_NOT_AVAILABLE 0x8063_20a1
+	/// The contact could not be found.
 	PEER_E_CONTACT_NOT_FOUND 0x8063_6001
+	/// The graph data was created.
 	PEER_S_GRAPH_DATA_CREATED 0x0063_0001
+	/// There is not more event data.
 	PEER_S_NO_EVENT_DATA 0x0063_0002
+	/// The graph is already connected.
 	PEER_S_ALREADY_CONNECTED 0x0063_2000
+	/// The subscription already exists.
 	PEER_S_SUBSCRIPTION_EXISTS 0x0063_6000
+	/// No connectivity.
 	PEER_S_NO_CONNECTIVITY 0x0063_0005
+	/// Already a member.
 	PEER_S_ALREADY_A_MEMBER 0x0063_0006
+	/// The peername could not be converted to a DNS pnrp name.
 	PEER_E_CANNOT_CONVERT_PEER_NAME 0x8063_4001
+	/// Invalid peer host name.
 	PEER_E_INVALID_PEER_HOST_NAME 0x8063_4002
+	/// No more data could be found.
 	PEER_E_NO_MORE 0x8063_4003
+	/// The existing peer name is already registered.
 	PEER_E_PNRP_DUPLICATE_PEER_NAME 0x8063_4005
+	/// The app invite request was cancelled by the user.
 	PEER_E_INVITE_CANCELLED 0x8063_7000
+	/// No response of the invite was received.
 	PEER_E_INVITE_RESPONSE_NOT_AVAILABLE 0x8063_7001
+	/// User is not signed into serverless presence.
 	PEER_E_NOT_SIGNED_IN 0x8063_7003
+	/// The user declined the privacy policy prompt.
 	PEER_E_PRIVACY_DECLINED 0x8063_7004
+	/// A timeout occurred.
 	PEER_E_TIMEOUT 0x8063_7005
+	/// The address is invalid.
 	PEER_E_INVALID_ADDRESS 0x8063_7007
+	/// A required firewall exception is disabled.
 	PEER_E_FW_EXCEPTION_DISABLED 0x8063_7008
+	/// The service is blocked by a firewall policy.
 	PEER_E_FW_BLOCKED_BY_POLICY 0x8063_7009
+	/// Firewall exceptions are disabled.
 	PEER_E_FW_BLOCKED_BY_SHIELDS_UP 0x8063_700a
+	/// The user declined to enable the firewall exceptions.
 	PEER_E_FW_DECLINED 0x8063_700b
+	/// The object could not be created.
 	UI_E_CREATE_FAILED 0x802a_0001
+	/// Shutdown was already called on this object or the object that owns it.
 	UI_E_SHUTDOWN_CALLED 0x802a_0002
+	/// This method cannot be called during this type of callback.
 	UI_E_ILLEGAL_REENTRANCY 0x802a_0003
+	/// This object has been sealed, so this change is no longer allowed.
 	UI_E_OBJECT_SEALED 0x802a_0004
+	/// The requested value was never set.
 	UI_E_VALUE_NOT_SET 0x802a_0005
+	/// The requested value cannot be determined.
 	UI_E_VALUE_NOT_DETERMINED 0x802a_0006
+	/// A callback returned an invalid output parameter.
 	UI_E_INVALID_OUTPUT 0x802a_0007
+	/// A callback returned a success code other than S_OK or S_FALSE.
 	UI_E_BOOLEAN_EXPECTED 0x802a_0008
+	/// A parameter that should be owned by this object is owned by a different object.
 	UI_E_DIFFERENT_OWNER 0x802a_0009
+	/// More than one item matched the search criteria.
 	UI_E_AMBIGUOUS_MATCH 0x802a_000a
+	/// A floating-point overflow occurred.
 	UI_E_FP_OVERFLOW 0x802a_000b
+	/// This method can only be called from the thread that created the object.
 	UI_E_WRONG_THREAD 0x802a_000c
+	/// The storyboard is currently in the schedule.
 	UI_E_STORYBOARD_ACTIVE 0x802a_0101
+	/// The storyboard is not playing.
 	UI_E_STORYBOARD_NOT_PLAYING 0x802a_0102
+	/// The start keyframe might occur after the end keyframe.
 	UI_E_START_KEYFRAME_AFTER_END 0x802a_0103
+	/// It might not be possible to determine the end keyframe time when the start keyframe is reached.
 	UI_E_END_KEYFRAME_NOT_DETERMINED 0x802a_0104
+	/// Two repeated portions of a storyboard might overlap.
 	UI_E_LOOPS_OVERLAP 0x802a_0105
+	/// The transition has already been added to a storyboard.
 	UI_E_TRANSITION_ALREADY_USED 0x802a_0106
+	/// The transition has not been added to a storyboard.
 	UI_E_TRANSITION_NOT_IN_STORYBOARD 0x802a_0107
+	/// The transition might eclipse the beginning of another transition in the storyboard.
 	UI_E_TRANSITION_ECLIPSED 0x802a_0108
+	/// The given time is earlier than the time passed to the last update.
 	UI_E_TIME_BEFORE_LAST_UPDATE 0x802a_0109
+	/// This client is already connected to a timer.
 	UI_E_TIMER_CLIENT_ALREADY_CONNECTED 0x802a_010a
+	/// The passed dimension is invalid or does not match the object's dimension.
 	UI_E_INVALID_DIMENSION 0x802a_010b
+	/// The added primitive begins at or beyond the duration of the interpolator.
 	UI_E_PRIMITIVE_OUT_OF_BOUNDS 0x802a_010c
+	/// The operation cannot be completed because the window is being closed.
 	UI_E_WINDOW_CLOSED 0x802a_0201
+	/// The attribute handle given was not valid on this server.
 	E_BLUETOOTH_ATT_INVALID_HANDLE 0x8065_0001
+	/// The attribute cannot be read.
 	E_BLUETOOTH_ATT_READ_NOT_PERMITTED 0x8065_0002
+	/// The attribute cannot be written.
 	E_BLUETOOTH_ATT_WRITE_NOT_PERMITTED 0x8065_0003
+	/// The attribute PDU was invalid.
 	E_BLUETOOTH_ATT_INVALID_PDU 0x8065_0004
+	/// The attribute requires authentication before it can be read or written.
 	E_BLUETOOTH_ATT_INSUFFICIENT_AUTHENTICATION 0x8065_0005
+	/// Attribute server does not support the request received from the client.
 	E_BLUETOOTH_ATT_REQUEST_NOT_SUPPORTED 0x8065_0006
+	/// Offset specified was past the end of the attribute.
 	E_BLUETOOTH_ATT_INVALID_OFFSET 0x8065_0007
+	/// The attribute requires authorization before it can be read or written.
 	E_BLUETOOTH_ATT_INSUFFICIENT_AUTHORIZATION 0x8065_0008
+	/// Too many prepare writes have been queued.
 	E_BLUETOOTH_ATT_PREPARE_QUEUE_FULL 0x8065_0009
+	/// No attribute found within the given attribute handle range.
 	E_BLUETOOTH_ATT_ATTRIBUTE_NOT_FOUND 0x8065_000a
+	/// The attribute cannot be read or written using the Read Blob Request.
 	E_BLUETOOTH_ATT_ATTRIBUTE_NOT_LONG 0x8065_000b
+	/// The Encryption Key Size used for encrypting this link is insufficient.
 	E_BLUETOOTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE 0x8065_000c
+	/// The attribute value length is invalid for the operation.
 	E_BLUETOOTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH 0x8065_000d
+	/// The attribute request that was requested has encountered an error that was unlikely, and therefore could not be completed as requested.
 	E_BLUETOOTH_ATT_UNLIKELY 0x8065_000e
+	/// The attribute requires encryption before it can be read or written.
 	E_BLUETOOTH_ATT_INSUFFICIENT_ENCRYPTION 0x8065_000f
+	/// The attribute type is not a supported grouping attribute as defined by a higher layer specification.
 	E_BLUETOOTH_ATT_UNSUPPORTED_GROUP_TYPE 0x8065_0010
+	/// Insufficient Resources to complete the request.
 	E_BLUETOOTH_ATT_INSUFFICIENT_RESOURCES 0x8065_0011
+	/// An error that lies in the reserved range has been received.
 	E_BLUETOOTH_ATT_UNKNOWN_ERROR 0x8065_1000
+	/// PortCls could not find an audio engine node exposed by a miniport driver claiming support for IMiniportAudioEngineNode.
 	E_AUDIO_ENGINE_NODE_NOT_FOUND 0x8066_0001
+	/// HD Audio widget encountered an unexpected empty connection list.
 	E_HDAUDIO_EMPTY_CONNECTION_LIST 0x8066_0002
+	/// HD Audio widget does not support the connection list parameter.
 	E_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED 0x8066_0003
+	/// No HD Audio subdevices were successfully created.
 	E_HDAUDIO_NO_LOGICAL_DEVICES_CREATED 0x8066_0004
+	/// An unexpected NULL pointer was encountered in a linked list.
 	E_HDAUDIO_NULL_LINKED_LIST_ENTRY 0x8066_0005
+	/// Optimistic locking failure. Data cannot be updated if it has changed since it was read.
 	STATEREPOSITORY_E_CONCURRENCY_LOCKING_FAILURE 0x8067_0001
+	/// A prepared statement has been stepped at least once but not run to completion or reset. This may result in busy waits.
 	STATEREPOSITORY_E_STATEMENT_INPROGRESS 0x8067_0002
+	/// The StateRepository configuration is not valid.
 	STATEREPOSITORY_E_CONFIGURATION_INVALID 0x8067_0003
+	/// The StateRepository schema version is not known.
 	STATEREPOSITORY_E_UNKNOWN_SCHEMA_VERSION 0x8067_0004
+	/// A StateRepository dictionary is not valid.
 	STATEREPOSITORY_ERROR_DICTIONARY_CORRUPTED 0x8067_0005
+	/// The request failed because the StateRepository is actively blocking requests.
 	STATEREPOSITORY_E_BLOCKED 0x8067_0006
+	/// The database file is locked. The request will be retried.
 	STATEREPOSITORY_E_BUSY_RETRY 0x8067_0007
+	/// The database file is locked because another process is busy recovering the database. The request will be retried.
 	STATEREPOSITORY_E_BUSY_RECOVERY_RETRY 0x8067_0008
+	/// A table in the database is locked. The request will be retried.
 	STATEREPOSITORY_E_LOCKED_RETRY 0x8067_0009
+	/// The shared cache for the database is locked by another connection. The request will be retried.
 	STATEREPOSITORY_E_LOCKED_SHAREDCACHE_RETRY 0x8067_000a
+	/// A transaction is required to perform the request operation.
 	STATEREPOSITORY_E_TRANSACTION_REQUIRED 0x8067_000b
+	/// The database file is locked. The request has exceeded the allowed threshold.
 	STATEREPOSITORY_E_BUSY_TIMEOUT_EXCEEDED 0x8067_000c
+	/// The database file is locked because another process is busy recovering the database. The request has exceeded the allowed threshold.
 	STATEREPOSITORY_E_BUSY_RECOVERY_TIMEOUT_EXCEEDED 0x8067_000d
+	/// A table in the database is locked. The request has exceeded the allowed threshold.
 	STATEREPOSITORY_E_LOCKED_TIMEOUT_EXCEEDED 0x8067_000e
+	/// The shared cache for the database is locked by another connection. The request has exceeded the allowed threshold.
 	STATEREPOSITORY_E_LOCKED_SHAREDCACHE_TIMEOUT_EXCEEDED 0x8067_000f
+	/// The StateRepository service Stop event is in progress.
 	STATEREPOSITORY_E_SERVICE_STOP_IN_PROGRESS 0x8067_0010
+	/// Nested transactions are not supported.
 	STATEREPOSTORY_E_NESTED_TRANSACTION_NOT_SUPPORTED 0x8067_0011
+	/// The StateRepository cache is not valid.
 	STATEREPOSITORY_ERROR_CACHE_CORRUPTED 0x8067_0012
+	/// The transaction caller id has changed.
 	STATEREPOSITORY_TRANSACTION_CALLER_ID_CHANGED 0x0067_0013
+	/// A transaction is in progress for the database connection.
 	STATEREPOSITORY_TRANSACTION_IN_PROGRESS 0x0067_0014
+	/// The storage pool was deleted by the driver. The object cache should be updated.
 	ERROR_SPACES_POOL_WAS_DELETED 0x00e7_0001
+	/// The specified fault domain type or combination of minimum / maximum fault domain type is not supported.
 	ERROR_SPACES_FAULT_DOMAIN_TYPE_INVALID 0x80e7_0001
+	/// A Storage Spaces internal error occurred.
 	ERROR_SPACES_INTERNAL_ERROR 0x80e7_0002
+	/// The specified resiliency type is not supported.
 	ERROR_SPACES_RESILIENCY_TYPE_INVALID 0x80e7_0003
+	/// The physical disk's sector size is not supported by the storage pool.
 	ERROR_SPACES_DRIVE_SECTOR_SIZE_INVALID 0x80e7_0004
+	/// The requested redundancy is outside of the supported range of values.
 	ERROR_SPACES_DRIVE_REDUNDANCY_INVALID 0x80e7_0006
+	/// The number of data copies requested is outside of the supported range of values.
 	ERROR_SPACES_NUMBER_OF_DATA_COPIES_INVALID 0x80e7_0007
+	/// The value for ParityLayout is outside of the supported range of values.
 	ERROR_SPACES_PARITY_LAYOUT_INVALID 0x80e7_0008
+	/// The value for interleave length is outside of the supported range of values or is not a power of 2.
 	ERROR_SPACES_INTERLEAVE_LENGTH_INVALID 0x80e7_0009
+	/// The number of columns specified is outside of the supported range of values.
 	ERROR_SPACES_NUMBER_OF_COLUMNS_INVALID 0x80e7_000a
+	/// There were not enough physical disks to complete the requested operation.
 	ERROR_SPACES_NOT_ENOUGH_DRIVES 0x80e7_000b
+	/// Extended error information is available.
 	ERROR_SPACES_EXTENDED_ERROR 0x80e7_000c
+	/// The specified provisioning type is not supported.
 	ERROR_SPACES_PROVISIONING_TYPE_INVALID 0x80e7_000d
+	/// The allocation size is outside of the supported range of values.
 	ERROR_SPACES_ALLOCATION_SIZE_INVALID 0x80e7_000e
+	/// Enclosure awareness is not supported for this virtual disk.
 	ERROR_SPACES_ENCLOSURE_AWARE_INVALID 0x80e7_000f
+	/// The write cache size is outside of the supported range of values.
 	ERROR_SPACES_WRITE_CACHE_SIZE_INVALID 0x80e7_0010
+	/// The value for number of groups is outside of the supported range of values.
 	ERROR_SPACES_NUMBER_OF_GROUPS_INVALID 0x80e7_0011
+	/// The OperationalState of the physical disk is invalid for this operation.
 	ERROR_SPACES_DRIVE_OPERATIONAL_STATE_INVALID 0x80e7_0012
+	/// The specified log entry is not complete.
 	ERROR_SPACES_ENTRY_INCOMPLETE 0x80e7_0013
+	/// The specified log entry is not valid.
 	ERROR_SPACES_ENTRY_INVALID 0x80e7_0014
+	/// The bootfile is too small to support persistent snapshots.
 	ERROR_VOLSNAP_BOOTFILE_NOT_VALID 0x8082_0001
+	/// Activation of persistent snapshots on this volume took longer than was allowed.
 	ERROR_VOLSNAP_ACTIVATION_TIMEOUT 0x8082_0002
+	/// The specified volume does not support storage tiers.
 	ERROR_TIERING_NOT_SUPPORTED_ON_VOLUME 0x8083_0001
+	/// The Storage Tiers Management service detected that the specified volume is in the process of being dismounted.
 	ERROR_TIERING_VOLUME_DISMOUNT_IN_PROGRESS 0x8083_0002
+	/// The specified storage tier could not be found on the volume. Confirm that the storage tier name is valid.
 	ERROR_TIERING_STORAGE_TIER_NOT_FOUND 0x8083_0003
+	/// The file identifier specified is not valid on the volume.
 	ERROR_TIERING_INVALID_FILE_ID 0x8083_0004
+	/// Storage tier operations must be called on the clustering node that owns the metadata volume.
 	ERROR_TIERING_WRONG_CLUSTER_NODE 0x8083_0005
+	/// The Storage Tiers Management service is already optimizing the storage tiers on the specified volume.
 	ERROR_TIERING_ALREADY_PROCESSING 0x8083_0006
+	/// The requested object type cannot be assigned to a storage tier.
 	ERROR_TIERING_CANNOT_PIN_OBJECT 0x8083_0007
+	/// The requested file is not pinned to a tier.
 	ERROR_TIERING_FILE_IS_NOT_PINNED 0x8083_0008
+	/// The volume is not a tiered volume.
 	ERROR_NOT_A_TIERED_VOLUME 0x8083_0009
+	/// The requested attribute is not present on the specified file or directory.
 	ERROR_ATTRIBUTE_NOT_PRESENT 0x8083_000a
+	/// The command was not recognized by the security core.
 	ERROR_SECCORE_INVALID_COMMAND 0xc0e8_0000
+	/// No applicable app licenses found.
 	ERROR_NO_APPLICABLE_APP_LICENSES_FOUND 0xc0ea_0001
+	/// CLiP license not found.
 	ERROR_CLIP_LICENSE_NOT_FOUND 0xc0ea_0002
+	/// CLiP device license not found.
 	ERROR_CLIP_DEVICE_LICENSE_MISSING 0xc0ea_0003
+	/// CLiP license has an invalid signature.
 	ERROR_CLIP_LICENSE_INVALID_SIGNATURE 0xc0ea_0004
+	/// CLiP keyholder license is invalid or missing.
 	ERROR_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID 0xc0ea_0005
+	/// CLiP license has expired.
 	ERROR_CLIP_LICENSE_EXPIRED 0xc0ea_0006
+	/// CLiP license is signed by an unknown source.
 	ERROR_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE 0xc0ea_0007
+	/// CLiP license is not signed.
 	ERROR_CLIP_LICENSE_NOT_SIGNED 0xc0ea_0008
+	/// CLiP license hardware ID is out of tolerance.
 	ERROR_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE 0xc0ea_0009
+	/// CLiP license device ID does not match the device ID in the bound device license.
 	ERROR_CLIP_LICENSE_DEVICE_ID_MISMATCH 0xc0ea_000a
+	/// There are too many unique state objects.
 	D3D10_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS 0x8879_0001
+	/// File not found.
 	D3D10_ERROR_FILE_NOT_FOUND 0x8879_0002
+	/// There are too many unique state objects.
 	D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS 0x887c_0001
+	/// File not found.
 	D3D11_ERROR_FILE_NOT_FOUND 0x887c_0002
+	/// Therea are too many unique view objects.
 	D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS 0x887c_0003
+	/// Deferred context requires Map-Discard usage pattern.
 	D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD 0x887c_0004
+	/// The blob provided does not match the adapter that the device was created on.
 	D3D12_ERROR_ADAPTER_NOT_FOUND 0x887e_0001
+	/// The blob provided was created for a different version of the driver, and must be re-created.
 	D3D12_ERROR_DRIVER_VERSION_MISMATCH 0x887e_0002
+	/// The object was not in the correct state to process the method.
 	D2DERR_WRONG_STATE 0x8899_0001
+	/// The object has not yet been initialized.
 	D2DERR_NOT_INITIALIZED 0x8899_0002
+	/// The requested operation is not supported.
 	D2DERR_UNSUPPORTED_OPERATION 0x8899_0003
+	/// The geometry scanner failed to process the data.
 	D2DERR_SCANNER_FAILED 0x8899_0004
+	/// Direct2D could not access the screen.
 	D2DERR_SCREEN_ACCESS_DENIED 0x8899_0005
+	/// A valid display state could not be determined.
 	D2DERR_DISPLAY_STATE_INVALID 0x8899_0006
+	/// The supplied vector is zero.
 	D2DERR_ZERO_VECTOR 0x8899_0007
+	/// An internal error (Direct2D bug) occurred. On checked builds, we would assert. The application should close this instance of Direct2D and should consider restarting its process.
 	D2DERR_INTERNAL_ERROR 0x8899_0008
+	/// The display format Direct2D needs to render is not supported by the hardware device.
 	D2DERR_DISPLAY_FORMAT_NOT_SUPPORTED 0x8899_0009
+	/// A call to this method is invalid.
 	D2DERR_INVALID_CALL 0x8899_000a
+	/// No hardware rendering device is available for this operation.
 	D2DERR_NO_HARDWARE_DEVICE 0x8899_000b
+	/// There has been a presentation error that may be recoverable. The caller needs to recreate, rerender the entire frame, and reattempt present.
 	D2DERR_RECREATE_TARGET 0x8899_000c
+	/// Shader construction failed because it was too complex.
 	D2DERR_TOO_MANY_SHADER_ELEMENTS 0x8899_000d
+	/// Shader compilation failed.
 	D2DERR_SHADER_COMPILE_FAILED 0x8899_000e
+	/// Requested DirectX surface size exceeded maximum texture size.
 	D2DERR_MAX_TEXTURE_SIZE_EXCEEDED 0x8899_000f
+	/// The requested Direct2D version is not supported.
 	D2DERR_UNSUPPORTED_VERSION 0x8899_0010
+	/// Invalid number.
 	D2DERR_BAD_NUMBER 0x8899_0011
+	/// Objects used together must be created from the same factory instance.
 	D2DERR_WRONG_FACTORY 0x8899_0012
+	/// A layer resource can only be in use once at any point in time.
 	D2DERR_LAYER_ALREADY_IN_USE 0x8899_0013
+	/// The pop call did not match the corresponding push call.
 	D2DERR_POP_CALL_DID_NOT_MATCH_PUSH 0x8899_0014
+	/// The resource was realized on the wrong render target.
 	D2DERR_WRONG_RESOURCE_DOMAIN 0x8899_0015
+	/// The push and pop calls were unbalanced.
 	D2DERR_PUSH_POP_UNBALANCED 0x8899_0016
+	/// Attempt to copy from a render target while a layer or clip rect is applied.
 	D2DERR_RENDER_TARGET_HAS_LAYER_OR_CLIPRECT 0x8899_0017
+	/// The brush types are incompatible for the call.
 	D2DERR_INCOMPATIBLE_BRUSH_TYPES 0x8899_0018
+	/// An unknown win32 failure occurred.
 	D2DERR_WIN32_ERROR 0x8899_0019
+	/// The render target is not compatible with GDI.
 	D2DERR_TARGET_NOT_GDI_COMPATIBLE 0x8899_001a
+	/// A text client drawing effect object is of the wrong type.
 	D2DERR_TEXT_EFFECT_IS_WRONG_TYPE 0x8899_001b
+	/// The application is holding a reference to the IDWriteTextRenderer interface after the corresponding DrawText or DrawTextLayout call has returned. The IDWriteTextRenderer instance will be invalid.
 	D2DERR_TEXT_RENDERER_NOT_RELEASED 0x8899_001c
+	/// The requested size is larger than the guaranteed supported texture size at the Direct3D device's current feature level.
 	D2DERR_EXCEEDS_MAX_BITMAP_SIZE 0x8899_001d
+	/// There was a configuration error in the graph.
 	D2DERR_INVALID_GRAPH_CONFIGURATION 0x8899_001e
+	/// There was a internal configuration error in the graph.
 	D2DERR_INVALID_INTERNAL_GRAPH_CONFIGURATION 0x8899_001f
+	/// There was a cycle in the graph.
 	D2DERR_CYCLIC_GRAPH 0x8899_0020
+	/// Cannot draw with a bitmap that has the D2D1_BITMAP_OPTIONS_CANNOT_DRAW option.
 	D2DERR_BITMAP_CANNOT_DRAW 0x8899_0021
+	/// The operation cannot complete while there are outstanding references to the target bitmap.
 	D2DERR_OUTSTANDING_BITMAP_REFERENCES 0x8899_0022
+	/// The operation failed because the original target is not currently bound as a target.
 	D2DERR_ORIGINAL_TARGET_NOT_BOUND 0x8899_0023
+	/// Cannot set the image as a target because it is either an effect or is a bitmap that does not have the D2D1_BITMAP_OPTIONS_TARGET flag set.
 	D2DERR_INVALID_TARGET 0x8899_0024
+	/// Cannot draw with a bitmap that is currently bound as the target bitmap.
 	D2DERR_BITMAP_BOUND_AS_TARGET 0x8899_0025
+	/// D3D Device does not have sufficient capabilities to perform the requested action.
 	D2DERR_INSUFFICIENT_DEVICE_CAPABILITIES 0x8899_0026
+	/// The graph could not be rendered with the context's current tiling settings.
 	D2DERR_INTERMEDIATE_TOO_LARGE 0x8899_0027
+	/// The CLSID provided to Unregister did not correspond to a registered effect.
 	D2DERR_EFFECT_IS_NOT_REGISTERED 0x8899_0028
+	/// The specified property does not exist.
 	D2DERR_INVALID_PROPERTY 0x8899_0029
+	/// The specified sub-property does not exist.
 	D2DERR_NO_SUBPROPERTIES 0x8899_002a
+	/// AddPage or Close called after print job is already closed.
 	D2DERR_PRINT_JOB_CLOSED 0x8899_002b
+	/// Error during print control creation. Indicates that none of the package target types (representing printer formats) are supported by Direct2D print control.
 	D2DERR_PRINT_FORMAT_NOT_SUPPORTED 0x8899_002c
+	/// An effect attempted to use a transform with too many inputs.
 	D2DERR_TOO_MANY_TRANSFORM_INPUTS 0x8899_002d
+	/// An error was encountered while decoding or parsing the requested glyph image.
 	D2DERR_INVALID_GLYPH_IMAGE 0x8899_002e
+	/// Indicates an error in an input file such as a font file.
 	DWRITE_E_FILEFORMAT 0x8898_5000
+	/// Indicates an error originating in DirectWrite code, which is not expected to occur but is safe to recover from.
 	DWRITE_E_UNEXPECTED 0x8898_5001
+	/// Indicates the specified font does not exist.
 	DWRITE_E_NOFONT 0x8898_5002
+	/// A font file could not be opened because the file, directory, network location, drive, or other storage location does not exist or is unavailable.
 	DWRITE_E_FILENOTFOUND 0x8898_5003
+	/// A font file exists but could not be opened due to access denied, sharing violation, or similar error.
 	DWRITE_E_FILEACCESS 0x8898_5004
+	/// A font collection is obsolete due to changes in the system.
 	DWRITE_E_FONTCOLLECTIONOBSOLETE 0x8898_5005
+	/// The given interface is already registered.
 	DWRITE_E_ALREADYREGISTERED 0x8898_5006
+	/// The font cache contains invalid data.
 	DWRITE_E_CACHEFORMAT 0x8898_5007
+	/// A font cache file corresponds to a different version of DirectWrite.
 	DWRITE_E_CACHEVERSION 0x8898_5008
+	/// The operation is not supported for this type of font.
 	DWRITE_E_UNSUPPORTEDOPERATION 0x8898_5009
+	/// The version of the text renderer interface is not compatible.
 	DWRITE_E_TEXTRENDERERINCOMPATIBLE 0x8898_500a
+	/// The flow direction conflicts with the reading direction. They must be perpendicular to each other.
 	DWRITE_E_FLOWDIRECTIONCONFLICTS 0x8898_500b
+	/// The font or glyph run does not contain any colored glyphs.
 	DWRITE_E_NOCOLOR 0x8898_500c
+	/// A font resource could not be accessed because it is remote.
 	DWRITE_E_REMOTEFONT 0x8898_500d
+	/// A font download was canceled.
 	DWRITE_E_DOWNLOADCANCELLED 0x8898_500e
+	/// A font download failed.
 	DWRITE_E_DOWNLOADFAILED 0x8898_500f
+	/// A font download request was not added or a download failed because there are too many active downloads.
 	DWRITE_E_TOOMANYDOWNLOADS 0x8898_5010
+	/// The codec is in the wrong state.
 	WINCODEC_ERR_WRONGSTATE 0x8898_2f04
+	/// The value is out of range.
 	WINCODEC_ERR_VALUEOUTOFRANGE 0x8898_2f05
+	/// The image format is unknown.
 	WINCODEC_ERR_UNKNOWNIMAGEFORMAT 0x8898_2f07
+	/// The SDK version is unsupported.
 	WINCODEC_ERR_UNSUPPORTEDVERSION 0x8898_2f0b
+	/// The component is not initialized.
 	WINCODEC_ERR_NOTINITIALIZED 0x8898_2f0c
+	/// There is already an outstanding read or write lock.
 	WINCODEC_ERR_ALREADYLOCKED 0x8898_2f0d
+	/// The specified bitmap property cannot be found.
 	WINCODEC_ERR_PROPERTYNOTFOUND 0x8898_2f40
+	/// The bitmap codec does not support the bitmap property.
 	WINCODEC_ERR_PROPERTYNOTSUPPORTED 0x8898_2f41
+	/// The bitmap property size is invalid.
 	WINCODEC_ERR_PROPERTYSIZE 0x8898_2f42
+	/// An unknown error has occurred.
 	WINCODEC_ERR_CODECPRESENT 0x8898_2f43
+	/// The bitmap codec does not support a thumbnail.
 	WINCODEC_ERR_CODECNOTHUMBNAIL 0x8898_2f44
+	/// The bitmap palette is unavailable.
 	WINCODEC_ERR_PALETTEUNAVAILABLE 0x8898_2f45
+	/// Too many scanlines were requested.
 	WINCODEC_ERR_CODECTOOMANYSCANLINES 0x8898_2f46
+	/// An internal error occurred.
 	WINCODEC_ERR_INTERNALERROR 0x8898_2f48
+	/// The bitmap bounds do not match the bitmap dimensions.
 	WINCODEC_ERR_SOURCERECTDOESNOTMATCHDIMENSIONS 0x8898_2f49
+	/// The component cannot be found.
 	WINCODEC_ERR_COMPONENTNOTFOUND 0x8898_2f50
+	/// The bitmap size is outside the valid range.
 	WINCODEC_ERR_IMAGESIZEOUTOFRANGE 0x8898_2f51
+	/// There is too much metadata to be written to the bitmap.
 	WINCODEC_ERR_TOOMUCHMETADATA 0x8898_2f52
+	/// The image is unrecognized.
 	WINCODEC_ERR_BADIMAGE 0x8898_2f60
+	/// The image header is unrecognized.
 	WINCODEC_ERR_BADHEADER 0x8898_2f61
+	/// The bitmap frame is missing.
 	WINCODEC_ERR_FRAMEMISSING 0x8898_2f62
+	/// The image metadata header is unrecognized.
 	WINCODEC_ERR_BADMETADATAHEADER 0x8898_2f63
+	/// The stream data is unrecognized.
 	WINCODEC_ERR_BADSTREAMDATA 0x8898_2f70
+	/// Failed to write to the stream.
 	WINCODEC_ERR_STREAMWRITE 0x8898_2f71
+	/// Failed to read from the stream.
 	WINCODEC_ERR_STREAMREAD 0x8898_2f72
+	/// The stream is not available.
 	WINCODEC_ERR_STREAMNOTAVAILABLE 0x8898_2f73
+	/// The bitmap pixel format is unsupported.
 	WINCODEC_ERR_UNSUPPORTEDPIXELFORMAT 0x8898_2f80
+	/// The operation is unsupported.
 	WINCODEC_ERR_UNSUPPORTEDOPERATION 0x8898_2f81
+	/// The component registration is invalid.
 	WINCODEC_ERR_INVALIDREGISTRATION 0x8898_2f8a
+	/// The component initialization has failed.
 	WINCODEC_ERR_COMPONENTINITIALIZEFAILURE 0x8898_2f8b
+	/// The buffer allocated is insufficient.
 	WINCODEC_ERR_INSUFFICIENTBUFFER 0x8898_2f8c
+	/// Duplicate metadata is present.
 	WINCODEC_ERR_DUPLICATEMETADATAPRESENT 0x8898_2f8d
+	/// The bitmap property type is unexpected.
 	WINCODEC_ERR_PROPERTYUNEXPECTEDTYPE 0x8898_2f8e
+	/// The size is unexpected.
 	WINCODEC_ERR_UNEXPECTEDSIZE 0x8898_2f8f
+	/// The property query is invalid.
 	WINCODEC_ERR_INVALIDQUERYREQUEST 0x8898_2f90
+	/// The metadata type is unexpected.
 	WINCODEC_ERR_UNEXPECTEDMETADATATYPE 0x8898_2f91
+	/// The specified bitmap property is only valid at root level.
 	WINCODEC_ERR_REQUESTONLYVALIDATMETADATAROOT 0x8898_2f92
+	/// The query string contains an invalid character.
 	WINCODEC_ERR_INVALIDQUERYCHARACTER 0x8898_2f93
+	/// Windows Codecs received an error from the Win32 system.
 	WINCODEC_ERR_WIN32ERROR 0x8898_2f94
+	/// The requested level of detail is not present.
 	WINCODEC_ERR_INVALIDPROGRESSIVELEVEL 0x8898_2f95
+	/// The scan index is invalid.
 	WINCODEC_ERR_INVALIDJPEGSCANINDEX 0x8898_2f96
+	/// MILERR_OBJECTBUSY
 	MILERR_OBJECTBUSY 0x8898_0001
+	/// MILERR_INSUFFICIENTBUFFER
 	MILERR_INSUFFICIENTBUFFER 0x8898_0002
+	/// MILERR_WIN32ERROR
 	MILERR_WIN32ERROR 0x8898_0003
+	/// MILERR_SCANNER_FAILED
 	MILERR_SCANNER_FAILED 0x8898_0004
+	/// MILERR_SCREENACCESSDENIED
 	MILERR_SCREENACCESSDENIED 0x8898_0005
+	/// MILERR_DISPLAYSTATEINVALID
 	MILERR_DISPLAYSTATEINVALID 0x8898_0006
+	/// MILERR_NONINVERTIBLEMATRIX
 	MILERR_NONINVERTIBLEMATRIX 0x8898_0007
+	/// MILERR_ZEROVECTOR
 	MILERR_ZEROVECTOR 0x8898_0008
+	/// MILERR_TERMINATED
 	MILERR_TERMINATED 0x8898_0009
+	/// MILERR_BADNUMBER
 	MILERR_BADNUMBER 0x8898_000a
+	/// An internal error (MIL bug) occurred. On checked builds, an assert would be raised.
 	MILERR_INTERNALERROR 0x8898_0080
+	/// The display format we need to render is not supported by the hardware device.
 	MILERR_DISPLAYFORMATNOTSUPPORTED 0x8898_0084
+	/// A call to this method is invalid.
 	MILERR_INVALIDCALL 0x8898_0085
+	/// Lock attempted on an already locked object.
 	MILERR_ALREADYLOCKED 0x8898_0086
+	/// Unlock attempted on an unlocked object.
 	MILERR_NOTLOCKED 0x8898_0087
+	/// No algorithm available to render text with this device.
 	MILERR_DEVICECANNOTRENDERTEXT 0x8898_0088
+	/// Some glyph bitmaps, required for glyph run rendering, are not contained in glyph cache.
 	MILERR_GLYPHBITMAPMISSED 0x8898_0089
+	/// Some glyph bitmaps in glyph cache are unexpectedly big.
 	MILERR_MALFORMEDGLYPHCACHE 0x8898_008a
+	/// Marker error for known Win32 errors that are currently being ignored by the compositor. This is to avoid returning S_OK when an error has occurred, but still unwind the stack to the correct location.
 	MILERR_GENERIC_IGNORE 0x8898_008b
+	/// Guideline coordinates are not sorted properly or contain NaNs.
 	MILERR_MALFORMED_GUIDELINE_DATA 0x8898_008c
+	/// No HW rendering device is available for this operation.
 	MILERR_NO_HARDWARE_DEVICE 0x8898_008d
+	/// There has been a presentation error that may be recoverable. The caller needs to recreate, rerender the entire frame, and reattempt present.
 	MILERR_NEED_RECREATE_AND_PRESENT 0x8898_008e
+	/// The object has already been initialized.
 	MILERR_ALREADY_INITIALIZED 0x8898_008f
+	/// The size of the object does not match the expected size.
 	MILERR_MISMATCHED_SIZE 0x8898_0090
+	/// No Redirection surface available.
 	MILERR_NO_REDIRECTION_SURFACE_AVAILABLE 0x8898_0091
+	/// Remoting of this content is not supported.
 	MILERR_REMOTING_NOT_SUPPORTED 0x8898_0092
+	/// Queued Presents are not supported.
 	MILERR_QUEUED_PRESENT_NOT_SUPPORTED 0x8898_0093
+	/// Queued Presents are not being used.
 	MILERR_NOT_QUEUING_PRESENTS 0x8898_0094
+	/// No redirection surface was available. Caller should retry the call.
 	MILERR_NO_REDIRECTION_SURFACE_RETRY_LATER 0x8898_0095
+	/// Shader construction failed because it was too complex.
 	MILERR_TOOMANYSHADERELEMNTS 0x8898_0096
+	/// MROW attempt to get a read lock failed.
 	MILERR_MROW_READLOCK_FAILED 0x8898_0097
+	/// MROW attempt to update the data failed because another update was outstanding.
 	MILERR_MROW_UPDATE_FAILED 0x8898_0098
+	/// Shader compilation failed.
 	MILERR_SHADER_COMPILE_FAILED 0x8898_0099
+	/// Requested DX redirection surface size exceeded maximum texture size.
 	MILERR_MAX_TEXTURE_SIZE_EXCEEDED 0x8898_009a
+	/// QueryPerformanceCounter returned a time in the past.
 	MILERR_QPC_TIME_WENT_BACKWARD 0x8898_009b
+	/// Primary Display device returned an invalid refresh rate.
 	MILERR_DXGI_ENUMERATION_OUT_OF_SYNC 0x8898_009d
+	/// DWM can not find the adapter specified by the LUID.
 	MILERR_ADAPTER_NOT_FOUND 0x8898_009e
+	/// The requested bitmap color space is not supported.
 	MILERR_COLORSPACE_NOT_SUPPORTED 0x8898_009f
+	/// The requested bitmap pre-filtering state is not supported.
 	MILERR_PREFILTER_NOT_SUPPORTED 0x8898_00a0
+	/// Access is denied to the requested bitmap for the specified display id.
 	MILERR_DISPLAYID_ACCESS_DENIED 0x8898_00a1
+	/// UCEERR_INVALIDPACKETHEADER
 	UCEERR_INVALIDPACKETHEADER 0x8898_0400
+	/// UCEERR_UNKNOWNPACKET
 	UCEERR_UNKNOWNPACKET 0x8898_0401
+	/// UCEERR_ILLEGALPACKET
 	UCEERR_ILLEGALPACKET 0x8898_0402
+	/// UCEERR_MALFORMEDPACKET
 	UCEERR_MALFORMEDPACKET 0x8898_0403
+	/// UCEERR_ILLEGALHANDLE
 	UCEERR_ILLEGALHANDLE 0x8898_0404
+	/// UCEERR_HANDLELOOKUPFAILED
 	UCEERR_HANDLELOOKUPFAILED 0x8898_0405
+	/// UCEERR_RENDERTHREADFAILURE
 	UCEERR_RENDERTHREADFAILURE 0x8898_0406
+	/// UCEERR_CTXSTACKFRSTTARGETNULL
 	UCEERR_CTXSTACKFRSTTARGETNULL 0x8898_0407
+	/// UCEERR_CONNECTIONIDLOOKUPFAILED
 	UCEERR_CONNECTIONIDLOOKUPFAILED 0x8898_0408
+	/// UCEERR_BLOCKSFULL
 	UCEERR_BLOCKSFULL 0x8898_0409
+	/// UCEERR_MEMORYFAILURE
 	UCEERR_MEMORYFAILURE 0x8898_040a
+	/// UCEERR_PACKETRECORDOUTOFRANGE
 	UCEERR_PACKETRECORDOUTOFRANGE 0x8898_040b
+	/// UCEERR_ILLEGALRECORDTYPE
 	UCEERR_ILLEGALRECORDTYPE 0x8898_040c
+	/// UCEERR_OUTOFHANDLES
 	UCEERR_OUTOFHANDLES 0x8898_040d
+	/// UCEERR_UNCHANGABLE_UPDATE_ATTEMPTED
 	UCEERR_UNCHANGABLE_UPDATE_ATTEMPTED 0x8898_040e
+	/// UCEERR_NO_MULTIPLE_WORKER_THREADS
 	UCEERR_NO_MULTIPLE_WORKER_THREADS 0x8898_040f
+	/// UCEERR_REMOTINGNOTSUPPORTED
 	UCEERR_REMOTINGNOTSUPPORTED 0x8898_0410
+	/// UCEERR_MISSINGENDCOMMAND
 	UCEERR_MISSINGENDCOMMAND 0x8898_0411
+	/// UCEERR_MISSINGBEGINCOMMAND
 	UCEERR_MISSINGBEGINCOMMAND 0x8898_0412
+	/// UCEERR_CHANNELSYNCTIMEDOUT
 	UCEERR_CHANNELSYNCTIMEDOUT 0x8898_0413
+	/// UCEERR_CHANNELSYNCABANDONED
 	UCEERR_CHANNELSYNCABANDONED 0x8898_0414
+	/// UCEERR_UNSUPPORTEDTRANSPORTVERSION
 	UCEERR_UNSUPPORTEDTRANSPORTVERSION 0x8898_0415
+	/// UCEERR_TRANSPORTUNAVAILABLE
 	UCEERR_TRANSPORTUNAVAILABLE 0x8898_0416
+	/// UCEERR_FEEDBACK_UNSUPPORTED
 	UCEERR_FEEDBACK_UNSUPPORTED 0x8898_0417
+	/// UCEERR_COMMANDTRANSPORTDENIED
 	UCEERR_COMMANDTRANSPORTDENIED 0x8898_0418
+	/// UCEERR_GRAPHICSSTREAMUNAVAILABLE
 	UCEERR_GRAPHICSSTREAMUNAVAILABLE 0x8898_0419
+	/// UCEERR_GRAPHICSSTREAMALREADYOPEN
 	UCEERR_GRAPHICSSTREAMALREADYOPEN 0x8898_0420
+	/// UCEERR_TRANSPORTDISCONNECTED
 	UCEERR_TRANSPORTDISCONNECTED 0x8898_0421
+	/// UCEERR_TRANSPORTOVERLOADED
 	UCEERR_TRANSPORTOVERLOADED 0x8898_0422
+	/// UCEERR_PARTITION_ZOMBIED
 	UCEERR_PARTITION_ZOMBIED 0x8898_0423
+	/// MILAVERR_NOCLOCK
 	MILAVERR_NOCLOCK 0x8898_0500
+	/// MILAVERR_NOMEDIATYPE
 	MILAVERR_NOMEDIATYPE 0x8898_0501
+	/// MILAVERR_NOVIDEOMIXER
 	MILAVERR_NOVIDEOMIXER 0x8898_0502
+	/// MILAVERR_NOVIDEOPRESENTER
 	MILAVERR_NOVIDEOPRESENTER 0x8898_0503
+	/// MILAVERR_NOREADYFRAMES
 	MILAVERR_NOREADYFRAMES 0x8898_0504
+	/// MILAVERR_MODULENOTLOADED
 	MILAVERR_MODULENOTLOADED 0x8898_0505
+	/// MILAVERR_WMPFACTORYNOTREGISTERED
 	MILAVERR_WMPFACTORYNOTREGISTERED 0x8898_0506
+	/// MILAVERR_INVALIDWMPVERSION
 	MILAVERR_INVALIDWMPVERSION 0x8898_0507
+	/// MILAVERR_INSUFFICIENTVIDEORESOURCES
 	MILAVERR_INSUFFICIENTVIDEORESOURCES 0x8898_0508
+	/// MILAVERR_VIDEOACCELERATIONNOTAVAILABLE
 	MILAVERR_VIDEOACCELERATIONNOTAVAILABLE 0x8898_0509
+	/// MILAVERR_REQUESTEDTEXTURETOOBIG
 	MILAVERR_REQUESTEDTEXTURETOOBIG 0x8898_050a
+	/// MILAVERR_SEEKFAILED
 	MILAVERR_SEEKFAILED 0x8898_050b
+	/// MILAVERR_UNEXPECTEDWMPFAILURE
 	MILAVERR_UNEXPECTEDWMPFAILURE 0x8898_050c
+	/// MILAVERR_MEDIAPLAYERCLOSED
 	MILAVERR_MEDIAPLAYERCLOSED 0x8898_050d
+	/// MILAVERR_UNKNOWNHARDWAREERROR
 	MILAVERR_UNKNOWNHARDWAREERROR 0x8898_050e
+	/// MILEFFECTSERR_UNKNOWNPROPERTY
 	MILEFFECTSERR_UNKNOWNPROPERTY 0x8898_060e
+	/// MILEFFECTSERR_EFFECTNOTPARTOFGROUP
 	MILEFFECTSERR_EFFECTNOTPARTOFGROUP 0x8898_060f
+	/// MILEFFECTSERR_NOINPUTSOURCEATTACHED
 	MILEFFECTSERR_NOINPUTSOURCEATTACHED 0x8898_0610
+	/// MILEFFECTSERR_CONNECTORNOTCONNECTED
 	MILEFFECTSERR_CONNECTORNOTCONNECTED 0x8898_0611
+	/// MILEFFECTSERR_CONNECTORNOTASSOCIATEDWITHEFFECT
 	MILEFFECTSERR_CONNECTORNOTASSOCIATEDWITHEFFECT 0x8898_0612
+	/// MILEFFECTSERR_RESERVED
 	MILEFFECTSERR_RESERVED 0x8898_0613
+	/// MILEFFECTSERR_CYCLEDETECTED
 	MILEFFECTSERR_CYCLEDETECTED 0x8898_0614
+	/// MILEFFECTSERR_EFFECTINMORETHANONEGRAPH
 	MILEFFECTSERR_EFFECTINMORETHANONEGRAPH 0x8898_0615
+	/// MILEFFECTSERR_EFFECTALREADYINAGRAPH
 	MILEFFECTSERR_EFFECTALREADYINAGRAPH 0x8898_0616
+	/// MILEFFECTSERR_EFFECTHASNOCHILDREN
 	MILEFFECTSERR_EFFECTHASNOCHILDREN 0x8898_0617
+	/// MILEFFECTSERR_ALREADYATTACHEDTOLISTENER
 	MILEFFECTSERR_ALREADYATTACHEDTOLISTENER 0x8898_0618
+	/// MILEFFECTSERR_NOTAFFINETRANSFORM
 	MILEFFECTSERR_NOTAFFINETRANSFORM 0x8898_0619
+	/// MILEFFECTSERR_EMPTYBOUNDS
 	MILEFFECTSERR_EMPTYBOUNDS 0x8898_061a
+	/// MILEFFECTSERR_OUTPUTSIZETOOLARGE
 	MILEFFECTSERR_OUTPUTSIZETOOLARGE 0x8898_061b
+	/// DWMERR_STATE_TRANSITION_FAILED
 	DWMERR_STATE_TRANSITION_FAILED 0x8898_0700
+	/// DWMERR_THEME_FAILED
 	DWMERR_THEME_FAILED 0x8898_0701
+	/// DWMERR_CATASTROPHIC_FAILURE
 	DWMERR_CATASTROPHIC_FAILURE 0x8898_0702
+	/// DCOMPOSITION_ERROR_WINDOW_ALREADY_COMPOSED
 	DCOMPOSITION_ERROR_WINDOW_ALREADY_COMPOSED 0x8898_0800
+	/// DCOMPOSITION_ERROR_SURFACE_BEING_RENDERED
 	DCOMPOSITION_ERROR_SURFACE_BEING_RENDERED 0x8898_0801
+	/// DCOMPOSITION_ERROR_SURFACE_NOT_BEING_RENDERED
 	DCOMPOSITION_ERROR_SURFACE_NOT_BEING_RENDERED 0x8898_0802
+	/// Authentication target is invalid or not configured correctly.
 	ONL_E_INVALID_AUTHENTICATION_TARGET 0x8086_0001
+	/// Your application cannot get the Online Id properties due to the Terms of Use accepted by the user.
 	ONL_E_ACCESS_DENIED_BY_TOU 0x8086_0002
+	/// The application requesting authentication tokens is either disabled or incorrectly configured.
 	ONL_E_INVALID_APPLICATION 0x8086_0003
+	/// Online Id password must be updated before signin.
 	ONL_E_PASSWORD_UPDATE_REQUIRED 0x8086_0004
+	/// Online Id account properties must be updated before signin.
 	ONL_E_ACCOUNT_UPDATE_REQUIRED 0x8086_0005
+	/// To help protect your Online Id account you must signin again.
 	ONL_E_FORCESIGNIN 0x8086_0006
+	/// Online Id account was locked because there have been too many attempts to sign in.
 	ONL_E_ACCOUNT_LOCKED 0x8086_0007
+	/// Online Id account requires parental consent before proceeding.
 	ONL_E_PARENTAL_CONSENT_REQUIRED 0x8086_0008
+	/// Online Id signin name is not yet verified. Email verification is required before signin.
 	ONL_E_EMAIL_VERIFICATION_REQUIRED 0x8086_0009
+	/// We have noticed some unusual activity in your Online Id account. Your action is needed to make sure no one else is using your account.
 	ONL_E_ACCOUNT_SUSPENDED_COMPROIMISE 0x8086_000a
+	/// We detected some suspicious activity with your Online Id account. To help protect you, we've temporarily blocked your account.
 	ONL_E_ACCOUNT_SUSPENDED_ABUSE 0x8086_000b
+	/// User interaction is required for authentication.
 	ONL_E_ACTION_REQUIRED 0x8086_000c
+	/// User has reached the maximum device associations per user limit.
 	ONL_CONNECTION_COUNT_LIMIT 0x8086_000d
+	/// Cannot sign out from the application since the user account is connected.
 	ONL_E_CONNECTED_ACCOUNT_CAN_NOT_SIGNOUT 0x8086_000e
+	/// User authentication is required for this operation.
 	ONL_E_USER_AUTHENTICATION_REQUIRED 0x8086_000f
+	/// We want to make sure this is you. User interaction is required for authentication.
 	ONL_E_REQUEST_THROTTLED 0x8086_0010
+	/// The maximum number of items for the access list has been reached. An item must be removed before another item is added.
 	FA_E_MAX_PERSISTED_ITEMS_REACHED 0x8027_0220
+	/// Cannot access Homegroup. Homegroup may not be set up or may have encountered an error.
 	FA_E_HOMEGROUP_NOT_AVAILABLE 0x8027_0222
+	/// This app can't start because the screen resolution is below 1024x768. Choose a higher screen resolution and then try again.
 	E_MONITOR_RESOLUTION_TOO_LOW 0x8027_0250
+	/// This app can't be activated from an elevated context.
 	E_ELEVATED_ACTIVATION_NOT_SUPPORTED 0x8027_0251
+	/// This app can't be activated when UAC is disabled.
 	E_UAC_DISABLED 0x8027_0252
+	/// This app can't be activated by the Built-in Administrator.
 	E_FULL_ADMIN_NOT_SUPPORTED 0x8027_0253
+	/// This app does not support the contract specified or is not installed.
 	E_APPLICATION_NOT_REGISTERED 0x8027_0254
+	/// This app has mulitple extensions registered to support the specified contract. Activation by AppUserModelId is ambiguous.
 	E_MULTIPLE_EXTENSIONS_FOR_APPLICATION 0x8027_0255
+	/// This app's package family has more than one package installed. This is not supported.
 	E_MULTIPLE_PACKAGES_FOR_FAMILY 0x8027_0256
+	/// The app manager is required to activate applications, but is not running.
 	E_APPLICATION_MANAGER_NOT_RUNNING 0x8027_0257
+	/// The Store was launched instead of the specified app because the app's package was in an invalid state.
 	S_STORE_LAUNCHED_FOR_REMEDIATION 0x0027_0258
+	/// This app failed to launch, but the error was handled with a dialog.
 	S_APPLICATION_ACTIVATION_ERROR_HANDLED_BY_DIALOG 0x0027_0259
+	/// The app didn't start in the required time.
 	E_APPLICATION_ACTIVATION_TIMED_OUT 0x8027_025a
+	/// The app didn't start.
 	E_APPLICATION_ACTIVATION_EXEC_FAILURE 0x8027_025b
+	/// This app failed to launch because of an issue with its license. Please try again in a moment.
 	E_APPLICATION_TEMPORARY_LICENSE_ERROR 0x8027_025c
+	/// This app failed to launch because its trial license has expired.
 	E_APPLICATION_TRIAL_LICENSE_EXPIRED 0x8027_025d
+	/// Please choose a folder on a drive that's formatted with the NTFS file system.
 	E_SKYDRIVE_ROOT_TARGET_FILE_SYSTEM_NOT_SUPPORTED 0x8027_0260
+	/// This location is already being used. Please choose a different location.
 	E_SKYDRIVE_ROOT_TARGET_OVERLAP 0x8027_0261
+	/// This location cannot be indexed. Please choose a different location.
 	E_SKYDRIVE_ROOT_TARGET_CANNOT_INDEX 0x8027_0262
+	/// Sorry, the action couldn't be completed because the file hasn't finished uploading. Try again later.
 	E_SKYDRIVE_FILE_NOT_UPLOADED 0x8027_0263
+	/// Sorry, the action couldn't be completed.
 	E_SKYDRIVE_UPDATE_AVAILABILITY_FAIL 0x8027_0264
+	/// This content can only be moved to a folder. To move the content to this drive, please choose or create a folder.
 	E_SKYDRIVE_ROOT_TARGET_VOLUME_ROOT_NOT_SUPPORTED 0x8027_0265
+	/// The file size is larger than supported by the sync engine.
 	E_SYNCENGINE_FILE_SIZE_OVER_LIMIT 0x8802_b001
+	/// The file cannot be uploaded because it doesn't fit in the user's cloud storage quota.
 	E_SYNCENGINE_FILE_SIZE_EXCEEDS_REMAINING_QUOTA 0x8802_b002
+	/// The file name contains invalid characters.
 	E_SYNCENGINE_UNSUPPORTED_FILE_NAME 0x8802_b003
+	/// The maximum file count has been reached for this folder in the sync engine.
 	E_SYNCENGINE_FOLDER_ITEM_COUNT_LIMIT_EXCEEDED 0x8802_b004
+	/// The file sync has been delegated to another program and has run into an issue.
 	E_SYNCENGINE_FILE_SYNC_PARTNER_ERROR 0x8802_b005
+	/// Sync has been delayed due to a throttling request from the service.
 	E_SYNCENGINE_SYNC_PAUSED_BY_SERVICE 0x8802_b006
+	/// We can't seem to find that file. Please try again later.
 	E_SYNCENGINE_FILE_IDENTIFIER_UNKNOWN 0x8802_c002
+	/// The account you're signed in with doesn't have permission to open this file.
 	E_SYNCENGINE_SERVICE_AUTHENTICATION_FAILED 0x8802_c003
+	/// There was a problem connecting to the service. Please try again later.
 	E_SYNCENGINE_UNKNOWN_SERVICE_ERROR 0x8802_c004
+	/// Sorry, there was a problem downloading the file.
 	E_SYNCENGINE_SERVICE_RETURNED_UNEXPECTED_SIZE 0x8802_c005
+	/// We're having trouble downloading the file right now. Please try again later.
 	E_SYNCENGINE_REQUEST_BLOCKED_BY_SERVICE 0x8802_c006
+	/// We're having trouble downloading the file right now. Please try again later.
 	E_SYNCENGINE_REQUEST_BLOCKED_DUE_TO_CLIENT_ERROR 0x8802_c007
+	/// The sync engine does not have permissions to access a local folder under the sync root.
 	E_SYNCENGINE_FOLDER_INACCESSIBLE 0x8802_d001
+	/// The folder name contains invalid characters.
 	E_SYNCENGINE_UNSUPPORTED_FOLDER_NAME 0x8802_d002
+	/// The sync engine is not allowed to run in your current market.
 	E_SYNCENGINE_UNSUPPORTED_MARKET 0x8802_d003
+	/// All files and folders can't be uploaded because a path of a file or folder is too long.
 	E_SYNCENGINE_PATH_LENGTH_LIMIT_EXCEEDED 0x8802_d004
+	/// All file and folders cannot be synchronized because a path of a file or folder would exceed the local path limit.
 	E_SYNCENGINE_REMOTE_PATH_LENGTH_LIMIT_EXCEEDED 0x8802_d005
+	/// Updates are needed in order to use the sync engine.
 	E_SYNCENGINE_CLIENT_UPDATE_NEEDED 0x8802_d006
+	/// The sync engine needs to authenticate with a proxy server.
 	E_SYNCENGINE_PROXY_AUTHENTICATION_REQUIRED 0x8802_d007
+	/// There was a problem setting up the storage services for the account.
 	E_SYNCENGINE_STORAGE_SERVICE_PROVISIONING_FAILED 0x8802_d008
+	/// Files can't be uploaded because there's an unsupported reparse point.
 	E_SYNCENGINE_UNSUPPORTED_REPARSE_POINT 0x8802_d009
+	/// The service has blocked your account from accessing the storage service.
 	E_SYNCENGINE_STORAGE_SERVICE_BLOCKED 0x8802_d00a
+	/// The action can't be performed right now because this folder is being moved. Please try again later.
 	E_SYNCENGINE_FOLDER_IN_REDIRECTION 0x8802_d00b
+	/// Mail policy is not managed by the OS.
 	EAS_E_POLICY_NOT_MANAGED_BY_OS 0x8055_0001
+	/// The system can be made compliant with the requested policies by taking further actions.
 	EAS_E_POLICY_COMPLIANT_WITH_ACTIONS 0x8055_0002
+	/// The system cannot be made compliant with the requested policies.
 	EAS_E_REQUESTED_POLICY_NOT_ENFORCEABLE 0x8055_0003
+	/// The account is not compliant because the current user has a blank password.
 	EAS_E_CURRENT_USER_HAS_BLANK_PASSWORD 0x8055_0004
+	/// The requested password expiration policy is incompatible with the current system.
 	EAS_E_REQUESTED_POLICY_PASSWORD_EXPIRATION_INCOMPATIBLE 0x8055_0005
+	/// The account is not compliant because the current user cannot change their password.
 	EAS_E_USER_CANNOT_CHANGE_PASSWORD 0x8055_0006
+	/// The account is not compliant because an administrator account has a blank password.
 	EAS_E_ADMINS_HAVE_BLANK_PASSWORD 0x8055_0007
+	/// The account is not compliant because an administrator account cannot change its password.
 	EAS_E_ADMINS_CANNOT_CHANGE_PASSWORD 0x8055_0008
+	/// The account is not compliant because a locally controlled user cannot change their password.
 	EAS_E_LOCAL_CONTROLLED_USERS_CANNOT_CHANGE_PASSWORD 0x8055_0009
+	/// The password policy cannot be enforced for connected administrator accounts.
 	EAS_E_PASSWORD_POLICY_NOT_ENFORCEABLE_FOR_CONNECTED_ADMINS 0x8055_000a
+	/// Connected administrator accounts need to change their passwords to be compliant.
 	EAS_E_CONNECTED_ADMINS_NEED_TO_CHANGE_PASSWORD 0x8055_000b
+	/// The password policy cannot be enforced for the current connected user.
 	EAS_E_PASSWORD_POLICY_NOT_ENFORCEABLE_FOR_CURRENT_CONNECTED_USER 0x8055_000c
+	/// The current connected user needs to change their password to be compliant.
 	EAS_E_CURRENT_CONNECTED_USER_NEED_TO_CHANGE_PASSWORD 0x8055_000d
+	/// Unsupported format.
 	WEB_E_UNSUPPORTED_FORMAT 0x8375_0001
+	/// Invalid XML.
 	WEB_E_INVALID_XML 0x8375_0002
+	/// Missing required element.
 	WEB_E_MISSING_REQUIRED_ELEMENT 0x8375_0003
+	/// Missing required attribute.
 	WEB_E_MISSING_REQUIRED_ATTRIBUTE 0x8375_0004
+	/// Unexpected content.
 	WEB_E_UNEXPECTED_CONTENT 0x8375_0005
+	/// Resource too large.
 	WEB_E_RESOURCE_TOO_LARGE 0x8375_0006
+	/// Invalid JSON string.
 	WEB_E_INVALID_JSON_STRING 0x8375_0007
+	/// Invalid JSON number.
 	WEB_E_INVALID_JSON_NUMBER 0x8375_0008
+	/// JSON value not found.
 	WEB_E_JSON_VALUE_NOT_FOUND 0x8375_0009
+	/// Unexpected HTTP status code.
 	HTTP_E_STATUS_UNEXPECTED 0x8019_0001
+	/// Unexpected redirection status code (3xx).
 	HTTP_E_STATUS_UNEXPECTED_REDIRECTION 0x8019_0003
+	/// Unexpected client error status code (4xx).
 	HTTP_E_STATUS_UNEXPECTED_CLIENT_ERROR 0x8019_0004
+	/// Unexpected server error status code (5xx).
 	HTTP_E_STATUS_UNEXPECTED_SERVER_ERROR 0x8019_0005
+	/// Multiple choices (300).
 	HTTP_E_STATUS_AMBIGUOUS 0x8019_012c
+	/// Moved permanently (301).
 	HTTP_E_STATUS_MOVED 0x8019_012d
+	/// Found (302).
 	HTTP_E_STATUS_REDIRECT 0x8019_012e
+	/// See Other (303).
 	HTTP_E_STATUS_REDIRECT_METHOD 0x8019_012f
+	/// Not modified (304).
 	HTTP_E_STATUS_NOT_MODIFIED 0x8019_0130
+	/// Use proxy (305).
 	HTTP_E_STATUS_USE_PROXY 0x8019_0131
+	/// Temporary redirect (307).
 	HTTP_E_STATUS_REDIRECT_KEEP_VERB 0x8019_0133
+	/// Bad request (400).
 	HTTP_E_STATUS_BAD_REQUEST 0x8019_0190
+	/// Unauthorized (401).
 	HTTP_E_STATUS_DENIED 0x8019_0191
+	/// Payment required (402).
 	HTTP_E_STATUS_PAYMENT_REQ 0x8019_0192
+	/// Forbidden (403).
 	HTTP_E_STATUS_FORBIDDEN 0x8019_0193
+	/// Not found (404).
 	HTTP_E_STATUS_NOT_FOUND 0x8019_0194
+	/// Method not allowed (405).
 	HTTP_E_STATUS_BAD_METHOD 0x8019_0195
+	/// Not acceptable (406).
 	HTTP_E_STATUS_NONE_ACCEPTABLE 0x8019_0196
+	/// Proxy authentication required (407).
 	HTTP_E_STATUS_PROXY_AUTH_REQ 0x8019_0197
+	/// Request timeout (408).
 	HTTP_E_STATUS_REQUEST_TIMEOUT 0x8019_0198
+	/// Conflict (409).
 	HTTP_E_STATUS_CONFLICT 0x8019_0199
+	/// Gone (410).
 	HTTP_E_STATUS_GONE 0x8019_019a
+	/// Length required (411).
 	HTTP_E_STATUS_LENGTH_REQUIRED 0x8019_019b
+	/// Precondition failed (412).
 	HTTP_E_STATUS_PRECOND_FAILED 0x8019_019c
+	/// Request entity too large (413).
 	HTTP_E_STATUS_REQUEST_TOO_LARGE 0x8019_019d
+	/// Request-URI too long (414).
 	HTTP_E_STATUS_URI_TOO_LONG 0x8019_019e
+	/// Unsupported media type (415).
 	HTTP_E_STATUS_UNSUPPORTED_MEDIA 0x8019_019f
+	/// Requested range not satisfiable (416).
 	HTTP_E_STATUS_RANGE_NOT_SATISFIABLE 0x8019_01a0
+	/// Expectation failed (417).
 	HTTP_E_STATUS_EXPECTATION_FAILED 0x8019_01a1
+	/// Internal server error (500).
 	HTTP_E_STATUS_SERVER_ERROR 0x8019_01f4
+	/// Not implemented (501).
 	HTTP_E_STATUS_NOT_SUPPORTED 0x8019_01f5
+	/// Bad gateway (502).
 	HTTP_E_STATUS_BAD_GATEWAY 0x8019_01f6
+	/// Service unavailable (503).
 	HTTP_E_STATUS_SERVICE_UNAVAIL 0x8019_01f7
+	/// Gateway timeout (504).
 	HTTP_E_STATUS_GATEWAY_TIMEOUT 0x8019_01f8
+	/// Version not supported (505).
 	HTTP_E_STATUS_VERSION_NOT_SUP 0x8019_01f9
+	/// Invalid operation performed by the protocol.
 	E_INVALID_PROTOCOL_OPERATION 0x8376_0001
+	/// Invalid data format for the specific protocol operation.
 	E_INVALID_PROTOCOL_FORMAT 0x8376_0002
+	/// Protocol extensions are not supported.
 	E_PROTOCOL_EXTENSIONS_NOT_SUPPORTED 0x8376_0003
+	/// Subprotocol is not supported.
 	E_SUBPROTOCOL_NOT_SUPPORTED 0x8376_0004
+	/// Incorrect protocol version.
 	E_PROTOCOL_VERSION_NOT_SUPPORTED 0x8376_0005
+	/// Input data cannot be processed in the non-chronological order.
 	INPUT_E_OUT_OF_ORDER 0x8040_0000
+	/// Requested operation cannot be performed inside the callback or event handler.
 	INPUT_E_REENTRANCY 0x8040_0001
+	/// Input cannot be processed because there is ongoing interaction with another pointer type.
 	INPUT_E_MULTIMODAL 0x8040_0002
+	/// One or more fields in the input packet are invalid.
 	INPUT_E_PACKET 0x8040_0003
+	/// Packets in the frame are inconsistent. Either pointer ids are not unique or there is a discrepancy in timestamps, frame ids, pointer types or source devices.
 	INPUT_E_FRAME 0x8040_0004
+	/// The history of frames is inconsistent. Pointer ids, types, source devices don't match, or frame ids are not unique, or timestamps are out of order.
 	INPUT_E_HISTORY 0x8040_0005
+	/// Failed to retrieve information about the input device.
 	INPUT_E_DEVICE_INFO 0x8040_0006
+	/// Coordinate system transformation failed to transform the data.
 	INPUT_E_TRANSFORM 0x8040_0007
+	/// The property is not supported or not reported correctly by the input device.
 	INPUT_E_DEVICE_PROPERTY 0x8040_0008
+	/// The URL is invalid.
 	INET_E_INVALID_URL 0x800c_0002
+	/// No Internet session has been established.
 	INET_E_NO_SESSION 0x800c_0003
+	/// Unable to connect to the target server.
 	INET_E_CANNOT_CONNECT 0x800c_0004
+	/// The system cannot locate the resource specified.
 	INET_E_RESOURCE_NOT_FOUND 0x800c_0005
+	/// The system cannot locate the object specified.
 	INET_E_OBJECT_NOT_FOUND 0x800c_0006
+	/// No data is available for the requested resource.
 	INET_E_DATA_NOT_AVAILABLE 0x800c_0007
+	/// The download of the specified resource has failed.
 	INET_E_DOWNLOAD_FAILURE 0x800c_0008
+	/// Authentication is required to access this resource.
 	INET_E_AUTHENTICATION_REQUIRED 0x800c_0009
+	/// The server could not recognize the provided mime type.
 	INET_E_NO_VALID_MEDIA 0x800c_000a
+	/// The operation was timed out.
 	INET_E_CONNECTION_TIMEOUT 0x800c_000b
+	/// The server did not understand the request, or the request was invalid.
 	INET_E_INVALID_REQUEST 0x800c_000c
+	/// The specified protocol is unknown.
 	INET_E_UNKNOWN_PROTOCOL 0x800c_000d
+	/// A security problem occurred.
 	INET_E_SECURITY_PROBLEM 0x800c_000e
+	/// The system could not load the persisted data.
 	INET_E_CANNOT_LOAD_DATA 0x800c_000f
+	/// Unable to instantiate the object.
 	INET_E_CANNOT_INSTANTIATE_OBJECT 0x800c_0010
+	/// Security certificate required to access this resource is invalid.
 	INET_E_INVALID_CERTIFICATE 0x800c_0019
+	/// A redirection problem occurred.
 	INET_E_REDIRECT_FAILED 0x800c_0014
+	/// The requested resource is a directory, not a file.
 	INET_E_REDIRECT_TO_DIR 0x800c_0015
+	/// Could not create new process from ARM architecture device.
 	ERROR_DBG_CREATE_PROCESS_FAILURE_LOCKDOWN 0x80b0_0001
+	/// Could not attach to the application process from ARM architecture device.
 	ERROR_DBG_ATTACH_PROCESS_FAILURE_LOCKDOWN 0x80b0_0002
+	/// Could not connect to dbgsrv server from ARM architecture device.
 	ERROR_DBG_CONNECT_SERVER_FAILURE_LOCKDOWN 0x80b0_0003
+	/// Could not start dbgsrv server from ARM architecture device.
 	ERROR_DBG_START_SERVER_FAILURE_LOCKDOWN 0x80b0_0004
+	/// The operation was preempted by a higher priority operation. It must be resumed later.
 	ERROR_IO_PREEMPTED 0x8901_0001
+	/// Function could not execute because it was deleted or garbage collected.
 	JSCRIPT_E_CANTEXECUTE 0x8902_0001
+	/// One or more fixed volumes are not provisioned with the 3rd party encryption providers to support device encryption. Enable encryption with the 3rd party provider to comply with policy.
 	WEP_E_NOT_PROVISIONED_ON_ALL_VOLUMES 0x8801_0001
+	/// This computer is not fully encrypted. There are fixed volumes present which are not supported for encryption.
 	WEP_E_FIXED_DATA_NOT_SUPPORTED 0x8801_0002
+	/// This computer does not meet the hardware requirements to support device encryption with the installed 3rd party provider.
 	WEP_E_HARDWARE_NOT_COMPLIANT 0x8801_0003
+	/// This computer cannot support device encryption because the requisite screen lock is not configured.
 	WEP_E_LOCK_NOT_CONFIGURED 0x8801_0004
+	/// Protection is enabled on this volume but is not in the active state.
 	WEP_E_PROTECTION_SUSPENDED 0x8801_0005
+	/// The 3rd party provider has been installed, but cannot activate encryption because a license has not been activated.
 	WEP_E_NO_LICENSE 0x8801_0006
+	/// The operating system drive is not protected by 3rd party drive encryption.
 	WEP_E_OS_NOT_PROTECTED 0x8801_0007
+	/// Unexpected failure was encountered while calling into the 3rd Party drive encryption plugin.
 	WEP_E_UNEXPECTED_FAIL 0x8801_0008
+	/// The input buffer size for the lockout metadata used by the 3rd party drive encryption is too large.
 	WEP_E_BUFFER_TOO_LARGE 0x8801_0009
+	/// The proper error code with sense data was stored on server side.
 	ERROR_SVHDX_ERROR_STORED 0xc05c_0000
+	/// The requested error data is not available on the server.
 	ERROR_SVHDX_ERROR_NOT_AVAILABLE 0xc05c_ff00
+	/// Unit Attention data is available for the initiator to query.
 	ERROR_SVHDX_UNIT_ATTENTION_AVAILABLE 0xc05c_ff01
+	/// The data capacity of the device has changed, resulting in a Unit Attention condition.
 	ERROR_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED 0xc05c_ff02
+	/// A previous operation resulted in this initiator's reservations being preempted, resulting in a Unit Attention condition.
 	ERROR_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED 0xc05c_ff03
+	/// A previous operation resulted in this initiator's reservations being released, resulting in a Unit Attention condition.
 	ERROR_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED 0xc05c_ff04
+	/// A previous operation resulted in this initiator's registrations being preempted, resulting in a Unit Attention condition.
 	ERROR_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED 0xc05c_ff05
+	/// The data storage format of the device has changed, resulting in a Unit Attention condition.
 	ERROR_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED 0xc05c_ff06
+	/// The current initiator is not allowed to perform the SCSI command because of a reservation conflict.
 	ERROR_SVHDX_RESERVATION_CONFLICT 0xc05c_ff07
+	/// Multiple virtual machines sharing a virtual hard disk is supported only on Fixed or Dynamic VHDX format virtual hard disks.
 	ERROR_SVHDX_WRONG_FILE_TYPE 0xc05c_ff08
+	/// The server version does not match the requested version.
 	ERROR_SVHDX_VERSION_MISMATCH 0xc05c_ff09
+	/// The requested operation cannot be performed on the virtual disk as it is currently used in shared mode.
 	ERROR_VHD_SHARED 0xc05c_ff0a
+	/// Invalid Shared VHDX open due to lack of initiator ID. Check for related Continuous Availability failures.
 	ERROR_SVHDX_NO_INITIATOR 0xc05c_ff0b
+	/// The requested operation failed due to a missing backing storage file.
 	ERROR_VHDSET_BACKING_STORAGE_NOT_FOUND 0xc05c_ff0c
+	/// Failed to negotiate a preauthentication integrity hash function.
 	ERROR_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP 0xc05d_0000
+	/// The current cluster functional level does not support this SMB dialect.
 	ERROR_SMB_BAD_CLUSTER_DIALECT 0xc05d_0001
+	/// No more Internet handles can be allocated.
 	WININET_E_OUT_OF_HANDLES 0x8007_2ee1
+	/// The operation timed out.
 	WININET_E_TIMEOUT 0x8007_2ee2
+	/// The server returned extended information.
 	WININET_E_EXTENDED_ERROR 0x8007_2ee3
+	/// An internal error occurred in the Microsoft Internet extensions.
 	WININET_E_INTERNAL_ERROR 0x8007_2ee4
+	/// The URL is invalid.
 	WININET_E_INVALID_URL 0x8007_2ee5
+	/// The URL does not use a recognized protocol.
 	WININET_E_UNRECOGNIZED_SCHEME 0x8007_2ee6
+	/// The server name or address could not be resolved.
 	WININET_E_NAME_NOT_RESOLVED 0x8007_2ee7
+	/// A protocol with the required capabilities was not found.
 	WININET_E_PROTOCOL_NOT_FOUND 0x8007_2ee8
+	/// The option is invalid.
 	WININET_E_INVALID_OPTION 0x8007_2ee9
+	/// The length is incorrect for the option type.
 	WININET_E_BAD_OPTION_LENGTH 0x8007_2eea
+	/// The option value cannot be set.
 	WININET_E_OPTION_NOT_SETTABLE 0x8007_2eeb
+	/// Microsoft Internet Extension support has been shut down.
 	WININET_E_SHUTDOWN 0x8007_2eec
+	/// The user name was not allowed.
 	WININET_E_INCORRECT_USER_NAME 0x8007_2eed
+	/// The password was not allowed.
 	WININET_E_INCORRECT_PASSWORD 0x8007_2eee
+	/// The login request was denied.
 	WININET_E_LOGIN_FAILURE 0x8007_2eef
+	/// The requested operation is invalid.
 	WININET_E_INVALID_OPERATION 0x8007_2ef0
+	/// The operation has been canceled.
 	WININET_E_OPERATION_CANCELLED 0x8007_2ef1
+	/// The supplied handle is the wrong type for the requested operation.
 	WININET_E_INCORRECT_HANDLE_TYPE 0x8007_2ef2
+	/// The handle is in the wrong state for the requested operation.
 	WININET_E_INCORRECT_HANDLE_STATE 0x8007_2ef3
+	/// The request cannot be made on a Proxy session.
 	WININET_E_NOT_PROXY_REQUEST 0x8007_2ef4
+	/// The registry value could not be found.
 	WININET_E_REGISTRY_VALUE_NOT_FOUND 0x8007_2ef5
+	/// The registry parameter is incorrect.
 	WININET_E_BAD_REGISTRY_PARAMETER 0x8007_2ef6
+	/// Direct Internet access is not available.
 	WININET_E_NO_DIRECT_ACCESS 0x8007_2ef7
+	/// No context value was supplied.
 	WININET_E_NO_CONTEXT 0x8007_2ef8
+	/// No status callback was supplied.
 	WININET_E_NO_CALLBACK 0x8007_2ef9
+	/// There are outstanding requests.
 	WININET_E_REQUEST_PENDING 0x8007_2efa
+	/// The information format is incorrect.
 	WININET_E_INCORRECT_FORMAT 0x8007_2efb
+	/// The requested item could not be found.
 	WININET_E_ITEM_NOT_FOUND 0x8007_2efc
+	/// A connection with the server could not be established.
 	WININET_E_CANNOT_CONNECT 0x8007_2efd
+	/// The connection with the server was terminated abnormally.
 	WININET_E_CONNECTION_ABORTED 0x8007_2efe
+	/// The connection with the server was reset.
 	WININET_E_CONNECTION_RESET 0x8007_2eff
+	/// The action must be retried.
 	WININET_E_FORCE_RETRY 0x8007_2f00
+	/// The proxy request is invalid.
 	WININET_E_INVALID_PROXY_REQUEST 0x8007_2f01
+	/// User interaction is required to complete the operation.
 	WININET_E_NEED_UI 0x8007_2f02
+	/// The handle already exists.
 	WININET_E_HANDLE_EXISTS 0x8007_2f04
+	/// The date in the certificate is invalid or has expired.
 	WININET_E_SEC_CERT_DATE_INVALID 0x8007_2f05
+	/// The host name in the certificate is invalid or does not match.
 	WININET_E_SEC_CERT_CN_INVALID 0x8007_2f06
+	/// A redirect request will change a non-secure to a secure connection.
 	WININET_E_HTTP_TO_HTTPS_ON_REDIR 0x8007_2f07
+	/// A redirect request will change a secure to a non-secure connection.
 	WININET_E_HTTPS_TO_HTTP_ON_REDIR 0x8007_2f08
+	/// Mixed secure and non-secure connections.
 	WININET_E_MIXED_SECURITY 0x8007_2f09
+	/// Changing to non-secure post.
 	WININET_E_CHG_POST_IS_NON_SECURE 0x8007_2f0a
+	/// Data is being posted on a non-secure connection.
 	WININET_E_POST_IS_NON_SECURE 0x8007_2f0b
+	/// A certificate is required to complete client authentication.
 	WININET_E_CLIENT_AUTH_CERT_NEEDED 0x8007_2f0c
+	/// The certificate authority is invalid or incorrect.
 	WININET_E_INVALID_CA 0x8007_2f0d
+	/// Client authentication has not been correctly installed.
 	WININET_E_CLIENT_AUTH_NOT_SETUP 0x8007_2f0e
+	/// An error has occurred in a Wininet asynchronous thread. You may need to restart.
 	WININET_E_ASYNC_THREAD_FAILED 0x8007_2f0f
+	/// The protocol scheme has changed during a redirect operation.
 	WININET_E_REDIRECT_SCHEME_CHANGE 0x8007_2f10
+	/// There are operations awaiting retry.
 	WININET_E_DIALOG_PENDING 0x8007_2f11
+	/// The operation must be retried.
 	WININET_E_RETRY_DIALOG 0x8007_2f12
+	/// There are no new cache containers.
 	WININET_E_NO_NEW_CONTAINERS 0x8007_2f13
+	/// A security zone check indicates the operation must be retried.
 	WININET_E_HTTPS_HTTP_SUBMIT_REDIR 0x8007_2f14
+	/// The SSL certificate contains errors.
 	WININET_E_SEC_CERT_ERRORS 0x8007_2f17
+	/// It was not possible to connect to the revocation server or a definitive response could not be obtained.
 	WININET_E_SEC_CERT_REV_FAILED 0x8007_2f19
+	/// The requested header was not found.
 	WININET_E_HEADER_NOT_FOUND 0x8007_2f76
+	/// The server does not support the requested protocol level.
 	WININET_E_DOWNLEVEL_SERVER 0x8007_2f77
+	/// The server returned an invalid or unrecognized response.
 	WININET_E_INVALID_SERVER_RESPONSE 0x8007_2f78
+	/// The supplied HTTP header is invalid.
 	WININET_E_INVALID_HEADER 0x8007_2f79
+	/// The request for a HTTP header is invalid.
 	WININET_E_INVALID_QUERY_REQUEST 0x8007_2f7a
+	/// The HTTP header already exists.
 	WININET_E_HEADER_ALREADY_EXISTS 0x8007_2f7b
+	/// The HTTP redirect request failed.
 	WININET_E_REDIRECT_FAILED 0x8007_2f7c
+	/// An error occurred in the secure channel support.
 	WININET_E_SECURITY_CHANNEL_ERROR 0x8007_2f7d
+	/// The file could not be written to the cache.
 	WININET_E_UNABLE_TO_CACHE_FILE 0x8007_2f7e
+	/// The TCP/IP protocol is not installed properly.
 	WININET_E_TCPIP_NOT_INSTALLED 0x8007_2f7f
+	/// The computer is disconnected from the network.
 	WININET_E_DISCONNECTED 0x8007_2f83
+	/// The server is unreachable.
 	WININET_E_SERVER_UNREACHABLE 0x8007_2f84
+	/// The proxy server is unreachable.
 	WININET_E_PROXY_SERVER_UNREACHABLE 0x8007_2f85
+	/// The proxy auto-configuration script is in error.
 	WININET_E_BAD_AUTO_PROXY_SCRIPT 0x8007_2f86
+	/// Could not download the proxy auto-configuration script file.
 	WININET_E_UNABLE_TO_DOWNLOAD_SCRIPT 0x8007_2f87
+	/// The supplied certificate is invalid.
 	WININET_E_SEC_INVALID_CERT 0x8007_2f89
+	/// The supplied certificate has been revoked.
 	WININET_E_SEC_CERT_REVOKED 0x8007_2f8a
+	/// The Dialup failed because file sharing was turned on and a failure was requested if security check was needed.
 	WININET_E_FAILED_DUETOSECURITYCHECK 0x8007_2f8b
+	/// Initialization of the WinINet API has not occurred.
 	WININET_E_NOT_INITIALIZED 0x8007_2f8c
+	/// Login failed and the client should display the entity body to the user.
 	WININET_E_LOGIN_FAILURE_DISPLAY_ENTITY_BODY 0x8007_2f8e
+	/// Content decoding has failed.
 	WININET_E_DECODING_FAILED 0x8007_2f8f
+	/// The HTTP request was not redirected.
 	WININET_E_NOT_REDIRECTED 0x8007_2f80
+	/// A cookie from the server must be confirmed by the user.
 	WININET_E_COOKIE_NEEDS_CONFIRMATION 0x8007_2f81
+	/// A cookie from the server has been declined acceptance.
 	WININET_E_COOKIE_DECLINED 0x8007_2f82
+	/// The HTTP redirect request must be confirmed by the user.
 	WININET_E_REDIRECT_NEEDS_CONFIRMATION 0x8007_2f88
+	/// SQL error or missing database.
 	SQLITE_E_ERROR 0x87af_0001
+	/// Internal logic error in SQLite.
 	SQLITE_E_INTERNAL 0x87af_0002
+	/// Access permission denied.
 	SQLITE_E_PERM 0x87af_0003
+	/// Callback routine requested an abort.
 	SQLITE_E_ABORT 0x87af_0004
+	/// The database file is locked.
 	SQLITE_E_BUSY 0x87af_0005
+	/// A table in the database is locked.
 	SQLITE_E_LOCKED 0x87af_0006
+	/// A malloc() failed.
 	SQLITE_E_NOMEM 0x87af_0007
+	/// Attempt to write a readonly database.
 	SQLITE_E_READONLY 0x87af_0008
+	/// Operation terminated by sqlite3_interrupt().
 	SQLITE_E_INTERRUPT 0x87af_0009
+	/// Some kind of disk I/O error occurred.
 	SQLITE_E_IOERR 0x87af_000a
+	/// The database disk image is malformed.
 	SQLITE_E_CORRUPT 0x87af_000b
+	/// Unknown opcode in sqlite3_file_control().
 	SQLITE_E_NOTFOUND 0x87af_000c
+	/// Insertion failed because database is full.
 	SQLITE_E_FULL 0x87af_000d
+	/// Unable to open the database file.
 	SQLITE_E_CANTOPEN 0x87af_000e
+	/// Database lock protocol error.
 	SQLITE_E_PROTOCOL 0x87af_000f
+	/// Database is empty.
 	SQLITE_E_EMPTY 0x87af_0010
+	/// The database schema changed.
 	SQLITE_E_SCHEMA 0x87af_0011
+	/// String or BLOB exceeds size limit.
 	SQLITE_E_TOOBIG 0x87af_0012
+	/// Abort due to constraint violation.
 	SQLITE_E_CONSTRAINT 0x87af_0013
+	/// Data type mismatch.
 	SQLITE_E_MISMATCH 0x87af_0014
+	/// Library used incorrectly.
 	SQLITE_E_MISUSE 0x87af_0015
+	/// Uses OS features not supported on host.
 	SQLITE_E_NOLFS 0x87af_0016
+	/// Authorization denied.
 	SQLITE_E_AUTH 0x87af_0017
+	/// Auxiliary database format error.
 	SQLITE_E_FORMAT 0x87af_0018
+	/// 2nd parameter to sqlite3_bind out of range.
 	SQLITE_E_RANGE 0x87af_0019
+	/// File opened that is not a database file.
 	SQLITE_E_NOTADB 0x87af_001a
+	/// Notifications from sqlite3_log().
 	SQLITE_E_NOTICE 0x87af_001b
+	/// Warnings from sqlite3_log().
 	SQLITE_E_WARNING 0x87af_001c
+	/// sqlite3_step() has another row ready.
 	SQLITE_E_ROW 0x87af_0064
+	/// sqlite3_step() has finished executing.
 	SQLITE_E_DONE 0x87af_0065
+	/// SQLite I/O error: read.
 	SQLITE_E_IOERR_READ 0x87af_010a
+	/// SQLite I/O error: short read.
 	SQLITE_E_IOERR_SHORT_READ 0x87af_020a
+	/// SQLite I/O error: write.
 	SQLITE_E_IOERR_WRITE 0x87af_030a
+	/// SQLite I/O error: fsync.
 	SQLITE_E_IOERR_FSYNC 0x87af_040a
+	/// SQLite I/O error: directory fsync.
 	SQLITE_E_IOERR_DIR_FSYNC 0x87af_050a
+	/// SQLite I/O error: truncate.
 	SQLITE_E_IOERR_TRUNCATE 0x87af_060a
+	/// SQLite I/O error: fstat.
 	SQLITE_E_IOERR_FSTAT 0x87af_070a
+	/// SQLite I/O error: unlock.
 	SQLITE_E_IOERR_UNLOCK 0x87af_080a
+	/// SQLite I/O error: read lock.
 	SQLITE_E_IOERR_RDLOCK 0x87af_090a
+	/// SQLite I/O error: delete.
 	SQLITE_E_IOERR_DELETE 0x87af_0a0a
+	/// SQLite I/O error: blocked.
 	SQLITE_E_IOERR_BLOCKED 0x87af_0b0a
+	/// SQLite I/O error: out of memory.
 	SQLITE_E_IOERR_NOMEM 0x87af_0c0a
+	/// SQLite I/O error: access.
 	SQLITE_E_IOERR_ACCESS 0x87af_0d0a
+	/// SQLite I/O error: check reserved lock.
 	SQLITE_E_IOERR_CHECKRESERVEDLOCK 0x87af_0e0a
+	/// SQLite I/O error: lock.
 	SQLITE_E_IOERR_LOCK 0x87af_0f0a
+	/// SQLite I/O error: close.
 	SQLITE_E_IOERR_CLOSE 0x87af_100a
+	/// SQLite I/O error: directory close.
 	SQLITE_E_IOERR_DIR_CLOSE 0x87af_110a
+	/// SQLite I/O error: shared memory open.
 	SQLITE_E_IOERR_SHMOPEN 0x87af_120a
+	/// SQLite I/O error: shared memory size.
 	SQLITE_E_IOERR_SHMSIZE 0x87af_130a
+	/// SQLite I/O error: shared memory lock.
 	SQLITE_E_IOERR_SHMLOCK 0x87af_140a
+	/// SQLite I/O error: shared memory map.
 	SQLITE_E_IOERR_SHMMAP 0x87af_150a
+	/// SQLite I/O error: seek.
 	SQLITE_E_IOERR_SEEK 0x87af_160a
+	/// SQLite I/O error: delete of a nonexistent file.
 	SQLITE_E_IOERR_DELETE_NOENT 0x87af_170a
+	/// SQLite I/O error: memory map.
 	SQLITE_E_IOERR_MMAP 0x87af_180a
+	/// SQLite I/O error: get temporary path.
 	SQLITE_E_IOERR_GETTEMPPATH 0x87af_190a
+	/// SQLite I/O error: path conversion.
 	SQLITE_E_IOERR_CONVPATH 0x87af_1a0a
+	/// SQLite I/O error: vnode.
 	SQLITE_E_IOERR_VNODE 0x87af_1a02
+	/// SQLite I/O error: authorization.
 	SQLITE_E_IOERR_AUTH 0x87af_1a03
+	/// A table in the database is locked by a shared cache connection.
 	SQLITE_E_LOCKED_SHAREDCACHE 0x87af_0106
+	/// The database file is locked because another process is recovering it.
 	SQLITE_E_BUSY_RECOVERY 0x87af_0105
+	/// The database file is locked because the snapshot is out of date.
 	SQLITE_E_BUSY_SNAPSHOT 0x87af_0205
+	/// Unable to open the database file: no temporary directory.
 	SQLITE_E_CANTOPEN_NOTEMPDIR 0x87af_010e
+	/// Unable to open the database file: the path is a directory.
 	SQLITE_E_CANTOPEN_ISDIR 0x87af_020e
+	/// Unable to open the database file: the full path could not be resolved.
 	SQLITE_E_CANTOPEN_FULLPATH 0x87af_030e
+	/// Unable to open the database file: path conversion failed.
 	SQLITE_E_CANTOPEN_CONVPATH 0x87af_040e
+	/// The virtual table content is corrupt.
 	SQLITE_E_CORRUPT_VTAB 0x87af_010b
+	/// Attempt to write a readonly database that needs recovery.
 	SQLITE_E_READONLY_RECOVERY 0x87af_0108
+	/// Attempt to write a readonly database that cannot be locked.
 	SQLITE_E_READONLY_CANTLOCK 0x87af_0208
+	/// Attempt to write a readonly database with a hot journal.
 	SQLITE_E_READONLY_ROLLBACK 0x87af_0308
+	/// Attempt to write a database file that has been moved.
 	SQLITE_E_READONLY_DBMOVED 0x87af_0408
+	/// The statement was aborted by a rollback.
 	SQLITE_E_ABORT_ROLLBACK 0x87af_0204
+	/// A CHECK constraint failed.
 	SQLITE_E_CONSTRAINT_CHECK 0x87af_0113
+	/// A commit hook callback requested a rollback.
 	SQLITE_E_CONSTRAINT_COMMITHOOK 0x87af_0213
+	/// A foreign key constraint failed.
 	SQLITE_E_CONSTRAINT_FOREIGNKEY 0x87af_0313
+	/// A constraint failed in an application-defined function.
 	SQLITE_E_CONSTRAINT_FUNCTION 0x87af_0413
+	/// A NOT NULL constraint failed.
 	SQLITE_E_CONSTRAINT_NOTNULL 0x87af_0513
+	/// A PRIMARY KEY constraint failed.
 	SQLITE_E_CONSTRAINT_PRIMARYKEY 0x87af_0613
+	/// A RAISE function within a trigger fired.
 	SQLITE_E_CONSTRAINT_TRIGGER 0x87af_0713
+	/// A UNIQUE constraint failed.
 	SQLITE_E_CONSTRAINT_UNIQUE 0x87af_0813
+	/// A constraint failed in a virtual table.
 	SQLITE_E_CONSTRAINT_VTAB 0x87af_0913
+	/// The rowid is not unique.
 	SQLITE_E_CONSTRAINT_ROWID 0x87af_0a13
+	/// A WAL mode database file was recovered.
 	SQLITE_E_NOTICE_RECOVER_WAL 0x87af_011b
+	/// A hot journal was rolled back.
 	SQLITE_E_NOTICE_RECOVER_ROLLBACK 0x87af_021b
+	/// An automatic index was created.
 	SQLITE_E_WARNING_AUTOINDEX 0x87af_011c
+	/// Toggle (alternative) trace started.
 	UTC_E_TOGGLE_TRACE_STARTED 0x87c5_1001
+	/// Cannot pre-empt running trace: The current trace has a higher priority.
 	UTC_E_ALTERNATIVE_TRACE_CANNOT_PREEMPT 0x87c5_1002
+	/// The always-on-trace is not running.
 	UTC_E_AOT_NOT_RUNNING 0x87c5_1003
+	/// RunScriptAction contains an invalid script type.
 	UTC_E_SCRIPT_TYPE_INVALID 0x87c5_1004
+	/// Requested scenario definition cannot be found.
 	UTC_E_SCENARIODEF_NOT_FOUND 0x87c5_1005
+	/// Requested trace profile cannot be found.
 	UTC_E_TRACEPROFILE_NOT_FOUND 0x87c5_1006
+	/// Trigger forwarder is already enabled.
 	UTC_E_FORWARDER_ALREADY_ENABLED 0x87c5_1007
+	/// Trigger forwarder is already disabled.
 	UTC_E_FORWARDER_ALREADY_DISABLED 0x87c5_1008
+	/// Cannot parse EventLog XML: The entry is malformed.
 	UTC_E_EVENTLOG_ENTRY_MALFORMED 0x87c5_1009
+	/// The diagrules node contains a schemaversion which is not compatible with this client.
 	UTC_E_DIAGRULES_SCHEMAVERSION_MISMATCH 0x87c5_100a
+	/// RunScriptAction was forced to terminate a script.
 	UTC_E_SCRIPT_TERMINATED 0x87c5_100b
+	/// A custom filter expression is invalid.
 	UTC_E_INVALID_CUSTOM_FILTER 0x87c5_100c
+	/// The trace is not running.
 	UTC_E_TRACE_NOT_RUNNING 0x87c5_100d
+	/// A scenario failed to escalate. This scenario has escalated too recently.
 	UTC_E_REESCALATED_TOO_QUICKLY 0x87c5_100e
+	/// A scenario failed to escalate. This scenario is already running an escalation.
 	UTC_E_ESCALATION_ALREADY_RUNNING 0x87c5_100f
+	/// Cannot start tracing: PerfTrack component is already tracing.
 	UTC_E_PERFTRACK_ALREADY_TRACING 0x87c5_1010
+	/// A scenario failed to escalate. This scenario has reached max escalations for this escalation type.
 	UTC_E_REACHED_MAX_ESCALATIONS 0x87c5_1011
+	/// Cannot update forwarder: The forwarder passed in the request is not compatible with the previously registered forwarder.
 	UTC_E_FORWARDER_PRODUCER_MISMATCH 0x87c5_1012
+	/// RunScriptAction failed intentionally to force this escalation to terminate.
 	UTC_E_INTENTIONAL_SCRIPT_FAILURE 0x87c5_1013
+	/// Failed to initialize SQM logger.
 	UTC_E_SQM_INIT_FAILED 0x87c5_1014
+	/// Failed to initialize WER logger. This system does not support WER for UTC.
 	UTC_E_NO_WER_LOGGER_SUPPORTED 0x87c5_1015
+	/// The TraceManager has attempted to take a tracing action without initializing tracers.
 	UTC_E_TRACERS_DONT_EXIST 0x87c5_1016
+	/// WinRT initialization failed.
 	UTC_E_WINRT_INIT_FAILED 0x87c5_1017
+	/// The scenario definition contains a schemaversion which is not compatible with this client.
 	UTC_E_SCENARIODEF_SCHEMAVERSION_MISMATCH 0x87c5_1018
+	/// Scenario contains an invalid filter that can never be satisfied.
 	UTC_E_INVALID_FILTER 0x87c5_1019
+	/// RunExeWithArgsAction was forced to terminate a running executable.
 	UTC_E_EXE_TERMINATED 0x87c5_101a
+	/// Escalation for the scenario failed due to insufficient permissions.
 	UTC_E_ESCALATION_NOT_AUTHORIZED 0x87c5_101b
+	/// Setup for the scenario failed due to insufficient permissions.
 	UTC_E_SETUP_NOT_AUTHORIZED 0x87c5_101c
+	/// A process launched by UTC failed with a non-zero exit code.
 	UTC_E_CHILD_PROCESS_FAILED 0x87c5_101d
+	/// A RunExeWithArgs action contains an unauthorized command line.
 	UTC_E_COMMAND_LINE_NOT_AUTHORIZED 0x87c5_101e
+	/// UTC cannot load Scenario Editor XML. Convert the scenario file to a DiagTrack XML using the editor.
 	UTC_E_CANNOT_LOAD_SCENARIO_EDITOR_XML 0x87c5_101f
+	/// Escalation for the scenario has timed out.
 	UTC_E_ESCALATION_TIMED_OUT 0x87c5_1020
+	/// Setup for the scenario has timed out.
 	UTC_E_SETUP_TIMED_OUT 0x87c5_1021
+	/// The given trigger does not match the expected trigger type.
 	UTC_E_TRIGGER_MISMATCH 0x87c5_1022
+	/// Requested trigger cannot be found.
 	UTC_E_TRIGGER_NOT_FOUND 0x87c5_1023
+	/// SIF is not supported on the machine.
 	UTC_E_SIF_NOT_SUPPORTED 0x87c5_1024
+	/// The delay action was terminated.
 	UTC_E_DELAY_TERMINATED 0x87c5_1025
+	/// Failed to retrieve a device ticket.
 	UTC_E_DEVICE_TICKET_ERROR 0x87c5_1026
+	/// The aggregate trace buffer size exceeded the allowed limit.
 	UTC_E_TRACE_BUFFER_LIMIT_EXCEEDED 0x87c5_1027
+	/// The result of the API call is not available.
 	UTC_E_API_RESULT_UNAVAILABLE 0x87c5_1028
+	/// The RPC request timed out.
 	UTC_E_RPC_TIMEOUT 0x87c5_1029
+	/// Waiting on the RPC request failed.
 	UTC_E_RPC_WAIT_FAILED 0x87c5_102a
+	/// UTC is busy with another API call.
 	UTC_E_API_BUSY 0x87c5_102b
+	/// The trace was not run for the minimum required duration.
 	UTC_E_TRACE_MIN_DURATION_REQUIREMENT_NOT_MET 0x87c5_102c
+	/// The requested trace exclusivity is not available.
 	UTC_E_EXCLUSIVITY_NOT_AVAILABLE 0x87c5_102d
+	/// The file path of the GetFile action is not approved.
 	UTC_E_GETFILE_FILE_PATH_NOT_APPROVED 0x87c5_102e
+	/// The escalation working directory for the requested escalation could not be created because it already exists.
 	UTC_E_ESCALATION_DIRECTORY_ALREADY_EXISTS 0x87c5_102f
+	/// Time triggers cannot be used on a transition originating from the "_start" state.
 	UTC_E_TIME_TRIGGER_ON_START_INVALID 0x87c5_1030
+	/// Time triggers can only be used on a transition originating from a state with a single outgoing transition.
 	UTC_E_TIME_TRIGGER_ONLY_VALID_ON_SINGLE_TRANSITION 0x87c5_1031
+	/// Time trigger duration must fall within an inclusive range of one second and 24 hours.
 	UTC_E_TIME_TRIGGER_INVALID_TIME_RANGE 0x87c5_1032
+	/// Only one time trigger is allowed per state.
 	UTC_E_MULTIPLE_TIME_TRIGGER_ON_SINGLE_STATE 0x87c5_1033
+	/// A RunExeWithArgs action contains a binary which is not present on the targeted device.
 	UTC_E_BINARY_MISSING 0x87c5_1034
+	/// A network capture trace is not allowed.
 	UTC_E_NETWORK_CAPTURE_NOT_ALLOWED 0x87c5_1035
+	/// UTC failed to identify the container id to use for a scenario escalation action.
 	UTC_E_FAILED_TO_RESOLVE_CONTAINER_ID 0x87c5_1036
+	/// Failed to resolve session for an escalation action.
 	UTC_E_UNABLE_TO_RESOLVE_SESSION 0x87c5_1037
+	/// UTC has throttled the event for firing too often.
 	UTC_E_THROTTLED 0x87c5_1038
+	/// The script is not approved to run as part of a DiagTrack scenario.
 	UTC_E_UNAPPROVED_SCRIPT 0x87c5_1039
+	/// The script referenced in a DiagTrack scenario is not present on the system.
 	UTC_E_SCRIPT_MISSING 0x87c5_103a
+	/// A trigger in this scenario is throttled, blocking the scenario from being loaded.
 	UTC_E_SCENARIO_THROTTLED 0x87c5_103b
+	/// The requested UTC API call is not supported on this device.
 	UTC_E_API_NOT_SUPPORTED 0x87c5_103c
+	/// The file path is not approved for collection on external devices for this scenario.
 	UTC_E_GETFILE_EXTERNAL_PATH_NOT_APPROVED 0x87c5_103d
+	/// The scenario could not be retrieved within the allowed timeout.
 	UTC_E_TRY_GET_SCENARIO_TIMEOUT_EXCEEDED 0x87c5_103e
+	/// The certificate revocation check failed.
 	UTC_E_CERT_REV_FAILED 0x87c5_103f
+	/// The ndiscap service failed to start.
 	UTC_E_FAILED_TO_START_NDISCAP 0x87c5_1040
+	/// The limit for kernel dumps on this machine has been reached.
 	UTC_E_KERNELDUMP_LIMIT_REACHED 0x87c5_1041
+	/// The aggregate event is missing a required tag.
 	UTC_E_MISSING_AGGREGATE_EVENT_TAG 0x87c5_1042
+	/// The aggregation structure is invalid.
 	UTC_E_INVALID_AGGREGATION_STRUCT 0x87c5_1043
+	/// The action is not supported in the destination.
 	UTC_E_ACTION_NOT_SUPPORTED_IN_DESTINATION 0x87c5_1044
+	/// The filter is missing a required attribute.
 	UTC_E_FILTER_MISSING_ATTRIBUTE 0x87c5_1045
+	/// The filter contains an invalid type.
 	UTC_E_FILTER_INVALID_TYPE 0x87c5_1046
+	/// A variable referenced in the filter was not found.
 	UTC_E_FILTER_VARIABLE_NOT_FOUND 0x87c5_1047
+	/// A function used in the filter is restricted.
 	UTC_E_FILTER_FUNCTION_RESTRICTED 0x87c5_1048
+	/// The filter version is not compatible with this client.
 	UTC_E_FILTER_VERSION_MISMATCH 0x87c5_1049
+	/// The filter contains an invalid function.
 	UTC_E_FILTER_INVALID_FUNCTION 0x87c5_1050
+	/// The filter contains invalid function parameters.
 	UTC_E_FILTER_INVALID_FUNCTION_PARAMS 0x87c5_1051
+	/// The filter contains an invalid command.
 	UTC_E_FILTER_INVALID_COMMAND 0x87c5_1052
+	/// The filter contains an illegal evaluation.
 	UTC_E_FILTER_ILLEGAL_EVAL 0x87c5_1053
+	/// The time travel tracer returned an error.
 	UTC_E_TTTRACER_RETURNED_ERROR 0x87c5_1054
+	/// The agent diagnostics are too large.
 	UTC_E_AGENT_DIAGNOSTICS_TOO_LARGE 0x87c5_1055
+	/// Failed to receive the agent diagnostics.
 	UTC_E_FAILED_TO_RECEIVE_AGENT_DIAGNOSTICS 0x87c5_1056
+	/// The scenario has no actions.
 	UTC_E_SCENARIO_HAS_NO_ACTIONS 0x87c5_1057
+	/// The time travel tracer storage is full.
 	UTC_E_TTTRACER_STORAGE_FULL 0x87c5_1058
+	/// There is insufficient disk space to start the trace.
 	UTC_E_INSUFFICIENT_SPACE_TO_START_TRACE 0x87c5_1059
+	/// The escalation was cancelled because the system is shutting down.
 	UTC_E_ESCALATION_CANCELLED_AT_SHUTDOWN 0x87c5_105a
+	/// The file of the GetFileInfo action is not approved.
 	UTC_E_GETFILEINFOACTION_FILE_NOT_APPROVED 0x87c5_105b
+	/// The registry value type of the SetRegKey action is not approved.
 	UTC_E_SETREGKEYACTION_TYPE_NOT_APPROVED 0x87c5_105c
+	/// The binding is incomplete or does not match the input/output description.
 	WINML_ERR_INVALID_DEVICE 0x8890_0001
+	/// An invalid binding was found.
 	WINML_ERR_INVALID_BINDING 0x8890_0002
+	/// The requested value was not found.
 	WINML_ERR_VALUE_NOTFOUND 0x8890_0003
+	/// The size of the buffer provided for a bound variable is invalid.
 	WINML_ERR_SIZE_MISMATCH 0x8890_0004
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::io::ErrorKind as K;
+
+	#[test]
+	fn parts() {
+		let hr = HRESULT::from_parts(co::SEVERITY::FAILURE, co::FACILITY::WIN32, 5);
+		assert!(hr == HRESULT::E_ACCESSDENIED);
+		assert!(hr.severity() == co::SEVERITY::FAILURE);
+		assert!(hr.facility() == co::FACILITY::WIN32);
+		assert_eq!(hr.code(), 5);
+
+		let hr = HRESULT::E_NOTIMPL;
+		assert!(hr.severity() == co::SEVERITY::FAILURE);
+		assert!(hr.facility() == co::FACILITY::NULL);
+		assert_eq!(hr.code(), 0x4001);
+		assert!(HRESULT::from_parts(hr.severity(), hr.facility(), hr.code()) == hr);
+
+		let hr = HRESULT::S_FALSE;
+		assert!(hr.severity() == co::SEVERITY::SUCCESS);
+		assert!(HRESULT::from_parts(hr.severity(), hr.facility(), hr.code()) == hr);
+	}
+
+	#[test]
+	fn to_error() {
+		assert!(co::ERROR::ACCESS_DENIED.to_hresult().to_error() == Some(co::ERROR::ACCESS_DENIED));
+		assert!(HRESULT::E_INVALIDARG.to_error() == Some(co::ERROR::INVALID_PARAMETER));
+		assert!(co::ERROR::SUCCESS.to_hresult() == HRESULT::S_OK);
+		assert!(HRESULT::S_OK.to_error().is_none());
+		assert!(HRESULT::E_NOTIMPL.to_error().is_none());
+		assert!(HRESULT::E_FAIL.to_error().is_none());
+	}
+
+	#[test]
+	fn io_error_kind() {
+		assert_eq!(K::from(co::ERROR::FILE_NOT_FOUND.to_hresult()), K::NotFound);
+		assert_eq!(K::from(HRESULT::E_ACCESSDENIED), K::PermissionDenied);
+		assert_eq!(K::from(HRESULT::E_OUTOFMEMORY), K::OutOfMemory);
+		assert_eq!(K::from(HRESULT::E_INVALIDARG), K::InvalidInput);
+		assert_eq!(K::from(HRESULT::E_NOTIMPL), K::Unsupported);
+		assert_eq!(K::from(HRESULT::E_POINTER), K::InvalidInput);
+		assert_eq!(K::from(HRESULT::E_ABORT), K::Interrupted);
+		assert_eq!(K::from(HRESULT::E_PENDING), K::WouldBlock);
+		assert_eq!(K::from(HRESULT::E_FAIL), K::Other);
+	}
+
+	#[cfg(feature = "msg-table")]
+	#[test]
+	fn description() {
+		assert_eq!(HRESULT::E_NOTIMPL.name(), Some("E_NOTIMPL"));
+		assert_eq!(HRESULT::E_NOTIMPL.description(), Some("Not implemented."));
+
+		// no entry of its own, falls back to the ERROR table
+		let hr = co::ERROR::FILE_NOT_FOUND.to_hresult();
+		assert_eq!(hr.name(), Some("FILE_NOT_FOUND"));
+		assert_eq!(hr.description(), co::ERROR::FILE_NOT_FOUND.description());
+
+		let unknown = unsafe { HRESULT::from_raw(0x8fff_fff0) };
+		assert_eq!(unknown.name(), None);
+		assert_eq!(unknown.description(), None);
+	}
+
+	#[cfg(feature = "msg-table")]
+	#[test]
+	fn description_table() {
+		for (val, name, desc) in HRESULT_MSGS_OLE.iter() {
+			assert!(!name.is_empty() && !desc.is_empty(), "{:#010x}", val);
+		}
+	}
+}
@@ -1,5 +1,6 @@
 use crate::co::*;
 
-const_values_pub! { HRESULT;
+const_values_pub_msgs! { HRESULT, HRESULT_MSGS_OLEAUT;
+	/// Invalid picture.
 	CTL_E_INVALIDPICTURE 0x800a_01e1
 }
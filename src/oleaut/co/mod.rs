@@ -2,3 +2,6 @@ mod consts;
 mod hresult;
 
 pub use consts::*;
+
+#[cfg(feature = "msg-table")]
+pub(crate) use hresult::HRESULT_MSGS_OLEAUT;
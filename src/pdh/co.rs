@@ -0,0 +1,82 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::*;
+
+const_values_pub! { ERROR;
+	/// The specified machine is not available.
+	PDH_CSTATUS_NO_MACHINE 0x8000_07d0
+	/// The specified instance is not present.
+	PDH_CSTATUS_NO_INSTANCE 0x8000_07d1
+	/// There is more data to return than would fit in the supplied buffer.
+	PDH_MORE_DATA 0x8000_07d2
+	/// The data item has been added to the query but has not been validated.
+	PDH_CSTATUS_ITEM_NOT_VALIDATED 0x8000_07d3
+	/// Unable to retrieve the specified data, try again.
+	PDH_RETRY 0x8000_07d4
+	/// The query does not currently contain any counters.
+	PDH_NO_DATA 0x8000_07d5
+	/// Unable to complete the calculation, the denominator is negative.
+	PDH_CALC_NEGATIVE_DENOMINATOR 0x8000_07d6
+	/// Unable to complete the calculation, the time base is negative.
+	PDH_CALC_NEGATIVE_TIMEBASE 0x8000_07d7
+	/// Unable to complete the calculation, the value is negative.
+	PDH_CALC_NEGATIVE_VALUE 0x8000_07d8
+	/// The specified object is not found on the system.
+	PDH_CSTATUS_NO_OBJECT 0xc000_0bb8
+	/// The specified counter could not be found.
+	PDH_CSTATUS_NO_COUNTER 0xc000_0bb9
+	/// The returned data is not valid.
+	PDH_CSTATUS_INVALID_DATA 0xc000_0bba
+	/// A PDH function could not allocate enough memory.
+	PDH_MEMORY_ALLOCATION_FAILURE 0xc000_0bbb
+	/// The handle is not a valid PDH object.
+	PDH_INVALID_HANDLE 0xc000_0bbc
+	/// A required argument is missing or incorrect.
+	PDH_INVALID_ARGUMENT 0xc000_0bbd
+	/// Unable to find the specified function.
+	PDH_FUNCTION_NOT_FOUND 0xc000_0bbe
+	/// No counter was specified.
+	PDH_CSTATUS_NO_COUNTERNAME 0xc000_0bbf
+	/// Unable to parse the counter path.
+	PDH_CSTATUS_BAD_COUNTERNAME 0xc000_0bc0
+	/// The buffer passed by the caller is not valid.
+	PDH_INVALID_BUFFER 0xc000_0bc1
+	/// The requested data is larger than the buffer supplied.
+	PDH_INSUFFICIENT_BUFFER 0xc000_0bc2
+	/// Unable to connect to the specified computer.
+	PDH_CANNOT_CONNECT_MACHINE 0xc000_0bc3
+	/// The specified counter path could not be interpreted.
+	PDH_INVALID_PATH 0xc000_0bc4
+	/// The instance name could not be read from the specified counter path.
+	PDH_INVALID_INSTANCE 0xc000_0bc5
+	/// The data is not valid. A rate counter needs two samples.
+	PDH_INVALID_DATA 0xc000_0bc6
+}
+
+const_bitflag! { PDH_EXPAND: u32;
+	/// [`PdhExpandWildCardPath`](crate::PdhExpandWildCardPath) `flags`
+	/// (`u32`).
+	///
+	/// Originally have `PDH_` prefix.
+	=>
+	NoValue 0
+	NOEXPANDCOUNTERS 1
+	NOEXPANDINSTANCES 2
+	REFRESHCOUNTERS 4
+}
+
+const_bitflag! { PDH_FMT: u32;
+	/// [`PdhGetFormattedCounterValue`](crate::prelude::pdh_Hcounter::PdhGetFormattedCounterValue)
+	/// `format` (`u32`).
+	=>
+	LONG 0x0000_0100
+	DOUBLE 0x0000_0200
+	LARGE 0x0000_0400
+	/// Disables the scaling factor of the counter.
+	NOSCALE 0x0000_1000
+	/// Multiplies the final value by 1,000. Originally `PDH_FMT_1000`.
+	TIMES1000 0x0000_2000
+	/// Doesn't cap percentage values at 100, which happens with the CPU usage
+	/// of multi-processor machines.
+	NOCAP100 0x0000_8000
+}
@@ -0,0 +1,15 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "pdh";
+	PdhAddCounterW(HANDLE, PCSTR, usize, *mut HANDLE) -> u32
+	PdhAddEnglishCounterW(HANDLE, PCSTR, usize, *mut HANDLE) -> u32
+	PdhCalculateCounterFromRawValue(HANDLE, u32, PCVOID, PCVOID, PVOID) -> u32
+	PdhCloseQuery(HANDLE) -> u32
+	PdhCollectQueryData(HANDLE) -> u32
+	PdhExpandWildCardPathW(PCSTR, PCSTR, PSTR, *mut u32, u32) -> u32
+	PdhGetFormattedCounterArrayW(HANDLE, u32, *mut u32, *mut u32, PVOID) -> u32
+	PdhGetFormattedCounterValue(HANDLE, u32, *mut u32, PVOID) -> u32
+	PdhGetRawCounterValue(HANDLE, *mut u32, PVOID) -> u32
+	PdhOpenQueryW(PCSTR, usize, *mut HANDLE) -> u32
+	PdhRemoveCounter(HANDLE) -> u32
+}
@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::pdh::ffi;
+use crate::prelude::*;
+
+/// [`PdhExpandWildCardPath`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhexpandwildcardpathw)
+/// function.
+///
+/// Returns the counter paths which match the given path, which can have
+/// wildcards in the machine, object, instance and counter parts.
+///
+/// # Examples
+///
+/// Listing the network interfaces:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let paths = w::PdhExpandWildCardPath(
+///     None,
+///     "\\Network Interface(*)\\Bytes Total/sec",
+///     co::PDH_EXPAND::NoValue,
+/// )?;
+///
+/// for path in paths.iter() {
+///     let parsed: w::CounterPath = path.parse()?;
+///     println!("{}", parsed.instance.as_deref().unwrap_or_default());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub fn PdhExpandWildCardPath(
+	data_source: Option<&str>,
+	wild_card_path: &str,
+	flags: co::PDH_EXPAND,
+) -> SysResult<Vec<String>> {
+	let wdata_source = WString::from_opt_str(data_source);
+	let wwild_card_path = WString::from_str(wild_card_path);
+	let mut len = 0u32;
+	let mut buf = Vec::<u16>::new();
+
+	loop {
+		match error_to_sysresult(unsafe {
+			ffi::PdhExpandWildCardPathW(
+				wdata_source.as_ptr(),
+				wwild_card_path.as_ptr(),
+				vec_ptr(&buf) as _,
+				&mut len,
+				flags.raw(),
+			)
+		} as _)
+		{
+			Ok(_) => break,
+			Err(co::ERROR::PDH_MORE_DATA) => buf.resize(len as usize + 1, 0), // instances may be added between calls
+			Err(e) => return Err(e),
+		}
+	}
+
+	if buf.is_empty() {
+		Ok(Vec::new())
+	} else {
+		Ok(unsafe { parse_multi_z_str(buf.as_ptr(), Some(buf.len())) })
+	}
+}
+
+/// [`PdhOpenQuery`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhopenqueryw)
+/// function.
+///
+/// If `data_source` is `None`, the query collects real-time data; otherwise
+/// it reads from the given log file.
+pub fn PdhOpenQuery(data_source: Option<&str>, user_data: usize) -> SysResult<PdhCloseQueryGuard> {
+	let mut hquery = HQUERY::NULL;
+	unsafe {
+		error_to_sysresult(ffi::PdhOpenQueryW(
+			WString::from_opt_str(data_source).as_ptr(),
+			user_data,
+			hquery.as_mut(),
+		) as _)
+		.map(|_| PdhCloseQueryGuard::new(hquery))
+	}
+}
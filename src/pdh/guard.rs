@@ -0,0 +1,11 @@
+use crate::decl::*;
+use crate::pdh::ffi;
+use crate::prelude::*;
+
+handle_guard! { PdhCloseQueryGuard: HQUERY;
+	ffi::PdhCloseQuery;
+	/// RAII implementation for [`HQUERY`](crate::HQUERY) which automatically
+	/// calls
+	/// [`PdhCloseQuery`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhclosequery)
+	/// when the object goes out of scope.
+}
@@ -0,0 +1,162 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::pdh::{ffi, structs::*};
+use crate::prelude::*;
+
+handle! { HCOUNTER;
+	/// Handle to a
+	/// [performance counter](https://learn.microsoft.com/en-us/windows/win32/perfctrs/adding-counters-to-a-query)
+	/// added to an [`HQUERY`](crate::HQUERY). Originally `PDH_HCOUNTER`.
+}
+
+impl pdh_Hcounter for HCOUNTER {}
+
+/// This trait is enabled with the `pdh` feature, and provides methods for
+/// [`HCOUNTER`](crate::HCOUNTER).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait pdh_Hcounter: Handle {
+	/// [`PdhCalculateCounterFromRawValue`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhcalculatecounterfromrawvalue)
+	/// function.
+	///
+	/// Rate counters need both raw values, the older one being `raw_value2`.
+	fn PdhCalculateCounterFromRawValue(
+		&self,
+		format: co::PDH_FMT,
+		raw_value1: &PDH_RAW_COUNTER,
+		raw_value2: Option<&PDH_RAW_COUNTER>,
+	) -> SysResult<PDH_FMT_COUNTERVALUE> {
+		let mut value = PDH_FMT_COUNTERVALUE::default();
+		error_to_sysresult(unsafe {
+			ffi::PdhCalculateCounterFromRawValue(
+				self.ptr(),
+				format.raw(),
+				raw_value1 as *const _ as _,
+				raw_value2.map_or(std::ptr::null(), |r| r as *const _ as _),
+				&mut value as *mut _ as _,
+			)
+		} as _)
+		.and_then(|_| counter_status(value))
+	}
+
+	/// [`PdhGetFormattedCounterArray`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhgetformattedcounterarrayw)
+	/// function.
+	///
+	/// Returns the value of each instance of a counter whose path has a
+	/// wildcard instance, like `\Process(*)\% Processor Time`, along with the
+	/// instance name.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hquery = w::PdhOpenQuery(None, 0)?;
+	/// let disks = hquery.PdhAddEnglishCounter(
+	///     "\\PhysicalDisk(*)\\Disk Bytes/sec", 0)?;
+	///
+	/// hquery.PdhCollectQueryData()?;
+	/// w::Sleep(1000);
+	/// hquery.PdhCollectQueryData()?;
+	///
+	/// for (instance, val) in disks.PdhGetFormattedCounterArray(co::PDH_FMT::DOUBLE)? {
+	///     println!("{}: {:.0} bytes/s", instance, val.doubleValue());
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn PdhGetFormattedCounterArray(
+		&self,
+		format: co::PDH_FMT,
+	) -> SysResult<Vec<(String, PDH_FMT_COUNTERVALUE)>> {
+		let mut buf_sz = 0u32;
+		let mut count = 0u32;
+		let mut buf = Vec::<u64>::new(); // 8-byte aligned
+
+		loop {
+			match error_to_sysresult(unsafe {
+				ffi::PdhGetFormattedCounterArrayW(
+					self.ptr(),
+					format.raw(),
+					&mut buf_sz,
+					&mut count,
+					vec_ptr(&buf) as _,
+				)
+			} as _)
+			{
+				Ok(_) => break,
+				Err(co::ERROR::PDH_MORE_DATA) => {
+					buf.resize((buf_sz as usize).div_ceil(8), 0); // buffer size is in bytes
+				},
+				Err(e) => return Err(e),
+			}
+		}
+
+		let items = unsafe {
+			std::slice::from_raw_parts(
+				buf.as_ptr() as *const PDH_FMT_COUNTERVALUE_ITEM,
+				count as _,
+			)
+		};
+		Ok(items
+			.iter()
+			.map(|item| {
+				(unsafe { WString::from_wchars_nullt(item.szName) }.to_string(), item.FmtValue)
+			})
+			.collect())
+	}
+
+	/// [`PdhGetFormattedCounterValue`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhgetformattedcountervalue)
+	/// function.
+	///
+	/// If the status of the value is not valid, it's returned as the error –
+	/// for example, a rate counter which was sampled only once yields
+	/// [`co::ERROR::PDH_INVALID_DATA`](crate::co::ERROR::PDH_INVALID_DATA).
+	fn PdhGetFormattedCounterValue(&self, format: co::PDH_FMT) -> SysResult<PDH_FMT_COUNTERVALUE> {
+		let mut value = PDH_FMT_COUNTERVALUE::default();
+		error_to_sysresult(unsafe {
+			ffi::PdhGetFormattedCounterValue(
+				self.ptr(),
+				format.raw(),
+				std::ptr::null_mut(),
+				&mut value as *mut _ as _,
+			)
+		} as _)
+		.and_then(|_| counter_status(value))
+	}
+
+	/// [`PdhGetRawCounterValue`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhgetrawcountervalue)
+	/// function.
+	///
+	/// The raw values can be stored and later turned into formatted values
+	/// with
+	/// [`PdhCalculateCounterFromRawValue`](crate::prelude::pdh_Hcounter::PdhCalculateCounterFromRawValue).
+	fn PdhGetRawCounterValue(&self) -> SysResult<PDH_RAW_COUNTER> {
+		let mut raw = PDH_RAW_COUNTER::default();
+		error_to_sysresult(unsafe {
+			ffi::PdhGetRawCounterValue(self.ptr(), std::ptr::null_mut(), &mut raw as *mut _ as _)
+		} as _)
+		.map(|_| raw)
+	}
+
+	/// [`PdhRemoveCounter`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhremovecounter)
+	/// function.
+	///
+	/// The handle becomes invalid after this call.
+	fn PdhRemoveCounter(&self) -> SysResult<()> {
+		error_to_sysresult(unsafe { ffi::PdhRemoveCounter(self.ptr()) } as _)
+	}
+}
+
+fn counter_status(value: PDH_FMT_COUNTERVALUE) -> SysResult<PDH_FMT_COUNTERVALUE> {
+	match value.CStatus.raw() {
+		0 | 1 => Ok(value), // PDH_CSTATUS_VALID_DATA, PDH_CSTATUS_NEW_DATA
+		_ => Err(value.CStatus),
+	}
+}
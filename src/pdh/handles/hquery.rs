@@ -0,0 +1,98 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::pdh::ffi;
+use crate::prelude::*;
+
+handle! { HQUERY;
+	/// Handle to a
+	/// [performance data query](https://learn.microsoft.com/en-us/windows/win32/perfctrs/creating-a-query).
+	/// Originally `PDH_HQUERY`.
+}
+
+impl pdh_Hquery for HQUERY {}
+
+/// This trait is enabled with the `pdh` feature, and provides methods for
+/// [`HQUERY`](crate::HQUERY).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait pdh_Hquery: Handle {
+	/// [`PdhAddCounter`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhaddcounterw)
+	/// function.
+	///
+	/// The counter path must be in the language of the system. To use English
+	/// paths regardless of the system language, prefer
+	/// [`PdhAddEnglishCounter`](crate::prelude::pdh_Hquery::PdhAddEnglishCounter).
+	///
+	/// The counter is freed when the query is closed.
+	fn PdhAddCounter(&self, full_counter_path: &str, user_data: usize) -> SysResult<HCOUNTER> {
+		let mut hcounter = HCOUNTER::NULL;
+		error_to_sysresult(unsafe {
+			ffi::PdhAddCounterW(
+				self.ptr(),
+				WString::from_str(full_counter_path).as_ptr(),
+				user_data,
+				hcounter.as_mut(),
+			)
+		} as _)
+		.map(|_| hcounter)
+	}
+
+	/// [`PdhAddEnglishCounter`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhaddenglishcounterw)
+	/// function.
+	///
+	/// The counter is freed when the query is closed.
+	///
+	/// # Examples
+	///
+	/// Sampling the CPU usage, and the free memory:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hquery = w::PdhOpenQuery(None, 0)?;
+	/// let cpu = hquery.PdhAddEnglishCounter(
+	///     "\\Processor(_Total)\\% Processor Time", 0)?;
+	/// let mem = hquery.PdhAddEnglishCounter(
+	///     "\\Memory\\Available MBytes", 0)?;
+	///
+	/// hquery.PdhCollectQueryData()?; // rate counters need two samples
+	/// w::Sleep(1000);
+	/// hquery.PdhCollectQueryData()?;
+	///
+	/// let cpu_val = cpu.PdhGetFormattedCounterValue(co::PDH_FMT::DOUBLE)?;
+	/// let mem_val = mem.PdhGetFormattedCounterValue(co::PDH_FMT::LARGE)?;
+	/// println!("CPU {:.1}%, {} MB free",
+	///     cpu_val.doubleValue(), mem_val.largeValue());
+	///
+	/// // PdhCloseQuery() automatically called
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn PdhAddEnglishCounter(
+		&self,
+		full_counter_path: &str,
+		user_data: usize,
+	) -> SysResult<HCOUNTER> {
+		let mut hcounter = HCOUNTER::NULL;
+		error_to_sysresult(unsafe {
+			ffi::PdhAddEnglishCounterW(
+				self.ptr(),
+				WString::from_str(full_counter_path).as_ptr(),
+				user_data,
+				hcounter.as_mut(),
+			)
+		} as _)
+		.map(|_| hcounter)
+	}
+
+	/// [`PdhCollectQueryData`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhcollectquerydata)
+	/// function.
+	fn PdhCollectQueryData(&self) -> SysResult<()> {
+		error_to_sysresult(unsafe { ffi::PdhCollectQueryData(self.ptr()) } as _)
+	}
+}
@@ -0,0 +1,12 @@
+mod hcounter;
+mod hquery;
+
+pub mod decl {
+	pub use super::hcounter::HCOUNTER;
+	pub use super::hquery::HQUERY;
+}
+
+pub mod traits {
+	pub use super::hcounter::pdh_Hcounter;
+	pub use super::hquery::pdh_Hquery;
+}
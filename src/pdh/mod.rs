@@ -0,0 +1,21 @@
+#![cfg(feature = "pdh")]
+
+mod funcs;
+mod handles;
+mod structs;
+mod utilities;
+
+pub mod co;
+pub(in crate::pdh) mod ffi;
+pub mod guard;
+
+pub mod decl {
+	pub use super::funcs::*;
+	pub use super::handles::decl::*;
+	pub use super::structs::*;
+	pub use super::utilities::*;
+}
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
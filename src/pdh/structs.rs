@@ -0,0 +1,69 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+
+/// [`PDH_FMT_COUNTERVALUE`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/ns-pdh-pdh_fmt_countervalue)
+/// struct.
+///
+/// The value must be read according to the
+/// [`co::PDH_FMT`](crate::co::PDH_FMT) used to retrieve it.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct PDH_FMT_COUNTERVALUE {
+	pub CStatus: co::ERROR,
+	value: u64,
+}
+
+impl std::fmt::Debug for PDH_FMT_COUNTERVALUE {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("PDH_FMT_COUNTERVALUE")
+			.field("CStatus", &self.CStatus)
+			.field("value", &format_args!("{:#018x}", self.value))
+			.finish()
+	}
+}
+
+impl PDH_FMT_COUNTERVALUE {
+	/// Returns the value retrieved with
+	/// [`co::PDH_FMT::DOUBLE`](crate::co::PDH_FMT::DOUBLE).
+	#[must_use]
+	pub const fn doubleValue(&self) -> f64 {
+		f64::from_bits(self.value)
+	}
+
+	/// Returns the value retrieved with
+	/// [`co::PDH_FMT::LARGE`](crate::co::PDH_FMT::LARGE).
+	#[must_use]
+	pub const fn largeValue(&self) -> i64 {
+		self.value as _
+	}
+
+	/// Returns the value retrieved with
+	/// [`co::PDH_FMT::LONG`](crate::co::PDH_FMT::LONG).
+	#[must_use]
+	pub const fn longValue(&self) -> i32 {
+		self.value as u32 as _
+	}
+}
+
+/// [`PDH_FMT_COUNTERVALUE_ITEM`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/ns-pdh-pdh_fmt_countervalue_item_w)
+/// struct.
+#[repr(C)]
+pub(in crate::pdh) struct PDH_FMT_COUNTERVALUE_ITEM {
+	pub(in crate::pdh) szName: PSTR,
+	pub(in crate::pdh) FmtValue: PDH_FMT_COUNTERVALUE,
+}
+
+/// [`PDH_RAW_COUNTER`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/ns-pdh-pdh_raw_counter)
+/// struct.
+#[repr(C)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PDH_RAW_COUNTER {
+	pub CStatus: co::ERROR,
+	pub TimeStamp: FILETIME,
+	pub FirstValue: i64,
+	pub SecondValue: i64,
+	pub MultiCount: u32,
+}
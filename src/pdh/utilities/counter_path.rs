@@ -0,0 +1,262 @@
+use crate::co;
+use crate::decl::*;
+
+/// The elements of a
+/// [counter path](https://learn.microsoft.com/en-us/windows/win32/perfctrs/specifying-a-counter-path),
+/// like:
+///
+/// ```text
+/// \\Machine\Object(ParentInstance/Instance#Index)\Counter
+/// ```
+///
+/// Only the object and the counter are mandatory. Wildcards are kept as
+/// written.
+///
+/// This is a pure Rust replacement for
+/// [`PdhParseCounterPath`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhparsecounterpathw)
+/// and
+/// [`PdhMakeCounterPath`](https://learn.microsoft.com/en-us/windows/win32/api/pdh/nf-pdh-pdhmakecounterpathw):
+/// the path is parsed with [`parse`](str::parse), and built back with
+/// [`to_string`](std::string::ToString::to_string).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let path: w::CounterPath = "\\\\SERVER\\Process(svchost#2)\\% Processor Time".parse()?;
+/// assert_eq!(path.machine.as_deref(), Some("SERVER"));
+/// assert_eq!(path.object, "Process");
+/// assert_eq!(path.instance.as_deref(), Some("svchost"));
+/// assert_eq!(path.instance_index, Some(2));
+/// assert_eq!(path.counter, "% Processor Time");
+///
+/// let path = w::CounterPath {
+///     object: "LogicalDisk".to_owned(),
+///     instance: Some("C:".to_owned()),
+///     counter: "% Free Space".to_owned(),
+///     ..Default::default()
+/// };
+/// assert_eq!(path.to_string(), "\\LogicalDisk(C:)\\% Free Space");
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct CounterPath {
+	/// Name of the machine, without the leading backslashes.
+	pub machine: Option<String>,
+	/// Name of the performance object, like `Processor`.
+	pub object: String,
+	/// Name of the parent instance, if any.
+	pub parent_instance: Option<String>,
+	/// Name of the instance, like `_Total`.
+	pub instance: Option<String>,
+	/// Index which tells apart instances with the same name.
+	pub instance_index: Option<u32>,
+	/// Name of the counter, like `% Processor Time`.
+	pub counter: String,
+}
+
+impl std::fmt::Display for CounterPath {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if let Some(machine) = &self.machine {
+			write!(f, "\\\\{}", machine)?;
+		}
+		write!(f, "\\{}", self.object)?;
+		if let Some(instance) = &self.instance {
+			write!(f, "(")?;
+			if let Some(parent) = &self.parent_instance {
+				write!(f, "{}/", parent)?;
+			}
+			write!(f, "{}", instance)?;
+			if let Some(index) = self.instance_index {
+				write!(f, "#{}", index)?;
+			}
+			write!(f, ")")?;
+		}
+		write!(f, "\\{}", self.counter)
+	}
+}
+
+impl std::str::FromStr for CounterPath {
+	type Err = co::ERROR;
+
+	/// Parses a counter path, failing with
+	/// [`co::ERROR::PDH_INVALID_PATH`](crate::co::ERROR::PDH_INVALID_PATH) if
+	/// it's malformed.
+	fn from_str(s: &str) -> SysResult<Self> {
+		let mut path = CounterPath::default();
+		let mut rest = s;
+
+		if let Some(after) = rest.strip_prefix("\\\\") {
+			let end = after.find('\\').ok_or(co::ERROR::PDH_INVALID_PATH)?;
+			if end == 0 {
+				return Err(co::ERROR::PDH_INVALID_PATH);
+			}
+			path.machine = Some(after[..end].to_owned());
+			rest = &after[end..];
+		}
+
+		let rest = rest.strip_prefix('\\').ok_or(co::ERROR::PDH_INVALID_PATH)?;
+		let counter_sep = rest.rfind('\\').ok_or(co::ERROR::PDH_INVALID_PATH)?;
+		let (object_part, counter) = (&rest[..counter_sep], &rest[counter_sep + 1..]);
+		if counter.is_empty() {
+			return Err(co::ERROR::PDH_INVALID_PATH);
+		}
+		path.counter = counter.to_owned();
+
+		let object = match object_part.strip_suffix(')') {
+			Some(before_close) => {
+				let open = before_close.find('(').ok_or(co::ERROR::PDH_INVALID_PATH)?;
+				let mut instance = &before_close[open + 1..];
+
+				if let Some(slash) = instance.find('/') {
+					path.parent_instance = Some(instance[..slash].to_owned());
+					instance = &instance[slash + 1..];
+				}
+				if let Some(hash) = instance.rfind('#') {
+					let index = &instance[hash + 1..];
+					if !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()) {
+						path.instance_index =
+							Some(index.parse().map_err(|_| co::ERROR::PDH_INVALID_PATH)?);
+						instance = &instance[..hash];
+					}
+				}
+				if instance.is_empty() {
+					return Err(co::ERROR::PDH_INVALID_PATH);
+				}
+				path.instance = Some(instance.to_owned());
+				&before_close[..open]
+			},
+			None => object_part,
+		};
+		if object.is_empty() {
+			return Err(co::ERROR::PDH_INVALID_PATH);
+		}
+		path.object = object.to_owned();
+
+		Ok(path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(s: &str) -> SysResult<CounterPath> {
+		s.parse()
+	}
+
+	fn owned(s: &str) -> Option<String> {
+		Some(s.to_owned())
+	}
+
+	#[test]
+	fn object_and_counter() {
+		let path = parse("\\Memory\\Available MBytes").unwrap();
+		assert_eq!(
+			path,
+			CounterPath {
+				object: "Memory".to_owned(),
+				counter: "Available MBytes".to_owned(),
+				..Default::default()
+			},
+		);
+	}
+
+	#[test]
+	fn machine() {
+		let path = parse("\\\\SERVER\\Processor(_Total)\\% Processor Time").unwrap();
+		assert_eq!(path.machine, owned("SERVER"));
+		assert_eq!(path.object, "Processor");
+		assert_eq!(path.instance, owned("_Total"));
+		assert_eq!(path.counter, "% Processor Time");
+	}
+
+	#[test]
+	fn parent_instance_and_index() {
+		let path = parse("\\Thread(explorer/12#3)\\% Processor Time").unwrap();
+		assert_eq!(path.machine, None);
+		assert_eq!(path.object, "Thread");
+		assert_eq!(path.parent_instance, owned("explorer"));
+		assert_eq!(path.instance, owned("12"));
+		assert_eq!(path.instance_index, Some(3));
+
+		let path = parse("\\Process(svchost#0)\\ID Process").unwrap();
+		assert_eq!(path.instance, owned("svchost"));
+		assert_eq!(path.instance_index, Some(0));
+	}
+
+	#[test]
+	fn wildcards() {
+		let path = parse("\\\\*\\Process(*)\\*").unwrap();
+		assert_eq!(path.machine, owned("*"));
+		assert_eq!(path.instance, owned("*"));
+		assert_eq!(path.counter, "*");
+
+		let path = parse("\\Thread(*/*)\\Context Switches/sec").unwrap();
+		assert_eq!(path.parent_instance, owned("*"));
+		assert_eq!(path.instance, owned("*"));
+		assert_eq!(path.counter, "Context Switches/sec");
+	}
+
+	#[test]
+	fn non_numeric_index() {
+		let path = parse("\\Process(C#)\\ID Process").unwrap();
+		assert_eq!(path.instance, owned("C#"));
+		assert_eq!(path.instance_index, None);
+
+		let path = parse("\\Process(app#x2)\\ID Process").unwrap();
+		assert_eq!(path.instance, owned("app#x2"));
+		assert_eq!(path.instance_index, None);
+
+		let path = parse("\\Process(a#b#7)\\ID Process").unwrap();
+		assert_eq!(path.instance, owned("a#b"));
+		assert_eq!(path.instance_index, Some(7));
+	}
+
+	#[test]
+	fn invalid() {
+		for s in [
+			"",
+			"Memory\\Available MBytes",             // no leading backslash
+			"\\Memory",                             // no counter
+			"\\Memory\\",                           // empty counter
+			"\\\\SERVER",                           // nothing after the machine
+			"\\\\\\Memory\\Available MBytes",       // empty machine
+			"\\\\SERVER\\\\Available MBytes",       // empty object
+			"\\(_Total)\\% Processor Time",         // empty object with instance
+			"\\Process()\\ID Process",              // empty instance
+			"\\Thread(explorer/)\\ID Thread",       // empty instance after parent
+			"\\Process(#2)\\ID Process",            // only the index
+			"\\Process_Total)\\ID Process",         // no opening parenthesis
+			"\\Process(a#99999999999)\\ID Process", // index overflow
+		] {
+			assert_eq!(parse(s), Err(co::ERROR::PDH_INVALID_PATH), "{}", s);
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		for s in [
+			"\\Memory\\Available MBytes",
+			"\\\\SERVER\\Processor(_Total)\\% Processor Time",
+			"\\Thread(explorer/12#3)\\% Processor Time",
+			"\\\\*\\Process(*)\\*",
+			"\\Process(C#)\\ID Process",
+			"\\LogicalDisk(C:)\\% Free Space",
+		] {
+			assert_eq!(parse(s).unwrap().to_string(), s);
+		}
+
+		let path = CounterPath {
+			machine: owned("HOST"),
+			object: "Thread".to_owned(),
+			parent_instance: owned("svchost"),
+			instance: owned("4"),
+			instance_index: Some(1),
+			counter: "Priority Current".to_owned(),
+		};
+		assert_eq!(path.to_string(), "\\\\HOST\\Thread(svchost/4#1)\\Priority Current");
+		assert_eq!(parse(&path.to_string()), Ok(path));
+	}
+}
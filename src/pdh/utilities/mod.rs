@@ -0,0 +1,3 @@
+mod counter_path;
+
+pub use counter_path::CounterPath;
@@ -0,0 +1,7 @@
+#![allow(non_snake_case)]
+
+/// Type alias to
+/// [`EDITSTREAMCALLBACK`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/nc-richedit-editstreamcallback)
+/// callback function.
+pub type EDITSTREAMCALLBACK =
+	extern "system" fn(dwCookie: usize, pbBuff: *mut u8, cb: i32, pcb: *mut i32) -> u32;
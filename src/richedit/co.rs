@@ -0,0 +1,324 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::*;
+
+const_bitflag! { AURL: u32;
+	/// [`em::AutoUrlDetect`](crate::msg::em::AutoUrlDetect) `flags` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ENABLEURL 0x0001
+	ENABLEEMAILADDR 0x0002
+	ENABLETELNO 0x0004
+	ENABLEEAURLS 0x0008
+	ENABLEDRIVELETTERS 0x0010
+	DISABLEMIXEDLGC 0x0020
+}
+
+const_bitflag! { CFE: u32;
+	/// [`CHARFORMAT2`](crate::CHARFORMAT2) `dwEffects` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	BOLD 0x0000_0001
+	ITALIC 0x0000_0002
+	UNDERLINE 0x0000_0004
+	STRIKEOUT 0x0000_0008
+	PROTECTED 0x0000_0010
+	LINK 0x0000_0020
+	AUTOBACKCOLOR 0x0400_0000
+	AUTOCOLOR 0x4000_0000
+	SUBSCRIPT 0x0001_0000
+	SUPERSCRIPT 0x0002_0000
+	SMALLCAPS 0x0000_0040
+	ALLCAPS 0x0000_0080
+	HIDDEN 0x0000_0100
+	OUTLINE 0x0000_0200
+	SHADOW 0x0000_0400
+	EMBOSS 0x0000_0800
+	IMPRINT 0x0000_1000
+	DISABLED 0x0000_2000
+	REVISED 0x0000_4000
+}
+
+const_bitflag! { CFM: u32;
+	/// [`CHARFORMAT2`](crate::CHARFORMAT2) `dwMask` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	BOLD 0x0000_0001
+	ITALIC 0x0000_0002
+	UNDERLINE 0x0000_0004
+	STRIKEOUT 0x0000_0008
+	PROTECTED 0x0000_0010
+	LINK 0x0000_0020
+	SMALLCAPS 0x0000_0040
+	ALLCAPS 0x0000_0080
+	HIDDEN 0x0000_0100
+	OUTLINE 0x0000_0200
+	SHADOW 0x0000_0400
+	EMBOSS 0x0000_0800
+	IMPRINT 0x0000_1000
+	DISABLED 0x0000_2000
+	REVISED 0x0000_4000
+	REVAUTHOR 0x0000_8000
+	SUBSCRIPT 0x0003_0000
+	SUPERSCRIPT Self::SUBSCRIPT.0
+	ANIMATION 0x0004_0000
+	STYLE 0x0008_0000
+	KERNING 0x0010_0000
+	SPACING 0x0020_0000
+	WEIGHT 0x0040_0000
+	UNDERLINETYPE 0x0080_0000
+	COOKIE 0x0100_0000
+	LCID 0x0200_0000
+	BACKCOLOR 0x0400_0000
+	CHARSET 0x0800_0000
+	OFFSET 0x1000_0000
+	FACE 0x2000_0000
+	COLOR 0x4000_0000
+	SIZE 0x8000_0000
+	EFFECTS Self::BOLD.0 | Self::ITALIC.0 | Self::UNDERLINE.0 | Self::COLOR.0 | Self::STRIKEOUT.0 | Self::PROTECTED.0 | Self::LINK.0
+	ALL Self::EFFECTS.0 | Self::SIZE.0 | Self::FACE.0 | Self::OFFSET.0 | Self::CHARSET.0
+}
+
+const_ordinary! { CFU: u8;
+	/// [`CHARFORMAT2`](crate::CHARFORMAT2) `bUnderlineType` (`u8`).
+	=>
+	UNDERLINENONE 0
+	UNDERLINE 1
+	UNDERLINEWORD 2
+	UNDERLINEDOUBLE 3
+	UNDERLINEDOTTED 4
+	UNDERLINEDASH 5
+	UNDERLINEDASHDOT 6
+	UNDERLINEDASHDOTDOT 7
+	UNDERLINEWAVE 8
+	UNDERLINETHICK 9
+	UNDERLINEHAIRLINE 10
+	UNDERLINEDOUBLEWAVE 11
+	UNDERLINEHEAVYWAVE 12
+	UNDERLINELONGDASH 13
+}
+
+const_values_pub! { EM;
+	CANPASTE WM::USER.raw() + 50
+	DISPLAYBAND WM::USER.raw() + 51
+	EXGETSEL WM::USER.raw() + 52
+	EXLIMITTEXT WM::USER.raw() + 53
+	EXLINEFROMCHAR WM::USER.raw() + 54
+	EXSETSEL WM::USER.raw() + 55
+	FORMATRANGE WM::USER.raw() + 57
+	GETCHARFORMAT WM::USER.raw() + 58
+	GETEVENTMASK WM::USER.raw() + 59
+	GETOLEINTERFACE WM::USER.raw() + 60
+	GETPARAFORMAT WM::USER.raw() + 61
+	GETSELTEXT WM::USER.raw() + 62
+	HIDESELECTION WM::USER.raw() + 63
+	PASTESPECIAL WM::USER.raw() + 64
+	REQUESTRESIZE WM::USER.raw() + 65
+	SELECTIONTYPE WM::USER.raw() + 66
+	SETBKGNDCOLOR WM::USER.raw() + 67
+	SETCHARFORMAT WM::USER.raw() + 68
+	SETEVENTMASK WM::USER.raw() + 69
+	SETOLECALLBACK WM::USER.raw() + 70
+	SETPARAFORMAT WM::USER.raw() + 71
+	SETTARGETDEVICE WM::USER.raw() + 72
+	STREAMIN WM::USER.raw() + 73
+	STREAMOUT WM::USER.raw() + 74
+	GETTEXTRANGE WM::USER.raw() + 75
+	FINDWORDBREAK WM::USER.raw() + 76
+	SETOPTIONS WM::USER.raw() + 77
+	GETOPTIONS WM::USER.raw() + 78
+	AUTOURLDETECT WM::USER.raw() + 91
+	GETAUTOURLDETECT WM::USER.raw() + 92
+	GETTEXTEX WM::USER.raw() + 94
+	GETTEXTLENGTHEX WM::USER.raw() + 95
+	SETTEXTEX WM::USER.raw() + 97
+	FINDTEXT WM::USER.raw() + 123
+	FINDTEXTEX WM::USER.raw() + 124
+}
+
+const_bitflag! { ENM: u32;
+	/// [`em::SetEventMask`](crate::msg::em::SetEventMask) `mask` (`u32`).
+	=>
+	NONE 0x0000_0000
+	CHANGE 0x0000_0001
+	UPDATE 0x0000_0002
+	SCROLL 0x0000_0004
+	SCROLLEVENTS 0x0000_0008
+	DRAGDROPDONE 0x0000_0010
+	PARAGRAPHEXPANDED 0x0000_0020
+	PAGECHANGE 0x0000_0040
+	CLIPFORMAT 0x0000_0080
+	KEYEVENTS 0x0001_0000
+	MOUSEEVENTS 0x0002_0000
+	REQUESTRESIZE 0x0004_0000
+	SELCHANGE 0x0008_0000
+	DROPFILES 0x0010_0000
+	PROTECTED 0x0020_0000
+	CORRECTTEXT 0x0040_0000
+	IMECHANGE 0x0080_0000
+	LANGCHANGE 0x0100_0000
+	OBJECTPOSITIONS 0x0200_0000
+	LINK 0x0400_0000
+	LOWFIRTF 0x0800_0000
+}
+
+const_bitflag! { FR: u32;
+	/// [`em::FindTextEx`](crate::msg::em::FindTextEx) `flags` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	DOWN 0x0000_0001
+	WHOLEWORD 0x0000_0002
+	MATCHCASE 0x0000_0004
+	MATCHDIAC 0x2000_0000
+	MATCHKASHIDA 0x4000_0000
+	MATCHALEFHAMZA 0x8000_0000
+}
+
+const_ordinary! { PFA: u16;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `wAlignment` (`u16`).
+	=>
+	LEFT 1
+	RIGHT 2
+	CENTER 3
+	JUSTIFY 4
+	FULL_INTERWORD Self::JUSTIFY.0
+}
+
+const_bitflag! { PFE: u16;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `wEffects` (`u16`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	RTLPARA 0x0001
+	KEEP 0x0002
+	KEEPNEXT 0x0004
+	PAGEBREAKBEFORE 0x0008
+	NOLINENUMBER 0x0010
+	NOWIDOWCONTROL 0x0020
+	DONOTHYPHEN 0x0040
+	SIDEBYSIDE 0x0080
+	TEXTWRAPPINGBREAK 0x0100
+	TABLE 0x4000
+}
+
+const_bitflag! { PFM: u32;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `dwMask` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	STARTINDENT 0x0000_0001
+	RIGHTINDENT 0x0000_0002
+	OFFSET 0x0000_0004
+	ALIGNMENT 0x0000_0008
+	TABSTOPS 0x0000_0010
+	NUMBERING 0x0000_0020
+	OFFSETINDENT 0x8000_0000
+	SPACEBEFORE 0x0000_0040
+	SPACEAFTER 0x0000_0080
+	LINESPACING 0x0000_0100
+	STYLE 0x0000_0400
+	BORDER 0x0000_0800
+	SHADING 0x0000_1000
+	NUMBERINGSTYLE 0x0000_2000
+	NUMBERINGTAB 0x0000_4000
+	NUMBERINGSTART 0x0000_8000
+	RTLPARA 0x0001_0000
+	KEEP 0x0002_0000
+	KEEPNEXT 0x0004_0000
+	PAGEBREAKBEFORE 0x0008_0000
+	NOLINENUMBER 0x0010_0000
+	NOWIDOWCONTROL 0x0020_0000
+	DONOTHYPHEN 0x0040_0000
+	SIDEBYSIDE 0x0080_0000
+	TABLE 0x4000_0000
+}
+
+const_ordinary! { PFN: u16;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `wNumbering` (`u16`).
+	=>
+	/// No paragraph numbering or bullets.
+	NoValue 0
+	BULLET 1
+	ARABIC 2
+	LCLETTER 3
+	UCLETTER 4
+	LCROMAN 5
+	UCROMAN 6
+}
+
+const_nm! { REN;
+	/// Rich edit control `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rich-edit-control-reference-notifications)
+	/// (`i32`).
+	///
+	/// Originally has `EN` prefix.
+	=>
+	MSGFILTER 0x0700
+	REQUESTRESIZE 0x0701
+	SELCHANGE 0x0702
+	DROPFILES 0x0703
+	PROTECTED 0x0704
+	CORRECTTEXT 0x0705
+	STOPNOUNDO 0x0706
+	IMECHANGE 0x0707
+	SAVECLIPBOARD 0x0708
+	OLEOPFAILED 0x0709
+	OBJECTPOSITIONS 0x070a
+	LINK 0x070b
+	DRAGDROPDONE 0x070c
+	PARAGRAPHEXPANDED 0x070d
+	PAGECHANGE 0x070e
+	LOWFIRTF 0x070f
+	ALIGNLTR 0x0710
+	ALIGNRTL 0x0711
+	CLIPFORMAT 0x0712
+	STARTCOMPOSITION 0x0713
+	ENDCOMPOSITION 0x0714
+}
+
+const_bitflag! { SCF: u32;
+	/// [`em::SetCharFormat`](crate::msg::em::SetCharFormat) `kind` (`u32`).
+	=>
+	DEFAULT 0x0000
+	SELECTION 0x0001
+	WORD 0x0002
+	ALL 0x0004
+	USEUIRULES 0x0008
+	ASSOCIATEFONT 0x0010
+	NOKBUPDATE 0x0020
+	ASSOCIATEFONT2 0x0040
+}
+
+const_bitflag! { SEL: u16;
+	/// [`SELCHANGE`](crate::SELCHANGE) `seltyp` (`u16`).
+	=>
+	EMPTY 0x0000
+	TEXT 0x0001
+	OBJECT 0x0002
+	MULTICHAR 0x0004
+	MULTIOBJECT 0x0008
+}
+
+const_bitflag! { SF: u32;
+	/// [`em::StreamIn`](crate::msg::em::StreamIn) and
+	/// [`em::StreamOut`](crate::msg::em::StreamOut) `format` (`u32`).
+	///
+	/// Includes values with `SFF` prefix.
+	=>
+	TEXT 0x0001
+	RTF 0x0002
+	RTFNOOBJS 0x0003
+	TEXTIZED 0x0004
+	UNICODE 0x0010
+	USECODEPAGE 0x0020
+	NCRFORNONASCII 0x0040
+	WRITEXTRAPAR 0x0080
+	KEEPDOCINFO 0x1000
+	PERSISTVIEWSCALE 0x2000
+	PLAINRTF 0x4000
+	SELECTION 0x8000
+}
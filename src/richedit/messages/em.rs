@@ -0,0 +1,429 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// [`EM_AUTOURLDETECT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-autourldetect)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+///
+/// This message is implemented for rich edit controls.
+pub struct AutoUrlDetect {
+	pub flags: co::AURL,
+}
+
+impl MsgSend for AutoUrlDetect {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		match v {
+			0 => Ok(()),
+			_ => Err(co::ERROR::BAD_ARGUMENTS),
+		}
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::AUTOURLDETECT.into(),
+			wparam: self.flags.raw() as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_EXGETSEL`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exgetsel)
+/// message parameters.
+///
+/// Return type: `()`.
+///
+/// This message is implemented for rich edit controls.
+pub struct ExGetSel<'a> {
+	pub range: &'a mut CHARRANGE,
+}
+
+impl<'a> MsgSend for ExGetSel<'a> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXGETSEL.into(),
+			wparam: 0,
+			lparam: self.range as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_EXLIMITTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exlimittext)
+/// message parameters.
+///
+/// Return type: `()`.
+///
+/// This message is implemented for rich edit controls.
+pub struct ExLimitText {
+	pub max_chars: Option<u32>,
+}
+
+impl MsgSend for ExLimitText {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXLIMITTEXT.into(),
+			wparam: 0,
+			lparam: self.max_chars.unwrap_or(0) as _,
+		}
+	}
+}
+
+/// [`EM_EXSETSEL`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exsetsel)
+/// message parameters.
+///
+/// Return type: `u32`.
+///
+/// This message is implemented for rich edit controls.
+pub struct ExSetSel {
+	pub range: CHARRANGE,
+}
+
+impl MsgSend for ExSetSel {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXSETSEL.into(),
+			wparam: 0,
+			lparam: &mut self.range as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_FINDTEXTEX`](https://learn.microsoft.com/en-us/windows/win32/controls/em-findtextex)
+/// message parameters.
+///
+/// Return type: `Option<u32>`.
+///
+/// This message is implemented for rich edit controls.
+pub struct FindTextEx<'a, 'b> {
+	pub flags: co::FR,
+	pub find: &'a mut FINDTEXTEX<'b>,
+}
+
+impl<'a, 'b> MsgSend for FindTextEx<'a, 'b> {
+	type RetType = Option<u32>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		minus1_as_none(v).map(|idx| idx as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::FINDTEXTEX.into(),
+			wparam: self.flags.raw() as _,
+			lparam: self.find as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_GETCHARFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getcharformat)
+/// message parameters.
+///
+/// Return type: `co::CFM`.
+///
+/// This message is implemented for rich edit controls.
+pub struct GetCharFormat<'a> {
+	pub kind: co::SCF,
+	pub format: &'a mut CHARFORMAT2,
+}
+
+impl<'a> MsgSend for GetCharFormat<'a> {
+	type RetType = co::CFM;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::CFM::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETCHARFORMAT.into(),
+			wparam: self.kind.raw() as _,
+			lparam: self.format as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_GETEVENTMASK`](https://learn.microsoft.com/en-us/windows/win32/controls/em-geteventmask)
+/// message, which has no parameters.
+///
+/// Return type: `co::ENM`.
+///
+/// This message is implemented for rich edit controls.
+pub struct GetEventMask {}
+
+impl MsgSend for GetEventMask {
+	type RetType = co::ENM;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::ENM::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETEVENTMASK.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_GETPARAFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getparaformat)
+/// message parameters.
+///
+/// Return type: `co::PFM`.
+///
+/// This message is implemented for rich edit controls.
+pub struct GetParaFormat<'a> {
+	pub format: &'a mut PARAFORMAT2,
+}
+
+impl<'a> MsgSend for GetParaFormat<'a> {
+	type RetType = co::PFM;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::PFM::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETPARAFORMAT.into(),
+			wparam: 0,
+			lparam: self.format as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_GETTEXTRANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/em-gettextrange)
+/// message parameters.
+///
+/// Return type: `u32`.
+///
+/// This message is implemented for rich edit controls.
+pub struct GetTextRange<'a, 'b> {
+	pub range: &'a mut TEXTRANGE<'b>,
+}
+
+impl<'a, 'b> MsgSend for GetTextRange<'a, 'b> {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETTEXTRANGE.into(),
+			wparam: 0,
+			lparam: self.range as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_HIDESELECTION`](https://learn.microsoft.com/en-us/windows/win32/controls/em-hideselection)
+/// message parameters.
+///
+/// Return type: `()`.
+///
+/// This message is implemented for rich edit controls.
+pub struct HideSelection {
+	pub hide: bool,
+}
+
+impl MsgSend for HideSelection {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::HIDESELECTION.into(),
+			wparam: self.hide as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_SETBKGNDCOLOR`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setbkgndcolor)
+/// message parameters.
+///
+/// Return type: `COLORREF`.
+///
+/// If `color` is `None`, the system window color is used.
+///
+/// This message is implemented for rich edit controls.
+pub struct SetBkgndColor {
+	pub color: Option<COLORREF>,
+}
+
+impl MsgSend for SetBkgndColor {
+	type RetType = COLORREF;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		COLORREF::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETBKGNDCOLOR.into(),
+			wparam: self.color.is_none() as _,
+			lparam: self.color.map_or(0, |color| color.raw()) as _,
+		}
+	}
+}
+
+/// [`EM_SETCHARFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setcharformat)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+///
+/// This message is implemented for rich edit controls.
+pub struct SetCharFormat<'a> {
+	pub kind: co::SCF,
+	pub format: &'a CHARFORMAT2,
+}
+
+impl<'a> MsgSend for SetCharFormat<'a> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETCHARFORMAT.into(),
+			wparam: self.kind.raw() as _,
+			lparam: self.format as *const _ as _,
+		}
+	}
+}
+
+/// [`EM_SETEVENTMASK`](https://learn.microsoft.com/en-us/windows/win32/controls/em-seteventmask)
+/// message parameters.
+///
+/// Return type: `co::ENM`.
+///
+/// This message is implemented for rich edit controls.
+pub struct SetEventMask {
+	pub mask: co::ENM,
+}
+
+impl MsgSend for SetEventMask {
+	type RetType = co::ENM;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::ENM::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETEVENTMASK.into(),
+			wparam: 0,
+			lparam: self.mask.raw() as _,
+		}
+	}
+}
+
+/// [`EM_SETPARAFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setparaformat)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+///
+/// This message is implemented for rich edit controls.
+pub struct SetParaFormat<'a> {
+	pub format: &'a PARAFORMAT2,
+}
+
+impl<'a> MsgSend for SetParaFormat<'a> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETPARAFORMAT.into(),
+			wparam: 0,
+			lparam: self.format as *const _ as _,
+		}
+	}
+}
+
+/// [`EM_STREAMIN`](https://learn.microsoft.com/en-us/windows/win32/controls/em-streamin)
+/// message parameters.
+///
+/// Return type: `u32`.
+///
+/// This message is implemented for rich edit controls.
+pub struct StreamIn<'a> {
+	pub format: co::SF,
+	pub stream: &'a mut EDITSTREAM,
+}
+
+impl<'a> MsgSend for StreamIn<'a> {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::STREAMIN.into(),
+			wparam: self.format.raw() as _,
+			lparam: self.stream as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_STREAMOUT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-streamout)
+/// message parameters.
+///
+/// Return type: `u32`.
+///
+/// This message is implemented for rich edit controls.
+pub struct StreamOut<'a> {
+	pub format: co::SF,
+	pub stream: &'a mut EDITSTREAM,
+}
+
+impl<'a> MsgSend for StreamOut<'a> {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::STREAMOUT.into(),
+			wparam: self.format.raw() as _,
+			lparam: self.stream as *mut _ as _,
+		}
+	}
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "richedit")]
+
+mod aliases;
+mod structs;
+mod utilities;
+
+pub mod co;
+pub mod messages;
+pub(in crate::richedit) mod privs;
+
+pub mod decl {
+	pub use super::aliases::*;
+	pub use super::structs::*;
+	pub use super::utilities::*;
+}
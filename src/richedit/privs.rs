@@ -0,0 +1,3 @@
+const_values_num_privs! {
+	MAX_TAB_STOPS usize = 32
+}
@@ -0,0 +1,183 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::privs::*;
+use crate::richedit::privs::*;
+
+/// [`CHARFORMAT2`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-charformat2w)
+/// struct.
+#[repr(C)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct CHARFORMAT2 {
+	cbSize: u32,
+	pub dwMask: co::CFM,
+	pub dwEffects: co::CFE,
+	pub yHeight: i32,
+	pub yOffset: i32,
+	pub crTextColor: COLORREF,
+	pub bCharSet: co::CHARSET,
+	pub bPitchAndFamily: co::PITCH,
+	szFaceName: [u16; LF_FACESIZE],
+	pub wWeight: u16,
+	pub sSpacing: i16,
+	pub crBackColor: COLORREF,
+	pub lcid: u32,
+	pub dwReserved: u32,
+	pub sStyle: i16,
+	pub wKerning: u16,
+	pub bUnderlineType: co::CFU,
+	pub bAnimation: u8,
+	pub bRevAuthor: u8,
+	pub bUnderlineColor: u8,
+}
+
+impl_default!(CHARFORMAT2, cbSize);
+
+impl CHARFORMAT2 {
+	pub_fn_string_arr_get_set!(szFaceName, set_szFaceName);
+}
+
+/// [`CHARRANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-charrange)
+/// struct.
+///
+/// A `cpMin` of `0` and a `cpMax` of `-1` stand for all the text.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CHARRANGE {
+	pub cpMin: i32,
+	pub cpMax: i32,
+}
+
+impl CHARRANGE {
+	/// Creates a new `CHARRANGE`.
+	#[must_use]
+	pub const fn new(cpMin: i32, cpMax: i32) -> Self {
+		Self { cpMin, cpMax }
+	}
+}
+
+/// [`EDITSTREAM`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-editstream)
+/// struct.
+#[repr(C, packed(4))]
+pub struct EDITSTREAM {
+	pub dwCookie: usize,
+	pub dwError: u32,
+	pub pfnCallback: Option<EDITSTREAMCALLBACK>,
+}
+
+impl_default!(EDITSTREAM);
+
+/// [`ENLINK`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-enlink)
+/// struct.
+#[repr(C, packed(4))]
+pub struct ENLINK {
+	pub nmhdr: NMHDR,
+	pub msg: co::WM,
+	pub wParam: usize,
+	pub lParam: isize,
+	pub chrg: CHARRANGE,
+}
+
+impl_default!(ENLINK);
+
+/// [`FINDTEXTEX`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-findtextexw)
+/// struct.
+#[repr(C)]
+pub struct FINDTEXTEX<'a> {
+	pub chrg: CHARRANGE,
+	lpstrText: *mut u16,
+	pub chrgText: CHARRANGE,
+
+	_lpstrText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(FINDTEXTEX, 'a);
+
+impl<'a> FINDTEXTEX<'a> {
+	pub_fn_string_ptr_get_set!('a, lpstrText, set_lpstrText);
+}
+
+/// [`PARAFORMAT2`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-paraformat2)
+/// struct.
+#[repr(C)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct PARAFORMAT2 {
+	cbSize: u32,
+	pub dwMask: co::PFM,
+	pub wNumbering: co::PFN,
+	pub wEffects: co::PFE,
+	pub dxStartIndent: i32,
+	pub dxRightIndent: i32,
+	pub dxOffset: i32,
+	pub wAlignment: co::PFA,
+	cTabCount: i16,
+	rgxTabs: [i32; MAX_TAB_STOPS],
+	pub dySpaceBefore: i32,
+	pub dySpaceAfter: i32,
+	pub dyLineSpacing: i32,
+	pub sStyle: i16,
+	pub bLineSpacingRule: u8,
+	pub bOutlineLevel: u8,
+	pub wShadingWeight: u16,
+	pub wShadingStyle: u16,
+	pub wNumberingStart: u16,
+	pub wNumberingStyle: u16,
+	pub wNumberingTab: u16,
+	pub wBorderSpace: u16,
+	pub wBorderWidth: u16,
+	pub wBorders: u16,
+}
+
+impl_default!(PARAFORMAT2, cbSize);
+
+impl PARAFORMAT2 {
+	/// Returns the tab stops, in twips, limited by the `cTabCount` field.
+	#[must_use]
+	pub fn rgxTabs(&self) -> &[i32] {
+		&self.rgxTabs[..(self.cTabCount.clamp(0, MAX_TAB_STOPS as _) as usize)]
+	}
+
+	/// Sets the tab stops, in twips, and the `cTabCount` field.
+	///
+	/// # Panics
+	///
+	/// Panics if `tabs` has more than 32 elements.
+	pub fn set_rgxTabs(&mut self, tabs: &[i32]) {
+		if tabs.len() > MAX_TAB_STOPS {
+			panic!("Too many tab stops: {}.", tabs.len());
+		}
+		self.rgxTabs = [0; MAX_TAB_STOPS];
+		self.rgxTabs[..tabs.len()].copy_from_slice(tabs);
+		self.cTabCount = tabs.len() as _;
+	}
+}
+
+/// [`SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-selchange)
+/// struct.
+#[repr(C, packed(4))]
+pub struct SELCHANGE {
+	pub nmhdr: NMHDR,
+	pub chrg: CHARRANGE,
+	pub seltyp: co::SEL,
+}
+
+impl_default!(SELCHANGE);
+
+/// [`TEXTRANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-textrangew)
+/// struct.
+#[repr(C)]
+pub struct TEXTRANGE<'a> {
+	pub chrg: CHARRANGE,
+	lpstrText: *mut u16,
+
+	_lpstrText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(TEXTRANGE, 'a);
+
+impl<'a> TEXTRANGE<'a> {
+	pub_fn_string_ptr_get_set!('a, lpstrText, set_lpstrText);
+}
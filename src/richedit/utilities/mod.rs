@@ -0,0 +1,3 @@
+mod rtf_writer;
+
+pub use rtf_writer::{RtfStyle, RtfWriter};
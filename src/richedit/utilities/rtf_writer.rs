@@ -0,0 +1,285 @@
+use crate::decl::*;
+
+/// Character formatting of a run of text written by
+/// [`RtfWriter::run`](crate::RtfWriter::run).
+///
+/// The default value writes the text with the default font and color of the
+/// writer.
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct RtfStyle {
+	/// Bold text.
+	pub bold: bool,
+	/// Italic text.
+	pub italic: bool,
+	/// Underlined text.
+	pub underline: bool,
+	/// Struck out text.
+	pub strikeout: bool,
+	/// Text color.
+	pub color: Option<COLORREF>,
+	/// Background color.
+	pub back_color: Option<COLORREF>,
+	/// Font face name.
+	pub font: Option<String>,
+	/// Font size, in points.
+	pub size: Option<u32>,
+}
+
+/// Writes a minimal
+/// [Rich Text Format](https://learn.microsoft.com/en-us/openspecs/exchange_server_protocols/ms-oxrtfcp/85c0b884-a960-4d1a-874e-53eeee527ca6)
+/// document made of styled runs of text, which can be loaded into a
+/// [`RichEdit`](crate::gui::RichEdit) control.
+///
+/// The document is written in pure Rust, without calling any system function,
+/// and retrieved with [`to_string`](std::string::ToString::to_string). All
+/// characters outside the ASCII range are escaped, so the output is plain
+/// ASCII.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let red = w::RtfStyle {
+///     bold: true,
+///     color: Some(w::COLORREF::new(0xc0, 0x00, 0x00)),
+///     ..Default::default()
+/// };
+///
+/// let rtf = w::RtfWriter::new("Consolas", 10)
+///     .text("12:00:01 ")
+///     .run("ERROR", &red)
+///     .text(" disk full")
+///     .line()
+///     .to_string();
+///
+/// assert!(rtf.starts_with("{\\rtf1"));
+/// ```
+#[derive(Clone)]
+pub struct RtfWriter {
+	fonts: Vec<String>,
+	colors: Vec<COLORREF>,
+	font_size: u32,
+	body: String,
+}
+
+impl std::fmt::Display for RtfWriter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{{\\rtf1\\ansi\\deff0{{\\fonttbl")?;
+		for (idx, font) in self.fonts.iter().enumerate() {
+			write!(f, "{{\\f{} {};}}", idx, escape(font))?;
+		}
+		write!(f, "}}{{\\colortbl ;")?; // index zero is the default color
+		for color in self.colors.iter() {
+			write!(
+				f,
+				"\\red{}\\green{}\\blue{};",
+				color.GetRValue(),
+				color.GetGValue(),
+				color.GetBValue(),
+			)?;
+		}
+		write!(f, "}}\\fs{} {}}}", self.font_size * 2, self.body)
+	}
+}
+
+impl RtfWriter {
+	/// Creates a new, empty document with the given default font face name and
+	/// size, in points.
+	#[must_use]
+	pub fn new(font: &str, font_size: u32) -> Self {
+		Self {
+			fonts: vec![font.to_owned()],
+			colors: Vec::new(),
+			font_size,
+			body: String::new(),
+		}
+	}
+
+	/// Ends the current paragraph.
+	#[must_use]
+	pub fn line(mut self) -> Self {
+		self.body.push_str("\\par\n");
+		self
+	}
+
+	/// Appends a run of text with the given formatting.
+	///
+	/// Line breaks within the text end the current paragraph.
+	#[must_use]
+	pub fn run(mut self, text: &str, style: &RtfStyle) -> Self {
+		let mut words = String::new();
+		if style.bold {
+			words.push_str("\\b");
+		}
+		if style.italic {
+			words.push_str("\\i");
+		}
+		if style.underline {
+			words.push_str("\\ul");
+		}
+		if style.strikeout {
+			words.push_str("\\strike");
+		}
+		if let Some(color) = style.color {
+			words.push_str(&format!("\\cf{}", self.color_index(color)));
+		}
+		if let Some(color) = style.back_color {
+			words.push_str(&format!("\\highlight{}", self.color_index(color)));
+		}
+		if let Some(font) = &style.font {
+			words.push_str(&format!("\\f{}", self.font_index(font)));
+		}
+		if let Some(size) = style.size {
+			words.push_str(&format!("\\fs{}", size * 2));
+		}
+
+		if words.is_empty() {
+			self.body.push_str(&escape(text));
+		} else {
+			self.body.push('{');
+			self.body.push_str(&words);
+			self.body.push(' '); // delimiter of the last control word
+			self.body.push_str(&escape(text));
+			self.body.push('}');
+		}
+		self
+	}
+
+	/// Appends a run of text with the default formatting.
+	///
+	/// Line breaks within the text end the current paragraph.
+	#[must_use]
+	pub fn text(self, text: &str) -> Self {
+		self.run(text, &RtfStyle::default())
+	}
+
+	fn color_index(&mut self, color: COLORREF) -> usize {
+		match self.colors.iter().position(|c| *c == color) {
+			Some(idx) => idx + 1,
+			None => {
+				self.colors.push(color);
+				self.colors.len()
+			},
+		}
+	}
+
+	fn font_index(&mut self, font: &str) -> usize {
+		match self.fonts.iter().position(|f| f == font) {
+			Some(idx) => idx,
+			None => {
+				self.fonts.push(font.to_owned());
+				self.fonts.len() - 1
+			},
+		}
+	}
+}
+
+fn escape(text: &str) -> String {
+	let mut out = String::with_capacity(text.len());
+	let mut chars = text.chars().peekable();
+	while let Some(ch) = chars.next() {
+		match ch {
+			'\\' | '{' | '}' => {
+				out.push('\\');
+				out.push(ch);
+			},
+			'\r' => {
+				if chars.peek() != Some(&'\n') {
+					out.push_str("\\par\n"); // a lone CR is also a line break
+				}
+			},
+			'\n' => out.push_str("\\par\n"),
+			'\t' => out.push_str("\\tab "),
+			' '..='~' => out.push(ch),
+			_ => {
+				let mut buf = [0u16; 2];
+				for unit in ch.encode_utf16(&mut buf).iter() {
+					out.push_str(&format!("\\u{}?", *unit as i16)); // "?" is the fallback for old readers
+				}
+			},
+		}
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const HEADER: &str = "{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0 Arial;}}";
+
+	#[test]
+	fn escape_special_chars() {
+		assert_eq!(escape("a\\b{c}d"), "a\\\\b\\{c\\}d");
+		assert_eq!(escape("{\\}"), "\\{\\\\\\}");
+		assert_eq!(escape("plain ASCII ~!"), "plain ASCII ~!");
+	}
+
+	#[test]
+	fn escape_line_breaks() {
+		assert_eq!(escape("a\r\nb\rc\nd"), "a\\par\nb\\par\nc\\par\nd");
+		assert_eq!(escape("a\tb"), "a\\tab b");
+	}
+
+	#[test]
+	fn escape_non_ascii() {
+		assert_eq!(escape("é"), "\\u233?");
+		assert_eq!(escape("€5"), "\\u8364?5");
+		assert_eq!(escape("\u{fffd}"), "\\u-3?"); // signed 16-bit
+		assert_eq!(escape("😀"), "\\u-10179?\\u-8704?"); // surrogate pair
+		assert_eq!(escape("\u{1}"), "\\u1?");
+	}
+
+	#[test]
+	fn document() {
+		let rtf = RtfWriter::new("Arial", 10).text("x").line().to_string();
+		assert_eq!(rtf, format!("{}{{\\colortbl ;}}\\fs20 x\\par\n}}", HEADER));
+
+		let rtf = RtfWriter::new("A{b}", 9).to_string();
+		assert_eq!(rtf, "{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0 A\\{b\\};}}{\\colortbl ;}\\fs18 }");
+	}
+
+	#[test]
+	fn style_words() {
+		let style = RtfStyle {
+			bold: true,
+			italic: true,
+			underline: true,
+			strikeout: true,
+			size: Some(12),
+			..Default::default()
+		};
+		let rtf = RtfWriter::new("Arial", 10).run("{x}", &style).to_string();
+		assert_eq!(
+			rtf,
+			format!("{}{{\\colortbl ;}}\\fs20 {{\\b\\i\\ul\\strike\\fs24 \\{{x\\}}}}}}", HEADER),
+		);
+	}
+
+	#[test]
+	fn table_deduplication() {
+		let red = COLORREF::new(0xff, 0, 0);
+		let blue = COLORREF::new(0, 0, 0xff);
+		let style = |color, back_color, font: &str| RtfStyle {
+			color: Some(color),
+			back_color,
+			font: Some(font.to_owned()),
+			..Default::default()
+		};
+
+		let rtf = RtfWriter::new("Arial", 10)
+			.run("a", &style(red, None, "Consolas"))
+			.run("b", &style(blue, Some(red), "Arial"))
+			.run("c", &style(red, Some(blue), "Consolas"))
+			.to_string();
+		assert_eq!(
+			rtf,
+			concat!(
+				"{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0 Arial;}{\\f1 Consolas;}}",
+				"{\\colortbl ;\\red255\\green0\\blue0;\\red0\\green0\\blue255;}\\fs20 ",
+				"{\\cf1\\f1 a}{\\cf2\\highlight1\\f0 b}{\\cf1\\highlight2\\f1 c}}",
+			),
+		);
+	}
+}
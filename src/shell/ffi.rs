@@ -24,6 +24,7 @@ extern_sys! { "shlwapi";
 	PathUndecorateW(PSTR)
 	PathUnquoteSpacesW(PSTR) -> BOOL
 	SHCreateMemStream(*const u8, u32) -> COMPTR
+	StrCmpLogicalW(PCSTR, PCSTR) -> i32
 }
 
 extern_sys! { "userenv";
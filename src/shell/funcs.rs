@@ -347,3 +347,31 @@ pub fn SHGetStockIconInfo(siid: co::SIID, flags: co::SHGSI) -> HrResult<DestroyI
 			.map(|_| DestroyIconSiiGuard::new(sii))
 	}
 }
+
+/// [`StrCmpLogical`](https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-strcmplogicalw)
+/// function.
+///
+/// Compares two strings the way Windows Explorer sorts file names: case is
+/// ignored, and digits are compared as numbers.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut names = vec!["file10.txt", "File2.txt", "file1.txt"];
+/// names.sort_by(|a, b| w::StrCmpLogical(a, b));
+///
+/// assert_eq!(names, ["file1.txt", "File2.txt", "file10.txt"]);
+/// ```
+///
+/// # Related functions
+///
+/// * [`CompareStringEx`](crate::CompareStringEx)
+#[must_use]
+pub fn StrCmpLogical(psz1: &str, psz2: &str) -> std::cmp::Ordering {
+	unsafe {
+		ffi::StrCmpLogicalW(WString::from_str(psz1).as_ptr(), WString::from_str(psz2).as_ptr())
+	}
+	.cmp(&0)
+}
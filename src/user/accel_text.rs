@@ -0,0 +1,398 @@
+use crate::co;
+use crate::decl::*;
+
+/// Modifiers, in the order they are displayed.
+const MODIFIERS: [(co::ACCELF, &str); 3] =
+	[(co::ACCELF::CONTROL, "Ctrl"), (co::ACCELF::SHIFT, "Shift"), (co::ACCELF::ALT, "Alt")];
+
+/// Alternative modifier names, accepted when parsing.
+const MODIFIER_ALIASES: [(co::ACCELF, &str); 1] = [(co::ACCELF::CONTROL, "Control")];
+
+/// Named keys; the first name of each key is the one displayed. When parsing,
+/// names are compared ignoring case and spaces.
+const KEY_NAMES: [(co::VK, &str); 47] = [
+	(co::VK::BACK, "Backspace"),
+	(co::VK::TAB, "Tab"),
+	(co::VK::RETURN, "Enter"),
+	(co::VK::PAUSE, "Pause"),
+	(co::VK::ESCAPE, "Esc"),
+	(co::VK::SPACE, "Space"),
+	(co::VK::PRIOR, "PgUp"),
+	(co::VK::NEXT, "PgDn"),
+	(co::VK::END, "End"),
+	(co::VK::HOME, "Home"),
+	(co::VK::LEFT, "Left"),
+	(co::VK::UP, "Up"),
+	(co::VK::RIGHT, "Right"),
+	(co::VK::DOWN, "Down"),
+	(co::VK::SNAPSHOT, "PrtSc"),
+	(co::VK::INSERT, "Ins"),
+	(co::VK::DELETE, "Del"),
+	(co::VK::APPS, "Apps"),
+	(co::VK::NUMPAD0, "Num 0"),
+	(co::VK::NUMPAD1, "Num 1"),
+	(co::VK::NUMPAD2, "Num 2"),
+	(co::VK::NUMPAD3, "Num 3"),
+	(co::VK::NUMPAD4, "Num 4"),
+	(co::VK::NUMPAD5, "Num 5"),
+	(co::VK::NUMPAD6, "Num 6"),
+	(co::VK::NUMPAD7, "Num 7"),
+	(co::VK::NUMPAD8, "Num 8"),
+	(co::VK::NUMPAD9, "Num 9"),
+	(co::VK::MULTIPLY, "Num *"),
+	(co::VK::ADD, "Num +"),
+	(co::VK::SUBTRACT, "Num -"),
+	(co::VK::DECIMAL, "Num ."),
+	(co::VK::DIVIDE, "Num /"),
+	(co::VK::OEM_1, ";"),
+	(co::VK::OEM_PLUS, "="),
+	(co::VK::OEM_COMMA, ","),
+	(co::VK::OEM_MINUS, "-"),
+	(co::VK::OEM_PERIOD, "."),
+	(co::VK::OEM_2, "/"),
+	(co::VK::OEM_3, "`"),
+	(co::VK::OEM_4, "["),
+	(co::VK::OEM_5, "\\"),
+	(co::VK::OEM_6, "]"),
+	(co::VK::OEM_7, "'"),
+	(co::VK::VOLUME_MUTE, "Volume Mute"),
+	(co::VK::VOLUME_DOWN, "Volume Down"),
+	(co::VK::VOLUME_UP, "Volume Up"),
+];
+
+/// Keys which need the extended-key flag to have their names retrieved by
+/// [`GetKeyNameText`](crate::GetKeyNameText); otherwise the names of the numeric
+/// keypad keys, which share the same scan codes, are returned.
+const EXTENDED_KEYS: [co::VK; 14] = [
+	co::VK::PRIOR,
+	co::VK::NEXT,
+	co::VK::END,
+	co::VK::HOME,
+	co::VK::LEFT,
+	co::VK::UP,
+	co::VK::RIGHT,
+	co::VK::DOWN,
+	co::VK::SNAPSHOT,
+	co::VK::INSERT,
+	co::VK::DELETE,
+	co::VK::LWIN,
+	co::VK::APPS,
+	co::VK::DIVIDE,
+];
+
+/// Alternative key names, accepted when parsing.
+const KEY_ALIASES: [(co::VK, &str); 13] = [
+	(co::VK::BACK, "Back"),
+	(co::VK::RETURN, "Return"),
+	(co::VK::PAUSE, "Break"),
+	(co::VK::ESCAPE, "Escape"),
+	(co::VK::PRIOR, "PageUp"),
+	(co::VK::NEXT, "PageDown"),
+	(co::VK::SNAPSHOT, "PrintScreen"),
+	(co::VK::INSERT, "Insert"),
+	(co::VK::DELETE, "Delete"),
+	(co::VK::APPS, "Menu"),
+	(co::VK::MULTIPLY, "Multiply"),
+	(co::VK::ADD, "Add"),
+	(co::VK::OEM_PLUS, "+"),
+];
+
+impl std::fmt::Display for ACCEL {
+	/// Formats the accelerator as a shortcut string like `"Ctrl+Shift+S"`,
+	/// which can be parsed back by [`ACCEL::parse`](crate::ACCEL::parse).
+	///
+	/// The names are always in English; for the names in the keyboard
+	/// language, use [`ACCEL::key_name_text`](crate::ACCEL::key_name_text).
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for (flag, name) in MODIFIERS.iter() {
+			if self.fVirt.has(*flag) {
+				write!(f, "{}+", name)?;
+			}
+		}
+
+		if !self.fVirt.has(co::ACCELF::VIRTKEY) {
+			return match char::from_u32(self.key.raw() as _) {
+				Some(ch) => write!(f, "{}", ch), // character code
+				None => write!(f, "{:#06x}", self.key.raw()),
+			};
+		}
+
+		let vk = self.key.raw();
+		if (co::VK::CHAR_0.raw()..=co::VK::CHAR_9.raw()).contains(&vk)
+			|| (co::VK::CHAR_A.raw()..=co::VK::CHAR_Z.raw()).contains(&vk)
+		{
+			write!(f, "{}", vk as u8 as char)
+		} else if (co::VK::F1.raw()..=co::VK::F24.raw()).contains(&vk) {
+			write!(f, "F{}", vk - co::VK::F1.raw() + 1)
+		} else if let Some((_, name)) = KEY_NAMES.iter().find(|(key, _)| *key == self.key) {
+			write!(f, "{}", name)
+		} else {
+			write!(f, "{:#04x}", vk) // unnamed virtual key
+		}
+	}
+}
+
+impl ACCEL {
+	/// Parses a shortcut string like `"Ctrl+Shift+S"`, `"Alt+F4"` or
+	/// `"Ctrl+Num +"` into a virtual-key accelerator.
+	///
+	/// The modifiers are `Ctrl`, `Shift` and `Alt`, and they must come before
+	/// the key. Names are case-insensitive, and spaces around `+` are ignored.
+	/// The key can be a letter, a digit, `F1` to `F24`, a named key like `Del`
+	/// or `PgUp`, a numeric keypad key like `Num 5` or `Num *`, or a punctuation
+	/// key of the US keyboard layout.
+	///
+	/// Returns [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the string is not a valid shortcut.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use winsafe::{self as w, co};
+	///
+	/// let accel = w::ACCEL::parse("ctrl + shift + s", 2001)?;
+	/// assert_eq!(accel.fVirt, co::ACCELF::VIRTKEY | co::ACCELF::CONTROL | co::ACCELF::SHIFT);
+	/// assert_eq!(accel.key, co::VK::CHAR_S);
+	/// assert_eq!(accel.cmd, 2001);
+	/// assert_eq!(accel.to_string(), "Ctrl+Shift+S");
+	///
+	/// let accel = w::ACCEL::parse("Ctrl+Num +", 2002)?;
+	/// assert_eq!(accel.key, co::VK::ADD);
+	///
+	/// let accel = w::ACCEL::parse("Ctrl++", 2003)?;
+	/// assert_eq!(accel.key, co::VK::OEM_PLUS);
+	///
+	/// assert!(w::ACCEL::parse("Ctrl+", 2004).is_err());
+	/// assert!(w::ACCEL::parse("S+Ctrl", 2005).is_err());
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn parse(shortcut: &str, cmd: u16) -> SysResult<Self> {
+		let mut flags = co::ACCELF::VIRTKEY;
+		let mut rest = shortcut;
+
+		while let Some((flag, after)) = parse_modifier(rest) {
+			if flags.has(flag) {
+				return Err(co::ERROR::INVALID_PARAMETER); // repeated modifier
+			}
+			flags |= flag;
+			rest = after;
+		}
+
+		Ok(Self {
+			fVirt: flags,
+			key: parse_key(rest).ok_or(co::ERROR::INVALID_PARAMETER)?,
+			cmd,
+		})
+	}
+
+	/// Formats the accelerator like [`to_string`](std::string::ToString),
+	/// but with the key names of the current keyboard layout, as returned by
+	/// [`GetKeyNameText`](crate::GetKeyNameText) – in German, `"Ctrl+Shift+S"`
+	/// becomes `"Strg+Umschalt+S"`.
+	///
+	/// This is the text to be displayed to the user, and it cannot be parsed
+	/// back.
+	pub fn key_name_text(&self) -> SysResult<String> {
+		let mut parts = Vec::<String>::with_capacity(4);
+		for (flag, vk) in [
+			(co::ACCELF::CONTROL, co::VK::CONTROL),
+			(co::ACCELF::SHIFT, co::VK::SHIFT),
+			(co::ACCELF::ALT, co::VK::MENU),
+		] {
+			if self.fVirt.has(flag) {
+				parts.push(vk_name_text(vk)?);
+			}
+		}
+
+		parts.push(if self.fVirt.has(co::ACCELF::VIRTKEY) {
+			vk_name_text(self.key)?
+		} else {
+			Self {
+				fVirt: co::ACCELF::default(),
+				key: self.key,
+				cmd: 0,
+			}
+			.to_string() // character code
+		});
+		Ok(parts.join("+"))
+	}
+}
+
+/// Retrieves the name of the virtual key in the current keyboard layout,
+/// falling back to the English name if the key has no scan code.
+fn vk_name_text(vk: co::VK) -> SysResult<String> {
+	let scan_code = MapVirtualKey(vk.raw() as _, co::MAPVK::VK_TO_VSC);
+	if scan_code == 0 {
+		return Ok(ACCEL {
+			fVirt: co::ACCELF::VIRTKEY,
+			key: vk,
+			cmd: 0,
+		}
+		.to_string());
+	}
+	let extended = if EXTENDED_KEYS.contains(&vk) { 1 << 24 } else { 0 };
+	GetKeyNameText(((scan_code << 16) | extended) as _)
+}
+
+/// If the string starts with a modifier followed by `+`, returns the modifier
+/// and the remaining string.
+#[must_use]
+fn parse_modifier(text: &str) -> Option<(co::ACCELF, &str)> {
+	let text = text.trim_start();
+	MODIFIERS
+		.iter()
+		.chain(MODIFIER_ALIASES.iter())
+		.find_map(|(flag, name)| {
+			let after = text
+				.get(..name.len())
+				.filter(|head| head.eq_ignore_ascii_case(name))
+				.map(|_| text[name.len()..].trim_start())?;
+			after.strip_prefix('+').map(|after| (*flag, after))
+		})
+}
+
+/// Parses the key name, which is what remains after the modifiers.
+#[must_use]
+fn parse_key(text: &str) -> Option<co::VK> {
+	let name = text
+		.chars()
+		.filter(|ch| !ch.is_whitespace())
+		.collect::<String>()
+		.to_ascii_uppercase();
+	let mut chars = name.chars();
+
+	match (chars.next(), chars.next()) {
+		(None, _) => return None,
+		(Some(ch), None) if ch.is_ascii_alphanumeric() => {
+			return Some(unsafe { co::VK::from_raw(ch as _) }); // letters and digits match their VK codes
+		},
+		(Some('F'), Some(_)) => {
+			if let Ok(num @ 1..=24) = name[1..].parse::<u16>() {
+				return Some(unsafe { co::VK::from_raw(co::VK::F1.raw() + num - 1) });
+			}
+		},
+		_ => {},
+	}
+
+	KEY_NAMES
+		.iter()
+		.chain(KEY_ALIASES.iter())
+		.find(|(_, key_name)| {
+			key_name
+				.chars()
+				.filter(|ch| !ch.is_whitespace())
+				.map(|ch| ch.to_ascii_uppercase())
+				.eq(name.chars())
+		})
+		.map(|(key, _)| *key)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(shortcut: &str) -> Option<(co::ACCELF, co::VK)> {
+		ACCEL::parse(shortcut, 1).ok().map(|a| (a.fVirt, a.key))
+	}
+
+	fn vk(ch: char) -> co::VK {
+		unsafe { co::VK::from_raw(ch as _) }
+	}
+
+	#[test]
+	fn parse_modifiers_and_keys() {
+		let ctrl = co::ACCELF::VIRTKEY | co::ACCELF::CONTROL;
+		assert_eq!(parse("Ctrl+Shift+S"), Some((ctrl | co::ACCELF::SHIFT, co::VK::CHAR_S)),);
+		assert_eq!(parse("Alt+F4"), Some((co::ACCELF::VIRTKEY | co::ACCELF::ALT, co::VK::F4)));
+		assert_eq!(parse("Ctrl+Num +"), Some((ctrl, co::VK::ADD)));
+		assert_eq!(parse("Ctrl++"), Some((ctrl, co::VK::OEM_PLUS)));
+		assert_eq!(parse("Ctrl+5"), Some((ctrl, co::VK::CHAR_5)));
+		assert_eq!(parse("Del"), Some((co::ACCELF::VIRTKEY, co::VK::DELETE)));
+		assert_eq!(ACCEL::parse("Ctrl+S", 1234).unwrap().cmd, 1234);
+	}
+
+	#[test]
+	fn parse_aliases_case_and_spacing() {
+		let ctrl = co::ACCELF::VIRTKEY | co::ACCELF::CONTROL;
+		assert_eq!(parse("Control+PageUp"), Some((ctrl, co::VK::PRIOR)));
+		assert_eq!(parse("  cTRL +  shift+ pgdn "), Some((ctrl | co::ACCELF::SHIFT, co::VK::NEXT)));
+		assert_eq!(parse("ctrl+s"), Some((ctrl, co::VK::CHAR_S)));
+		assert_eq!(parse("Ctrl+Num5"), Some((ctrl, co::VK::NUMPAD5)));
+		assert_eq!(parse("escape"), Some((co::ACCELF::VIRTKEY, co::VK::ESCAPE)));
+	}
+
+	#[test]
+	fn parse_function_keys() {
+		assert_eq!(parse("F1"), Some((co::ACCELF::VIRTKEY, co::VK::F1)));
+		assert_eq!(parse("f24"), Some((co::ACCELF::VIRTKEY, co::VK::F24)));
+		assert_eq!(parse("F"), Some((co::ACCELF::VIRTKEY, co::VK::CHAR_F)));
+		assert_eq!(parse("F0"), None);
+		assert_eq!(parse("F25"), None);
+		assert_eq!(parse("FOO"), None);
+	}
+
+	#[test]
+	fn parse_rejects() {
+		assert_eq!(parse(""), None);
+		assert_eq!(parse("Ctrl+"), None);
+		assert_eq!(parse("Ctrl+ "), None);
+		assert_eq!(parse("Ctrl+Ctrl+S"), None);
+		assert_eq!(parse("Ctrl+Control+S"), None);
+		assert_eq!(parse("S+Ctrl"), None);
+		assert_eq!(parse("Ctrl+S+Alt"), None);
+		assert_eq!(parse("Ctrl+Hyper"), None);
+	}
+
+	#[test]
+	fn display_round_trip() {
+		for shortcut in [
+			"Ctrl+Shift+S",
+			"Alt+F4",
+			"Ctrl+Num +",
+			"Shift+F24",
+			"Ctrl+Alt+Del",
+			"PgUp",
+			"Ctrl+Shift+Alt+0",
+			"Ctrl+\\",
+			"Volume Mute",
+		] {
+			let accel = ACCEL::parse(shortcut, 1).unwrap();
+			assert_eq!(accel.to_string(), shortcut);
+			assert!(ACCEL::parse(&accel.to_string(), 1).unwrap() == accel);
+		}
+	}
+
+	#[test]
+	fn display_normalizes() {
+		assert_eq!(ACCEL::parse("control + pageup", 1).unwrap().to_string(), "Ctrl+PgUp");
+
+		// "+" is accepted for the OEM_PLUS key, which is displayed as "="
+		let plus = ACCEL::parse("Ctrl++", 1).unwrap();
+		assert_eq!(plus.to_string(), "Ctrl+=");
+		assert!(ACCEL::parse("Ctrl+=", 1).unwrap() == plus);
+	}
+
+	#[test]
+	fn display_character_codes() {
+		let accel = ACCEL {
+			fVirt: co::ACCELF::CONTROL,
+			key: vk('a'),
+			cmd: 1,
+		};
+		assert_eq!(accel.to_string(), "Ctrl+a");
+
+		let accel = ACCEL {
+			fVirt: co::ACCELF::default(),
+			key: vk('+'),
+			cmd: 1,
+		};
+		assert_eq!(accel.to_string(), "+");
+
+		let accel = ACCEL {
+			fVirt: co::ACCELF::VIRTKEY,
+			key: unsafe { co::VK::from_raw(0xff) },
+			cmd: 1,
+		};
+		assert_eq!(accel.to_string(), "0xff"); // unnamed virtual key
+	}
+}
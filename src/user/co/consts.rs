@@ -1075,6 +1075,17 @@ const_ordinary! { DLGID: u16;
 	CONTINUE 11
 }
 
+const_ordinary! { DPI_AWARENESS_CONTEXT: isize;
+	/// [`DPI_AWARENESS_CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context)
+	/// pseudo-handle values (`isize`).
+	=>
+	UNAWARE -1
+	SYSTEM_AWARE -2
+	PER_MONITOR_AWARE -3
+	PER_MONITOR_AWARE_V2 -4
+	UNAWARE_GDISCALED -5
+}
+
 const_ordinary! { DPI_HOSTING_BEHAVIOR: u32;
 	/// [`DPI_HOSTING_BEHAVIOR`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ne-windef-dpi_hosting_behavior)
 	/// enumeration (`u32`).
@@ -1735,6 +1746,16 @@ const_bitflag! { LWA: u32;
 	COLORKEY 0x0000_0001
 }
 
+const_ordinary! { MAPVK: u32;
+	/// [`MapVirtualKey`](crate::MapVirtualKey) `map_type` (`u32`).
+	=>
+	VK_TO_VSC 0
+	VSC_TO_VK 1
+	VK_TO_CHAR 2
+	VSC_TO_VK_EX 3
+	VK_TO_VSC_EX 4
+}
+
 const_bitflag! { MB: u32;
 	/// [`HWND::MessageBox`](crate::prelude::user_Hwnd::MessageBox) `flags`
 	/// (`u32`).
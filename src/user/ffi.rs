@@ -118,6 +118,7 @@ extern_sys! { "user32";
 	GetGUIThreadInfo(u32, PVOID) -> BOOL
 	GetIconInfo(HANDLE, PVOID) -> BOOL
 	GetIconInfoExW(HANDLE, PVOID) -> BOOL
+	GetKeyNameTextW(i32, PSTR, i32) -> i32
 	GetLastActivePopup(HANDLE) -> HANDLE
 	GetLastInputInfo(PVOID) -> BOOL
 	GetMenu(HANDLE) -> HANDLE
@@ -198,6 +199,7 @@ extern_sys! { "user32";
 	LockWindowUpdate(HANDLE) -> BOOL
 	LogicalToPhysicalPoint(HANDLE, PVOID) -> BOOL
 	MapDialogRect(HANDLE, PVOID) -> BOOL
+	MapVirtualKeyW(u32, u32) -> u32
 	MapWindowPoints(HANDLE, HANDLE, PVOID, u32) -> i32
 	MessageBeep(u32) -> BOOL
 	MessageBoxW(HANDLE, PCSTR, PCSTR, u32) -> i32
@@ -249,6 +251,7 @@ extern_sys! { "user32";
 	SetPhysicalCursorPos(i32, i32) -> BOOL
 	SetProcessDefaultLayout(u32) -> BOOL
 	SetProcessDPIAware() -> BOOL
+	SetProcessDpiAwarenessContext(isize) -> BOOL
 	SetScrollInfo(HANDLE, i32, PCVOID, BOOL) -> i32
 	SetScrollPos(HANDLE, i32, i32, BOOL) -> i32
 	SetScrollRange(HANDLE, i32, i32, i32, BOOL) -> BOOL
@@ -525,6 +525,21 @@ pub fn GetGUIThreadInfo(thread_id: u32) -> SysResult<GUITHREADINFO> {
 		.map(|_| gti)
 }
 
+/// [`GetKeyNameText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeynametextw)
+/// function.
+///
+/// The `lparam` has the same layout of the `lParam` of
+/// [`wm::KeyDown`](crate::msg::wm::KeyDown): the scan code is in bits 16-23,
+/// and the extended-key flag is bit 24.
+#[must_use]
+pub fn GetKeyNameText(lparam: i32) -> SysResult<String> {
+	let mut buf = WString::new_alloc_buf(64);
+	match unsafe { ffi::GetKeyNameTextW(lparam, buf.as_mut_ptr(), buf.buf_len() as _) } {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
 /// [`GetLastInputInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getlastinputinfo)
 /// function.
 #[must_use]
@@ -709,6 +724,15 @@ pub fn LockSetForegroundWindow(lock_code: co::LSFW) -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::LockSetForegroundWindow(lock_code.raw()) })
 }
 
+/// [`MapVirtualKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapvirtualkeyw)
+/// function.
+///
+/// Returns zero if there is no translation.
+#[must_use]
+pub fn MapVirtualKey(code: u32, map_type: co::MAPVK) -> u32 {
+	unsafe { ffi::MapVirtualKeyW(code, map_type.raw()) }
+}
+
 /// [`MessageBeep`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-messagebeep)
 /// function.
 pub fn MessageBeep(sound_type: co::MBP) -> SysResult<()> {
@@ -906,6 +930,16 @@ pub fn SetProcessDPIAware() -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::SetProcessDPIAware() })
 }
 
+/// [`SetProcessDpiAwarenessContext`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext)
+/// function.
+///
+/// Fails with [`co::ERROR::ACCESS_DENIED`](crate::co::ERROR::ACCESS_DENIED)
+/// if the DPI awareness was already set, either by the application manifest or
+/// by a previous call.
+pub fn SetProcessDpiAwarenessContext(value: co::DPI_AWARENESS_CONTEXT) -> SysResult<()> {
+	bool_to_sysresult(unsafe { ffi::SetProcessDpiAwarenessContext(value.raw()) })
+}
+
 /// [`SetSysColors`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setsyscolors)
 /// function.
 ///
@@ -344,6 +344,40 @@ impl<'a> MsgSendRecv for DeviceChange<'a> {
 	}
 }
 
+/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DpiChanged<'a> {
+	pub new_dpi: (u16, u16),
+	pub suggested_rect: &'a RECT,
+}
+
+impl<'a> MsgSend for DpiChanged<'a> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DPICHANGED,
+			wparam: MAKEDWORD(self.new_dpi.0, self.new_dpi.1) as _,
+			lparam: self.suggested_rect as *const _ as _,
+		}
+	}
+}
+
+impl<'a> MsgSendRecv for DpiChanged<'a> {
+	unsafe fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			new_dpi: (LOWORD(p.wparam as _), HIWORD(p.wparam as _)),
+			suggested_rect: &*(p.lparam as *const _),
+		}
+	}
+}
+
 /// [`WM_ENABLE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-enable)
 /// message parameters.
 ///
@@ -1,5 +1,6 @@
 #![cfg(feature = "user")]
 
+mod accel_text;
 mod aliases;
 mod enums;
 mod funcs;